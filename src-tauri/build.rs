@@ -9,5 +9,12 @@ fn main() {
             "<!DOCTYPE html><html><body>Frontend not built. Run <code>pnpm build</code> first.</body></html>",
         );
     }
-    tauri_build::build()
+    // 发布公钥通过 option_env! 编译进程序，变更后需重新编译
+    println!("cargo:rerun-if-env-changed=MXU_RELEASE_PUBLIC_KEY");
+    // 在 Tauri 默认清单基础上声明 longPathAware：系统开启长路径支持后，
+    // 进程内（含 MaaFramework）的文件 API 才能访问超过 260 字符的路径
+    let windows = tauri_build::WindowsAttributes::new()
+        .app_manifest(include_str!("windows-app-manifest.xml"));
+    tauri_build::try_build(tauri_build::Attributes::new().windows_attributes(windows))
+        .expect("failed to run tauri-build");
 }
@@ -0,0 +1,402 @@
+//! 本地 REST 控制服务（`api-server` feature，默认启用）
+//!
+//! 独立于 Web UI 服务器，仅绑定 127.0.0.1，供脚本 / Home Assistant 等外部程序
+//! 通过令牌鉴权触发任务、查询状态和获取截图，并通过 `/v1/events` WebSocket
+//! 订阅实时事件（Maa 回调、Agent 输出、下载进度等），供外部看板 / OBS 叠加层使用。
+//!
+//! 配置项位于 `settings.apiServer`：
+//! - `enabled`: 是否启用（重启生效）
+//! - `port`: 监听端口（默认 12711）
+//! - `token`: 访问令牌，请求需携带 `Authorization: Bearer <token>`（无法设置请求头的
+//!   客户端如 OBS 浏览器源可使用 `?token=` 查询参数），为空时拒绝启动
+//!
+//! 开始任务复用前端的启动流程（连接、加载资源、前置动作等），
+//! 因此需要主窗口已加载；停止任务直接调用后端实现。
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, Request, State,
+    },
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use tauri::{Emitter, Manager};
+
+use crate::commands::{
+    app_config::AppConfigState,
+    maa_core::{get_cached_image_impl, post_screencap_impl, stop_task_impl},
+    types::{InstanceRuntime, MaaState},
+    utils::emit_state_changed,
+};
+use crate::i18n::tr;
+use crate::ws_broadcast::WsBroadcast;
+
+/// 默认监听端口（与 Web UI 服务器的 12701 错开）
+pub const DEFAULT_API_PORT: u16 = 12711;
+
+/// 截图等待超时
+const SCREENSHOT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[derive(Clone)]
+struct ApiState {
+    app_config: Arc<AppConfigState>,
+    maa_state: Arc<MaaState>,
+    app_handle: tauri::AppHandle,
+    token: Arc<str>,
+}
+
+/// 读取 `settings.apiServer`，启用时在后台启动 REST 服务
+pub fn spawn_from_config(
+    app_config: Arc<AppConfigState>,
+    maa_state: Arc<MaaState>,
+    app_handle: tauri::AppHandle,
+) {
+    let (enabled, port, token) = {
+        let config = app_config.config.lock();
+        let settings = config.get("settings").and_then(|s| s.get("apiServer"));
+        let enabled = settings
+            .and_then(|s| s.get("enabled"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let port = settings
+            .and_then(|s| s.get("port"))
+            .and_then(|v| v.as_u64())
+            .and_then(|v| u16::try_from(v).ok())
+            .filter(|&p| p > 0)
+            .unwrap_or(DEFAULT_API_PORT);
+        let token = settings
+            .and_then(|s| s.get("token"))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        (enabled, port, token)
+    };
+
+    if !enabled {
+        return;
+    }
+    if token.is_empty() {
+        log::warn!("API server: enabled but token is empty, refusing to start");
+        return;
+    }
+
+    let state = ApiState {
+        app_config,
+        maa_state,
+        app_handle,
+        token: Arc::from(token),
+    };
+    tauri::async_runtime::spawn(async move {
+        start_api_server(state, port).await;
+    });
+}
+
+async fn start_api_server(state: ApiState, port: u16) {
+    let app = Router::new()
+        .route("/v1/instances", get(handle_list_instances))
+        .route("/v1/instances/:id/status", get(handle_get_status))
+        .route("/v1/instances/:id/start", post(handle_start))
+        .route("/v1/instances/:id/stop", post(handle_stop))
+        .route("/v1/instances/:id/screenshot", get(handle_screenshot))
+        .route("/v1/events", get(handle_events_upgrade))
+        .layer(middleware::from_fn_with_state(state.clone(), require_token))
+        .with_state(state);
+
+    let addr = format!("127.0.0.1:{}", port);
+    let listener = match tokio::net::TcpListener::bind(&addr).await {
+        Ok(l) => l,
+        Err(e) => {
+            log::error!("API server: failed to bind {}: {}", addr, e);
+            return;
+        }
+    };
+    log::info!("API server listening on http://{}", addr);
+    if let Err(e) = axum::serve(listener, app).await {
+        log::error!("API server error: {}", e);
+    }
+}
+
+/// 常量时间比较，避免通过响应时间逐字节猜测令牌
+fn token_matches(expected: &str, provided: &str) -> bool {
+    let (a, b) = (expected.as_bytes(), provided.as_bytes());
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// 鉴权中间件：校验 `Authorization: Bearer <token>` 或 `?token=` 查询参数
+async fn require_token(State(state): State<ApiState>, request: Request, next: Next) -> Response {
+    let from_header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|v| v.trim().to_string());
+    let from_query = || {
+        request.uri().query().and_then(|q| {
+            q.split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .find(|(k, _)| *k == "token")
+                .and_then(|(_, v)| urlencoding::decode(v).ok())
+                .map(|v| v.into_owned())
+        })
+    };
+    let provided = from_header.or_else(from_query);
+
+    match provided {
+        Some(token) if token_matches(&state.token, &token) => next.run(request).await,
+        _ => error_response(StatusCode::UNAUTHORIZED, tr!("error.api.unauthorized")),
+    }
+}
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> Response {
+    (status, Json(serde_json::json!({ "error": message.into() }))).into_response()
+}
+
+/// 从配置中读取实例列表（id, name）
+fn configured_instances(app_config: &AppConfigState) -> Vec<(String, String)> {
+    let config = app_config.config.lock();
+    config
+        .get("instances")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|inst| {
+                    let id = inst.get("id")?.as_str()?.to_string();
+                    let name = inst
+                        .get("name")
+                        .and_then(|n| n.as_str())
+                        .unwrap_or_default()
+                        .to_string();
+                    Some((id, name))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 构造实例运行时状态（字段与 /api/maa/state 保持一致，使用 camelCase）
+fn runtime_status(runtime: Option<&InstanceRuntime>) -> serde_json::Value {
+    let Some(runtime) = runtime else {
+        return serde_json::json!({
+            "connected": false,
+            "resourceLoaded": false,
+            "isRunning": false,
+            "taskRunState": null,
+        });
+    };
+    serde_json::json!({
+        "connected": runtime.controller.as_ref().is_some_and(|c| c.connected()),
+        "resourceLoaded": runtime.resource.as_ref().is_some_and(|r| r.loaded()),
+        "isRunning": runtime.tasker.as_ref().is_some_and(|t| t.running()),
+        "taskRunState": serde_json::to_value(&runtime.task_run_state).unwrap_or_default(),
+    })
+}
+
+/// GET /v1/instances
+/// 返回已配置的实例及其运行状态
+async fn handle_list_instances(State(state): State<ApiState>) -> Response {
+    let configured = configured_instances(&state.app_config);
+
+    let list: Vec<serde_json::Value> = configured
+        .into_iter()
+        .map(|(id, name)| {
+            let mut status = runtime_status(state.maa_state.instances.get(&id).as_deref());
+            status["id"] = serde_json::Value::String(id);
+            status["name"] = serde_json::Value::String(name);
+            status
+        })
+        .collect();
+    Json(serde_json::json!({ "instances": list })).into_response()
+}
+
+/// GET /v1/instances/:id/status
+async fn handle_get_status(
+    State(state): State<ApiState>,
+    Path(instance_id): Path<String>,
+) -> Response {
+    let Some((_, name)) = configured_instances(&state.app_config)
+        .into_iter()
+        .find(|(id, _)| *id == instance_id)
+    else {
+        return error_response(StatusCode::NOT_FOUND, tr!("error.instance_not_found"));
+    };
+    let mut status = runtime_status(state.maa_state.instances.get(&instance_id).as_deref());
+    status["id"] = serde_json::Value::String(instance_id);
+    status["name"] = serde_json::Value::String(name);
+    Json(status).into_response()
+}
+
+/// POST /v1/instances/:id/start
+/// 通知前端按该实例的已保存配置开始任务（异步，返回 202）
+async fn handle_start(State(state): State<ApiState>, Path(instance_id): Path<String>) -> Response {
+    if !configured_instances(&state.app_config)
+        .iter()
+        .any(|(id, _)| *id == instance_id)
+    {
+        return error_response(StatusCode::NOT_FOUND, tr!("error.instance_not_found"));
+    }
+
+    let running = state
+        .maa_state
+        .instances
+        .get(&instance_id)
+        .is_some_and(|r| r.tasker.as_ref().is_some_and(|t| t.running()));
+    if running {
+        return error_response(StatusCode::CONFLICT, tr!("error.api.instance_running"));
+    }
+
+    let Some(window) = state.app_handle.get_webview_window("main") else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            tr!("error.api.window_not_loaded"),
+        );
+    };
+    if let Err(e) = window.emit(
+        "api-start-tasks",
+        serde_json::json!({ "instanceId": instance_id }),
+    ) {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string());
+    }
+
+    log::info!("API server: start requested for instance {}", instance_id);
+    (
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({ "accepted": true })),
+    )
+        .into_response()
+}
+
+/// POST /v1/instances/:id/stop
+async fn handle_stop(State(state): State<ApiState>, Path(instance_id): Path<String>) -> Response {
+    match stop_task_impl(&state.maa_state, &instance_id) {
+        Ok(()) => {
+            log::info!("API server: stop requested for instance {}", instance_id);
+            emit_state_changed(&state.app_handle, &instance_id, "task-stopped");
+            Json(serde_json::json!({ "ok": true })).into_response()
+        }
+        Err(e) => error_response(StatusCode::CONFLICT, e),
+    }
+}
+
+/// GET /v1/instances/:id/screenshot
+/// 返回最新截图（PNG），无缓存时触发一次截图并等待
+async fn handle_screenshot(
+    State(state): State<ApiState>,
+    Path(instance_id): Path<String>,
+) -> Response {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    let deadline = std::time::Instant::now() + SCREENSHOT_TIMEOUT;
+    let mut triggered = false;
+    loop {
+        match get_cached_image_impl(&state.maa_state, &instance_id) {
+            Ok(data_url) => {
+                if let Some(bytes) = data_url
+                    .strip_prefix("data:image/png;base64,")
+                    .and_then(|b64| STANDARD.decode(b64).ok())
+                {
+                    return (StatusCode::OK, [(header::CONTENT_TYPE, "image/png")], bytes)
+                        .into_response();
+                }
+            }
+            Err(e) => return error_response(StatusCode::NOT_FOUND, e),
+        }
+
+        if std::time::Instant::now() > deadline {
+            return error_response(
+                StatusCode::GATEWAY_TIMEOUT,
+                tr!("error.web.screencap_timeout"),
+            );
+        }
+        if !triggered {
+            triggered = true;
+            if let Err(e) = post_screencap_impl(&state.maa_state, &instance_id) {
+                return error_response(StatusCode::CONFLICT, e);
+            }
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    }
+}
+
+/// GET /v1/events 查询参数
+#[derive(serde::Deserialize)]
+struct EventsQuery {
+    /// 逗号分隔的事件类型过滤（如 `maa-callback,download-progress`），为空时推送全部
+    types: Option<String>,
+}
+
+/// GET /v1/events
+/// WebSocket 事件流，消息格式与 Web UI 的 `/api/ws` 相同：`{ "type": ..., "payload": ... }`
+async fn handle_events_upgrade(
+    ws: WebSocketUpgrade,
+    State(state): State<ApiState>,
+    Query(query): Query<EventsQuery>,
+) -> Response {
+    let Some(broadcast) = state
+        .app_handle
+        .try_state::<Arc<WsBroadcast>>()
+        .map(|b| b.inner().clone())
+    else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            tr!("error.api.events_unavailable"),
+        );
+    };
+    let filter: Vec<String> = query
+        .types
+        .unwrap_or_default()
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect();
+
+    ws.on_upgrade(move |socket| forward_events(socket, broadcast, filter))
+}
+
+/// 将广播事件按类型过滤后转发给外部订阅者，每 30 秒 Ping 保活
+async fn forward_events(mut socket: WebSocket, broadcast: Arc<WsBroadcast>, filter: Vec<String>) {
+    let mut rx = broadcast.subscribe();
+    let mut ping_interval = tokio::time::interval(std::time::Duration::from_secs(30));
+    ping_interval.tick().await;
+
+    loop {
+        tokio::select! {
+            result = rx.recv() => {
+                match result {
+                    Ok(event) => {
+                        if !filter.is_empty() && !filter.iter().any(|t| t == event.kind()) {
+                            continue;
+                        }
+                        let Ok(json) = serde_json::to_string(&event) else {
+                            continue;
+                        };
+                        if socket.send(Message::Text(json.into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                        log::warn!("API events subscriber lagged, skipped {} events", n);
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            _ = ping_interval.tick() => {
+                if socket.send(Message::Ping(vec![].into())).await.is_err() {
+                    break;
+                }
+            }
+            msg = socket.recv() => {
+                match msg {
+                    Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+}
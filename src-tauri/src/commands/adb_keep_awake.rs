@@ -0,0 +1,169 @@
+//! 运行期间保持 ADB 设备亮屏
+//!
+//! 开启 `settings.adbKeepAwake`（默认关闭）后，ADB 实例开始运行任务时记录设备原有的
+//! `stay_on_while_plugged_in` 设置并执行 `svc power stayon true`，运行结束或停止后恢复原值，
+//! 避免手机自动息屏导致截图失败。恢复所需的原值保存在 `InstanceRuntime::adb_stay_on_restore` 中，
+//! 运行中关闭程序时由 [`release_all`] 恢复。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use log::{info, warn};
+use tauri::{AppHandle, Manager};
+
+use super::types::{ControllerConfig, MaaState};
+use super::utils::adb_shell;
+
+/// 是否启用
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// 读取 `settings.adbKeepAwake`（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let enabled = config
+        .get("settings")
+        .and_then(|s| s.get("adbKeepAwake"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// 处理状态变更（由 `emit_state_changed` 调用）
+pub fn on_state_changed(app: &AppHandle, instance_id: &str, kind: &str) {
+    let starting = match kind {
+        "task-started" => true,
+        "tasks-completed" | "task-stopped" => false,
+        _ => return,
+    };
+    if starting && !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // 可能由回调线程触发：实例锁与 adb 调用都放到后台线程，避免阻塞或死锁
+    let app = app.clone();
+    let instance_id = instance_id.to_string();
+    std::thread::spawn(move || update(&app, &instance_id, starting));
+}
+
+fn update(app: &AppHandle, instance_id: &str, starting: bool) {
+    let Some(state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+    let (adb_path, address, restore) = {
+        let Some(mut instance) = state.instances.get_mut(instance_id) else {
+            return;
+        };
+        let Some(ControllerConfig::Adb {
+            adb_path, address, ..
+        }) = instance.controller_config.clone()
+        else {
+            return;
+        };
+        if starting {
+            if instance.adb_stay_on_restore.is_some() {
+                return;
+            }
+            // 先占位，防止同一批次重复触发；原值读取后写入
+            instance.adb_stay_on_restore = Some(String::new());
+            (adb_path, address, None)
+        } else {
+            let Some(restore) = instance.adb_stay_on_restore.take() else {
+                return;
+            };
+            (adb_path, address, Some(restore))
+        }
+    };
+
+    match restore {
+        None => acquire(app, instance_id, &adb_path, &address),
+        Some(prev) => release(instance_id, &adb_path, &address, &prev),
+    }
+}
+
+fn acquire(app: &AppHandle, instance_id: &str, adb_path: &str, address: &str) {
+    let prev = adb_shell(
+        adb_path,
+        address,
+        &["settings", "get", "global", "stay_on_while_plugged_in"],
+    )
+    .ok()
+    .filter(|v| v.parse::<u32>().is_ok())
+    .unwrap_or_else(|| "0".to_string());
+
+    if let Err(e) = adb_shell(adb_path, address, &["svc", "power", "stayon", "true"]) {
+        warn!("Failed to keep device {} awake: {}", address, e);
+        if let Some(state) = app.try_state::<Arc<MaaState>>() {
+            if let Some(mut instance) = state.instances.get_mut(instance_id) {
+                instance.adb_stay_on_restore = None;
+            }
+        }
+        return;
+    }
+    info!(
+        "Device {} kept awake for instance {} (previous stay_on: {})",
+        address, instance_id, prev
+    );
+
+    let Some(state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+    let restore_now = match state.instances.get_mut(instance_id) {
+        Some(mut instance) => match instance.adb_stay_on_restore.as_mut() {
+            Some(slot) => {
+                *slot = prev.clone();
+                false
+            }
+            // 读取原值期间运行已结束，立即恢复
+            None => true,
+        },
+        None => true,
+    };
+    if restore_now {
+        release(instance_id, adb_path, address, &prev);
+    }
+}
+
+fn release(instance_id: &str, adb_path: &str, address: &str, prev: &str) {
+    // 占位值说明启用流程尚未完成，由 acquire 负责恢复
+    if prev.is_empty() {
+        return;
+    }
+    match adb_shell(
+        adb_path,
+        address,
+        &[
+            "settings",
+            "put",
+            "global",
+            "stay_on_while_plugged_in",
+            prev,
+        ],
+    ) {
+        Ok(_) => info!(
+            "Device {} stay_on restored to {} for instance {}",
+            address, prev, instance_id
+        ),
+        Err(e) => warn!("Failed to restore stay_on for device {}: {}", address, e),
+    }
+}
+
+/// 恢复所有仍处于保持亮屏状态的设备（窗口销毁、程序退出前调用）
+pub fn release_all(state: &MaaState) {
+    let pending: Vec<_> = state
+        .instances
+        .iter_mut()
+        .filter_map(|mut entry| {
+            let (id, instance) = entry.pair_mut();
+            let prev = instance.adb_stay_on_restore.take()?;
+            let Some(ControllerConfig::Adb {
+                adb_path, address, ..
+            }) = instance.controller_config.clone()
+            else {
+                return None;
+            };
+            Some((id.clone(), adb_path, address, prev))
+        })
+        .collect();
+    // 实例锁释放后再调用 adb
+    for (instance_id, adb_path, address, prev) in pending {
+        release(&instance_id, &adb_path, &address, &prev);
+    }
+}
@@ -0,0 +1,213 @@
+//! Agent TCP 转发
+//!
+//! MaaFramework 的 TCP 模式 AgentClient 只监听 127.0.0.1，本模块负责把外部连接
+//! 原样转发到 AgentClient 的回环端口：
+//! - `listen`：在指定地址监听，等待 Agent 连接（`tcp_interface` 为非回环地址时），
+//!   只接受 `tcp_allowed_peers` 中的地址，其余连接直接关闭
+//! - `dial`：主动连接远程 Agent 所在的 `host:port`（`remote`）
+//!
+//! 每个转发只建立一个连接：连接建立后两端任一关闭即结束。连接建立前 `RelayGuard`
+//! 被丢弃（如 Agent 连接超时）时停止等待并释放端口。
+
+use std::io::ErrorKind;
+use std::net::{IpAddr, Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use log::{info, warn};
+
+use crate::i18n::tr;
+
+/// 等待连接时检查停止标记的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// 连接远程 Agent 失败后的重试间隔
+const DIAL_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+/// 单次连接远程 Agent 的超时
+const DIAL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 转发的生命周期句柄，丢弃后停止等待尚未建立的连接
+pub struct RelayGuard {
+    stop: Arc<AtomicBool>,
+}
+
+impl Drop for RelayGuard {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// 地址是否为本机回环地址（AgentClient 可直接监听）
+pub fn is_loopback(interface: &str) -> bool {
+    interface.eq_ignore_ascii_case("localhost")
+        || interface
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// 解析允许连接的 Agent 地址（IP 或主机名）
+fn resolve_allowed_peers(interface: &str, peers: &[String]) -> Result<Vec<IpAddr>, String> {
+    let mut allowed = Vec::new();
+    for peer in peers.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let addrs = (peer, 0)
+            .to_socket_addrs()
+            .map_err(|e| tr!("error.agent.allowed_peer_invalid", peer = peer, error = e))?;
+        allowed.extend(addrs.map(|addr| addr.ip().to_canonical()));
+    }
+    if allowed.is_empty() {
+        return Err(tr!("error.agent.allowed_peers_missing", host = interface));
+    }
+    Ok(allowed)
+}
+
+/// 双向转发两个连接，任一方向结束时关闭两端
+fn pipe(a: TcpStream, b: TcpStream) {
+    let (Ok(a_read), Ok(b_read)) = (a.try_clone(), b.try_clone()) else {
+        warn!("[agent_relay] Failed to clone relay sockets");
+        return;
+    };
+    let forward = |mut from: TcpStream, mut to: TcpStream| {
+        thread::spawn(move || {
+            let _ = std::io::copy(&mut from, &mut to);
+            let _ = from.shutdown(Shutdown::Both);
+            let _ = to.shutdown(Shutdown::Both);
+        })
+    };
+    forward(a_read, b);
+    forward(b_read, a);
+}
+
+/// 在 `interface:port` 监听一个 Agent 连接并转发到本机 `local_port`
+///
+/// `port` 为 0 时由系统分配。端口不可用时返回错误，返回实际监听的端口。
+/// 只转发来自 `allowed_peers`（IP 或主机名，不能为空）的连接。
+pub fn listen(
+    interface: &str,
+    port: u16,
+    local_port: u16,
+    allowed_peers: &[String],
+) -> Result<(u16, RelayGuard), String> {
+    let allowed = resolve_allowed_peers(interface, allowed_peers)?;
+    let listener = TcpListener::bind((interface, port)).map_err(|e| {
+        tr!(
+            "error.agent.port_unavailable",
+            host = interface,
+            port = port,
+            error = e
+        )
+    })?;
+    let bound = listener
+        .local_addr()
+        .map_err(|e| tr!("error.agent.local_addr_failed", error = e))?
+        .port();
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| tr!("error.agent.listen_failed", error = e))?;
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let guard = RelayGuard { stop: stop.clone() };
+    info!(
+        "[agent_relay] Listening on {}:{} for agent, forwarding to 127.0.0.1:{}",
+        interface, bound, local_port
+    );
+    thread::spawn(move || loop {
+        if stop.load(Ordering::SeqCst) {
+            info!("[agent_relay] Stopped listening on port {}", bound);
+            return;
+        }
+        match listener.accept() {
+            Ok((remote, addr)) => {
+                if !allowed.contains(&addr.ip().to_canonical()) {
+                    warn!(
+                        "[agent_relay] Rejected connection from {} (not in tcp_allowed_peers)",
+                        addr
+                    );
+                    let _ = remote.shutdown(Shutdown::Both);
+                    continue;
+                }
+                let _ = remote.set_nonblocking(false);
+                match TcpStream::connect(("127.0.0.1", local_port)) {
+                    Ok(local) => {
+                        info!("[agent_relay] Agent connected from {}", addr);
+                        pipe(remote, local);
+                    }
+                    Err(e) => warn!(
+                        "[agent_relay] Failed to reach agent client on port {}: {}",
+                        local_port, e
+                    ),
+                }
+                return;
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                warn!("[agent_relay] Accept failed on port {}: {}", bound, e);
+                return;
+            }
+        }
+    });
+    Ok((bound, guard))
+}
+
+/// 连接远程 Agent 的 `host:port` 并转发到本机 `local_port`
+///
+/// 连接失败时按间隔重试，直到连接成功或 `RelayGuard` 被丢弃。
+pub fn dial(host: &str, port: u16, local_port: u16) -> Result<RelayGuard, String> {
+    let addrs: Vec<_> = (host, port)
+        .to_socket_addrs()
+        .map_err(|e| {
+            tr!(
+                "error.agent.resolve_failed",
+                host = host,
+                port = port,
+                error = e
+            )
+        })?
+        .collect();
+    if addrs.is_empty() {
+        return Err(tr!("error.agent.resolve_empty", host = host, port = port));
+    }
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let guard = RelayGuard { stop: stop.clone() };
+    let target = format!("{}:{}", host, port);
+    info!(
+        "[agent_relay] Connecting to remote agent {}, forwarding to 127.0.0.1:{}",
+        target, local_port
+    );
+    thread::spawn(move || {
+        let mut last_error = None;
+        while !stop.load(Ordering::SeqCst) {
+            let connected = addrs.iter().find_map(|addr| {
+                match TcpStream::connect_timeout(addr, DIAL_TIMEOUT) {
+                    Ok(stream) => Some(stream),
+                    Err(e) => {
+                        last_error = Some(e.to_string());
+                        None
+                    }
+                }
+            });
+            let Some(remote) = connected else {
+                thread::sleep(DIAL_RETRY_INTERVAL);
+                continue;
+            };
+            match TcpStream::connect(("127.0.0.1", local_port)) {
+                Ok(local) => {
+                    info!("[agent_relay] Connected to remote agent {}", target);
+                    pipe(remote, local);
+                }
+                Err(e) => warn!(
+                    "[agent_relay] Failed to reach agent client on port {}: {}",
+                    local_port, e
+                ),
+            }
+            return;
+        }
+        warn!(
+            "[agent_relay] Gave up connecting to remote agent {}: {}",
+            target,
+            last_error.unwrap_or_default()
+        );
+    });
+    Ok(guard)
+}
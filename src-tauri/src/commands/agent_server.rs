@@ -0,0 +1,183 @@
+//! Agent Server 模式
+//!
+//! 让 MXU 作为 MaaFramework Agent 对外提供内置 custom actions，供其他 MaaFramework 宿主
+//! （MaaPiCli、其他 GUI 等）通过 `AgentClient` 连接复用。
+//!
+//! MaaAgentServer 与 MaaFramework 不能在同一进程中加载，因此 `mxu_agent_server_start`
+//! 以 `--agent-server <identifier>` 参数启动 MXU 自身的子进程，子进程加载 MaaAgentServer
+//! 并注册动作后阻塞等待宿主断开。
+
+use std::fs::File;
+use std::process::{Child, Command, Stdio};
+
+use log::{info, warn};
+use maa_framework::agent_server::AgentServer;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use super::utils::{get_agent_server_library_path, get_logs_dir, get_maafw_dir};
+use crate::i18n::tr;
+
+/// Agent Server 子进程参数（内部使用，不出现在帮助文本中）
+pub const AGENT_SERVER_FLAG: &str = "--agent-server";
+
+/// 正在运行的 Agent Server 子进程（identifier, 子进程）
+static SERVER: Mutex<Option<(String, Child)>> = Mutex::new(None);
+
+/// Agent Server 运行状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentServerStatus {
+    pub running: bool,
+    pub identifier: Option<String>,
+    pub pid: Option<u32>,
+}
+
+/// 获取命令行 --agent-server 参数指定的 identifier
+pub fn get_agent_server_identifier() -> Option<String> {
+    super::system::get_cli_arg_value(AGENT_SERVER_FLAG, AGENT_SERVER_FLAG)
+}
+
+/// 在当前进程中运行 Agent Server（子进程入口），返回进程退出码
+pub fn run_agent_server(identifier: &str) -> i32 {
+    let maafw_dir = match get_maafw_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+    let lib_path = get_agent_server_library_path(&maafw_dir);
+    if let Err(e) = maa_framework::load_library(&lib_path) {
+        eprintln!("load_library failed: {} (path: {:?})", e, lib_path);
+        return 1;
+    }
+
+    let failed = crate::mxu_actions::register_agent_server_actions();
+    if failed > 0 {
+        eprintln!("{} custom actions failed to register", failed);
+    }
+
+    if let Err(e) = AgentServer::start_up(identifier) {
+        eprintln!("start_up failed: {:?}", e);
+        return 2;
+    }
+    println!("MXU agent server started: {}", identifier);
+
+    AgentServer::join();
+    AgentServer::shut_down();
+    0
+}
+
+fn reap_exited(server: &mut Option<(String, Child)>) {
+    let exited = match server.as_mut() {
+        Some((_, child)) => !matches!(child.try_wait(), Ok(None)),
+        None => false,
+    };
+    if exited {
+        if let Some((identifier, _)) = server.take() {
+            info!("[agent_server] Agent server {} exited", identifier);
+        }
+    }
+}
+
+fn status_of(server: &Option<(String, Child)>) -> AgentServerStatus {
+    match server {
+        Some((identifier, child)) => AgentServerStatus {
+            running: true,
+            identifier: Some(identifier.clone()),
+            pid: Some(child.id()),
+        },
+        None => AgentServerStatus {
+            running: false,
+            identifier: None,
+            pid: None,
+        },
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 启动 Agent Server 子进程，宿主使用相同的 identifier 连接
+#[tauri::command]
+pub fn mxu_agent_server_start(identifier: String) -> Result<AgentServerStatus, String> {
+    let identifier = identifier.trim().to_string();
+    if identifier.is_empty() || identifier.starts_with('-') {
+        return Err(tr!("error.agent_server.invalid_identifier"));
+    }
+
+    let mut guard = SERVER.lock();
+    reap_exited(&mut guard);
+    if let Some((running, _)) = guard.as_ref() {
+        return Err(tr!(
+            "error.agent_server.already_running",
+            identifier = running
+        ));
+    }
+
+    let maafw_dir = get_maafw_dir()?;
+    let lib_path = get_agent_server_library_path(&maafw_dir);
+    if !lib_path.exists() {
+        return Err(tr!(
+            "error.agent_server.lib_missing",
+            path = lib_path.display()
+        ));
+    }
+
+    let exe = std::env::current_exe().map_err(|e| tr!("error.fs.exe_path", error = e))?;
+    let logs_dir = get_logs_dir();
+    let _ = std::fs::create_dir_all(&logs_dir);
+    let log_file = File::create(logs_dir.join("mxu-agent-server.log"))
+        .map_err(|e| tr!("error.agent_server.log_file", error = e))?;
+    let log_file_err = log_file
+        .try_clone()
+        .map_err(|e| tr!("error.agent_server.log_file", error = e))?;
+
+    let mut cmd = Command::new(exe);
+    cmd.arg(AGENT_SERVER_FLAG)
+        .arg(&identifier)
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(log_file))
+        .stderr(Stdio::from(log_file_err));
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let child = cmd
+        .spawn()
+        .map_err(|e| tr!("error.agent_server.spawn", error = e))?;
+    info!(
+        "[agent_server] Agent server {} started (pid {})",
+        identifier,
+        child.id()
+    );
+    *guard = Some((identifier, child));
+    Ok(status_of(&guard))
+}
+
+/// 停止 Agent Server 子进程
+#[tauri::command]
+pub fn mxu_agent_server_stop() -> Result<(), String> {
+    let mut guard = SERVER.lock();
+    if let Some((identifier, mut child)) = guard.take() {
+        if let Err(e) = child.kill() {
+            warn!("[agent_server] Failed to kill agent server: {}", e);
+        }
+        let _ = child.wait();
+        info!("[agent_server] Agent server {} stopped", identifier);
+    }
+    Ok(())
+}
+
+/// 查询 Agent Server 运行状态
+#[tauri::command]
+pub fn mxu_agent_server_status() -> Result<AgentServerStatus, String> {
+    let mut guard = SERVER.lock();
+    reap_exited(&mut guard);
+    Ok(status_of(&guard))
+}
@@ -0,0 +1,283 @@
+//! 杀毒软件误报自诊断
+//!
+//! 解压后 MaaFramework 动态库、Agent 可执行文件"消失"多半是被 Windows Defender 等杀毒软件
+//! 隔离。`mxu_diagnose_antivirus` 检查关键文件是否缺失、Defender 实时保护与排除项状态，
+//! 以及 Defender 威胁记录中是否有安装目录下的文件，并给出需要加入白名单的具体路径。
+
+use std::path::{Path, PathBuf};
+
+use log::info;
+use serde::Serialize;
+
+use super::maa_agent::resolve_child_exec_path;
+use super::types::AgentConfig;
+use super::utils::{
+    get_agent_server_library_path, get_exe_directory, get_maafw_dir, get_maafw_library_path,
+};
+use crate::i18n::tr;
+
+/// 缺失的关键文件
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingFile {
+    pub path: String,
+    /// maafw / agent / manifest（完整性清单中的二进制文件）
+    pub kind: String,
+}
+
+/// Windows Defender 状态
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefenderStatus {
+    /// 实时保护是否开启（未安装或被第三方杀毒软件接管时为 None）
+    pub realtime_enabled: Option<bool>,
+    /// 排除项是否可读（非管理员运行时不可读）
+    pub exclusions_readable: bool,
+    /// 安装目录是否已在排除项中（排除项不可读时为 None）
+    pub excluded: Option<bool>,
+    /// 威胁记录中位于安装目录下的文件
+    pub detections: Vec<String>,
+}
+
+/// 误报自诊断报告
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AntivirusReport {
+    pub missing: Vec<MissingFile>,
+    /// 仅 Windows
+    pub defender: Option<DefenderStatus>,
+    /// 是否疑似被杀毒软件隔离
+    pub suspected: bool,
+    /// 建议加入白名单的路径
+    pub paths_to_whitelist: Vec<String>,
+    /// 引导步骤
+    pub suggestions: Vec<String>,
+}
+
+const BINARY_EXTENSIONS: [&str; 6] = ["dll", "exe", "so", "dylib", "node", "pyd"];
+
+fn is_binary(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| BINARY_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+}
+
+/// 收集缺失的关键文件
+fn find_missing(
+    exe_dir: &Path,
+    maafw_dir: &Path,
+    agents: &[AgentConfig],
+    cwd: &str,
+) -> Vec<MissingFile> {
+    let mut missing = Vec::new();
+    let mut push = |path: PathBuf, kind: &str| {
+        let path = path.display().to_string();
+        if !missing.iter().any(|m: &MissingFile| m.path == path) {
+            missing.push(MissingFile {
+                path,
+                kind: kind.to_string(),
+            });
+        }
+    };
+
+    for lib in [
+        get_maafw_library_path(maafw_dir),
+        get_agent_server_library_path(maafw_dir),
+    ] {
+        if !lib.is_file() {
+            push(lib, "maafw");
+        }
+    }
+
+    for agent in agents {
+        let child_exec = agent.child_exec.trim();
+        let is_remote = agent
+            .remote
+            .as_deref()
+            .is_some_and(|r| !r.trim().is_empty());
+        if is_remote || child_exec.is_empty() {
+            continue;
+        }
+        let exec_path = resolve_child_exec_path(child_exec, cwd);
+        // 裸命令名（python / node）走 PATH，不属于安装包内的文件
+        if exec_path.components().count() == 1 {
+            continue;
+        }
+        let found =
+            exec_path.is_file() || (cfg!(windows) && exec_path.with_extension("exe").is_file());
+        if !found {
+            push(exec_path, "agent");
+        }
+    }
+
+    // 完整性清单中列出但已不存在的二进制文件
+    if let Ok(report) = super::integrity::verify_integrity_impl() {
+        for rel in report.missing.iter().filter(|p| is_binary(p)) {
+            push(exe_dir.join(rel), "manifest");
+        }
+    }
+    missing
+}
+
+/// 路径比较用的规范形式（Windows 下不区分大小写、统一分隔符）
+fn path_key(path: &str) -> String {
+    let path = path.trim().trim_end_matches(['\\', '/']);
+    if cfg!(windows) {
+        path.replace('/', "\\").to_lowercase()
+    } else {
+        path.to_string()
+    }
+}
+
+fn is_under(path: &str, dir: &str) -> bool {
+    let (path, dir) = (path_key(path), path_key(dir));
+    path == dir || path.starts_with(&format!("{}{}", dir, std::path::MAIN_SEPARATOR))
+}
+
+#[cfg(windows)]
+fn query_defender(exe_dir: &Path) -> Option<DefenderStatus> {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    #[derive(serde::Deserialize)]
+    struct Raw {
+        realtime: Option<bool>,
+        #[serde(default)]
+        exclusions: Vec<Option<String>>,
+        #[serde(default)]
+        detections: Vec<Option<String>>,
+    }
+
+    // 输出默认使用控制台代码页（如 GBK），强制无 BOM 的 UTF-8 以正确解析非 ASCII 路径
+    let script = "$ErrorActionPreference='SilentlyContinue';\
+        [Console]::OutputEncoding=New-Object Text.UTF8Encoding $false;\
+        $s=Get-MpComputerStatus;$p=Get-MpPreference;$d=Get-MpThreatDetection;\
+        [pscustomobject]@{realtime=$s.RealTimeProtectionEnabled;\
+        exclusions=@($p.ExclusionPath);\
+        detections=@($d|ForEach-Object{$_.Resources})}|ConvertTo-Json -Compress";
+    let output = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", script])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|e| log::warn!("Failed to query Windows Defender: {}", e))
+        .ok()?;
+    let raw: Raw = serde_json::from_slice(&output.stdout)
+        .map_err(|e| log::warn!("Unexpected Get-MpPreference output: {}", e))
+        .ok()?;
+
+    let exe_dir = exe_dir.to_string_lossy();
+    let exclusions: Vec<String> = raw.exclusions.into_iter().flatten().collect();
+    // 非管理员时 Get-MpPreference 以 "N/A: Must be an administrator..." 占位
+    let exclusions_readable = !exclusions.iter().any(|e| e.starts_with("N/A"));
+    let excluded = exclusions_readable.then(|| exclusions.iter().any(|e| is_under(&exe_dir, e)));
+    let detections = raw
+        .detections
+        .into_iter()
+        .flatten()
+        .map(|r| r.trim_start_matches("file:_").to_string())
+        .filter(|r| is_under(r, &exe_dir))
+        .collect();
+
+    Some(DefenderStatus {
+        realtime_enabled: raw.realtime,
+        exclusions_readable,
+        excluded,
+        detections,
+    })
+}
+
+#[cfg(not(windows))]
+fn query_defender(_exe_dir: &Path) -> Option<DefenderStatus> {
+    None
+}
+
+/// 执行杀毒软件误报自诊断（阻塞，Windows 下会调用 PowerShell）
+pub fn diagnose_antivirus_impl(
+    agents: &[AgentConfig],
+    cwd: Option<&str>,
+) -> Result<AntivirusReport, String> {
+    let exe_dir = get_exe_directory()?;
+    let maafw_dir = get_maafw_dir()?;
+    let exe_dir_str = exe_dir.to_string_lossy().to_string();
+    let cwd = cwd.unwrap_or(&exe_dir_str);
+
+    let missing = find_missing(&exe_dir, &maafw_dir, agents, cwd);
+    let defender = query_defender(&exe_dir);
+    let detected = defender.as_ref().is_some_and(|d| !d.detections.is_empty());
+    let suspected = detected || !missing.is_empty();
+
+    let mut paths_to_whitelist = vec![exe_dir_str.clone()];
+    if !is_under(&maafw_dir.to_string_lossy(), &exe_dir_str) {
+        paths_to_whitelist.push(maafw_dir.display().to_string());
+    }
+    for m in missing.iter().filter(|m| m.kind == "agent") {
+        if let Some(parent) = Path::new(&m.path).parent() {
+            let parent = parent.display().to_string();
+            if !paths_to_whitelist.iter().any(|p| is_under(&parent, p)) {
+                paths_to_whitelist.push(parent);
+            }
+        }
+    }
+
+    let mut suggestions = Vec::new();
+    if detected {
+        suggestions.push(tr!("antivirus.restore_quarantined"));
+    }
+    if suspected {
+        suggestions.push(tr!(
+            "antivirus.add_exclusions",
+            paths = paths_to_whitelist.join("; ")
+        ));
+        suggestions.push(tr!("antivirus.reinstall"));
+        if defender.is_none()
+            || defender
+                .as_ref()
+                .is_some_and(|d| d.realtime_enabled.is_none())
+        {
+            suggestions.push(tr!("antivirus.third_party"));
+        }
+    }
+    match defender
+        .as_ref()
+        .map(|d| (d.exclusions_readable, d.excluded))
+    {
+        Some((false, _)) => suggestions.push(tr!("antivirus.run_as_admin")),
+        Some((true, Some(true))) if suspected => {
+            suggestions.push(tr!("antivirus.already_excluded"))
+        }
+        _ => {}
+    }
+
+    info!(
+        "Antivirus diagnose: {} missing, suspected={}",
+        missing.len(),
+        suspected
+    );
+    Ok(AntivirusReport {
+        missing,
+        defender,
+        suspected,
+        paths_to_whitelist,
+        suggestions,
+    })
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 诊断关键文件是否被杀毒软件误报隔离，并给出需要加入白名单的路径
+///
+/// `agents` / `cwd` 来自 interface.json 中的 agent 配置，未提供时只检查 MaaFramework 文件。
+#[tauri::command]
+pub async fn mxu_diagnose_antivirus(
+    agents: Option<Vec<AgentConfig>>,
+    cwd: Option<String>,
+) -> Result<AntivirusReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        diagnose_antivirus_impl(agents.as_deref().unwrap_or_default(), cwd.as_deref())
+    })
+    .await
+    .map_err(|e| tr!("error.task_panicked", error = e))?
+}
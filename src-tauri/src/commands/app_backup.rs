@@ -0,0 +1,393 @@
+//! 通过 adb 备份 / 恢复目标应用数据
+//!
+//! 在执行有风险的操作前，把游戏的 `shared_prefs`（需 root）、`Android/data`、`Android/obb`
+//! 目录拉取到 `backups/<实例>/<时间戳>/`，需要时再推送回设备。
+//! 每个实例的默认包名与备份范围保存在 MXU 配置的 `instances[].appBackup` 中：
+//! `{ "package": "com.example.game", "targets": ["sharedPrefs", "data"] }`。
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::Local;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use super::app_config::AppConfigState;
+use super::failure_capture::sanitize;
+use super::types::{ControllerConfig, MaaState};
+use super::utils::{adb_command, adb_shell, get_app_data_dir};
+use crate::i18n::tr;
+
+/// 备份说明文件名
+const MANIFEST_FILE: &str = "manifest.json";
+/// 需要 root 的目录在设备上的中转位置（shell 用户可读写）
+const STAGING_DIR: &str = "/data/local/tmp/mxu_backup";
+
+/// 备份范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AppBackupTarget {
+    /// `/data/data/<包名>/shared_prefs`（需要 root）
+    SharedPrefs,
+    /// `/sdcard/Android/data/<包名>`
+    Data,
+    /// `/sdcard/Android/obb/<包名>`
+    Obb,
+}
+
+impl AppBackupTarget {
+    /// 备份目录下的子目录名
+    fn dir_name(self) -> &'static str {
+        match self {
+            Self::SharedPrefs => "sharedPrefs",
+            Self::Data => "data",
+            Self::Obb => "obb",
+        }
+    }
+}
+
+/// 实例的备份配置（`instances[].appBackup`）
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AppBackupConfig {
+    package: Option<String>,
+    #[serde(default)]
+    targets: Vec<AppBackupTarget>,
+}
+
+/// 一次备份
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppBackupInfo {
+    /// 备份 ID（时间戳目录名）
+    pub id: String,
+    pub package: String,
+    /// 成功备份的范围
+    pub targets: Vec<AppBackupTarget>,
+    /// 备份失败的范围及原因（无权限、目录不存在等）
+    #[serde(default)]
+    pub failures: Vec<(AppBackupTarget, String)>,
+    pub created_at: String,
+    #[serde(skip_deserializing)]
+    pub path: String,
+}
+
+fn backups_dir(instance_id: &str) -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?
+        .join("backups")
+        .join(sanitize(instance_id)))
+}
+
+/// 包名仅允许字母、数字、`.` 与 `_`（会拼接进设备端 shell 命令）
+fn validate_package(package: &str) -> Result<(), String> {
+    let valid = !package.is_empty()
+        && package
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(tr!("error.backup.invalid_package", package = package))
+    }
+}
+
+fn adb_target(state: &MaaState, instance_id: &str) -> Result<(String, String), String> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(|| tr!("error.instance_not_found"))?;
+    match instance.controller_config.as_ref() {
+        Some(ControllerConfig::Adb {
+            adb_path, address, ..
+        }) => Ok((adb_path.clone(), address.clone())),
+        _ => Err(tr!("error.backup.not_adb")),
+    }
+}
+
+fn instance_backup_config(app_config: &AppConfigState, instance_id: &str) -> AppBackupConfig {
+    let config = app_config.config.lock();
+    config
+        .get("instances")
+        .and_then(|v| v.as_array())
+        .and_then(|list| {
+            list.iter()
+                .find(|inst| inst.get("id").and_then(|v| v.as_str()) == Some(instance_id))
+        })
+        .and_then(|inst| inst.get("appBackup"))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// 备份单个范围到 `dest`（`dest/<远端目录名>`）
+fn backup_target(
+    adb_path: &str,
+    address: &str,
+    package: &str,
+    target: AppBackupTarget,
+    dest: &Path,
+) -> Result<(), String> {
+    std::fs::create_dir_all(dest)
+        .map_err(|e| tr!("error.fs.create_dir", path = dest.display(), error = e))?;
+    let dest = dest.to_string_lossy();
+    match target {
+        AppBackupTarget::Data | AppBackupTarget::Obb => {
+            let remote = format!("/sdcard/Android/{}/{}", target.dir_name(), package);
+            adb_command(adb_path, address, &["pull", &remote, &dest])?;
+        }
+        AppBackupTarget::SharedPrefs => {
+            let staging = format!("{}/shared_prefs", STAGING_DIR);
+            let script = format!(
+                "su -c 'rm -rf {staging} && mkdir -p {dir} && cp -r /data/data/{package}/shared_prefs {dir}/ && chmod -R a+rX {dir}'",
+                staging = staging,
+                dir = STAGING_DIR,
+                package = package,
+            );
+            let result = adb_shell(adb_path, address, &[&script])
+                .and_then(|_| adb_command(adb_path, address, &["pull", &staging, &dest]));
+            let _ = adb_shell(
+                adb_path,
+                address,
+                &[&format!("su -c 'rm -rf {}'", STAGING_DIR)],
+            );
+            result?;
+        }
+    }
+    Ok(())
+}
+
+/// 从 `src`（备份时的 `dest`）恢复单个范围
+fn restore_target(
+    adb_path: &str,
+    address: &str,
+    package: &str,
+    target: AppBackupTarget,
+    src: &Path,
+) -> Result<(), String> {
+    match target {
+        AppBackupTarget::Data | AppBackupTarget::Obb => {
+            let local = src.join(package);
+            let parent = format!("/sdcard/Android/{}/", target.dir_name());
+            adb_command(
+                adb_path,
+                address,
+                &["push", &local.to_string_lossy(), &parent],
+            )?;
+        }
+        AppBackupTarget::SharedPrefs => {
+            // 整体替换而非合并，备份之后新增的配置文件不应残留；复制后恢复属主与 SELinux 上下文
+            let local = src.join("shared_prefs");
+            adb_shell(adb_path, address, &["mkdir", "-p", STAGING_DIR])?;
+            let staging_parent = format!("{}/", STAGING_DIR);
+            let script = format!(
+                "su -c 'owner=$(stat -c %u:%g /data/data/{package}) && rm -rf /data/data/{package}/shared_prefs && cp -r {dir}/shared_prefs /data/data/{package}/ && chown -R $owner /data/data/{package}/shared_prefs && (restorecon -R /data/data/{package}/shared_prefs || true)'",
+                dir = STAGING_DIR,
+                package = package,
+            );
+            let result = adb_command(
+                adb_path,
+                address,
+                &["push", &local.to_string_lossy(), &staging_parent],
+            )
+            .and_then(|_| adb_shell(adb_path, address, &[&script]));
+            let _ = adb_shell(
+                adb_path,
+                address,
+                &[&format!("su -c 'rm -rf {}'", STAGING_DIR)],
+            );
+            result?;
+        }
+    }
+    Ok(())
+}
+
+/// 备份目标应用数据（`package` / `targets` 缺省时使用实例配置，范围缺省为 `data`）
+pub fn backup_app_data_impl(
+    maa_state: &MaaState,
+    app_config: &AppConfigState,
+    instance_id: &str,
+    package: Option<String>,
+    targets: Option<Vec<AppBackupTarget>>,
+) -> Result<AppBackupInfo, String> {
+    let config = instance_backup_config(app_config, instance_id);
+    let package = package
+        .or(config.package)
+        .ok_or_else(|| tr!("error.backup.no_package"))?;
+    validate_package(&package)?;
+    let mut targets = targets.unwrap_or(config.targets);
+    if targets.is_empty() {
+        targets.push(AppBackupTarget::Data);
+    }
+    let mut seen = Vec::new();
+    targets.retain(|t| {
+        let first = !seen.contains(t);
+        seen.push(*t);
+        first
+    });
+    let (adb_path, address) = adb_target(maa_state, instance_id)?;
+
+    // 精确到毫秒，避免同一秒内的两次备份写入同一目录
+    let now = Local::now();
+    let id = now.format("%Y%m%d-%H%M%S-%3f").to_string();
+    let dir = backups_dir(instance_id)?.join(&id);
+    if dir.exists() {
+        return Err(tr!("error.backup.exists", path = dir.display()));
+    }
+    let mut info = AppBackupInfo {
+        id,
+        package: package.clone(),
+        targets: Vec::new(),
+        failures: Vec::new(),
+        created_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        path: dir.to_string_lossy().to_string(),
+    };
+    for target in targets {
+        let dest = dir.join(target.dir_name());
+        match backup_target(&adb_path, &address, &package, target, &dest) {
+            Ok(()) => info.targets.push(target),
+            Err(e) => {
+                warn!("Failed to back up {:?} of {}: {}", target, package, e);
+                let _ = std::fs::remove_dir_all(&dest);
+                info.failures.push((target, e));
+            }
+        }
+    }
+    if info.targets.is_empty() {
+        let _ = std::fs::remove_dir_all(&dir);
+        let reasons: Vec<String> = info.failures.iter().map(|(_, e)| e.clone()).collect();
+        return Err(tr!("error.backup.failed", reasons = reasons.join("; ")));
+    }
+
+    let manifest = serde_json::to_string_pretty(&info).map_err(|e| e.to_string())?;
+    let manifest_path = dir.join(MANIFEST_FILE);
+    std::fs::write(&manifest_path, manifest).map_err(|e| {
+        tr!(
+            "error.fs.write_file",
+            path = manifest_path.display(),
+            error = e
+        )
+    })?;
+    info!(
+        "Backed up {:?} of {} for instance {} to {}",
+        info.targets, package, instance_id, info.path
+    );
+    Ok(info)
+}
+
+/// 列出实例的备份（按时间倒序）
+pub fn list_app_backups_impl(instance_id: &str) -> Result<Vec<AppBackupInfo>, String> {
+    let dir = backups_dir(instance_id)?;
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+    let mut backups: Vec<AppBackupInfo> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let content = std::fs::read_to_string(path.join(MANIFEST_FILE)).ok()?;
+            let mut info: AppBackupInfo = serde_json::from_str(&content).ok()?;
+            info.path = path.to_string_lossy().to_string();
+            Some(info)
+        })
+        .collect();
+    backups.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(backups)
+}
+
+/// 将备份恢复到设备（恢复前强制停止目标应用）
+pub fn restore_app_data_impl(
+    maa_state: &MaaState,
+    instance_id: &str,
+    backup_id: &str,
+) -> Result<AppBackupInfo, String> {
+    if backup_id.is_empty() || sanitize(backup_id) != backup_id {
+        return Err(tr!("error.backup.invalid_id", id = backup_id));
+    }
+    if maa_state
+        .instances
+        .get(instance_id)
+        .and_then(|inst| inst.tasker.as_ref().map(|t| t.running()))
+        .unwrap_or(false)
+    {
+        return Err(tr!("error.backup.tasker_running"));
+    }
+    let (adb_path, address) = adb_target(maa_state, instance_id)?;
+
+    let dir = backups_dir(instance_id)?.join(backup_id);
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let content = std::fs::read_to_string(&manifest_path).map_err(|e| {
+        tr!(
+            "error.fs.read_file",
+            path = manifest_path.display(),
+            error = e
+        )
+    })?;
+    let mut info: AppBackupInfo = serde_json::from_str(&content)
+        .map_err(|e| tr!("error.backup.invalid_manifest", error = e))?;
+    info.path = dir.to_string_lossy().to_string();
+    validate_package(&info.package)?;
+
+    adb_shell(&adb_path, &address, &["am", "force-stop", &info.package])?;
+    for target in &info.targets {
+        restore_target(
+            &adb_path,
+            &address,
+            &info.package,
+            *target,
+            &dir.join(target.dir_name()),
+        )
+        .map_err(|e| {
+            tr!(
+                "error.backup.restore_failed",
+                target = target.dir_name(),
+                error = e
+            )
+        })?;
+    }
+    info!(
+        "Restored backup {} ({}) for instance {}",
+        backup_id, info.package, instance_id
+    );
+    Ok(info)
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 备份目标应用数据（`package` / `targets` 缺省时使用实例的 `appBackup` 配置）
+#[tauri::command]
+pub async fn maa_backup_app_data(
+    state: State<'_, Arc<MaaState>>,
+    app_config: State<'_, Arc<AppConfigState>>,
+    instance_id: String,
+    package: Option<String>,
+    targets: Option<Vec<AppBackupTarget>>,
+) -> Result<AppBackupInfo, String> {
+    let state = state.inner().clone();
+    let app_config = app_config.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        backup_app_data_impl(&state, &app_config, &instance_id, package, targets)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 列出实例的应用数据备份
+#[tauri::command]
+pub fn maa_list_app_backups(instance_id: String) -> Result<Vec<AppBackupInfo>, String> {
+    list_app_backups_impl(&instance_id)
+}
+
+/// 将应用数据备份恢复到设备
+#[tauri::command]
+pub async fn maa_restore_app_data(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    backup_id: String,
+) -> Result<AppBackupInfo, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || restore_app_data_impl(&state, &instance_id, &backup_id))
+        .await
+        .map_err(|e| e.to_string())?
+}
@@ -3,11 +3,14 @@
 //! 为 HTTP 服务器提供 interface.json 和配置文件的内存缓存，
 //! 与现有 MaaState 并列，由 `app.manage()` 注入。
 
+use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use tauri::State;
 
+use crate::i18n::tr;
+
 /// 应用配置状态（供 HTTP server 使用）
 #[derive(Default)]
 pub struct AppConfigState {
@@ -70,26 +73,26 @@ impl AppConfigState {
         // 加载翻译文件
         let translations = load_translations(&interface, exe_dir);
 
-        *self.project_interface.lock().unwrap() = Some(interface);
-        *self.translations.lock().unwrap() = translations;
-        *self.project_name.lock().unwrap() = project_name;
-        *self.base_path.lock().unwrap() = exe_dir.to_string_lossy().to_string();
+        *self.project_interface.lock() = Some(interface);
+        *self.translations.lock() = translations;
+        *self.project_name.lock() = project_name;
+        *self.base_path.lock() = exe_dir.to_string_lossy().to_string();
     }
 
     /// 从数据目录加载配置文件，写入内存
     pub fn load_config(&self, data_dir: &Path) {
-        *self.data_path.lock().unwrap() = data_dir.to_string_lossy().to_string();
+        *self.data_path.lock() = data_dir.to_string_lossy().to_string();
 
-        let project_name = self.project_name.lock().unwrap().clone();
+        let project_name = self.project_name.lock().clone();
         let config_filename = make_config_filename(project_name.as_deref());
         let config_path = data_dir.join("config").join(&config_filename);
 
         if config_path.exists() {
-            match std::fs::read_to_string(&config_path) {
+            match super::config_crypto::read_to_string(&config_path) {
                 Ok(content) => match serde_json::from_str::<serde_json::Value>(&content) {
                     Ok(config) => {
                         log::info!("AppConfigState: config loaded from {:?}", config_path);
-                        *self.config.lock().unwrap() = config;
+                        *self.config.lock() = config;
                         return;
                     }
                     Err(e) => {
@@ -108,7 +111,7 @@ impl AppConfigState {
         }
 
         // 默认配置（第一次使用时）
-        *self.config.lock().unwrap() = serde_json::json!({
+        *self.config.lock() = serde_json::json!({
             "version": "1.0",
             "instances": [],
             "settings": {
@@ -118,19 +121,30 @@ impl AppConfigState {
         });
     }
 
-    /// 保存配置到磁盘并更新内存
+    /// 保存配置到磁盘并更新内存（桌面端后端命令调用）
     pub fn save_config(&self, config: serde_json::Value) -> Result<(), String> {
-        let data_path = self.data_path.lock().unwrap().clone();
+        self.save_config_from(config, "desktop")
+    }
+
+    /// 保存配置到磁盘并更新内存，`source` 记入配置变更审计日志
+    pub fn save_config_from(&self, config: serde_json::Value, source: &str) -> Result<(), String> {
+        let data_path = self.data_path.lock().clone();
         if data_path.is_empty() {
-            return Err("数据路径未初始化".to_string());
+            return Err(tr!("error.config.data_path_unset"));
         }
 
-        let project_name = self.project_name.lock().unwrap().clone();
+        let project_name = self.project_name.lock().clone();
         let config_filename = make_config_filename(project_name.as_deref());
         let config_dir = Path::new(&data_path).join("config");
 
         if !config_dir.exists() {
-            std::fs::create_dir_all(&config_dir).map_err(|e| format!("创建配置目录失败: {}", e))?;
+            std::fs::create_dir_all(&config_dir).map_err(|e| {
+                tr!(
+                    "error.fs.create_dir",
+                    path = config_dir.display(),
+                    error = e
+                )
+            })?;
         }
 
         let config_path = config_dir.join(&config_filename);
@@ -143,7 +157,7 @@ impl AppConfigState {
             .unwrap_or(true);
 
         if new_instances_empty && config_path.exists() {
-            if let Ok(existing_content) = std::fs::read_to_string(&config_path) {
+            if let Ok(existing_content) = super::config_crypto::read_to_string(&config_path) {
                 if let Ok(existing) = serde_json::from_str::<serde_json::Value>(&existing_content) {
                     let existing_non_empty = existing
                         .get("instances")
@@ -154,32 +168,44 @@ impl AppConfigState {
                         log::error!(
                             "AppConfigState: refusing to overwrite non-empty config with empty instances"
                         );
-                        return Err("拒绝用空实例列表覆盖已有配置".to_string());
+                        return Err(tr!("error.config.empty_instances"));
                     }
                 }
             }
         }
 
         let content =
-            serde_json::to_string_pretty(&config).map_err(|e| format!("序列化配置失败: {}", e))?;
+            serde_json::to_string_pretty(&config).map_err(|e| tr!("error.serialize", error = e))?;
 
         // 原子写：先写到 .tmp，再 rename 覆盖正式文件。
         // 与前端 configService.ts 保持一致，避免进程在写入中途被杀
         // （如自动更新触发的 Tauri relaunch）时把配置文件截断为 0 字节。
-        // std::fs::rename 在 Windows 上走 MoveFileExW(MOVEFILE_REPLACE_EXISTING)，
-        // 在 Unix 上是原子的 rename(2)，同文件系统内可保证原子替换。
-        let tmp_path = config_path.with_extension("json.tmp");
-        std::fs::write(&tmp_path, content).map_err(|e| {
-            // 清理半成品 .tmp，避免遗留
-            let _ = std::fs::remove_file(&tmp_path);
-            format!("写入临时配置文件失败: {}", e)
+        // 开启配置加密时写入密文（见 config_crypto）。
+        super::config_crypto::write_string(&config_path, &content).map_err(|e| {
+            tr!(
+                "error.fs.write_file",
+                path = config_path.display(),
+                error = e
+            )
         })?;
-        if let Err(e) = std::fs::rename(&tmp_path, &config_path) {
-            let _ = std::fs::remove_file(&tmp_path);
-            return Err(format!("重命名配置文件失败: {}", e));
-        }
 
-        *self.config.lock().unwrap() = config;
+        super::download::init_from_config(&config);
+        super::screenshot_history::init_from_config(&config);
+        super::capture_resolution::init_from_config(&config);
+        super::adb_keep_awake::init_from_config(&config);
+        super::device_health::init_from_config(&config);
+        super::reconnect::init_from_config(&config);
+        super::elevation::init_from_config(&config);
+        super::maintenance::init_from_config(&config);
+        super::server_reset::init_from_config(&config);
+        super::clock_drift::init_from_config(&config);
+        super::integrity::init_from_config(&config);
+        super::path_compat::init_from_config(&config);
+        {
+            let mut current = self.config.lock();
+            super::audit_log::record_change(source, &current, &config);
+            *current = config;
+        }
         log::debug!("AppConfigState: config saved to {:?}", config_path);
         Ok(())
     }
@@ -200,7 +226,23 @@ pub fn notify_config_changed(
     state: State<Arc<AppConfigState>>,
     config: serde_json::Value,
 ) -> Result<(), String> {
-    *state.config.lock().map_err(|e| e.to_string())? = config;
+    super::download::init_from_config(&config);
+    super::screenshot_history::init_from_config(&config);
+    super::capture_resolution::init_from_config(&config);
+    super::adb_keep_awake::init_from_config(&config);
+    super::device_health::init_from_config(&config);
+    super::reconnect::init_from_config(&config);
+    super::elevation::init_from_config(&config);
+    super::maintenance::init_from_config(&config);
+    super::server_reset::init_from_config(&config);
+    super::clock_drift::init_from_config(&config);
+    super::integrity::init_from_config(&config);
+    super::path_compat::init_from_config(&config);
+    {
+        let mut current = state.config.lock();
+        super::audit_log::record_change("desktop", &current, &config);
+        *current = config;
+    }
 
     super::utils::emit_config_changed(&app);
 
@@ -211,7 +253,7 @@ pub fn notify_config_changed(
 // 内部辅助函数
 // ============================================================================
 
-fn make_config_filename(project_name: Option<&str>) -> String {
+pub(crate) fn make_config_filename(project_name: Option<&str>) -> String {
     match project_name {
         Some(name) => {
             let sanitized: String = name
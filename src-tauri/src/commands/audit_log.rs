@@ -0,0 +1,256 @@
+//! 配置变更审计日志
+//!
+//! 每次保存配置时对比新旧配置，将变更（时间、系统用户、来源、变更的字段与前后值）
+//! 追加写入 `<数据目录>/audit/audit.jsonl`（只追加，不改写）。多人共用一台挂机电脑时
+//! 可用 `mxu_get_audit_log` 查看"谁在什么时候改了什么"。
+//!
+//! - 字段以 JSON Pointer 表示；带 `id` 的数组元素（实例、定时策略等）按 id 而非下标定位，
+//!   避免调整顺序产生大量变更
+//! - 敏感字段（与配置导出剥离的字段相同）只记录"已修改"，不记录值
+//! - 窗口尺寸、面板展开状态等界面状态不记录
+//! - 开启配置加密时每行单独加密（见 [`config_crypto`](super::config_crypto)）
+
+use std::io::BufRead;
+use std::path::PathBuf;
+
+use chrono::Local;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+const AUDIT_FILE: &str = "audit.jsonl";
+/// 单条记录最多保留的变更数
+const MAX_CHANGES_PER_ENTRY: usize = 200;
+/// 敏感字段的占位值
+const MASKED: &str = "***";
+
+/// 不记录的界面状态字段（前缀匹配）
+const IGNORED_POINTERS: &[&str] = &[
+    "/lastActiveInstanceId",
+    "/recentlyClosed",
+    "/interfaceTaskSnapshot",
+    "/newTaskNames",
+    "/presetInitialized",
+    "/settings/windowSize",
+    "/settings/windowPosition",
+    "/settings/sidePanelExpanded",
+    "/settings/connectionPanelExpanded",
+    "/settings/screenshotPanelExpanded",
+    "/settings/rightPanelWidth",
+    "/settings/rightPanelCollapsed",
+    "/settings/addTaskPanelHeight",
+];
+
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+/// 单个字段的变更
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditChange {
+    pub pointer: String,
+    /// profile / schedule / secret / settings / other
+    pub category: String,
+    /// 变更前的值（新增时为 null）
+    pub before: Value,
+    /// 变更后的值（删除时为 null）
+    pub after: Value,
+}
+
+/// 一次配置保存的审计记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub time: String,
+    /// 操作系统用户名
+    pub user: String,
+    /// 变更来源（desktop / web）
+    pub source: String,
+    pub changes: Vec<AuditChange>,
+    /// 变更过多时被截断
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// 审计日志文件路径
+pub fn audit_path() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join("audit").join(AUDIT_FILE))
+}
+
+fn current_user() -> String {
+    std::env::var("USERNAME")
+        .or_else(|_| std::env::var("USER"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// JSON Pointer 片段转义
+fn escape_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+fn is_ignored(pointer: &str) -> bool {
+    IGNORED_POINTERS
+        .iter()
+        .any(|p| pointer == *p || pointer.starts_with(&format!("{}/", p)))
+}
+
+fn is_secret(pointer: &str) -> bool {
+    super::config_transfer::SECRET_POINTERS.contains(&pointer)
+}
+
+fn category(pointer: &str) -> &'static str {
+    if is_secret(pointer) {
+        "secret"
+    } else if pointer.contains("/schedulePolicies") {
+        "schedule"
+    } else if pointer.starts_with("/instances") {
+        "profile"
+    } else if pointer.starts_with("/settings") {
+        "settings"
+    } else {
+        "other"
+    }
+}
+
+/// 数组元素均带字符串 `id` 时按 id 建立索引
+fn keyed_by_id(items: &[Value]) -> Option<Vec<(&str, &Value)>> {
+    items
+        .iter()
+        .map(|item| Some((item.get("id")?.as_str()?, item)))
+        .collect()
+}
+
+fn push_change(changes: &mut Vec<AuditChange>, pointer: String, before: &Value, after: &Value) {
+    if is_ignored(&pointer) {
+        return;
+    }
+    let (before, after) = if is_secret(&pointer) {
+        let mask = |v: &Value| {
+            if v.is_null() {
+                Value::Null
+            } else {
+                Value::String(MASKED.to_string())
+            }
+        };
+        (mask(before), mask(after))
+    } else {
+        (before.clone(), after.clone())
+    };
+    changes.push(AuditChange {
+        category: category(&pointer).to_string(),
+        pointer,
+        before,
+        after,
+    });
+}
+
+/// 递归对比两个 JSON 值，收集叶子级别的变更
+fn diff(pointer: &str, before: &Value, after: &Value, changes: &mut Vec<AuditChange>) {
+    if before == after || is_ignored(pointer) {
+        return;
+    }
+    match (before, after) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, old) in a {
+                let path = format!("{}/{}", pointer, escape_segment(key));
+                diff(&path, old, b.get(key).unwrap_or(&Value::Null), changes);
+            }
+            for (key, new) in b.iter().filter(|(k, _)| !a.contains_key(*k)) {
+                let path = format!("{}/{}", pointer, escape_segment(key));
+                diff(&path, &Value::Null, new, changes);
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => match (keyed_by_id(a), keyed_by_id(b)) {
+            (Some(a), Some(b)) => {
+                for (id, old) in &a {
+                    let path = format!("{}/{}", pointer, escape_segment(id));
+                    let new = b.iter().find(|(k, _)| k == id).map(|(_, v)| *v);
+                    diff(&path, old, new.unwrap_or(&Value::Null), changes);
+                }
+                for (id, new) in b.iter().filter(|(k, _)| !a.iter().any(|(o, _)| o == k)) {
+                    let path = format!("{}/{}", pointer, escape_segment(id));
+                    diff(&path, &Value::Null, new, changes);
+                }
+            }
+            _ => push_change(changes, pointer.to_string(), before, after),
+        },
+        _ => push_change(changes, pointer.to_string(), before, after),
+    }
+}
+
+/// 对比新旧配置并追加审计记录（无有效变更时不写入）
+pub fn record_change(source: &str, before: &Value, after: &Value) {
+    let mut changes = Vec::new();
+    diff("", before, after, &mut changes);
+    if changes.is_empty() {
+        return;
+    }
+    let truncated = changes.len() > MAX_CHANGES_PER_ENTRY;
+    changes.truncate(MAX_CHANGES_PER_ENTRY);
+    let entry = AuditEntry {
+        time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        user: current_user(),
+        source: source.to_string(),
+        changes,
+        truncated,
+    };
+    if let Err(e) = append(&entry) {
+        warn!("Failed to write audit log: {}", e);
+    } else {
+        info!(
+            "Audit: {} change(s) from {} by {}",
+            entry.changes.len(),
+            entry.source,
+            entry.user
+        );
+    }
+}
+
+fn append(entry: &AuditEntry) -> Result<(), String> {
+    let line = serde_json::to_string(entry).map_err(|e| tr!("error.serialize", error = e))?;
+    let path = audit_path()?;
+    let _guard = WRITE_LOCK.lock();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+    }
+    super::config_crypto::append_line(&path, &line)
+}
+
+/// 读取最近的审计记录（新的在前）
+pub fn read_entries(limit: usize) -> Result<Vec<AuditEntry>, String> {
+    let path = audit_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = std::fs::File::open(&path)
+        .map_err(|e| tr!("error.fs.read_file", path = path.display(), error = e))?;
+    let mut entries: Vec<AuditEntry> = std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match super::config_crypto::decrypt_line(&line) {
+            Ok(line) => serde_json::from_str(&line).ok(),
+            Err(e) => {
+                warn!("Skipping unreadable audit entry: {}", e);
+                None
+            }
+        })
+        .collect();
+    entries.reverse();
+    entries.truncate(limit);
+    Ok(entries)
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取最近的配置变更审计记录（新的在前，默认 100 条）
+#[tauri::command]
+pub fn mxu_get_audit_log(limit: Option<usize>) -> Result<Vec<AuditEntry>, String> {
+    read_entries(limit.unwrap_or(100))
+}
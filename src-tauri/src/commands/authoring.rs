@@ -0,0 +1,724 @@
+//! 资源编写辅助命令
+//!
+//! 基于实例当前缓存截图的小工具，便于在 MXU 内直接编写 pipeline 资源：
+//! - `maa_capture_template`: 从截图裁剪 ROI 保存为模板图片
+//! - `maa_inspect_pixels`: 读取指定坐标的 RGB / HSV 值（用于编写 ColorMatch 节点）
+//! - `maa_test_ocr`: 对当前截图执行一次 OCR 识别
+//! - `maa_test_template`: 对当前截图执行一次模板匹配
+//! - `mxu_diff_images`: 对比两张图片，输出差异分数与热力图（排查分辨率、HDR、夜间模式等导致的识别失败）
+//!
+//! 识别测试通过临时任务执行：入口节点挂载 `MXU_QUICK_RECO_ACTION`，在 Context 中
+//! 调用 `run_recognition` 对截图识别一次，结果通过 reco_id 从 tasker 读取。
+//! 识别测试与取色均可传入本地图片路径代替实时截图，便于针对保存的失败截图离线调试
+//! （识别测试仍需实例已加载资源并连接控制器，以便创建 tasker）。
+
+use std::collections::HashMap;
+use std::path::{Component, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use image::RgbaImage;
+use log::{info, warn};
+use maa_framework::buffer::MaaImageBuffer;
+use maa_framework::controller::Controller;
+use maa_framework::MaaStatus;
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tauri::{AppHandle, State};
+
+use super::error::{ErrorCode, MxuError, MxuResult};
+use super::types::MaaState;
+use super::utils::{get_app_data_dir, normalize_path};
+use crate::i18n::tr;
+
+/// ROI：`[x, y, width, height]`，与 pipeline 中的 roi 格式一致
+pub type Roi = [i32; 4];
+
+/// 读取实例的缓存截图并解码
+pub(crate) fn load_cached_frame(state: &MaaState, instance_id: &str) -> MxuResult<RgbaImage> {
+    let data = {
+        let instance = state
+            .instances
+            .get(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let controller = instance
+            .controller
+            .as_ref()
+            .ok_or_else(MxuError::controller_not_connected)?;
+        let buffer = controller.cached_image().map_err(|e| e.to_string())?;
+        buffer
+            .to_vec()
+            .ok_or_else(|| tr!("error.screenshot.convert_failed"))?
+    };
+    if data.is_empty() {
+        return Err(tr!("error.screenshot.no_data").into());
+    }
+    image::load_from_memory(&data)
+        .map(|img| img.to_rgba8())
+        .map_err(|e| tr!("error.authoring.decode_screenshot", error = e).into())
+}
+
+/// 读取并解码本地图片
+pub(crate) fn load_image_file(path: &std::path::Path) -> Result<RgbaImage, String> {
+    image::open(path).map(|img| img.to_rgba8()).map_err(|e| {
+        tr!(
+            "error.authoring.read_image",
+            path = path.display(),
+            error = e
+        )
+    })
+}
+
+/// 校验 ROI 是否在图像范围内
+pub(crate) fn check_roi(image: &RgbaImage, roi: Roi) -> Result<(u32, u32, u32, u32), String> {
+    let [x, y, w, h] = roi;
+    if x < 0 || y < 0 || w <= 0 || h <= 0 {
+        return Err(tr!(
+            "error.authoring.invalid_roi",
+            roi = format!("{:?}", roi)
+        ));
+    }
+    let (x, y, w, h) = (x as u32, y as u32, w as u32, h as u32);
+    if x + w > image.width() || y + h > image.height() {
+        return Err(tr!(
+            "error.authoring.roi_out_of_bounds",
+            roi = format!("{:?}", roi),
+            width = image.width(),
+            height = image.height()
+        ));
+    }
+    Ok((x, y, w, h))
+}
+
+/// 实例当前生效的资源目录（最后加载的资源包）
+fn active_resource_dir(state: &MaaState, instance_id: &str) -> MxuResult<PathBuf> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    instance
+        .resource_paths
+        .last()
+        .cloned()
+        .ok_or_else(MxuError::resource_not_loaded)
+}
+
+/// 校验模板名称：仅允许相对路径，不允许跳出 image 目录
+fn normalize_template_name(name: &str) -> Result<PathBuf, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(tr!("error.authoring.template_name_empty"));
+    }
+    let mut path = PathBuf::from(name.replace('\\', "/"));
+    if !path.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(tr!("error.authoring.invalid_template_name", name = name));
+    }
+    if path
+        .extension()
+        .is_none_or(|ext| !ext.eq_ignore_ascii_case("png"))
+    {
+        path.set_extension("png");
+    }
+    Ok(path)
+}
+
+/// 模板截取结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureTemplateResult {
+    /// 相对于资源 image 目录的路径（可直接填入 pipeline 的 template 字段）
+    pub relative_path: String,
+    /// 保存的完整路径
+    pub full_path: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// 截取模板的内部实现
+pub fn capture_template_impl(
+    state: &MaaState,
+    instance_id: &str,
+    roi: Roi,
+    name: &str,
+    overwrite: bool,
+) -> MxuResult<CaptureTemplateResult> {
+    let frame = load_cached_frame(state, instance_id)?;
+    let (x, y, w, h) = check_roi(&frame, roi)?;
+
+    let relative = normalize_template_name(name)?;
+    let image_dir = active_resource_dir(state, instance_id)?.join("image");
+    let full_path = image_dir.join(&relative);
+    if full_path.exists() && !overwrite {
+        return Err(tr!("error.authoring.template_exists", name = relative.display()).into());
+    }
+    if let Some(parent) = full_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+    }
+
+    let cropped = image::imageops::crop_imm(&frame, x, y, w, h).to_image();
+    cropped
+        .save_with_format(&full_path, image::ImageFormat::Png)
+        .map_err(|e| {
+            tr!(
+                "error.authoring.save_image",
+                path = full_path.display(),
+                error = e
+            )
+        })?;
+
+    let relative_path = relative.to_string_lossy().replace('\\', "/");
+    info!(
+        "capture_template: instance={}, roi={:?} -> {}",
+        instance_id,
+        roi,
+        full_path.display()
+    );
+    Ok(CaptureTemplateResult {
+        relative_path,
+        full_path: full_path.to_string_lossy().to_string(),
+        width: w,
+        height: h,
+    })
+}
+
+/// 像素取色结果
+#[derive(Debug, Clone, Serialize)]
+pub struct PixelInfo {
+    pub x: i32,
+    pub y: i32,
+    /// `[r, g, b]`
+    pub rgb: [u8; 3],
+    /// `[h, s, v]`，与 ColorMatch（OpenCV）一致：H 0-180，S/V 0-255
+    pub hsv: [u8; 3],
+}
+
+/// RGB → HSV（OpenCV 8 位约定）
+fn rgb_to_hsv([r, g, b]: [u8; 3]) -> [u8; 3] {
+    let (rf, gf, bf) = (r as f64, g as f64, b as f64);
+    let max = rf.max(gf).max(bf);
+    let min = rf.min(gf).min(bf);
+    let delta = max - min;
+
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == rf {
+        60.0 * (((gf - bf) / delta).rem_euclid(6.0))
+    } else if max == gf {
+        60.0 * ((bf - rf) / delta + 2.0)
+    } else {
+        60.0 * ((rf - gf) / delta + 4.0)
+    };
+    let s = if max == 0.0 { 0.0 } else { delta / max * 255.0 };
+
+    [(h / 2.0).round() as u8, s.round() as u8, max as u8]
+}
+
+/// 取色的内部实现
+pub fn inspect_pixels_impl(
+    state: &MaaState,
+    instance_id: &str,
+    points: &[[i32; 2]],
+    image_path: Option<&str>,
+) -> MxuResult<Vec<PixelInfo>> {
+    let frame = match image_path {
+        Some(path) => load_image_file(&normalize_path(path))?,
+        None => load_cached_frame(state, instance_id)?,
+    };
+    points
+        .iter()
+        .map(|&[x, y]| {
+            if x < 0 || y < 0 || x as u32 >= frame.width() || y as u32 >= frame.height() {
+                return Err(MxuError::new(
+                    ErrorCode::InvalidArgument,
+                    tr!(
+                        "error.authoring.point_out_of_bounds",
+                        x = x,
+                        y = y,
+                        width = frame.width(),
+                        height = frame.height()
+                    ),
+                ));
+            }
+            let [r, g, b, _] = frame.get_pixel(x as u32, y as u32).0;
+            Ok(PixelInfo {
+                x,
+                y,
+                rgb: [r, g, b],
+                hsv: rgb_to_hsv([r, g, b]),
+            })
+        })
+        .collect()
+}
+
+/// 读取缓存截图中指定坐标的 RGB / HSV 值
+///
+/// `points` 为 `[x, y]` 列表，坐标基于截图分辨率；`image_path` 不为空时读取本地图片
+#[tauri::command]
+pub fn maa_inspect_pixels(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    points: Vec<[i32; 2]>,
+    image_path: Option<String>,
+) -> MxuResult<Vec<PixelInfo>> {
+    inspect_pixels_impl(&state, &instance_id, &points, image_path.as_deref())
+}
+
+// ============================================================================
+// 识别测试
+// ============================================================================
+
+/// 识别测试的临时入口节点（任务回调中据此忽略，不计入任务运行状态）
+pub(crate) const QUICK_TEST_ENTRY: &str = "MXU_QuickTest";
+
+/// 被测试的识别节点
+const QUICK_TEST_TARGET: &str = "MXU_QuickTest_Target";
+
+/// 识别测试超时
+const QUICK_TEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 识别所用图像来源
+#[derive(Clone)]
+enum ImageSource {
+    /// 控制器的缓存截图
+    Controller(Controller),
+    /// 本地图片文件（png / jpg / bmp）
+    File(PathBuf),
+}
+
+/// 待 custom action 执行的识别请求
+struct QuickRecoRequest {
+    /// 被测试节点的 pipeline 定义
+    node: Value,
+    /// 图像来源
+    image: ImageSource,
+    /// custom action 写回的 reco_id
+    reco_id: Option<Result<i64, String>>,
+}
+
+static QUICK_RECO_REQUESTS: Mutex<Option<HashMap<u64, QuickRecoRequest>>> = Mutex::new(None);
+static QUICK_RECO_NEXT_TOKEN: AtomicU64 = AtomicU64::new(1);
+
+/// 识别测试中的单个候选结果
+#[derive(Debug, Clone, Serialize)]
+pub struct RecognitionCandidate {
+    /// `[x, y, width, height]`
+    #[serde(rename = "box")]
+    pub box_rect: Roi,
+    pub score: Option<f64>,
+    /// OCR 识别文本
+    pub text: Option<String>,
+}
+
+/// 识别测试结果
+#[derive(Debug, Clone, Serialize)]
+pub struct QuickRecognitionResult {
+    /// 是否命中（满足阈值 / expected 等过滤条件）
+    pub hit: bool,
+    /// 最佳结果
+    pub best: Option<RecognitionCandidate>,
+    /// 满足过滤条件的结果
+    pub filtered: Vec<RecognitionCandidate>,
+    /// 全部候选结果
+    pub all: Vec<RecognitionCandidate>,
+    pub cost_ms: u64,
+}
+
+fn parse_candidate(value: &Value) -> Option<RecognitionCandidate> {
+    let b = value.get("box")?.as_array()?;
+    if b.len() != 4 {
+        return None;
+    }
+    let mut box_rect = [0; 4];
+    for (slot, v) in box_rect.iter_mut().zip(b) {
+        *slot = v.as_i64()? as i32;
+    }
+    Some(RecognitionCandidate {
+        box_rect,
+        score: value.get("score").and_then(|v| v.as_f64()),
+        text: value
+            .get("text")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+    })
+}
+
+fn parse_candidates(detail: &Value, key: &str) -> Vec<RecognitionCandidate> {
+    detail
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|list| list.iter().filter_map(parse_candidate).collect())
+        .unwrap_or_default()
+}
+
+/// 在 custom action 中执行识别请求（由 `MXU_QUICK_RECO_ACTION` 调用）
+pub(crate) fn run_quick_recognition_in_context(
+    ctx: &maa_framework::context::Context,
+    param: &str,
+) -> bool {
+    let Some(token) = serde_json::from_str::<Value>(param)
+        .ok()
+        .and_then(|v| v.get("token").and_then(|t| t.as_u64()))
+    else {
+        warn!("[MXU_QUICK_RECO] Missing token in param: {}", param);
+        return false;
+    };
+
+    let (node, image) = {
+        let guard = QUICK_RECO_REQUESTS.lock();
+        let Some(request) = guard.as_ref().and_then(|m| m.get(&token)) else {
+            warn!("[MXU_QUICK_RECO] Unknown token: {}", token);
+            return false;
+        };
+        (request.node.clone(), request.image.clone())
+    };
+
+    let result = (|| {
+        let image = match image {
+            ImageSource::Controller(controller) => {
+                controller.cached_image().map_err(|e| e.to_string())?
+            }
+            ImageSource::File(path) => {
+                let data = std::fs::read(&path).map_err(|e| {
+                    tr!(
+                        "error.authoring.read_image",
+                        path = path.display(),
+                        error = e
+                    )
+                })?;
+                let mut buffer = MaaImageBuffer::new().map_err(|e| e.to_string())?;
+                buffer.set_encoded(&data).map_err(|e| e.to_string())?;
+                buffer
+            }
+        };
+        let pipeline_override = json!({ QUICK_TEST_TARGET: node }).to_string();
+        ctx.run_recognition(QUICK_TEST_TARGET, &pipeline_override, &image)
+            .map_err(|e| e.to_string())
+    })();
+
+    if let Some(request) = QUICK_RECO_REQUESTS
+        .lock()
+        .as_mut()
+        .and_then(|m| m.get_mut(&token))
+    {
+        request.reco_id = Some(result.clone());
+    }
+    result.is_ok()
+}
+
+/// 以临时任务执行一次识别，返回识别详情
+pub fn run_quick_recognition(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    node: Value,
+    image_path: Option<PathBuf>,
+) -> MxuResult<QuickRecognitionResult> {
+    let started = Instant::now();
+    if let Some(path) = &image_path {
+        if !path.is_file() {
+            return Err(tr!("error.fs.not_found", path = path.display()).into());
+        }
+    }
+    let (tasker, image) = {
+        let mut guard = state
+            .instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
+        let tasker = super::maa_core::ensure_tasker(app, state, instance_id, instance)?;
+        if tasker.running() {
+            return Err(tr!("error.authoring.tasker_running").into());
+        }
+        let image = match image_path {
+            Some(path) => ImageSource::File(path),
+            None => ImageSource::Controller(
+                instance
+                    .controller
+                    .clone()
+                    .ok_or_else(MxuError::controller_not_connected)?,
+            ),
+        };
+        (tasker, image)
+    };
+
+    let token = QUICK_RECO_NEXT_TOKEN.fetch_add(1, Ordering::SeqCst);
+    QUICK_RECO_REQUESTS
+        .lock()
+        .get_or_insert_with(HashMap::new)
+        .insert(
+            token,
+            QuickRecoRequest {
+                node,
+                image,
+                reco_id: None,
+            },
+        );
+
+    let result = (|| -> Result<QuickRecognitionResult, String> {
+        let pipeline_override = json!({
+            QUICK_TEST_ENTRY: {
+                "action": "Custom",
+                "custom_action": crate::mxu_actions::MXU_QUICK_RECO_ACTION,
+                "custom_action_param": { "token": token },
+            }
+        })
+        .to_string();
+        let job = tasker
+            .post_task(QUICK_TEST_ENTRY, &pipeline_override)
+            .map_err(|e| e.to_string())?;
+
+        let deadline = Instant::now() + QUICK_TEST_TIMEOUT;
+        loop {
+            let status = tasker
+                .get_task_detail(job.id)
+                .map_err(|e| e.to_string())?
+                .map(|d| d.status)
+                .unwrap_or(MaaStatus::INVALID);
+            if status != MaaStatus::PENDING && status != MaaStatus::RUNNING {
+                break;
+            }
+            if Instant::now() >= deadline {
+                return Err(tr!("error.authoring.recognition_timeout"));
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        let reco_id = QUICK_RECO_REQUESTS
+            .lock()
+            .as_mut()
+            .and_then(|m| m.get_mut(&token))
+            .and_then(|r| r.reco_id.take())
+            .ok_or_else(|| tr!("error.authoring.reco_not_run"))??;
+
+        let detail = tasker
+            .get_recognition_detail(reco_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| tr!("error.authoring.reco_detail_missing"))?;
+        // detail 字段为算法输出的 JSON（含 all / filtered / best）
+        let detail_json: Value =
+            serde_json::from_str(&detail.detail.to_string()).unwrap_or(Value::Null);
+
+        Ok(QuickRecognitionResult {
+            hit: detail.hit,
+            best: detail_json.get("best").and_then(parse_candidate),
+            filtered: parse_candidates(&detail_json, "filtered"),
+            all: parse_candidates(&detail_json, "all"),
+            cost_ms: started.elapsed().as_millis() as u64,
+        })
+    })();
+
+    if let Some(map) = QUICK_RECO_REQUESTS.lock().as_mut() {
+        map.remove(&token);
+    }
+    result.map_err(MxuError::from)
+}
+
+/// 对当前截图执行一次 OCR 识别
+///
+/// `roi` 为空时识别全图；`expected` 为空时返回所有文本（此时 hit 表示识别到任意文本）；
+/// `image_path` 不为空时识别本地图片而非实时截图
+#[tauri::command]
+pub async fn maa_test_ocr(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    roi: Option<Roi>,
+    expected: Option<Vec<String>>,
+    image_path: Option<String>,
+) -> MxuResult<QuickRecognitionResult> {
+    let mut node = json!({ "recognition": "OCR" });
+    if let Some(roi) = roi {
+        node["roi"] = json!(roi);
+    }
+    if let Some(expected) = expected.filter(|e| !e.is_empty()) {
+        node["expected"] = json!(expected);
+    }
+    info!("maa_test_ocr: instance={}, node={}", instance_id, node);
+
+    let state = state.inner().clone();
+    let image_path = image_path.map(|p| normalize_path(&p));
+    tokio::task::spawn_blocking(move || {
+        run_quick_recognition(&app, &state, &instance_id, node, image_path)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 对当前截图执行一次模板匹配，返回所有候选框及分数
+///
+/// `template_path` 与 pipeline 的 template 字段一致（相对资源 image 目录）；
+/// `image_path` 不为空时识别本地图片而非实时截图
+#[tauri::command]
+pub async fn maa_test_template(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    template_path: String,
+    threshold: Option<f64>,
+    roi: Option<Roi>,
+    image_path: Option<String>,
+) -> MxuResult<QuickRecognitionResult> {
+    let mut node = json!({
+        "recognition": "TemplateMatch",
+        "template": [template_path],
+    });
+    if let Some(threshold) = threshold {
+        node["threshold"] = json!(threshold);
+    }
+    if let Some(roi) = roi {
+        node["roi"] = json!(roi);
+    }
+    info!("maa_test_template: instance={}, node={}", instance_id, node);
+
+    let state = state.inner().clone();
+    let image_path = image_path.map(|p| normalize_path(&p));
+    tokio::task::spawn_blocking(move || {
+        run_quick_recognition(&app, &state, &instance_id, node, image_path)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 从缓存截图裁剪 ROI，保存为当前资源 image 目录下的 PNG 模板
+///
+/// `name` 为相对 image 目录的文件名（可含子目录，缺省补 `.png`）
+#[tauri::command]
+pub fn maa_capture_template(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    roi: Roi,
+    name: String,
+    overwrite: Option<bool>,
+) -> MxuResult<CaptureTemplateResult> {
+    capture_template_impl(&state, &instance_id, roi, &name, overwrite.unwrap_or(false))
+}
+
+// ============================================================================
+// 图像对比
+// ============================================================================
+
+/// 默认的像素差异阈值（各通道最大差值超过该值视为不同）
+const DEFAULT_DIFF_THRESHOLD: u8 = 16;
+
+/// 图像对比结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDiffResult {
+    /// 对比使用的分辨率（以 image_a 为准）
+    pub width: u32,
+    pub height: u32,
+    /// 两张图分辨率不同，image_b 已缩放到 image_a 的尺寸
+    pub resized: bool,
+    /// image_b 的原始分辨率
+    pub original_b_size: [u32; 2],
+    /// 平均差异（0 完全相同，1 完全不同）
+    pub score: f64,
+    /// 差异超过阈值的像素占比
+    pub changed_ratio: f64,
+    /// 平均亮度差（image_b - image_a，-255 ~ 255），明显偏移通常意味着 HDR / 夜间模式 / 滤镜
+    pub brightness_delta: f64,
+    /// 热力图 PNG 路径（差异越大越红，未超过阈值的区域显示为暗化的 image_a）
+    pub heatmap_path: String,
+}
+
+fn luminance([r, g, b, _]: [u8; 4]) -> f64 {
+    0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64
+}
+
+/// 图像对比的内部实现
+pub fn diff_images_impl(
+    image_a: &str,
+    image_b: &str,
+    threshold: u8,
+) -> Result<ImageDiffResult, String> {
+    let a = load_image_file(&normalize_path(image_a))?;
+    let b_original = load_image_file(&normalize_path(image_b))?;
+    let (width, height) = a.dimensions();
+    let original_b_size = [b_original.width(), b_original.height()];
+    let resized = b_original.dimensions() != (width, height);
+    let b = if resized {
+        image::imageops::resize(
+            &b_original,
+            width,
+            height,
+            image::imageops::FilterType::Triangle,
+        )
+    } else {
+        b_original
+    };
+
+    let pixel_count = (width as u64 * height as u64).max(1) as f64;
+    let mut diff_sum = 0f64;
+    let mut changed = 0u64;
+    let mut brightness_sum = 0f64;
+    let mut heatmap = RgbaImage::new(width, height);
+
+    for (x, y, pa) in a.enumerate_pixels() {
+        let pb = b.get_pixel(x, y);
+        let d = (0..3).map(|c| pa.0[c].abs_diff(pb.0[c])).max().unwrap_or(0);
+        diff_sum += d as f64;
+        brightness_sum += luminance(pb.0) - luminance(pa.0);
+
+        let heat = if d > threshold {
+            changed += 1;
+            // 黄 -> 红：差异越大绿色分量越低
+            [255, 255 - d, 0, 255]
+        } else {
+            let gray = (luminance(pa.0) / 3.0) as u8;
+            [gray, gray, gray, 255]
+        };
+        heatmap.put_pixel(x, y, image::Rgba(heat));
+    }
+
+    let dir = get_app_data_dir()?.join("debug").join("diffs");
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| tr!("error.fs.create_dir", path = dir.display(), error = e))?;
+    let heatmap_path = dir.join(format!(
+        "{}.png",
+        chrono::Local::now().format("%Y%m%d-%H%M%S-%3f")
+    ));
+    heatmap.save(&heatmap_path).map_err(|e| {
+        tr!(
+            "error.authoring.save_image",
+            path = heatmap_path.display(),
+            error = e
+        )
+    })?;
+
+    let result = ImageDiffResult {
+        width,
+        height,
+        resized,
+        original_b_size,
+        score: diff_sum / pixel_count / 255.0,
+        changed_ratio: changed as f64 / pixel_count,
+        brightness_delta: brightness_sum / pixel_count,
+        heatmap_path: heatmap_path.to_string_lossy().to_string(),
+    };
+    info!(
+        "Image diff {} vs {}: score {:.4}, changed {:.2}%",
+        image_a,
+        image_b,
+        result.score,
+        result.changed_ratio * 100.0
+    );
+    Ok(result)
+}
+
+/// 对比两张本地图片，返回差异分数并生成热力图
+///
+/// `threshold` 为像素差异阈值（0-255，缺省 16）；分辨率不同时 image_b 缩放到 image_a 的尺寸
+#[tauri::command]
+pub async fn mxu_diff_images(
+    image_a: String,
+    image_b: String,
+    threshold: Option<u8>,
+) -> Result<ImageDiffResult, String> {
+    let threshold = threshold.unwrap_or(DEFAULT_DIFF_THRESHOLD);
+    tokio::task::spawn_blocking(move || diff_images_impl(&image_a, &image_b, threshold))
+        .await
+        .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,329 @@
+//! 缓存清理命令
+//!
+//! `purge_cache` 永久删除 `cache/old`（更新残留的旧文件）与 `debug` 下的日志文件，
+//! 可按最后修改时间只清理较旧的条目，并返回释放的空间。
+//!
+//! `mxu_cache_stats` 统计各类缓存占用；`settings.cachePrune` 配置的自动清理策略
+//! （总容量上限、最长保留天数）在应用启动时执行，清理的文件优先移入回收站。
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+/// 缓存清理结果
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeCacheResult {
+    /// 删除的文件数
+    pub deleted_files: u64,
+    /// 释放的字节数
+    pub freed_bytes: u64,
+    /// 删除失败的文件数（通常是被占用的 DLL 或当前日志）
+    pub failed: u64,
+}
+
+/// 文件是否早于截止时间；`cutoff` 为 None 时视为全部过期
+fn is_older_than(path: &Path, cutoff: Option<SystemTime>) -> bool {
+    let Some(cutoff) = cutoff else {
+        return true;
+    };
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(|mtime| mtime < cutoff)
+        .unwrap_or(false)
+}
+
+/// 删除文件并计数；`to_trash` 为 true 时优先移入回收站
+fn remove_counted(path: &Path, size: u64, to_trash: bool, result: &mut PurgeCacheResult) {
+    let removed = if to_trash {
+        super::file_ops::trash_or_remove(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+    match removed {
+        Ok(()) => {
+            result.deleted_files += 1;
+            result.freed_bytes += size;
+        }
+        Err(e) => {
+            result.failed += 1;
+            warn!("[cache] Failed to delete {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// 递归删除目录下早于截止时间的文件，并尝试移除清空后的子目录
+fn purge_dir(dir: &Path, cutoff: Option<SystemTime>, result: &mut PurgeCacheResult) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            purge_dir(&path, cutoff, result);
+            let _ = std::fs::remove_dir(&path);
+        } else if is_older_than(&path, cutoff) {
+            remove_counted(&path, metadata.len(), false, result);
+        }
+    }
+}
+
+/// 删除 debug 目录下（不含子目录）早于截止时间的 .log 文件
+fn purge_logs(debug_dir: &Path, cutoff: Option<SystemTime>, result: &mut PurgeCacheResult) {
+    let Ok(entries) = std::fs::read_dir(debug_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let is_log = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("log"));
+        if metadata.is_file() && is_log && is_older_than(&path, cutoff) {
+            remove_counted(&path, metadata.len(), false, result);
+        }
+    }
+}
+
+pub(crate) fn purge_cache_blocking(
+    older_than_days: Option<u32>,
+) -> Result<PurgeCacheResult, String> {
+    let data_dir = get_app_data_dir()?;
+    let cutoff = older_than_days
+        .map(|days| SystemTime::now() - Duration::from_secs(u64::from(days) * 24 * 60 * 60));
+
+    let mut result = PurgeCacheResult::default();
+    purge_dir(&data_dir.join("cache").join("old"), cutoff, &mut result);
+    purge_logs(&data_dir.join("debug"), cutoff, &mut result);
+
+    info!(
+        "[cache] Purged {} files ({} bytes), {} failed",
+        result.deleted_files, result.freed_bytes, result.failed
+    );
+    Ok(result)
+}
+
+// ============================================================================
+// 缓存统计与自动清理
+// ============================================================================
+
+/// 按容量清理时跳过最近修改的文件（可能是正在写入的日志或下载）
+const PRUNE_MIN_AGE: Duration = Duration::from_secs(10 * 60);
+
+/// 缓存分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CacheKind {
+    /// cache/old：更新残留的旧文件
+    Old,
+    /// debug/*.log
+    Logs,
+    /// cache 下的下载包（zip/tar.gz 及 .downloading 半成品）
+    Archives,
+    /// debug/on_error 与 debug/vision 下的调试图片
+    DebugImages,
+}
+
+struct CacheFile {
+    path: PathBuf,
+    size: u64,
+    mtime: SystemTime,
+}
+
+fn walk_files(
+    dir: &Path,
+    recursive: bool,
+    filter: &dyn Fn(&Path) -> bool,
+    out: &mut Vec<CacheFile>,
+) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if recursive {
+                walk_files(&path, true, filter, out);
+            }
+        } else if metadata.is_file() && filter(&path) {
+            out.push(CacheFile {
+                path,
+                size: metadata.len(),
+                mtime: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            });
+        }
+    }
+}
+
+fn is_archive(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    [".zip", ".tar.gz", ".tgz", ".downloading"]
+        .iter()
+        .any(|ext| name.ends_with(ext))
+}
+
+fn has_ext(path: &Path, exts: &[&str]) -> bool {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .is_some_and(|e| exts.contains(&e.as_str()))
+}
+
+fn collect_cache_files(data_dir: &Path, kind: CacheKind) -> Vec<CacheFile> {
+    let mut files = Vec::new();
+    let cache_dir = data_dir.join("cache");
+    let debug_dir = data_dir.join("debug");
+    match kind {
+        CacheKind::Old => walk_files(&cache_dir.join("old"), true, &|_| true, &mut files),
+        CacheKind::Logs => walk_files(&debug_dir, false, &|p| has_ext(p, &["log"]), &mut files),
+        CacheKind::Archives => walk_files(&cache_dir, false, &is_archive, &mut files),
+        CacheKind::DebugImages => {
+            let is_image = |p: &Path| has_ext(p, &["png", "jpg", "jpeg"]);
+            walk_files(&debug_dir.join("on_error"), true, &is_image, &mut files);
+            walk_files(&debug_dir.join("vision"), true, &is_image, &mut files);
+        }
+    }
+    files
+}
+
+/// 缓存占用统计（字节）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    pub old_bytes: u64,
+    pub log_bytes: u64,
+    pub archive_bytes: u64,
+    pub debug_image_bytes: u64,
+    pub total_bytes: u64,
+}
+
+fn cache_stats_blocking() -> Result<CacheStats, String> {
+    let data_dir = get_app_data_dir()?;
+    let size_of = |kind| {
+        collect_cache_files(&data_dir, kind)
+            .iter()
+            .map(|f| f.size)
+            .sum::<u64>()
+    };
+    let mut stats = CacheStats {
+        old_bytes: size_of(CacheKind::Old),
+        log_bytes: size_of(CacheKind::Logs),
+        archive_bytes: size_of(CacheKind::Archives),
+        debug_image_bytes: size_of(CacheKind::DebugImages),
+        total_bytes: 0,
+    };
+    stats.total_bytes =
+        stats.old_bytes + stats.log_bytes + stats.archive_bytes + stats.debug_image_bytes;
+    Ok(stats)
+}
+
+/// 自动清理策略（`settings.cachePrune`）
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachePrunePolicy {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 缓存总容量上限（MB），超出时从最旧的文件开始删除
+    pub max_total_mb: Option<u64>,
+    /// 最长保留天数，超过的文件直接删除
+    pub max_age_days: Option<u32>,
+}
+
+/// 按策略清理缓存（优先移入回收站）：先清理超龄文件，再按修改时间从旧到新清理直到低于容量上限
+fn prune_with_policy(policy: &CachePrunePolicy) -> Result<PurgeCacheResult, String> {
+    let data_dir = get_app_data_dir()?;
+    let now = SystemTime::now();
+    let mut files: Vec<CacheFile> = [
+        CacheKind::Old,
+        CacheKind::Logs,
+        CacheKind::Archives,
+        CacheKind::DebugImages,
+    ]
+    .into_iter()
+    .flat_map(|kind| collect_cache_files(&data_dir, kind))
+    // 最近修改的文件可能仍在使用，不参与自动清理
+    .filter(|f| now.duration_since(f.mtime).unwrap_or_default() >= PRUNE_MIN_AGE)
+    .collect();
+    files.sort_by_key(|f| f.mtime);
+
+    let mut result = PurgeCacheResult::default();
+    let mut remaining: u64 = files.iter().map(|f| f.size).sum();
+    let age_cutoff = policy
+        .max_age_days
+        .map(|days| now - Duration::from_secs(u64::from(days) * 24 * 60 * 60));
+    let max_bytes = policy.max_total_mb.map(|mb| mb * 1024 * 1024);
+
+    for file in files {
+        let expired = age_cutoff.is_some_and(|cutoff| file.mtime < cutoff);
+        let over_limit = max_bytes.is_some_and(|max| remaining > max);
+        if !expired && !over_limit {
+            continue;
+        }
+        let before = result.deleted_files;
+        remove_counted(&file.path, file.size, true, &mut result);
+        if result.deleted_files > before {
+            remaining = remaining.saturating_sub(file.size);
+        }
+    }
+    Ok(result)
+}
+
+/// 从 `settings.cachePrune` 读取策略，启用时在后台线程执行一次自动清理（应用启动时调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let Some(value) = config.get("settings").and_then(|s| s.get("cachePrune")) else {
+        return;
+    };
+    let policy = match serde_json::from_value::<CachePrunePolicy>(value.clone()) {
+        Ok(p) => p,
+        Err(e) => {
+            warn!("Invalid cachePrune settings: {}", e);
+            return;
+        }
+    };
+    if !policy.enabled || (policy.max_total_mb.is_none() && policy.max_age_days.is_none()) {
+        return;
+    }
+
+    std::thread::spawn(move || match prune_with_policy(&policy) {
+        Ok(result) => info!(
+            "[cache] Auto-pruned {} files ({} bytes), {} failed",
+            result.deleted_files, result.freed_bytes, result.failed
+        ),
+        Err(e) => warn!("[cache] Auto-prune failed: {}", e),
+    });
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 永久清理 cache/old 与 debug 日志
+/// - `older_than_days`: 只删除最后修改时间早于该天数的文件；为空时全部删除
+#[tauri::command]
+pub async fn purge_cache(older_than_days: Option<u32>) -> Result<PurgeCacheResult, String> {
+    tokio::task::spawn_blocking(move || purge_cache_blocking(older_than_days))
+        .await
+        .map_err(|e| tr!("error.task_panicked", error = e))?
+}
+
+/// 统计 cache/old、debug 日志、下载包与调试图片的占用
+#[tauri::command]
+pub async fn mxu_cache_stats() -> Result<CacheStats, String> {
+    tokio::task::spawn_blocking(cache_stats_blocking)
+        .await
+        .map_err(|e| tr!("error.task_panicked", error = e))?
+}
@@ -0,0 +1,163 @@
+//! 实例截图分辨率预设
+//!
+//! 预设保存在 MXU 配置的 `instances[].captureResolution` 中（随配置持久化），
+//! 连接控制器时优先于 interface 中的 `display_short_side`（缺省 720）生效，
+//! 便于需要 1080p 识别的资源开箱即用。格式为 `{ "shortSide": 1080 }` 或 `"raw"`（原始分辨率）。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use log::{info, warn};
+use maa_framework::controller::Controller;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use super::app_config::AppConfigState;
+use super::types::MaaState;
+use crate::i18n::tr;
+
+/// 允许的截图短边范围
+const MIN_SHORT_SIDE: i32 = 360;
+const MAX_SHORT_SIDE: i32 = 2160;
+
+/// 截图分辨率预设
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaptureResolution {
+    /// 按短边缩放到指定像素
+    ShortSide(i32),
+    /// 使用设备原始分辨率
+    Raw,
+}
+
+/// 实例 ID -> 预设（由配置同步）
+static PRESETS: Mutex<Option<HashMap<String, CaptureResolution>>> = Mutex::new(None);
+
+/// 从配置读取所有实例的预设（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let presets: HashMap<String, CaptureResolution> = config
+        .get("instances")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|inst| {
+                    let id = inst.get("id")?.as_str()?.to_string();
+                    let value = inst.get("captureResolution")?;
+                    match serde_json::from_value::<CaptureResolution>(value.clone()) {
+                        Ok(res) => Some((id, res)),
+                        Err(e) => {
+                            warn!("Invalid captureResolution for instance {}: {}", id, e);
+                            None
+                        }
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    *PRESETS.lock() = Some(presets);
+}
+
+/// 实例的截图分辨率预设
+pub fn preset(instance_id: &str) -> Option<CaptureResolution> {
+    PRESETS.lock().as_ref()?.get(instance_id).copied()
+}
+
+/// 将预设应用到控制器（连接前调用，也可对已连接的控制器即时生效）
+pub fn apply(controller: &Controller, resolution: CaptureResolution) -> Result<(), String> {
+    match resolution {
+        CaptureResolution::ShortSide(short_side) => {
+            controller.set_screenshot_use_raw_size(false).ok();
+            controller
+                .set_screenshot_target_short_side(short_side)
+                .map_err(|e| tr!("error.capture.short_side", error = e))
+        }
+        CaptureResolution::Raw => controller
+            .set_screenshot_use_raw_size(true)
+            .map_err(|e| tr!("error.capture.raw_size", error = e)),
+    }
+}
+
+/// 设置实例的截图分辨率预设（None 表示清除，恢复使用 interface 配置）
+pub fn set_capture_resolution_impl(
+    app: &AppHandle,
+    maa_state: &MaaState,
+    app_config: &AppConfigState,
+    instance_id: &str,
+    resolution: Option<CaptureResolution>,
+) -> Result<(), String> {
+    if let Some(CaptureResolution::ShortSide(short_side)) = resolution {
+        if !(MIN_SHORT_SIDE..=MAX_SHORT_SIDE).contains(&short_side) {
+            return Err(tr!(
+                "error.capture.invalid_short_side",
+                value = short_side,
+                min = MIN_SHORT_SIDE,
+                max = MAX_SHORT_SIDE
+            ));
+        }
+    }
+
+    let mut config = app_config.config.lock().clone();
+    let inst = config
+        .get_mut("instances")
+        .and_then(|v| v.as_array_mut())
+        .and_then(|list| {
+            list.iter_mut()
+                .find(|inst| inst.get("id").and_then(|v| v.as_str()) == Some(instance_id))
+        })
+        .ok_or_else(|| tr!("error.instance_not_found"))?;
+    let Some(obj) = inst.as_object_mut() else {
+        return Err(tr!("error.config.invalid_instance"));
+    };
+    match resolution {
+        Some(res) => {
+            obj.insert(
+                "captureResolution".to_string(),
+                serde_json::to_value(res).map_err(|e| e.to_string())?,
+            );
+        }
+        None => {
+            obj.remove("captureResolution");
+        }
+    }
+
+    // save_config 会同步更新 PRESETS
+    app_config.save_config(config)?;
+    super::utils::emit_config_changed(app);
+    info!(
+        "Instance {} capture resolution set to {:?}",
+        instance_id, resolution
+    );
+
+    // 已连接时立即生效（清除预设时保持当前设置，下次连接恢复 interface 配置）
+    if let Some(res) = resolution {
+        if let Some(instance) = maa_state.instances.get(instance_id) {
+            if let Some(controller) = instance.controller.as_ref() {
+                apply(controller, res)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 设置实例截图分辨率：`{ "shortSide": 1080 }`、`"raw"` 或 null（清除预设）
+#[tauri::command]
+pub fn maa_set_capture_resolution(
+    app: AppHandle,
+    state: State<Arc<MaaState>>,
+    app_config: State<Arc<AppConfigState>>,
+    instance_id: String,
+    resolution: Option<CaptureResolution>,
+) -> Result<(), String> {
+    set_capture_resolution_impl(&app, &state, &app_config, &instance_id, resolution)
+}
+
+/// 获取实例截图分辨率预设（未设置时返回 null）
+#[tauri::command]
+pub fn maa_get_capture_resolution(instance_id: String) -> Option<CaptureResolution> {
+    preset(&instance_id)
+}
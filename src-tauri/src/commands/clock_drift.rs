@@ -0,0 +1,200 @@
+//! 系统时钟偏差检测
+//!
+//! MXU_WAITUNTIL、定时执行与服务器重置时钟都依赖本机时间，系统时钟不准时会静默错过触发。
+//! 通过 SNTP 向 NTP 服务器查询标准时间，偏差超过阈值时发送 `clock-drift-warning` 事件
+//! （启动时检查一次），启动自检中也包含此项。启动检查可能早于前端注册监听，
+//! 结果同时暂存，待前端就绪后通过 `mxu_take_clock_drift_warning` 取走。
+//!
+//! 通过 `settings.clockDrift` 配置：
+//! - `enabled`: 启动时是否检查（默认 true）
+//! - `server`: NTP 服务器（缺省依次尝试内置列表）
+//! - `thresholdSecs`: 偏差阈值（默认 60 秒）
+
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use crate::i18n::tr;
+
+const DEFAULT_SERVERS: [&str; 3] = ["ntp.aliyun.com", "pool.ntp.org", "time.windows.com"];
+const DEFAULT_THRESHOLD_SECS: u64 = 60;
+const QUERY_TIMEOUT: Duration = Duration::from_secs(3);
+/// NTP 纪元（1900-01-01）与 Unix 纪元之间的秒数
+const NTP_UNIX_OFFSET_SECS: f64 = 2_208_988_800.0;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static THRESHOLD_SECS: AtomicU64 = AtomicU64::new(DEFAULT_THRESHOLD_SECS);
+static SERVER: Mutex<Option<String>> = Mutex::new(None);
+/// 启动检查发现的偏差（前端取走前暂存）
+static PENDING_WARNING: Mutex<Option<ClockDriftReport>> = Mutex::new(None);
+
+/// 时钟偏差检测结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClockDriftReport {
+    /// 实际应答的 NTP 服务器
+    pub server: String,
+    /// 本机时钟相对标准时间的偏差（毫秒，正值表示本机偏快）
+    pub offset_ms: i64,
+    /// 往返延迟（毫秒）
+    pub round_trip_ms: u64,
+    pub threshold_secs: u64,
+    /// 偏差是否超过阈值
+    pub drifted: bool,
+}
+
+/// 读取 `settings.clockDrift`（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let drift = config.get("settings").and_then(|s| s.get("clockDrift"));
+    ENABLED.store(
+        drift
+            .and_then(|d| d.get("enabled"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+        Ordering::Relaxed,
+    );
+    THRESHOLD_SECS.store(
+        drift
+            .and_then(|d| d.get("thresholdSecs"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_THRESHOLD_SECS)
+            .max(1),
+        Ordering::Relaxed,
+    );
+    *SERVER.lock() = drift
+        .and_then(|d| d.get("server"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+}
+
+fn unix_secs(time: SystemTime) -> f64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs_f64(),
+        Err(e) => -e.duration().as_secs_f64(),
+    }
+}
+
+/// 读取 NTP 64 位时间戳并转换为 Unix 秒
+fn read_timestamp(bytes: &[u8]) -> f64 {
+    let secs = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64;
+    let frac = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as f64;
+    secs + frac / 4_294_967_296.0 - NTP_UNIX_OFFSET_SECS
+}
+
+/// 向单个服务器发起 SNTP 查询，返回（偏差秒，往返秒）
+fn query_server(server: &str) -> Result<(f64, f64), String> {
+    let socket = UdpSocket::bind("0.0.0.0:0").map_err(|e| tr!("error.clock.socket", error = e))?;
+    socket
+        .set_read_timeout(Some(QUERY_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    socket
+        .connect((server, 123))
+        .map_err(|e| tr!("error.clock.connect", server = server, error = e))?;
+
+    // LI = 0, VN = 3, Mode = 3（客户端）
+    let mut packet = [0u8; 48];
+    packet[0] = 0x1B;
+    let sent_at = Instant::now();
+    let t1 = unix_secs(SystemTime::now());
+    socket
+        .send(&packet)
+        .map_err(|e| tr!("error.clock.send", error = e))?;
+    let len = socket
+        .recv(&mut packet)
+        .map_err(|e| tr!("error.clock.no_response", server = server, error = e))?;
+    let elapsed = sent_at.elapsed().as_secs_f64();
+    let t4 = t1 + elapsed;
+    if len < 48 || packet[0] & 0x07 != 4 {
+        return Err(tr!("error.clock.invalid_response", server = server));
+    }
+
+    let t2 = read_timestamp(&packet[32..40]);
+    let t3 = read_timestamp(&packet[40..48]);
+    // 服务器时间减本机时间
+    let offset = ((t2 - t1) + (t3 - t4)) / 2.0;
+    let round_trip = (elapsed - (t3 - t2)).max(0.0);
+    Ok((offset, round_trip))
+}
+
+/// 查询 NTP 服务器并计算本机时钟偏差（阻塞，最长数秒）
+pub fn check_clock_drift_impl(server: Option<&str>) -> Result<ClockDriftReport, String> {
+    let configured = SERVER.lock().clone();
+    let servers: Vec<String> = match server.map(str::to_string).or(configured) {
+        Some(server) => vec![server],
+        None => DEFAULT_SERVERS.iter().map(|s| s.to_string()).collect(),
+    };
+
+    let mut last_error = String::new();
+    for server in servers {
+        match query_server(&server) {
+            Ok((offset, round_trip)) => {
+                let threshold_secs = THRESHOLD_SECS.load(Ordering::Relaxed);
+                // offset 为服务器减本机，取反后正值表示本机偏快
+                let offset_ms = (-offset * 1000.0).round() as i64;
+                return Ok(ClockDriftReport {
+                    server,
+                    offset_ms,
+                    round_trip_ms: (round_trip * 1000.0).round() as u64,
+                    threshold_secs,
+                    drifted: offset_ms.unsigned_abs() > threshold_secs * 1000,
+                });
+            }
+            Err(e) => {
+                warn!("NTP query failed: {}", e);
+                last_error = e;
+            }
+        }
+    }
+    Err(tr!("error.clock.unavailable", error = last_error))
+}
+
+/// 启动时在后台检查一次时钟偏差，超过阈值时发送 `clock-drift-warning` 事件
+pub fn start(app: &AppHandle) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let app = app.clone();
+    std::thread::spawn(move || match check_clock_drift_impl(None) {
+        Ok(report) if report.drifted => {
+            warn!(
+                "System clock is off by {} ms (server {}, threshold {} s)",
+                report.offset_ms, report.server, report.threshold_secs
+            );
+            *PENDING_WARNING.lock() = Some(report.clone());
+            if let Err(e) = app.emit("clock-drift-warning", &report) {
+                warn!("Failed to emit clock-drift-warning: {}", e);
+            }
+        }
+        Ok(report) => info!(
+            "System clock offset {} ms (server {})",
+            report.offset_ms, report.server
+        ),
+        Err(e) => info!("Clock drift check skipped: {}", e),
+    });
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 检查本机时钟与 NTP 标准时间的偏差
+///
+/// `server` 为空时使用 `settings.clockDrift.server` 或内置服务器列表。
+#[tauri::command]
+pub async fn mxu_check_clock_drift(server: Option<String>) -> Result<ClockDriftReport, String> {
+    tauri::async_runtime::spawn_blocking(move || check_clock_drift_impl(server.as_deref()))
+        .await
+        .map_err(|e| tr!("error.task_panicked", error = e))?
+}
+
+/// 取走启动检查暂存的时钟偏差警告（前端就绪后调用一次）
+#[tauri::command]
+pub fn mxu_take_clock_drift_warning() -> Option<ClockDriftReport> {
+    PENDING_WARNING.lock().take()
+}
@@ -0,0 +1,632 @@
+//! 配置加密存储
+//!
+//! 开启后，配置文件（`<数据目录>/config/*.json`）与运行报告（`<数据目录>/reports/*/report.json`）
+//! 以 AES-256-GCM 加密写入磁盘；配置变更审计日志为只追加的 JSONL，按行单独加密。密钥由 PBKDF2-HMAC-SHA256 从以下来源派生：
+//! - `machine`：本机标识（Windows MachineGuid / Linux machine-id / macOS IOPlatformUUID），
+//!   无法获取时使用数据目录下随机生成的密钥文件
+//! - `passphrase`：用户密码，每次启动后需通过 `mxu_unlock_config` 解锁
+//!
+//! 加密文件内容为 `MXUENC1:<base64(nonce || 密文)>`，仍是文本，可正常备份与复制。
+//! 读取时自动识别明文与密文，关闭加密后文件被还原为明文。
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::Engine;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tauri::State;
+
+use super::app_config::AppConfigState;
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+/// 加密文件前缀
+const MAGIC: &str = "MXUENC1:";
+
+/// 加密设置文件（位于数据目录）
+const SETTINGS_FILE: &str = "encryption.json";
+
+/// 本机标识不可用时使用的密钥文件（位于数据目录）
+const MACHINE_KEY_FILE: &str = ".mxu-machine-key";
+
+const PBKDF2_ROUNDS: u32 = 100_000;
+const NONCE_LEN: usize = 12;
+
+/// 用于校验密码的明文
+const VERIFIER_PLAINTEXT: &str = "MXU";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct EncryptionSettings {
+    enabled: bool,
+    /// machine / passphrase
+    mode: String,
+    /// base64 编码的 PBKDF2 盐
+    salt: String,
+    /// 用当前密钥加密的校验串
+    verifier: String,
+}
+
+struct KeyState {
+    settings: EncryptionSettings,
+    /// 未解锁（密码模式）时为 None
+    key: Option<[u8; 32]>,
+}
+
+/// 首次访问时从磁盘加载
+static STATE: Mutex<Option<KeyState>> = Mutex::new(None);
+
+/// 加密状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigEncryptionStatus {
+    pub enabled: bool,
+    pub mode: Option<String>,
+    /// 密码模式下尚未解锁
+    pub locked: bool,
+}
+
+fn b64() -> base64::engine::GeneralPurpose {
+    base64::engine::general_purpose::STANDARD
+}
+
+fn settings_path() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join(SETTINGS_FILE))
+}
+
+fn read_machine_id() -> Option<String> {
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        let output = std::process::Command::new("reg")
+            .args([
+                "query",
+                r"HKLM\SOFTWARE\Microsoft\Cryptography",
+                "/v",
+                "MachineGuid",
+            ])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        text.lines()
+            .find(|l| l.contains("MachineGuid"))
+            .and_then(|l| l.split_whitespace().last())
+            .map(|s| s.to_string())
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let output = std::process::Command::new("ioreg")
+            .args(["-rd1", "-c", "IOPlatformExpertDevice"])
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        text.lines()
+            .find(|l| l.contains("IOPlatformUUID"))
+            .and_then(|l| l.split('"').nth(3))
+            .map(|s| s.to_string())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        ["/etc/machine-id", "/var/lib/dbus/machine-id"]
+            .iter()
+            .find_map(|p| std::fs::read_to_string(p).ok())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+}
+
+/// 本机密钥材料：优先本机标识，否则读取（或生成）密钥文件
+fn machine_secret() -> Result<String, String> {
+    if let Some(id) = read_machine_id() {
+        return Ok(format!("mxu-machine:{}", id));
+    }
+    let path = get_app_data_dir()?.join(MACHINE_KEY_FILE);
+    if let Ok(content) = std::fs::read_to_string(&path) {
+        if !content.trim().is_empty() {
+            return Ok(content.trim().to_string());
+        }
+    }
+    let secret = b64().encode(rand::random::<[u8; 32]>());
+    std::fs::write(&path, &secret).map_err(|e| tr!("error.crypto.write_machine_key", error = e))?;
+    Ok(secret)
+}
+
+fn derive_key(secret: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(secret.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+    key
+}
+
+fn encrypt_with(key: &[u8; 32], plaintext: &str) -> Result<String, String> {
+    let cipher =
+        Aes256Gcm::new_from_slice(key).map_err(|e| tr!("error.crypto.init_failed", error = e))?;
+    let nonce_bytes = rand::random::<[u8; NONCE_LEN]>();
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext.as_bytes())
+        .map_err(|_| tr!("error.crypto.encrypt_failed"))?;
+    let mut data = nonce_bytes.to_vec();
+    data.extend(ciphertext);
+    Ok(format!("{}{}", MAGIC, b64().encode(data)))
+}
+
+fn decrypt_with(key: &[u8; 32], content: &str) -> Result<String, String> {
+    let data = b64()
+        .decode(content.trim_start_matches(MAGIC).trim())
+        .map_err(|e| format!("{}: {}", tr!("error.crypto.bad_format"), e))?;
+    if data.len() < NONCE_LEN {
+        return Err(tr!("error.crypto.bad_format"));
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let cipher =
+        Aes256Gcm::new_from_slice(key).map_err(|e| tr!("error.crypto.init_failed", error = e))?;
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| tr!("error.crypto.decrypt_failed"))?;
+    String::from_utf8(plaintext).map_err(|e| tr!("error.crypto.not_text", error = e))
+}
+
+fn load_state() -> KeyState {
+    let settings: EncryptionSettings = settings_path()
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default();
+
+    let key = if settings.enabled && settings.mode == "machine" {
+        match (machine_secret(), b64().decode(&settings.salt)) {
+            (Ok(secret), Ok(salt)) => Some(derive_key(&secret, &salt)),
+            (Err(e), _) => {
+                warn!("[config_crypto] Failed to load machine key: {}", e);
+                None
+            }
+            (_, Err(e)) => {
+                warn!("[config_crypto] Invalid salt in settings: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    KeyState { settings, key }
+}
+
+fn with_state<T>(f: impl FnOnce(&mut KeyState) -> Result<T, String>) -> Result<T, String> {
+    let mut guard = STATE.lock();
+    f(guard.get_or_insert_with(load_state))
+}
+
+/// 当前写入使用的密钥（未开启加密时为 None）
+fn write_key() -> Result<Option<[u8; 32]>, String> {
+    with_state(|state| {
+        if !state.settings.enabled {
+            return Ok(None);
+        }
+        state
+            .key
+            .map(Some)
+            .ok_or_else(|| tr!("error.crypto.locked"))
+    })
+}
+
+/// 读取可能加密的文本文件
+pub fn read_to_string(path: &Path) -> Result<String, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| tr!("error.fs.read_file", path = path.display(), error = e))?;
+    if !content.starts_with(MAGIC) {
+        return Ok(content);
+    }
+    let key = with_state(|state| state.key.ok_or_else(|| tr!("error.crypto.locked")))?;
+    decrypt_with(&key, &content)
+}
+
+/// 按当前加密设置追加一行到只追加的 JSONL 文件（开启加密时逐行加密）
+///
+/// 加密与写入期间持有密钥状态锁，避免与切换加密设置交错而用旧密钥写入新文件。
+pub fn append_line(path: &Path, line: &str) -> Result<(), String> {
+    use std::io::Write;
+
+    with_state(|state| {
+        let line = match (state.settings.enabled, state.key) {
+            (false, _) => line.to_string(),
+            (true, Some(key)) => encrypt_with(&key, line)?,
+            (true, None) => return Err(tr!("error.crypto.locked")),
+        };
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))?;
+        writeln!(file, "{}", line)
+            .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))
+    })
+}
+
+/// 解密 [`append_line`] 写入的单行文本（明文行原样返回）
+pub fn decrypt_line(line: &str) -> Result<String, String> {
+    if !line.starts_with(MAGIC) {
+        return Ok(line.to_string());
+    }
+    let key = with_state(|state| state.key.ok_or_else(|| tr!("error.crypto.locked")))?;
+    decrypt_with(&key, line)
+}
+
+/// 按当前加密设置原子写入文本文件（先写 .tmp 再 rename）
+pub fn write_string(path: &Path, content: &str) -> Result<(), String> {
+    let data = match write_key()? {
+        Some(key) => encrypt_with(&key, content)?,
+        None => content.to_string(),
+    };
+    let tmp_path = with_suffix(path, ".tmp");
+    std::fs::write(&tmp_path, data).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        tr!("error.fs.write_file", path = tmp_path.display(), error = e)
+    })?;
+    std::fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        tr!("error.fs.rename", path = path.display(), error = e)
+    })
+}
+
+/// 在路径末尾追加后缀（`a.json` -> `a.json<suffix>`）
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// 用临时文件替换原文件，最后写入加密设置
+///
+/// 原文件先移为 `.bak`，任一步失败时恢复全部原文件；加密设置通过 rename 原子替换，
+/// 使磁盘上的文件与加密设置保持一致。
+fn swap_in(
+    staged: &[(PathBuf, PathBuf)],
+    settings: &Path,
+    settings_content: &str,
+) -> Result<(), String> {
+    let settings_tmp = with_suffix(settings, ".tmp");
+    let mut swapped: Vec<&PathBuf> = Vec::with_capacity(staged.len());
+
+    let result = (|| {
+        for (path, tmp_path) in staged {
+            std::fs::rename(path, with_suffix(path, ".bak")).map_err(|e| {
+                tr!(
+                    "error.crypto.backup_failed",
+                    path = path.display(),
+                    error = e
+                )
+            })?;
+            swapped.push(path);
+            std::fs::rename(tmp_path, path).map_err(|e| {
+                tr!(
+                    "error.crypto.replace_failed",
+                    path = path.display(),
+                    error = e
+                )
+            })?;
+        }
+        std::fs::write(&settings_tmp, settings_content)
+            .map_err(|e| tr!("error.crypto.write_settings", error = e))?;
+        std::fs::rename(&settings_tmp, settings)
+            .map_err(|e| tr!("error.crypto.write_settings", error = e))
+    })();
+
+    match result {
+        Ok(()) => {
+            for path in swapped {
+                let _ = std::fs::remove_file(with_suffix(path, ".bak"));
+            }
+            Ok(())
+        }
+        Err(e) => {
+            for path in swapped {
+                if let Err(restore) = std::fs::rename(with_suffix(path, ".bak"), path) {
+                    warn!(
+                        "[config_crypto] Failed to restore {}: {}",
+                        path.display(),
+                        restore
+                    );
+                }
+            }
+            for (_, tmp_path) in staged {
+                let _ = std::fs::remove_file(tmp_path);
+            }
+            let _ = std::fs::remove_file(&settings_tmp);
+            Err(e)
+        }
+    }
+}
+
+/// 用新密钥重写逐行加密的 JSONL 内容（新密钥为 None 时还原为明文）
+///
+/// 明文行与加密行可混合出现；无法用旧密钥解密的行原样保留，避免丢失记录。
+fn rekey_lines(
+    content: &str,
+    old_key: Option<&[u8; 32]>,
+    new_key: Option<&[u8; 32]>,
+) -> Result<String, String> {
+    let mut output = String::with_capacity(content.len());
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let plaintext = match (line.starts_with(MAGIC), old_key) {
+            (false, _) => line.to_string(),
+            (true, Some(key)) => match decrypt_with(key, line) {
+                Ok(plaintext) => plaintext,
+                Err(e) => {
+                    warn!("[config_crypto] Keeping undecryptable audit line: {}", e);
+                    output.push_str(line);
+                    output.push('\n');
+                    continue;
+                }
+            },
+            (true, None) => line.to_string(),
+        };
+        match new_key {
+            Some(key) => output.push_str(&encrypt_with(key, &plaintext)?),
+            None => output.push_str(&plaintext),
+        }
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// 受加密保护的文件（配置文件与运行报告）
+fn protected_files() -> Result<Vec<PathBuf>, String> {
+    let data_dir = get_app_data_dir()?;
+    let mut files = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(data_dir.join("config")) {
+        files.extend(
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "json")),
+        );
+    }
+    if let Ok(entries) = std::fs::read_dir(data_dir.join("reports")) {
+        files.extend(
+            entries
+                .flatten()
+                .map(|e| e.path().join("report.json"))
+                .filter(|p| p.is_file()),
+        );
+    }
+    Ok(files)
+}
+
+/// 检查路径位于数据目录的 config 或 reports 下
+fn ensure_protected_path(path: &str) -> Result<PathBuf, String> {
+    let data_dir = get_app_data_dir()?;
+    let path = super::utils::normalize_path(path);
+    if path.starts_with(data_dir.join("config")) || path.starts_with(data_dir.join("reports")) {
+        Ok(path)
+    } else {
+        Err(tr!("error.fs.path_not_allowed", path = path.display()))
+    }
+}
+
+fn status_of(state: &KeyState) -> ConfigEncryptionStatus {
+    ConfigEncryptionStatus {
+        enabled: state.settings.enabled,
+        mode: state.settings.enabled.then(|| state.settings.mode.clone()),
+        locked: state.settings.enabled && state.key.is_none(),
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取配置加密状态
+#[tauri::command]
+pub fn mxu_get_config_encryption() -> Result<ConfigEncryptionStatus, String> {
+    with_state(|state| Ok(status_of(state)))
+}
+
+/// 开启或关闭配置加密，并重写已有的配置文件、运行报告与审计日志
+///
+/// 开启时提供 `passphrase` 使用密码模式，否则使用本机密钥。
+/// 所有文件改写成功后才写入新的加密设置，失败时原文件与设置保持不变。
+#[tauri::command]
+pub fn mxu_set_config_encryption(
+    enabled: bool,
+    passphrase: Option<String>,
+) -> Result<ConfigEncryptionStatus, String> {
+    let passphrase = passphrase.filter(|p| !p.is_empty());
+    let mut guard = STATE.lock();
+    let state = guard.get_or_insert_with(load_state);
+    if state.settings.enabled && state.key.is_none() {
+        return Err(tr!("error.crypto.locked"));
+    }
+
+    // 先用旧密钥读出所有受保护文件
+    let files = protected_files()?;
+    let mut contents = Vec::with_capacity(files.len());
+    for path in files {
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| tr!("error.fs.read_file", path = path.display(), error = e))?;
+        let plaintext = match (content.starts_with(MAGIC), state.key) {
+            (true, Some(key)) => decrypt_with(&key, &content)?,
+            (true, None) => return Err(tr!("error.crypto.cannot_decrypt", path = path.display())),
+            (false, _) => content,
+        };
+        contents.push((path, plaintext));
+    }
+    let audit_path = super::audit_log::audit_path()?;
+    let audit_content = if audit_path.is_file() {
+        Some(
+            std::fs::read_to_string(&audit_path)
+                .map_err(|e| tr!("error.fs.read_file", path = audit_path.display(), error = e))?,
+        )
+    } else {
+        None
+    };
+
+    let new_state = if enabled {
+        let salt = rand::random::<[u8; 16]>();
+        let (mode, secret) = match passphrase {
+            Some(p) => ("passphrase", p),
+            None => ("machine", machine_secret()?),
+        };
+        let key = derive_key(&secret, &salt);
+        KeyState {
+            settings: EncryptionSettings {
+                enabled: true,
+                mode: mode.to_string(),
+                salt: b64().encode(salt),
+                verifier: encrypt_with(&key, VERIFIER_PLAINTEXT)?,
+            },
+            key: Some(key),
+        }
+    } else {
+        KeyState {
+            settings: EncryptionSettings::default(),
+            key: None,
+        }
+    };
+
+    let settings_content = serde_json::to_string_pretty(&new_state.settings)
+        .map_err(|e| tr!("error.crypto.write_settings", error = e))?;
+
+    // 用新密钥写入临时文件，全部成功后再替换原文件，最后写入加密设置。
+    // 整个过程持有 STATE 锁，期间其他写入会等待，不会用旧密钥写入。
+    let mut staged = Vec::with_capacity(contents.len());
+    let stage_result = contents.iter().try_for_each(|(path, plaintext)| {
+        let data = match new_state.key {
+            Some(key) => encrypt_with(&key, plaintext)?,
+            None => plaintext.clone(),
+        };
+        let tmp_path = with_suffix(path, ".enc.tmp");
+        std::fs::write(&tmp_path, data)
+            .map_err(|e| tr!("error.fs.write_file", path = tmp_path.display(), error = e))?;
+        staged.push((path.clone(), tmp_path));
+        Ok::<(), String>(())
+    });
+    // 审计日志逐行加密，与其他文件在同一事务中替换
+    let stage_result = stage_result.and_then(|()| {
+        let Some(content) = &audit_content else {
+            return Ok(());
+        };
+        let data = rekey_lines(content, state.key.as_ref(), new_state.key.as_ref())?;
+        let tmp_path = with_suffix(&audit_path, ".enc.tmp");
+        std::fs::write(&tmp_path, data)
+            .map_err(|e| tr!("error.fs.write_file", path = tmp_path.display(), error = e))?;
+        staged.push((audit_path.clone(), tmp_path));
+        Ok(())
+    });
+    if let Err(e) = stage_result {
+        for (_, tmp_path) in &staged {
+            let _ = std::fs::remove_file(tmp_path);
+        }
+        return Err(e);
+    }
+
+    swap_in(&staged, &settings_path()?, &settings_content)?;
+
+    *state = new_state;
+    let status = status_of(state);
+    drop(guard);
+
+    info!(
+        "[config_crypto] Config encryption {} ({:?})",
+        if enabled { "enabled" } else { "disabled" },
+        status.mode
+    );
+    Ok(status)
+}
+
+/// 使用密码解锁加密配置（密码模式）
+#[tauri::command]
+pub fn mxu_unlock_config(
+    config_state: State<Arc<AppConfigState>>,
+    passphrase: String,
+) -> Result<ConfigEncryptionStatus, String> {
+    let status = with_state(|state| {
+        if !state.settings.enabled || state.key.is_some() {
+            return Ok(status_of(state));
+        }
+        let salt = b64()
+            .decode(&state.settings.salt)
+            .map_err(|e| tr!("error.crypto.settings_corrupted", error = e))?;
+        let key = derive_key(&passphrase, &salt);
+        decrypt_with(&key, &state.settings.verifier)
+            .map_err(|_| tr!("error.crypto.wrong_passphrase"))?;
+        state.key = Some(key);
+        Ok(status_of(state))
+    })?;
+
+    // 解锁后重新加载后端缓存的配置
+    let data_path = config_state.data_path.lock().clone();
+    if !data_path.is_empty() {
+        config_state.load_config(Path::new(&data_path));
+    }
+    Ok(status)
+}
+
+/// 读取配置/报告文件（自动解密）
+#[tauri::command]
+pub fn mxu_read_protected_file(path: String) -> Result<String, String> {
+    read_to_string(&ensure_protected_path(&path)?)
+}
+
+/// 写入配置/报告文件（按加密设置加密，原子写入）
+#[tauri::command]
+pub fn mxu_write_protected_file(path: String, content: String) -> Result<(), String> {
+    let path = ensure_protected_path(&path)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+    }
+    write_string(&path, &content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_A: [u8; 32] = [1; 32];
+    const KEY_B: [u8; 32] = [2; 32];
+
+    fn decrypt_lines(content: &str, key: Option<&[u8; 32]>) -> Vec<String> {
+        content
+            .lines()
+            .map(|line| match (line.starts_with(MAGIC), key) {
+                (true, Some(key)) => decrypt_with(key, line).unwrap(),
+                _ => line.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rekey_lines_keeps_old_entries_readable() {
+        let plain = r#"{"seq":1}"#;
+        let encrypted = encrypt_with(&KEY_A, r#"{"seq":2}"#).unwrap();
+        let original = format!("{}\n{}\n", plain, encrypted);
+        let expected = vec![r#"{"seq":1}"#.to_string(), r#"{"seq":2}"#.to_string()];
+
+        // 开启加密 / 更换密钥：旧的明文行与旧密钥加密的行都用新密钥重写
+        let rekeyed = rekey_lines(&original, Some(&KEY_A), Some(&KEY_B)).unwrap();
+        assert!(rekeyed.lines().all(|line| line.starts_with(MAGIC)));
+        assert_eq!(decrypt_lines(&rekeyed, Some(&KEY_B)), expected);
+
+        // 关闭加密：还原为明文
+        let disabled = rekey_lines(&rekeyed, Some(&KEY_B), None).unwrap();
+        assert_eq!(decrypt_lines(&disabled, None), expected);
+
+        // 再次开启
+        let enabled = rekey_lines(&disabled, None, Some(&KEY_A)).unwrap();
+        assert_eq!(decrypt_lines(&enabled, Some(&KEY_A)), expected);
+    }
+
+    #[test]
+    fn rekey_lines_keeps_undecryptable_lines() {
+        let foreign = encrypt_with(&KEY_B, "lost").unwrap();
+        let rekeyed = rekey_lines(&foreign, Some(&KEY_A), None).unwrap();
+        assert_eq!(rekeyed.trim_end(), foreign);
+    }
+}
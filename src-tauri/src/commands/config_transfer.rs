@@ -0,0 +1,475 @@
+//! 配置导入导出
+//!
+//! 将数据目录 `config/` 下的所有配置文件（实例、定时策略等均保存在其中）打包为 zip，
+//! 用于迁移到其他机器或重装后恢复。导出时剥离 CDK、GitHub PAT 等敏感字段，
+//! 仅在 manifest 中记录其是否存在；导入时按 schema 版本迁移旧配置，
+//! 并保留本机已有的敏感字段。
+
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::State;
+
+use super::app_config::{make_config_filename, AppConfigState};
+use super::types::{ConfigExportResult, ConfigImportResult, ConfigSecretMeta};
+use super::utils::{emit_config_changed, get_app_data_dir};
+use crate::i18n::tr;
+
+/// 导出包格式版本（manifest 结构变化时递增）
+const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// 当前配置 schema 版本（与前端 defaultConfig.version 保持一致）
+const CURRENT_CONFIG_VERSION: &str = "1.0";
+
+/// 导出包中的 manifest 文件名
+const MANIFEST_NAME: &str = "manifest.json";
+
+/// 导出包中配置文件所在目录
+const ARCHIVE_CONFIG_DIR: &str = "config";
+
+/// 敏感字段（JSON Pointer），导出时剥离
+pub(crate) const SECRET_POINTERS: &[&str] = &[
+    "/settings/mirrorChyan/cdk",
+    "/settings/mirrorChyan/cdkEncrypted",
+    "/settings/mirrorChyan/githubPat",
+    "/settings/proxy/url",
+    "/settings/mqtt/password",
+];
+
+/// 导出包 manifest
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportManifest {
+    format_version: u32,
+    mxu_version: String,
+    exported_at: String,
+    files: Vec<String>,
+    secrets: Vec<ConfigSecretMeta>,
+}
+
+/// 判断字段是否为需要剥离的敏感值
+///
+/// 代理地址仅在包含认证信息（`user:pass@`）时视为敏感。
+fn is_secret_value(pointer: &str, value: &Value) -> bool {
+    match value.as_str() {
+        Some("") | None => false,
+        Some(s) if pointer == "/settings/proxy/url" => s.contains('@'),
+        Some(_) => true,
+    }
+}
+
+/// 剥离配置中的敏感字段，返回被剥离字段的元数据
+fn strip_secrets(file: &str, config: &mut Value) -> Vec<ConfigSecretMeta> {
+    let mut stripped = Vec::new();
+    for pointer in SECRET_POINTERS {
+        if let Some(value) = config.pointer_mut(pointer) {
+            if is_secret_value(pointer, value) {
+                *value = Value::String(String::new());
+                stripped.push(ConfigSecretMeta {
+                    file: file.to_string(),
+                    pointer: pointer.to_string(),
+                });
+            }
+        }
+    }
+    stripped
+}
+
+/// 将本机已有配置中的敏感字段回填到导入的配置，返回仍缺失的字段
+fn restore_secrets(
+    file: &str,
+    imported: &mut Value,
+    existing: Option<&Value>,
+    exported_secrets: &[ConfigSecretMeta],
+) -> Vec<ConfigSecretMeta> {
+    let mut missing = Vec::new();
+    for pointer in SECRET_POINTERS {
+        let local = existing
+            .and_then(|c| c.pointer(pointer))
+            .filter(|v| is_secret_value(pointer, v));
+        match (local, imported.pointer_mut(pointer)) {
+            (Some(local), Some(target)) => *target = local.clone(),
+            (Some(local), None) => {
+                if let Some(target) = ensure_pointer(imported, pointer) {
+                    *target = local.clone();
+                }
+            }
+            (None, _) => {
+                if exported_secrets
+                    .iter()
+                    .any(|s| s.file == file && s.pointer == *pointer)
+                {
+                    missing.push(ConfigSecretMeta {
+                        file: file.to_string(),
+                        pointer: pointer.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    missing
+}
+
+/// 按 JSON Pointer 逐级创建对象，返回末端字段的可变引用
+fn ensure_pointer<'a>(root: &'a mut Value, pointer: &str) -> Option<&'a mut Value> {
+    let mut current = root;
+    for key in pointer.trim_start_matches('/').split('/') {
+        current = current
+            .as_object_mut()?
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Object(Default::default()));
+    }
+    Some(current)
+}
+
+/// 解析 "主版本.次版本" 形式的配置版本号，缺失或无法解析视为 0.0
+fn parse_config_version(version: Option<&str>) -> (u32, u32) {
+    let Some(version) = version else {
+        return (0, 0);
+    };
+    let mut parts = version
+        .split('.')
+        .map(|p| p.trim().parse::<u32>().unwrap_or(0));
+    (parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+}
+
+/// 将实例对象中旧版单个 `preAction` 迁移为 `preActions` 数组
+///
+/// 与前端 appStore.ts 的 migratePreActions 一致；缺失的 id 由前端加载时补全。
+fn migrate_pre_action(instance: &mut Value) -> bool {
+    let Some(obj) = instance.as_object_mut() else {
+        return false;
+    };
+    let Some(legacy) = obj.remove("preAction") else {
+        return false;
+    };
+    let has_new = obj
+        .get("preActions")
+        .and_then(|v| v.as_array())
+        .is_some_and(|a| !a.is_empty());
+    if !has_new && legacy.is_object() {
+        obj.insert("preActions".to_string(), Value::Array(vec![legacy]));
+    }
+    true
+}
+
+/// 将旧版配置迁移到当前 schema，返回已执行的迁移步骤说明
+///
+/// 比当前版本更新的配置直接拒绝，避免旧版本程序丢弃未知字段。
+fn migrate_config(file: &str, config: &mut Value) -> Result<Vec<String>, String> {
+    if !config.is_object() {
+        return Err(tr!("error.config.not_object", file = file));
+    }
+
+    let from = parse_config_version(config.get("version").and_then(|v| v.as_str()));
+    let current = parse_config_version(Some(CURRENT_CONFIG_VERSION));
+    if from > current {
+        return Err(tr!(
+            "error.config.version_too_new",
+            file = file,
+            version = format!("{}.{}", from.0, from.1),
+            current = CURRENT_CONFIG_VERSION
+        ));
+    }
+
+    let mut steps = Vec::new();
+
+    // 0.x -> 1.0：补齐 version / instances / settings，preAction -> preActions
+    if from < (1, 0) {
+        let obj = config.as_object_mut().unwrap();
+        obj.entry("instances")
+            .or_insert_with(|| Value::Array(Vec::new()));
+        obj.entry("settings")
+            .or_insert_with(|| serde_json::json!({ "theme": "system", "language": "system" }));
+        steps.push(tr!("config.migrate.fill_fields", file = file));
+    }
+
+    let mut migrated_pre_actions = 0;
+    for key in ["instances", "recentlyClosed"] {
+        if let Some(list) = config.get_mut(key).and_then(|v| v.as_array_mut()) {
+            for instance in list {
+                if migrate_pre_action(instance) {
+                    migrated_pre_actions += 1;
+                }
+            }
+        }
+    }
+    if migrated_pre_actions > 0 {
+        steps.push(tr!(
+            "config.migrate.pre_actions",
+            file = file,
+            count = migrated_pre_actions
+        ));
+    }
+
+    if from < current {
+        config["version"] = Value::String(CURRENT_CONFIG_VERSION.to_string());
+        steps.push(tr!(
+            "config.migrate.version",
+            file = file,
+            from = format!("{}.{}", from.0, from.1),
+            to = CURRENT_CONFIG_VERSION
+        ));
+    }
+
+    Ok(steps)
+}
+
+/// 校验 zip 条目名为 `config/<文件名>.json`，返回文件名
+fn archive_config_file_name(entry_name: &str) -> Option<&str> {
+    let name = entry_name
+        .strip_prefix(ARCHIVE_CONFIG_DIR)?
+        .strip_prefix('/')?;
+    let valid = !name.is_empty()
+        && name.ends_with(".json")
+        && !name.contains(['/', '\\'])
+        && name != "."
+        && name != "..";
+    valid.then_some(name)
+}
+
+/// 原子写入 JSON 文件（与 AppConfigState::save_config 一致，开启加密时写入密文）
+fn write_json_atomic(path: &Path, value: &Value) -> Result<(), String> {
+    let content =
+        serde_json::to_string_pretty(value).map_err(|e| tr!("error.serialize", error = e))?;
+    super::config_crypto::write_string(path, &content)
+        .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))
+}
+
+fn export_blocking(dest_zip: String) -> Result<ConfigExportResult, String> {
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    let config_dir = get_app_data_dir()?.join(ARCHIVE_CONFIG_DIR);
+    let mut files = Vec::new();
+    let mut secrets = Vec::new();
+    let mut contents = Vec::new();
+
+    let entries = std::fs::read_dir(&config_dir)
+        .map_err(|e| tr!("error.fs.read_dir", path = config_dir.display(), error = e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() || path.extension().map(|e| e != "json").unwrap_or(true) {
+            continue;
+        }
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+        let mut config: Value = match super::config_crypto::read_to_string(&path)
+            .and_then(|c| super::app_config::parse_jsonc(&c).map_err(|e| e.to_string()))
+        {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("config_export: skip unreadable {}: {}", file_name, e);
+                continue;
+            }
+        };
+        secrets.extend(strip_secrets(&file_name, &mut config));
+        contents.push((file_name.clone(), config));
+        files.push(file_name);
+    }
+
+    if files.is_empty() {
+        return Err(tr!("error.config.nothing_to_export"));
+    }
+
+    let manifest = ExportManifest {
+        format_version: EXPORT_FORMAT_VERSION,
+        mxu_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: chrono::Local::now().to_rfc3339(),
+        files: files.clone(),
+        secrets: secrets.clone(),
+    };
+
+    let dest_path = Path::new(&dest_zip);
+    if let Some(parent) = dest_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+    }
+    let file = std::fs::File::create(dest_path)
+        .map_err(|e| tr!("error.fs.create_file", path = dest_zip, error = e))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let mut write_entry = |name: &str, value: &Value| -> Result<(), String> {
+        let content =
+            serde_json::to_vec_pretty(value).map_err(|e| tr!("error.serialize", error = e))?;
+        zip.start_file(name, options)
+            .map_err(|e| tr!("error.fs.write_archive", path = name, error = e))?;
+        zip.write_all(&content)
+            .map_err(|e| tr!("error.fs.write_archive", path = name, error = e))
+    };
+
+    let manifest_value =
+        serde_json::to_value(&manifest).map_err(|e| tr!("error.serialize", error = e))?;
+    write_entry(MANIFEST_NAME, &manifest_value)?;
+    for (file_name, config) in &contents {
+        write_entry(&format!("{}/{}", ARCHIVE_CONFIG_DIR, file_name), config)?;
+    }
+    zip.finish()
+        .map_err(|e| tr!("error.fs.write_archive", path = dest_zip, error = e))?;
+
+    info!(
+        "config_export: {} file(s), {} secret(s) stripped -> {}",
+        files.len(),
+        secrets.len(),
+        dest_zip
+    );
+    Ok(ConfigExportResult {
+        path: dest_zip,
+        files,
+        stripped_secrets: secrets,
+    })
+}
+
+fn import_blocking(
+    src_zip: &str,
+    current_config_file: &str,
+) -> Result<(ConfigImportResult, Option<Value>), String> {
+    let file = std::fs::File::open(src_zip)
+        .map_err(|e| tr!("error.config.open_archive", path = src_zip, error = e))?;
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|e| tr!("error.config.parse_archive", error = e))?;
+
+    let manifest: ExportManifest = {
+        let mut entry = archive
+            .by_name(MANIFEST_NAME)
+            .map_err(|_| tr!("error.config.no_manifest"))?;
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| tr!("error.fs.read_file", path = MANIFEST_NAME, error = e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| tr!("error.config.parse_file", file = MANIFEST_NAME, error = e))?
+    };
+    if manifest.format_version > EXPORT_FORMAT_VERSION {
+        return Err(tr!(
+            "error.config.format_too_new",
+            version = manifest.format_version,
+            current = EXPORT_FORMAT_VERSION
+        ));
+    }
+
+    // 先全部解析与迁移，全部成功后再落盘，避免导入一半
+    let mut pending = Vec::new();
+    let mut migrations = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| tr!("error.update.read_zip_entry", index = i, error = e))?;
+        let Some(file_name) = archive_config_file_name(entry.name()).map(|s| s.to_string()) else {
+            continue;
+        };
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| tr!("error.fs.read_file", path = file_name, error = e))?;
+        let mut config = super::app_config::parse_jsonc(&content)
+            .map_err(|e| tr!("error.config.parse_file", file = file_name, error = e))?;
+        migrations.extend(migrate_config(&file_name, &mut config)?);
+        pending.push((file_name, config));
+    }
+
+    if pending.is_empty() {
+        return Err(tr!("error.config.archive_empty"));
+    }
+
+    let config_dir = get_app_data_dir()?.join(ARCHIVE_CONFIG_DIR);
+    std::fs::create_dir_all(&config_dir).map_err(|e| {
+        tr!(
+            "error.fs.create_dir",
+            path = config_dir.display(),
+            error = e
+        )
+    })?;
+    let backup_suffix = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+
+    let mut files = Vec::new();
+    let mut missing_secrets = Vec::new();
+    let mut current_config = None;
+    for (file_name, mut config) in pending {
+        let target = config_dir.join(&file_name);
+        let existing = super::config_crypto::read_to_string(&target)
+            .ok()
+            .and_then(|c| super::app_config::parse_jsonc(&c).ok());
+
+        missing_secrets.extend(restore_secrets(
+            &file_name,
+            &mut config,
+            existing.as_ref(),
+            &manifest.secrets,
+        ));
+
+        // 覆盖前备份原配置
+        if target.exists() {
+            let backup = config_dir.join(format!("{}.bak-{}", file_name, backup_suffix));
+            std::fs::copy(&target, &backup).map_err(|e| {
+                tr!(
+                    "error.config.backup_failed",
+                    path = backup.display(),
+                    error = e
+                )
+            })?;
+        }
+        write_json_atomic(&target, &config)?;
+
+        if file_name == current_config_file {
+            current_config = Some(config);
+        }
+        files.push(file_name);
+    }
+
+    info!(
+        "config_import: {} file(s) imported from {}, {} migration step(s)",
+        files.len(),
+        src_zip,
+        migrations.len()
+    );
+    Ok((
+        ConfigImportResult {
+            files,
+            migrations,
+            missing_secrets,
+            source_mxu_version: manifest.mxu_version,
+        },
+        current_config,
+    ))
+}
+
+/// 导出全部配置到 zip（敏感字段已剥离）
+#[tauri::command]
+pub async fn config_export(dest_zip: String) -> Result<ConfigExportResult, String> {
+    info!("config_export called, dest_zip: {}", dest_zip);
+    tokio::task::spawn_blocking(move || export_blocking(dest_zip))
+        .await
+        .map_err(|e| tr!("error.task_panicked", error = e))?
+}
+
+/// 从 zip 导入配置，按需迁移旧版 schema，并保留本机已有的敏感字段
+///
+/// 被覆盖的配置会备份为 `<文件名>.bak-<时间>`；当前项目配置导入后广播
+/// `ConfigChanged`，各客户端重新加载。
+#[tauri::command]
+pub async fn config_import(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<AppConfigState>>,
+    src_zip: String,
+) -> Result<ConfigImportResult, String> {
+    info!("config_import called, src_zip: {}", src_zip);
+    let project_name = state.project_name.lock().clone();
+    let current_config_file = make_config_filename(project_name.as_deref());
+
+    let (result, current_config) =
+        tokio::task::spawn_blocking(move || import_blocking(&src_zip, &current_config_file))
+            .await
+            .map_err(|e| tr!("error.task_panicked", error = e))??;
+
+    if let Some(config) = current_config {
+        *state.config.lock() = config;
+        emit_config_changed(&app);
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,261 @@
+//! ADB 设备电量与温度监控
+//!
+//! 通过 `adb shell dumpsys battery` / `dumpsys thermalservice` 读取设备健康状态。
+//! 后台线程定期检查正在运行任务的 ADB 实例，电量过低（且未充电）或温度过高时停止任务
+//! 并发送 `device-health-alert` 事件，避免长时间挂机损伤手机。
+//!
+//! 通过 `settings.deviceHealth` 配置：
+//! - `enabled`: 是否启用监控（默认 true）
+//! - `pollIntervalSecs`: 检查间隔（默认 60 秒）
+//! - `minBattery`: 电量下限百分比（默认 15）
+//! - `maxTemperature`: 电池温度上限 °C（默认 45）
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{info, warn};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::types::{ControllerConfig, MaaState};
+use super::utils::adb_shell;
+use crate::i18n::tr;
+
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 60;
+const DEFAULT_MIN_BATTERY: u64 = 15;
+const DEFAULT_MAX_TEMPERATURE: u64 = 45;
+/// Android 热状态 THERMAL_STATUS_SEVERE，达到该级别时视为过热
+const THERMAL_STATUS_SEVERE: i32 = 3;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static POLL_INTERVAL_SECS: AtomicU64 = AtomicU64::new(DEFAULT_POLL_INTERVAL_SECS);
+static MIN_BATTERY: AtomicU64 = AtomicU64::new(DEFAULT_MIN_BATTERY);
+static MAX_TEMPERATURE: AtomicU64 = AtomicU64::new(DEFAULT_MAX_TEMPERATURE);
+
+/// 设备健康状态（无法解析的字段为 None）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceHealth {
+    /// 电量百分比
+    pub battery_level: Option<u32>,
+    /// 是否正在充电（任一电源接入）
+    pub charging: Option<bool>,
+    /// 电池温度（°C）
+    pub battery_temperature: Option<f32>,
+    /// Android 热状态（0 NONE ~ 6 SHUTDOWN）
+    pub thermal_status: Option<i32>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceHealthAlertEvent {
+    instance_id: String,
+    /// `low_battery` | `overheat`
+    reason: String,
+    health: DeviceHealth,
+}
+
+/// 读取 `settings.deviceHealth`（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let health = config.get("settings").and_then(|s| s.get("deviceHealth"));
+    let get_u64 = |key: &str, default: u64| {
+        health
+            .and_then(|h| h.get(key))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(default)
+    };
+    ENABLED.store(
+        health
+            .and_then(|h| h.get("enabled"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+        Ordering::Relaxed,
+    );
+    POLL_INTERVAL_SECS.store(
+        get_u64("pollIntervalSecs", DEFAULT_POLL_INTERVAL_SECS).max(5),
+        Ordering::Relaxed,
+    );
+    MIN_BATTERY.store(
+        get_u64("minBattery", DEFAULT_MIN_BATTERY),
+        Ordering::Relaxed,
+    );
+    MAX_TEMPERATURE.store(
+        get_u64("maxTemperature", DEFAULT_MAX_TEMPERATURE),
+        Ordering::Relaxed,
+    );
+}
+
+/// 解析 `dumpsys battery` 输出
+fn parse_battery(output: &str, health: &mut DeviceHealth) {
+    let mut level = None;
+    let mut scale = None;
+    let mut charging = false;
+    let mut has_power_field = false;
+    for line in output.lines() {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "level" => level = value.parse::<u32>().ok(),
+            "scale" => scale = value.parse::<u32>().ok(),
+            // 单位为 0.1°C
+            "temperature" => {
+                health.battery_temperature = value.parse::<f32>().ok().map(|t| t / 10.0)
+            }
+            "AC powered" | "USB powered" | "Wireless powered" | "Dock powered" => {
+                has_power_field = true;
+                charging |= value == "true";
+            }
+            _ => {}
+        }
+    }
+    health.battery_level = match (level, scale) {
+        (Some(level), Some(scale)) if scale > 0 && scale != 100 => Some(level * 100 / scale),
+        (level, _) => level,
+    };
+    health.charging = has_power_field.then_some(charging);
+}
+
+/// 解析 `dumpsys thermalservice` 输出中的 `Thermal Status: N`
+fn parse_thermal_status(output: &str) -> Option<i32> {
+    output.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("Thermal Status:")
+            .and_then(|v| v.trim().parse::<i32>().ok())
+    })
+}
+
+/// 读取设备健康状态
+pub fn query_health(adb_path: &str, address: &str) -> Result<DeviceHealth, String> {
+    let mut health = DeviceHealth::default();
+    let battery = adb_shell(adb_path, address, &["dumpsys", "battery"])?;
+    parse_battery(&battery, &mut health);
+    // 旧版 Android 没有 thermalservice，忽略失败
+    if let Ok(thermal) = adb_shell(adb_path, address, &["dumpsys", "thermalservice"]) {
+        health.thermal_status = parse_thermal_status(&thermal);
+    }
+    Ok(health)
+}
+
+/// 实例的 ADB 路径与地址
+fn adb_target(state: &MaaState, instance_id: &str) -> Option<(String, String)> {
+    match state
+        .instances
+        .get(instance_id)?
+        .controller_config
+        .as_ref()?
+    {
+        ControllerConfig::Adb {
+            adb_path, address, ..
+        } => Some((adb_path.clone(), address.clone())),
+        _ => None,
+    }
+}
+
+pub fn get_device_health_impl(state: &MaaState, instance_id: &str) -> Result<DeviceHealth, String> {
+    let (adb_path, address) =
+        adb_target(state, instance_id).ok_or_else(|| tr!("error.device_health.not_adb"))?;
+    query_health(&adb_path, &address)
+}
+
+/// 按阈值判断是否需要停止任务，返回原因
+fn check_thresholds(health: &DeviceHealth) -> Option<&'static str> {
+    let min_battery = MIN_BATTERY.load(Ordering::Relaxed) as u32;
+    if let Some(level) = health.battery_level {
+        if level < min_battery && health.charging != Some(true) {
+            return Some("low_battery");
+        }
+    }
+    let max_temperature = MAX_TEMPERATURE.load(Ordering::Relaxed) as f32;
+    if health
+        .battery_temperature
+        .is_some_and(|t| t > max_temperature)
+        || health
+            .thermal_status
+            .is_some_and(|s| s >= THERMAL_STATUS_SEVERE)
+    {
+        return Some("overheat");
+    }
+    None
+}
+
+/// 正在运行任务的 ADB 实例
+fn running_adb_instances(state: &MaaState) -> Vec<(String, String, String)> {
+    state
+        .instances
+        .iter()
+        .filter(|inst| inst.tasker.as_ref().is_some_and(|t| t.running()) && !inst.stop_in_progress)
+        .filter_map(|inst| match inst.controller_config.as_ref()? {
+            ControllerConfig::Adb {
+                adb_path, address, ..
+            } => Some((inst.key().clone(), adb_path.clone(), address.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn poll_once(app: &AppHandle, state: &MaaState) {
+    for (instance_id, adb_path, address) in running_adb_instances(state) {
+        let health = match query_health(&adb_path, &address) {
+            Ok(health) => health,
+            Err(e) => {
+                warn!("Failed to query device health of {}: {}", address, e);
+                continue;
+            }
+        };
+        let Some(reason) = check_thresholds(&health) else {
+            continue;
+        };
+        warn!(
+            "Device {} of instance {} unhealthy ({}): {:?}, stopping tasks",
+            address, instance_id, reason, health
+        );
+        match super::maa_core::stop_task_impl(state, &instance_id) {
+            Ok(()) => super::utils::emit_state_changed(app, &instance_id, "task-stopped"),
+            Err(e) => warn!("Failed to stop instance {}: {}", instance_id, e),
+        }
+        let event = DeviceHealthAlertEvent {
+            instance_id,
+            reason: reason.to_string(),
+            health,
+        };
+        if let Err(e) = app.emit("device-health-alert", event) {
+            warn!("Failed to emit device-health-alert: {}", e);
+        }
+    }
+}
+
+/// 启动后台监控线程（应用启动时调用一次）
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(
+            POLL_INTERVAL_SECS.load(Ordering::Relaxed),
+        ));
+        if !ENABLED.load(Ordering::Relaxed) {
+            continue;
+        }
+        if let Some(state) = app.try_state::<Arc<MaaState>>() {
+            poll_once(&app, &state);
+        }
+    });
+    info!("Device health monitor started");
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取 ADB 实例设备的电量与温度
+#[tauri::command]
+pub async fn maa_get_device_health(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+) -> Result<DeviceHealth, String> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || get_device_health_impl(&state, &instance_id))
+        .await
+        .map_err(|e| e.to_string())?
+}
@@ -1,20 +1,35 @@
 //! 下载相关命令
 //!
 //! 提供流式文件下载功能，支持进度回调和取消
+//!
+//! 下载相关设置位于 `settings.download`：
+//! - `userAgent`: 自定义 User-Agent（见 [`build_user_agent`]）
+//! - `headers`: 按主机名分组的请求头（如私有镜像的鉴权令牌），形如
+//!   `{ "mirror.example.com": { "Authorization": "..." } }`，只发往对应主机，
+//!   重定向到其他主机时不再携带（见 [`send_with_scoped_headers`]）
+//! - `maxKbps`: 下载限速（KB/s，0 或缺省为不限速），单次下载可通过 `max_kbps` 参数覆盖
+//! - `connections`: 分块下载的并发连接数（默认 4，1 为禁用）。服务器支持 Range 且文件
+//!   不小于 [`CHUNKED_MIN_SIZE`] 时按连接数切分，各分块独立重试后写入同一临时文件
 
 use log::{error, info, warn};
+use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 
-use tauri::Emitter;
-
-use super::types::GitHubRelease;
-use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use super::error::MxuResult;
+use super::system::{ensure_disk_space, get_arch, get_os};
+use super::types::{GitHubAsset, GitHubRelease, GitHubUpdateCheckResult};
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT, ACCEPT_RANGES, AUTHORIZATION, LOCATION, RANGE,
+    USER_AGENT,
+};
 
 use super::types::{DownloadProgressEvent, DownloadResult};
 use super::update::move_to_old_folder;
-use super::utils::build_user_agent;
+use super::utils::{build_user_agent, emit_download_progress, set_user_agent_override};
+use crate::i18n::tr;
 
 /// 进度上报任务的守卫，在函数任意返回路径上都能确保发送停止信号
 struct ProgressEmitterGuard(Option<tokio::sync::oneshot::Sender<()>>);
@@ -58,19 +73,228 @@ impl Drop for TempFileGuard {
 static DOWNLOAD_CANCELLED: AtomicBool = AtomicBool::new(false);
 /// 当前下载的 session ID，用于区分不同的下载任务
 static CURRENT_DOWNLOAD_SESSION: AtomicU64 = AtomicU64::new(0);
+/// 分块下载的默认并发连接数
+const DEFAULT_CONNECTIONS: u64 = 4;
+/// 分块下载的最大并发连接数
+const MAX_CONNECTIONS: u64 = 16;
+/// 启用分块下载的最小文件大小
+const CHUNKED_MIN_SIZE: u64 = 16 * 1024 * 1024;
+/// 单个分块的最大重试次数
+const CHUNK_MAX_RETRIES: u32 = 3;
+/// 下载时最多跟随的重定向次数
+const MAX_REDIRECTS: usize = 10;
+
+/// 分块下载的并发连接数（`settings.download.connections`）
+static CONNECTIONS: AtomicU64 = AtomicU64::new(DEFAULT_CONNECTIONS);
+/// 全局下载限速（KB/s，0 表示不限速）
+static MAX_KBPS: AtomicU64 = AtomicU64::new(0);
+/// 按主机名（小写）分组的请求头（`settings.download.headers`）
+static HOST_HEADERS: Mutex<Option<HashMap<String, HashMap<String, String>>>> = Mutex::new(None);
+
+/// 从配置读取下载设置（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let download = config.get("settings").and_then(|s| s.get("download"));
+
+    let user_agent = download
+        .and_then(|d| d.get("userAgent"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    set_user_agent_override(user_agent);
+
+    let headers = download
+        .and_then(|d| d.get("headers"))
+        .and_then(|v| v.as_object())
+        .map(|hosts| {
+            let mut scoped = HashMap::new();
+            for (host, value) in hosts {
+                match serde_json::from_value::<HashMap<String, String>>(value.clone()) {
+                    Ok(h) if !h.is_empty() => {
+                        scoped.insert(host.trim().to_ascii_lowercase(), h);
+                    }
+                    Ok(_) => {}
+                    // 旧版不分主机的写法会把令牌发给任意下载地址，不再生效
+                    Err(_) => warn!(
+                        "settings.download.headers.{} is not a per-host header map, ignored",
+                        host
+                    ),
+                }
+            }
+            scoped
+        })
+        .filter(|h| !h.is_empty());
+    *HOST_HEADERS.lock() = headers;
+
+    let max_kbps = download
+        .and_then(|d| d.get("maxKbps"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    MAX_KBPS.store(max_kbps, Ordering::Relaxed);
+
+    let connections = download
+        .and_then(|d| d.get("connections"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_CONNECTIONS)
+        .clamp(1, MAX_CONNECTIONS);
+    CONNECTIONS.store(connections, Ordering::Relaxed);
+}
 
-/// 根据版本号获取 GitHub Release URL
+/// 当前下载是否已被取消（取消标志或被新会话取代）
+fn is_cancelled(session_id: u64) -> bool {
+    DOWNLOAD_CANCELLED.load(Ordering::SeqCst)
+        || CURRENT_DOWNLOAD_SESSION.load(Ordering::SeqCst) != session_id
+}
+
+/// 令牌桶限速器：按设定速率补充令牌，允许最多 1 秒的突发，超额时休眠补足欠额
+struct RateLimiter {
+    bytes_per_sec: f64,
+    tokens: f64,
+    last_refill: tokio::time::Instant,
+}
+
+impl RateLimiter {
+    /// `max_kbps` 为 0 时返回 None（不限速）
+    fn new(max_kbps: u64) -> Option<Self> {
+        if max_kbps == 0 {
+            return None;
+        }
+        let bytes_per_sec = (max_kbps * 1024) as f64;
+        Some(Self {
+            bytes_per_sec,
+            tokens: bytes_per_sec,
+            last_refill: tokio::time::Instant::now(),
+        })
+    }
+
+    /// 消耗 `bytes` 个令牌，不足时等待
+    async fn consume(&mut self, bytes: u64) {
+        let now = tokio::time::Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.last_refill = now;
+        self.tokens = (self.tokens + elapsed * self.bytes_per_sec).min(self.bytes_per_sec);
+        self.tokens -= bytes as f64;
+        if self.tokens < 0.0 {
+            let wait = -self.tokens / self.bytes_per_sec;
+            tokio::time::sleep(std::time::Duration::from_secs_f64(wait)).await;
+        }
+    }
+}
+
+/// 两个地址是否指向同一主机（主机名与端口均相同）
+fn same_host(a: &reqwest::Url, b: &reqwest::Url) -> bool {
+    a.host_str()
+        .zip(b.host_str())
+        .is_some_and(|(x, y)| x.eq_ignore_ascii_case(y))
+        && a.port_or_known_default() == b.port_or_known_default()
+}
+
+/// 计算发往 `url` 的请求头：设置中与其主机名（或 `主机名:端口`）匹配的请求头，
+/// 加上本次下载附加的请求头（仅当 `url` 与原始地址 `origin` 同主机时，同名时覆盖），校验名称与取值
+fn build_download_headers(
+    url: &reqwest::Url,
+    origin: &reqwest::Url,
+    extra: Option<&HashMap<String, String>>,
+) -> Result<HeaderMap, String> {
+    let mut merged: HashMap<String, String> = HashMap::new();
+    if let Some(host) = url.host_str().map(str::to_ascii_lowercase) {
+        let guard = HOST_HEADERS.lock();
+        if let Some(hosts) = guard.as_ref() {
+            let with_port = url.port().map(|p| format!("{}:{}", host, p));
+            for key in std::iter::once(&host).chain(with_port.as_ref()) {
+                if let Some(h) = hosts.get(key) {
+                    merged.extend(h.iter().map(|(k, v)| (k.clone(), v.clone())));
+                }
+            }
+        }
+    }
+    if let Some(extra) = extra.filter(|_| same_host(url, origin)) {
+        merged.extend(extra.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    let mut headers = HeaderMap::with_capacity(merged.len());
+    for (name, value) in merged {
+        let header_name = HeaderName::from_bytes(name.trim().as_bytes())
+            .map_err(|_| tr!("error.download.header_name", name = name))?;
+        let header_value = HeaderValue::from_str(value.trim())
+            .map_err(|_| tr!("error.download.header_value", name = name))?;
+        headers.insert(header_name, header_value);
+    }
+    Ok(headers)
+}
+
+/// 发送 GET 请求并手动跟随重定向，每一跳按目标主机重新计算请求头，
+/// 避免鉴权令牌随跨主机重定向发往第三方。`client` 需禁用自动重定向。
 ///
-/// 使用 GitHub API 获取指定版本的 Release 信息，支持使用 GitHub PAT 和代理
-/// 解析 GitHub API 返回的 JSON 数据，找到与 target_version 匹配的 release，并返回 URL
-#[tauri::command]
-pub async fn get_github_release_by_version(
-    owner: String,
-    repo: String,
-    target_version: String,
+/// 返回最终响应及最后一跳使用的请求头（分块下载沿用）
+async fn send_with_scoped_headers(
+    client: &reqwest::Client,
+    url: &str,
+    extra: Option<&HashMap<String, String>>,
+) -> Result<(reqwest::Response, HeaderMap), String> {
+    use reqwest::StatusCode;
+
+    let origin =
+        reqwest::Url::parse(url).map_err(|e| tr!("error.download.invalid_url", error = e))?;
+    let mut current = origin.clone();
+    for _ in 0..=MAX_REDIRECTS {
+        let headers = build_download_headers(&current, &origin, extra)?;
+        if !headers.is_empty() {
+            // 只记录名称，避免把令牌写入日志
+            let names: Vec<&str> = headers.keys().map(|k| k.as_str()).collect();
+            info!(
+                "[下载] 附加请求头 ({}): {}",
+                current.host_str().unwrap_or_default(),
+                names.join(", ")
+            );
+        }
+
+        let response = client
+            .get(current.clone())
+            .headers(headers.clone())
+            .send()
+            .await
+            .map_err(|e| tr!("error.download.request", error = e))?;
+
+        let is_redirect = matches!(
+            response.status(),
+            StatusCode::MOVED_PERMANENTLY
+                | StatusCode::FOUND
+                | StatusCode::SEE_OTHER
+                | StatusCode::TEMPORARY_REDIRECT
+                | StatusCode::PERMANENT_REDIRECT
+        );
+        if !is_redirect {
+            return Ok((response, headers));
+        }
+
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| tr!("error.download.redirect_no_location"))?;
+        let next = current
+            .join(location)
+            .map_err(|e| tr!("error.download.invalid_url", error = e))?;
+        if !same_host(&current, &next) {
+            info!(
+                "[下载] 跨主机重定向: {} -> {}",
+                current.host_str().unwrap_or_default(),
+                next.host_str().unwrap_or_default()
+            );
+        }
+        current = next;
+    }
+    Err(tr!("error.download.too_many_redirects"))
+}
+
+/// 请求 GitHub Releases API，返回仓库最近的 Release 列表
+///
+/// 支持使用 GitHub PAT 和代理；命中 API 限流时在错误信息中附带重置时间
+async fn fetch_github_releases(
+    owner: &str,
+    repo: &str,
     github_pat: Option<String>,
     proxy_url: Option<String>,
-) -> Result<Option<GitHubRelease>, String> {
+) -> Result<Vec<GitHubRelease>, String> {
     let url = format!("https://api.github.com/repos/{}/{}/releases", owner, repo);
 
     // 构造请求头
@@ -86,10 +310,7 @@ pub async fn get_github_release_by_version(
             info!("[检查更新] 目标: {}", url);
             let reqwest_proxy = reqwest::Proxy::all(proxy).map_err(|e| {
                 error!("代理配置失败: {} (代理地址: {})", e, proxy);
-                format!(
-                    "代理配置失败: {}。请检查代理格式是否正确（支持 http:// 或 socks5://）",
-                    e
-                )
+                tr!("error.download.proxy", error = e)
             })?;
             client_builder = client_builder.proxy(reqwest_proxy);
         }
@@ -97,7 +318,7 @@ pub async fn get_github_release_by_version(
 
     let client = client_builder
         .build()
-        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+        .map_err(|e| tr!("error.download.client", error = e))?;
 
     let mut request = client
         .get(&url)
@@ -114,16 +335,54 @@ pub async fn get_github_release_by_version(
     let response = request
         .send()
         .await
-        .map_err(|e| format!("请求失败: {}", e))?;
+        .map_err(|e| tr!("error.download.request", error = e))?;
 
     if !response.status().is_success() {
-        return Err(format!("GitHub API 错误: {}", response.status()));
+        let rate_limited = response
+            .headers()
+            .get("x-ratelimit-remaining")
+            .and_then(|v| v.to_str().ok())
+            == Some("0");
+        if rate_limited {
+            let reset_at = response
+                .headers()
+                .get("x-ratelimit-reset")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<i64>().ok())
+                .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                .map(|dt| {
+                    dt.with_timezone(&chrono::Local)
+                        .format("%H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_else(|| "unknown".to_string());
+            return Err(tr!("error.download.github_rate_limit", reset_at = reset_at));
+        }
+        return Err(tr!(
+            "error.download.github_status",
+            status = response.status()
+        ));
     }
 
-    let releases: Vec<GitHubRelease> = response
+    response
         .json()
         .await
-        .map_err(|e| format!("解析 JSON 失败: {}", e))?;
+        .map_err(|e| tr!("error.download.parse_json", error = e))
+}
+
+/// 根据版本号获取 GitHub Release URL
+///
+/// 使用 GitHub API 获取指定版本的 Release 信息，支持使用 GitHub PAT 和代理
+/// 解析 GitHub API 返回的 JSON 数据，找到与 target_version 匹配的 release，并返回 URL
+#[tauri::command]
+pub async fn get_github_release_by_version(
+    owner: String,
+    repo: String,
+    target_version: String,
+    github_pat: Option<String>,
+    proxy_url: Option<String>,
+) -> Result<Option<GitHubRelease>, String> {
+    let releases = fetch_github_releases(&owner, &repo, github_pat, proxy_url).await?;
 
     let normalize = |v: &str| {
         v.trim_start_matches(|c| c == 'v' || c == 'V')
@@ -144,6 +403,135 @@ pub async fn get_github_release_by_version(
     Ok(None)
 }
 
+/// 解析 tag 为 semver 版本（容忍 v 前缀）
+fn parse_tag_version(tag: &str) -> Option<semver::Version> {
+    semver::Version::parse(tag.trim().trim_start_matches(['v', 'V'])).ok()
+}
+
+/// 判断 Release 是否属于指定更新通道
+///
+/// - stable: 非 prerelease，且 tag 不带预发布标签
+/// - beta: 在 stable 基础上额外接受 beta / rc 预发布版本
+fn release_matches_channel(release: &GitHubRelease, channel: &str) -> bool {
+    let Some(version) = parse_tag_version(&release.tag_name) else {
+        return false;
+    };
+    let is_stable = !release.prerelease && version.pre.is_empty();
+    match channel {
+        "beta" => {
+            is_stable
+                || version
+                    .pre
+                    .as_str()
+                    .split('.')
+                    .any(|tag| tag == "beta" || tag == "rc")
+        }
+        _ => is_stable,
+    }
+}
+
+/// 按 `-` `_` `.` 与空格拆分文件名
+fn name_tokens(name: &str) -> Vec<String> {
+    name.to_lowercase()
+        .split(['-', '_', '.', ' '])
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 文件名是否按分隔符完整包含某个别名（如 `darwin` 不会匹配 `win`）
+fn has_alias_token(tokens: &[String], alias: &str) -> bool {
+    let alias = name_tokens(alias);
+    !alias.is_empty() && tokens.windows(alias.len()).any(|w| w == alias.as_slice())
+}
+
+/// 资产文件名是否同时匹配 OS 与架构别名
+fn asset_matches(name: &str, os_aliases: &[&str], arch_aliases: &[&str]) -> bool {
+    let tokens = name_tokens(name);
+    os_aliases.iter().any(|a| has_alias_token(&tokens, a))
+        && arch_aliases.iter().any(|a| has_alias_token(&tokens, a))
+}
+
+/// 按当前系统和架构筛选 Release 资产（文件名按分隔符匹配 OS / 架构别名）
+fn filter_assets_for_platform(assets: Vec<GitHubAsset>) -> Vec<GitHubAsset> {
+    let os_aliases: &[&str] = match get_os().as_str() {
+        "windows" => &["win", "windows", "win32", "win64"],
+        "macos" => &["macos", "mac", "darwin", "osx"],
+        "linux" => &["linux"],
+        _ => &[],
+    };
+    let arch_aliases: &[&str] = match get_arch().as_str() {
+        "x86_64" => &["x86_64", "x64", "amd64", "x86-64"],
+        "aarch64" => &["aarch64", "arm64"],
+        _ => &[],
+    };
+
+    assets
+        .into_iter()
+        .filter(|asset| asset_matches(&asset.name, os_aliases, arch_aliases))
+        .collect()
+}
+
+/// 检查 GitHub Release 更新
+///
+/// `repo` 格式为 `owner/repo`；`channel` 为 stable（默认）或 beta。
+/// 在后端完成请求、semver 比较与资产筛选，避免前端直接访问 GitHub API 遇到 CORS 与限流。
+#[tauri::command]
+pub async fn check_update(
+    repo: String,
+    channel: Option<String>,
+    current_version: String,
+    github_pat: Option<String>,
+    proxy_url: Option<String>,
+) -> Result<GitHubUpdateCheckResult, String> {
+    let (owner, name) = repo
+        .trim()
+        .split_once('/')
+        .filter(|(o, n)| !o.is_empty() && !n.is_empty())
+        .ok_or_else(|| tr!("error.download.invalid_repo", repo = repo))?;
+    let channel = channel.unwrap_or_else(|| "stable".to_string());
+
+    let releases = fetch_github_releases(owner, name, github_pat, proxy_url).await?;
+
+    let latest = releases
+        .into_iter()
+        .filter(|r| release_matches_channel(r, &channel))
+        .filter_map(|r| parse_tag_version(&r.tag_name).map(|v| (v, r)))
+        .max_by(|(a, _), (b, _)| a.cmp(b));
+
+    let Some((latest_version, release)) = latest else {
+        warn!("[检查更新] {} 通道 {} 下没有可用的 Release", repo, channel);
+        return Ok(GitHubUpdateCheckResult {
+            has_update: false,
+            current_version,
+            latest_version: None,
+            tag_name: None,
+            release_note: None,
+            assets: Vec::new(),
+        });
+    };
+
+    let has_update = match parse_tag_version(&current_version) {
+        Some(current) => latest_version > current,
+        // 当前版本无法解析（如 DEBUG_VERSION）时不提示更新
+        None => false,
+    };
+
+    info!(
+        "[检查更新] {}: 当前 {}, 最新 {} ({}), 有更新={}",
+        repo, current_version, latest_version, channel, has_update
+    );
+
+    Ok(GitHubUpdateCheckResult {
+        has_update,
+        current_version,
+        latest_version: Some(latest_version.to_string()),
+        tag_name: Some(release.tag_name),
+        release_note: release.body,
+        assets: filter_assets_for_platform(release.assets),
+    })
+}
+
 /// 流式下载文件，支持进度回调和取消
 ///
 /// 使用 reqwest 进行流式下载，直接写入文件而不经过内存缓冲，
@@ -152,6 +540,10 @@ pub async fn get_github_release_by_version(
 /// 返回 DownloadResult，包含 session_id 和实际保存路径
 /// 如果检测到重定向后的 URL 或 Content-Disposition 包含正确的文件名，
 /// 会使用该文件名保存（替换原始 save_path 的文件名部分）
+///
+/// `headers` 为本次下载附加的请求头（如 Authorization、Referer），与设置中同主机的请求头合并，
+/// 同名时覆盖，且只发往 `url` 所在主机；
+/// `max_kbps` 覆盖全局限速（0 表示本次不限速，None 使用全局设置）
 #[tauri::command]
 pub async fn download_file(
     app: tauri::AppHandle,
@@ -159,7 +551,9 @@ pub async fn download_file(
     save_path: String,
     total_size: Option<u64>,
     proxy_url: Option<String>,
-) -> Result<DownloadResult, String> {
+    headers: Option<HashMap<String, String>>,
+    max_kbps: Option<u64>,
+) -> MxuResult<DownloadResult> {
     use futures_util::StreamExt;
     use std::io::Write;
     use tokio::time::{sleep, Duration};
@@ -177,14 +571,16 @@ pub async fn download_file(
 
     // 确保目录存在
     if let Some(parent) = save_path_obj.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| format!("无法创建目录: {}", e))?;
+        std::fs::create_dir_all(parent).map_err(|e| tr!("error.download.create_dir", error = e))?;
     }
 
     // 构建 HTTP 客户端和请求
     let mut client_builder = reqwest::Client::builder()
         .user_agent(build_user_agent())
         .timeout(std::time::Duration::from_secs(600)) // 10 分钟超时，足够下载大文件但防止无限挂起
-        .connect_timeout(std::time::Duration::from_secs(10));
+        .connect_timeout(std::time::Duration::from_secs(10))
+        // 由 send_with_scoped_headers 跟随重定向，逐跳决定是否携带请求头
+        .redirect(reqwest::redirect::Policy::none());
 
     // 配置代理（如果提供）
     if let Some(ref proxy) = proxy_url {
@@ -193,10 +589,7 @@ pub async fn download_file(
             info!("[下载] 目标: {}", url);
             let reqwest_proxy = reqwest::Proxy::all(proxy).map_err(|e| {
                 error!("代理配置失败: {} (代理地址: {})", e, proxy);
-                format!(
-                    "代理配置失败: {}。请检查代理格式是否正确（支持 http:// 或 socks5://）",
-                    e
-                )
+                tr!("error.download.proxy", error = e)
             })?;
             client_builder = client_builder.proxy(reqwest_proxy);
         } else {
@@ -208,18 +601,17 @@ pub async fn download_file(
 
     let client = client_builder
         .build()
-        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+        .map_err(|e| tr!("error.download.client", error = e))?;
 
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("请求失败: {}", e))?;
+    let (response, request_headers) =
+        send_with_scoped_headers(&client, &url, headers.as_ref()).await?;
 
     if !response.status().is_success() {
-        return Err(format!("HTTP 错误: {}", response.status()));
+        return Err(tr!("error.download.http_status", status = response.status()).into());
     }
 
+    let final_url = response.url().clone();
+
     // 尝试从 Content-Disposition header 或最终 URL 提取文件名
     let detected_filename = extract_filename_from_response(&response);
     if let Some(ref name) = detected_filename {
@@ -248,30 +640,19 @@ pub async fn download_file(
     let content_length = response.content_length();
     let total = total_size.or(content_length).unwrap_or(0);
 
-    // 有界通道将网络读取与磁盘写入解耦：
-    // - 下载循环纯异步，不阻塞 runtime，可全速消费 TCP 流
-    // - 写入线程用同步 BufWriter，单线程从头跑到尾，避免 tokio::fs 逐次 spawn_blocking 的调度开销
-    let (write_tx, write_rx) = tokio::sync::mpsc::channel::<bytes::Bytes>(64);
+    // 预检磁盘空间：临时文件与最终文件位于同一目录，按总大小检查即可
+    if total > 0 {
+        ensure_disk_space(actual_save_path_obj, total)?;
+    }
 
-    let temp_path_for_writer = temp_path.clone();
-    let write_handle = tokio::task::spawn_blocking(move || -> Result<(), String> {
-        let file = std::fs::File::create(&temp_path_for_writer)
-            .map_err(|e| format!("无法创建文件: {}", e))?;
-        let mut writer = std::io::BufWriter::with_capacity(512 * 1024, file);
-        let mut write_rx = write_rx;
-        while let Some(chunk) = write_rx.blocking_recv() {
-            writer
-                .write_all(&chunk)
-                .map_err(|e| format!("写入文件失败: {}", e))?;
-        }
-        writer
-            .flush()
-            .map_err(|e| format!("刷新写入缓冲区失败: {}", e))?;
-        writer
-            .get_ref()
-            .sync_all()
-            .map_err(|e| format!("同步文件失败: {}", e))?;
-        Ok(())
+    // 服务器支持 Range 且文件足够大时改用多连接分块下载
+    let accepts_ranges = response
+        .headers()
+        .get(ACCEPT_RANGES)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.eq_ignore_ascii_case("bytes"));
+    let chunked_len = content_length.filter(|&len| {
+        accepts_ranges && len >= CHUNKED_MIN_SIZE && CONNECTIONS.load(Ordering::Relaxed) > 1
     });
 
     // 共享下载字节计数，用于独立的进度上报任务
@@ -319,8 +700,8 @@ pub async fn download_file(
                         0.0
                     };
 
-                    let _ = app_for_emitter.emit(
-                        "download-progress",
+                    emit_download_progress(
+                        &app_for_emitter,
                         DownloadProgressEvent {
                             session_id,
                             downloaded_size: downloaded,
@@ -340,69 +721,130 @@ pub async fn download_file(
     // 说明：downloaded_shared 仅用于进度上报的近实时采样，对 UI 来说允许“最终一致”，
     // 因此这里使用 Relaxed 内存序即可，避免在热路径上引入不必要的全序栅栏。
 
-    // 流式下载
-    let mut stream = response.bytes_stream();
-    let mut downloaded: u64 = 0;
-    let mut download_err: Option<String> = None;
+    let max_kbps = max_kbps.unwrap_or_else(|| MAX_KBPS.load(Ordering::Relaxed));
+    let mut limiter = RateLimiter::new(max_kbps);
+    if limiter.is_some() {
+        info!("[下载] 限速 {} KB/s (session {})", max_kbps, session_id);
+    }
 
-    while let Some(chunk) = stream.next().await {
-        if DOWNLOAD_CANCELLED.load(Ordering::SeqCst)
-            || CURRENT_DOWNLOAD_SESSION.load(Ordering::SeqCst) != session_id
-        {
-            info!("download_file cancelled (session {})", session_id);
-            download_err = Some("下载已取消".to_string());
-            break;
-        }
+    let downloaded = if let Some(len) = chunked_len {
+        drop(response);
+        let connections = CONNECTIONS.load(Ordering::Relaxed);
+        info!(
+            "[下载] 分块下载: {} 字节, {} 个连接 (session {})",
+            len, connections, session_id
+        );
+        download_chunked(
+            ChunkedDownload {
+                client: client.clone(),
+                url: final_url,
+                headers: request_headers,
+                temp_path: temp_path.clone(),
+                total: len,
+                connections,
+                session_id,
+                downloaded: downloaded_shared.clone(),
+            },
+            limiter,
+        )
+        .await?;
+        len
+    } else {
+        // 有界通道将网络读取与磁盘写入解耦：
+        // - 下载循环纯异步，不阻塞 runtime，可全速消费 TCP 流
+        // - 写入线程用同步 BufWriter，单线程从头跑到尾，避免 tokio::fs 逐次 spawn_blocking 的调度开销
+        let (write_tx, write_rx) = tokio::sync::mpsc::channel::<bytes::Bytes>(64);
+
+        let temp_path_for_writer = temp_path.clone();
+        let write_handle = tokio::task::spawn_blocking(move || -> Result<(), String> {
+            let file = std::fs::File::create(&temp_path_for_writer)
+                .map_err(|e| tr!("error.download.create_file", error = e))?;
+            let mut writer = std::io::BufWriter::with_capacity(512 * 1024, file);
+            let mut write_rx = write_rx;
+            while let Some(chunk) = write_rx.blocking_recv() {
+                writer
+                    .write_all(&chunk)
+                    .map_err(|e| tr!("error.download.write_file", error = e))?;
+            }
+            writer
+                .flush()
+                .map_err(|e| tr!("error.download.flush", error = e))?;
+            writer
+                .get_ref()
+                .sync_all()
+                .map_err(|e| tr!("error.download.sync", error = e))?;
+            Ok(())
+        });
+
+        // 流式下载
+        let mut stream = response.bytes_stream();
+        let mut downloaded: u64 = 0;
+        let mut download_err: Option<String> = None;
+
+        while let Some(chunk) = stream.next().await {
+            if DOWNLOAD_CANCELLED.load(Ordering::SeqCst)
+                || CURRENT_DOWNLOAD_SESSION.load(Ordering::SeqCst) != session_id
+            {
+                info!("download_file cancelled (session {})", session_id);
+                download_err = Some(tr!("error.download.cancelled"));
+                break;
+            }
 
-        let chunk = match chunk {
-            Ok(c) => c,
-            Err(e) => {
-                download_err = Some(format!("下载数据失败: {}", e));
+            let chunk = match chunk {
+                Ok(c) => c,
+                Err(e) => {
+                    download_err = Some(tr!("error.download.read_body", error = e));
+                    break;
+                }
+            };
+
+            let len = chunk.len() as u64;
+            if write_tx.send(chunk).await.is_err() {
+                download_err = Some(tr!("error.download.writer_exited"));
                 break;
             }
-        };
+            downloaded += len;
+            downloaded_shared.store(downloaded, Ordering::Relaxed);
 
-        let len = chunk.len() as u64;
-        if write_tx.send(chunk).await.is_err() {
-            download_err = Some("磁盘写入线程异常退出".to_string());
-            break;
+            if let Some(limiter) = limiter.as_mut() {
+                limiter.consume(len).await;
+            }
         }
-        downloaded += len;
-        downloaded_shared.store(downloaded, Ordering::Relaxed);
-    }
 
-    // 最后再检查一次取消标志
-    if download_err.is_none()
-        && (DOWNLOAD_CANCELLED.load(Ordering::SeqCst)
-            || CURRENT_DOWNLOAD_SESSION.load(Ordering::SeqCst) != session_id)
-    {
-        info!(
-            "download_file cancelled before finalization (session {})",
-            session_id
-        );
-        download_err = Some("下载已取消".to_string());
-    }
+        // 最后再检查一次取消标志
+        if download_err.is_none()
+            && (DOWNLOAD_CANCELLED.load(Ordering::SeqCst)
+                || CURRENT_DOWNLOAD_SESSION.load(Ordering::SeqCst) != session_id)
+        {
+            info!(
+                "download_file cancelled before finalization (session {})",
+                session_id
+            );
+            download_err = Some(tr!("error.download.cancelled"));
+        }
 
-    // 关闭发送端，通知写入线程所有数据已发送完毕
-    drop(write_tx);
+        // 关闭发送端，通知写入线程所有数据已发送完毕
+        drop(write_tx);
 
-    // 等待写入线程完成，确保文件句柄关闭后再进行重命名等后续操作
-    let write_thread_result = write_handle
-        .await
-        .map_err(|e| format!("写入任务异常: {}", e))?;
+        // 等待写入线程完成，确保文件句柄关闭后再进行重命名等后续操作
+        let write_thread_result = write_handle
+            .await
+            .map_err(|e| tr!("error.download.writer_panicked", error = e))?;
 
-    if let Some(err) = download_err {
-        // 写入线程通常持有更具体的 I/O 错误信息（如磁盘满），优先返回
-        if let Err(write_err) = write_thread_result {
-            return Err(write_err);
+        if let Some(err) = download_err {
+            // 写入线程通常持有更具体的 I/O 错误信息（如磁盘满），优先返回
+            if let Err(write_err) = write_thread_result {
+                return Err(write_err.into());
+            }
+            return Err(err.into());
         }
-        return Err(err);
-    }
-    write_thread_result?;
+        write_thread_result?;
+        downloaded
+    };
 
     // 发送最终进度
-    let _ = app.emit(
-        "download-progress",
+    emit_download_progress(
+        &app,
         DownloadProgressEvent {
             session_id,
             downloaded_size: downloaded,
@@ -420,7 +862,7 @@ pub async fn download_file(
     // 重命名临时文件（使用异步版本避免阻塞 runtime 线程）
     tokio::fs::rename(&temp_path, &actual_save_path)
         .await
-        .map_err(|e| format!("重命名文件失败: {}", e))?;
+        .map_err(|e| tr!("error.download.rename", error = e))?;
     temp_guard.disarm();
 
     info!(
@@ -438,6 +880,182 @@ pub async fn download_file(
     })
 }
 
+/// 分块下载参数
+struct ChunkedDownload {
+    client: reqwest::Client,
+    url: reqwest::Url,
+    headers: HeaderMap,
+    temp_path: String,
+    total: u64,
+    connections: u64,
+    session_id: u64,
+    /// 已下载字节数（所有分块累加，供进度上报）
+    downloaded: Arc<AtomicU64>,
+}
+
+/// 多连接分块下载：预分配临时文件，按连接数切分 Range 并发下载，
+/// 每个分块由独立的写入线程写到对应偏移，失败时从断点重试
+async fn download_chunked(
+    task: ChunkedDownload,
+    limiter: Option<RateLimiter>,
+) -> Result<(), String> {
+    let file = std::fs::File::create(&task.temp_path)
+        .map_err(|e| tr!("error.download.create_file", error = e))?;
+    file.set_len(task.total)
+        .map_err(|e| tr!("error.download.preallocate", error = e))?;
+    drop(file);
+
+    let task = Arc::new(task);
+    let limiter = limiter.map(|l| Arc::new(tokio::sync::Mutex::new(l)));
+    let chunk_size = task.total.div_ceil(task.connections);
+
+    let mut chunks = tokio::task::JoinSet::new();
+    let mut start = 0;
+    while start < task.total {
+        let end = (start + chunk_size).min(task.total) - 1;
+        chunks.spawn(download_range(task.clone(), start, end, limiter.clone()));
+        start = end + 1;
+    }
+
+    while let Some(joined) = chunks.join_next().await {
+        let result = joined
+            .map_err(|e| tr!("error.download.chunk_task_panicked", error = e))
+            .and_then(|r| r);
+        if let Err(e) = result {
+            // 等待其余分块退出，确保写入线程释放文件后再由调用方清理临时文件
+            chunks.abort_all();
+            while chunks.join_next().await.is_some() {}
+            return Err(e);
+        }
+    }
+
+    if is_cancelled(task.session_id) {
+        info!(
+            "download_file cancelled before finalization (session {})",
+            task.session_id
+        );
+        return Err(tr!("error.download.cancelled"));
+    }
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .open(&task.temp_path)
+        .and_then(|f| f.sync_all())
+        .map_err(|e| tr!("error.download.sync", error = e))
+}
+
+/// 下载 `[start, end]` 区间（含两端），连接中断时从已写入位置续传，最多重试 [`CHUNK_MAX_RETRIES`] 次
+async fn download_range(
+    task: Arc<ChunkedDownload>,
+    start: u64,
+    end: u64,
+    limiter: Option<Arc<tokio::sync::Mutex<RateLimiter>>>,
+) -> Result<(), String> {
+    use futures_util::StreamExt;
+    use std::io::{Seek, SeekFrom, Write};
+
+    // 分块内的数据严格顺序到达（重试从断点续传），写入线程只需在开头定位一次
+    let (write_tx, write_rx) = tokio::sync::mpsc::channel::<bytes::Bytes>(16);
+    let temp_path = task.temp_path.clone();
+    let write_handle = tokio::task::spawn_blocking(move || -> Result<(), String> {
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(&temp_path)
+            .map_err(|e| tr!("error.download.open_file", error = e))?;
+        file.seek(SeekFrom::Start(start))
+            .map_err(|e| tr!("error.download.seek", error = e))?;
+        let mut writer = std::io::BufWriter::with_capacity(256 * 1024, file);
+        let mut write_rx = write_rx;
+        while let Some(chunk) = write_rx.blocking_recv() {
+            writer
+                .write_all(&chunk)
+                .map_err(|e| tr!("error.download.write_file", error = e))?;
+        }
+        writer
+            .flush()
+            .map_err(|e| tr!("error.download.flush", error = e))
+    });
+
+    let mut offset = start;
+    let mut attempt = 0u32;
+    let download_result = loop {
+        let result: Result<(), String> = async {
+            let response = task
+                .client
+                .get(task.url.clone())
+                .headers(task.headers.clone())
+                .header(RANGE, format!("bytes={}-{}", offset, end))
+                .send()
+                .await
+                .map_err(|e| tr!("error.download.request", error = e))?;
+            if response.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+                return Err(tr!(
+                    "error.download.no_partial_content",
+                    status = response.status()
+                ));
+            }
+
+            let mut stream = response.bytes_stream();
+            while let Some(chunk) = stream.next().await {
+                if is_cancelled(task.session_id) {
+                    return Err(tr!("error.download.cancelled"));
+                }
+                let mut chunk = chunk.map_err(|e| tr!("error.download.read_body", error = e))?;
+                let remaining = end + 1 - offset;
+                if chunk.len() as u64 > remaining {
+                    chunk.truncate(remaining as usize);
+                }
+
+                let len = chunk.len() as u64;
+                write_tx
+                    .send(chunk)
+                    .await
+                    .map_err(|_| tr!("error.download.writer_exited"))?;
+                offset += len;
+                task.downloaded.fetch_add(len, Ordering::Relaxed);
+
+                if let Some(limiter) = limiter.as_ref() {
+                    limiter.lock().await.consume(len).await;
+                }
+                if offset > end {
+                    return Ok(());
+                }
+            }
+            Err(tr!("error.download.connection_closed"))
+        }
+        .await;
+
+        match result {
+            Ok(()) => break Ok(()),
+            Err(e) if is_cancelled(task.session_id) => break Err(e),
+            Err(e) if attempt >= CHUNK_MAX_RETRIES => {
+                break Err(tr!(
+                    "error.download.chunk_failed",
+                    start = start,
+                    end = end,
+                    error = e
+                ));
+            }
+            Err(e) => {
+                attempt += 1;
+                warn!(
+                    "[下载] 分块 {}-{} 第 {} 次重试（已完成至 {}）: {}",
+                    start, end, attempt, offset, e
+                );
+                tokio::time::sleep(std::time::Duration::from_secs(attempt as u64)).await;
+            }
+        }
+    };
+
+    drop(write_tx);
+    let write_result = write_handle
+        .await
+        .map_err(|e| tr!("error.download.writer_panicked", error = e))?;
+    // 写入线程通常持有更具体的 I/O 错误信息（如磁盘满），优先返回
+    write_result?;
+    download_result
+}
+
 /// 取消下载
 #[tauri::command]
 pub fn cancel_download(save_path: String) -> Result<(), String> {
@@ -579,3 +1197,26 @@ fn parse_content_disposition(header: &str) -> Option<String> {
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOWS: &[&str] = &["win", "windows", "win32", "win64"];
+    const MACOS: &[&str] = &["macos", "mac", "darwin", "osx"];
+    const X64: &[&str] = &["x86_64", "x64", "amd64", "x86-64"];
+
+    #[test]
+    fn darwin_asset_does_not_match_windows() {
+        assert!(!asset_matches("MXU-darwin-x86_64.tar.gz", WINDOWS, X64));
+        assert!(asset_matches("MXU-darwin-x86_64.tar.gz", MACOS, X64));
+    }
+
+    #[test]
+    fn matches_on_token_boundaries() {
+        assert!(asset_matches("MXU-win-x64.zip", WINDOWS, X64));
+        assert!(asset_matches("MXU_Windows_x86-64_v1.2.0.zip", WINDOWS, X64));
+        assert!(!asset_matches("MXU-winter-x64.zip", WINDOWS, X64));
+        assert!(!asset_matches("MXU-win-arm64.zip", WINDOWS, X64));
+    }
+}
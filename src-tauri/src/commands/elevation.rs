@@ -0,0 +1,117 @@
+//! Win32 目标窗口权限检测
+//!
+//! 目标窗口属于管理员权限进程而 MXU 未提升时，UIPI 会静默丢弃发送给它的输入，
+//! 表现为"能截图但点击无效"。连接 Win32 控制器前检测这一情况，按 `settings.win32ElevationPolicy` 处理：
+//! - `warn`（默认）：照常连接，并发送 `win32-elevation-warning` 事件提示用户
+//! - `block`：拒绝连接，返回 `TARGET_ELEVATED` 错误
+//! - `restart`：自动以管理员身份重启 MXU
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use log::{info, warn};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use super::error::{ErrorCode, MxuError};
+use crate::i18n::tr;
+
+/// 检测到提升的目标进程时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[repr(u8)]
+pub enum ElevationPolicy {
+    Warn = 0,
+    Block = 1,
+    Restart = 2,
+}
+
+static POLICY: AtomicU8 = AtomicU8::new(ElevationPolicy::Warn as u8);
+
+fn policy() -> ElevationPolicy {
+    match POLICY.load(Ordering::Relaxed) {
+        1 => ElevationPolicy::Block,
+        2 => ElevationPolicy::Restart,
+        _ => ElevationPolicy::Warn,
+    }
+}
+
+/// 读取 `settings.win32ElevationPolicy`（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let policy = match config
+        .get("settings")
+        .and_then(|s| s.get("win32ElevationPolicy"))
+        .and_then(|v| v.as_str())
+    {
+        Some("block") => ElevationPolicy::Block,
+        Some("restart") => ElevationPolicy::Restart,
+        _ => ElevationPolicy::Warn,
+    };
+    POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// 目标进程权限警告
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElevationWarning {
+    pub instance_id: String,
+    pub handle: u64,
+    pub pid: u32,
+    pub process_name: Option<String>,
+    pub policy: ElevationPolicy,
+}
+
+/// 目标窗口属于提升进程且 MXU 未提升时返回其进程信息
+fn elevated_target(handle: u64) -> Option<(u32, Option<String>)> {
+    if super::system::is_elevated() {
+        return None;
+    }
+    let details = super::system::query_window_details(handle);
+    let pid = details.pid?;
+    super::system::is_process_elevated(pid)
+        .filter(|elevated| *elevated)
+        .map(|_| (pid, details.process_name))
+}
+
+/// 连接 Win32 控制器前检查目标窗口权限，按策略警告、拒绝或重启
+pub fn check_before_connect(
+    app: &AppHandle,
+    instance_id: &str,
+    handle: u64,
+) -> Result<(), MxuError> {
+    let Some((pid, process_name)) = elevated_target(handle) else {
+        return Ok(());
+    };
+    let policy = policy();
+    warn!(
+        "Target window {:#x} (pid {}, {:?}) is elevated but MXU is not, policy: {:?}",
+        handle, pid, process_name, policy
+    );
+    let warning = ElevationWarning {
+        instance_id: instance_id.to_string(),
+        handle,
+        pid,
+        process_name: process_name.clone(),
+        policy,
+    };
+    if let Err(e) = app.emit("win32-elevation-warning", warning) {
+        warn!("Failed to emit win32-elevation-warning: {}", e);
+    }
+
+    let context = process_name.unwrap_or_else(|| format!("pid {}", pid));
+    match policy {
+        ElevationPolicy::Warn => Ok(()),
+        ElevationPolicy::Block => Err(MxuError::new(
+            ErrorCode::TargetElevated,
+            tr!("error.elevation.target_elevated"),
+        )
+        .with_context(context)),
+        ElevationPolicy::Restart => {
+            info!("Restarting as admin to control elevated target {}", context);
+            super::system::restart_as_admin_impl(app, true)?;
+            Err(
+                MxuError::new(ErrorCode::TargetElevated, tr!("error.elevation.restarting"))
+                    .with_context(context),
+            )
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! 结构化命令错误
+//!
+//! `MxuError` 序列化为 `{ code, message, context, hint, details }`，前端可按 `code` 分支并显示本地化提示。
+//! 错误码在出错处确定（如 [`MxuError::instance_not_found`]），实现函数返回 [`MxuResult`]；
+//! 仍返回 `Result<_, String>` 的函数经 `?` 转换为 [`ErrorCode::Unknown`]。
+//!
+//! 适用范围：实例、控制器、资源、任务与 Agent 相关命令（`maa_core`、`maa_agent`、`reconnect`、
+//! `authoring`、`input_recorder`、`gamepad`、`run_options`）及长路径检查，
+//! 即前端需要按错误类型给出恢复建议的命令；下载与全量更新的磁盘空间预检也返回 `MxuError`。
+//! 文件、配置等其余命令仍返回 `String`，
+//! 前端直接显示消息文本。
+//! `MxuError` 也可经 `?` 转回 `String`，供 HTTP 处理器等只需要消息的调用方使用。
+
+use serde::Serialize;
+
+use crate::i18n;
+
+/// 错误码
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// MaaFramework 未加载或未初始化
+    NotInitialized,
+    /// 实例不存在（未调用 maa_create_instance 或已销毁）
+    InstanceNotFound,
+    /// 控制器未连接
+    ControllerNotConnected,
+    /// 资源未加载
+    ResourceNotLoaded,
+    /// 目标窗口属于管理员权限进程，而 MXU 未以管理员身份运行（输入会被系统拦截）
+    TargetElevated,
+    /// Agent 启动或连接失败
+    AgentFailed,
+    /// 设备未授权 USB 调试（ADB 显示 unauthorized）
+    AdbUnauthorized,
+    /// 目标卷剩余空间不足（`details` 含 `path`、`requiredBytes`、`availableBytes`）
+    InsufficientDiskSpace,
+    /// 安装路径过深，超出 Windows 路径长度限制
+    PathTooLong,
+    /// 参数不合法
+    InvalidArgument,
+    /// 未归类的错误
+    Unknown,
+}
+
+impl ErrorCode {
+    /// 默认恢复建议
+    fn default_hint(self) -> Option<&'static str> {
+        let key = match self {
+            ErrorCode::NotInitialized => "error.hint.not_initialized",
+            ErrorCode::InstanceNotFound => "error.hint.instance_not_found",
+            ErrorCode::ControllerNotConnected => "error.hint.controller_not_connected",
+            ErrorCode::ResourceNotLoaded => "error.hint.resource_not_loaded",
+            ErrorCode::TargetElevated => "error.hint.target_elevated",
+            ErrorCode::AgentFailed => "error.hint.agent_failed",
+            ErrorCode::AdbUnauthorized => "error.hint.adb_unauthorized",
+            ErrorCode::InsufficientDiskSpace => "error.hint.insufficient_disk_space",
+            ErrorCode::PathTooLong => "error.hint.path_too_long",
+            ErrorCode::InvalidArgument | ErrorCode::Unknown => return None,
+        };
+        Some(i18n::t(key))
+    }
+}
+
+/// 命令错误
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MxuError {
+    pub code: ErrorCode,
+    pub message: String,
+    /// 出错时的上下文（如实例 ID、文件路径）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// 恢复建议
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// 供前端按错误码读取的结构化数据
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+/// 命令返回类型
+pub type MxuResult<T> = Result<T, MxuError>;
+
+impl MxuError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            context: None,
+            hint: code.default_hint().map(str::to_string),
+            details: None,
+        }
+    }
+
+    pub fn instance_not_found() -> Self {
+        Self::new(
+            ErrorCode::InstanceNotFound,
+            i18n::t("error.instance_not_found"),
+        )
+    }
+
+    pub fn controller_not_connected() -> Self {
+        Self::new(
+            ErrorCode::ControllerNotConnected,
+            i18n::t("error.controller_not_connected"),
+        )
+    }
+
+    pub fn resource_not_loaded() -> Self {
+        Self::new(
+            ErrorCode::ResourceNotLoaded,
+            i18n::t("error.resource_not_loaded"),
+        )
+    }
+
+    pub fn insufficient_disk_space(path: &str, required_bytes: u64, available_bytes: u64) -> Self {
+        let mb = |bytes: u64| format!("{:.1}", bytes as f64 / 1024.0 / 1024.0);
+        Self::new(
+            ErrorCode::InsufficientDiskSpace,
+            i18n::tr!(
+                "error.insufficient_disk_space",
+                path = path,
+                required = mb(required_bytes),
+                available = mb(available_bytes)
+            ),
+        )
+        .with_context(path)
+        .with_details(serde_json::json!({
+            "path": path,
+            "requiredBytes": required_bytes,
+            "availableBytes": available_bytes,
+        }))
+    }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+impl std::fmt::Display for MxuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for MxuError {}
+
+impl From<String> for MxuError {
+    fn from(message: String) -> Self {
+        Self::new(ErrorCode::Unknown, message)
+    }
+}
+
+impl From<&str> for MxuError {
+    fn from(message: &str) -> Self {
+        Self::from(message.to_string())
+    }
+}
+
+impl From<MxuError> for String {
+    fn from(error: MxuError) -> Self {
+        error.message
+    }
+}
@@ -0,0 +1,147 @@
+//! 任务失败截图
+//!
+//! 任务以 Failed 结束时自动保存控制器的缓存截图到 `debug/failures/`，
+//! 同名 `.json` 文件记录任务元数据，方便事后查看失败现场。
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::Local;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use super::types::MaaState;
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+/// 失败截图记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailureCapture {
+    /// 文件名（不含扩展名），同时作为记录 ID
+    pub id: String,
+    pub instance_id: String,
+    pub task_id: i64,
+    pub entry: String,
+    /// 所属运行报告（如有）
+    pub run_id: Option<String>,
+    pub captured_at: String,
+    pub image_path: String,
+}
+
+fn failures_dir() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join("debug").join("failures"))
+}
+
+/// 文件名中不允许出现的字符替换为下划线
+pub(crate) fn sanitize(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// 保存失败截图与元数据（由 `handle_task_callback` 在任务失败时调用）
+///
+/// 控制器未连接或没有缓存截图时返回 None。
+pub fn capture(
+    app: &AppHandle,
+    instance_id: &str,
+    task_id: i64,
+    entry: &str,
+) -> Option<FailureCapture> {
+    let state = app.try_state::<Arc<MaaState>>()?;
+    let data = {
+        let instance = state.instances.get(instance_id)?;
+        let controller = instance.controller.as_ref()?;
+        controller.cached_image().ok()?.to_vec()?
+    };
+    if data.is_empty() {
+        return None;
+    }
+
+    let result = (|| -> Result<FailureCapture, String> {
+        let dir = failures_dir()?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| tr!("error.fs.create_dir", path = dir.display(), error = e))?;
+
+        let now = Local::now();
+        let id = format!(
+            "{}-{}-{}",
+            now.format("%Y%m%d-%H%M%S-%3f"),
+            sanitize(instance_id),
+            task_id
+        );
+        let image_path = dir.join(format!("{}.png", id));
+        std::fs::write(&image_path, &data).map_err(|e| {
+            tr!(
+                "error.fs.write_file",
+                path = image_path.display(),
+                error = e
+            )
+        })?;
+
+        let capture = FailureCapture {
+            id: id.clone(),
+            instance_id: instance_id.to_string(),
+            task_id,
+            entry: entry.to_string(),
+            run_id: super::run_report::current_run_id(instance_id),
+            captured_at: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+            image_path: image_path.to_string_lossy().to_string(),
+        };
+        let meta = serde_json::to_string_pretty(&capture)
+            .map_err(|e| tr!("error.serialize", error = e))?;
+        let meta_path = dir.join(format!("{}.json", id));
+        std::fs::write(&meta_path, meta)
+            .map_err(|e| tr!("error.fs.write_file", path = meta_path.display(), error = e))?;
+        Ok(capture)
+    })();
+
+    match result {
+        Ok(capture) => {
+            info!(
+                "Failure capture saved: {} (task {} {})",
+                capture.image_path, task_id, entry
+            );
+            Some(capture)
+        }
+        Err(e) => {
+            warn!("Failed to save failure capture: {}", e);
+            None
+        }
+    }
+}
+
+/// 列出失败截图（按时间倒序）
+#[tauri::command]
+pub fn mxu_list_failure_captures(
+    instance_id: Option<String>,
+) -> Result<Vec<FailureCapture>, String> {
+    let dir = failures_dir()?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = std::fs::read_dir(&dir)
+        .map_err(|e| tr!("error.fs.read_dir", path = dir.display(), error = e))?;
+    let mut captures: Vec<FailureCapture> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|p| {
+            let content = std::fs::read_to_string(&p).ok()?;
+            serde_json::from_str::<FailureCapture>(&content).ok()
+        })
+        .filter(|c| instance_id.as_ref().is_none_or(|id| &c.instance_id == id))
+        .collect();
+    // ID 以时间戳开头，按字典序倒序即为时间倒序
+    captures.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(captures)
+}
@@ -0,0 +1,267 @@
+//! 文件占用检测
+//!
+//! 更新时替换被占用的文件（通常是仍被加载的 DLL）会失败。
+//! Windows 下通过 Restart Manager 查询占用进程，并支持用
+//! MoveFileEx(MOVEFILE_DELAY_UNTIL_REBOOT) 安排下次开机时替换。
+
+use serde::Serialize;
+use std::path::Path;
+
+use crate::i18n::tr;
+
+/// 占用文件的进程信息
+#[derive(Debug, Clone, Serialize)]
+pub struct LockingProcess {
+    pub pid: u32,
+    pub name: String,
+}
+
+/// 判断 IO 错误是否由文件被占用引起
+pub fn is_locked_error(err: &std::io::Error) -> bool {
+    #[cfg(windows)]
+    {
+        // ERROR_ACCESS_DENIED / ERROR_SHARING_VIOLATION / ERROR_LOCK_VIOLATION / ERROR_USER_MAPPED_FILE
+        matches!(err.raw_os_error(), Some(5 | 32 | 33 | 1224))
+    }
+
+    #[cfg(not(windows))]
+    {
+        // 非 Windows 平台可直接替换正在使用的文件，仅 ETXTBSY 属于占用
+        err.raw_os_error() == Some(libc::ETXTBSY)
+    }
+}
+
+/// 将占用进程列表格式化为可读文本，如 "MaaAgent.exe (PID 1234), python.exe (PID 5678)"
+pub fn describe_lockers(lockers: &[LockingProcess]) -> String {
+    lockers
+        .iter()
+        .map(|p| format!("{} (PID {})", p.name, p.pid))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 查询占用指定文件的进程（仅 Windows 有效，其他平台返回空列表）
+pub fn find_locking_processes(path: &Path) -> Vec<LockingProcess> {
+    #[cfg(windows)]
+    {
+        match win::find_locking_processes(path) {
+            Ok(list) => list,
+            Err(e) => {
+                log::warn!("Restart Manager query failed for {}: {}", path.display(), e);
+                Vec::new()
+            }
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = path;
+        Vec::new()
+    }
+}
+
+/// 安排在下次开机时用 `src` 替换 `dst`
+///
+/// 需要管理员权限（写入 PendingFileRenameOperations），`src` 必须与 `dst` 位于同一卷。
+pub fn schedule_replace_on_reboot(src: &Path, dst: &Path) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        win::move_file_on_reboot(src, dst)
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (src, dst);
+        Err(tr!("error.file_lock.windows_only"))
+    }
+}
+
+#[cfg(windows)]
+mod win {
+    use super::LockingProcess;
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::Path;
+
+    const CCH_RM_SESSION_KEY: usize = 32;
+    const CCH_RM_MAX_APP_NAME: usize = 255;
+    const CCH_RM_MAX_SVC_NAME: usize = 63;
+    const ERROR_SUCCESS: u32 = 0;
+    const ERROR_MORE_DATA: u32 = 234;
+    const MOVEFILE_REPLACE_EXISTING: u32 = 0x1;
+    const MOVEFILE_DELAY_UNTIL_REBOOT: u32 = 0x4;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    #[allow(dead_code)]
+    struct FileTime {
+        low: u32,
+        high: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct RmUniqueProcess {
+        process_id: u32,
+        process_start_time: FileTime,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    #[allow(dead_code)]
+    struct RmProcessInfo {
+        process: RmUniqueProcess,
+        app_name: [u16; CCH_RM_MAX_APP_NAME + 1],
+        service_short_name: [u16; CCH_RM_MAX_SVC_NAME + 1],
+        application_type: u32,
+        app_status: u32,
+        ts_session_id: u32,
+        restartable: i32,
+    }
+
+    #[link(name = "rstrtmgr")]
+    extern "system" {
+        fn RmStartSession(session: *mut u32, flags: u32, session_key: *mut u16) -> u32;
+        fn RmRegisterResources(
+            session: u32,
+            n_files: u32,
+            file_names: *const *const u16,
+            n_applications: u32,
+            applications: *const RmUniqueProcess,
+            n_services: u32,
+            service_names: *const *const u16,
+        ) -> u32;
+        fn RmGetList(
+            session: u32,
+            proc_info_needed: *mut u32,
+            proc_info: *mut u32,
+            affected_apps: *mut RmProcessInfo,
+            reboot_reasons: *mut u32,
+        ) -> u32;
+        fn RmEndSession(session: u32) -> u32;
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn MoveFileExW(existing: *const u16, new: *const u16, flags: u32) -> i32;
+    }
+
+    fn to_wide(path: &Path) -> Vec<u16> {
+        path.as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect()
+    }
+
+    fn from_wide(buf: &[u16]) -> String {
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        String::from_utf16_lossy(&buf[..len])
+    }
+
+    pub(super) fn find_locking_processes(path: &Path) -> Result<Vec<LockingProcess>, String> {
+        let wide_path = to_wide(path);
+        let mut session: u32 = 0;
+        let mut session_key = [0u16; CCH_RM_SESSION_KEY + 1];
+
+        // SAFETY: 所有指针均指向本函数栈上或堆上有效的缓冲区，长度符合 API 要求
+        unsafe {
+            let rc = RmStartSession(&mut session, 0, session_key.as_mut_ptr());
+            if rc != ERROR_SUCCESS {
+                return Err(tr!(
+                    "error.file_lock.restart_manager",
+                    api = "RmStartSession",
+                    code = rc
+                ));
+            }
+
+            let result = (|| {
+                let files = [wide_path.as_ptr()];
+                let rc = RmRegisterResources(
+                    session,
+                    1,
+                    files.as_ptr(),
+                    0,
+                    std::ptr::null(),
+                    0,
+                    std::ptr::null(),
+                );
+                if rc != ERROR_SUCCESS {
+                    return Err(tr!(
+                        "error.file_lock.restart_manager",
+                        api = "RmRegisterResources",
+                        code = rc
+                    ));
+                }
+
+                let mut needed: u32 = 0;
+                let mut count: u32 = 0;
+                let mut reasons: u32 = 0;
+                let mut infos: Vec<RmProcessInfo> = Vec::new();
+
+                // 占用进程数可能在两次调用间变化，最多重试几次
+                for _ in 0..3 {
+                    count = infos.len() as u32;
+                    let rc = RmGetList(
+                        session,
+                        &mut needed,
+                        &mut count,
+                        if infos.is_empty() {
+                            std::ptr::null_mut()
+                        } else {
+                            infos.as_mut_ptr()
+                        },
+                        &mut reasons,
+                    );
+                    match rc {
+                        ERROR_SUCCESS => break,
+                        ERROR_MORE_DATA => {
+                            infos = vec![std::mem::zeroed(); needed as usize];
+                            count = 0;
+                        }
+                        other => {
+                            return Err(tr!(
+                                "error.file_lock.restart_manager",
+                                api = "RmGetList",
+                                code = other
+                            ))
+                        }
+                    }
+                }
+
+                Ok(infos
+                    .iter()
+                    .take(count as usize)
+                    .map(|info| LockingProcess {
+                        pid: info.process.process_id,
+                        name: from_wide(&info.app_name),
+                    })
+                    .collect())
+            })();
+
+            RmEndSession(session);
+            result
+        }
+    }
+
+    pub(super) fn move_file_on_reboot(src: &Path, dst: &Path) -> Result<(), String> {
+        let wide_src = to_wide(src);
+        let wide_dst = to_wide(dst);
+        // SAFETY: 两个路径均为以 0 结尾的 UTF-16 缓冲区，在调用期间保持有效
+        let ok = unsafe {
+            MoveFileExW(
+                wide_src.as_ptr(),
+                wide_dst.as_ptr(),
+                MOVEFILE_REPLACE_EXISTING | MOVEFILE_DELAY_UNTIL_REBOOT,
+            )
+        };
+        if ok == 0 {
+            let err = std::io::Error::last_os_error();
+            return Err(tr!(
+                "error.file_lock.schedule_replace",
+                src = src.display(),
+                dst = dst.display(),
+                error = err
+            ));
+        }
+        Ok(())
+    }
+}
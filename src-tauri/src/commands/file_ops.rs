@@ -1,6 +1,9 @@
 //! 文件操作命令
 //!
-//! 提供本地文件读取和路径检查功能
+//! 提供本地文件读写和路径检查功能
+//!
+//! 本地文件命令只允许读取 exe 目录、资源目录与数据目录（见 [`resolve_local_file_path`]），
+//! 写入仅限数据目录下的 cache 与 debug 目录
 
 use log::debug;
 use std::io::{self, BufWriter, Seek, SeekFrom, Write};
@@ -9,6 +12,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use super::utils::{get_app_data_dir, get_exe_directory, normalize_path};
+use crate::i18n::tr;
 
 /// 单个分卷 zip 的大小上限（字节）。
 const MAX_VOLUME_BYTES: u64 = 24_500_000;
@@ -120,7 +124,7 @@ fn collect_files_recursively(dir: &Path, archive_prefix: &str) -> Result<Vec<Exp
 
     while let Some(current_dir) = stack.pop() {
         let entries = std::fs::read_dir(&current_dir)
-            .map_err(|e| format!("读取目录失败 [{}]: {}", current_dir.display(), e))?;
+            .map_err(|e| tr!("error.fs.read_dir", path = current_dir.display(), error = e))?;
 
         for entry in entries.flatten() {
             let path = entry.path();
@@ -185,7 +189,7 @@ fn collect_debug_subdir_files(
     extensions: &[&str],
 ) -> Result<Vec<ExportEntry>, String> {
     let entries = std::fs::read_dir(debug_dir)
-        .map_err(|e| format!("读取日志目录失败 [{}]: {}", debug_dir.display(), e))?;
+        .map_err(|e| tr!("error.fs.read_dir", path = debug_dir.display(), error = e))?;
 
     let mut files = Vec::new();
     for entry in entries.flatten() {
@@ -208,27 +212,110 @@ fn collect_debug_subdir_files(
     Ok(files)
 }
 
-pub fn resolve_local_file_path(filename: &str) -> Result<PathBuf, String> {
+/// 数据目录下的用户数据（配置、密钥、日志、报告、备份等），不通过 Web 服务对外提供
+const PRIVATE_DATA_ENTRIES: &[&str] = &[
+    "config",
+    "reports",
+    "debug",
+    "debug_exports",
+    "cache",
+    "audit",
+    "results",
+    "backups",
+    "variables.json",
+    "encryption.json",
+    ".mxu-machine-key",
+];
+
+/// 前端可写入的数据目录子目录（缓存与前端日志）
+const WRITABLE_DATA_DIRS: &[&str] = &["cache", "debug"];
+
+/// 资源根目录：exe 目录，以及 macOS 应用包内的资源目录
+fn resource_roots() -> Result<Vec<PathBuf>, String> {
+    let exe_dir = get_exe_directory()?;
+    #[allow(unused_mut)]
+    let mut roots = vec![normalize_path(&exe_dir.to_string_lossy())];
+
+    // macOS 应用包内的资源目录（MXU.app/Contents/Resources）
+    #[cfg(target_os = "macos")]
+    if let Some(contents) = exe_dir.parent() {
+        let resources = contents.join("Resources");
+        if resources.is_dir() {
+            roots.push(normalize_path(&resources.to_string_lossy()));
+        }
+    }
+    Ok(roots)
+}
+
+/// 本地文件命令允许读取的根目录：资源目录与数据目录
+fn sandbox_roots() -> Result<Vec<PathBuf>, String> {
+    let mut roots = resource_roots()?;
+    let data_dir = normalize_path(&get_app_data_dir()?.to_string_lossy());
+    if !roots.contains(&data_dir) {
+        roots.push(data_dir);
+    }
+    Ok(roots)
+}
+
+/// 相对路径基于 exe 目录解析，规范化（处理 `..`）后须位于某个根目录之内
+fn resolve_within(filename: &str, roots: &[PathBuf]) -> Result<PathBuf, String> {
     let exe_dir = get_exe_directory()?;
     let file_path = normalize_path(&exe_dir.join(filename).to_string_lossy());
-    // 防止路径穿越，确保仍在 exe 目录下
-    if !file_path.starts_with(&exe_dir) {
-        return Err(format!("非法文件路径: {}", filename));
+    // 防止路径穿越，确保仍在允许的根目录下
+    if !roots.iter().any(|root| file_path.starts_with(root)) {
+        return Err(tr!("error.fs.path_not_allowed", path = filename));
     }
     Ok(file_path)
 }
 
-/// 读取 exe 同目录下的文本文件
+/// 将前端传入的路径解析为沙箱内的绝对路径
+///
+/// 相对路径基于 exe 目录解析；绝对路径须位于 exe 目录、资源目录或数据目录之内。
+/// 规范化（处理 `..`）后仍在根目录之外的路径一律拒绝。
+pub fn resolve_local_file_path(filename: &str) -> Result<PathBuf, String> {
+    resolve_within(filename, &sandbox_roots()?)
+}
+
+/// 将 Web 客户端请求的路径解析为可对外提供的资源文件
+///
+/// 只允许 exe 目录与资源目录；Windows/Linux 的数据目录即 exe 目录，
+/// 其中的用户数据（见 [`PRIVATE_DATA_ENTRIES`]）一律拒绝。
+pub fn resolve_served_file_path(filename: &str) -> Result<PathBuf, String> {
+    let file_path = resolve_within(filename, &resource_roots()?)?;
+    // 按小写比较，Windows/macOS 文件系统不区分大小写
+    let lower = |path: &Path| PathBuf::from(path.to_string_lossy().to_lowercase());
+    let requested = lower(&file_path);
+    let data_dir = normalize_path(&get_app_data_dir()?.to_string_lossy());
+    if PRIVATE_DATA_ENTRIES
+        .iter()
+        .any(|entry| requested.starts_with(lower(&data_dir.join(entry))))
+    {
+        return Err(tr!("error.fs.path_not_allowed", path = filename));
+    }
+    Ok(file_path)
+}
+
+/// 将前端传入的写入路径解析为数据目录下可写子目录内的绝对路径
+fn resolve_writable_path(filename: &str) -> Result<PathBuf, String> {
+    let data_dir = normalize_path(&get_app_data_dir()?.to_string_lossy());
+    let roots: Vec<PathBuf> = WRITABLE_DATA_DIRS
+        .iter()
+        .map(|dir| data_dir.join(dir))
+        .collect();
+    resolve_within(filename, &roots)
+}
+
+/// 读取沙箱内的文本文件
 #[tauri::command]
 pub fn read_local_file(filename: String) -> Result<String, String> {
     let file_path = resolve_local_file_path(&filename)?;
     debug!("Reading local file: {:?}", file_path);
 
     std::fs::read_to_string(&file_path)
-        .map_err(|e| format!("读取文件失败 [{}]: {}", file_path.display(), e))
+        .map_err(|e| tr!("error.fs.read_file", path = file_path.display(), error = e))
 }
 
-/// 读取 exe 同目录下的二进制文件，返回 base64 编码
+/// 读取沙箱内的二进制文件，返回 base64 编码
 #[tauri::command]
 pub fn read_local_file_base64(filename: String) -> Result<String, String> {
     use base64::{engine::general_purpose::STANDARD, Engine as _};
@@ -237,18 +324,293 @@ pub fn read_local_file_base64(filename: String) -> Result<String, String> {
     debug!("Reading local file (base64): {:?}", file_path);
 
     let data = std::fs::read(&file_path)
-        .map_err(|e| format!("读取文件失败 [{}]: {}", file_path.display(), e))?;
+        .map_err(|e| tr!("error.fs.read_file", path = file_path.display(), error = e))?;
 
     Ok(STANDARD.encode(&data))
 }
 
-/// 检查 exe 同目录下的文件是否存在
+/// 检查沙箱内的文件是否存在
 #[tauri::command]
 pub fn local_file_exists(filename: String) -> Result<bool, String> {
     let file_path = resolve_local_file_path(&filename)?;
     Ok(file_path.exists())
 }
 
+/// 写入数据目录下 cache 或 debug 内的文本文件，父目录不存在时自动创建
+/// - `append`: 为 true 时追加到文件末尾
+#[tauri::command]
+pub fn write_local_file(
+    filename: String,
+    content: String,
+    append: Option<bool>,
+) -> Result<(), String> {
+    let file_path = resolve_writable_path(&filename)?;
+
+    if let Some(parent) = file_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+    }
+
+    let result = if append.unwrap_or(false) {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&file_path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+    } else {
+        std::fs::write(&file_path, content)
+    };
+    result.map_err(|e| tr!("error.fs.write_file", path = file_path.display(), error = e))
+}
+
+/// 简单通配符匹配（`*` 匹配任意字符序列，`?` 匹配单个字符，不区分大小写）
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // 最近一个 `*` 的位置及其当前匹配到的 name 位置，用于回溯
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((sp, sn)) = star {
+            p = sp + 1;
+            n = sn + 1;
+            star = Some((sp, sn + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// 目录条目
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalDirEntry {
+    /// 相对于所列目录的路径（使用 `/` 分隔）
+    pub name: String,
+    /// 绝对路径
+    pub path: String,
+    pub is_dir: bool,
+    pub is_file: bool,
+    /// 文件大小（字节），目录为 0
+    pub size: u64,
+    /// 最后修改时间（Unix 毫秒时间戳），无法获取时为 None
+    pub mtime: Option<u64>,
+}
+
+/// 列出沙箱内目录下的条目，目录不存在时返回空列表
+/// - `glob`: 按文件名过滤的通配符（如 `*.png`），递归时仍会进入不匹配的子目录
+/// - `recursive`: 是否递归子目录
+#[tauri::command]
+pub async fn list_local_dir(
+    dirname: String,
+    glob: Option<String>,
+    recursive: Option<bool>,
+) -> Result<Vec<LocalDirEntry>, String> {
+    tokio::task::spawn_blocking(move || {
+        list_local_dir_blocking(&dirname, glob.as_deref(), recursive.unwrap_or(false))
+    })
+    .await
+    .map_err(|e| tr!("error.task_panicked", error = e))?
+}
+
+fn list_local_dir_blocking(
+    dirname: &str,
+    glob: Option<&str>,
+    recursive: bool,
+) -> Result<Vec<LocalDirEntry>, String> {
+    let root = resolve_local_file_path(dirname)?;
+    if !root.is_dir() {
+        return Ok(Vec::new());
+    }
+    let glob = glob.map(str::trim).filter(|g| !g.is_empty());
+
+    let mut result = Vec::new();
+    let mut stack = vec![root.clone()];
+    while let Some(current_dir) = stack.pop() {
+        let entries = std::fs::read_dir(&current_dir)
+            .map_err(|e| tr!("error.fs.read_dir", path = current_dir.display(), error = e))?;
+
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let entry_path = entry.path();
+            let is_dir = metadata.is_dir();
+            if is_dir && recursive {
+                stack.push(entry_path.clone());
+            }
+
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if glob.is_some_and(|g| !wildcard_match(g, &file_name)) {
+                continue;
+            }
+
+            let Ok(relative) = entry_path.strip_prefix(&root) else {
+                continue;
+            };
+            let mtime = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as u64);
+            result.push(LocalDirEntry {
+                name: normalize_archive_path(relative),
+                path: entry_path.to_string_lossy().to_string(),
+                is_dir,
+                is_file: metadata.is_file(),
+                size: if metadata.is_file() {
+                    metadata.len()
+                } else {
+                    0
+                },
+                mtime,
+            });
+        }
+    }
+
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(result)
+}
+
+/// 以流式读取计算文件摘要，返回小写十六进制字符串
+pub(crate) fn digest_file<D: sha2::Digest>(path: &Path) -> Result<String, String> {
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| tr!("error.fs.open_file", path = path.display(), error = e))?;
+    let mut hasher = D::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .map_err(|e| tr!("error.fs.read_file", path = path.display(), error = e))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// 计算沙箱内文件的哈希值（流式读取，不将整个文件载入内存）
+/// - `algorithm`: `md5` / `sha1` / `sha256`（不区分大小写）
+#[tauri::command]
+pub async fn hash_file(path: String, algorithm: String) -> Result<String, String> {
+    let file_path = resolve_local_file_path(&path)?;
+    if !file_path.is_file() {
+        return Err(tr!("error.fs.not_found", path = file_path.display()));
+    }
+    let algorithm = algorithm.trim().to_lowercase();
+    tokio::task::spawn_blocking(move || match algorithm.as_str() {
+        "md5" => digest_file::<md5::Md5>(&file_path),
+        "sha1" => digest_file::<sha1::Sha1>(&file_path),
+        "sha256" => digest_file::<sha2::Sha256>(&file_path),
+        other => Err(tr!("error.fs.unsupported_hash", name = other)),
+    })
+    .await
+    .map_err(|e| tr!("error.task_panicked", error = e))?
+}
+
+/// 解析可删除的路径：须位于数据目录可写子目录之内，且不是可写子目录本身
+fn resolve_removable_path(path: &str) -> Result<PathBuf, String> {
+    let file_path = resolve_writable_path(path)?;
+    let data_dir = normalize_path(&get_app_data_dir()?.to_string_lossy());
+    if WRITABLE_DATA_DIRS
+        .iter()
+        .any(|dir| file_path == data_dir.join(dir))
+    {
+        return Err(tr!("error.fs.path_not_allowed", path = path));
+    }
+    Ok(file_path)
+}
+
+/// 将数据目录可写子目录（见 [`WRITABLE_DATA_DIRS`]）内的文件或目录移入系统回收站
+///
+/// 可写子目录本身不允许删除。回收站不可用（如部分 Linux 环境、网络驱动器）时返回错误，
+/// 由调用方决定是否改为 [`remove_local_file`]。
+#[tauri::command]
+pub fn move_to_trash(path: String) -> Result<(), String> {
+    let file_path = resolve_removable_path(&path)?;
+    if !file_path.exists() {
+        return Ok(());
+    }
+    trash::delete(&file_path)
+        .map_err(|e| tr!("error.fs.trash", path = file_path.display(), error = e))?;
+    log::info!("Moved to trash: {}", file_path.display());
+    Ok(())
+}
+
+/// 直接删除数据目录可写子目录内的文件或目录，限制同 [`move_to_trash`]
+#[tauri::command]
+pub fn remove_local_file(path: String) -> Result<(), String> {
+    let file_path = resolve_removable_path(&path)?;
+    let result = if file_path.is_dir() {
+        std::fs::remove_dir_all(&file_path)
+    } else {
+        std::fs::remove_file(&file_path)
+    };
+    match result {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(tr!(
+            "error.fs.remove",
+            path = file_path.display(),
+            error = e
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// 可通过 [`read_image_file`] 读取的图片扩展名
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "gif", "bmp"];
+
+/// 读取用户选择的图片（如背景图），返回 base64 编码
+///
+/// 图片可位于任意目录（由文件对话框选择，重启后对话框授权不再有效），
+/// 因此不做目录限制，只允许图片扩展名。
+#[tauri::command]
+pub fn read_image_file(path: String) -> Result<String, String> {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    let file_path = PathBuf::from(&path);
+    let is_image = file_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+    if !is_image {
+        return Err(tr!("error.fs.path_not_allowed", path = path));
+    }
+
+    let data = std::fs::read(&file_path)
+        .map_err(|e| tr!("error.fs.read_file", path = file_path.display(), error = e))?;
+    Ok(STANDARD.encode(&data))
+}
+
+/// 优先移入系统回收站，回收站不可用时直接删除
+///
+/// 供后端清理流程使用，不做沙箱检查。
+pub(crate) fn trash_or_remove(path: &Path) -> io::Result<()> {
+    let Err(e) = trash::delete(path) else {
+        return Ok(());
+    };
+    log::debug!("Trash unavailable for {}, deleting: {}", path.display(), e);
+    if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
+
 /// 获取 exe 所在目录路径
 #[tauri::command]
 pub fn get_exe_dir() -> Result<String, String> {
@@ -265,7 +627,7 @@ pub fn get_data_dir() -> Result<String, String> {
     Ok(data_dir.to_string_lossy().to_string())
 }
 
-/// 删除 debug 目录中的 .log 文件，可选择排除一个当前正在使用的日志文件
+/// 清理 debug 目录中的 .log 文件（优先移入回收站），可选择排除一个当前正在使用的日志文件
 #[tauri::command]
 pub fn clear_log_files(exclude_file_name: Option<String>) -> Result<u64, String> {
     let debug_dir = get_app_data_dir()?.join("debug");
@@ -276,7 +638,7 @@ pub fn clear_log_files(exclude_file_name: Option<String>) -> Result<u64, String>
 
     let mut deleted = 0_u64;
     let entries = std::fs::read_dir(&debug_dir)
-        .map_err(|e| format!("读取日志目录失败 [{}]: {}", debug_dir.display(), e))?;
+        .map_err(|e| tr!("error.fs.read_dir", path = debug_dir.display(), error = e))?;
 
     for entry in entries {
         let entry = match entry {
@@ -300,7 +662,7 @@ pub fn clear_log_files(exclude_file_name: Option<String>) -> Result<u64, String>
             continue;
         }
 
-        match std::fs::remove_file(&path) {
+        match trash_or_remove(&path) {
             Ok(()) => deleted = deleted.saturating_add(1),
             Err(e) => log::debug!("Failed to delete log file [{}]: {}", path.display(), e),
         }
@@ -420,13 +782,13 @@ pub fn set_executable(file_path: String) -> Result<(), String> {
     {
         use std::os::unix::fs::PermissionsExt;
         let metadata = std::fs::metadata(&file_path)
-            .map_err(|e| format!("无法获取文件元数据 [{}]: {}", file_path, e))?;
+            .map_err(|e| tr!("error.fs.metadata", path = file_path, error = e))?;
         let mut permissions = metadata.permissions();
         // 添加可执行权限 (owner, group, others)
         let mode = permissions.mode() | 0o111;
         permissions.set_mode(mode);
         std::fs::set_permissions(&file_path, permissions)
-            .map_err(|e| format!("无法设置执行权限 [{}]: {}", file_path, e))?;
+            .map_err(|e| tr!("error.fs.set_executable", path = file_path, error = e))?;
         log::info!("Set executable permission: {}", file_path);
     }
     #[cfg(not(unix))]
@@ -490,7 +852,7 @@ pub async fn export_logs(
 ) -> Result<String, String> {
     tokio::task::spawn_blocking(move || export_logs_blocking(project_name, project_version))
         .await
-        .map_err(|e| format!("导出任务执行失败: {}", e))?
+        .map_err(|e| tr!("error.task_panicked", error = e))?
 }
 
 fn export_logs_blocking(
@@ -506,7 +868,7 @@ fn export_logs_blocking(
     let debug_dir = data_dir.join("debug");
 
     if !debug_dir.exists() {
-        return Err("日志目录不存在".to_string());
+        return Err(tr!("error.fs.no_logs_dir"));
     }
 
     let now = chrono::Local::now();
@@ -522,14 +884,15 @@ fn export_logs_blocking(
     let exports_root = data_dir.join("debug_exports");
     let out_dir = exports_root.join(&dir_name);
     std::fs::create_dir_all(&out_dir)
-        .map_err(|e| format!("创建导出目录失败 [{}]: {}", out_dir.display(), e))?;
+        .map_err(|e| tr!("error.fs.create_dir", path = out_dir.display(), error = e))?;
 
     let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
 
     // ─── 1. 收集常规文件（log / config / 子目录下的 log/json） ───
     let mut regular_entries: Vec<ExportEntry> = Vec::new();
 
-    let entries = std::fs::read_dir(&debug_dir).map_err(|e| format!("读取日志目录失败: {}", e))?;
+    let entries = std::fs::read_dir(&debug_dir)
+        .map_err(|e| tr!("error.fs.read_dir", path = debug_dir.display(), error = e))?;
     for entry in entries.flatten() {
         let path = entry.path();
         if !path.is_file() {
@@ -565,7 +928,7 @@ fn export_logs_blocking(
 
     let total_entries = all_entries.len();
     if total_entries == 0 {
-        return Err("没有可导出的日志文件".to_string());
+        return Err(tr!("error.fs.no_logs"));
     }
 
     // ─── 4. 分卷打包 ───
@@ -584,8 +947,13 @@ fn export_logs_blocking(
             volume_idx,
             width = width
         ));
-        let file = File::create(&volume_path)
-            .map_err(|e| format!("创建分卷文件失败 [{}]: {}", volume_path.display(), e))?;
+        let file = File::create(&volume_path).map_err(|e| {
+            tr!(
+                "error.fs.create_file",
+                path = volume_path.display(),
+                error = e
+            )
+        })?;
         // 64 KB 缓冲：deflate 输出的 chunk 经常几十到几百 KB，默认 8 KB 太小。
         let (counting, counter) = CountingWriter::new(BufWriter::with_capacity(64 * 1024, file));
         let mut zip = ZipWriter::new(counting);
@@ -615,8 +983,13 @@ fn export_logs_blocking(
             }
         }
 
-        zip.finish()
-            .map_err(|e| format!("完成分卷压缩失败 [{}]: {}", volume_path.display(), e))?;
+        zip.finish().map_err(|e| {
+            tr!(
+                "error.fs.write_archive",
+                path = volume_path.display(),
+                error = e
+            )
+        })?;
 
         if let Ok(metadata) = std::fs::metadata(&volume_path) {
             log::info!(
@@ -689,7 +1062,7 @@ fn prune_old_exports(exports_root: &Path, current_export: &Path) {
     // 时间戳字符串的字典序就是时间序
     dirs.sort_by(|a, b| b.1.cmp(&a.1));
     for (path, _) in dirs.into_iter().skip(keep_others) {
-        match std::fs::remove_dir_all(&path) {
+        match trash_or_remove(&path) {
             Ok(()) => log::info!("已清理旧导出: {}", path.display()),
             Err(e) => log::warn!("清理旧导出失败 [{}]: {}", path.display(), e),
         }
@@ -0,0 +1,165 @@
+//! focus 运行日志
+//!
+//! 在后端解析 pipeline 节点的 `focus` 配置，将命中的回调消息整理为面向用户的运行日志
+//! （开始 / 成功 / 失败 / 提示），按实例缓存并以 `maa-focus-log` 事件推送，
+//! 与 MaaPiCli 向用户展示的内容一致。`mxu_get_focus_log` 返回实例的完整记录。
+//!
+//! 文本只做 `{name}`、`{task_id}` 等占位符替换；`$` 开头的国际化键、Markdown 渲染与
+//! `{image}` 截图占位符由前端处理。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::ws_broadcast::{WsBroadcast, WsEvent};
+
+/// 每个实例最多保留的 focus 日志条数
+const MAX_ENTRIES_PER_INSTANCE: usize = 500;
+
+/// focus 日志级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FocusLevel {
+    Start,
+    Succeeded,
+    Failed,
+    Tip,
+}
+
+/// 一条 focus 日志
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusLogEntry {
+    pub instance_id: String,
+    pub timestamp: String,
+    /// 节点名称
+    pub node: String,
+    /// 触发该条日志的回调消息（如 `Node.Action.Succeeded`）
+    pub message: String,
+    pub level: FocusLevel,
+    pub text: String,
+    /// 配置的展示渠道（log / toast / notification / dialog / modal）
+    pub display: Vec<String>,
+}
+
+static LOGS: Mutex<Option<HashMap<String, VecDeque<FocusLogEntry>>>> = Mutex::new(None);
+
+/// 替换 `{key}` 占位符（`{image}` 保留给前端）
+fn replace_placeholders(template: &str, details: &serde_json::Value) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let key = &after[..end];
+        let is_key = key != "image" && key.chars().all(|c| c.is_alphanumeric() || c == '_');
+        let value = if is_key {
+            details.get(key).filter(|v| !v.is_null())
+        } else {
+            None
+        };
+        match value {
+            Some(serde_json::Value::String(s)) => result.push_str(s),
+            Some(v) => result.push_str(&v.to_string()),
+            None => result.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 从回调中提取 focus 日志（节点未配置该消息的 focus 时返回 None）
+fn parse_focus(instance_id: &str, message: &str, details: &str) -> Option<FocusLogEntry> {
+    if !message.starts_with("Node.") {
+        return None;
+    }
+    let details: serde_json::Value = serde_json::from_str(details).ok()?;
+    let entry = details.get("focus")?.get(message)?;
+
+    let (template, display) = match entry {
+        serde_json::Value::String(s) => (s.clone(), vec!["log".to_string()]),
+        serde_json::Value::Object(obj) => {
+            let content = obj.get("content")?.as_str()?.to_string();
+            let display = match obj.get("display") {
+                Some(serde_json::Value::String(d)) => vec![d.clone()],
+                Some(serde_json::Value::Array(arr)) => arr
+                    .iter()
+                    .filter_map(|d| d.as_str().map(str::to_string))
+                    .collect(),
+                _ => vec!["log".to_string()],
+            };
+            (content, display)
+        }
+        _ => return None,
+    };
+
+    let level = match message.rsplit('.').next() {
+        Some("Starting") => FocusLevel::Start,
+        Some("Succeeded") => FocusLevel::Succeeded,
+        Some("Failed") => FocusLevel::Failed,
+        _ => FocusLevel::Tip,
+    };
+
+    Some(FocusLogEntry {
+        instance_id: instance_id.to_string(),
+        timestamp: chrono::Local::now().to_rfc3339(),
+        node: details
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        message: message.to_string(),
+        level,
+        text: replace_placeholders(&template, &details),
+        display,
+    })
+}
+
+/// 处理一条回调消息（由 `emit_callback_event` 调用）
+pub fn on_callback(app: &AppHandle, instance_id: &str, message: &str, details: &str) {
+    let Some(entry) = parse_focus(instance_id, message, details) else {
+        return;
+    };
+
+    {
+        let mut guard = LOGS.lock();
+        let entries = guard
+            .get_or_insert_with(HashMap::new)
+            .entry(instance_id.to_string())
+            .or_default();
+        entries.push_back(entry.clone());
+        while entries.len() > MAX_ENTRIES_PER_INSTANCE {
+            entries.pop_front();
+        }
+    }
+
+    if let Some(ws) = app.try_state::<Arc<WsBroadcast>>() {
+        ws.send(WsEvent::FocusLog(entry.clone()));
+    }
+    if let Err(e) = app.emit("maa-focus-log", entry) {
+        log::error!("Failed to emit maa-focus-log: {}", e);
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取实例的 focus 运行日志
+#[tauri::command]
+pub fn mxu_get_focus_log(instance_id: String) -> Result<Vec<FocusLogEntry>, String> {
+    let guard = LOGS.lock();
+    Ok(guard
+        .as_ref()
+        .and_then(|logs| logs.get(&instance_id))
+        .map(|entries| entries.iter().cloned().collect())
+        .unwrap_or_default())
+}
@@ -0,0 +1,218 @@
+//! Gamepad 控制器相关命令
+//!
+//! 摇杆死区、扳机灵敏度、轮询频率来自 `ControllerConfig::Gamepad`，
+//! 作用于 MXU 自身发出的手柄输入（测试输入等），MaaFramework 不直接处理这些参数。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::info;
+use tauri::State;
+
+use super::error::{ErrorCode, MxuError, MxuResult};
+use super::types::{ControllerConfig, MaaState};
+use crate::i18n::tr;
+
+/// 默认轮询频率（Hz），决定连续输入之间的间隔
+const DEFAULT_POLLING_RATE: u32 = 60;
+
+/// 摇杆轴最大值（XInput 范围 -32768..=32767）
+const STICK_MAX: i32 = 32767;
+
+/// 扳机最大值
+const TRIGGER_MAX: i32 = 255;
+
+/// 触点编号：左摇杆 / 右摇杆 / 左扳机 / 右扳机
+const CONTACT_LEFT_STICK: i32 = 0;
+const CONTACT_RIGHT_STICK: i32 = 1;
+const CONTACT_LEFT_TRIGGER: i32 = 2;
+const CONTACT_RIGHT_TRIGGER: i32 = 3;
+
+/// 默认测试序列
+const DEFAULT_TEST_SEQUENCE: &[&str] = &["A", "B", "X", "Y", "LS_UP", "LS_DOWN", "RT"];
+
+/// 手柄输入参数（由控制器配置解析）
+#[derive(Debug, Clone, Copy)]
+pub struct GamepadTuning {
+    /// 摇杆死区（0-100，百分比）
+    pub stick_dead_zone: u32,
+    /// 扳机灵敏度（1-100，百分比，100 为按满）
+    pub trigger_sensitivity: u32,
+    /// 轮询频率（Hz）
+    pub polling_rate: u32,
+}
+
+impl Default for GamepadTuning {
+    fn default() -> Self {
+        Self {
+            stick_dead_zone: 0,
+            trigger_sensitivity: 100,
+            polling_rate: DEFAULT_POLLING_RATE,
+        }
+    }
+}
+
+impl GamepadTuning {
+    pub fn from_config(config: &ControllerConfig) -> Option<Self> {
+        let ControllerConfig::Gamepad {
+            stick_dead_zone,
+            trigger_sensitivity,
+            polling_rate,
+            ..
+        } = config
+        else {
+            return None;
+        };
+        let default = Self::default();
+        Some(Self {
+            stick_dead_zone: stick_dead_zone.unwrap_or(default.stick_dead_zone).min(95),
+            trigger_sensitivity: trigger_sensitivity
+                .unwrap_or(default.trigger_sensitivity)
+                .clamp(1, 100),
+            polling_rate: polling_rate.unwrap_or(default.polling_rate).clamp(1, 1000),
+        })
+    }
+
+    /// 将 -1.0..=1.0 的摇杆输入映射到死区之外的轴值
+    pub fn stick_axis(&self, value: f64) -> i32 {
+        let value = value.clamp(-1.0, 1.0);
+        if value == 0.0 {
+            return 0;
+        }
+        let dz = self.stick_dead_zone as f64 / 100.0;
+        let scaled = dz + (1.0 - dz) * value.abs();
+        (scaled * STICK_MAX as f64).round() as i32 * value.signum() as i32
+    }
+
+    /// 将 0.0..=1.0 的扳机输入按灵敏度换算为压力值
+    pub fn trigger_pressure(&self, value: f64) -> i32 {
+        let value = value.clamp(0.0, 1.0) * self.trigger_sensitivity as f64 / 100.0;
+        (value * TRIGGER_MAX as f64).round() as i32
+    }
+
+    /// 连续输入之间的间隔
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_micros(1_000_000 / self.polling_rate as u64)
+    }
+}
+
+/// 按键名 → XUSB 按键码
+fn button_code(name: &str) -> Option<i32> {
+    Some(match name {
+        "DPAD_UP" => 0x0001,
+        "DPAD_DOWN" => 0x0002,
+        "DPAD_LEFT" => 0x0004,
+        "DPAD_RIGHT" => 0x0008,
+        "START" => 0x0010,
+        "BACK" => 0x0020,
+        "LS" => 0x0040,
+        "RS" => 0x0080,
+        "LB" => 0x0100,
+        "RB" => 0x0200,
+        "GUIDE" => 0x0400,
+        "A" => 0x1000,
+        "B" => 0x2000,
+        "X" => 0x4000,
+        "Y" => 0x8000,
+        _ => return None,
+    })
+}
+
+/// 摇杆/扳机输入名 → (触点, x, y)，x/y 为 -1.0..=1.0（扳机取 x 为 0.0..=1.0）
+fn analog_input(name: &str) -> Option<(i32, f64, f64)> {
+    Some(match name {
+        "LS_UP" => (CONTACT_LEFT_STICK, 0.0, 1.0),
+        "LS_DOWN" => (CONTACT_LEFT_STICK, 0.0, -1.0),
+        "LS_LEFT" => (CONTACT_LEFT_STICK, -1.0, 0.0),
+        "LS_RIGHT" => (CONTACT_LEFT_STICK, 1.0, 0.0),
+        "RS_UP" => (CONTACT_RIGHT_STICK, 0.0, 1.0),
+        "RS_DOWN" => (CONTACT_RIGHT_STICK, 0.0, -1.0),
+        "RS_LEFT" => (CONTACT_RIGHT_STICK, -1.0, 0.0),
+        "RS_RIGHT" => (CONTACT_RIGHT_STICK, 1.0, 0.0),
+        "LT" => (CONTACT_LEFT_TRIGGER, 1.0, 0.0),
+        "RT" => (CONTACT_RIGHT_TRIGGER, 1.0, 0.0),
+        _ => return None,
+    })
+}
+
+/// 发送测试输入序列的内部实现
+pub fn gamepad_test_input_impl(
+    state: &MaaState,
+    instance_id: &str,
+    sequence: Option<Vec<String>>,
+) -> MxuResult<usize> {
+    let (controller, tuning) = {
+        let instance = state
+            .instances
+            .get(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let controller = instance
+            .controller
+            .clone()
+            .ok_or_else(MxuError::controller_not_connected)?;
+        let tuning = instance
+            .controller_config
+            .as_ref()
+            .and_then(GamepadTuning::from_config)
+            .ok_or_else(|| tr!("error.gamepad.not_gamepad"))?;
+        (controller, tuning)
+    };
+
+    let sequence = sequence.unwrap_or_else(|| {
+        DEFAULT_TEST_SEQUENCE
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    });
+    info!(
+        "gamepad_test_input: instance={}, tuning={:?}, sequence={:?}",
+        instance_id, tuning, sequence
+    );
+
+    // 每个输入保持若干个轮询周期，确保游戏能采样到
+    let hold = tuning.poll_interval() * 4;
+    let mut sent = 0;
+    for name in &sequence {
+        let name = name.to_uppercase();
+        if let Some(code) = button_code(&name) {
+            controller.post_click_key(code).map_err(|e| e.to_string())?;
+        } else if let Some((contact, x, y)) = analog_input(&name) {
+            let (ax, ay, pressure) = if contact >= CONTACT_LEFT_TRIGGER {
+                (0, 0, tuning.trigger_pressure(x))
+            } else {
+                (tuning.stick_axis(x), tuning.stick_axis(y), 0)
+            };
+            controller
+                .post_touch_down(contact, ax, ay, pressure)
+                .map_err(|e| e.to_string())?;
+            std::thread::sleep(hold);
+            controller
+                .post_touch_up(contact)
+                .map_err(|e| e.to_string())?;
+        } else {
+            return Err(MxuError::new(
+                ErrorCode::InvalidArgument,
+                tr!("error.gamepad.unknown_input", name = name),
+            ));
+        }
+        sent += 1;
+        std::thread::sleep(hold);
+    }
+    Ok(sent)
+}
+
+/// 发送一段测试按键序列，用于确认游戏能识别虚拟手柄
+///
+/// `sequence` 为空时使用默认序列；可选值包括 A/B/X/Y/LB/RB/START/BACK/DPAD_*、
+/// LS_*/RS_*（摇杆方向）、LT/RT（扳机）。返回已发送的输入数。
+#[tauri::command]
+pub async fn maa_gamepad_test_input(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    sequence: Option<Vec<String>>,
+) -> MxuResult<usize> {
+    let state = state.inner().clone();
+    tokio::task::spawn_blocking(move || gamepad_test_input_impl(&state, &instance_id, sequence))
+        .await
+        .map_err(|e| e.to_string())?
+}
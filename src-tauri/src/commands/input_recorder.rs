@@ -0,0 +1,224 @@
+//! 输入录制与回放
+//!
+//! 录制期间，通过控制器命令接口（`maa_post_click` / `maa_post_swipe`）发出的手动点击、
+//! 滑动会被记录为 JSON 脚本（含相邻输入的时间间隔），之后可用 `maa_replay_inputs` 回放，
+//! 无需编写 pipeline 即可实现简单自动化。
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::info;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use super::error::{MxuError, MxuResult};
+use super::types::MaaState;
+use crate::i18n::tr;
+
+/// 脚本格式版本
+const INPUT_SCRIPT_VERSION: u32 = 1;
+
+/// 单条录制输入
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RecordedInput {
+    Click {
+        x: i32,
+        y: i32,
+    },
+    Swipe {
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        duration: i32,
+    },
+}
+
+/// 脚本中的一步：距上一步的等待时间 + 输入
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputScriptStep {
+    pub delay_ms: u64,
+    #[serde(flatten)]
+    pub input: RecordedInput,
+}
+
+/// 输入脚本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputScript {
+    pub version: u32,
+    pub steps: Vec<InputScriptStep>,
+}
+
+struct Recording {
+    last_at: Instant,
+    steps: Vec<InputScriptStep>,
+}
+
+/// instance_id → 录制中的脚本
+static RECORDINGS: Mutex<Option<HashMap<String, Recording>>> = Mutex::new(None);
+
+/// instance_id → 回放停止标记
+static REPLAY_STOPS: Mutex<Option<HashMap<String, Arc<AtomicBool>>>> = Mutex::new(None);
+
+/// 记录一次输入（实例未在录制时忽略），由控制器命令实现调用
+pub fn record_input(instance_id: &str, input: RecordedInput) {
+    let mut guard = RECORDINGS.lock();
+    let Some(recording) = guard.as_mut().and_then(|m| m.get_mut(instance_id)) else {
+        return;
+    };
+    let now = Instant::now();
+    recording.steps.push(InputScriptStep {
+        delay_ms: now.duration_since(recording.last_at).as_millis() as u64,
+        input,
+    });
+    recording.last_at = now;
+}
+
+/// 开始录制（已在录制时重新开始）
+#[tauri::command]
+pub fn maa_record_inputs_start(instance_id: String) -> Result<(), String> {
+    info!("maa_record_inputs_start: {}", instance_id);
+    let mut guard = RECORDINGS.lock();
+    guard.get_or_insert_with(HashMap::new).insert(
+        instance_id,
+        Recording {
+            last_at: Instant::now(),
+            steps: Vec::new(),
+        },
+    );
+    Ok(())
+}
+
+/// 停止录制并返回脚本
+#[tauri::command]
+pub fn maa_record_inputs_stop(instance_id: String) -> Result<InputScript, String> {
+    let mut guard = RECORDINGS.lock();
+    let recording = guard
+        .as_mut()
+        .and_then(|m| m.remove(&instance_id))
+        .ok_or_else(|| tr!("error.recorder.not_recording"))?;
+    info!(
+        "maa_record_inputs_stop: {} ({} steps)",
+        instance_id,
+        recording.steps.len()
+    );
+    Ok(InputScript {
+        version: INPUT_SCRIPT_VERSION,
+        steps: recording.steps,
+    })
+}
+
+/// 回放脚本的内部实现，返回已执行的步数
+pub fn replay_inputs_impl(
+    state: &MaaState,
+    instance_id: &str,
+    script: &InputScript,
+    speed: f64,
+    stop: &AtomicBool,
+) -> MxuResult<usize> {
+    if script.version > INPUT_SCRIPT_VERSION {
+        return Err(tr!(
+            "error.recorder.unsupported_version",
+            version = script.version
+        )
+        .into());
+    }
+    let controller = {
+        let instance = state
+            .instances
+            .get(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        instance
+            .controller
+            .clone()
+            .ok_or_else(MxuError::controller_not_connected)?
+    };
+    let speed = if speed > 0.0 { speed } else { 1.0 };
+
+    for (index, step) in script.steps.iter().enumerate() {
+        // 分段等待，期间响应停止请求
+        let deadline =
+            Instant::now() + Duration::from_millis((step.delay_ms as f64 / speed) as u64);
+        while Instant::now() < deadline {
+            if stop.load(Ordering::SeqCst) {
+                return Ok(index);
+            }
+            std::thread::sleep(Duration::from_millis(20).min(deadline - Instant::now()));
+        }
+        if stop.load(Ordering::SeqCst) {
+            return Ok(index);
+        }
+
+        match step.input {
+            RecordedInput::Click { x, y } => controller.post_click(x, y),
+            RecordedInput::Swipe {
+                x1,
+                y1,
+                x2,
+                y2,
+                duration,
+            } => controller.post_swipe(x1, y1, x2, y2, duration),
+        }
+        .map_err(|e| tr!("error.recorder.step_failed", step = index + 1, error = e))?;
+    }
+    Ok(script.steps.len())
+}
+
+/// 回放输入脚本
+///
+/// `speed` 为回放倍速（默认 1.0），返回已执行的步数（被停止时小于总步数）
+#[tauri::command]
+pub async fn maa_replay_inputs(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    script: InputScript,
+    speed: Option<f64>,
+) -> MxuResult<usize> {
+    info!(
+        "maa_replay_inputs: {} ({} steps)",
+        instance_id,
+        script.steps.len()
+    );
+    let stop = Arc::new(AtomicBool::new(false));
+    {
+        let mut guard = REPLAY_STOPS.lock();
+        if let Some(prev) = guard
+            .get_or_insert_with(HashMap::new)
+            .insert(instance_id.clone(), stop.clone())
+        {
+            prev.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let state = state.inner().clone();
+    let id = instance_id.clone();
+    let stop_flag = stop.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        replay_inputs_impl(&state, &id, &script, speed.unwrap_or(1.0), &stop_flag)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    if let Some(map) = REPLAY_STOPS.lock().as_mut() {
+        if map.get(&instance_id).is_some_and(|s| Arc::ptr_eq(s, &stop)) {
+            map.remove(&instance_id);
+        }
+    }
+    result
+}
+
+/// 停止正在进行的回放
+#[tauri::command]
+pub fn maa_replay_inputs_stop(instance_id: String) -> Result<(), String> {
+    let guard = REPLAY_STOPS.lock();
+    if let Some(stop) = guard.as_ref().and_then(|m| m.get(&instance_id)) {
+        stop.store(true, Ordering::SeqCst);
+    }
+    Ok(())
+}
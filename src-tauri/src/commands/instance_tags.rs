@@ -0,0 +1,278 @@
+//! 实例分组与标签
+//!
+//! 标签保存在 MXU 配置的 `instances[].tags` 中（随配置持久化），
+//! 可按标签筛选实例，并对同一标签下的所有实例批量开始/停止任务。
+
+use std::sync::Arc;
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::app_config::AppConfigState;
+use super::types::MaaState;
+use crate::i18n::tr;
+
+/// 单个实例的标签上限
+const MAX_TAGS_PER_INSTANCE: usize = 32;
+
+/// 实例概要（配置信息 + 运行状态）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceSummary {
+    pub id: String,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub connected: bool,
+    pub resource_loaded: bool,
+    pub is_running: bool,
+}
+
+/// 实例筛选条件（各字段均为可选，同时指定时取交集）
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceFilter {
+    /// 包含该标签（不区分大小写）
+    pub tag: Option<String>,
+    /// 名称包含该关键字（不区分大小写）
+    pub name: Option<String>,
+    /// 仅返回运行中 / 未运行的实例
+    pub running: Option<bool>,
+}
+
+/// 规范化标签：去除首尾空白、丢弃空标签、按大小写不敏感去重
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim();
+        if tag.is_empty() || result.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            continue;
+        }
+        result.push(tag.to_string());
+    }
+    result
+}
+
+fn read_tags(inst: &serde_json::Value) -> Vec<String> {
+    inst.get("tags")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|t| t.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 按筛选条件列出已配置的实例
+pub fn list_instances_impl(
+    maa_state: &MaaState,
+    app_config: &AppConfigState,
+    filter: &InstanceFilter,
+) -> Result<Vec<InstanceSummary>, String> {
+    let configured: Vec<(String, String, Vec<String>)> = {
+        let config = app_config.config.lock();
+        config
+            .get("instances")
+            .and_then(|v| v.as_array())
+            .map(|list| {
+                list.iter()
+                    .filter_map(|inst| {
+                        let id = inst.get("id")?.as_str()?.to_string();
+                        let name = inst
+                            .get("name")
+                            .and_then(|n| n.as_str())
+                            .unwrap_or_default()
+                            .to_string();
+                        Some((id, name, read_tags(inst)))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let tag = filter
+        .tag
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty());
+    let name = filter
+        .name
+        .as_deref()
+        .map(|n| n.trim().to_lowercase())
+        .filter(|n| !n.is_empty());
+
+    Ok(configured
+        .into_iter()
+        .map(|(id, name, tags)| {
+            let guard = maa_state.instances.get(&id);
+            let runtime = guard.as_deref();
+            InstanceSummary {
+                connected: runtime
+                    .and_then(|r| r.controller.as_ref())
+                    .is_some_and(|c| c.connected()),
+                resource_loaded: runtime
+                    .and_then(|r| r.resource.as_ref())
+                    .is_some_and(|r| r.loaded()),
+                is_running: runtime
+                    .and_then(|r| r.tasker.as_ref())
+                    .is_some_and(|t| t.running()),
+                id,
+                name,
+                tags,
+            }
+        })
+        .filter(|s| tag.is_none_or(|tag| s.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))))
+        .filter(|s| {
+            name.as_deref()
+                .is_none_or(|n| s.name.to_lowercase().contains(n))
+        })
+        .filter(|s| filter.running.is_none_or(|r| s.is_running == r))
+        .collect())
+}
+
+/// 设置实例标签并写回配置
+pub fn set_instance_tags_impl(
+    app: &AppHandle,
+    app_config: &AppConfigState,
+    instance_id: &str,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let tags = normalize_tags(tags);
+    if tags.len() > MAX_TAGS_PER_INSTANCE {
+        return Err(tr!("error.tags.too_many", max = MAX_TAGS_PER_INSTANCE));
+    }
+
+    let mut config = app_config.config.lock().clone();
+    let inst = config
+        .get_mut("instances")
+        .and_then(|v| v.as_array_mut())
+        .and_then(|list| {
+            list.iter_mut()
+                .find(|inst| inst.get("id").and_then(|v| v.as_str()) == Some(instance_id))
+        })
+        .ok_or_else(|| tr!("error.instance_not_found"))?;
+    let Some(obj) = inst.as_object_mut() else {
+        return Err(tr!("error.config.invalid_instance"));
+    };
+    if tags.is_empty() {
+        obj.remove("tags");
+    } else {
+        obj.insert("tags".to_string(), serde_json::json!(tags));
+    }
+
+    app_config.save_config(config)?;
+    super::utils::emit_config_changed(app);
+    info!("Instance {} tags set to {:?}", instance_id, tags);
+    Ok(tags)
+}
+
+/// 请求前端按已保存配置依次启动带有该标签的实例（异步，返回将要启动的实例 ID）
+pub fn start_tasks_by_tag_impl(
+    app: &AppHandle,
+    maa_state: &MaaState,
+    app_config: &AppConfigState,
+    tag: &str,
+) -> Result<Vec<String>, String> {
+    let filter = InstanceFilter {
+        tag: Some(tag.to_string()),
+        running: Some(false),
+        ..Default::default()
+    };
+    let ids: Vec<String> = list_instances_impl(maa_state, app_config, &filter)?
+        .into_iter()
+        .map(|s| s.id)
+        .collect();
+    if ids.is_empty() {
+        return Ok(ids);
+    }
+
+    let window = app
+        .get_webview_window("main")
+        .ok_or_else(|| tr!("error.api.window_not_loaded"))?;
+    window
+        .emit(
+            "tag-start-tasks",
+            serde_json::json!({ "tag": tag, "instanceIds": ids }),
+        )
+        .map_err(|e| e.to_string())?;
+    info!(
+        "Start requested for {} instance(s) tagged '{}'",
+        ids.len(),
+        tag
+    );
+    Ok(ids)
+}
+
+/// 停止带有该标签的所有运行中实例（返回已停止的实例 ID）
+pub fn stop_tasks_by_tag_impl(
+    app: &AppHandle,
+    maa_state: &MaaState,
+    app_config: &AppConfigState,
+    tag: &str,
+) -> Result<Vec<String>, String> {
+    let filter = InstanceFilter {
+        tag: Some(tag.to_string()),
+        running: Some(true),
+        ..Default::default()
+    };
+    let mut stopped = Vec::new();
+    for summary in list_instances_impl(maa_state, app_config, &filter)? {
+        match super::maa_core::stop_task_impl(maa_state, &summary.id) {
+            Ok(()) => {
+                super::utils::emit_state_changed(app, &summary.id, "task-stopped");
+                stopped.push(summary.id);
+            }
+            Err(e) => warn!("Failed to stop instance {}: {}", summary.id, e),
+        }
+    }
+    info!("Stopped {} instance(s) tagged '{}'", stopped.len(), tag);
+    Ok(stopped)
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 设置实例标签（空列表表示清除）
+#[tauri::command]
+pub fn mxu_set_instance_tags(
+    app: AppHandle,
+    app_config: State<Arc<AppConfigState>>,
+    instance_id: String,
+    tags: Vec<String>,
+) -> Result<Vec<String>, String> {
+    set_instance_tags_impl(&app, &app_config, &instance_id, tags)
+}
+
+/// 列出实例（可按标签、名称、运行状态筛选）
+#[tauri::command]
+pub fn mxu_list_instances(
+    state: State<Arc<MaaState>>,
+    app_config: State<Arc<AppConfigState>>,
+    filter: Option<InstanceFilter>,
+) -> Result<Vec<InstanceSummary>, String> {
+    list_instances_impl(&state, &app_config, &filter.unwrap_or_default())
+}
+
+/// 启动带有指定标签的所有未运行实例
+#[tauri::command]
+pub fn mxu_start_tasks_by_tag(
+    app: AppHandle,
+    state: State<Arc<MaaState>>,
+    app_config: State<Arc<AppConfigState>>,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    start_tasks_by_tag_impl(&app, &state, &app_config, &tag)
+}
+
+/// 停止带有指定标签的所有运行中实例
+#[tauri::command]
+pub fn mxu_stop_tasks_by_tag(
+    app: AppHandle,
+    state: State<Arc<MaaState>>,
+    app_config: State<Arc<AppConfigState>>,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    stop_tasks_by_tag_impl(&app, &state, &app_config, &tag)
+}
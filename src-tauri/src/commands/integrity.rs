@@ -0,0 +1,255 @@
+//! 程序文件完整性校验
+//!
+//! 发布包根目录附带 `integrity.json` 清单（关键文件的相对路径 -> SHA-256）及其 Ed25519 签名
+//! `integrity.json.sig`。启动时在后台校验清单签名并逐一比对文件哈希，发现文件缺失、被修改
+//! 或更新只完成了一半时发送 `integrity-warning` 事件。清单格式：
+//!
+//! ```json
+//! { "version": "v1.2.3", "files": { "interface.json": "<sha256>", "maafw/MaaFramework.dll": "<sha256>" } }
+//! ```
+//!
+//! 通过 `settings.integrity` 配置：
+//! - `enabled`: 启动时是否校验（默认 true）
+//! - `policy`: `warn`（仅提示，默认）/ `block`（校验未通过时拒绝启动任务）
+//!
+//! 未内置发布公钥的开发构建：没有清单时跳过校验，有清单时仅比对哈希、不校验清单签名。
+//! 内置了发布公钥时，清单或其签名缺失均视为校验未通过。
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::Local;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use super::utils::{get_exe_directory, is_safe_relative_path};
+use crate::i18n::tr;
+
+pub const MANIFEST_FILE: &str = "integrity.json";
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static BLOCK_ON_FAILURE: AtomicBool = AtomicBool::new(false);
+/// 最近一次校验结果
+static LAST_REPORT: Mutex<Option<IntegrityReport>> = Mutex::new(None);
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    version: Option<String>,
+    files: BTreeMap<String, String>,
+}
+
+/// 完整性校验结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    pub checked_at: String,
+    /// 是否找到清单（开发构建没有清单）
+    pub manifest_found: bool,
+    /// 清单记录的版本
+    pub version: Option<String>,
+    /// 清单签名状态：valid / invalid / missing / noKey
+    pub signature: String,
+    /// 已比对的文件数
+    pub checked: usize,
+    /// 缺失的文件
+    pub missing: Vec<String>,
+    /// 哈希不一致的文件
+    pub mismatched: Vec<String>,
+    /// 校验是否通过
+    pub ok: bool,
+}
+
+/// 读取 `settings.integrity`（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let integrity = config.get("settings").and_then(|s| s.get("integrity"));
+    ENABLED.store(
+        integrity
+            .and_then(|i| i.get("enabled"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+        Ordering::Relaxed,
+    );
+    BLOCK_ON_FAILURE.store(
+        integrity
+            .and_then(|i| i.get("policy"))
+            .and_then(|v| v.as_str())
+            == Some("block"),
+        Ordering::Relaxed,
+    );
+}
+
+/// 校验清单签名
+fn check_signature(manifest_path: &Path, data: &[u8]) -> &'static str {
+    if !super::signature::has_public_key() {
+        return "noKey";
+    }
+    let sig_path = super::signature::signature_path(manifest_path);
+    let Ok(signature) = std::fs::read_to_string(&sig_path) else {
+        return "missing";
+    };
+    match super::signature::verify(data, &signature) {
+        Ok(()) => "valid",
+        Err(e) => {
+            warn!("Integrity manifest signature rejected: {}", e);
+            "invalid"
+        }
+    }
+}
+
+/// 校验安装目录下的关键文件（阻塞，需读取全部清单文件）
+pub fn verify_integrity_impl() -> Result<IntegrityReport, String> {
+    let root = get_exe_directory()?;
+    let manifest_path = root.join(MANIFEST_FILE);
+    let checked_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let has_key = super::signature::has_public_key();
+
+    if !manifest_path.exists() {
+        // 发布构建必须附带清单，缺失说明安装包不完整或清单被删除
+        return Ok(IntegrityReport {
+            checked_at,
+            manifest_found: false,
+            version: None,
+            signature: "missing".to_string(),
+            checked: 0,
+            missing: Vec::new(),
+            mismatched: Vec::new(),
+            ok: !has_key,
+        });
+    }
+
+    let data = std::fs::read(&manifest_path).map_err(|e| {
+        tr!(
+            "error.fs.read_file",
+            path = manifest_path.display(),
+            error = e
+        )
+    })?;
+    let signature = check_signature(&manifest_path, &data);
+    let manifest: Manifest = serde_json::from_slice(&data)
+        .map_err(|e| tr!("error.integrity.invalid_manifest", error = e))?;
+
+    let mut missing = Vec::new();
+    let mut mismatched = Vec::new();
+    for (rel, expected) in &manifest.files {
+        // 清单中的路径必须是安装目录内的相对路径
+        if !is_safe_relative_path(rel) {
+            warn!("Skipping unsafe integrity manifest path: {}", rel);
+            continue;
+        }
+        let path = root.join(rel);
+        if !path.is_file() {
+            missing.push(rel.clone());
+            continue;
+        }
+        match super::file_ops::digest_file::<sha2::Sha256>(&path) {
+            Ok(actual) if actual.eq_ignore_ascii_case(expected.trim()) => {}
+            Ok(_) => mismatched.push(rel.clone()),
+            Err(e) => {
+                warn!("{}", e);
+                mismatched.push(rel.clone());
+            }
+        }
+    }
+
+    // 内置了公钥却没有有效签名时，清单本身不可信；仅未内置公钥的构建接受 noKey
+    let signature_ok = match signature {
+        "valid" => true,
+        "noKey" => !has_key,
+        _ => false,
+    };
+    Ok(IntegrityReport {
+        checked_at,
+        manifest_found: true,
+        version: manifest.version,
+        signature: signature.to_string(),
+        checked: manifest.files.len(),
+        ok: signature_ok && missing.is_empty() && mismatched.is_empty(),
+        missing,
+        mismatched,
+    })
+}
+
+/// 校验并记录结果，未通过时发送 `integrity-warning` 事件
+fn run_check(app: &AppHandle) -> Result<IntegrityReport, String> {
+    let report = verify_integrity_impl()?;
+    if report.ok {
+        info!(
+            "Integrity check passed ({} files, signature {})",
+            report.checked, report.signature
+        );
+    } else {
+        warn!(
+            "Integrity check failed: signature {}, {} missing, {} modified",
+            report.signature,
+            report.missing.len(),
+            report.mismatched.len()
+        );
+        if let Err(e) = app.emit("integrity-warning", &report) {
+            warn!("Failed to emit integrity-warning: {}", e);
+        }
+    }
+    *LAST_REPORT.lock() = Some(report.clone());
+    Ok(report)
+}
+
+/// 启动时在后台校验一次
+pub fn start(app: &AppHandle) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let app = app.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = run_check(&app) {
+            warn!("Integrity check skipped: {}", e);
+        }
+    });
+}
+
+/// 启动任务前检查：`block` 策略下最近一次校验未通过时拒绝启动
+///
+/// 启动时的后台校验尚未完成（如启动后立即自动执行）时，先等待一次校验完成。
+pub async fn gate_start(app: &AppHandle) -> Result<(), String> {
+    if !BLOCK_ON_FAILURE.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+    let pending = ENABLED.load(Ordering::Relaxed) && LAST_REPORT.lock().is_none();
+    if pending {
+        let app = app.clone();
+        tauri::async_runtime::spawn_blocking(move || run_check(&app))
+            .await
+            .map_err(|e| tr!("error.task_panicked", error = e))??;
+    }
+    let last = LAST_REPORT.lock();
+    match last.as_ref() {
+        Some(report) if !report.ok => Err(tr!(
+            "error.integrity.failed",
+            missing = report.missing.len(),
+            mismatched = report.mismatched.len(),
+            signature = report.signature
+        )),
+        _ => Ok(()),
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取最近一次完整性校验结果（尚未校验时为 null）
+#[tauri::command]
+pub fn mxu_get_integrity_report() -> Result<Option<IntegrityReport>, String> {
+    Ok(LAST_REPORT.lock().clone())
+}
+
+/// 立即重新校验程序文件完整性
+#[tauri::command]
+pub async fn mxu_verify_integrity(app: AppHandle) -> Result<IntegrityReport, String> {
+    tauri::async_runtime::spawn_blocking(move || run_check(&app))
+        .await
+        .map_err(|e| tr!("error.task_panicked", error = e))?
+}
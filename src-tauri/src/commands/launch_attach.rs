@@ -0,0 +1,164 @@
+//! 启动程序并自动连接 Win32 窗口
+//!
+//! 将"启动游戏 → 等待窗口出现 → 搜索窗口并连接"三步合并为 `maa_launch_and_attach`：
+//! 启动 exe 后轮询桌面窗口，标题匹配 `window_regex` 的窗口出现后，
+//! 以该窗口句柄填充控制器配置（Win32 / Gamepad）并发起连接。
+//! 优先选择属于启动进程的窗口；通过启动器拉起游戏时进程不同，退回第一个匹配的窗口。
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+use super::maa_core::{connect_controller_impl, find_win32_windows_impl};
+use super::types::{ControllerConfig, MaaState, Win32Window};
+use super::utils::{build_launch_command, emit_callback_event};
+use crate::i18n::tr;
+
+/// 默认等待窗口出现的时间
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
+/// 窗口轮询间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 启动并连接的结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchAttachResult {
+    /// 启动的进程 ID
+    pub pid: u32,
+    /// 连接的窗口
+    pub window: Win32Window,
+    /// 连接请求 ID（通过 maa-callback 获取连接结果）
+    pub conn_id: i64,
+}
+
+/// 从匹配的窗口中选出目标：优先属于启动进程的窗口
+fn pick_window(windows: Vec<Win32Window>, pid: u32) -> Option<Win32Window> {
+    let own = windows.iter().position(|w| w.pid == Some(pid));
+    match own {
+        Some(index) => windows.into_iter().nth(index),
+        None => windows.into_iter().next(),
+    }
+}
+
+/// 启动程序，等待窗口出现后连接控制器
+///
+/// `config` 为 Win32 / Gamepad 控制器配置，其中的 `handle` 会被替换为找到的窗口句柄。
+pub async fn launch_and_attach_impl(
+    app: &AppHandle,
+    state: Arc<MaaState>,
+    instance_id: &str,
+    exe_path: &str,
+    window_regex: &str,
+    timeout_secs: u64,
+    mut config: ControllerConfig,
+) -> Result<LaunchAttachResult, String> {
+    regex::Regex::new(window_regex).map_err(|e| tr!("error.launch.invalid_regex", error = e))?;
+    if !matches!(
+        config,
+        ControllerConfig::Win32 { .. } | ControllerConfig::Gamepad { .. }
+    ) {
+        return Err(tr!("error.launch.unsupported_controller"));
+    }
+    let exe = std::path::Path::new(exe_path);
+    if !exe.is_file() {
+        return Err(tr!("error.launch.exe_missing", path = exe_path));
+    }
+
+    let mut cmd = build_launch_command(exe_path, &[], false);
+    if let Some(parent) = exe.parent() {
+        cmd.current_dir(parent);
+    }
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| tr!("error.launch.spawn", path = exe_path, error = e))?;
+    let pid = child.id();
+    info!(
+        "launch_and_attach: started {} (pid {}), waiting for window /{}/",
+        exe_path, pid, window_regex
+    );
+    // 回收子进程，避免僵尸进程（游戏本身的生命周期不受影响）
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs.max(1));
+    let window = loop {
+        let windows =
+            find_win32_windows_impl(state.clone(), None, Some(window_regex.to_string())).await?;
+        if let Some(window) = pick_window(windows, pid) {
+            break window;
+        }
+        if Instant::now() >= deadline {
+            return Err(tr!("error.launch.window_timeout", secs = timeout_secs));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    };
+    if window.pid != Some(pid) {
+        warn!(
+            "launch_and_attach: window {:#x} belongs to pid {:?}, not the launched process {}",
+            window.handle, window.pid, pid
+        );
+    }
+
+    if let ControllerConfig::Win32 { handle, .. } | ControllerConfig::Gamepad { handle, .. } =
+        &mut config
+    {
+        *handle = window.handle;
+    }
+    // 与 maa_connect_controller 一致：Gamepad 通过虚拟手柄输入，不受 UIPI 限制，仅检查 Win32
+    if matches!(config, ControllerConfig::Win32 { .. }) {
+        super::elevation::check_before_connect(app, instance_id, window.handle)?;
+    }
+
+    let app_clone = app.clone();
+    let id = instance_id.to_string();
+    let conn_id = connect_controller_impl(
+        state,
+        instance_id.to_string(),
+        config,
+        Arc::new(move |msg, detail| emit_callback_event(&app_clone, &id, msg, detail)),
+    )
+    .await?;
+    super::utils::emit_state_changed(app, instance_id, "connected");
+    super::win32_dpi::check_after_connect(app, instance_id, window.handle);
+    info!(
+        "launch_and_attach: instance {} attached to window {:#x} ({})",
+        instance_id, window.handle, window.window_name
+    );
+
+    Ok(LaunchAttachResult {
+        pid,
+        window,
+        conn_id,
+    })
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 启动程序，等待标题匹配 `window_regex` 的窗口出现后自动连接控制器
+#[tauri::command]
+pub async fn maa_launch_and_attach(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    exe_path: String,
+    window_regex: String,
+    timeout: Option<u64>,
+    config: ControllerConfig,
+) -> Result<LaunchAttachResult, String> {
+    launch_and_attach_impl(
+        &app,
+        state.inner().clone(),
+        &instance_id,
+        &exe_path,
+        &window_regex,
+        timeout.unwrap_or(DEFAULT_TIMEOUT_SECS),
+        config,
+    )
+    .await
+}
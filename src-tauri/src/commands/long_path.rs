@@ -0,0 +1,216 @@
+//! Windows 长路径检查
+//!
+//! MaaFramework 内部按 260 字符（MAX_PATH）处理路径，MXU 解压到层级很深的目录时，
+//! 资源文件的完整路径可能超出限制，表现为资源加载失败或找不到文件。
+//! 初始化 MaaFramework 前检查安装目录下最长的文件路径，并读取注册表
+//! `HKLM\SYSTEM\CurrentControlSet\Control\FileSystem\LongPathsEnabled`：
+//! - 超出限制且系统未开启长路径支持时，`maa_init` 直接返回 `PATH_TOO_LONG` 错误
+//! - 超出限制但已开启长路径支持，或安装目录本身过长时，仅给出警告
+//!
+//! 用户可通过 `mxu_enable_long_paths` 开启系统长路径支持（需管理员授权）。程序清单
+//! （`windows-app-manifest.xml`）已声明 `longPathAware`，开启后重启 MXU 即可生效。
+
+use std::path::{Path, PathBuf};
+
+use log::warn;
+use serde::Serialize;
+
+use super::error::{ErrorCode, MxuError};
+use super::utils::get_exe_directory;
+use crate::i18n::tr;
+
+/// Windows 传统路径长度上限（含结尾 NUL）
+pub const MAX_PATH: usize = 260;
+/// 安装目录超过该长度时提示（为调试图像、缓存等运行时生成的文件预留余量）
+const EXE_DIR_WARN_LEN: usize = 150;
+/// 扫描的最大目录深度
+const MAX_SCAN_DEPTH: usize = 16;
+/// 不扫描的运行时目录（其中的文件由 MXU / MaaFramework 生成）
+const SKIPPED_DIRS: [&str; 5] = ["cache", "debug", "logs", "config", "old"];
+
+/// 长路径检查结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongPathReport {
+    pub exe_dir: String,
+    pub exe_dir_len: usize,
+    /// 安装目录下最长的文件路径
+    pub longest_path: Option<String>,
+    pub longest_len: usize,
+    pub limit: usize,
+    /// 系统是否开启长路径支持（非 Windows 为 None）
+    pub long_paths_enabled: Option<bool>,
+    /// ok / warn / fail
+    pub status: String,
+    pub message: String,
+}
+
+/// 路径长度按 UTF-16 编码单元计算（与 Win32 API 一致）
+fn path_len(path: &Path) -> usize {
+    path.to_string_lossy().encode_utf16().count()
+}
+
+/// 查找目录下最长的文件路径
+fn find_longest(dir: &Path, depth: usize, longest: &mut Option<(PathBuf, usize)>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let skipped = depth == 0
+                && SKIPPED_DIRS
+                    .iter()
+                    .any(|d| entry.file_name().eq_ignore_ascii_case(d));
+            if !skipped && depth < MAX_SCAN_DEPTH {
+                find_longest(&path, depth + 1, longest);
+            }
+        } else {
+            let len = path_len(&path);
+            if longest.as_ref().is_none_or(|(_, max)| len > *max) {
+                *longest = Some((path, len));
+            }
+        }
+    }
+}
+
+/// 读取系统长路径支持开关
+#[cfg(windows)]
+pub fn long_paths_enabled() -> Option<bool> {
+    use winsafe::co::{KEY, REG_OPTION, RRF};
+    use winsafe::{RegistryValue, HKEY};
+
+    let key = HKEY::LOCAL_MACHINE
+        .RegOpenKeyEx(
+            Some(r"SYSTEM\CurrentControlSet\Control\FileSystem"),
+            REG_OPTION::NoValue,
+            KEY::READ,
+        )
+        .ok()?;
+    match key.RegGetValue(None, Some("LongPathsEnabled"), RRF::RT_REG_DWORD) {
+        Ok(RegistryValue::Dword(value)) => Some(value != 0),
+        _ => Some(false),
+    }
+}
+
+#[cfg(not(windows))]
+pub fn long_paths_enabled() -> Option<bool> {
+    None
+}
+
+/// 检查安装目录的路径长度
+pub fn check_long_paths_impl() -> Result<LongPathReport, String> {
+    let exe_dir = get_exe_directory()?;
+    let exe_dir_len = path_len(&exe_dir);
+    let mut longest = None;
+    find_longest(&exe_dir, 0, &mut longest);
+    let longest_len = longest.as_ref().map_or(exe_dir_len, |(_, len)| *len);
+    let enabled = long_paths_enabled();
+
+    let too_long = cfg!(windows) && longest_len >= MAX_PATH;
+    let (status, message) = if too_long && enabled != Some(true) {
+        (
+            "fail",
+            tr!("long_path.too_long", len = longest_len, limit = MAX_PATH),
+        )
+    } else if too_long {
+        ("warn", tr!("long_path.long_but_enabled", len = longest_len))
+    } else if cfg!(windows) && exe_dir_len > EXE_DIR_WARN_LEN {
+        (
+            "warn",
+            tr!(
+                "long_path.exe_dir_long",
+                len = exe_dir_len,
+                limit = MAX_PATH
+            ),
+        )
+    } else {
+        ("ok", tr!("long_path.ok", len = longest_len))
+    };
+
+    Ok(LongPathReport {
+        exe_dir: exe_dir.display().to_string(),
+        exe_dir_len,
+        longest_path: longest.map(|(path, _)| path.display().to_string()),
+        longest_len,
+        limit: MAX_PATH,
+        long_paths_enabled: enabled,
+        status: status.to_string(),
+        message,
+    })
+}
+
+/// 初始化 MaaFramework 前调用：路径超出限制且无法通过系统设置绕过时返回错误
+pub fn ensure_path_length() -> Result<(), MxuError> {
+    let report = check_long_paths_impl()?;
+    match report.status.as_str() {
+        "fail" => {
+            warn!("{}", report.message);
+            let mut err = MxuError::new(ErrorCode::PathTooLong, report.message);
+            if let Some(path) = report.longest_path {
+                err = err.with_context(path);
+            }
+            Err(err)
+        }
+        "warn" => {
+            warn!("{}", report.message);
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 开启系统长路径支持（写入 HKLM，会弹出 UAC 授权窗口）
+#[cfg(windows)]
+fn enable_long_paths_impl() -> Result<bool, String> {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    if long_paths_enabled() == Some(true) {
+        return Ok(true);
+    }
+    let script = "Start-Process -FilePath reg -Verb RunAs -Wait -WindowStyle Hidden -ArgumentList \
+        'add','HKLM\\SYSTEM\\CurrentControlSet\\Control\\FileSystem','/v','LongPathsEnabled','/t','REG_DWORD','/d','1','/f'";
+    let output = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", script])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|e| tr!("error.long_path.powershell", error = e))?;
+    if !output.status.success() {
+        return Err(tr!(
+            "error.long_path.enable_failed",
+            error = String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    let enabled = long_paths_enabled() == Some(true);
+    log::info!("LongPathsEnabled set, effective: {}", enabled);
+    Ok(enabled)
+}
+
+#[cfg(not(windows))]
+fn enable_long_paths_impl() -> Result<bool, String> {
+    Err(tr!("error.long_path.windows_only"))
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 检查安装目录路径长度与系统长路径支持状态
+#[tauri::command]
+pub async fn mxu_check_long_paths() -> Result<LongPathReport, String> {
+    tauri::async_runtime::spawn_blocking(check_long_paths_impl)
+        .await
+        .map_err(|e| tr!("error.task_panicked", error = e))?
+}
+
+/// 开启 Windows 系统长路径支持（需管理员授权，返回开启后的状态）
+#[tauri::command]
+pub async fn mxu_enable_long_paths() -> Result<bool, String> {
+    tauri::async_runtime::spawn_blocking(enable_long_paths_impl)
+        .await
+        .map_err(|e| tr!("error.task_panicked", error = e))?
+}
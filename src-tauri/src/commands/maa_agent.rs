@@ -8,19 +8,22 @@ use std::fs::OpenOptions;
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Component, Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::thread;
 
 use chrono::Local;
-use tauri::{Emitter, Manager, State};
+use parking_lot::Mutex;
+use tauri::{Emitter, State};
 
 use maa_framework::agent_client::AgentClient;
 use maa_framework::controller::Controller;
 use maa_framework::resource::Resource;
 use maa_framework::tasker::Tasker;
 
-use super::types::{AgentConfig, MaaState, TaskConfig};
+use super::error::{ErrorCode, MxuError, MxuResult};
+use super::types::{AgentConfig, AgentLaunch, MaaState, TaskConfig};
 use super::utils::{emit_callback_event, get_logs_dir, handle_task_callback, normalize_path};
+use crate::i18n::tr;
 use regex::Regex;
 use std::sync::LazyLock;
 use std::time::{Duration, Instant};
@@ -64,7 +67,7 @@ impl AgentOutputBatcher {
 
     fn enqueue(self: &Arc<Self>, stream: &str, line: &str) {
         let should_spawn = {
-            let mut state = self.state.lock().unwrap();
+            let mut state = self.state.lock();
             state.lines.push(line.to_string());
             match state.first_stream.as_ref() {
                 None => state.first_stream = Some(stream.to_string()),
@@ -90,7 +93,7 @@ impl AgentOutputBatcher {
     fn flush_loop(self: Arc<Self>) {
         loop {
             let deadline = {
-                let state = self.state.lock().unwrap();
+                let state = self.state.lock();
                 match state.flush_deadline {
                     Some(deadline) => deadline,
                     None => {
@@ -108,7 +111,7 @@ impl AgentOutputBatcher {
             }
 
             let payload = {
-                let mut state = self.state.lock().unwrap();
+                let mut state = self.state.lock();
                 match state.flush_deadline {
                     Some(current_deadline) if Instant::now() >= current_deadline => {
                         if state.lines.is_empty() {
@@ -145,7 +148,7 @@ impl AgentOutputBatcher {
                 }
                 None => {
                     let should_exit = {
-                        let state = self.state.lock().unwrap();
+                        let state = self.state.lock();
                         !state.flush_running
                     };
                     if should_exit {
@@ -157,7 +160,7 @@ impl AgentOutputBatcher {
     }
 
     fn finish_flush_loop(&self) {
-        let mut state = self.state.lock().unwrap();
+        let mut state = self.state.lock();
         state.flush_running = false;
     }
 }
@@ -166,14 +169,16 @@ impl AgentOutputBatcher {
 fn emit_agent_output(app: &tauri::AppHandle, instance_id: &str, stream: &str, line: &str) {
     let clean_line = strip_ansi_escapes(line);
 
-    // 广播到所有 WebSocket 客户端
-    if let Some(ws) = app.try_state::<Arc<crate::ws_broadcast::WsBroadcast>>() {
-        ws.send(crate::ws_broadcast::WsEvent::AgentOutput {
+    // 广播到所有 WebSocket 客户端，并留存供前端补取
+    super::utils::broadcast_event(
+        app,
+        Some(instance_id),
+        crate::ws_broadcast::WsEvent::AgentOutput {
             instance_id: instance_id.to_string(),
             stream: stream.to_string(),
             line: clean_line.clone(),
-        });
-    }
+        },
+    );
 
     // 发送到 Tauri WebView
     let event = AgentOutputEvent {
@@ -247,7 +252,7 @@ fn classify_child_exec(child_exec: &str) -> ChildExecKind {
 /// - 裸命令名（如 `python` / `node`）-> 原样返回，由系统 PATH 解析
 /// - 相对路径型 child_exec -> 先基于 cwd 拼接，再通过 `normalize_path` 规范化
 /// - 绝对路径 / 带盘符前缀路径 -> 不拼接 cwd，但会通过 `normalize_path` 规范化
-fn resolve_child_exec_path(child_exec: &str, cwd: &str) -> PathBuf {
+pub(crate) fn resolve_child_exec_path(child_exec: &str, cwd: &str) -> PathBuf {
     match classify_child_exec(child_exec) {
         // 空字符串由上层提前校验；这里保守返回原值，避免误拼 cwd。
         ChildExecKind::Empty => PathBuf::from(child_exec),
@@ -261,7 +266,75 @@ fn resolve_child_exec_path(child_exec: &str, cwd: &str) -> PathBuf {
     }
 }
 
+/// TCP 模式下 Agent 监听端口已确定
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AgentTcpBoundEvent {
+    instance_id: String,
+    agent_index: usize,
+    interface: String,
+    port: u16,
+}
+
+/// 检查固定端口是否可用
+fn check_tcp_port_available(interface: &str, port: u16) -> Result<(), String> {
+    std::net::TcpListener::bind((interface, port))
+        .map(drop)
+        .map_err(|e| {
+            tr!(
+                "error.agent.port_unavailable",
+                host = interface,
+                port = port,
+                error = e
+            )
+        })
+}
+
+/// 检查本机回环连接是否可用（防火墙或安全软件可能拦截）
+fn probe_loopback(interface: &str) -> Result<(), String> {
+    let listener = std::net::TcpListener::bind((interface, 0))
+        .map_err(|e| tr!("error.agent.listen_failed", error = e))?;
+    let addr = listener
+        .local_addr()
+        .map_err(|e| tr!("error.agent.local_addr_failed", error = e))?;
+    std::net::TcpStream::connect_timeout(&addr, Duration::from_secs(2))
+        .map(drop)
+        .map_err(|e| tr!("error.agent.connect_failed", addr = addr, error = e))
+}
+
+/// TCP 模式连接失败时生成诊断信息
+fn diagnose_tcp_failure(interface: &str, port: u16) -> String {
+    match probe_loopback(interface) {
+        Err(e) if cfg!(windows) => tr!("error.agent.diag_loopback_blocked", error = e),
+        Err(e) => tr!("error.agent.diag_loopback_failed", error = e),
+        Ok(()) => tr!(
+            "error.agent.diag_loopback_ok",
+            host = interface,
+            port = port
+        ),
+    }
+}
+
+/// 解析远程 Agent 地址（host:port）
+fn parse_remote_addr(addr: &str) -> Result<(String, u16), String> {
+    let (host, port) = addr
+        .rsplit_once(':')
+        .ok_or_else(|| tr!("error.agent.remote_addr_invalid", addr = addr))?;
+    let port = port
+        .parse::<u16>()
+        .ok()
+        .filter(|p| *p != 0)
+        .ok_or_else(|| tr!("error.agent.remote_port_invalid", addr = addr))?;
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if host.is_empty() {
+        return Err(tr!("error.agent.remote_host_missing", addr = addr));
+    }
+    Ok((host.to_string(), port))
+}
+
 /// 启动单个 Agent 子进程并完成连接
+///
+/// 配置了 `remote` 的 Agent 不启动子进程，由 `agent_relay` 主动连接远程 Agent 并转发。
 async fn start_single_agent(
     app: tauri::AppHandle,
     agent: AgentConfig,
@@ -273,20 +346,72 @@ async fn start_single_agent(
     controller: Controller,
     tasker: Tasker,
     pi_envs: Arc<HashMap<String, String>>,
-) -> Result<(AgentClient, std::process::Child), String> {
+) -> Result<(AgentClient, Option<std::process::Child>), String> {
     info!("[agent#{}] Starting agent: {:?}", agent_index, agent);
 
     // 将整个启动过程移入 spawn_blocking，避免阻塞 async runtime 线程
     tauri::async_runtime::spawn_blocking(move || {
-        let mut client = if tcp_compat_mode {
+        let remote = match agent.remote.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+            Some(addr) => Some(parse_remote_addr(addr)?),
+            None => None,
+        };
+        let tcp_compat_mode = tcp_compat_mode || remote.is_some();
+        let tcp_interface = match &remote {
+            Some((host, _)) => host.clone(),
+            None => agent
+                .tcp_interface
+                .clone()
+                .filter(|s| !s.trim().is_empty())
+                .unwrap_or_else(|| "127.0.0.1".to_string()),
+        };
+        // 非回环地址由 agent_relay 在该地址监听，转发到 AgentClient 的回环端口
+        let mut relay = None;
+        let mut client = if let Some((host, port)) = &remote {
+            debug!("[agent#{}] Creating TCP agent client for remote agent...", agent_index);
+            let client = AgentClient::create_tcp(0).map_err(|e| e.to_string())?;
+            let local_port = client
+                .identifier()
+                .and_then(|id| id.parse::<u16>().ok())
+                .ok_or_else(|| format!("Failed to get TCP port for agent #{}", agent_index))?;
+            let guard = super::agent_relay::dial(host, *port, local_port)?;
+            relay = Some((*port, guard));
+            client
+        } else if tcp_compat_mode {
             debug!("[agent#{}] Creating TCP agent client...", agent_index);
-            AgentClient::create_tcp(0).or_else(|e| {
-                warn!(
-                    "[agent#{}] TCP compat mode requested but failed: {}, falling back to default (IPC)",
-                    agent_index, e
-                );
-                AgentClient::new(None)
-            }).map_err(|e| e.to_string())?
+            let port = agent.tcp_port.unwrap_or(0);
+            if !super::agent_relay::is_loopback(&tcp_interface) {
+                let client = AgentClient::create_tcp(0).map_err(|e| e.to_string())?;
+                let local_port = client
+                    .identifier()
+                    .and_then(|id| id.parse::<u16>().ok())
+                    .ok_or_else(|| format!("Failed to get TCP port for agent #{}", agent_index))?;
+                relay = Some(super::agent_relay::listen(
+                    &tcp_interface,
+                    port,
+                    local_port,
+                    agent.tcp_allowed_peers.as_deref().unwrap_or_default(),
+                )?);
+                client
+            } else if port != 0 {
+                // 指定了固定端口时不回退到 IPC，端口不可用直接报错
+                check_tcp_port_available(&tcp_interface, port)?;
+                AgentClient::create_tcp(port).map_err(|e| {
+                    tr!(
+                        "error.agent.port_listen_failed",
+                        host = tcp_interface,
+                        port = port,
+                        error = e
+                    )
+                })?
+            } else {
+                AgentClient::create_tcp(0).or_else(|e| {
+                    warn!(
+                        "[agent#{}] TCP compat mode requested but failed: {}, falling back to default (IPC)",
+                        agent_index, e
+                    );
+                    AgentClient::new(None)
+                }).map_err(|e| e.to_string())?
+            }
         } else {
             debug!("[agent#{}] Creating default agent client...", agent_index);
             AgentClient::new(None).map_err(|e| e.to_string())?
@@ -297,165 +422,221 @@ async fn start_single_agent(
             return Err(e.to_string());
         }
 
-        let socket_id = client
-            .identifier()
-            .ok_or_else(|| format!("Failed to get identifier for agent #{}", agent_index))?;
+        let socket_id = match &relay {
+            Some((port, _)) => port.to_string(),
+            None => client
+                .identifier()
+                .ok_or_else(|| format!("Failed to get identifier for agent #{}", agent_index))?,
+        };
         info!("[agent#{}] Agent socket_id: {}", agent_index, socket_id);
 
-        // 启动子进程
-        let mut args = agent.child_args.clone().unwrap_or_default();
-        args.push(socket_id.clone());
-
-        let child_exec = agent.child_exec.trim();
-        if child_exec.is_empty() {
-            return Err(format!(
-                "Failed to spawn agent #{}: child_exec is empty",
-                agent_index
-            ));
+        // TCP 模式下 socket_id 即监听端口
+        let tcp_port = if tcp_compat_mode {
+            socket_id.parse::<u16>().ok()
+        } else {
+            None
+        };
+        if let Some(port) = tcp_port {
+            let event = AgentTcpBoundEvent {
+                instance_id: instance_id.clone(),
+                agent_index,
+                interface: tcp_interface.clone(),
+                port,
+            };
+            if let Err(e) = app.emit("agent-tcp-bound", event) {
+                warn!("Failed to emit agent-tcp-bound: {}", e);
+            }
         }
 
-        let exec_path = resolve_child_exec_path(child_exec, &cwd);
+        // 远程 Agent 已在其他机器上运行，不启动子进程
+        let mut child = if let Some((host, _)) = &remote {
+            info!(
+                "[agent#{}] Connecting to remote agent {}:{}",
+                agent_index, host, socket_id
+            );
+            None
+        } else {
+            // 启动子进程
+            let mut args = agent.child_args.clone().unwrap_or_default();
+            args.push(socket_id.clone());
+
+            let child_exec = agent.child_exec.trim();
+            if child_exec.is_empty() {
+                return Err(format!(
+                    "Failed to spawn agent #{}: child_exec is empty",
+                    agent_index
+                ));
+            }
 
-        info!(
-            "[agent#{}] Spawning process: {:?} {:?} in {}",
-            agent_index, exec_path, args, cwd
-        );
+            let mut exec_path = match super::python_env::venv_exec_override(child_exec, &cwd) {
+                Some(venv_python) => {
+                    info!(
+                        "[agent#{}] Using venv interpreter {:?} instead of {}",
+                        agent_index, venv_python, child_exec
+                    );
+                    venv_python
+                }
+                None => resolve_child_exec_path(child_exec, &cwd),
+            };
 
-        #[cfg(windows)]
-        let mut cmd = {
-            use std::os::windows::process::CommandExt;
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            let mut c = Command::new(&exec_path);
-            c.creation_flags(CREATE_NO_WINDOW);
-            c
-        };
+            // 脚本型 child_exec 通过 Node.js 运行，脚本路径作为第一个参数
+            if super::node_runtime::is_node_script(child_exec) {
+                let log_line = |line: &str| {
+                    emit_agent_output(&app, &instance_id, "stdout", &format!("[MXU] {}", line))
+                };
+                let (node, prefix) =
+                    super::node_runtime::prepare_node_command(&exec_path, &cwd, &log_line).map_err(
+                        |e| format!("Failed to prepare Node.js for agent #{}: {}", agent_index, e),
+                    )?;
+                args.splice(0..0, prefix);
+                exec_path = node;
+            }
 
-        #[cfg(not(windows))]
-        let mut cmd = Command::new(&exec_path);
+            info!(
+                "[agent#{}] Spawning process: {:?} {:?} in {}",
+                agent_index, exec_path, args, cwd
+            );
 
-        cmd.args(&args)
-            .current_dir(&cwd)
-            .env("PYTHONIOENCODING", "utf-8")
-            .env("PYTHONUTF8", "1")
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            #[cfg(windows)]
+            let mut cmd = {
+                use std::os::windows::process::CommandExt;
+                const CREATE_NO_WINDOW: u32 = 0x08000000;
+                let mut c = Command::new(&exec_path);
+                c.creation_flags(CREATE_NO_WINDOW);
+                c
+            };
 
-        // PI v2.5.0: 仅允许注入 PI_* 环境变量，避免覆盖宿主进程关键环境。
-        let mut injected_count = 0usize;
-        for (key, value) in pi_envs.iter() {
-            if !key.starts_with("PI_") {
+            #[cfg(not(windows))]
+            let mut cmd = Command::new(&exec_path);
+
+            cmd.args(&args)
+                .current_dir(&cwd)
+                .env("PYTHONIOENCODING", "utf-8")
+                .env("PYTHONUTF8", "1")
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped());
+
+            // PI v2.5.0: 仅允许注入 PI_* 环境变量，避免覆盖宿主进程关键环境。
+            let mut injected_count = 0usize;
+            for (key, value) in pi_envs.iter() {
+                if !key.starts_with("PI_") {
+                    warn!(
+                        "[agent#{}] Skipping non-PI_ env key from pi_envs: {}",
+                        agent_index, key
+                    );
+                    continue;
+                }
+
+                cmd.env(key, value);
+                injected_count += 1;
+            }
+            if injected_count > 0 {
+                info!(
+                    "[agent#{}] Injected {} PI_* env vars (requested: {})",
+                    agent_index,
+                    injected_count,
+                    pi_envs.len()
+                );
+            } else if !pi_envs.is_empty() {
                 warn!(
-                    "[agent#{}] Skipping non-PI_ env key from pi_envs: {}",
-                    agent_index, key
+                    "[agent#{}] No PI_* env vars were injected ({} entries provided)",
+                    agent_index,
+                    pi_envs.len()
                 );
-                continue;
             }
 
-            cmd.env(key, value);
-            injected_count += 1;
-        }
-        if injected_count > 0 {
-            info!(
-                "[agent#{}] Injected {} PI_* env vars (requested: {})",
-                agent_index,
-                injected_count,
-                pi_envs.len()
-            );
-        } else if !pi_envs.is_empty() {
-            warn!(
-                "[agent#{}] No PI_* env vars were injected ({} entries provided)",
-                agent_index,
-                pi_envs.len()
-            );
-        }
-
-        let mut child = cmd.spawn().map_err(|e| {
-            format!(
-                "Failed to spawn agent #{}: {} (path: {:?})",
-                agent_index, e, exec_path
-            )
-        })?;
-
-        // agent 日志文件路径（延迟创建：仅在有实际输出时才打开文件）
-        let pid = child.id();
-        let log_filename = format!("mxu-agent-{}-{}.log", agent_index, pid);
-        let agent_log_path = Arc::new(get_logs_dir().join(&log_filename));
-        let log_file: Arc<Mutex<Option<std::fs::File>>> = Arc::new(Mutex::new(None));
-        let output_batcher = AgentOutputBatcher::new(app.clone(), instance_id.clone());
-
-        // 在单独线程中读取 stdout
-        if let Some(stdout) = child.stdout.take() {
-            let lf = log_file.clone();
-            let lf_path = agent_log_path.clone();
-            let batcher = output_batcher.clone();
-            thread::spawn(move || {
-                let mut reader = BufReader::new(stdout);
-                let mut buffer = Vec::new();
-                loop {
-                    buffer.clear();
-                    match reader.read_until(b'\n', &mut buffer) {
-                        Ok(0) => break,
-                        Ok(_) => {
-                            let line = String::from_utf8_lossy(&buffer);
-                            let clean_line = line.trim_end();
-                            if let Ok(mut guard) = lf.lock() {
-                                if guard.is_none() {
-                                    *guard = OpenOptions::new()
-                                        .create(true)
-                                        .append(true)
-                                        .open(lf_path.as_ref())
-                                        .ok();
-                                }
-                                if let Some(file) = guard.as_mut() {
-                                    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-                                    let _ = writeln!(file, "{} [stdout] {}", timestamp, clean_line);
+            let mut child = cmd.spawn().map_err(|e| {
+                format!(
+                    "Failed to spawn agent #{}: {} (path: {:?})",
+                    agent_index, e, exec_path
+                )
+            })?;
+
+            // agent 日志文件路径（延迟创建：仅在有实际输出时才打开文件）
+            let pid = child.id();
+            let log_filename = format!("mxu-agent-{}-{}.log", agent_index, pid);
+            let agent_log_path = Arc::new(get_logs_dir().join(&log_filename));
+            let log_file: Arc<Mutex<Option<std::fs::File>>> = Arc::new(Mutex::new(None));
+            let output_batcher = AgentOutputBatcher::new(app.clone(), instance_id.clone());
+
+            // 在单独线程中读取 stdout
+            if let Some(stdout) = child.stdout.take() {
+                let lf = log_file.clone();
+                let lf_path = agent_log_path.clone();
+                let batcher = output_batcher.clone();
+                thread::spawn(move || {
+                    let mut reader = BufReader::new(stdout);
+                    let mut buffer = Vec::new();
+                    loop {
+                        buffer.clear();
+                        match reader.read_until(b'\n', &mut buffer) {
+                            Ok(0) => break,
+                            Ok(_) => {
+                                let line = String::from_utf8_lossy(&buffer);
+                                let clean_line = line.trim_end();
+                                {
+                                    let mut guard = lf.lock();
+                                    if guard.is_none() {
+                                        *guard = OpenOptions::new()
+                                            .create(true)
+                                            .append(true)
+                                            .open(lf_path.as_ref())
+                                            .ok();
+                                    }
+                                    if let Some(file) = guard.as_mut() {
+                                        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+                                        let _ = writeln!(file, "{} [stdout] {}", timestamp, clean_line);
+                                    }
                                 }
+                                info!(target: "agent", "[agent#{}][stdout] {}", agent_index, clean_line);
+                                batcher.enqueue("stdout", clean_line);
                             }
-                            info!(target: "agent", "[agent#{}][stdout] {}", agent_index, clean_line);
-                            batcher.enqueue("stdout", clean_line);
+                            Err(_) => break,
                         }
-                        Err(_) => break,
                     }
-                }
-            });
-        }
+                });
+            }
 
-        // Stderr thread
-        if let Some(stderr) = child.stderr.take() {
-            let lf = log_file.clone();
-            let lf_path = agent_log_path.clone();
-            let batcher = output_batcher.clone();
-            thread::spawn(move || {
-                let mut reader = BufReader::new(stderr);
-                let mut buffer = Vec::new();
-                loop {
-                    buffer.clear();
-                    match reader.read_until(b'\n', &mut buffer) {
-                        Ok(0) => break,
-                        Ok(_) => {
-                            let line = String::from_utf8_lossy(&buffer);
-                            let clean_line = line.trim_end();
-                            if let Ok(mut guard) = lf.lock() {
-                                if guard.is_none() {
-                                    *guard = OpenOptions::new()
-                                        .create(true)
-                                        .append(true)
-                                        .open(lf_path.as_ref())
-                                        .ok();
-                                }
-                                if let Some(file) = guard.as_mut() {
-                                    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-                                    let _ = writeln!(file, "{} [stderr] {}", timestamp, clean_line);
+            // Stderr thread
+            if let Some(stderr) = child.stderr.take() {
+                let lf = log_file.clone();
+                let lf_path = agent_log_path.clone();
+                let batcher = output_batcher.clone();
+                thread::spawn(move || {
+                    let mut reader = BufReader::new(stderr);
+                    let mut buffer = Vec::new();
+                    loop {
+                        buffer.clear();
+                        match reader.read_until(b'\n', &mut buffer) {
+                            Ok(0) => break,
+                            Ok(_) => {
+                                let line = String::from_utf8_lossy(&buffer);
+                                let clean_line = line.trim_end();
+                                {
+                                    let mut guard = lf.lock();
+                                    if guard.is_none() {
+                                        *guard = OpenOptions::new()
+                                            .create(true)
+                                            .append(true)
+                                            .open(lf_path.as_ref())
+                                            .ok();
+                                    }
+                                    if let Some(file) = guard.as_mut() {
+                                        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+                                        let _ = writeln!(file, "{} [stderr] {}", timestamp, clean_line);
+                                    }
                                 }
+                                warn!(target: "agent", "[agent#{}][stderr] {}", agent_index, clean_line);
+                                batcher.enqueue("stderr", clean_line);
                             }
-                            warn!(target: "agent", "[agent#{}][stderr] {}", agent_index, clean_line);
-                            batcher.enqueue("stderr", clean_line);
+                            Err(_) => break,
                         }
-                        Err(_) => break,
                     }
-                }
-            });
-        }
+                });
+            }
+            Some(child)
+        };
 
         // 设置连接超时
         let timeout = agent.timeout.unwrap_or(-1);
@@ -467,9 +648,27 @@ async fn start_single_agent(
 
         if let Err(e) = client.connect() {
              error!("[agent#{}] Connection failed: {}", agent_index, e);
-             let _ = child.kill();
-             let _ = child.wait();
-             return Err(e.to_string());
+             if let Some(child) = child.as_mut() {
+                 let _ = child.kill();
+                 let _ = child.wait();
+             }
+             return match tcp_port {
+                 Some(_) if remote.is_some() => Err(format!(
+                     "{}\n{}",
+                     e,
+                     tr!(
+                         "error.agent.diag_remote_unreachable",
+                         host = tcp_interface,
+                         port = socket_id
+                     )
+                 )),
+                 Some(port) => Err(format!(
+                     "{}\n{}",
+                     e,
+                     diagnose_tcp_failure(&tcp_interface, port)
+                 )),
+                 None => Err(e.to_string()),
+             };
         }
 
         info!("[agent#{}] Connected successfully!", agent_index);
@@ -477,8 +676,10 @@ async fn start_single_agent(
         // 注册 Agent sink
         if let Err(e) = client.register_sinks(resource, controller, tasker) {
             error!("[agent#{}] Failed to register sinks: {}", agent_index, e);
-            let _ = child.kill();
-            let _ = child.wait();
+            if let Some(child) = child.as_mut() {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
             return Err(e.to_string());
         }
 
@@ -496,34 +697,48 @@ pub async fn start_tasks_impl(
     cwd: String,
     tcp_compat_mode: bool,
     pi_envs: Option<HashMap<String, String>>,
-) -> Result<Vec<i64>, String> {
+    dry_run: bool,
+) -> MxuResult<Vec<i64>> {
     info!("start_tasks_impl called");
 
     info!("instance_id: {}", instance_id);
     info!("tasks: {:?}", tasks);
     info!("agent_configs: {:?}", agent_configs);
-    info!("cwd: {}, tcp_compat_mode: {}", cwd, tcp_compat_mode);
+    info!(
+        "cwd: {}, tcp_compat_mode: {}, dry_run: {}",
+        cwd, tcp_compat_mode, dry_run
+    );
+
+    // 按依赖关系确定提交顺序（依赖或执行条件无效时在启动 Agent 前失败）
+    let submit_order = super::task_graph::topological_order(&tasks)?;
+    super::task_graph::validate_conditions(&tasks)?;
 
-    let (resource, controller, tasker) = {
-        debug!("[start_tasks] Acquiring instances lock...");
-        let mut instances = maa_state.instances.lock().map_err(|e| e.to_string())?;
-        debug!("[start_tasks] Instances lock acquired");
-        let instance = instances
+    // 程序文件校验未通过时拒绝启动，维护时段内拒绝或延后启动（试运行不受影响）
+    if !dry_run {
+        super::integrity::gate_start(&app).await?;
+        super::maintenance::gate_start(&app, &instance_id).await?;
+    }
+
+    let (resource, controller, tasker, instance_run_options) = {
+        debug!("[start_tasks] Acquiring instance lock...");
+        let mut guard = maa_state
+            .instances
             .get_mut(&instance_id)
-            .ok_or("Instance not found")?;
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
         debug!("[start_tasks] Instance found: {}", instance_id);
 
         let res = instance
             .resource
             .as_ref()
-            .ok_or("Resource not loaded")?
+            .ok_or_else(MxuError::resource_not_loaded)?
             .clone();
         debug!("[start_tasks] Resource acquired");
 
         let ctrl = instance
             .controller
             .as_ref()
-            .ok_or("Controller not connected")?
+            .ok_or_else(MxuError::controller_not_connected)?
             .clone();
         debug!("[start_tasks] Controller acquired");
 
@@ -557,7 +772,7 @@ pub async fn start_tasks_impl(
                     detail,
                 );
                 // 再转发原始回调到前端
-                emit_callback_event(&app_handle, msg, detail);
+                emit_callback_event(&app_handle, &inst_id_for_sink, msg, detail);
             })
             .map_err(|e| e.to_string())?;
             debug!("[start_tasks] Tasker sink added");
@@ -565,8 +780,9 @@ pub async fn start_tasks_impl(
             // 添加 Context Sink，用于接收 Node 级别的通知（包含 focus 消息）
             debug!("[start_tasks] Adding tasker context sink...");
             let app_handle = app.clone();
+            let inst_id_for_sink = instance_id.clone();
             t.add_context_sink(move |msg, detail| {
-                emit_callback_event(&app_handle, msg, detail);
+                emit_callback_event(&app_handle, &inst_id_for_sink, msg, detail);
             })
             .map_err(|e| e.to_string())?;
             debug!("[start_tasks] Tasker context sink added");
@@ -582,14 +798,14 @@ pub async fn start_tasks_impl(
         }
 
         let t = instance.tasker.as_ref().unwrap().clone();
-        (res, ctrl, t)
+        (res, ctrl, t, instance.run_options.clone())
     };
     debug!("[start_tasks] Resource, controller and tasker acquired, proceeding...");
 
     // 检查 Tasker 初始化状态
     if !tasker.inited() {
         error!("[start_tasks] Tasker not properly initialized");
-        return Err("Tasker not properly initialized".to_string());
+        return Err("Tasker not properly initialized".to_string().into());
     }
 
     // 启动所有 Agent（如果配置了）
@@ -604,6 +820,7 @@ pub async fn start_tasks_impl(
             // 用于收集所有成功启动的 agent，失败时需要回滚清理
             let mut new_clients = Vec::new();
             let mut new_children = Vec::new();
+            let mut new_launches = Vec::new();
 
             for (idx, config) in configs.iter().enumerate() {
                 let res_clone = resource.clone();
@@ -631,6 +848,12 @@ pub async fn start_tasks_impl(
                     Ok((client, child)) => {
                         new_clients.push(client);
                         new_children.push(child);
+                        new_launches.push(AgentLaunch {
+                            config: config.clone(),
+                            cwd: cwd.clone(),
+                            tcp_compat_mode,
+                            pi_envs: Arc::clone(&pi_envs),
+                        });
                     }
                     Err(e) => {
                         error!(
@@ -642,20 +865,23 @@ pub async fn start_tasks_impl(
                         for client in &new_clients {
                             let _ = client.disconnect();
                         }
-                        for mut child in new_children {
+                        for mut child in new_children.into_iter().flatten() {
                             let _ = child.kill();
                             let _ = child.wait();
                         }
-                        return Err(format!("Agent start failed: {}", e));
+                        return Err(MxuError::new(
+                            ErrorCode::AgentFailed,
+                            format!("Agent start failed: {}", e),
+                        ));
                     }
                 }
             }
 
             // 保存所有 agent 状态到 instance
-            let mut instances = maa_state.instances.lock().map_err(|e| e.to_string())?;
-            if let Some(instance) = instances.get_mut(&instance_id) {
+            if let Some(mut instance) = maa_state.instances.get_mut(&instance_id) {
                 instance.agent_clients.extend(new_clients);
                 instance.agent_children.extend(new_children);
+                instance.agent_launches.extend(new_launches);
             }
 
             info!(
@@ -669,12 +895,79 @@ pub async fn start_tasks_impl(
         debug!("[start_tasks] No agent configs, skipping agent setup");
     };
 
+    // 按运行选项改写 pipeline override（试运行、运行速度等）
+    let run_options = super::run_options::RunOptions {
+        dry_run,
+        delay_multiplier: instance_run_options.delay_multiplier,
+        action_interval_ms: instance_run_options.action_interval_ms,
+    };
+    let tasks = super::run_options::prepare_tasks(&resource, tasks, &run_options);
+
     debug!("[start_tasks] Submitting {} tasks...", tasks.len());
     // (maa_task_id, selected_task_id) 配对列表，用于后续初始化 TaskRunState
     let mut task_id_pairs: Vec<(i64, Option<String>)> = Vec::new();
-    for (idx, task) in tasks.iter().enumerate() {
+    let mut submitted_tasks = HashMap::new();
+    // (任务列表下标, maa_task_id)，用于构建依赖图
+    let mut posted: Vec<(usize, i64)> = Vec::new();
+    // 未提交的任务（执行条件不满足或前置任务未提交）
+    let mut not_posted: Vec<super::task_graph::SkippedTask> = Vec::new();
+    for idx in submit_order {
+        let task = &tasks[idx];
         debug!("[start_tasks] Preparing task {}: entry={}", idx, task.entry);
 
+        // 前置任务未提交时，依赖它的任务也不再提交
+        if let Some(dep) = task
+            .depends_on
+            .iter()
+            .flatten()
+            .find(|dep| !posted.iter().any(|(i, _)| i == *dep))
+        {
+            warn!(
+                "[start_tasks] Skipping task {} ({}): dependency {} was not submitted",
+                idx, task.entry, dep
+            );
+            not_posted.push(super::task_graph::SkippedTask {
+                reason: "dependency-failed",
+                task_id: None,
+                entry: task.entry.clone(),
+                index: Some(idx),
+                selected_task_id: task.selected_task_id.clone(),
+                dependency_task_id: None,
+                dependency_index: Some(*dep),
+                condition: None,
+            });
+            continue;
+        }
+
+        // 队首任务的执行条件在提交前求值，其余任务在前一个任务结束时求值
+        if posted.is_empty() {
+            if let Some(condition) = task.run_if.as_deref().filter(|c| !c.trim().is_empty()) {
+                let met = super::task_graph::evaluate_condition(condition, &instance_id, &|i| {
+                    not_posted
+                        .iter()
+                        .any(|t| t.index == Some(i))
+                        .then(|| "skipped".to_string())
+                });
+                if !met {
+                    info!(
+                        "[start_tasks] Skipping task {} ({}): run_if not met",
+                        idx, task.entry
+                    );
+                    not_posted.push(super::task_graph::SkippedTask {
+                        reason: "condition-false",
+                        task_id: None,
+                        entry: task.entry.clone(),
+                        index: Some(idx),
+                        selected_task_id: task.selected_task_id.clone(),
+                        dependency_task_id: None,
+                        dependency_index: None,
+                        condition: Some(condition.to_string()),
+                    });
+                    continue;
+                }
+            }
+        }
+
         info!(
             "[start_tasks] Calling post_task: entry={}, override={}",
             task.entry, task.pipeline_override
@@ -683,6 +976,8 @@ pub async fn start_tasks_impl(
             Ok(job) => {
                 info!("[start_tasks] post_task returned task_id: {}", job.id);
                 task_id_pairs.push((job.id, task.selected_task_id.clone()));
+                submitted_tasks.insert(job.id, task.clone());
+                posted.push((idx, job.id));
                 debug!(
                     "[start_tasks] Task {} submitted successfully, task_id: {}",
                     idx, job.id
@@ -703,9 +998,17 @@ pub async fn start_tasks_impl(
     // 初始化后端 TaskRunState（单一真相来源）并缓存 task_ids
     debug!("[start_tasks] Initializing TaskRunState...");
     {
-        let mut instances = maa_state.instances.lock().map_err(|e| e.to_string())?;
-        if let Some(instance) = instances.get_mut(&instance_id) {
+        if let Some(mut guard) = maa_state.instances.get_mut(&instance_id) {
+            let instance = guard.value_mut();
             instance.task_ids = task_ids.clone();
+            instance.submitted_tasks = submitted_tasks.clone();
+            instance.skipped_task_ids.clear();
+            instance.task_graph = super::task_graph::TaskGraph::build(&tasks, &posted);
+            for task in &not_posted {
+                if let Some(idx) = task.index {
+                    instance.task_graph.record_index_outcome(idx, "skipped");
+                }
+            }
 
             // 重置任务运行状态
             let state = &mut instance.task_run_state;
@@ -726,6 +1029,17 @@ pub async fn start_tasks_impl(
     }
     debug!("[start_tasks] TaskRunState initialized");
 
+    // 开始记录运行报告
+    let report_tasks: Vec<(i64, String, Option<String>)> = task_ids
+        .iter()
+        .filter_map(|id| {
+            let task = submitted_tasks.get(id)?;
+            Some((*id, task.entry.clone(), task.selected_task_id.clone()))
+        })
+        .collect();
+    super::run_report::begin_run(&instance_id, &report_tasks);
+    super::task_graph::emit_skipped(&app, &instance_id, not_posted);
+
     info!(
         "[start_tasks] start_tasks_impl completed successfully, returning {} task_ids",
         task_ids.len()
@@ -738,6 +1052,8 @@ pub async fn start_tasks_impl(
 }
 
 /// 启动任务（支持多个 Agent）— Tauri invoke 入口，委托给 start_tasks_impl
+///
+/// `dry_run` 为 true 时所有节点的 action 改为 DoNothing，只验证识别
 #[tauri::command]
 pub async fn maa_start_tasks(
     app: tauri::AppHandle,
@@ -748,8 +1064,10 @@ pub async fn maa_start_tasks(
     cwd: String,
     tcp_compat_mode: bool,
     pi_envs: Option<HashMap<String, String>>,
-) -> Result<Vec<i64>, String> {
-    start_tasks_impl(
+    dry_run: Option<bool>,
+) -> Result<Vec<i64>, MxuError> {
+    let correlation_id = super::trace::begin("maa_start_tasks", Some(&instance_id));
+    let result = start_tasks_impl(
         app,
         &state,
         instance_id,
@@ -758,18 +1076,68 @@ pub async fn maa_start_tasks(
         cwd,
         tcp_compat_mode,
         pi_envs,
+        dry_run.unwrap_or(false),
     )
-    .await
+    .await;
+    super::trace::finish(&correlation_id, &result);
+    result
+}
+
+/// 断开 Agent 连接并等待子进程退出（超时后强制结束）
+fn shutdown_agents(clients: Vec<AgentClient>, children: Vec<Option<std::process::Child>>) {
+    for client in clients {
+        let _ = client.disconnect();
+    }
+
+    for (i, child) in children.into_iter().enumerate() {
+        // 远程 Agent 没有子进程
+        let Some(mut child) = child else {
+            continue;
+        };
+        debug!("Waiting for agent process #{} to exit...", i);
+
+        let start = std::time::Instant::now();
+        let timeout = std::time::Duration::from_secs(5);
+        let mut exited = false;
+
+        while start.elapsed() < timeout {
+            match child.try_wait() {
+                Ok(Some(_)) => {
+                    exited = true;
+                    break;
+                }
+                Ok(None) => {
+                    thread::sleep(std::time::Duration::from_millis(100));
+                }
+                Err(e) => {
+                    error!("Error waiting for agent #{}: {}", i, e);
+                    break;
+                }
+            }
+        }
+
+        if !exited {
+            warn!("Agent process #{} did not exit in time, killing it...", i);
+            let _ = child.kill();
+            let _ = child.wait();
+        } else {
+            info!("Background: Agent #{} child process exited", i);
+        }
+    }
 }
 
 /// 停止所有 Agent 的核心实现（Tauri invoke 和 HTTP handler 共享）
-pub fn stop_agent_impl(maa_state: &Arc<MaaState>, instance_id: &str) -> Result<(), String> {
+pub fn stop_agent_impl(maa_state: &Arc<MaaState>, instance_id: &str) -> MxuResult<()> {
     info!("stop_agent_impl called for instance: {}", instance_id);
 
     let (clients, children) = {
-        let mut instances = maa_state.instances.lock().map_err(|e| e.to_string())?;
-        let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+        let mut guard = maa_state
+            .instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
 
+        instance.agent_launches.clear();
         (
             std::mem::take(&mut instance.agent_clients),
             std::mem::take(&mut instance.agent_children),
@@ -787,49 +1155,254 @@ pub fn stop_agent_impl(maa_state: &Arc<MaaState>, instance_id: &str) -> Result<(
         children.len()
     );
 
-    thread::spawn(move || {
-        for client in clients {
-            let _ = client.disconnect();
+    thread::spawn(move || shutdown_agents(clients, children));
+
+    Ok(())
+}
+
+/// 停止单个 Agent 的核心实现（Tauri invoke 和 HTTP handler 共享）
+///
+/// 停止后该 Agent 从列表中移除，其后的 Agent 下标依次前移。
+pub fn stop_single_agent_impl(
+    maa_state: &Arc<MaaState>,
+    instance_id: &str,
+    agent_index: usize,
+) -> MxuResult<()> {
+    info!(
+        "stop_single_agent_impl called for instance: {}, agent #{}",
+        instance_id, agent_index
+    );
+
+    let (client, child) = {
+        let mut guard = maa_state
+            .instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
+        if agent_index >= instance.agent_clients.len()
+            || agent_index >= instance.agent_children.len()
+        {
+            return Err(MxuError::new(
+                ErrorCode::InvalidArgument,
+                tr!("error.agent.not_found", index = agent_index),
+            ));
+        }
+        if agent_index < instance.agent_launches.len() {
+            instance.agent_launches.remove(agent_index);
         }
+        (
+            instance.agent_clients.remove(agent_index),
+            instance.agent_children.remove(agent_index),
+        )
+    };
 
-        for (i, mut child) in children.into_iter().enumerate() {
-            debug!("Waiting for agent process #{} to exit...", i);
+    thread::spawn(move || shutdown_agents(vec![client], vec![child]));
+    Ok(())
+}
 
-            let start = std::time::Instant::now();
-            let timeout = std::time::Duration::from_secs(5);
-            let mut exited = false;
+/// 重启单个 Agent 的核心实现（Tauri invoke 和 HTTP handler 共享）
+///
+/// 使用首次启动时的参数重新拉起子进程并连接，新 Agent 保持原下标。
+/// 启动失败时该 Agent 被移除。
+pub async fn restart_agent_impl(
+    app: tauri::AppHandle,
+    maa_state: &Arc<MaaState>,
+    instance_id: &str,
+    agent_index: usize,
+) -> MxuResult<()> {
+    info!(
+        "restart_agent_impl called for instance: {}, agent #{}",
+        instance_id, agent_index
+    );
 
-            while start.elapsed() < timeout {
-                match child.try_wait() {
-                    Ok(Some(_)) => {
-                        exited = true;
-                        break;
-                    }
-                    Ok(None) => {
-                        thread::sleep(std::time::Duration::from_millis(100));
-                    }
-                    Err(e) => {
-                        error!("Error waiting for agent #{}: {}", i, e);
-                        break;
-                    }
-                }
+    let (launch, resource, controller, tasker, client, child) = {
+        let mut guard = maa_state
+            .instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
+        let launch = instance
+            .agent_launches
+            .get(agent_index)
+            .cloned()
+            .ok_or_else(|| tr!("error.agent.not_found", index = agent_index))?;
+        if agent_index >= instance.agent_clients.len()
+            || agent_index >= instance.agent_children.len()
+        {
+            return Err(MxuError::new(
+                ErrorCode::InvalidArgument,
+                tr!("error.agent.not_found", index = agent_index),
+            ));
+        }
+        let resource = instance
+            .resource
+            .clone()
+            .ok_or_else(MxuError::resource_not_loaded)?;
+        let controller = instance
+            .controller
+            .clone()
+            .ok_or_else(MxuError::controller_not_connected)?;
+        let tasker = instance
+            .tasker
+            .clone()
+            .ok_or_else(|| tr!("error.tasker_not_created"))?;
+        (
+            launch,
+            resource,
+            controller,
+            tasker,
+            instance.agent_clients.remove(agent_index),
+            instance.agent_children.remove(agent_index),
+        )
+    };
+
+    tauri::async_runtime::spawn_blocking(move || shutdown_agents(vec![client], vec![child]))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let result = start_single_agent(
+        app,
+        launch.config,
+        agent_index,
+        instance_id.to_string(),
+        launch.cwd,
+        launch.tcp_compat_mode,
+        resource,
+        controller,
+        tasker,
+        launch.pi_envs,
+    )
+    .await;
+
+    let mut guard = maa_state
+        .instances
+        .get_mut(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
+    match result {
+        Ok((client, child)) => {
+            let index = agent_index.min(instance.agent_clients.len());
+            instance.agent_clients.insert(index, client);
+            instance
+                .agent_children
+                .insert(index.min(instance.agent_children.len()), child);
+            info!("[restart_agent] Agent #{} restarted", agent_index);
+            Ok(())
+        }
+        Err(e) => {
+            if agent_index < instance.agent_launches.len() {
+                instance.agent_launches.remove(agent_index);
             }
+            Err(MxuError::new(
+                ErrorCode::AgentFailed,
+                tr!("error.agent.restart_failed", index = agent_index, error = e),
+            ))
+        }
+    }
+}
 
-            if !exited {
-                warn!("Agent process #{} did not exit in time, killing it...", i);
-                let _ = child.kill();
-                let _ = child.wait();
-            } else {
-                info!("Background: Agent #{} child process exited", i);
+/// 向 Agent 子进程的 stdin 写入一行输入的核心实现（Tauri invoke 和 HTTP handler 共享）
+///
+/// 供需要交互输入（如登录验证码）的 Agent 使用，`line` 末尾自动补换行。
+/// 写入期间暂时取出 stdin 句柄，不持有实例锁，避免 Agent 未读取输入时阻塞整个实例表。
+pub fn send_agent_input_impl(
+    maa_state: &Arc<MaaState>,
+    instance_id: &str,
+    agent_index: usize,
+    line: &str,
+) -> MxuResult<()> {
+    let (pid, mut stdin) = {
+        let mut guard = maa_state
+            .instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
+        let child = instance
+            .agent_children
+            .get_mut(agent_index)
+            .ok_or_else(|| tr!("error.agent.not_found", index = agent_index))?
+            .as_mut()
+            .ok_or_else(|| tr!("error.agent.input_remote", index = agent_index))?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| tr!("error.agent.input_busy", index = agent_index))?;
+        (child.id(), stdin)
+    };
+
+    let mut data = line.trim_end_matches(['\r', '\n']).to_string();
+    data.push('\n');
+    let result = stdin
+        .write_all(data.as_bytes())
+        .and_then(|_| stdin.flush())
+        .map_err(|e| {
+            tr!(
+                "error.agent.input_write_failed",
+                index = agent_index,
+                error = e
+            )
+        });
+
+    // 放回 stdin（期间 Agent 已重启或退出时丢弃旧句柄）
+    if let Some(mut guard) = maa_state.instances.get_mut(instance_id) {
+        if let Some(Some(child)) = guard.value_mut().agent_children.get_mut(agent_index) {
+            if child.id() == pid && child.stdin.is_none() {
+                child.stdin = Some(stdin);
             }
         }
-    });
+    }
 
+    result?;
+    info!("[agent#{}] Sent input line to agent", agent_index);
     Ok(())
 }
 
+/// 向 Agent 发送一行输入 — Tauri invoke 入口，委托给 send_agent_input_impl
+#[tauri::command]
+pub fn maa_agent_send_input(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    agent_index: usize,
+    line: String,
+) -> Result<(), MxuError> {
+    send_agent_input_impl(&state, &instance_id, agent_index, &line)
+}
+
 /// 停止所有 Agent 并断开连接 — Tauri invoke 入口，委托给 stop_agent_impl
 #[tauri::command]
-pub fn maa_stop_agent(state: State<'_, Arc<MaaState>>, instance_id: String) -> Result<(), String> {
-    stop_agent_impl(&state, &instance_id)
+pub fn maa_stop_agent(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+) -> Result<(), MxuError> {
+    let correlation_id = super::trace::begin("maa_stop_agent", Some(&instance_id));
+    let result = stop_agent_impl(&state, &instance_id);
+    super::trace::finish(&correlation_id, &result);
+    result
+}
+
+/// 停止单个 Agent — Tauri invoke 入口，委托给 stop_single_agent_impl
+#[tauri::command]
+pub fn maa_stop_single_agent(
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    agent_index: usize,
+) -> Result<(), MxuError> {
+    let correlation_id = super::trace::begin("maa_stop_single_agent", Some(&instance_id));
+    let result = stop_single_agent_impl(&state, &instance_id, agent_index);
+    super::trace::finish(&correlation_id, &result);
+    result
+}
+
+/// 重启单个 Agent — Tauri invoke 入口，委托给 restart_agent_impl
+#[tauri::command]
+pub async fn maa_restart_agent(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    agent_index: usize,
+) -> Result<(), MxuError> {
+    let correlation_id = super::trace::begin("maa_restart_agent", Some(&instance_id));
+    let result = restart_agent_impl(app, &state, &instance_id, agent_index).await;
+    super::trace::finish(&correlation_id, &result);
+    result
 }
@@ -7,7 +7,7 @@ use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use tauri::State;
+use tauri::{Emitter, Manager, State};
 
 use maa_framework::controller::{AdbControllerBuilder, Controller};
 use maa_framework::resource::Resource;
@@ -15,11 +15,21 @@ use maa_framework::tasker::Tasker;
 use maa_framework::toolkit::Toolkit;
 use maa_framework::MaaStatus;
 
+use super::capture_resolution::{self, CaptureResolution};
+use super::error::{ErrorCode, MxuError, MxuResult};
+use super::maa_ffi::{
+    with_ffi_timeout, with_ffi_timeout_guarded, FFI_CONNECT_TIMEOUT, FFI_DESTROY_TIMEOUT,
+    FFI_LOAD_TIMEOUT,
+};
+use super::monitors;
+use super::trace;
 use super::types::{
-    AdbDevice, ConnectionStatus, ControllerConfig, MaaState, TaskStatus, VersionCheckResult,
-    Win32Window,
+    AdbDevice, ConnectionStatus, ControllerConfig, ControllerInfo, InstanceRuntime, MaaState,
+    Orientation, ResolutionChangedEvent, TaskConfig, TaskStatus, VersionCheckResult, Win32Window,
 };
 use super::utils::{emit_callback_event, get_maafw_dir, handle_task_callback, normalize_path};
+use crate::i18n::tr;
+use crate::ws_broadcast::{WsBroadcast, WsEvent};
 
 /// MaaFramework 最小支持版本
 const MIN_MAAFW_VERSION: &str = "5.5.0-beta.1";
@@ -37,29 +47,34 @@ fn update_instance_controller(
     instance_id: &str,
     controller: maa_framework::controller::Controller,
     new_config: super::types::ControllerConfig,
-) -> Result<(), String> {
-    let cleanup_config = {
-        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-        let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+) -> MxuResult<()> {
+    let old_config = {
+        let mut guard = state
+            .instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
 
         let old_config = instance.controller_config.clone();
         instance.controller = Some(controller);
         instance.controller_config = Some(new_config.clone());
         instance.tasker = None;
-
-        old_config.filter(|old| {
-            *old != new_config
-                && !instances
-                    .values()
-                    .any(|inst| inst.controller_config.as_ref() == Some(old))
-        })
+        instance.last_resolution = None;
+        old_config
     };
 
+    // 释放该实例的写锁后再遍历，遍历会读锁所有分片
+    let cleanup_config = old_config.filter(|old| {
+        *old != new_config
+            && !state
+                .instances
+                .iter()
+                .any(|inst| inst.controller_config.as_ref() == Some(old))
+    });
+
     if let Some(old_cfg) = cleanup_config {
-        if let Ok(mut pool) = state.controller_pool.lock() {
-            pool.remove(&old_cfg);
-            info!("ControllerPool: removed unused entry for old config");
-        }
+        state.controller_pool.lock().remove(&old_cfg);
+        info!("ControllerPool: removed unused entry for old config");
     }
 
     Ok(())
@@ -72,7 +87,7 @@ fn update_instance_controller(
 /// 初始化 MaaFramework
 /// 如果提供 lib_dir 则使用该路径，否则自动从 exe 目录/maafw 加载
 #[tauri::command]
-pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<String, String> {
+pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<String, MxuError> {
     info!("maa_init called, lib_dir: {:?}", lib_dir);
 
     let lib_path = match lib_dir {
@@ -82,13 +97,16 @@ pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<
 
     info!("maa_init using path: {:?}", lib_path);
 
+    // 安装路径过深时提前失败，避免资源加载时出现难以排查的错误
+    super::long_path::ensure_path_length()?;
+
     if !lib_path.exists() {
         let err = format!(
             "MaaFramework library directory not found: {}",
             lib_path.display()
         );
         error!("{}", err);
-        return Err(err);
+        return Err(MxuError::new(ErrorCode::NotInitialized, err));
     }
 
     // Windows: 将 lib_dir 添加到 DLL 搜索路径，确保依赖 DLL 能被找到
@@ -116,7 +134,7 @@ pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<
     } else {
         lib_path.clone()
     };
-    *state.lib_dir.lock().map_err(|e| e.to_string())? = Some(effective_dir);
+    *state.lib_dir.lock() = Some(effective_dir);
 
     // 加载库
     // 允许用户指定具体的文件路径，或者只指定目录
@@ -137,16 +155,17 @@ pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<
         Err(e) if e.contains("already loaded") => {
             info!("maa_init library already loaded, skipping");
         }
-        Err(e) => return Err(e),
+        Err(e) => return Err(MxuError::new(ErrorCode::NotInitialized, e)),
     }
 
     // 初始化 Toolkit
     // 初始化 Toolkit 配置，user_path 指向应用数据目录
     let data_dir = crate::commands::utils::get_app_data_dir()
         .unwrap_or_else(|_| std::path::PathBuf::from("."));
-    let user_path_str = data_dir.to_string_lossy();
     // 确保数据目录存在
     let _ = std::fs::create_dir_all(&data_dir);
+    let user_path = super::path_compat::compat_path(&data_dir);
+    let user_path_str = user_path.to_string_lossy();
 
     if let Err(e) = Toolkit::init_option(&user_path_str, "{}") {
         warn!("Failed to init toolkit option: {}", e);
@@ -163,20 +182,19 @@ pub fn maa_init(state: State<Arc<MaaState>>, lib_dir: Option<String>) -> Result<
 pub fn maa_set_resource_dir(
     state: State<Arc<MaaState>>,
     resource_dir: String,
-) -> Result<(), String> {
+) -> Result<(), MxuError> {
     info!(
         "maa_set_resource_dir called, resource_dir: {}",
         resource_dir
     );
-    *state.resource_dir.lock().map_err(|e| e.to_string())? =
-        Some(std::path::PathBuf::from(&resource_dir));
+    *state.resource_dir.lock() = Some(std::path::PathBuf::from(&resource_dir));
     info!("maa_set_resource_dir success");
     Ok(())
 }
 
 /// 获取 MaaFramework 版本
 #[tauri::command]
-pub fn maa_get_version() -> Result<String, String> {
+pub fn maa_get_version() -> Result<String, MxuError> {
     debug!("maa_get_version called");
     let version = std::panic::catch_unwind(|| maa_framework::maa_version().to_string())
         .map_err(|_| "MaaFramework library not loaded".to_string())?;
@@ -186,10 +204,10 @@ pub fn maa_get_version() -> Result<String, String> {
 
 /// 检查 MaaFramework 版本是否满足最小要求
 #[tauri::command]
-pub fn maa_check_version(state: State<Arc<MaaState>>) -> Result<VersionCheckResult, String> {
+pub fn maa_check_version(state: State<Arc<MaaState>>) -> Result<VersionCheckResult, MxuError> {
     debug!("maa_check_version called");
 
-    let lib_dir = state.lib_dir.lock().map_err(|e| e.to_string())?.clone();
+    let lib_dir = state.lib_dir.lock().clone();
 
     if let Some(dir) = lib_dir {
         #[cfg(windows)]
@@ -205,7 +223,10 @@ pub fn maa_check_version(state: State<Arc<MaaState>>) -> Result<VersionCheckResu
                     "Failed to load MaaFramework library from {:?}: {:?}",
                     dll_path, e
                 );
-                return Err(format!("MaaFramework library failed to load: {}", e));
+                return Err(MxuError::new(
+                    ErrorCode::NotInitialized,
+                    format!("MaaFramework library failed to load: {}", e),
+                ));
             }
         }
     }
@@ -214,9 +235,19 @@ pub fn maa_check_version(state: State<Arc<MaaState>>) -> Result<VersionCheckResu
         .map_err(|_| "MaaFramework library not loaded (panic in maa_version)".to_string())?;
 
     if current_str == "unknown" || current_str.is_empty() {
-        return Err("MaaFramework not initialized".to_string());
+        return Err(MxuError::new(
+            ErrorCode::NotInitialized,
+            "MaaFramework not initialized",
+        ));
     }
 
+    Ok(check_version_compatibility(current_str)?)
+}
+
+/// 将 MaaFramework 版本号与最小支持版本比较
+///
+/// 也用于更新后对新库的兼容性检查（版本号来自探测子进程）
+pub fn check_version_compatibility(current_str: String) -> Result<VersionCheckResult, String> {
     // 去掉版本号前缀 'v'（如 "v5.5.0-beta.1" -> "5.5.0-beta.1"）
     let current_clean = current_str.trim_start_matches('v');
     let min_clean = MIN_MAAFW_VERSION.trim_start_matches('v');
@@ -257,9 +288,7 @@ pub async fn find_adb_devices_impl(state: Arc<MaaState>) -> Result<Vec<AdbDevice
             })
             .collect();
 
-        if let Ok(mut cached) = state.cached_adb_devices.lock() {
-            *cached = result_devices.clone();
-        }
+        *state.cached_adb_devices.lock() = result_devices.clone();
 
         info!("find_adb_devices_impl: {} device(s)", result_devices.len());
         Ok(result_devices)
@@ -271,9 +300,9 @@ pub async fn find_adb_devices_impl(state: Arc<MaaState>) -> Result<Vec<AdbDevice
 #[tauri::command]
 pub async fn maa_find_adb_devices(
     state: State<'_, Arc<MaaState>>,
-) -> Result<Vec<AdbDevice>, String> {
+) -> Result<Vec<AdbDevice>, MxuError> {
     info!("maa_find_adb_devices called");
-    find_adb_devices_impl(state.inner().clone()).await
+    Ok(find_adb_devices_impl(state.inner().clone()).await?)
 }
 
 /// 查找 Win32 窗口的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
@@ -304,16 +333,20 @@ pub async fn find_win32_windows_impl(
                 }
             }
 
+            let handle = w.hwnd as u64;
+            let details = super::system::query_window_details(handle);
             result_windows.push(Win32Window {
-                handle: w.hwnd as u64,
+                handle,
                 class_name: w.class_name,
                 window_name: w.window_name,
+                pid: details.pid,
+                process_name: details.process_name,
+                rect: details.rect,
+                dpi_scale: details.dpi_scale,
             });
         }
 
-        if let Ok(mut cached) = state.cached_win32_windows.lock() {
-            *cached = result_windows.clone();
-        }
+        *state.cached_win32_windows.lock() = result_windows.clone();
 
         info!(
             "find_win32_windows_impl: {} window(s)",
@@ -341,9 +374,7 @@ pub async fn find_wlroots_sockets_impl(state: Arc<MaaState>) -> Result<Vec<Strin
             }
         }
 
-        if let Ok(mut cached) = state.cached_wlroots_sockets.lock() {
-            *cached = result_sockets.clone();
-        }
+        *state.cached_wlroots_sockets.lock() = result_sockets.clone();
 
         info!(
             "find_wlroots_sockets_impl: {} wlroots socket(s)",
@@ -361,21 +392,21 @@ pub async fn maa_find_win32_windows(
     state: State<'_, Arc<MaaState>>,
     class_regex: Option<String>,
     window_regex: Option<String>,
-) -> Result<Vec<Win32Window>, String> {
+) -> Result<Vec<Win32Window>, MxuError> {
     info!(
         "maa_find_win32_windows called, class_regex: {:?}, window_regex: {:?}",
         class_regex, window_regex
     );
-    find_win32_windows_impl(state.inner().clone(), class_regex, window_regex).await
+    Ok(find_win32_windows_impl(state.inner().clone(), class_regex, window_regex).await?)
 }
 
 /// 查找 WlRoots 可用的 Wayland socket（结果会缓存到 MaaState）
 #[tauri::command]
 pub async fn maa_find_wlroots_sockets(
     state: State<'_, Arc<MaaState>>,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<String>, MxuError> {
     info!("maa_find_wlroots_sockets called");
-    find_wlroots_sockets_impl(state.inner().clone()).await
+    Ok(find_wlroots_sockets_impl(state.inner().clone()).await?)
 }
 
 // ============================================================================
@@ -384,20 +415,21 @@ pub async fn maa_find_wlroots_sockets(
 
 /// 创建实例（幂等操作，实例已存在时直接返回成功）
 #[tauri::command]
-pub fn maa_create_instance(state: State<Arc<MaaState>>, instance_id: String) -> Result<(), String> {
+pub fn maa_create_instance(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<(), MxuError> {
     info!("maa_create_instance called, instance_id: {}", instance_id);
 
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-
-    if instances.contains_key(&instance_id) {
-        debug!("maa_create_instance: instance already exists, returning success");
-        return Ok(());
+    match state.instances.entry(instance_id.clone()) {
+        dashmap::mapref::entry::Entry::Occupied(_) => {
+            debug!("maa_create_instance: instance already exists, returning success");
+            return Ok(());
+        }
+        dashmap::mapref::entry::Entry::Vacant(entry) => {
+            entry.insert(super::types::InstanceRuntime::default());
+        }
     }
-
-    instances.insert(
-        instance_id.clone(),
-        super::types::InstanceRuntime::default(),
-    );
     info!("maa_create_instance success, instance_id: {}", instance_id);
     Ok(())
 }
@@ -407,20 +439,17 @@ pub fn destroy_instance_impl(state: &Arc<MaaState>, instance_id: &str) -> Result
     info!("destroy_instance_impl called, instance_id: {}", instance_id);
 
     let cleanup_config = {
-        let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-        let old_config = instances
-            .get(instance_id)
-            .and_then(|inst| inst.controller_config.clone());
-        let removed = instances.remove(instance_id).is_some();
+        let removed = state.instances.remove(instance_id);
 
-        if removed {
+        if let Some((_, instance)) = removed {
             info!(
                 "destroy_instance_impl success, instance_id: {}",
                 instance_id
             );
-            old_config.filter(|cfg| {
-                !instances
-                    .values()
+            instance.controller_config.clone().filter(|cfg| {
+                !state
+                    .instances
+                    .iter()
                     .any(|inst| inst.controller_config.as_ref() == Some(cfg))
             })
         } else {
@@ -434,27 +463,30 @@ pub fn destroy_instance_impl(state: &Arc<MaaState>, instance_id: &str) -> Result
 
     // ControllerPool: 清理不再被任何实例使用的条目
     if let Some(cfg) = cleanup_config {
-        if let Ok(mut pool) = state.controller_pool.lock() {
-            pool.remove(&cfg);
-            info!("ControllerPool: cleaned up entry after instance destroy");
-        }
+        state.controller_pool.lock().remove(&cfg);
+        info!("ControllerPool: cleaned up entry after instance destroy");
     }
 
-    if let Ok(mut log_buffer) = state.log_buffer.lock() {
-        log_buffer.clear_instance(instance_id);
-    }
+    state.log_buffer.lock().clear_instance(instance_id);
 
     Ok(())
 }
 
-/// 销毁实例
+/// 销毁实例（释放控制器、资源与 Tasker 可能阻塞，在阻塞线程池中执行）
 #[tauri::command]
-pub fn maa_destroy_instance(
-    state: State<Arc<MaaState>>,
+pub async fn maa_destroy_instance(
+    state: State<'_, Arc<MaaState>>,
     instance_id: String,
-) -> Result<(), String> {
+) -> Result<(), MxuError> {
     info!("maa_destroy_instance called, instance_id: {}", instance_id);
-    destroy_instance_impl(&state, &instance_id)
+    let correlation_id = trace::begin("maa_destroy_instance", Some(&instance_id));
+    let state = state.inner().clone();
+    let result = with_ffi_timeout("destroy_instance", FFI_DESTROY_TIMEOUT, move || {
+        destroy_instance_impl(&state, &instance_id)
+    })
+    .await;
+    trace::finish(&correlation_id, &result);
+    Ok(result?)
 }
 
 // ============================================================================
@@ -470,15 +502,14 @@ pub async fn connect_controller_impl(
     config: ControllerConfig,
     on_event: Arc<dyn Fn(&str, &str) + Send + Sync + 'static>,
 ) -> Result<i64, String> {
-    tokio::task::spawn_blocking(move || {
+    with_ffi_timeout_guarded("connect_controller", FFI_CONNECT_TIMEOUT, move |guard| {
         // ControllerPool: 检查是否有可复用的已连接控制器
-        let pooled = {
-            let pool = state_arc
-                .controller_pool
-                .lock()
-                .map_err(|e| e.to_string())?;
-            pool.get(&config).filter(|c| c.connected()).cloned()
-        };
+        let pooled = state_arc
+            .controller_pool
+            .lock()
+            .get(&config)
+            .filter(|c| c.connected())
+            .cloned();
 
         if let Some(pooled_ctrl) = pooled {
             info!(
@@ -488,6 +519,7 @@ pub async fn connect_controller_impl(
 
             let conn_id = next_synthetic_conn_id();
 
+            guard.commit("connect_controller")?;
             update_instance_controller(&state_arc, &instance_id, pooled_ctrl, config)?;
 
             // 发送合成回调事件，前端无感知
@@ -499,13 +531,7 @@ pub async fn connect_controller_impl(
         }
 
         // Pool 中无可用控制器（不存在或已断连），移除过期条目
-        {
-            let mut pool = state_arc
-                .controller_pool
-                .lock()
-                .map_err(|e| e.to_string())?;
-            pool.remove(&config);
-        }
+        state_arc.controller_pool.lock().remove(&config);
 
         info!(
             "ControllerPool miss: creating new controller for {:?}",
@@ -549,8 +575,10 @@ pub async fn connect_controller_impl(
                 screencap_method,
                 mouse_method,
                 keyboard_method,
+                monitor,
                 ..
             } => {
+                monitors::apply_monitor(*handle, monitor.as_deref());
                 let hwnd = *handle as *mut std::ffi::c_void;
                 Controller::new_win32(
                     hwnd,
@@ -579,8 +607,10 @@ pub async fn connect_controller_impl(
                 handle,
                 gamepad_type,
                 screencap_method,
+                monitor,
                 ..
             } => {
+                monitors::apply_monitor(*handle, monitor.as_deref());
                 let hwnd = *handle as *mut std::ffi::c_void;
                 let gp_type = match gamepad_type.as_deref() {
                     Some("DualShock4") | Some("DS4") => {
@@ -622,24 +652,24 @@ pub async fn connect_controller_impl(
             } => display_short_side.unwrap_or(720),
         };
 
-        if let Err(e) = controller.set_screenshot_target_short_side(display_short_side) {
-            warn!(
-                "Failed to set screenshot target short side to {}: {}",
-                display_short_side, e
-            );
+        // 实例预设（maa_set_capture_resolution）优先于 interface 中的 display_short_side
+        let resolution = capture_resolution::preset(&instance_id)
+            .unwrap_or(CaptureResolution::ShortSide(display_short_side));
+        if let Err(e) = capture_resolution::apply(&controller, resolution) {
+            warn!("Failed to apply capture resolution {:?}: {}", resolution, e);
         }
 
         // 发起连接
         let conn_id = controller.post_connection().map_err(|e| e.to_string())?;
 
+        // 调用方已超时放弃时不再写入控制器池与实例状态
+        guard.commit("connect_controller")?;
+
         // 存入 ControllerPool
-        {
-            let mut pool = state_arc
-                .controller_pool
-                .lock()
-                .map_err(|e| e.to_string())?;
-            pool.insert(config.clone(), controller.clone());
-        }
+        state_arc
+            .controller_pool
+            .lock()
+            .insert(config.clone(), controller.clone());
 
         // 更新实例状态
         debug!("Updating instance state...");
@@ -648,7 +678,20 @@ pub async fn connect_controller_impl(
         Ok(conn_id)
     })
     .await
-    .map_err(|e| e.to_string())?
+}
+
+/// 连接前检查 ADB 设备是否已授权 USB 调试（`adb get-state` 报告 unauthorized 时返回错误）
+///
+/// 其他错误（设备尚未连接、adb 不可用等）交由 MaaFramework 连接流程处理。
+fn check_adb_authorized(adb_path: &str, address: &str) -> MxuResult<()> {
+    match super::utils::adb_command(adb_path, address, &["get-state"]) {
+        Err(e) if e.contains("unauthorized") => Err(MxuError::new(
+            ErrorCode::AdbUnauthorized,
+            tr!("error.adb_unauthorized"),
+        )
+        .with_context(address)),
+        _ => Ok(()),
+    }
 }
 
 /// 连接控制器（异步，通过回调通知完成状态）
@@ -659,24 +702,62 @@ pub async fn maa_connect_controller(
     state: State<'_, Arc<MaaState>>,
     instance_id: String,
     config: ControllerConfig,
-) -> Result<i64, String> {
+) -> Result<i64, MxuError> {
     info!(
         "maa_connect_controller called, instance_id: {}",
         instance_id
     );
 
+    let correlation_id = trace::begin("maa_connect_controller", Some(&instance_id));
+    let window_handle = match &config {
+        ControllerConfig::Win32 { handle, .. } | ControllerConfig::Gamepad { handle, .. } => {
+            Some(*handle)
+        }
+        _ => None,
+    };
+    // Gamepad 通过虚拟手柄输入，不受 UIPI 限制，仅检查 Win32
+    if let ControllerConfig::Win32 { handle, .. } = &config {
+        if let Err(e) = super::elevation::check_before_connect(&app, &instance_id, *handle) {
+            trace::finish(&correlation_id, &Err::<(), _>(&e));
+            return Err(e);
+        }
+    }
+    if let ControllerConfig::Adb {
+        adb_path, address, ..
+    } = &config
+    {
+        let (adb_path, address) = (adb_path.clone(), address.clone());
+        let checked =
+            tokio::task::spawn_blocking(move || check_adb_authorized(&adb_path, &address))
+                .await
+                .map_err(|e| MxuError::from(e.to_string()))
+                .and_then(|r| r);
+        if let Err(e) = checked {
+            trace::finish(&correlation_id, &Err::<(), _>(&e));
+            return Err(e);
+        }
+    }
     let app_clone = app.clone();
+    let cid = correlation_id.clone();
+    let id = instance_id.clone();
     let result = connect_controller_impl(
         state.inner().clone(),
         instance_id.clone(),
         config,
-        Arc::new(move |msg, detail| emit_callback_event(&app, msg, detail)),
+        Arc::new(move |msg, detail| {
+            trace::record(&cid, log::Level::Debug, format!("{} {}", msg, detail));
+            emit_callback_event(&app, &id, msg, detail)
+        }),
     )
     .await;
+    trace::finish(&correlation_id, &result);
     if result.is_ok() {
         super::utils::emit_state_changed(&app_clone, &instance_id, "connected");
+        if let Some(handle) = window_handle {
+            super::win32_dpi::check_after_connect(&app_clone, &instance_id, handle);
+        }
     }
-    result
+    Ok(result?)
 }
 
 /// 获取连接状态（通过 MaaControllerConnected API 查询）
@@ -684,9 +765,11 @@ pub async fn maa_connect_controller(
 pub fn maa_get_connection_status(
     state: State<Arc<MaaState>>,
     instance_id: String,
-) -> Result<ConnectionStatus, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(&instance_id).ok_or("Instance not found")?;
+) -> Result<ConnectionStatus, MxuError> {
+    let instance = state
+        .instances
+        .get(&instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
 
     if instance.controller.as_ref().is_some_and(|c| c.connected()) {
         Ok(ConnectionStatus::Connected)
@@ -695,6 +778,92 @@ pub fn maa_get_connection_status(
     }
 }
 
+/// 获取控制器信息（内部实现）
+pub fn get_controller_info_impl(state: &MaaState, instance_id: &str) -> MxuResult<ControllerInfo> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let controller = instance
+        .controller
+        .as_ref()
+        .ok_or_else(MxuError::controller_not_connected)?;
+
+    let uuid = controller.uuid().ok().filter(|u| !u.is_empty());
+    let (width, height) = controller.resolution().unwrap_or((0, 0));
+    Ok(ControllerInfo {
+        uuid,
+        width,
+        height,
+        orientation: Orientation::from_size(width, height),
+    })
+}
+
+/// 获取控制器信息：设备 UUID、截图分辨率与方向
+#[tauri::command]
+pub fn maa_get_controller_info(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<ControllerInfo, MxuError> {
+    get_controller_info_impl(&state, &instance_id)
+}
+
+/// 检查控制器分辨率是否变化（由 `emit_callback_event` 在截图、识别相关回调时调用）
+///
+/// 首次观测只记录；之后分辨率变化时发送 `maa-resolution-changed` 事件，
+/// 前端据此提示当前分辨率可能不受资源支持。
+pub fn check_resolution_change(app: &tauri::AppHandle, instance_id: &str, message: &str) {
+    if !matches!(
+        message,
+        "Controller.Action.Succeeded" | "Node.Recognition.Starting"
+    ) {
+        return;
+    }
+    let Some(state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+
+    // 回调线程上不阻塞等待实例锁：持锁方可能正在销毁 Tasker / Controller 并等待回调线程退出
+    let changed = {
+        let Some(mut guard) = state.instances.try_get_mut(instance_id).try_unwrap() else {
+            return;
+        };
+        let instance = guard.value_mut();
+        let Some(current) = instance
+            .controller
+            .as_ref()
+            .and_then(|c| c.resolution().ok())
+            .filter(|&(w, h)| w > 0 && h > 0)
+        else {
+            return;
+        };
+        match instance.last_resolution.replace(current) {
+            Some(previous) if previous != current => Some((previous, current)),
+            _ => None,
+        }
+    };
+
+    let Some((previous, current)) = changed else {
+        return;
+    };
+    warn!(
+        "Controller resolution changed for {}: {}x{} -> {}x{}",
+        instance_id, previous.0, previous.1, current.0, current.1
+    );
+    let event = ResolutionChangedEvent {
+        instance_id: instance_id.to_string(),
+        previous: [previous.0, previous.1],
+        current: [current.0, current.1],
+        orientation: Orientation::from_size(current.0, current.1),
+    };
+    if let Some(ws) = app.try_state::<Arc<WsBroadcast>>() {
+        ws.send(WsEvent::ResolutionChanged(event.clone()));
+    }
+    if let Err(e) = app.emit("maa-resolution-changed", event) {
+        log::error!("Failed to emit maa-resolution-changed: {}", e);
+    }
+}
+
 // ============================================================================
 // 资源命令
 // ============================================================================
@@ -706,14 +875,17 @@ pub fn load_resource_impl(
     paths: &[String],
     on_event: Arc<dyn Fn(&str, &str) + Send + Sync + 'static>,
     app: Option<&tauri::AppHandle>,
-) -> Result<Vec<i64>, String> {
+) -> MxuResult<Vec<i64>> {
     info!(
         "load_resource_impl called, instance: {}, paths: {:?}",
         instance_id, paths
     );
 
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
+    let mut guard = state
+        .instances
+        .get_mut(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
 
     // 创建或获取资源
     if instance.resource.is_none() {
@@ -742,11 +914,17 @@ pub fn load_resource_impl(
     let mut res_ids = Vec::new();
 
     for path in paths {
-        let normalized = normalize_path(path).to_string_lossy().to_string();
-        match resource.post_bundle(&normalized) {
+        let normalized_path = normalize_path(path);
+        let normalized = normalized_path.to_string_lossy().to_string();
+        // 含非 ASCII 字符的路径映射为 ASCII 路径后再交给 MaaFramework
+        let bundle_path = super::path_compat::compat_path(&normalized_path);
+        match resource.post_bundle(&bundle_path.to_string_lossy()) {
             Ok(job) => {
                 info!("Posted resource bundle: {} -> id: {}", normalized, job.id);
                 res_ids.push(job.id);
+                if !instance.resource_paths.contains(&normalized_path) {
+                    instance.resource_paths.push(normalized_path);
+                }
             }
             Err(e) => {
                 warn!("Failed to post resource bundle {}: {}", normalized, e);
@@ -760,22 +938,32 @@ pub fn load_resource_impl(
 /// 加载资源（异步，通过回调通知完成状态）
 /// 返回资源加载请求 ID 列表，前端通过监听 maa-callback 事件获取完成状态
 #[tauri::command]
-pub fn maa_load_resource(
+pub async fn maa_load_resource(
     app: tauri::AppHandle,
-    state: State<Arc<MaaState>>,
+    state: State<'_, Arc<MaaState>>,
     instance_id: String,
     paths: Vec<String>,
-) -> Result<Vec<i64>, String> {
-    let res_ids = load_resource_impl(
-        &state,
-        &instance_id,
-        &paths,
-        Arc::new({
-            let app = app.clone();
-            move |msg, detail| emit_callback_event(&app, msg, detail)
-        }),
-        Some(&app),
-    )?;
+) -> Result<Vec<i64>, MxuError> {
+    let correlation_id = trace::begin("maa_load_resource", Some(&instance_id));
+    let state_arc = state.inner().clone();
+    let app_for_load = app.clone();
+    let id = instance_id.clone();
+    let result = with_ffi_timeout("load_resource", FFI_LOAD_TIMEOUT, move || {
+        load_resource_impl(
+            &state_arc,
+            &id,
+            &paths,
+            Arc::new({
+                let app = app_for_load.clone();
+                let id = id.clone();
+                move |msg, detail| emit_callback_event(&app, &id, msg, detail)
+            }),
+            Some(&app_for_load),
+        )
+    })
+    .await;
+    trace::finish(&correlation_id, &result);
+    let res_ids = result?;
 
     super::utils::emit_state_changed(&app, &instance_id, "resource-loading");
 
@@ -787,9 +975,11 @@ pub fn maa_load_resource(
 pub fn maa_is_resource_loaded(
     state: State<Arc<MaaState>>,
     instance_id: String,
-) -> Result<bool, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(&instance_id).ok_or("Instance not found")?;
+) -> Result<bool, MxuError> {
+    let instance = state
+        .instances
+        .get(&instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
 
     Ok(instance.resource.as_ref().is_some_and(|r| r.loaded()))
 }
@@ -799,9 +989,11 @@ pub fn maa_is_resource_loaded(
 pub fn maa_get_resource_hash(
     state: State<Arc<MaaState>>,
     instance_id: String,
-) -> Result<Option<String>, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(&instance_id).ok_or("Instance not found")?;
+) -> Result<Option<String>, MxuError> {
+    let instance = state
+        .instances
+        .get(&instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
 
     match instance.resource.as_ref() {
         Some(r) => match r.hash() {
@@ -817,44 +1009,51 @@ pub fn maa_get_resource_hash(
 
 /// 销毁资源（用于切换资源时重新创建）
 #[tauri::command]
-pub fn maa_destroy_resource(
-    state: State<Arc<MaaState>>,
+pub async fn maa_destroy_resource(
+    state: State<'_, Arc<MaaState>>,
     instance_id: String,
-) -> Result<(), String> {
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances
-        .get_mut(&instance_id)
-        .ok_or("Instance not found")?;
-
-    // 销毁旧的资源
-    instance.resource = None;
-    instance.tasker = None;
+) -> Result<(), MxuError> {
+    // 在实例锁内取出句柄，锁外销毁（Tasker 销毁会等待运行中的任务退出）
+    let (resource, tasker) = {
+        let mut guard = state
+            .instances
+            .get_mut(&instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
+        instance.resource_paths.clear();
+        (instance.resource.take(), instance.tasker.take())
+    };
 
-    Ok(())
+    let correlation_id = trace::begin("maa_destroy_resource", Some(&instance_id));
+    let result = with_ffi_timeout("destroy_resource", FFI_DESTROY_TIMEOUT, move || {
+        drop(tasker);
+        drop(resource);
+        Ok::<(), MxuError>(())
+    })
+    .await;
+    trace::finish(&correlation_id, &result);
+    result
 }
 
 // ============================================================================
 // 任务命令
 // ============================================================================
 
-/// 运行任务（异步，通过回调通知完成状态）
-/// 运行单个任务的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
-pub fn run_task_impl(
+/// 获取实例的 tasker，不存在或未初始化时创建并绑定资源与控制器
+pub(crate) fn ensure_tasker(
     app: &tauri::AppHandle,
     state: &Arc<MaaState>,
     instance_id: &str,
-    entry: &str,
-    pipeline_override: &str,
-    selected_task_id: Option<&str>,
-) -> Result<i64, String> {
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
-
-    let resource = instance.resource.as_ref().ok_or("Resource not loaded")?;
+    instance: &mut super::types::InstanceRuntime,
+) -> MxuResult<Tasker> {
+    let resource = instance
+        .resource
+        .as_ref()
+        .ok_or_else(MxuError::resource_not_loaded)?;
     let controller = instance
         .controller
         .as_ref()
-        .ok_or("Controller not connected")?;
+        .ok_or_else(MxuError::controller_not_connected)?;
 
     // 创建或获取 tasker
     let needs_new_tasker = match instance.tasker.as_ref() {
@@ -881,14 +1080,20 @@ pub fn run_task_impl(
                     msg,
                     detail,
                 );
-                emit_callback_event(&app_for_sink, msg, detail);
+                emit_callback_event(&app_for_sink, &instance_id_for_sink, msg, detail);
             })
             .map_err(|e| e.to_string())?;
 
         let app_for_context_sink = app.clone();
+        let instance_id_for_context_sink = instance_id.to_string();
         tasker
             .add_context_sink(move |msg, detail| {
-                emit_callback_event(&app_for_context_sink, msg, detail);
+                emit_callback_event(
+                    &app_for_context_sink,
+                    &instance_id_for_context_sink,
+                    msg,
+                    detail,
+                );
             })
             .map_err(|e| e.to_string())?;
 
@@ -900,10 +1105,31 @@ pub fn run_task_impl(
     }
 
     let tasker = instance.tasker.as_ref().unwrap();
-
     if !tasker.inited() {
-        return Err("Tasker not initialized even after rebuild".to_string());
+        return Err("Tasker not initialized even after rebuild"
+            .to_string()
+            .into());
     }
+    Ok(tasker.clone())
+}
+
+/// 运行任务（异步，通过回调通知完成状态）
+/// 运行单个任务的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
+pub fn run_task_impl(
+    app: &tauri::AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    entry: &str,
+    pipeline_override: &str,
+    selected_task_id: Option<&str>,
+) -> MxuResult<i64> {
+    let mut guard = state
+        .instances
+        .get_mut(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
+
+    let tasker = ensure_tasker(app, state, instance_id, instance)?;
 
     let job = tasker
         .post_task(entry, pipeline_override)
@@ -913,6 +1139,15 @@ pub fn run_task_impl(
     if !instance.task_ids.contains(&task_id) {
         instance.task_ids.push(task_id);
     }
+    instance.submitted_tasks.insert(
+        task_id,
+        TaskConfig {
+            entry: entry.to_string(),
+            pipeline_override: pipeline_override.to_string(),
+            selected_task_id: selected_task_id.map(str::to_string),
+            ..Default::default()
+        },
+    );
 
     if let Some(selected_task_id) = selected_task_id {
         let task_run_state = &mut instance.task_run_state;
@@ -931,6 +1166,40 @@ pub fn run_task_impl(
     Ok(task_id)
 }
 
+/// 重新提交已有的任务配置（保留超时等任务级设置），用于插队、断线恢复与超时重试
+///
+/// 新任务取代 `old_task_id`：旧 ID 从实例的任务记录与运行报告中移除，
+/// 避免之后再次被当作未完成任务重复提交；依赖图中的 ID 同步替换，保留依赖与执行条件。
+pub fn resubmit_task_impl(
+    app: &tauri::AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    old_task_id: i64,
+    task: &TaskConfig,
+) -> Result<i64, String> {
+    let task_id = run_task_impl(
+        app,
+        state,
+        instance_id,
+        &task.entry,
+        &task.pipeline_override,
+        task.selected_task_id.as_deref(),
+    )?;
+    if let Some(mut instance) = state.instances.get_mut(instance_id) {
+        instance.submitted_tasks.remove(&old_task_id);
+        instance.submitted_tasks.insert(task_id, task.clone());
+        instance.task_ids.retain(|id| *id != old_task_id);
+        let task_run_state = &mut instance.task_run_state;
+        task_run_state
+            .pending_task_ids
+            .retain(|id| *id != old_task_id);
+        task_run_state.mappings.remove(&old_task_id);
+        instance.task_graph.replace(old_task_id, task_id);
+    }
+    super::run_report::replace_task(instance_id, old_task_id, task_id);
+    Ok(task_id)
+}
+
 /// 运行单个任务
 /// 返回任务 ID，前端通过监听 maa-callback 事件获取完成状态
 #[tauri::command]
@@ -941,8 +1210,9 @@ pub fn maa_run_task(
     entry: String,
     pipeline_override: String,
     selected_task_id: Option<String>,
-) -> Result<i64, String> {
+) -> Result<i64, MxuError> {
     info!("maa_run_task called, entry: {}", entry);
+    let correlation_id = trace::begin("maa_run_task", Some(&instance_id));
     let app_clone = app.clone();
     let result = run_task_impl(
         &app,
@@ -952,22 +1222,106 @@ pub fn maa_run_task(
         &pipeline_override,
         selected_task_id.as_deref(),
     );
+    trace::finish(&correlation_id, &result);
     if result.is_ok() {
         super::utils::emit_state_changed(&app_clone, &instance_id, "task-started");
     }
     result
 }
 
+/// 插队运行任务的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
+///
+/// 先撤下队列中尚未开始的任务，提交新任务后再按原顺序重新提交被撤下的任务，
+/// 使新任务在当前任务之后立即执行。
+pub fn run_task_next_impl(
+    app: &tauri::AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    entry: &str,
+    pipeline_override: &str,
+    selected_task_id: Option<&str>,
+) -> MxuResult<i64> {
+    let withdrawn = {
+        let mut guard = state
+            .instances
+            .get_mut(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        let instance = guard.value_mut();
+        withdraw_queued_tasks(instance)
+    };
+
+    let result = run_task_impl(
+        app,
+        state,
+        instance_id,
+        entry,
+        pipeline_override,
+        selected_task_id,
+    );
+
+    // 无论新任务是否提交成功，都要恢复被撤下的任务
+    for (old_id, task) in &withdrawn {
+        if let Err(e) = resubmit_task_impl(app, state, instance_id, *old_id, task) {
+            warn!(
+                "run_task_next: failed to requeue task {}: {}",
+                task.entry, e
+            );
+            super::run_report::on_task_event(instance_id, *old_id, "failed", None);
+        }
+    }
+
+    if result.is_ok() {
+        info!(
+            "run_task_next: {} inserted ahead of {} queued task(s)",
+            entry,
+            withdrawn.len()
+        );
+    }
+    result
+}
+
+/// 插队运行任务：在当前任务之后立即执行，排在其余等待中的任务之前
+#[tauri::command]
+pub fn maa_run_task_next(
+    app: tauri::AppHandle,
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    entry: String,
+    pipeline_override: String,
+    selected_task_id: Option<String>,
+) -> Result<i64, MxuError> {
+    info!("maa_run_task_next called, entry: {}", entry);
+    let correlation_id = trace::begin("maa_run_task_next", Some(&instance_id));
+    let result = run_task_next_impl(
+        &app,
+        &state,
+        &instance_id,
+        &entry,
+        &pipeline_override,
+        selected_task_id.as_deref(),
+    );
+    trace::finish(&correlation_id, &result);
+    if result.is_ok() {
+        super::utils::emit_state_changed(&app, &instance_id, "task-started");
+    }
+    result
+}
+
 /// 获取任务状态
 #[tauri::command]
 pub fn maa_get_task_status(
     state: State<Arc<MaaState>>,
     instance_id: String,
     task_id: i64,
-) -> Result<TaskStatus, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(&instance_id).ok_or("Instance not found")?;
-    let tasker = instance.tasker.as_ref().ok_or("Tasker not created")?;
+) -> Result<TaskStatus, MxuError> {
+    let instance = state
+        .instances
+        .get(&instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let tasker = instance
+        .tasker
+        .as_ref()
+        .ok_or_else(|| tr!("error.tasker_not_created"))?;
 
     let status = tasker
         .get_task_detail(task_id)
@@ -987,10 +1341,16 @@ pub fn maa_get_task_status(
 
 /// 停止任务
 /// 停止任务的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
-pub fn stop_task_impl(state: &MaaState, instance_id: &str) -> Result<(), String> {
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get_mut(instance_id).ok_or("Instance not found")?;
-    let tasker = instance.tasker.as_ref().ok_or("Tasker not created")?;
+pub fn stop_task_impl(state: &MaaState, instance_id: &str) -> MxuResult<()> {
+    let mut guard = state
+        .instances
+        .get_mut(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
+    let tasker = instance
+        .tasker
+        .as_ref()
+        .ok_or_else(|| tr!("error.tasker_not_created"))?;
 
     if instance.stop_in_progress {
         if !tasker.running() {
@@ -1031,14 +1391,134 @@ pub fn maa_stop_task(
     app: tauri::AppHandle,
     state: State<Arc<MaaState>>,
     instance_id: String,
-) -> Result<(), String> {
+) -> Result<(), MxuError> {
+    let correlation_id = trace::begin("maa_stop_task", Some(&instance_id));
     let result = stop_task_impl(&state, &instance_id);
+    trace::finish(&correlation_id, &result);
     if result.is_ok() {
         super::utils::emit_state_changed(&app, &instance_id, "task-stopped");
     }
     result
 }
 
+/// 撤下单个尚未开始的任务，返回其配置；任务已开始或无法覆盖时返回 None
+///
+/// MaaFramework 不支持撤回已提交的任务，因此将其入口节点覆盖为 DoNothing 使其空跑结束，
+/// 并从运行状态中移除（回调不再计入状态）。
+pub(super) fn withdraw_task(
+    instance: &mut InstanceRuntime,
+    tasker: &Tasker,
+    task_id: i64,
+) -> Option<TaskConfig> {
+    if !matches!(
+        tasker
+            .get_task_detail(task_id)
+            .ok()
+            .flatten()
+            .map(|d| d.status),
+        None | Some(MaaStatus::PENDING)
+    ) {
+        return None;
+    }
+    let entry = instance.submitted_tasks.get(&task_id)?.entry.clone();
+    let noop = serde_json::json!({
+        entry.as_str(): {
+            "recognition": "DirectHit",
+            "action": "DoNothing",
+            "next": [],
+            "on_error": [],
+            "pre_delay": 0,
+            "post_delay": 0,
+        }
+    });
+    match tasker.override_pipeline(task_id, &noop.to_string()) {
+        Ok(true) => {}
+        Ok(false) => {
+            warn!("Queued task {} can no longer be overridden", task_id);
+            return None;
+        }
+        Err(e) => {
+            warn!("Failed to override queued task {}: {}", task_id, e);
+            return None;
+        }
+    }
+
+    instance.skipped_task_ids.insert(task_id);
+    instance.task_ids.retain(|id| *id != task_id);
+    let run_state = &mut instance.task_run_state;
+    run_state.pending_task_ids.retain(|id| *id != task_id);
+    run_state.mappings.remove(&task_id);
+    instance.submitted_tasks.remove(&task_id)
+}
+
+/// 从队列中撤下尚未开始的任务，返回被撤下的任务（按执行顺序）
+fn withdraw_queued_tasks(instance: &mut InstanceRuntime) -> Vec<(i64, TaskConfig)> {
+    let Some(tasker) = instance.tasker.clone() else {
+        return Vec::new();
+    };
+
+    let run_state = &instance.task_run_state;
+    let queued: Vec<i64> = run_state
+        .pending_task_ids
+        .iter()
+        .skip(run_state.current_task_index + 1)
+        .copied()
+        .collect();
+
+    queued
+        .into_iter()
+        .filter_map(|task_id| Some((task_id, withdraw_task(instance, &tasker, task_id)?)))
+        .collect()
+}
+
+/// 当前任务完成后停止的内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
+///
+/// 撤下队列中尚未开始的任务，返回撤下的任务数。
+pub fn stop_after_current_impl(state: &MaaState, instance_id: &str) -> MxuResult<usize> {
+    let mut guard = state
+        .instances
+        .get_mut(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
+    if instance.tasker.is_none() {
+        return Err(tr!("error.tasker_not_created").into());
+    }
+
+    let withdrawn = withdraw_queued_tasks(instance);
+    for (task_id, task) in &withdrawn {
+        if let Some(selected_id) = &task.selected_task_id {
+            instance
+                .task_run_state
+                .statuses
+                .insert(selected_id.clone(), "idle".to_string());
+        }
+        super::run_report::on_task_event(instance_id, *task_id, "skipped", None);
+    }
+
+    info!(
+        "stop_after_current: {} queued task(s) removed from instance {}",
+        withdrawn.len(),
+        instance_id
+    );
+    Ok(withdrawn.len())
+}
+
+/// 当前任务完成后停止：清空等待中的任务队列，但不打断正在执行的任务
+#[tauri::command]
+pub fn maa_stop_after_current(
+    app: tauri::AppHandle,
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<usize, MxuError> {
+    let correlation_id = trace::begin("maa_stop_after_current", Some(&instance_id));
+    let result = stop_after_current_impl(&state, &instance_id);
+    trace::finish(&correlation_id, &result);
+    if result.is_ok() {
+        super::utils::emit_state_changed(&app, &instance_id, "task-progress");
+    }
+    result
+}
+
 /// 覆盖已提交任务的 Pipeline 配置（用于运行中修改尚未执行的任务选项）
 /// 内部实现（可从 Tauri 命令和 HTTP 处理器共享调用）
 pub fn override_pipeline_impl(
@@ -1046,14 +1526,19 @@ pub fn override_pipeline_impl(
     instance_id: &str,
     task_id: i64,
     pipeline_override: &str,
-) -> Result<bool, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(instance_id).ok_or("Instance not found")?;
-    let tasker = instance.tasker.as_ref().ok_or("Tasker not created")?;
+) -> MxuResult<bool> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let tasker = instance
+        .tasker
+        .as_ref()
+        .ok_or_else(|| tr!("error.tasker_not_created"))?;
 
     tasker
         .override_pipeline(task_id, pipeline_override)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string().into())
 }
 
 /// 覆盖已提交任务的 Pipeline 配置（用于运行中修改尚未执行的任务选项）
@@ -1063,15 +1548,17 @@ pub fn maa_override_pipeline(
     instance_id: String,
     task_id: i64,
     pipeline_override: String,
-) -> Result<bool, String> {
+) -> Result<bool, MxuError> {
     override_pipeline_impl(&state, &instance_id, task_id, &pipeline_override)
 }
 
 /// 检查是否正在运行
 #[tauri::command]
-pub fn maa_is_running(state: State<Arc<MaaState>>, instance_id: String) -> Result<bool, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(&instance_id).ok_or("Instance not found")?;
+pub fn maa_is_running(state: State<Arc<MaaState>>, instance_id: String) -> Result<bool, MxuError> {
+    let instance = state
+        .instances
+        .get(&instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
 
     Ok(instance.tasker.as_ref().is_some_and(|t| t.running()))
 }
@@ -1081,14 +1568,21 @@ pub fn maa_is_running(state: State<Arc<MaaState>>, instance_id: String) -> Resul
 // ============================================================================
 
 /// 发起点击请求（内部实现）
-pub fn post_click_impl(state: &MaaState, instance_id: &str, x: i32, y: i32) -> Result<i64, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(instance_id).ok_or("Instance not found")?;
+pub fn post_click_impl(state: &MaaState, instance_id: &str, x: i32, y: i32) -> MxuResult<i64> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
     let controller = instance
         .controller
         .as_ref()
-        .ok_or("Controller not connected")?;
-    controller.post_click(x, y).map_err(|e| e.to_string())
+        .ok_or_else(MxuError::controller_not_connected)?;
+    let id = controller.post_click(x, y).map_err(|e| e.to_string())?;
+    super::input_recorder::record_input(
+        instance_id,
+        super::input_recorder::RecordedInput::Click { x, y },
+    );
+    Ok(id)
 }
 
 /// 发起点击请求
@@ -1098,47 +1592,102 @@ pub fn maa_post_click(
     instance_id: String,
     x: i32,
     y: i32,
-) -> Result<i64, String> {
+) -> Result<i64, MxuError> {
     post_click_impl(&state, &instance_id, x, y)
 }
 
+/// 发起滑动请求（内部实现）
+pub fn post_swipe_impl(
+    state: &MaaState,
+    instance_id: &str,
+    (x1, y1): (i32, i32),
+    (x2, y2): (i32, i32),
+    duration: i32,
+) -> MxuResult<i64> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let controller = instance
+        .controller
+        .as_ref()
+        .ok_or_else(MxuError::controller_not_connected)?;
+    let id = controller
+        .post_swipe(x1, y1, x2, y2, duration)
+        .map_err(|e| e.to_string())?;
+    super::input_recorder::record_input(
+        instance_id,
+        super::input_recorder::RecordedInput::Swipe {
+            x1,
+            y1,
+            x2,
+            y2,
+            duration,
+        },
+    );
+    Ok(id)
+}
+
+/// 发起滑动请求
+#[tauri::command]
+pub fn maa_post_swipe(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    duration: i32,
+) -> Result<i64, MxuError> {
+    post_swipe_impl(&state, &instance_id, (x1, y1), (x2, y2), duration)
+}
+
 // ============================================================================
 // 截图命令
 // ============================================================================
 
 /// 发起截图请求（内部实现）
-pub fn post_screencap_impl(state: &MaaState, instance_id: &str) -> Result<i64, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(instance_id).ok_or("Instance not found")?;
+pub fn post_screencap_impl(state: &MaaState, instance_id: &str) -> MxuResult<i64> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
     let controller = instance
         .controller
         .as_ref()
-        .ok_or("Controller not connected")?;
-    controller.post_screencap().map_err(|e| e.to_string())
+        .ok_or_else(MxuError::controller_not_connected)?;
+    controller
+        .post_screencap()
+        .map_err(|e| e.to_string().into())
 }
 
 /// 发起截图请求
 #[tauri::command]
-pub fn maa_post_screencap(state: State<Arc<MaaState>>, instance_id: String) -> Result<i64, String> {
+pub fn maa_post_screencap(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+) -> Result<i64, MxuError> {
     post_screencap_impl(&state, &instance_id)
 }
 
 /// 获取缓存的截图（内部实现，返回 base64 编码的 PNG 图像）
-pub fn get_cached_image_impl(state: &MaaState, instance_id: &str) -> Result<String, String> {
-    let instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances.get(instance_id).ok_or("Instance not found")?;
+pub fn get_cached_image_impl(state: &MaaState, instance_id: &str) -> MxuResult<String> {
+    let instance = state
+        .instances
+        .get(instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
     let controller = instance
         .controller
         .as_ref()
-        .ok_or("Controller not connected")?;
+        .ok_or_else(MxuError::controller_not_connected)?;
 
     let buffer = controller.cached_image().map_err(|e| e.to_string())?;
     let data = buffer
         .to_vec()
-        .ok_or("Failed to convert image buffer".to_string())?;
+        .ok_or_else(|| tr!("error.screenshot.convert_failed"))?;
 
     if data.is_empty() {
-        return Err("No image data available".to_string());
+        return Err(tr!("error.screenshot.no_data").into());
     }
 
     use base64::{engine::general_purpose::STANDARD, Engine as _};
@@ -1151,10 +1700,77 @@ pub fn get_cached_image_impl(state: &MaaState, instance_id: &str) -> Result<Stri
 pub fn maa_get_cached_image(
     state: State<Arc<MaaState>>,
     instance_id: String,
-) -> Result<String, String> {
+) -> Result<String, MxuError> {
     get_cached_image_impl(&state, &instance_id)
 }
 
+/// 预览窗口截图的超时时间（连接 + 截图）
+const PREVIEW_WINDOW_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 预览窗口截图的内部实现：创建临时 Win32 控制器，截取一帧后销毁
+///
+/// 临时控制器不放入 ControllerPool，不影响已有连接。
+pub fn preview_window_impl(handle: u64, screencap_method: u64) -> Result<String, String> {
+    if handle == 0 {
+        return Err(tr!("error.preview.invalid_handle"));
+    }
+
+    let hwnd = handle as *mut std::ffi::c_void;
+    let controller = Controller::new_win32(
+        hwnd,
+        maa_framework::common::Win32ScreencapMethod::from_bits_truncate(screencap_method).bits(),
+        maa_framework::common::Win32InputMethod::from_bits_truncate(0).bits(),
+        maa_framework::common::Win32InputMethod::from_bits_truncate(0).bits(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let deadline = Instant::now() + PREVIEW_WINDOW_TIMEOUT;
+
+    controller.post_connection().map_err(|e| e.to_string())?;
+    while !controller.connected() {
+        if Instant::now() >= deadline {
+            return Err(tr!("error.preview.connect_timeout"));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    controller.post_screencap().map_err(|e| e.to_string())?;
+    let data = loop {
+        if let Some(data) = controller
+            .cached_image()
+            .ok()
+            .and_then(|buffer| buffer.to_vec())
+            .filter(|data| !data.is_empty())
+        {
+            break data;
+        }
+        if Instant::now() >= deadline {
+            return Err(tr!("error.preview.screencap_timeout"));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    // controller 在此处 drop，临时控制器随之销毁
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    Ok(format!("data:image/png;base64,{}", STANDARD.encode(&data)))
+}
+
+/// 预览窗口截图（连接前使用，便于确认目标窗口与截图方式）
+///
+/// 返回 base64 编码的 PNG 图像
+#[tauri::command]
+pub async fn maa_preview_window(handle: u64, screencap_method: u64) -> Result<String, MxuError> {
+    info!(
+        "maa_preview_window called, handle: {}, screencap_method: {}",
+        handle, screencap_method
+    );
+    Ok(
+        tokio::task::spawn_blocking(move || preview_window_impl(handle, screencap_method))
+            .await
+            .map_err(|e| e.to_string())??,
+    )
+}
+
 /// 订阅实例的实时截图（后端统一驱动截图循环）
 ///
 /// 多个客户端可同时订阅同一实例，后端按最快订阅者的帧率驱动唯一一份截图循环。
@@ -1167,7 +1783,7 @@ pub async fn maa_screenshot_subscribe(
     instance_id: String,
     subscriber_id: String,
     interval_ms: u64,
-) -> Result<(), String> {
+) -> Result<(), MxuError> {
     let handle = tokio::runtime::Handle::current();
     state.screenshot_service.subscribe(
         state.inner().clone(),
@@ -1185,7 +1801,7 @@ pub async fn maa_screenshot_unsubscribe(
     state: State<'_, Arc<MaaState>>,
     instance_id: String,
     subscriber_id: String,
-) -> Result<(), String> {
+) -> Result<(), MxuError> {
     state
         .screenshot_service
         .unsubscribe(&instance_id, &subscriber_id);
@@ -0,0 +1,170 @@
+//! MaaFramework 回调消息的类型化解析
+//!
+//! 将 `Resource.Loading.*`、`Controller.Action.*`、`Tasker.Task.*`、`Node.*` 等通知
+//! 解析为带 `kind` 标签的结构化事件（如 `task_started`、`node_recognized`、`connect_failed`），
+//! 以 `maa-event` 事件发送，前端无需再对消息名与 details 字符串做正则匹配。
+//! 原始的 `maa-callback` 事件保持不变。
+
+use serde::{Deserialize, Serialize};
+
+/// 资源加载详情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceDetail {
+    pub res_id: i64,
+    #[serde(default)]
+    pub path: String,
+}
+
+/// 控制器动作详情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControllerDetail {
+    pub ctrl_id: i64,
+    #[serde(default)]
+    pub action: String,
+}
+
+/// 任务详情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDetail {
+    pub task_id: i64,
+    #[serde(default)]
+    pub entry: String,
+}
+
+/// 节点详情（识别 / 动作 / 节点执行）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeDetail {
+    pub task_id: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reco_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<i64>,
+    /// pipeline 中配置的 focus 字段（原样透传）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub focus: Option<serde_json::Value>,
+}
+
+/// 类型化的 MaaFramework 回调事件
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MaaEvent {
+    ResourceLoadStarted(ResourceDetail),
+    ResourceLoaded(ResourceDetail),
+    ResourceLoadFailed(ResourceDetail),
+
+    ConnectStarted(ControllerDetail),
+    Connected(ControllerDetail),
+    ConnectFailed(ControllerDetail),
+    ControllerActionStarted(ControllerDetail),
+    ControllerActionSucceeded(ControllerDetail),
+    ControllerActionFailed(ControllerDetail),
+
+    TaskStarted(TaskDetail),
+    TaskSucceeded(TaskDetail),
+    TaskFailed(TaskDetail),
+
+    NodeStarted(NodeDetail),
+    NodeSucceeded(NodeDetail),
+    NodeFailed(NodeDetail),
+    NodeRecognitionStarted(NodeDetail),
+    NodeRecognized(NodeDetail),
+    NodeRecognitionFailed(NodeDetail),
+    NodeActionStarted(NodeDetail),
+    NodeActed(NodeDetail),
+    NodeActionFailed(NodeDetail),
+
+    /// 未识别的消息或 details 解析失败，原样透传
+    Other {
+        message: String,
+        details: serde_json::Value,
+    },
+}
+
+/// 发送给前端的 `maa-event` 载荷
+#[derive(Debug, Clone, Serialize)]
+pub struct MaaEventPayload {
+    pub instance_id: String,
+    #[serde(flatten)]
+    pub event: MaaEvent,
+}
+
+impl MaaEvent {
+    /// 解析一条回调消息
+    pub fn parse(message: &str, details: &str) -> Self {
+        Self::try_parse(message, details).unwrap_or_else(|| MaaEvent::Other {
+            message: message.to_string(),
+            details: serde_json::from_str(details)
+                .unwrap_or_else(|_| serde_json::Value::String(details.to_string())),
+        })
+    }
+
+    fn try_parse(message: &str, details: &str) -> Option<Self> {
+        let (category, phase) = message.rsplit_once('.')?;
+        let parsed = match category {
+            "Resource.Loading" => {
+                let d: ResourceDetail = serde_json::from_str(details).ok()?;
+                match phase {
+                    "Starting" => MaaEvent::ResourceLoadStarted(d),
+                    "Succeeded" => MaaEvent::ResourceLoaded(d),
+                    "Failed" => MaaEvent::ResourceLoadFailed(d),
+                    _ => return None,
+                }
+            }
+            "Controller.Action" => {
+                let d: ControllerDetail = serde_json::from_str(details).ok()?;
+                let is_connect = d.action.eq_ignore_ascii_case("connect");
+                match (phase, is_connect) {
+                    ("Starting", true) => MaaEvent::ConnectStarted(d),
+                    ("Succeeded", true) => MaaEvent::Connected(d),
+                    ("Failed", true) => MaaEvent::ConnectFailed(d),
+                    ("Starting", false) => MaaEvent::ControllerActionStarted(d),
+                    ("Succeeded", false) => MaaEvent::ControllerActionSucceeded(d),
+                    ("Failed", false) => MaaEvent::ControllerActionFailed(d),
+                    _ => return None,
+                }
+            }
+            "Tasker.Task" => {
+                let d: TaskDetail = serde_json::from_str(details).ok()?;
+                match phase {
+                    "Starting" => MaaEvent::TaskStarted(d),
+                    "Succeeded" => MaaEvent::TaskSucceeded(d),
+                    "Failed" => MaaEvent::TaskFailed(d),
+                    _ => return None,
+                }
+            }
+            "Node.PipelineNode" => {
+                let d: NodeDetail = serde_json::from_str(details).ok()?;
+                match phase {
+                    "Starting" => MaaEvent::NodeStarted(d),
+                    "Succeeded" => MaaEvent::NodeSucceeded(d),
+                    "Failed" => MaaEvent::NodeFailed(d),
+                    _ => return None,
+                }
+            }
+            "Node.Recognition" => {
+                let d: NodeDetail = serde_json::from_str(details).ok()?;
+                match phase {
+                    "Starting" => MaaEvent::NodeRecognitionStarted(d),
+                    "Succeeded" => MaaEvent::NodeRecognized(d),
+                    "Failed" => MaaEvent::NodeRecognitionFailed(d),
+                    _ => return None,
+                }
+            }
+            "Node.Action" => {
+                let d: NodeDetail = serde_json::from_str(details).ok()?;
+                match phase {
+                    "Starting" => MaaEvent::NodeActionStarted(d),
+                    "Succeeded" => MaaEvent::NodeActed(d),
+                    "Failed" => MaaEvent::NodeActionFailed(d),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        Some(parsed)
+    }
+}
@@ -0,0 +1,112 @@
+//! MaaFramework FFI 调用辅助
+//!
+//! 创建控制器、销毁资源/Tasker 等 FFI 调用可能长时间阻塞（例如 adb 连接卡死、
+//! Tasker 销毁时等待运行中的任务退出）。这些调用统一经 [`with_ffi_timeout`]
+//! 放到阻塞线程池执行，并限定等待时间，避免冻结 Tauri 主线程或 async 运行时。
+//!
+//! 句柄管理：`Resource` / `Controller` / `Tasker` / `AgentClient` 均使用 `maa_framework`
+//! crate 提供的安全封装（引用计数、`Drop` 时销毁底层句柄），MXU 侧不持有裸指针，
+//! 也没有 `unsafe impl Send`。实例销毁时正在使用句柄的操作持有的是克隆，
+//! 底层句柄会在最后一个引用释放后才销毁。
+
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::warn;
+
+use crate::i18n::tr;
+
+/// 创建并连接控制器的超时
+pub const FFI_CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 提交资源加载的超时
+pub const FFI_LOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 销毁控制器 / 资源 / Tasker 的超时
+pub const FFI_DESTROY_TIMEOUT: Duration = Duration::from_secs(15);
+
+const GUARD_PENDING: u8 = 0;
+const GUARD_COMMITTED: u8 = 1;
+const GUARD_CANCELLED: u8 = 2;
+
+/// 超时后的写入保护
+///
+/// FFI 闭包在写入共享状态（控制器池、实例状态等）前调用 [`FfiCommitGuard::commit`]；
+/// 调用方超时放弃等待时会先将其标记为已取消，此后遗留在阻塞线程中的闭包无法再写入状态。
+/// 两者互斥：闭包一旦提交成功，超时不再生效，调用方会等待闭包返回并得到真实结果。
+#[derive(Clone, Default)]
+pub struct FfiCommitGuard(Arc<AtomicU8>);
+
+impl FfiCommitGuard {
+    /// 申请写入共享状态；调用方已超时放弃时返回错误，闭包应直接返回且不做任何写入
+    pub fn commit(&self, label: &str) -> Result<(), String> {
+        match self.0.compare_exchange(
+            GUARD_PENDING,
+            GUARD_COMMITTED,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) | Err(GUARD_COMMITTED) => Ok(()),
+            Err(_) => {
+                warn!(
+                    "[maa_ffi] {} returned after timeout, discarding its result",
+                    label
+                );
+                Err(tr!("error.ffi.discarded", label = label))
+            }
+        }
+    }
+
+    /// 超时时调用：闭包尚未提交则标记为已取消并返回 true
+    fn cancel(&self) -> bool {
+        self.0
+            .compare_exchange(
+                GUARD_PENDING,
+                GUARD_CANCELLED,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok()
+    }
+}
+
+/// 在阻塞线程池中执行 FFI 调用，超时返回错误
+///
+/// 超时后调用方立即得到错误，但 FFI 调用本身无法被中断，会在阻塞线程中继续执行直到返回；
+/// 闭包持有的句柄届时才会释放。闭包需要写入共享状态时使用 [`with_ffi_timeout_guarded`]。
+pub async fn with_ffi_timeout<T, E, F>(label: &str, timeout: Duration, f: F) -> Result<T, E>
+where
+    F: FnOnce() -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: From<String> + Send + 'static,
+{
+    with_ffi_timeout_guarded(label, timeout, move |_| f()).await
+}
+
+/// 同 [`with_ffi_timeout`]，闭包额外获得 [`FfiCommitGuard`]，写入共享状态前须先 `commit`
+pub async fn with_ffi_timeout_guarded<T, E, F>(label: &str, timeout: Duration, f: F) -> Result<T, E>
+where
+    F: FnOnce(&FfiCommitGuard) -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: From<String> + Send + 'static,
+{
+    let guard = FfiCommitGuard::default();
+    let closure_guard = guard.clone();
+    let mut handle = tokio::task::spawn_blocking(move || f(&closure_guard));
+
+    let joined = match tokio::time::timeout(timeout, &mut handle).await {
+        Ok(joined) => joined,
+        Err(_) if guard.cancel() => {
+            warn!(
+                "[maa_ffi] {} did not return within {}s, leaving it on the blocking pool",
+                label,
+                timeout.as_secs()
+            );
+            return Err(tr!("error.ffi.timeout", label = label, secs = timeout.as_secs()).into());
+        }
+        // 闭包已开始写入状态，等待其完成以保证返回值与状态一致
+        Err(_) => handle.await,
+    };
+    joined.unwrap_or_else(|e| Err(tr!("error.ffi.panicked", label = label, error = e).into()))
+}
@@ -0,0 +1,258 @@
+//! 游戏维护时段
+//!
+//! 用户在 `settings.maintenance` 中登记游戏维护时段（版本更新日等），
+//! 维护期间启动任务必然失败，`start_tasks_impl`（定时执行同样经过此处）在维护时段内
+//! 拒绝或延后启动，并发送 `maintenance-window` 事件说明原因。
+//!
+//! 配置示例：
+//! ```json
+//! "maintenance": {
+//!   "policy": "delay",
+//!   "maxDelayMinutes": 180,
+//!   "windows": [
+//!     { "name": "版本更新", "start": "2026-10-20 06:00", "end": "2026-10-20 11:00" },
+//!     { "name": "例行维护", "weekdays": [4], "startTime": "04:00", "endTime": "06:00" }
+//!   ]
+//! }
+//! ```
+//! - 一次性时段使用 `start` / `end`（本地时间）
+//! - 每周时段使用 `weekdays`（1 = 周一 … 7 = 周日）与 `startTime` / `endTime`，
+//!   `endTime` 不晚于 `startTime` 时视为跨越午夜
+//! - `policy`: `refuse`（默认，直接拒绝）或 `delay`（等待维护结束后再启动，最多 `maxDelayMinutes` 分钟）
+
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::time::Duration;
+
+use chrono::{Datelike, NaiveDateTime, NaiveTime};
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::i18n::tr;
+
+const DEFAULT_MAX_DELAY_MINUTES: u64 = 180;
+/// 延后等待期间重新检查配置的间隔
+const RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+const DATETIME_FORMATS: [&str; 2] = ["%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"];
+
+/// 维护时段内启动任务的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[repr(u8)]
+pub enum MaintenancePolicy {
+    Refuse = 0,
+    Delay = 1,
+}
+
+/// 维护时段配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MaintenanceWindow {
+    #[serde(default)]
+    name: String,
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+    #[serde(default)]
+    start: Option<String>,
+    #[serde(default)]
+    end: Option<String>,
+    #[serde(default)]
+    weekdays: Vec<u32>,
+    #[serde(default)]
+    start_time: Option<String>,
+    #[serde(default)]
+    end_time: Option<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 当前生效的维护时段
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveMaintenance {
+    pub name: String,
+    /// 结束时间（本地时间，`YYYY-MM-DD HH:MM`）
+    pub ends_at: String,
+    /// 距结束的秒数
+    pub remaining_secs: u64,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MaintenanceEvent {
+    instance_id: String,
+    policy: MaintenancePolicy,
+    #[serde(flatten)]
+    window: ActiveMaintenance,
+}
+
+static WINDOWS: Mutex<Vec<MaintenanceWindow>> = Mutex::new(Vec::new());
+static POLICY: AtomicU8 = AtomicU8::new(MaintenancePolicy::Refuse as u8);
+static MAX_DELAY_MINUTES: AtomicU64 = AtomicU64::new(DEFAULT_MAX_DELAY_MINUTES);
+
+fn policy() -> MaintenancePolicy {
+    match POLICY.load(Ordering::Relaxed) {
+        1 => MaintenancePolicy::Delay,
+        _ => MaintenancePolicy::Refuse,
+    }
+}
+
+/// 读取 `settings.maintenance`（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let maintenance = config.get("settings").and_then(|s| s.get("maintenance"));
+    let windows: Vec<MaintenanceWindow> = maintenance
+        .and_then(|m| m.get("windows"))
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|w| match serde_json::from_value(w.clone()) {
+                    Ok(window) => Some(window),
+                    Err(e) => {
+                        warn!("Ignoring invalid maintenance window {}: {}", w, e);
+                        None
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let policy = match maintenance
+        .and_then(|m| m.get("policy"))
+        .and_then(|v| v.as_str())
+    {
+        Some("delay") => MaintenancePolicy::Delay,
+        _ => MaintenancePolicy::Refuse,
+    };
+    POLICY.store(policy as u8, Ordering::Relaxed);
+    MAX_DELAY_MINUTES.store(
+        maintenance
+            .and_then(|m| m.get("maxDelayMinutes"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_MAX_DELAY_MINUTES),
+        Ordering::Relaxed,
+    );
+    *WINDOWS.lock() = windows;
+}
+
+fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(value.trim(), fmt).ok())
+}
+
+fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+impl MaintenanceWindow {
+    /// `now` 处于该时段内时返回时段结束时间
+    fn end_if_active(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        if !self.enabled {
+            return None;
+        }
+        if let (Some(start), Some(end)) = (self.start.as_deref(), self.end.as_deref()) {
+            let (start, end) = (parse_datetime(start)?, parse_datetime(end)?);
+            return (start <= now && now < end).then_some(end);
+        }
+
+        let start_time = parse_time(self.start_time.as_deref()?)?;
+        let end_time = parse_time(self.end_time.as_deref()?)?;
+        // 检查今天和昨天开始的时段（后者用于跨越午夜的时段）
+        (0..=1).find_map(|days_ago| {
+            let date = now.date() - chrono::Duration::days(days_ago);
+            if !self.weekdays.contains(&date.weekday().number_from_monday()) {
+                return None;
+            }
+            let start = date.and_time(start_time);
+            let mut end = date.and_time(end_time);
+            if end <= start {
+                end += chrono::Duration::days(1);
+            }
+            (start <= now && now < end).then_some(end)
+        })
+    }
+}
+
+/// 当前生效的维护时段（多个重叠时取最晚结束的）
+pub fn active_window() -> Option<ActiveMaintenance> {
+    let now = chrono::Local::now().naive_local();
+    let windows = WINDOWS.lock();
+    windows
+        .iter()
+        .filter_map(|w| w.end_if_active(now).map(|end| (w, end)))
+        .max_by_key(|(_, end)| *end)
+        .map(|(w, end)| ActiveMaintenance {
+            name: w.name.clone(),
+            ends_at: end.format("%Y-%m-%d %H:%M").to_string(),
+            remaining_secs: (end - now).num_seconds().max(0) as u64,
+        })
+}
+
+fn emit_event(
+    app: &AppHandle,
+    instance_id: &str,
+    policy: MaintenancePolicy,
+    window: &ActiveMaintenance,
+) {
+    let event = MaintenanceEvent {
+        instance_id: instance_id.to_string(),
+        policy,
+        window: window.clone(),
+    };
+    if let Err(e) = app.emit("maintenance-window", event) {
+        warn!("Failed to emit maintenance-window: {}", e);
+    }
+}
+
+/// 启动任务前检查维护时段：`refuse` 策略直接返回错误，`delay` 策略等待维护结束
+pub async fn gate_start(app: &AppHandle, instance_id: &str) -> Result<(), String> {
+    let mut notified = false;
+    while let Some(window) = active_window() {
+        let policy = policy();
+        if !notified {
+            emit_event(app, instance_id, policy, &window);
+            notified = true;
+        }
+        if policy == MaintenancePolicy::Refuse {
+            return Err(tr!(
+                "error.maintenance.refused",
+                name = window.name,
+                ends_at = window.ends_at
+            ));
+        }
+        let max_delay_secs = MAX_DELAY_MINUTES.load(Ordering::Relaxed) * 60;
+        if window.remaining_secs > max_delay_secs {
+            return Err(tr!(
+                "error.maintenance.too_long",
+                name = window.name,
+                ends_at = window.ends_at,
+                minutes = max_delay_secs / 60
+            ));
+        }
+        info!(
+            "Instance {} delayed by maintenance window '{}' until {}",
+            instance_id, window.name, window.ends_at
+        );
+        tokio::time::sleep(RECHECK_INTERVAL.min(Duration::from_secs(window.remaining_secs.max(1))))
+            .await;
+    }
+    if notified {
+        info!(
+            "Maintenance window ended, starting tasks for {}",
+            instance_id
+        );
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 查询当前生效的维护时段（不在维护时段内时返回 null）
+#[tauri::command]
+pub fn mxu_get_maintenance_status() -> Option<ActiveMaintenance> {
+    active_window()
+}
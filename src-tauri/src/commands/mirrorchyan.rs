@@ -0,0 +1,458 @@
+//! MirrorChyan 更新源
+//!
+//! 查询 MirrorChyan 上 MXU 本体及资源包的最新版本，携带 CDK 时返回下载链接。
+//! 主站失败时自动切换备用站，并对 429 限流做冷却处理，避免前端反复触发请求。
+//!
+//! 错误码参考: https://github.com/MirrorChyan/docs/blob/main/ErrorCode.md
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::utils::build_user_agent;
+use crate::i18n::tr;
+
+/// MirrorChyan API 基础地址（主站 + 备用站，按顺序尝试）
+const MIRRORCHYAN_API_BASES: &[&str] = &[
+    "https://mirrorchyan.com/api/resources",
+    "https://mirrorchyan.net/api/resources",
+];
+
+/// 服务端未给出 Retry-After 时的默认限流冷却时间
+const DEFAULT_RATE_LIMIT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// 各资源的限流解除时间点（key 为 resource_id）
+static RATE_LIMITED_UNTIL: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+
+/// MirrorChyan 业务错误码
+pub mod error_code {
+    /// 未区分的业务错误
+    pub const UNDIVIDED: i64 = 1;
+    /// 参数不正确
+    pub const INVALID_PARAMS: i64 = 1001;
+    /// CDK 已过期
+    pub const KEY_EXPIRED: i64 = 7001;
+    /// CDK 错误
+    pub const KEY_INVALID: i64 = 7002;
+    /// CDK 今日下载次数已达上限
+    pub const RESOURCE_QUOTA_EXHAUSTED: i64 = 7003;
+    /// CDK 类型和待下载的资源不匹配
+    pub const KEY_MISMATCHED: i64 = 7004;
+    /// CDK 已被封禁
+    pub const KEY_BLOCKED: i64 = 7005;
+    /// 对应架构和系统下的资源不存在
+    pub const RESOURCE_NOT_FOUND: i64 = 8001;
+    /// 错误的系统参数
+    pub const INVALID_OS: i64 = 8002;
+    /// 错误的架构参数
+    pub const INVALID_ARCH: i64 = 8003;
+    /// 错误的更新通道参数
+    pub const INVALID_CHANNEL: i64 = 8004;
+}
+
+/// 前端可直接据此展示提示文案的错误分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MirrorChyanErrorKind {
+    /// CDK 相关错误（过期、错误、封禁、类型不匹配）
+    Cdk,
+    /// CDK 今日下载次数已用尽
+    Quota,
+    /// 当前系统/架构/通道下没有对应资源
+    NotFound,
+    /// 请求参数错误
+    InvalidParams,
+    /// 被服务端限流（HTTP 429）
+    RateLimited,
+    /// 网络请求失败或响应无法解析
+    Network,
+    /// 其他业务错误
+    Unknown,
+}
+
+impl MirrorChyanErrorKind {
+    fn from_code(code: i64) -> Self {
+        use error_code::*;
+        match code {
+            KEY_EXPIRED | KEY_INVALID | KEY_MISMATCHED | KEY_BLOCKED => Self::Cdk,
+            RESOURCE_QUOTA_EXHAUSTED => Self::Quota,
+            RESOURCE_NOT_FOUND => Self::NotFound,
+            INVALID_PARAMS | INVALID_OS | INVALID_ARCH | INVALID_CHANNEL => Self::InvalidParams,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// MirrorChyan API 原始响应
+#[derive(Debug, Deserialize)]
+struct MirrorChyanApiResponse {
+    code: i64,
+    #[serde(default)]
+    msg: String,
+    #[serde(default)]
+    data: Option<MirrorChyanApiData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MirrorChyanApiData {
+    #[serde(default)]
+    version_name: String,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    sha256: Option<String>,
+    #[serde(default)]
+    release_note: Option<String>,
+    #[serde(default)]
+    update_type: Option<String>,
+    #[serde(default)]
+    channel: Option<String>,
+    #[serde(default)]
+    filesize: Option<u64>,
+    #[serde(default)]
+    cdk_expired_time: Option<i64>,
+}
+
+/// 返回给前端的更新查询结果
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MirrorChyanUpdateInfo {
+    /// 是否有新版本（与 current_version 做 semver 比较）
+    pub has_update: bool,
+    pub version_name: String,
+    pub release_note: String,
+    /// 下载链接，仅在携带有效 CDK 时返回
+    pub download_url: Option<String>,
+    pub sha256: Option<String>,
+    /// "incremental" | "full"
+    pub update_type: Option<String>,
+    pub channel: Option<String>,
+    pub file_size: Option<u64>,
+    /// CDK 过期时间（Unix 秒）
+    pub cdk_expired_time: Option<i64>,
+    /// MirrorChyan 业务错误码（0 表示成功）
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    pub error_kind: Option<MirrorChyanErrorKind>,
+    /// 被限流时建议的重试等待秒数
+    pub retry_after: Option<u64>,
+}
+
+impl MirrorChyanUpdateInfo {
+    fn from_error(kind: MirrorChyanErrorKind, code: i64, message: String) -> Self {
+        Self {
+            error_code: code,
+            error_message: Some(message),
+            error_kind: Some(kind),
+            ..Default::default()
+        }
+    }
+
+    fn rate_limited(retry_after: Duration) -> Self {
+        let mut info = Self::from_error(
+            MirrorChyanErrorKind::RateLimited,
+            0,
+            "请求过于频繁，请稍后再试".to_string(),
+        );
+        info.retry_after = Some(retry_after.as_secs().max(1));
+        info
+    }
+}
+
+/// 将 Rust 的 OS 名称映射为 MirrorChyan 参数
+fn mirrorchyan_os() -> &'static str {
+    match std::env::consts::OS {
+        "windows" => "windows",
+        "macos" => "darwin",
+        "linux" => "linux",
+        _ => "",
+    }
+}
+
+/// 将 Rust 的架构名称映射为 MirrorChyan 参数
+fn mirrorchyan_arch() -> &'static str {
+    match std::env::consts::ARCH {
+        "x86_64" => "x86_64",
+        "aarch64" => "aarch64",
+        _ => "",
+    }
+}
+
+/// 比较版本号，remote 比 current 新时返回 true
+/// 无法按 semver 解析时回退到字符串不等判断
+fn is_newer_version(remote: &str, current: &str) -> bool {
+    let normalize = |v: &str| v.trim().trim_start_matches(['v', 'V']).to_string();
+    let (remote, current) = (normalize(remote), normalize(current));
+    match (
+        semver::Version::parse(&remote),
+        semver::Version::parse(&current),
+    ) {
+        (Ok(r), Ok(c)) => r > c,
+        _ => !remote.is_empty() && remote != current,
+    }
+}
+
+/// 查询资源是否仍处于限流冷却期，返回剩余时间
+fn remaining_cooldown(resource_id: &str) -> Option<Duration> {
+    let guard = RATE_LIMITED_UNTIL.lock();
+    let until = guard.as_ref()?.get(resource_id)?;
+    until.checked_duration_since(Instant::now())
+}
+
+fn mark_rate_limited(resource_id: &str, cooldown: Duration) {
+    RATE_LIMITED_UNTIL
+        .lock()
+        .get_or_insert_with(HashMap::new)
+        .insert(resource_id.to_string(), Instant::now() + cooldown);
+}
+
+fn parse_retry_after(response: &reqwest::Response) -> Duration {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_RATE_LIMIT_COOLDOWN)
+}
+
+/// 单个站点的请求结果
+enum FetchOutcome {
+    Response(MirrorChyanApiResponse),
+    RateLimited(Duration),
+}
+
+async fn fetch_latest(
+    client: &reqwest::Client,
+    api_base: &str,
+    resource_id: &str,
+    query: &[(&str, &str)],
+) -> Result<FetchOutcome, String> {
+    let url = format!("{}/{}/latest", api_base, urlencoding::encode(resource_id));
+    let response = client
+        .get(&url)
+        .query(query)
+        .send()
+        .await
+        .map_err(|e| tr!("error.download.request", error = e))?;
+
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Ok(FetchOutcome::RateLimited(parse_retry_after(&response)));
+    }
+
+    // MirrorChyan 的业务错误也可能伴随非 2xx 状态码返回 JSON，优先尝试解析
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| tr!("error.mirrorchyan.read_response", error = e))?;
+    serde_json::from_str::<MirrorChyanApiResponse>(&body)
+        .map(FetchOutcome::Response)
+        .map_err(|e| {
+            tr!(
+                "error.mirrorchyan.parse_response",
+                status = status,
+                error = e
+            )
+        })
+}
+
+/// 查询 MirrorChyan 资源的最新版本
+///
+/// 适用于 MXU 本体（项目的 mirrorchyan_rid）和资源包；`cdk` 为空时只返回版本信息，
+/// 不含下载链接。业务错误以 `error_code` / `error_kind` 字段返回，不视为命令失败。
+#[tauri::command]
+pub async fn mirrorchyan_check_update(
+    resource_id: String,
+    current_version: String,
+    cdk: Option<String>,
+    channel: Option<String>,
+    user_agent: Option<String>,
+    proxy_url: Option<String>,
+) -> Result<MirrorChyanUpdateInfo, String> {
+    if resource_id.trim().is_empty() {
+        return Err(tr!("error.mirrorchyan.no_rid"));
+    }
+
+    if let Some(remaining) = remaining_cooldown(&resource_id) {
+        warn!(
+            "[MirrorChyan] {} 仍处于限流冷却期，剩余 {}s",
+            resource_id,
+            remaining.as_secs()
+        );
+        return Ok(MirrorChyanUpdateInfo::rate_limited(remaining));
+    }
+
+    let mut client_builder = reqwest::Client::builder()
+        .user_agent(build_user_agent())
+        .timeout(Duration::from_secs(10))
+        .connect_timeout(Duration::from_secs(5));
+
+    if let Some(proxy) = proxy_url.as_deref().filter(|p| !p.is_empty()) {
+        let reqwest_proxy =
+            reqwest::Proxy::all(proxy).map_err(|e| tr!("error.download.proxy", error = e))?;
+        client_builder = client_builder.proxy(reqwest_proxy);
+    }
+
+    let client = client_builder
+        .build()
+        .map_err(|e| tr!("error.download.client", error = e))?;
+
+    let channel = channel
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| "stable".to_string());
+    let user_agent = user_agent
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| "MXU".to_string());
+    let cdk = cdk.map(|c| c.trim().to_string()).filter(|c| !c.is_empty());
+
+    let mut query: Vec<(&str, &str)> = vec![
+        ("current_version", current_version.as_str()),
+        ("user_agent", user_agent.as_str()),
+        ("channel", channel.as_str()),
+    ];
+    let (os, arch) = (mirrorchyan_os(), mirrorchyan_arch());
+    if !os.is_empty() {
+        query.push(("os", os));
+    }
+    if !arch.is_empty() {
+        query.push(("arch", arch));
+    }
+    if let Some(ref cdk) = cdk {
+        query.push(("cdk", cdk.as_str()));
+    }
+
+    info!(
+        "[MirrorChyan] 检查更新: {}, 当前版本: {}, 频道: {}",
+        resource_id, current_version, channel
+    );
+
+    let mut last_response: Option<MirrorChyanApiResponse> = None;
+    let mut last_error: Option<String> = None;
+    let mut rate_limit: Option<Duration> = None;
+
+    for api_base in MIRRORCHYAN_API_BASES {
+        match fetch_latest(&client, api_base, &resource_id, &query).await {
+            Ok(FetchOutcome::Response(resp)) => {
+                let ok = resp.code == 0;
+                if !ok {
+                    warn!(
+                        "[MirrorChyan] {} 返回错误: code={}, msg={}",
+                        api_base, resp.code, resp.msg
+                    );
+                }
+                last_response = Some(resp);
+                if ok {
+                    break;
+                }
+            }
+            Ok(FetchOutcome::RateLimited(retry_after)) => {
+                warn!(
+                    "[MirrorChyan] {} 触发限流，Retry-After: {}s",
+                    api_base,
+                    retry_after.as_secs()
+                );
+                rate_limit = Some(rate_limit.map_or(retry_after, |d| d.min(retry_after)));
+            }
+            Err(e) => {
+                warn!("[MirrorChyan] {} 请求失败: {}", api_base, e);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    let Some(resp) = last_response else {
+        // 所有站点均未给出有效响应
+        if let Some(retry_after) = rate_limit {
+            mark_rate_limited(&resource_id, retry_after);
+            return Ok(MirrorChyanUpdateInfo::rate_limited(retry_after));
+        }
+        return Ok(MirrorChyanUpdateInfo::from_error(
+            MirrorChyanErrorKind::Network,
+            0,
+            last_error.unwrap_or_else(|| tr!("error.mirrorchyan.all_unavailable")),
+        ));
+    };
+
+    let mut result = match resp.data {
+        Some(data) => MirrorChyanUpdateInfo {
+            has_update: is_newer_version(&data.version_name, &current_version),
+            release_note: data.release_note.unwrap_or_default(),
+            // 业务错误时即使返回了 url 也不应使用
+            download_url: if resp.code == 0 { data.url } else { None },
+            sha256: data.sha256,
+            update_type: data.update_type,
+            channel: data.channel,
+            file_size: data.filesize,
+            cdk_expired_time: data.cdk_expired_time,
+            version_name: data.version_name,
+            ..Default::default()
+        },
+        None if resp.code == 0 => {
+            return Ok(MirrorChyanUpdateInfo::from_error(
+                MirrorChyanErrorKind::Unknown,
+                0,
+                "响应缺少 data 字段".to_string(),
+            ));
+        }
+        None => MirrorChyanUpdateInfo::default(),
+    };
+
+    if resp.code != 0 {
+        result.error_code = resp.code;
+        result.error_message = Some(resp.msg);
+        result.error_kind = Some(if resp.code < 0 {
+            MirrorChyanErrorKind::Unknown
+        } else {
+            MirrorChyanErrorKind::from_code(resp.code)
+        });
+    }
+
+    info!(
+        "[MirrorChyan] 检查完成: 最新版本={}, 有更新={}, code={}",
+        result.version_name, result.has_update, result.error_code
+    );
+
+    Ok(result)
+}
+
+/// 获取 MirrorChyan 资源的 CDK 鉴权下载链接
+///
+/// 与 `mirrorchyan_check_update` 共用同一查询逻辑，但要求提供 CDK；
+/// 未拿到下载链接时通过 `error_code` / `error_kind` 告知前端原因。
+#[tauri::command]
+pub async fn mirrorchyan_get_download_url(
+    resource_id: String,
+    current_version: String,
+    cdk: String,
+    channel: Option<String>,
+    user_agent: Option<String>,
+    proxy_url: Option<String>,
+) -> Result<MirrorChyanUpdateInfo, String> {
+    if cdk.trim().is_empty() {
+        return Ok(MirrorChyanUpdateInfo::from_error(
+            MirrorChyanErrorKind::Cdk,
+            error_code::KEY_INVALID,
+            "获取下载链接需要填写 MirrorChyan CDK".to_string(),
+        ));
+    }
+
+    let mut info = mirrorchyan_check_update(
+        resource_id,
+        current_version,
+        Some(cdk),
+        channel,
+        user_agent,
+        proxy_url,
+    )
+    .await?;
+
+    if info.download_url.is_none() && info.error_kind.is_none() {
+        info.error_kind = Some(MirrorChyanErrorKind::Unknown);
+        info.error_code = error_code::UNDIVIDED;
+        info.error_message = Some(tr!("error.mirrorchyan.no_download_url"));
+    }
+    Ok(info)
+}
@@ -5,27 +5,125 @@
 //! 模块结构：
 //! - `types`: 数据类型定义
 //! - `utils`: 辅助函数
+//! - `error`: 结构化命令错误
 //! - `maa_core`: Maa 核心命令（初始化、设备搜索、控制器、资源、任务）
 //! - `maa_agent`: Agent 相关命令
+//! - `maa_event`: Maa 回调消息的类型化解析
+//! - `maa_ffi`: 阻塞 FFI 调用的超时封装
+//! - `adb_keep_awake`: 运行期间保持 ADB 设备亮屏
+//! - `agent_relay`: Agent TCP 转发（非回环地址监听 / 远程 Agent）
+//! - `agent_server`: Agent Server 模式（MXU 作为 Agent 对外提供内置动作）
 //! - `state`: 状态查询命令
 //! - `file_ops`: 文件操作命令
+//! - `antivirus`: 杀毒软件误报自诊断
+//! - `app_backup`: 通过 adb 备份 / 恢复目标应用数据
+//! - `audit_log`: 配置变更审计日志
+//! - `authoring`: 资源编写辅助（模板截取、取色、识别测试）
+//! - `cache`: 缓存清理
+//! - `capture_resolution`: 实例截图分辨率预设
+//! - `clock_drift`: 系统时钟偏差检测（NTP）
+//! - `config_crypto`: 配置与运行报告加密存储
+//! - `config_transfer`: 配置导入导出与旧版迁移
 //! - `update`: 更新安装相关命令
+//! - `device_health`: ADB 设备电量与温度监控
+//! - `elevation`: Win32 目标窗口权限检测
+//! - `failure_capture`: 任务失败截图
+//! - `file_lock`: 文件占用检测与重启后替换
+//! - `focus_log`: focus 运行日志
 //! - `download`: 下载相关命令
+//! - `gamepad`: Gamepad 控制器参数与测试输入
+//! - `input_recorder`: 输入录制与回放
+//! - `instance_tags`: 实例分组与标签
+//! - `integrity`: 程序文件完整性校验（签名清单）
+//! - `launch_attach`: 启动程序并自动连接 Win32 窗口
+//! - `long_path`: Windows 长路径检查
+//! - `maintenance`: 游戏维护时段（维护期间拒绝或延后启动任务）
+//! - `mirrorchyan`: MirrorChyan 更新源查询
+//! - `monitors`: 多显示器截图选择
+//! - `mqtt`: MQTT 状态发布
+//! - `node_runtime`: Node.js Agent 运行时
+//! - `path_compat`: 非 ASCII 路径兼容（短路径 / 目录联接映射）
+//! - `post_run`: 运行结束后的电源操作
+//! - `python_env`: Python Agent 虚拟环境
+//! - `reconnect`: 控制器幂等重连与断线看门狗
+//! - `results`: MXU_REPORT_RESULT 上报的结构化结果
+//! - `run_report`: 运行总结报告
+//! - `run_options`: 任务运行选项（试运行、运行速度、操作间隔）
+//! - `screenshot_history`: 运行中的截图历史
+//! - `server_reset`: 游戏服务器每日重置时间
+//! - `session`: 会话快照与启动恢复
+//! - `signature`: 发布签名校验（Ed25519）
+//! - `stats_export`: 运行统计导出（CSV / JSON）
+//! - `task_graph`: 任务依赖图
+//! - `task_timeout`: 任务超时看门狗
+//! - `variables`: 持久化变量存储（供 MXU 内置动作使用）
 //! - `system`: 系统相关命令
+//! - `win32_dpi`: Win32 窗口 DPI 检测
+//! - `self_check`: 启动自检
+//! - `trace`: 命令级追踪（关联 ID）
 //! - `tray`: 托盘相关命令
 
+pub mod error;
 pub mod types;
 pub mod utils;
 
+pub mod adb_keep_awake;
+pub mod agent_relay;
+pub mod agent_server;
+pub mod antivirus;
+pub mod app_backup;
 pub mod app_config;
+pub mod audit_log;
+pub mod authoring;
+pub mod cache;
+pub mod capture_resolution;
+pub mod clock_drift;
+pub mod config_crypto;
+pub mod config_transfer;
+pub mod device_health;
 pub mod download;
+pub mod elevation;
+pub mod failure_capture;
+pub mod file_lock;
 pub mod file_ops;
+pub mod focus_log;
+pub mod gamepad;
+pub mod input_recorder;
+pub mod instance_tags;
+pub mod integrity;
+pub mod launch_attach;
+pub mod long_path;
 pub mod maa_agent;
 pub mod maa_core;
+pub mod maa_event;
+pub mod maa_ffi;
+pub mod maintenance;
+pub mod mirrorchyan;
+pub mod monitors;
+pub mod mqtt;
+pub mod node_runtime;
+pub mod path_compat;
+pub mod post_run;
+pub mod python_env;
+pub mod reconnect;
+pub mod results;
+pub mod run_options;
+pub mod run_report;
+pub mod screenshot_history;
+pub mod self_check;
+pub mod server_reset;
+pub mod session;
+pub mod signature;
 pub mod state;
+pub mod stats_export;
 pub mod system;
+pub mod task_graph;
+pub mod task_timeout;
+pub mod trace;
 pub mod tray;
 pub mod update;
+pub mod variables;
+pub mod win32_dpi;
 
 // 重新导出类型（供 lib.rs 使用）
 pub use app_config::AppConfigState;
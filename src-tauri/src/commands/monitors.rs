@@ -0,0 +1,243 @@
+//! 多显示器截图选择
+//!
+//! DXGI 桌面复制（`DXGI_DESKTOP_DUP`）截取目标窗口所在的显示器输出。
+//! Win32 / Gamepad 控制器配置可通过 `monitor` 指定显示器设备名（如 `\\.\DISPLAY2`），
+//! 连接前若窗口不在该显示器上，会将窗口移动到该显示器的工作区内（保持窗口大小），
+//! 从而截取指定显示器而不是默认的主显示器。
+
+use log::warn;
+use serde::Serialize;
+
+use crate::i18n::tr;
+
+/// 显示器信息
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitorInfo {
+    /// 设备名（如 `\\.\DISPLAY1`），用于 `ControllerConfig` 的 `monitor` 字段
+    pub device_name: String,
+    /// 虚拟桌面坐标
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub primary: bool,
+}
+
+#[cfg(windows)]
+mod ffi {
+    use std::ffi::c_void;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Default)]
+    pub struct Rect {
+        pub left: i32,
+        pub top: i32,
+        pub right: i32,
+        pub bottom: i32,
+    }
+
+    #[repr(C)]
+    pub struct MonitorInfoExW {
+        pub cb_size: u32,
+        pub rc_monitor: Rect,
+        pub rc_work: Rect,
+        pub dw_flags: u32,
+        pub sz_device: [u16; 32],
+    }
+
+    pub const MONITORINFOF_PRIMARY: u32 = 1;
+    pub const MONITOR_DEFAULTTONEAREST: u32 = 2;
+    pub const SWP_NOSIZE: u32 = 0x0001;
+    pub const SWP_NOZORDER: u32 = 0x0004;
+    pub const SWP_NOACTIVATE: u32 = 0x0010;
+
+    pub type MonitorEnumProc =
+        unsafe extern "system" fn(*mut c_void, *mut c_void, *mut Rect, isize) -> i32;
+
+    extern "system" {
+        pub fn EnumDisplayMonitors(
+            hdc: *mut c_void,
+            clip: *const Rect,
+            callback: MonitorEnumProc,
+            data: isize,
+        ) -> i32;
+        pub fn GetMonitorInfoW(monitor: *mut c_void, info: *mut MonitorInfoExW) -> i32;
+        pub fn MonitorFromWindow(hwnd: *mut c_void, flags: u32) -> *mut c_void;
+        pub fn IsWindow(hwnd: *mut c_void) -> i32;
+        pub fn GetWindowRect(hwnd: *mut c_void, rect: *mut Rect) -> i32;
+        pub fn SetWindowPos(
+            hwnd: *mut c_void,
+            insert_after: *mut c_void,
+            x: i32,
+            y: i32,
+            cx: i32,
+            cy: i32,
+            flags: u32,
+        ) -> i32;
+    }
+
+    /// 读取显示器信息，返回（信息，工作区）
+    pub fn monitor_info(monitor: *mut c_void) -> Option<(super::MonitorInfo, Rect)> {
+        let mut info = MonitorInfoExW {
+            cb_size: std::mem::size_of::<MonitorInfoExW>() as u32,
+            rc_monitor: Rect::default(),
+            rc_work: Rect::default(),
+            dw_flags: 0,
+            sz_device: [0; 32],
+        };
+        // SAFETY: cb_size 已设置为 MONITORINFOEXW 的大小
+        if unsafe { GetMonitorInfoW(monitor, &mut info) } == 0 {
+            return None;
+        }
+        let len = info.sz_device.iter().position(|&c| c == 0).unwrap_or(32);
+        let rc = info.rc_monitor;
+        Some((
+            super::MonitorInfo {
+                device_name: String::from_utf16_lossy(&info.sz_device[..len]),
+                x: rc.left,
+                y: rc.top,
+                width: rc.right - rc.left,
+                height: rc.bottom - rc.top,
+                primary: info.dw_flags & MONITORINFOF_PRIMARY != 0,
+            },
+            info.rc_work,
+        ))
+    }
+
+    pub fn enum_monitors() -> Vec<(super::MonitorInfo, Rect)> {
+        unsafe extern "system" fn callback(
+            monitor: *mut c_void,
+            _hdc: *mut c_void,
+            _rect: *mut Rect,
+            data: isize,
+        ) -> i32 {
+            // SAFETY: data 为 enum_monitors 中传入的 Vec 指针，仅在枚举期间使用
+            let list = &mut *(data as *mut Vec<(super::MonitorInfo, Rect)>);
+            if let Some(entry) = monitor_info(monitor) {
+                list.push(entry);
+            }
+            1
+        }
+
+        let mut list: Vec<(super::MonitorInfo, Rect)> = Vec::new();
+        // SAFETY: 回调同步执行，list 在调用期间有效
+        unsafe {
+            EnumDisplayMonitors(
+                std::ptr::null_mut(),
+                std::ptr::null(),
+                callback,
+                &mut list as *mut _ as isize,
+            );
+        }
+        list
+    }
+}
+
+/// 枚举显示器（主显示器在前）
+#[cfg(windows)]
+pub fn list_monitors_impl() -> Result<Vec<MonitorInfo>, String> {
+    let mut monitors: Vec<MonitorInfo> = ffi::enum_monitors().into_iter().map(|(m, _)| m).collect();
+    if monitors.is_empty() {
+        return Err(tr!("error.monitor.none"));
+    }
+    monitors.sort_by_key(|m| !m.primary);
+    Ok(monitors)
+}
+
+#[cfg(not(windows))]
+pub fn list_monitors_impl() -> Result<Vec<MonitorInfo>, String> {
+    Err(tr!("error.system.windows_only"))
+}
+
+/// 将窗口移动到指定显示器（已在该显示器上时不做处理）
+#[cfg(windows)]
+pub fn move_window_to_monitor(handle: u64, device_name: &str) -> Result<(), String> {
+    use ffi::*;
+
+    let hwnd = handle as *mut std::ffi::c_void;
+    let monitors = enum_monitors();
+    let (target, work) = monitors
+        .iter()
+        .find(|(m, _)| m.device_name.eq_ignore_ascii_case(device_name))
+        .ok_or_else(|| tr!("error.monitor.not_found", name = device_name))?;
+
+    // SAFETY: 仅向 user32 传递窗口句柄与本地缓冲区
+    unsafe {
+        if IsWindow(hwnd) == 0 {
+            return Err(tr!("error.monitor.invalid_window"));
+        }
+        let current = monitor_info(MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST));
+        let Some((current, current_work)) = current else {
+            return Err(tr!("error.monitor.window_monitor"));
+        };
+        if current
+            .device_name
+            .eq_ignore_ascii_case(&target.device_name)
+        {
+            return Ok(());
+        }
+
+        let mut rect = Rect::default();
+        if GetWindowRect(hwnd, &mut rect) == 0 {
+            return Err(tr!("error.monitor.window_rect"));
+        }
+        let (w, h) = (rect.right - rect.left, rect.bottom - rect.top);
+        // 保持窗口在原显示器工作区中的相对位置，并限制在目标工作区内
+        let x = (work.left + rect.left - current_work.left)
+            .min(work.right - w)
+            .max(work.left);
+        let y = (work.top + rect.top - current_work.top)
+            .min(work.bottom - h)
+            .max(work.top);
+        if SetWindowPos(
+            hwnd,
+            std::ptr::null_mut(),
+            x,
+            y,
+            0,
+            0,
+            SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        ) == 0
+        {
+            return Err(tr!("error.monitor.move_failed"));
+        }
+        log::info!(
+            "Moved window {:#x} from {} to {} at ({}, {})",
+            handle,
+            current.device_name,
+            target.device_name,
+            x,
+            y
+        );
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+pub fn move_window_to_monitor(_handle: u64, _device_name: &str) -> Result<(), String> {
+    Err(tr!("error.system.windows_only"))
+}
+
+/// 连接前按控制器配置的 `monitor` 调整窗口所在显示器（失败时仅记录警告）
+pub fn apply_monitor(handle: u64, monitor: Option<&str>) {
+    let Some(monitor) = monitor.filter(|m| !m.is_empty()) else {
+        return;
+    };
+    if let Err(e) = move_window_to_monitor(handle, monitor) {
+        warn!(
+            "Failed to move window {:#x} to monitor {}: {}",
+            handle, monitor, e
+        );
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 枚举显示器（用于选择 DXGI 桌面复制截取的显示器）
+#[tauri::command]
+pub fn maa_list_monitors() -> Result<Vec<MonitorInfo>, String> {
+    list_monitors_impl()
+}
@@ -0,0 +1,300 @@
+//! MQTT 状态发布
+//!
+//! 将实例状态变更、任务完成和任务失败事件发布到 MQTT broker，
+//! 供 Home Assistant 等智能家居平台订阅（如任务完成后开灯提醒）。
+//!
+//! 主题（`{prefix}` 默认为 `mxu`）：
+//! - `{prefix}/availability`: `online` / `offline`（遗嘱消息，保留）
+//! - `{prefix}/{instance_id}/status`: 实例状态变更（保留）
+//! - `{prefix}/{instance_id}/task`: 单个任务完成（成功或失败）
+//! - `{prefix}/{instance_id}/error`: 任务失败
+
+use std::time::Duration;
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use rumqttc::{AsyncClient, Event, LastWill, MqttOptions, Packet, QoS};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+/// 默认主题前缀
+const DEFAULT_TOPIC_PREFIX: &str = "mxu";
+
+/// 连接断开后的重连间隔
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// MQTT 配置（对应 `settings.mqtt`）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttConfig {
+    pub host: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    /// 客户端 ID，为空时自动生成
+    #[serde(default)]
+    pub client_id: Option<String>,
+    /// 主题前缀，默认 `mxu`
+    #[serde(default)]
+    pub topic_prefix: Option<String>,
+    /// QoS 等级（0 / 1 / 2），默认 0
+    #[serde(default)]
+    pub qos: u8,
+}
+
+fn default_port() -> u16 {
+    1883
+}
+
+impl MqttConfig {
+    fn prefix(&self) -> &str {
+        self.topic_prefix
+            .as_deref()
+            .map(|p| p.trim_end_matches('/'))
+            .filter(|p| !p.is_empty())
+            .unwrap_or(DEFAULT_TOPIC_PREFIX)
+    }
+
+    fn qos(&self) -> QoS {
+        match self.qos {
+            1 => QoS::AtLeastOnce,
+            2 => QoS::ExactlyOnce,
+            _ => QoS::AtMostOnce,
+        }
+    }
+}
+
+/// MQTT 连接状态（供前端展示）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttStatus {
+    pub enabled: bool,
+    pub configured: bool,
+    pub connected: bool,
+    pub last_error: Option<String>,
+}
+
+/// 已启用的发布器
+struct Publisher {
+    client: AsyncClient,
+    config: MqttConfig,
+    event_loop: tauri::async_runtime::JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct MqttState {
+    config: Option<MqttConfig>,
+    publisher: Option<Publisher>,
+    connected: bool,
+    last_error: Option<String>,
+}
+
+static MQTT_STATE: Mutex<Option<MqttState>> = Mutex::new(None);
+
+fn with_state<R>(f: impl FnOnce(&mut MqttState) -> R) -> R {
+    let mut guard = MQTT_STATE.lock();
+    f(guard.get_or_insert_with(MqttState::default))
+}
+
+fn availability_topic(config: &MqttConfig) -> String {
+    format!("{}/availability", config.prefix())
+}
+
+/// 创建客户端并在后台驱动事件循环（rumqttc 在下一次 poll 时自动重连）
+fn start_publisher(config: MqttConfig) -> Result<Publisher, String> {
+    if config.host.trim().is_empty() {
+        return Err(tr!("error.mqtt.empty_host"));
+    }
+
+    let client_id = config
+        .client_id
+        .clone()
+        .filter(|id| !id.is_empty())
+        .unwrap_or_else(|| format!("mxu-{}", std::process::id()));
+    let mut options = MqttOptions::new(client_id, config.host.trim(), config.port);
+    options.set_keep_alive(Duration::from_secs(30));
+    if let Some(username) = config.username.as_ref().filter(|u| !u.is_empty()) {
+        options.set_credentials(username, config.password.clone().unwrap_or_default());
+    }
+    let availability = availability_topic(&config);
+    options.set_last_will(LastWill::new(
+        &availability,
+        "offline",
+        QoS::AtLeastOnce,
+        true,
+    ));
+
+    let (client, mut event_loop) = AsyncClient::new(options, 64);
+    let online_client = client.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        loop {
+            match event_loop.poll().await {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                    info!("MQTT connected");
+                    with_state(|s| {
+                        s.connected = true;
+                        s.last_error = None;
+                    });
+                    let _ =
+                        online_client.try_publish(&availability, QoS::AtLeastOnce, true, "online");
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    warn!("MQTT connection error: {}", e);
+                    with_state(|s| {
+                        s.connected = false;
+                        s.last_error = Some(e.to_string());
+                    });
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                }
+            }
+        }
+    });
+
+    Ok(Publisher {
+        client,
+        config,
+        event_loop: handle,
+    })
+}
+
+fn stop_publisher(publisher: Publisher) {
+    let availability = availability_topic(&publisher.config);
+    let _ = publisher
+        .client
+        .try_publish(availability, QoS::AtLeastOnce, true, "offline");
+    let _ = publisher.client.try_disconnect();
+    publisher.event_loop.abort();
+}
+
+/// 发布 JSON 消息；未启用 MQTT 时静默忽略
+fn publish(topic_suffix: &str, payload: serde_json::Value, retain: bool) {
+    with_state(|s| {
+        let Some(publisher) = &s.publisher else {
+            return;
+        };
+        let topic = format!("{}/{}", publisher.config.prefix(), topic_suffix);
+        if let Err(e) = publisher.client.try_publish(
+            &topic,
+            publisher.config.qos(),
+            retain,
+            payload.to_string(),
+        ) {
+            warn!("MQTT publish to {} failed: {}", topic, e);
+        }
+    });
+}
+
+/// 发布实例状态变更（由 `emit_state_changed` 调用）
+pub fn publish_state_changed(instance_id: &str, kind: &str, overall_status: Option<&str>) {
+    publish(
+        &format!("{}/status", instance_id),
+        serde_json::json!({
+            "instanceId": instance_id,
+            "kind": kind,
+            "overallStatus": overall_status,
+            "timestamp": chrono::Local::now().to_rfc3339(),
+        }),
+        true,
+    );
+}
+
+/// 发布单个任务完成事件，失败时额外发布到 error 主题
+pub fn publish_task_finished(instance_id: &str, task_id: i64, entry: Option<&str>, ok: bool) {
+    let payload = serde_json::json!({
+        "instanceId": instance_id,
+        "taskId": task_id,
+        "entry": entry,
+        "status": if ok { "succeeded" } else { "failed" },
+        "timestamp": chrono::Local::now().to_rfc3339(),
+    });
+    if !ok {
+        publish(&format!("{}/error", instance_id), payload.clone(), false);
+    }
+    publish(&format!("{}/task", instance_id), payload, false);
+}
+
+/// 从 `settings.mqtt` 读取配置，`enabled` 为 true 时启动发布（应用启动时调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let Some(mqtt) = config.get("settings").and_then(|s| s.get("mqtt")) else {
+        return;
+    };
+    let enabled = mqtt
+        .get("enabled")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let parsed = match serde_json::from_value::<MqttConfig>(mqtt.clone()) {
+        Ok(c) => c,
+        Err(e) => {
+            warn!("Invalid MQTT settings: {}", e);
+            return;
+        }
+    };
+
+    with_state(|s| s.config = Some(parsed.clone()));
+    if enabled {
+        if let Err(e) = set_enabled(true) {
+            warn!("Failed to enable MQTT on startup: {}", e);
+        }
+    }
+}
+
+fn set_enabled(enabled: bool) -> Result<(), String> {
+    with_state(|s| {
+        if let Some(publisher) = s.publisher.take() {
+            stop_publisher(publisher);
+        }
+        s.connected = false;
+        if !enabled {
+            return Ok(());
+        }
+        let config = s
+            .config
+            .clone()
+            .ok_or_else(|| tr!("error.mqtt.not_configured"))?;
+        info!(
+            "MQTT enabling: {}:{} (prefix: {})",
+            config.host,
+            config.port,
+            config.prefix()
+        );
+        s.publisher = Some(start_publisher(config)?);
+        Ok(())
+    })
+}
+
+/// 设置 MQTT 配置；若已启用则使用新配置重新连接
+#[tauri::command]
+pub fn mqtt_configure(config: MqttConfig) -> Result<(), String> {
+    let was_enabled = with_state(|s| {
+        s.config = Some(config);
+        s.publisher.is_some()
+    });
+    if was_enabled {
+        set_enabled(true)?;
+    }
+    Ok(())
+}
+
+/// 启用或停用 MQTT 发布
+#[tauri::command]
+pub fn mqtt_enable(enabled: bool) -> Result<(), String> {
+    info!("mqtt_enable called: {}", enabled);
+    set_enabled(enabled)
+}
+
+/// 获取 MQTT 连接状态
+#[tauri::command]
+pub fn mqtt_get_status() -> MqttStatus {
+    with_state(|s| MqttStatus {
+        enabled: s.publisher.is_some(),
+        configured: s.config.is_some(),
+        connected: s.publisher.is_some() && s.connected,
+        last_error: s.last_error.clone(),
+    })
+}
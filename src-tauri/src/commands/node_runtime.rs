@@ -0,0 +1,254 @@
+//! Node.js Agent 运行时
+//!
+//! `child_exec` 为 `.js/.mjs/.cjs/.ts/.mts/.cts` 脚本时，以 Node.js 运行该脚本：
+//! 依次查找资源目录内置的 `node/`、已下载的运行时与 PATH 中的 `node`，
+//! 均未找到时从 nodejs.org 下载官方二进制包到 `<数据目录>/cache/node_runtime`，
+//! 解压前按同一发行版的 `SHASUMS256.txt` 校验 SHA-256。
+//!
+//! TypeScript 脚本通过 `--experimental-strip-types` 直接运行，该参数需要 Node.js 22.6 及以上；
+//! 找到的 node 版本过低时改用自动下载的运行时。
+//! 与 Python Agent 一致，socket_id 作为最后一个命令行参数传入（`process.argv.at(-1)`）。
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use log::{info, warn};
+
+use super::utils::{build_user_agent, get_app_data_dir};
+use crate::i18n::tr;
+
+/// 自动下载的 Node.js 版本（需支持 `--experimental-strip-types`）
+const NODE_VERSION: &str = "22.12.0";
+
+/// 支持 `--experimental-strip-types` 的最低 Node.js 版本
+const MIN_STRIP_TYPES_VERSION: semver::Version = semver::Version::new(22, 6, 0);
+
+const SCRIPT_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "ts", "mts", "cts"];
+const TS_EXTENSIONS: &[&str] = &["ts", "mts", "cts"];
+
+fn extension_of(path: &str) -> Option<String> {
+    Path::new(path)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+}
+
+/// child_exec 是否为需要 Node.js 运行的脚本
+pub(crate) fn is_node_script(child_exec: &str) -> bool {
+    extension_of(child_exec).is_some_and(|ext| SCRIPT_EXTENSIONS.contains(&ext.as_str()))
+}
+
+fn node_exe_name() -> &'static str {
+    if cfg!(windows) {
+        "node.exe"
+    } else {
+        "node"
+    }
+}
+
+/// nodejs.org 发行包名称（不含扩展名）与扩展名
+fn dist_name() -> Result<(String, &'static str), String> {
+    let os = match std::env::consts::OS {
+        "windows" => "win",
+        "macos" => "darwin",
+        "linux" => "linux",
+        other => return Err(tr!("error.node.unsupported_os", os = other)),
+    };
+    let arch = match std::env::consts::ARCH {
+        "x86_64" => "x64",
+        "aarch64" => "arm64",
+        other => return Err(tr!("error.node.unsupported_arch", arch = other)),
+    };
+    let ext = if cfg!(windows) { "zip" } else { "tar.gz" };
+    Ok((format!("node-v{}-{}-{}", NODE_VERSION, os, arch), ext))
+}
+
+fn runtime_dir() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join("cache").join("node_runtime"))
+}
+
+/// 已下载运行时中 node 可执行文件的路径
+fn downloaded_node_path() -> Result<PathBuf, String> {
+    let (name, _) = dist_name()?;
+    let dir = runtime_dir()?.join(name);
+    Ok(if cfg!(windows) {
+        dir.join(node_exe_name())
+    } else {
+        dir.join("bin").join(node_exe_name())
+    })
+}
+
+fn find_node(cwd: &str) -> Option<PathBuf> {
+    let bundled = Path::new(cwd).join("node").join(node_exe_name());
+    if bundled.is_file() {
+        return Some(bundled);
+    }
+    if let Some(downloaded) = downloaded_node_path().ok().filter(|p| p.is_file()) {
+        return Some(downloaded);
+    }
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(node_exe_name()))
+        .find(|p| p.is_file())
+}
+
+/// 执行 `node --version` 获取版本号，失败时返回 None
+fn node_version(node: &Path) -> Option<semver::Version> {
+    let mut cmd = std::process::Command::new(node);
+    cmd.arg("--version");
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = cmd.output().ok().filter(|o| o.status.success())?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    semver::Version::parse(text.trim().trim_start_matches('v')).ok()
+}
+
+/// 从发行版的 SHASUMS256.txt 中查找文件的 SHA-256
+fn expected_sha256(
+    client: &reqwest::blocking::Client,
+    base_url: &str,
+    file_name: &str,
+) -> Result<String, String> {
+    let url = format!("{}/SHASUMS256.txt", base_url);
+    let response = client
+        .get(&url)
+        .send()
+        .map_err(|e| tr!("error.node.checksum_download", error = e))?;
+    if !response.status().is_success() {
+        return Err(tr!(
+            "error.node.checksum_download",
+            error = format!("HTTP {}", response.status())
+        ));
+    }
+    let text = response
+        .text()
+        .map_err(|e| tr!("error.node.checksum_download", error = e))?;
+    text.lines()
+        .filter_map(|line| line.split_once(char::is_whitespace))
+        .find(|(_, name)| name.trim() == file_name)
+        .map(|(hash, _)| hash.to_lowercase())
+        .ok_or_else(|| tr!("error.node.checksum_missing", file = file_name))
+}
+
+/// 下载并解压 Node.js 官方二进制包，返回 node 可执行文件路径
+///
+/// 阻塞调用，只在 Agent 启动的 `spawn_blocking` 线程中执行。
+fn download_node(log_line: &dyn Fn(&str)) -> Result<PathBuf, String> {
+    use sha2::{Digest, Sha256};
+
+    let (name, ext) = dist_name()?;
+    let base_url = format!("https://nodejs.org/dist/v{}", NODE_VERSION);
+    let file_name = format!("{}.{}", name, ext);
+    let url = format!("{}/{}", base_url, file_name);
+    let dir = runtime_dir()?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| tr!("error.fs.create_dir", path = dir.display(), error = e))?;
+    let archive = dir.join(&file_name);
+
+    log_line(&format!("正在下载 Node.js v{}: {}", NODE_VERSION, url));
+    info!("[node_runtime] Downloading {}", url);
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(build_user_agent())
+        .connect_timeout(std::time::Duration::from_secs(30))
+        .timeout(std::time::Duration::from_secs(600))
+        .build()
+        .map_err(|e| tr!("error.download.client", error = e))?;
+    let expected = expected_sha256(&client, &base_url, &file_name)?;
+    let mut response = client
+        .get(&url)
+        .send()
+        .map_err(|e| tr!("error.node.download", error = e))?;
+    if !response.status().is_success() {
+        return Err(tr!(
+            "error.node.download",
+            error = format!("HTTP {}", response.status())
+        ));
+    }
+    let mut data = Vec::new();
+    response
+        .read_to_end(&mut data)
+        .map_err(|e| tr!("error.node.download", error = e))?;
+    let actual = format!("{:x}", Sha256::digest(&data));
+    if actual != expected {
+        return Err(tr!(
+            "error.node.hash_mismatch",
+            expected = expected,
+            actual = actual
+        ));
+    }
+    std::fs::write(&archive, &data)
+        .map_err(|e| tr!("error.fs.write_file", path = archive.display(), error = e))?;
+
+    log_line("正在解压 Node.js");
+    let extracted = super::update::extract_zip(
+        archive.to_string_lossy().to_string(),
+        dir.to_string_lossy().to_string(),
+    );
+    let _ = std::fs::remove_file(&archive);
+    extracted?;
+
+    let node = downloaded_node_path()?;
+    if node.is_file() {
+        log_line(&format!("Node.js 已安装: {}", node.display()));
+        Ok(node)
+    } else {
+        Err(tr!(
+            "error.node.missing_after_extract",
+            path = node.display()
+        ))
+    }
+}
+
+/// 为脚本型 child_exec 准备 Node.js 运行时
+///
+/// 返回 node 可执行文件路径与需要放在脚本参数之前的参数（含脚本路径）。
+/// `log_line` 用于向 Agent 输出面板报告下载进度。需要下载时会阻塞，调用方须在
+/// 阻塞线程中执行。
+pub(crate) fn prepare_node_command(
+    script: &Path,
+    cwd: &str,
+    log_line: &dyn Fn(&str),
+) -> Result<(PathBuf, Vec<String>), String> {
+    let script_str = script.to_string_lossy().to_string();
+    let is_ts = extension_of(&script_str).is_some_and(|ext| TS_EXTENSIONS.contains(&ext.as_str()));
+
+    let node = match find_node(cwd) {
+        // 自动下载的运行时版本固定为 NODE_VERSION，无需再检查
+        Some(node) if is_ts && downloaded_node_path().ok().as_ref() != Some(&node) => {
+            match node_version(&node) {
+                Some(version) if version >= MIN_STRIP_TYPES_VERSION => node,
+                version => {
+                    let version = version.map_or_else(|| "?".to_string(), |v| v.to_string());
+                    warn!(
+                        "[node_runtime] {} (v{}) cannot run TypeScript directly, using Node.js v{}",
+                        node.display(),
+                        version,
+                        NODE_VERSION
+                    );
+                    log_line(&format!(
+                        "Node.js v{} 不支持直接运行 TypeScript（需要 v{} 及以上），改用 Node.js v{}",
+                        version, MIN_STRIP_TYPES_VERSION, NODE_VERSION
+                    ));
+                    match downloaded_node_path().ok().filter(|p| p.is_file()) {
+                        Some(downloaded) => downloaded,
+                        None => download_node(log_line)?,
+                    }
+                }
+            }
+        }
+        Some(node) => node,
+        None => download_node(log_line)?,
+    };
+
+    let mut prefix = Vec::new();
+    if is_ts {
+        prefix.push("--experimental-strip-types".to_string());
+    }
+    prefix.push(script_str);
+    Ok((node, prefix))
+}
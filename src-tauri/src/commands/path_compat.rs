@@ -0,0 +1,220 @@
+//! 非 ASCII 路径兼容
+//!
+//! MaaFramework 及部分 OCR / 推理依赖在 Windows 下以 ANSI 代码页处理路径，
+//! MXU 位于含中文等非 ASCII 字符的目录（如 `D:\游戏工具\MXU`）时资源加载会失败。
+//! 传给 MaaFramework 的路径含非 ASCII 字符时，依次尝试：
+//! 1. 8.3 短路径（`GetShortPathNameW`，卷未禁用短文件名时可用）
+//! 2. 在 `%ProgramData%\MXU\links` 下创建指向原目录的目录联接（junction，无需管理员权限）
+//!
+//! 映射结果仅用于传给 MaaFramework，界面与配置中仍显示原路径。
+//! 可通过 `settings.asciiPathMapping = false` 关闭。
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use super::utils::get_exe_directory;
+use crate::i18n::tr;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+/// 原路径 -> 映射后的 ASCII 路径
+static MAPPINGS: Mutex<Option<HashMap<PathBuf, PathBuf>>> = Mutex::new(None);
+
+/// 一条路径映射
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathMapping {
+    pub original: String,
+    pub mapped: String,
+}
+
+/// 路径兼容状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathCompatStatus {
+    pub exe_dir: String,
+    /// 安装目录是否含非 ASCII 字符
+    pub non_ascii: bool,
+    /// 是否启用映射
+    pub enabled: bool,
+    pub mappings: Vec<PathMapping>,
+}
+
+/// 读取 `settings.asciiPathMapping`（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    ENABLED.store(
+        config
+            .get("settings")
+            .and_then(|s| s.get("asciiPathMapping"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+        Ordering::Relaxed,
+    );
+}
+
+fn is_ascii(path: &Path) -> bool {
+    path.to_str().is_some_and(str::is_ascii)
+}
+
+/// 启动时检查安装目录，含非 ASCII 字符时记录日志
+pub fn detect() {
+    let Ok(exe_dir) = get_exe_directory() else {
+        return;
+    };
+    if cfg!(windows) && !is_ascii(&exe_dir) {
+        warn!(
+            "Install directory contains non-ASCII characters ({}), resource paths will be mapped: {}",
+            exe_dir.display(),
+            ENABLED.load(Ordering::Relaxed)
+        );
+    }
+}
+
+#[cfg(windows)]
+fn short_path(path: &Path) -> Option<PathBuf> {
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetShortPathNameW(long: *const u16, short: *mut u16, len: u32) -> u32;
+    }
+
+    let wide: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut buf = vec![0u16; 1024];
+    // SAFETY: wide 以 NUL 结尾，buf 长度与传入的 len 一致
+    let len = unsafe { GetShortPathNameW(wide.as_ptr(), buf.as_mut_ptr(), buf.len() as u32) };
+    if len == 0 || len as usize >= buf.len() {
+        return None;
+    }
+    let short = PathBuf::from(std::ffi::OsString::from_wide(&buf[..len as usize]));
+    is_ascii(&short).then_some(short)
+}
+
+/// 在 `%ProgramData%\MXU\links` 下创建指向 `dir` 的目录联接
+#[cfg(windows)]
+fn junction(dir: &Path) -> Result<PathBuf, String> {
+    use sha2::{Digest, Sha256};
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let base = std::env::var_os("ProgramData")
+        .map(PathBuf::from)
+        .filter(|p| is_ascii(p))
+        .ok_or_else(|| tr!("error.path_compat.program_data"))?
+        .join("MXU")
+        .join("links");
+    let digest = Sha256::digest(dir.to_string_lossy().to_lowercase().as_bytes());
+    let name: String = digest
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let link = base.join(name);
+
+    if link.exists() {
+        return Ok(link);
+    }
+    // 原目录移动后残留的失效联接
+    if std::fs::symlink_metadata(&link).is_ok() {
+        let _ = std::fs::remove_dir(&link);
+    }
+    std::fs::create_dir_all(&base)
+        .map_err(|e| tr!("error.fs.create_dir", path = base.display(), error = e))?;
+    let output = std::process::Command::new("cmd")
+        .arg("/C")
+        .arg("mklink")
+        .arg("/J")
+        .arg(&link)
+        .arg(dir)
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|e| tr!("error.path_compat.junction", error = e))?;
+    if !output.status.success() || !link.exists() {
+        return Err(tr!(
+            "error.path_compat.junction",
+            error = String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(link)
+}
+
+#[cfg(windows)]
+fn map_dir(dir: &Path) -> Option<PathBuf> {
+    if let Some(short) = short_path(dir) {
+        return Some(short);
+    }
+    match junction(dir) {
+        Ok(link) => Some(link),
+        Err(e) => {
+            warn!("Failed to map non-ASCII path {}: {}", dir.display(), e);
+            None
+        }
+    }
+}
+
+#[cfg(not(windows))]
+fn map_dir(_dir: &Path) -> Option<PathBuf> {
+    None
+}
+
+/// 返回可安全传给 MaaFramework 的路径（ASCII 路径或无法映射时原样返回）
+///
+/// 目录整体映射；文件则映射其所在目录，文件名本身含非 ASCII 字符时无法处理。
+pub fn compat_path(path: &Path) -> PathBuf {
+    if !cfg!(windows) || !ENABLED.load(Ordering::Relaxed) || is_ascii(path) {
+        return path.to_path_buf();
+    }
+    if let Some(mapped) = MAPPINGS.lock().as_ref().and_then(|m| m.get(path).cloned()) {
+        return mapped;
+    }
+
+    let mapped = if path.is_dir() {
+        map_dir(path)
+    } else {
+        path.parent()
+            .zip(path.file_name())
+            .and_then(|(parent, name)| Some(map_dir(parent)?.join(name)))
+    };
+    let Some(mapped) = mapped else {
+        return path.to_path_buf();
+    };
+    info!("Mapped {} -> {}", path.display(), mapped.display());
+    MAPPINGS
+        .lock()
+        .get_or_insert_with(HashMap::new)
+        .insert(path.to_path_buf(), mapped.clone());
+    mapped
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取非 ASCII 路径兼容状态与已建立的映射
+#[tauri::command]
+pub fn mxu_get_path_compat() -> Result<PathCompatStatus, String> {
+    let exe_dir = get_exe_directory()?;
+    let mappings = MAPPINGS
+        .lock()
+        .iter()
+        .flatten()
+        .map(|(original, mapped)| PathMapping {
+            original: original.display().to_string(),
+            mapped: mapped.display().to_string(),
+        })
+        .collect();
+    Ok(PathCompatStatus {
+        non_ascii: !is_ascii(&exe_dir),
+        exe_dir: exe_dir.display().to_string(),
+        enabled: ENABLED.load(Ordering::Relaxed),
+        mappings,
+    })
+}
@@ -0,0 +1,232 @@
+//! 运行结束后的电源操作
+//!
+//! 比 pipeline 中的 MXU_POWER 更上一层：由后端在所有选定实例的任务全部结束后
+//! 执行关机 / 重启 / 睡眠 / 休眠 / 退出 MXU，执行前有可取消的倒计时。
+//!
+//! 倒计时期间每秒发射 `post-run-countdown` 事件，取消或执行后发射 `post-run-finished`。
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use crate::i18n::tr;
+
+/// 默认倒计时秒数
+const DEFAULT_DELAY_SECS: u64 = 60;
+
+const VALID_ACTIONS: &[&str] = &["shutdown", "restart", "sleep", "hibernate", "exit"];
+
+struct PostRunPlan {
+    action: String,
+    delay_secs: u64,
+    /// 尚未结束的实例
+    pending: HashSet<String>,
+    /// 已正常完成的实例数（全部被手动停止时不执行）
+    completed: usize,
+}
+
+static PLAN: Mutex<Option<PostRunPlan>> = Mutex::new(None);
+
+/// 当前倒计时的取消标记
+static COUNTDOWN_CANCEL: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+
+/// 运行结束后操作的当前状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostRunActionInfo {
+    pub action: String,
+    pub delay_secs: u64,
+    pub pending_instances: Vec<String>,
+    /// 是否正在倒计时
+    pub counting_down: bool,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostRunCountdownEvent {
+    action: String,
+    remaining_secs: u64,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostRunFinishedEvent {
+    action: String,
+    /// 是否已执行（false 表示被取消）
+    executed: bool,
+}
+
+fn current_info() -> Option<PostRunActionInfo> {
+    let counting_down = COUNTDOWN_CANCEL.lock().is_some();
+    let plan = PLAN.lock();
+    let plan = plan.as_ref()?;
+    let mut pending_instances: Vec<String> = plan.pending.iter().cloned().collect();
+    pending_instances.sort();
+    Some(PostRunActionInfo {
+        action: plan.action.clone(),
+        delay_secs: plan.delay_secs,
+        pending_instances,
+        counting_down,
+    })
+}
+
+/// 实例状态变更时调用（由 `emit_state_changed` 转发）
+///
+/// `tasks-completed` 视为正常完成，`task-stopped` 视为手动停止；
+/// 所有计划内实例结束且至少一个正常完成时开始倒计时。
+pub fn on_state_changed(app: &AppHandle, instance_id: &str, kind: &str) {
+    let completed = match kind {
+        "tasks-completed" => true,
+        "task-stopped" => false,
+        _ => return,
+    };
+
+    let ready = {
+        let mut guard = PLAN.lock();
+        let Some(plan) = guard.as_mut() else {
+            return;
+        };
+        if !plan.pending.remove(instance_id) {
+            return;
+        }
+        if completed {
+            plan.completed += 1;
+        }
+        if !plan.pending.is_empty() {
+            return;
+        }
+        let plan = guard.take().unwrap();
+        if plan.completed == 0 {
+            info!(
+                "Post-run action {} skipped: all instances were stopped manually",
+                plan.action
+            );
+            return;
+        }
+        plan
+    };
+
+    start_countdown(app.clone(), ready.action, ready.delay_secs);
+}
+
+fn start_countdown(app: AppHandle, action: String, delay_secs: u64) {
+    let cancel = Arc::new(AtomicBool::new(false));
+    if let Some(prev) = COUNTDOWN_CANCEL.lock().replace(cancel.clone()) {
+        prev.store(true, Ordering::SeqCst);
+    }
+    info!("Post-run action {} in {}s", action, delay_secs);
+
+    std::thread::spawn(move || {
+        for remaining_secs in (1..=delay_secs).rev() {
+            if cancel.load(Ordering::SeqCst) {
+                break;
+            }
+            let _ = app.emit(
+                "post-run-countdown",
+                PostRunCountdownEvent {
+                    action: action.clone(),
+                    remaining_secs,
+                },
+            );
+            std::thread::sleep(Duration::from_secs(1));
+        }
+
+        let executed = !cancel.load(Ordering::SeqCst);
+        {
+            let mut guard = COUNTDOWN_CANCEL.lock();
+            if guard.as_ref().is_some_and(|c| Arc::ptr_eq(c, &cancel)) {
+                *guard = None;
+            }
+        }
+        let _ = app.emit(
+            "post-run-finished",
+            PostRunFinishedEvent {
+                action: action.clone(),
+                executed,
+            },
+        );
+        if !executed {
+            info!("Post-run action {} cancelled", action);
+            return;
+        }
+
+        info!("Executing post-run action: {}", action);
+        match action.as_str() {
+            "shutdown" => {
+                crate::mxu_actions::execute_power_shutdown();
+            }
+            "restart" => {
+                crate::mxu_actions::execute_power_restart();
+            }
+            "sleep" => {
+                crate::mxu_actions::execute_power_sleep();
+            }
+            "hibernate" => {
+                crate::mxu_actions::execute_power_hibernate();
+            }
+            "exit" => app.exit(0),
+            _ => warn!("Unknown post-run action: {}", action),
+        }
+    });
+}
+
+/// 为实例设置运行结束后的电源操作
+///
+/// 多次调用（不同实例）会合并为同一个计划，动作与延迟以最后一次为准；
+/// `action` 为 `none` 时将该实例移出计划。
+/// 可选动作：shutdown / restart / sleep / hibernate / exit
+#[tauri::command]
+pub fn mxu_set_post_run_action(
+    instance_id: String,
+    action: String,
+    delay_secs: Option<u64>,
+) -> Result<Option<PostRunActionInfo>, String> {
+    {
+        let mut guard = PLAN.lock();
+        if action == "none" {
+            if let Some(plan) = guard.as_mut() {
+                plan.pending.remove(&instance_id);
+                if plan.pending.is_empty() {
+                    *guard = None;
+                }
+            }
+        } else {
+            if !VALID_ACTIONS.contains(&action.as_str()) {
+                return Err(tr!("error.post_run.unsupported_action", action = action));
+            }
+            let delay_secs = delay_secs.unwrap_or(DEFAULT_DELAY_SECS);
+            let plan = guard.get_or_insert_with(|| PostRunPlan {
+                action: action.clone(),
+                delay_secs,
+                pending: HashSet::new(),
+                completed: 0,
+            });
+            plan.action = action;
+            plan.delay_secs = delay_secs;
+            plan.pending.insert(instance_id);
+        }
+    }
+    Ok(current_info())
+}
+
+/// 获取当前运行结束后操作计划
+#[tauri::command]
+pub fn mxu_get_post_run_action() -> Option<PostRunActionInfo> {
+    current_info()
+}
+
+/// 取消运行结束后操作（清空计划并中止正在进行的倒计时）
+#[tauri::command]
+pub fn mxu_cancel_post_run_action() -> Result<(), String> {
+    PLAN.lock().take();
+    if let Some(cancel) = COUNTDOWN_CANCEL.lock().take() {
+        cancel.store(true, Ordering::SeqCst);
+    }
+    Ok(())
+}
@@ -0,0 +1,242 @@
+//! Python Agent 虚拟环境
+//!
+//! `agent_setup_python_env` 在资源目录下创建（或更新）`.venv` 虚拟环境并用 pip 安装
+//! requirements，安装输出通过 `python-env-progress` 事件实时推送。
+//! requirements 未变化时跳过安装。
+//!
+//! 环境就绪后，启动 Agent 时 `child_exec` 为 python 解释器（`python`、`python3`、
+//! `./python/python.exe` 等）的配置会被改写为虚拟环境中的解释器。
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use log::{info, warn};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Emitter};
+
+use super::utils::normalize_path;
+use crate::i18n::tr;
+
+/// 虚拟环境目录名（位于资源目录下）
+const VENV_DIR: &str = ".venv";
+
+/// 记录已安装 requirements 哈希的文件（位于虚拟环境目录下），同时标记该环境由 MXU 创建
+const REQUIREMENTS_STAMP: &str = "mxu-requirements.sha256";
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PythonEnvProgressEvent {
+    /// venv / pip / done
+    stage: &'static str,
+    line: String,
+}
+
+/// 虚拟环境设置结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PythonEnvResult {
+    /// 虚拟环境中的解释器路径
+    pub python_path: String,
+    /// 是否执行了 pip 安装（requirements 未变化时为 false）
+    pub installed: bool,
+}
+
+fn emit_progress(app: &AppHandle, stage: &'static str, line: impl Into<String>) {
+    let event = PythonEnvProgressEvent {
+        stage,
+        line: line.into(),
+    };
+    if let Err(e) = app.emit("python-env-progress", event) {
+        warn!("Failed to emit python-env-progress: {}", e);
+    }
+}
+
+fn venv_dir(cwd: &str) -> PathBuf {
+    Path::new(cwd).join(VENV_DIR)
+}
+
+/// 虚拟环境中的解释器路径
+fn venv_python_path(venv: &Path) -> PathBuf {
+    if cfg!(windows) {
+        venv.join("Scripts").join("python.exe")
+    } else {
+        venv.join("bin").join("python")
+    }
+}
+
+/// child_exec 是否指向 python 解释器
+fn is_python_exec(child_exec: &str) -> bool {
+    Path::new(child_exec)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_lowercase())
+        .is_some_and(|stem| matches!(stem.as_str(), "python" | "python3" | "pythonw" | "py"))
+}
+
+/// 资源目录下存在 MXU 创建的虚拟环境时，返回替换 child_exec 的解释器路径
+pub(crate) fn venv_exec_override(child_exec: &str, cwd: &str) -> Option<PathBuf> {
+    if !is_python_exec(child_exec) {
+        return None;
+    }
+    let venv = venv_dir(cwd);
+    let python = venv_python_path(&venv);
+    (venv.join(REQUIREMENTS_STAMP).is_file() && python.is_file()).then_some(python)
+}
+
+/// 查找创建虚拟环境使用的基础解释器：优先资源目录内置的 python，其次 PATH
+fn find_base_python(cwd: &str) -> Option<PathBuf> {
+    let exe_name = if cfg!(windows) {
+        "python.exe"
+    } else {
+        "python3"
+    };
+    let bundled = Path::new(cwd).join("python").join(exe_name);
+    if bundled.is_file() {
+        return Some(bundled);
+    }
+    let paths = std::env::var_os("PATH")?;
+    let candidates: &[&str] = if cfg!(windows) {
+        &["python.exe"]
+    } else {
+        &["python3", "python"]
+    };
+    candidates.iter().find_map(|name| {
+        std::env::split_paths(&paths)
+            .map(|dir| dir.join(name))
+            .find(|p| p.is_file())
+    })
+}
+
+fn new_command(program: &Path) -> Command {
+    #[cfg(windows)]
+    let cmd = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        let mut c = Command::new(program);
+        c.creation_flags(CREATE_NO_WINDOW);
+        c
+    };
+    #[cfg(not(windows))]
+    let cmd = Command::new(program);
+    cmd
+}
+
+/// 运行命令并逐行推送 stdout/stderr，非零退出时返回错误
+fn run_streaming(app: &AppHandle, stage: &'static str, mut cmd: Command) -> Result<(), String> {
+    let mut child = cmd
+        .env("PYTHONIOENCODING", "utf-8")
+        .env("PYTHONUTF8", "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| tr!("error.python.spawn", error = e))?;
+
+    let stderr_thread = child.stderr.take().map(|stderr| {
+        let app = app.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                emit_progress(&app, stage, line);
+            }
+        })
+    });
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            emit_progress(app, stage, line);
+        }
+    }
+    if let Some(handle) = stderr_thread {
+        let _ = handle.join();
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| tr!("error.python.wait", error = e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(tr!("error.python.exit", status = status))
+    }
+}
+
+fn setup_python_env(
+    app: &AppHandle,
+    cwd: &str,
+    requirements_path: &str,
+    python_path: Option<&str>,
+) -> Result<PythonEnvResult, String> {
+    let requirements = normalize_path(&Path::new(cwd).join(requirements_path).to_string_lossy());
+    let content = std::fs::read(&requirements).map_err(|e| {
+        tr!(
+            "error.fs.read_file",
+            path = requirements.display(),
+            error = e
+        )
+    })?;
+    let hash = format!("{:x}", Sha256::digest(&content));
+
+    let venv = venv_dir(cwd);
+    let python = venv_python_path(&venv);
+    if !python.is_file() {
+        let base = match python_path.filter(|p| !p.trim().is_empty()) {
+            Some(p) => super::maa_agent::resolve_child_exec_path(p.trim(), cwd),
+            None => find_base_python(cwd).ok_or_else(|| tr!("error.python.not_found"))?,
+        };
+        info!("[python_env] Creating venv {:?} with {:?}", venv, base);
+        emit_progress(
+            app,
+            "venv",
+            format!("{} -m venv {}", base.display(), venv.display()),
+        );
+        let mut cmd = new_command(&base);
+        cmd.arg("-m").arg("venv").arg(&venv).current_dir(cwd);
+        run_streaming(app, "venv", cmd).map_err(|e| tr!("error.python.venv_failed", error = e))?;
+        if !python.is_file() {
+            return Err(tr!("error.python.venv_no_python", path = python.display()));
+        }
+    }
+
+    let stamp = venv.join(REQUIREMENTS_STAMP);
+    let installed_hash = std::fs::read_to_string(&stamp).unwrap_or_default();
+    let installed = installed_hash.trim() != hash;
+    if installed {
+        info!("[python_env] Installing {:?}", requirements);
+        let mut cmd = new_command(&python);
+        cmd.args(["-m", "pip", "install", "--disable-pip-version-check", "-r"])
+            .arg(&requirements)
+            .current_dir(cwd);
+        run_streaming(app, "pip", cmd).map_err(|e| tr!("error.python.pip_failed", error = e))?;
+        std::fs::write(&stamp, &hash)
+            .map_err(|e| tr!("error.fs.write_file", path = stamp.display(), error = e))?;
+    }
+
+    let python_path = python.to_string_lossy().to_string();
+    emit_progress(app, "done", python_path.clone());
+    Ok(PythonEnvResult {
+        python_path,
+        installed,
+    })
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 在资源目录下创建/更新 Python 虚拟环境并安装 requirements
+///
+/// - `requirements_path`、`python_path` 为相对路径时基于资源目录 `cwd` 解析
+/// - 未指定 `python_path` 时依次查找资源目录内置的 `python/` 与 PATH
+#[tauri::command]
+pub async fn agent_setup_python_env(
+    app: AppHandle,
+    cwd: String,
+    requirements_path: String,
+    python_path: Option<String>,
+) -> Result<PythonEnvResult, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        setup_python_env(&app, &cwd, &requirements_path, python_path.as_deref())
+    })
+    .await
+    .map_err(|e| tr!("error.task_panicked", error = e))?
+}
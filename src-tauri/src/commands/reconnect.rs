@@ -0,0 +1,507 @@
+//! 控制器重连与断线看门狗
+//!
+//! - `maa_reconnect_controller`：幂等重连，已连接时直接返回，否则在原控制器上重新发起连接
+//! - 看门狗：定期检查运行中实例的控制器连接状态，任务进行中断线（模拟器重启、adb 抖动等）
+//!   时自动重连，并可选择重新提交尚未完成的任务
+//!
+//! 看门狗由 `settings.reconnectWatchdog` 配置，重连过程通过 `controller-reconnect` 事件通知前端。
+//!
+//! `maa_switch_adb_methods` 以新的截图 / 输入方式（如从 Minitouch/MaaTouch 切到 adb shell）
+//! 重建 ADB 控制器，不修改实例配置，适合临时排查设备兼容性问题。
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use maa_framework::MaaStatus;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use super::error::{ErrorCode, MxuError, MxuResult};
+use super::types::{ControllerConfig, MaaState, TaskConfig};
+use crate::i18n::tr;
+
+/// 默认检查间隔（秒）
+const DEFAULT_INTERVAL_SECS: u64 = 10;
+/// 默认最大重连次数
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+/// 单次重连等待连接成功的超时
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
+/// 恢复任务前等待 Tasker 停止的超时
+const STOP_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 看门狗配置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconnectWatchdogConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 检查间隔（秒）
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64,
+    /// 每次断线的最大重连次数
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// 重连成功后是否重新提交未完成的任务
+    #[serde(default)]
+    pub resume_tasks: bool,
+}
+
+fn default_interval_secs() -> u64 {
+    DEFAULT_INTERVAL_SECS
+}
+
+fn default_max_attempts() -> u32 {
+    DEFAULT_MAX_ATTEMPTS
+}
+
+impl Default for ReconnectWatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: DEFAULT_INTERVAL_SECS,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            resume_tasks: false,
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReconnectEvent {
+    instance_id: String,
+    /// lost / reconnecting / reconnected / resumed / failed
+    phase: &'static str,
+    attempt: u32,
+    message: Option<String>,
+}
+
+static CONFIG: Mutex<Option<ReconnectWatchdogConfig>> = Mutex::new(None);
+
+/// 由 `start` 记录，供配置变更时重启看门狗线程
+static APP: OnceLock<AppHandle> = OnceLock::new();
+
+/// 看门狗线程代号，配置变更时递增使旧线程退出
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 正在重连中的实例（避免重复处理）
+static RECOVERING: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+fn emit_event(
+    app: &AppHandle,
+    instance_id: &str,
+    phase: &'static str,
+    attempt: u32,
+    message: Option<String>,
+) {
+    let event = ReconnectEvent {
+        instance_id: instance_id.to_string(),
+        phase,
+        attempt,
+        message,
+    };
+    if let Err(e) = app.emit("controller-reconnect", event) {
+        warn!("Failed to emit controller-reconnect: {}", e);
+    }
+}
+
+/// 在实例现有控制器上重新发起连接并等待结果
+///
+/// 已连接时直接返回 `Ok(true)`；超时返回 `Ok(false)`。
+pub fn reconnect_impl(state: &MaaState, instance_id: &str) -> MxuResult<bool> {
+    let controller = {
+        let instance = state
+            .instances
+            .get(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        instance
+            .controller
+            .clone()
+            .ok_or_else(MxuError::controller_not_connected)?
+    };
+    if controller.connected() {
+        return Ok(true);
+    }
+
+    controller.post_connection().map_err(|e| e.to_string())?;
+    let deadline = Instant::now() + CONNECT_TIMEOUT;
+    while Instant::now() < deadline {
+        if controller.connected() {
+            return Ok(true);
+        }
+        std::thread::sleep(Duration::from_millis(500));
+    }
+    Ok(controller.connected())
+}
+
+/// 以新的截图 / 输入方式重建实例的 ADB 控制器，返回连接请求 ID
+///
+/// 任务运行中拒绝切换；其余参数（adb 路径、地址、额外配置）沿用当前控制器。
+pub async fn switch_adb_methods_impl(
+    app: &AppHandle,
+    state: Arc<MaaState>,
+    instance_id: &str,
+    screencap: u64,
+    input: u64,
+) -> MxuResult<i64> {
+    use maa_framework::common::{AdbInputMethod, AdbScreencapMethod};
+
+    if AdbScreencapMethod::from_bits_truncate(screencap).is_empty() {
+        return Err(MxuError::new(
+            ErrorCode::InvalidArgument,
+            tr!("error.reconnect.invalid_screencap", value = screencap),
+        ));
+    }
+    if AdbInputMethod::from_bits_truncate(input).is_empty() {
+        return Err(MxuError::new(
+            ErrorCode::InvalidArgument,
+            tr!("error.reconnect.invalid_input", value = input),
+        ));
+    }
+
+    let current = {
+        let instance = state
+            .instances
+            .get(instance_id)
+            .ok_or_else(MxuError::instance_not_found)?;
+        if instance.tasker.as_ref().is_some_and(|t| t.running()) {
+            return Err(tr!("error.reconnect.tasker_running").into());
+        }
+        instance
+            .controller_config
+            .clone()
+            .ok_or_else(MxuError::controller_not_connected)?
+    };
+    let ControllerConfig::Adb {
+        adb_path,
+        address,
+        config,
+        display_short_side,
+        ..
+    } = current
+    else {
+        return Err(tr!("error.reconnect.adb_only").into());
+    };
+
+    let new_config = ControllerConfig::Adb {
+        adb_path,
+        address,
+        screencap_methods: screencap.to_string(),
+        input_methods: input.to_string(),
+        config,
+        display_short_side,
+    };
+    info!(
+        "Switching ADB methods for {}: screencap={}, input={}",
+        instance_id, screencap, input
+    );
+
+    let app_for_events = app.clone();
+    let id = instance_id.to_string();
+    let conn_id = super::maa_core::connect_controller_impl(
+        state,
+        instance_id.to_string(),
+        new_config,
+        Arc::new(move |msg, detail| {
+            super::utils::emit_callback_event(&app_for_events, &id, msg, detail)
+        }),
+    )
+    .await?;
+    super::utils::emit_state_changed(app, instance_id, "connected");
+    Ok(conn_id)
+}
+
+/// 运行中（或仍有待执行任务）但控制器已断开的实例
+fn disconnected_busy_instances(state: &MaaState) -> Vec<String> {
+    state
+        .instances
+        .iter()
+        .filter(|inst| {
+            let busy = inst.tasker.as_ref().is_some_and(|t| t.running())
+                || inst.task_run_state.overall_status.as_deref() == Some("Running");
+            busy && !inst.stop_in_progress
+                && inst.controller.as_ref().is_some_and(|c| !c.connected())
+        })
+        .map(|inst| inst.key().clone())
+        .collect()
+}
+
+/// 收集尚未成功完成的任务（按提交顺序）
+pub(super) fn unfinished_tasks(state: &MaaState, instance_id: &str) -> Vec<(i64, TaskConfig)> {
+    let Some(instance) = state.instances.get(instance_id) else {
+        return Vec::new();
+    };
+    let Some(tasker) = instance.tasker.as_ref() else {
+        return Vec::new();
+    };
+    let mut ids: Vec<i64> = instance.submitted_tasks.keys().copied().collect();
+    ids.sort_unstable();
+    ids.into_iter()
+        .filter(|id| {
+            !matches!(
+                tasker.get_task_detail(*id).ok().flatten().map(|d| d.status),
+                Some(MaaStatus::SUCCEEDED)
+            )
+        })
+        .filter_map(|id| Some((id, instance.submitted_tasks.get(&id)?.clone())))
+        .collect()
+}
+
+/// 停止实例当前任务并等待 Tasker 空闲
+pub(super) fn stop_and_wait(state: &MaaState, instance_id: &str) -> bool {
+    if let Err(e) = super::maa_core::stop_task_impl(state, instance_id) {
+        warn!("[reconnect] Failed to stop instance {}: {}", instance_id, e);
+    }
+    let deadline = Instant::now() + STOP_TIMEOUT;
+    while Instant::now() < deadline {
+        let running = state
+            .instances
+            .get(instance_id)
+            .is_some_and(|r| r.tasker.as_ref().is_some_and(|t| t.running()));
+        if !running {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(200));
+    }
+    false
+}
+
+/// 处理单个断线实例：重连，必要时恢复任务
+fn recover_instance(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    config: &ReconnectWatchdogConfig,
+) {
+    warn!(
+        "[reconnect] Controller of instance {} disconnected during tasks",
+        instance_id
+    );
+    emit_event(app, instance_id, "lost", 0, None);
+
+    // 断线后剩余任务会迅速失败，先记录未完成的任务再停止
+    let pending = if config.resume_tasks {
+        let tasks = unfinished_tasks(state, instance_id);
+        if !stop_and_wait(state, instance_id) {
+            warn!(
+                "[reconnect] Tasker of instance {} did not stop in time",
+                instance_id
+            );
+        }
+        tasks
+    } else {
+        Vec::new()
+    };
+
+    for attempt in 1..=config.max_attempts.max(1) {
+        emit_event(app, instance_id, "reconnecting", attempt, None);
+        match reconnect_impl(state, instance_id) {
+            Ok(true) => {
+                info!(
+                    "[reconnect] Instance {} reconnected (attempt {})",
+                    instance_id, attempt
+                );
+                emit_event(app, instance_id, "reconnected", attempt, None);
+                super::utils::emit_state_changed(app, instance_id, "connected");
+                resume_tasks(app, state, instance_id, pending, attempt);
+                return;
+            }
+            Ok(false) => warn!(
+                "[reconnect] Instance {} reconnect attempt {} timed out",
+                instance_id, attempt
+            ),
+            Err(e) => {
+                warn!(
+                    "[reconnect] Instance {} reconnect attempt {} failed: {}",
+                    instance_id, attempt, e
+                );
+                emit_event(app, instance_id, "failed", attempt, Some(e.to_string()));
+                return;
+            }
+        }
+    }
+
+    emit_event(
+        app,
+        instance_id,
+        "failed",
+        config.max_attempts,
+        Some(tr!(
+            "error.reconnect.exhausted",
+            attempts = config.max_attempts
+        )),
+    );
+}
+
+fn resume_tasks(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    tasks: Vec<(i64, TaskConfig)>,
+    attempt: u32,
+) {
+    if tasks.is_empty() {
+        return;
+    }
+    let mut resumed = 0;
+    for (old_id, task) in &tasks {
+        match super::maa_core::resubmit_task_impl(app, state, instance_id, *old_id, task) {
+            Ok(_) => resumed += 1,
+            Err(e) => warn!("[reconnect] Failed to resume task {}: {}", task.entry, e),
+        }
+    }
+    if resumed > 0 {
+        info!(
+            "[reconnect] Resumed {} task(s) on instance {}",
+            resumed, instance_id
+        );
+        super::utils::emit_state_changed(app, instance_id, "task-started");
+        emit_event(
+            app,
+            instance_id,
+            "resumed",
+            attempt,
+            Some(format!("{}", resumed)),
+        );
+    }
+}
+
+fn spawn_watchdog(app: AppHandle, generation: u64) {
+    std::thread::spawn(move || loop {
+        let Some(config) = CONFIG.lock().clone() else {
+            return;
+        };
+        std::thread::sleep(Duration::from_secs(config.interval_secs.max(1)));
+        if GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        let Some(state) = app.try_state::<Arc<MaaState>>() else {
+            continue;
+        };
+        let state = state.inner().clone();
+
+        for instance_id in disconnected_busy_instances(&state) {
+            {
+                let mut recovering = RECOVERING.lock();
+                if !recovering
+                    .get_or_insert_with(HashSet::new)
+                    .insert(instance_id.clone())
+                {
+                    continue;
+                }
+            }
+            let app = app.clone();
+            let state = state.clone();
+            let config = config.clone();
+            std::thread::spawn(move || {
+                recover_instance(&app, &state, &instance_id, &config);
+                if let Some(set) = RECOVERING.lock().as_mut() {
+                    set.remove(&instance_id);
+                }
+            });
+        }
+    });
+}
+
+/// 应用看门狗配置（启用时启动新的检查线程，旧线程在下一轮检查时退出）
+fn apply_config(app: &AppHandle, config: ReconnectWatchdogConfig) {
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let enabled = config.enabled;
+    *CONFIG.lock() = enabled.then_some(config);
+    if enabled {
+        spawn_watchdog(app.clone(), generation);
+        info!("Reconnect watchdog enabled");
+    } else {
+        info!("Reconnect watchdog disabled");
+    }
+}
+
+/// 从 `settings.reconnectWatchdog` 读取看门狗配置，配置有变化时重启看门狗线程
+///
+/// 在 `start` 之前调用时仅记录配置，由 `start` 启动线程。
+pub fn init_from_config(config: &serde_json::Value) {
+    let watchdog = config
+        .get("settings")
+        .and_then(|s| s.get("reconnectWatchdog"))
+        .and_then(|v| serde_json::from_value::<ReconnectWatchdogConfig>(v.clone()).ok())
+        .map(|mut c| {
+            c.max_attempts = c.max_attempts.max(1);
+            c
+        })
+        .unwrap_or_default();
+
+    let mut guard = CONFIG.lock();
+    let current = guard.clone().unwrap_or_default();
+    if current == watchdog {
+        return;
+    }
+    match APP.get() {
+        Some(app) => {
+            drop(guard);
+            apply_config(app, watchdog);
+        }
+        None => *guard = watchdog.enabled.then_some(watchdog),
+    }
+}
+
+/// 按已读取的配置启动看门狗（应用启动时调用一次）
+pub fn start(app: &AppHandle) {
+    if APP.set(app.clone()).is_err() {
+        return;
+    }
+    let config = CONFIG.lock().clone();
+    if let Some(config) = config {
+        apply_config(app, config);
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 幂等重连：已连接时直接返回 true，否则重新发起连接并等待结果
+#[tauri::command]
+pub async fn maa_reconnect_controller(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+) -> MxuResult<bool> {
+    let state = state.inner().clone();
+    let id = instance_id.clone();
+    let connected = tokio::task::spawn_blocking(move || reconnect_impl(&state, &id))
+        .await
+        .map_err(|e| e.to_string())??;
+    if connected {
+        super::utils::emit_state_changed(&app, &instance_id, "connected");
+    }
+    Ok(connected)
+}
+
+/// 以新的截图 / 输入方式重新连接 ADB 控制器，返回连接请求 ID
+///
+/// `screencap` / `input` 为位掩码，u64 以字符串传递避免 JS 精度丢失
+#[tauri::command]
+pub async fn maa_switch_adb_methods(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+    instance_id: String,
+    screencap: String,
+    input: String,
+) -> MxuResult<i64> {
+    let screencap = screencap.parse::<u64>().map_err(|_| {
+        MxuError::new(
+            ErrorCode::InvalidArgument,
+            tr!("error.reconnect.invalid_screencap", value = screencap),
+        )
+    })?;
+    let input = input.parse::<u64>().map_err(|_| {
+        MxuError::new(
+            ErrorCode::InvalidArgument,
+            tr!("error.reconnect.invalid_input", value = input),
+        )
+    })?;
+    switch_adb_methods_impl(&app, state.inner().clone(), &instance_id, screencap, input).await
+}
@@ -0,0 +1,143 @@
+//! 结构化结果记录
+//!
+//! 资源的 pipeline 节点、custom action 与 Agent 可通过 MXU 内置动作 `MXU_REPORT_RESULT`
+//! 上报结构化结果（如抽卡、掉落识别结果），每条记录附带实例、运行 ID、任务与节点信息，
+//! 追加写入 `<数据目录>/results/results.jsonl`，并发送 `task-result` 事件。
+//! 前端通过 `mxu_query_results(task, key)` 查询，用于统计掉落率等，无需为每个资源单独开发界面。
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use chrono::Local;
+use log::warn;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+const RESULTS_FILE: &str = "results.jsonl";
+/// 查询默认返回的最大条数
+const DEFAULT_QUERY_LIMIT: usize = 1000;
+
+/// 串行化对结果文件的写入
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+/// 一条结构化结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultRecord {
+    /// 记录时间（本地时间）
+    pub time: String,
+    pub instance_id: String,
+    pub run_id: Option<String>,
+    /// 上报时正在执行的任务入口
+    pub task: Option<String>,
+    /// 上报的 pipeline 节点
+    pub node: String,
+    pub key: String,
+    pub value: Value,
+}
+
+fn results_path() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join("results").join(RESULTS_FILE))
+}
+
+/// 记录一条结果并发送 `task-result` 事件
+pub fn record(
+    app: &AppHandle,
+    instance_id: &str,
+    task: Option<String>,
+    node: &str,
+    key: &str,
+    value: Value,
+) -> Result<ResultRecord, String> {
+    let record = ResultRecord {
+        time: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        instance_id: instance_id.to_string(),
+        run_id: super::run_report::current_run_id(instance_id),
+        task: task.or_else(|| super::run_report::current_task_entry(instance_id)),
+        node: node.to_string(),
+        key: key.to_string(),
+        value,
+    };
+    let line = serde_json::to_string(&record).map_err(|e| tr!("error.serialize", error = e))?;
+
+    let path = results_path()?;
+    {
+        let _guard = WRITE_LOCK.lock();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| tr!("error.fs.open_file", path = path.display(), error = e))?;
+        writeln!(file, "{}", line)
+            .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))?;
+    }
+
+    if let Err(e) = app.emit("task-result", &record) {
+        warn!("Failed to emit task-result: {}", e);
+    }
+    Ok(record)
+}
+
+/// 查询结果（按时间倒序，最多 `limit` 条）
+pub fn query(
+    task: Option<&str>,
+    key: Option<&str>,
+    instance_id: Option<&str>,
+    limit: usize,
+) -> Result<Vec<ResultRecord>, String> {
+    let path = results_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let file = std::fs::File::open(&path)
+        .map_err(|e| tr!("error.fs.read_file", path = path.display(), error = e))?;
+    let mut records: Vec<ResultRecord> = std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str::<ResultRecord>(&line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                warn!("Skipping invalid result record: {}", e);
+                None
+            }
+        })
+        .filter(|r| task.is_none_or(|t| r.task.as_deref() == Some(t)))
+        .filter(|r| key.is_none_or(|k| r.key == k))
+        .filter(|r| instance_id.is_none_or(|id| r.instance_id == id))
+        .collect();
+    records.reverse();
+    records.truncate(limit);
+    Ok(records)
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 查询 `MXU_REPORT_RESULT` 上报的结构化结果（按时间倒序）
+///
+/// `task` / `key` / `instance_id` 为空时不按该字段筛选，`limit` 默认 1000。
+#[tauri::command]
+pub fn mxu_query_results(
+    task: Option<String>,
+    key: Option<String>,
+    instance_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<ResultRecord>, String> {
+    query(
+        task.as_deref(),
+        key.as_deref(),
+        instance_id.as_deref(),
+        limit.unwrap_or(DEFAULT_QUERY_LIMIT),
+    )
+}
@@ -0,0 +1,402 @@
+//! 任务运行选项
+//!
+//! 在提交任务前改写 pipeline override：
+//! - 试运行（dry-run）：所有节点的 action 改为 DoNothing，只验证识别是否成功
+//! - 运行速度：按实例倍率缩放每个节点的 pre_delay / post_delay
+//! - 操作间隔：为每个节点的 post_delay 设置下限，限制控制器操作频率
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use log::{debug, info, warn};
+use maa_framework::resource::Resource;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use tauri::State;
+
+use super::error::{ErrorCode, MxuError, MxuResult};
+use super::types::{MaaState, TaskConfig};
+use crate::i18n::tr;
+
+/// MaaFramework 节点 pre_delay / post_delay 的默认值（毫秒）
+const DEFAULT_NODE_DELAY_MS: u64 = 200;
+
+/// 延迟倍率的允许范围
+const MIN_DELAY_MULTIPLIER: f64 = 0.1;
+const MAX_DELAY_MULTIPLIER: f64 = 10.0;
+
+/// 操作间隔上限（毫秒）
+const MAX_ACTION_INTERVAL_MS: u64 = 60_000;
+
+/// 实例级运行选项（保存在 InstanceRuntime 中，每次启动任务时生效）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceRunOptions {
+    /// 延迟倍率（>1 放慢，<1 加快），None 表示不改写
+    pub delay_multiplier: Option<f64>,
+    /// 两次控制器操作之间的最小间隔（毫秒），None 表示不限制
+    pub action_interval_ms: Option<u64>,
+}
+
+/// 单次启动的运行选项
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub dry_run: bool,
+    pub delay_multiplier: Option<f64>,
+    pub action_interval_ms: Option<u64>,
+}
+
+/// 将额外的节点覆盖合并进 pipeline override 字符串
+///
+/// override 为对象时按节点合并字段；为数组（多段覆盖）时作为一段加入。
+/// `overwrite` 为 false 时只补充 override 中未显式设置的字段（数组时插入到最前，由后续段覆盖）。
+fn merge_node_overrides(
+    pipeline_override: &str,
+    extra: Map<String, Value>,
+    overwrite: bool,
+) -> String {
+    let mut value: Value = match pipeline_override.trim() {
+        "" => Value::Object(Map::new()),
+        s => serde_json::from_str(s).unwrap_or_else(|e| {
+            warn!("Invalid pipeline override, ignoring: {}", e);
+            Value::Object(Map::new())
+        }),
+    };
+
+    match &mut value {
+        Value::Object(nodes) => {
+            for (name, fields) in extra {
+                let node = nodes
+                    .entry(name)
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let (Some(node), Value::Object(fields)) = (node.as_object_mut(), fields) {
+                    for (key, field) in fields {
+                        if overwrite || !node.contains_key(&key) {
+                            node.insert(key, field);
+                        }
+                    }
+                }
+            }
+        }
+        Value::Array(list) if overwrite => list.push(Value::Object(extra)),
+        Value::Array(list) => list.insert(0, Value::Object(extra)),
+        _ => value = Value::Object(extra),
+    }
+    value.to_string()
+}
+
+/// 试运行：将任务可达的节点（以及 override 中新定义的节点）的 action 改为 DoNothing
+pub fn apply_dry_run(pipeline_override: &str, node_names: &[String]) -> String {
+    let mut extra = Map::new();
+    for name in node_names {
+        extra.insert(name.clone(), json!({ "action": "DoNothing" }));
+    }
+    // override 中可能定义了资源里没有的节点
+    if let Ok(Value::Object(nodes)) = serde_json::from_str::<Value>(pipeline_override) {
+        for name in nodes.keys() {
+            extra
+                .entry(name.clone())
+                .or_insert_with(|| json!({ "action": "DoNothing" }));
+        }
+    }
+    merge_node_overrides(pipeline_override, extra, true)
+}
+
+/// 读取节点的延迟字段（未设置时为默认值）
+fn node_delay(node: &Value, key: &str) -> u64 {
+    node.get(key)
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_NODE_DELAY_MS)
+}
+
+/// 为指定节点生成缩放后的 pre_delay / post_delay
+///
+/// post_delay 不低于 `min_interval_ms`，即每次动作后至少等待该时长再进行下一次识别与操作。
+fn delay_overrides(
+    nodes: &HashMap<String, Value>,
+    node_names: &[String],
+    multiplier: f64,
+    min_interval_ms: u64,
+) -> Map<String, Value> {
+    let mut extra = Map::new();
+    for name in node_names {
+        let node = nodes.get(name).unwrap_or(&Value::Null);
+        let scale = |ms: u64| (ms as f64 * multiplier).round() as u64;
+        extra.insert(
+            name.clone(),
+            json!({
+                "pre_delay": scale(node_delay(node, "pre_delay")),
+                "post_delay": scale(node_delay(node, "post_delay")).max(min_interval_ms),
+            }),
+        );
+    }
+    extra
+}
+
+/// 读取资源中全部节点的数据
+fn load_nodes(resource: &Resource, node_names: &[String]) -> HashMap<String, Value> {
+    node_names
+        .iter()
+        .filter_map(|name| {
+            let data = resource.get_node_data(name).ok().flatten()?;
+            Some((name.clone(), serde_json::from_str(&data).ok()?))
+        })
+        .collect()
+}
+
+/// pipeline override 中的各段（对象为一段，数组为多段）
+fn override_segments(pipeline_override: &str) -> Vec<Map<String, Value>> {
+    match serde_json::from_str::<Value>(pipeline_override) {
+        Ok(Value::Object(nodes)) => vec![nodes],
+        Ok(Value::Array(list)) => list
+            .into_iter()
+            .filter_map(|v| match v {
+                Value::Object(nodes) => Some(nodes),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 节点 next / on_error / interrupt 中引用的节点，返回（名称，是否为锚点引用）
+fn node_refs(node: &Value) -> Vec<(String, bool)> {
+    let mut refs = Vec::new();
+    for key in ["next", "on_error", "interrupt"] {
+        let items = match node.get(key) {
+            Some(Value::Array(list)) => list.iter().collect(),
+            Some(v) => vec![v],
+            None => Vec::new(),
+        };
+        for item in items {
+            match item {
+                Value::String(name) => {
+                    let mut name = name.as_str();
+                    let mut anchor = false;
+                    loop {
+                        if let Some(rest) = name.strip_prefix("[JumpBack]") {
+                            name = rest;
+                        } else if let Some(rest) = name.strip_prefix("[Anchor]") {
+                            name = rest;
+                            anchor = true;
+                        } else {
+                            break;
+                        }
+                    }
+                    refs.push((name.to_string(), anchor));
+                }
+                Value::Object(attr) => {
+                    if let Some(name) = attr.get("name").and_then(|v| v.as_str()) {
+                        let anchor = attr.get("anchor").and_then(|v| v.as_bool()) == Some(true);
+                        refs.push((name.to_string(), anchor));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    refs
+}
+
+/// 记录节点设置的锚点（锚点名 -> 节点名）
+fn collect_anchors(name: &str, node: &Value, anchors: &mut HashMap<String, HashSet<String>>) {
+    let mut add = |anchor: &str, target: &str| {
+        if !target.is_empty() {
+            anchors
+                .entry(anchor.to_string())
+                .or_default()
+                .insert(target.to_string());
+        }
+    };
+    match node.get("anchor") {
+        Some(Value::String(anchor)) => add(anchor, name),
+        Some(Value::Array(list)) => list
+            .iter()
+            .filter_map(|v| v.as_str())
+            .for_each(|anchor| add(anchor, name)),
+        Some(Value::Object(map)) => map
+            .iter()
+            .filter_map(|(anchor, target)| Some((anchor, target.as_str()?)))
+            .for_each(|(anchor, target)| add(anchor, target)),
+        _ => {}
+    }
+}
+
+/// 从任务入口沿 next / on_error 可达的节点（包含 override 改写后的跳转）
+///
+/// 锚点引用按所有设置过该锚点的节点展开；custom action 中通过 run_task 等动态进入的节点
+/// 无法静态分析，不在此列。
+fn reachable_nodes(
+    nodes: &HashMap<String, Value>,
+    segments: &[Map<String, Value>],
+    entry: &str,
+) -> Vec<String> {
+    let views = |name: &str| -> Vec<&Value> {
+        nodes
+            .get(name)
+            .into_iter()
+            .chain(segments.iter().filter_map(|seg| seg.get(name)))
+            .collect()
+    };
+
+    let mut anchors: HashMap<String, HashSet<String>> = HashMap::new();
+    for (name, node) in nodes {
+        collect_anchors(name, node, &mut anchors);
+    }
+    for seg in segments {
+        for (name, node) in seg {
+            collect_anchors(name, node, &mut anchors);
+        }
+    }
+
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut order = Vec::new();
+    let mut queue = VecDeque::from([entry.to_string()]);
+    while let Some(name) = queue.pop_front() {
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        let node_views = views(&name);
+        if node_views.is_empty() {
+            continue;
+        }
+        order.push(name);
+        for node in node_views {
+            for (target, anchor) in node_refs(node) {
+                if anchor {
+                    queue.extend(anchors.get(&target).into_iter().flatten().cloned());
+                } else {
+                    queue.push_back(target);
+                }
+            }
+        }
+    }
+    order
+}
+
+/// 按运行选项改写任务列表
+///
+/// 延迟与试运行只作用于各任务入口可达的节点，不影响同批其他任务。
+pub fn prepare_tasks(
+    resource: &Resource,
+    tasks: Vec<TaskConfig>,
+    options: &RunOptions,
+) -> Vec<TaskConfig> {
+    let multiplier = options
+        .delay_multiplier
+        .filter(|m| (*m - 1.0).abs() > f64::EPSILON);
+    let interval = options.action_interval_ms.filter(|ms| *ms > 0);
+    if !options.dry_run && multiplier.is_none() && interval.is_none() {
+        return tasks;
+    }
+
+    let node_names = resource.node_list().unwrap_or_else(|e| {
+        warn!("Failed to get node list for run options: {}", e);
+        Vec::new()
+    });
+    let nodes = load_nodes(resource, &node_names);
+    info!(
+        "[run_options] dry_run={}, delay_multiplier={:?}, action_interval_ms={:?}, {} node(s)",
+        options.dry_run,
+        multiplier,
+        interval,
+        nodes.len()
+    );
+
+    tasks
+        .into_iter()
+        .map(|task| {
+            let mut pipeline_override = task.pipeline_override.clone();
+            let segments = override_segments(&pipeline_override);
+            let reachable = reachable_nodes(&nodes, &segments, &task.entry);
+            debug!(
+                "[run_options] task {} reaches {} node(s)",
+                task.entry,
+                reachable.len()
+            );
+            if multiplier.is_some() || interval.is_some() {
+                let extra = delay_overrides(
+                    &nodes,
+                    &reachable,
+                    multiplier.unwrap_or(1.0),
+                    interval.unwrap_or(0),
+                );
+                // 任务 override 中显式设置的延迟优先
+                pipeline_override = merge_node_overrides(&pipeline_override, extra, false);
+            }
+            if options.dry_run {
+                pipeline_override = apply_dry_run(&pipeline_override, &reachable);
+            }
+            TaskConfig {
+                pipeline_override,
+                ..task
+            }
+        })
+        .collect()
+}
+
+/// 设置实例的运行速度（延迟倍率）
+///
+/// `multiplier` > 1 时放慢（延迟变长，适合卡顿的模拟器或低端设备），< 1 时加快，
+/// 1.0 恢复资源原始设置。下次启动任务时生效。
+#[tauri::command]
+pub fn maa_set_run_speed(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    multiplier: f64,
+) -> MxuResult<InstanceRunOptions> {
+    if !multiplier.is_finite() || multiplier <= 0.0 {
+        return Err(MxuError::new(
+            ErrorCode::InvalidArgument,
+            tr!("error.run_options.invalid_multiplier", value = multiplier),
+        ));
+    }
+    let multiplier = multiplier.clamp(MIN_DELAY_MULTIPLIER, MAX_DELAY_MULTIPLIER);
+
+    let mut guard = state
+        .instances
+        .get_mut(&instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
+    instance.run_options.delay_multiplier =
+        ((multiplier - 1.0).abs() > f64::EPSILON).then_some(multiplier);
+    info!(
+        "maa_set_run_speed: instance={}, multiplier={}",
+        instance_id, multiplier
+    );
+    Ok(instance.run_options.clone())
+}
+
+/// 设置实例的最小操作间隔
+///
+/// 部分游戏会检测过于密集的点击/滑动，设置后每个节点动作执行完至少等待
+/// `interval_ms` 毫秒。传 0 取消限制。下次启动任务时生效。
+#[tauri::command]
+pub fn maa_set_action_interval(
+    state: State<Arc<MaaState>>,
+    instance_id: String,
+    interval_ms: u64,
+) -> MxuResult<InstanceRunOptions> {
+    if interval_ms > MAX_ACTION_INTERVAL_MS {
+        return Err(MxuError::new(
+            ErrorCode::InvalidArgument,
+            tr!(
+                "error.run_options.interval_too_large",
+                value = interval_ms,
+                max = MAX_ACTION_INTERVAL_MS
+            ),
+        ));
+    }
+
+    let mut guard = state
+        .instances
+        .get_mut(&instance_id)
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
+    instance.run_options.action_interval_ms = (interval_ms > 0).then_some(interval_ms);
+    info!(
+        "maa_set_action_interval: instance={}, interval_ms={}",
+        instance_id, interval_ms
+    );
+    Ok(instance.run_options.clone())
+}
@@ -0,0 +1,508 @@
+//! 运行总结报告
+//!
+//! 每次 `start_tasks` 视为一次运行（run），记录各任务的起止时间、结果，
+//! 任务失败时关联 `failure_capture` 保存的截图。运行结束后报告写入 `<数据目录>/reports/<run_id>/report.json`，
+//! 可导出为 HTML 或 Markdown，方便查看无人值守运行的结果。
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::Local;
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::types::MaaState;
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+/// 内存中保留的最近报告数
+const MAX_RECENT_REPORTS: usize = 50;
+
+/// 单个任务的执行记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskReport {
+    pub task_id: i64,
+    pub entry: String,
+    pub selected_task_id: Option<String>,
+    /// pending / running / succeeded / failed
+    pub status: String,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub duration_ms: Option<u64>,
+    /// 失败时的截图路径
+    pub error_screenshot: Option<String>,
+}
+
+/// 一次运行的总结报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunReport {
+    pub run_id: String,
+    pub instance_id: String,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub duration_ms: Option<u64>,
+    /// Running / Succeeded / Failed / Stopped
+    pub status: String,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub tasks: Vec<TaskReport>,
+}
+
+/// 运行中的报告（附带计时起点，不参与序列化）
+struct ActiveRun {
+    report: RunReport,
+    started: std::time::Instant,
+    task_started: HashMap<i64, std::time::Instant>,
+}
+
+/// 各实例当前的运行（instance_id -> run）
+static ACTIVE: Mutex<Option<HashMap<String, ActiveRun>>> = Mutex::new(None);
+
+/// 最近结束的报告（新的在前）
+static RECENT: Mutex<Vec<RunReport>> = Mutex::new(Vec::new());
+
+fn reports_dir() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join("reports"))
+}
+
+fn now_string() -> String {
+    Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// 开始记录一次运行，返回 run_id（由 `start_tasks_impl` 在任务提交后调用）
+pub fn begin_run(instance_id: &str, tasks: &[(i64, String, Option<String>)]) -> String {
+    let run_id = format!(
+        "{}-{}",
+        Local::now().format("%Y%m%d-%H%M%S-%3f"),
+        instance_id
+    );
+    let report = RunReport {
+        run_id: run_id.clone(),
+        instance_id: instance_id.to_string(),
+        started_at: now_string(),
+        finished_at: None,
+        duration_ms: None,
+        status: "Running".to_string(),
+        succeeded: 0,
+        failed: 0,
+        tasks: tasks
+            .iter()
+            .map(|(task_id, entry, selected_task_id)| TaskReport {
+                task_id: *task_id,
+                entry: entry.clone(),
+                selected_task_id: selected_task_id.clone(),
+                status: "pending".to_string(),
+                started_at: None,
+                finished_at: None,
+                duration_ms: None,
+                error_screenshot: None,
+            })
+            .collect(),
+    };
+
+    ACTIVE.lock().get_or_insert_with(HashMap::new).insert(
+        instance_id.to_string(),
+        ActiveRun {
+            report,
+            started: std::time::Instant::now(),
+            task_started: HashMap::new(),
+        },
+    );
+    info!("Run report started: {}", run_id);
+    run_id
+}
+
+/// 实例当前运行的 run_id
+pub fn current_run_id(instance_id: &str) -> Option<String> {
+    let active = ACTIVE.lock();
+    Some(active.as_ref()?.get(instance_id)?.report.run_id.clone())
+}
+
+/// 实例当前正在执行的任务入口
+pub fn current_task_entry(instance_id: &str) -> Option<String> {
+    let active = ACTIVE.lock();
+    active
+        .as_ref()?
+        .get(instance_id)?
+        .report
+        .tasks
+        .iter()
+        .find(|t| t.status == "running" && !t.entry.is_empty())
+        .map(|t| t.entry.clone())
+}
+
+/// 任务状态回调（由 `handle_task_callback` 调用，失败时附带失败截图路径）
+pub fn on_task_event(
+    instance_id: &str,
+    task_id: i64,
+    status: &str,
+    error_screenshot: Option<String>,
+) {
+    let mut active = ACTIVE.lock();
+    let Some(run) = active.as_mut().and_then(|a| a.get_mut(instance_id)) else {
+        return;
+    };
+    // 重连恢复等后续提交的任务也计入本次运行
+    if !run.report.tasks.iter().any(|t| t.task_id == task_id) {
+        run.report.tasks.push(TaskReport {
+            task_id,
+            entry: String::new(),
+            selected_task_id: None,
+            status: "pending".to_string(),
+            started_at: None,
+            finished_at: None,
+            duration_ms: None,
+            error_screenshot: None,
+        });
+    }
+    let now = std::time::Instant::now();
+    let started = run.task_started.get(&task_id).copied();
+    let task = run
+        .report
+        .tasks
+        .iter_mut()
+        .find(|t| t.task_id == task_id)
+        .unwrap();
+    task.status = status.to_string();
+    if status == "running" {
+        task.started_at = Some(now_string());
+        run.task_started.insert(task_id, now);
+        return;
+    }
+    task.finished_at = Some(now_string());
+    task.duration_ms = started.map(|s| now.duration_since(s).as_millis() as u64);
+    if error_screenshot.is_some() {
+        task.error_screenshot = error_screenshot;
+    }
+}
+
+/// 任务被重新提交（如插队后恢复队列）时更新报告中的任务 ID
+pub fn replace_task(instance_id: &str, old_task_id: i64, new_task_id: i64) {
+    let mut active = ACTIVE.lock();
+    let Some(run) = active.as_mut().and_then(|a| a.get_mut(instance_id)) else {
+        return;
+    };
+    if let Some(task) = run
+        .report
+        .tasks
+        .iter_mut()
+        .find(|t| t.task_id == old_task_id)
+    {
+        task.task_id = new_task_id;
+    }
+}
+
+/// 结束当前运行并保存报告
+fn finish_run(app: &AppHandle, instance_id: &str, status: &str) {
+    let Some(mut run) = ACTIVE.lock().as_mut().and_then(|a| a.remove(instance_id)) else {
+        return;
+    };
+
+    let report = &mut run.report;
+    report.finished_at = Some(now_string());
+    report.duration_ms = Some(run.started.elapsed().as_millis() as u64);
+    report.status = status.to_string();
+    // 被停止时未执行的任务保持 pending，正在执行的视为失败
+    for task in report.tasks.iter_mut().filter(|t| t.status == "running") {
+        task.status = "failed".to_string();
+    }
+    report.succeeded = report
+        .tasks
+        .iter()
+        .filter(|t| t.status == "succeeded")
+        .count();
+    report.failed = report.tasks.iter().filter(|t| t.status == "failed").count();
+
+    if let Err(e) = save_report(report) {
+        warn!("Failed to save run report {}: {}", report.run_id, e);
+    }
+    info!(
+        "Run report finished: {} ({}, {} succeeded, {} failed)",
+        report.run_id, report.status, report.succeeded, report.failed
+    );
+    let _ = app.emit("run-report-ready", report.clone());
+
+    let mut recent = RECENT.lock();
+    recent.insert(0, run.report);
+    recent.truncate(MAX_RECENT_REPORTS);
+}
+
+/// 实例状态变更时调用（由 `emit_state_changed` 转发）
+pub fn on_state_changed(app: &AppHandle, instance_id: &str, kind: &str) {
+    match kind {
+        "tasks-completed" => {
+            let overall = app
+                .try_state::<Arc<MaaState>>()
+                .and_then(|state| {
+                    state
+                        .instances
+                        .get(instance_id)?
+                        .task_run_state
+                        .overall_status
+                        .clone()
+                })
+                .unwrap_or_else(|| "Succeeded".to_string());
+            finish_run(app, instance_id, &overall);
+        }
+        "task-stopped" => finish_run(app, instance_id, "Stopped"),
+        _ => {}
+    }
+}
+
+fn save_report(report: &RunReport) -> Result<(), String> {
+    let dir = reports_dir()?.join(&report.run_id);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| tr!("error.fs.create_dir", path = dir.display(), error = e))?;
+    let content =
+        serde_json::to_string_pretty(report).map_err(|e| tr!("error.serialize", error = e))?;
+    let path = dir.join("report.json");
+    super::config_crypto::write_string(&path, &content)
+        .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))
+}
+
+/// 按 run_id 查找报告：进行中 → 最近 → 磁盘
+fn find_report(run_id: &str) -> Result<RunReport, String> {
+    if let Some(run) = ACTIVE
+        .lock()
+        .as_ref()
+        .and_then(|a| a.values().find(|r| r.report.run_id == run_id))
+    {
+        return Ok(run.report.clone());
+    }
+    if let Some(report) = RECENT.lock().iter().find(|r| r.run_id == run_id) {
+        return Ok(report.clone());
+    }
+
+    // run_id 用作目录名，拒绝路径分隔符
+    if run_id.contains(['/', '\\']) || run_id.contains("..") {
+        return Err(tr!("error.report.invalid_run_id", id = run_id));
+    }
+    let path = reports_dir()?.join(run_id).join("report.json");
+    if !path.exists() {
+        return Err(tr!("error.report.not_found", id = run_id));
+    }
+    let content = super::config_crypto::read_to_string(&path)?;
+    serde_json::from_str(&content).map_err(|e| tr!("error.report.parse", error = e))
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn format_duration(ms: Option<u64>) -> String {
+    match ms {
+        Some(ms) if ms >= 60_000 => format!("{}m{}s", ms / 60_000, ms % 60_000 / 1000),
+        Some(ms) => format!("{:.1}s", ms as f64 / 1000.0),
+        None => "-".to_string(),
+    }
+}
+
+/// 截图在报告目录中的文件名（导出时复制到报告目录）
+fn screenshot_name(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+fn render_markdown(report: &RunReport) -> String {
+    let mut out = format!(
+        "# 运行报告 {}\n\n- 实例：{}\n- 开始：{}\n- 结束：{}\n- 耗时：{}\n- 结果：{}（成功 {}，失败 {}）\n\n",
+        report.run_id,
+        report.instance_id,
+        report.started_at,
+        report.finished_at.as_deref().unwrap_or("-"),
+        format_duration(report.duration_ms),
+        report.status,
+        report.succeeded,
+        report.failed,
+    );
+    out.push_str("| # | 任务 | 状态 | 开始 | 耗时 |\n|---|---|---|---|---|\n");
+    for (i, task) in report.tasks.iter().enumerate() {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            i + 1,
+            task.entry,
+            task.status,
+            task.started_at.as_deref().unwrap_or("-"),
+            format_duration(task.duration_ms),
+        ));
+    }
+    let failures: Vec<&TaskReport> = report
+        .tasks
+        .iter()
+        .filter(|t| t.error_screenshot.is_some())
+        .collect();
+    if !failures.is_empty() {
+        out.push_str("\n## 失败截图\n");
+        for task in failures {
+            let name = screenshot_name(task.error_screenshot.as_deref().unwrap());
+            out.push_str(&format!(
+                "\n### {}\n\n![{}]({})\n",
+                task.entry, task.entry, name
+            ));
+        }
+    }
+    out
+}
+
+fn render_html(report: &RunReport) -> String {
+    let mut rows = String::new();
+    for (i, task) in report.tasks.iter().enumerate() {
+        let screenshot = task
+            .error_screenshot
+            .as_deref()
+            .map(|p| {
+                format!(
+                    r#"<a href="{0}"><img src="{0}" alt="screenshot"></a>"#,
+                    escape_html(&screenshot_name(p))
+                )
+            })
+            .unwrap_or_default();
+        rows.push_str(&format!(
+            r#"<tr class="{}"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>"#,
+            escape_html(&task.status),
+            i + 1,
+            escape_html(&task.entry),
+            escape_html(&task.status),
+            escape_html(task.started_at.as_deref().unwrap_or("-")),
+            format_duration(task.duration_ms),
+            screenshot,
+        ));
+        rows.push('\n');
+    }
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="zh-CN">
+<head>
+<meta charset="utf-8">
+<title>运行报告 {run_id}</title>
+<style>
+body {{ font-family: system-ui, sans-serif; margin: 24px; color: #222; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ border: 1px solid #ddd; padding: 6px 10px; text-align: left; vertical-align: top; }}
+tr.succeeded td:nth-child(3) {{ color: #1a7f37; }}
+tr.failed td:nth-child(3) {{ color: #cf222e; }}
+img {{ max-width: 320px; }}
+</style>
+</head>
+<body>
+<h1>运行报告 {run_id}</h1>
+<ul>
+<li>实例：{instance}</li>
+<li>开始：{started}</li>
+<li>结束：{finished}</li>
+<li>耗时：{duration}</li>
+<li>结果：{status}（成功 {succeeded}，失败 {failed}）</li>
+</ul>
+<table>
+<tr><th>#</th><th>任务</th><th>状态</th><th>开始</th><th>耗时</th><th>截图</th></tr>
+{rows}</table>
+</body>
+</html>
+"#,
+        run_id = escape_html(&report.run_id),
+        instance = escape_html(&report.instance_id),
+        started = escape_html(&report.started_at),
+        finished = escape_html(report.finished_at.as_deref().unwrap_or("-")),
+        duration = format_duration(report.duration_ms),
+        status = escape_html(&report.status),
+        succeeded = report.succeeded,
+        failed = report.failed,
+        rows = rows,
+    )
+}
+
+/// 列出报告（按时间倒序，可按实例筛选；包含进行中的运行与磁盘上的历史报告）
+pub fn list_reports(instance_id: Option<&str>) -> Result<Vec<RunReport>, String> {
+    let mut reports: Vec<RunReport> = Vec::new();
+    reports.extend(
+        ACTIVE
+            .lock()
+            .iter()
+            .flat_map(|a| a.values().map(|r| r.report.clone())),
+    );
+
+    let dir = reports_dir()?;
+    if dir.exists() {
+        let entries = std::fs::read_dir(&dir)
+            .map_err(|e| tr!("error.fs.read_dir", path = dir.display(), error = e))?;
+        for entry in entries.flatten() {
+            let Ok(content) =
+                super::config_crypto::read_to_string(&entry.path().join("report.json"))
+            else {
+                continue;
+            };
+            match serde_json::from_str::<RunReport>(&content) {
+                Ok(report) => reports.push(report),
+                Err(e) => warn!("Skipping invalid report {:?}: {}", entry.path(), e),
+            }
+        }
+    }
+
+    if let Some(instance_id) = instance_id {
+        reports.retain(|r| r.instance_id == instance_id);
+    }
+    // run_id 以时间戳开头，按字典序倒序即为时间倒序
+    reports.sort_by(|a, b| b.run_id.cmp(&a.run_id));
+    reports.dedup_by(|a, b| a.run_id == b.run_id);
+    Ok(reports)
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取运行报告
+#[tauri::command]
+pub fn mxu_get_run_report(run_id: String) -> Result<RunReport, String> {
+    find_report(&run_id)
+}
+
+/// 列出报告（按时间倒序，可按实例筛选；包含进行中的运行与磁盘上的历史报告）
+#[tauri::command]
+pub fn mxu_list_run_reports(instance_id: Option<String>) -> Result<Vec<RunReport>, String> {
+    list_reports(instance_id.as_deref())
+}
+
+/// 导出报告为 HTML 或 Markdown，写入报告目录并返回文件路径
+///
+/// `format` 为 `"html"` 或 `"markdown"`；失败截图以相对路径引用，与导出文件位于同一目录。
+#[tauri::command]
+pub fn mxu_export_run_report(run_id: String, format: String) -> Result<String, String> {
+    let report = find_report(&run_id)?;
+    let (content, file_name) = match format.to_ascii_lowercase().as_str() {
+        "html" => (render_html(&report), "report.html"),
+        "markdown" | "md" => (render_markdown(&report), "report.md"),
+        other => return Err(tr!("error.report.unsupported_format", format = other)),
+    };
+    let dir = reports_dir()?.join(&report.run_id);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| tr!("error.fs.create_dir", path = dir.display(), error = e))?;
+    // 失败截图复制到报告目录，导出文件以相对路径引用
+    for screenshot in report
+        .tasks
+        .iter()
+        .filter_map(|t| t.error_screenshot.as_deref())
+    {
+        let target = dir.join(screenshot_name(screenshot));
+        if !target.exists() {
+            if let Err(e) = std::fs::copy(screenshot, &target) {
+                warn!("Failed to copy screenshot {}: {}", screenshot, e);
+            }
+        }
+    }
+    let path = dir.join(file_name);
+    std::fs::write(&path, content)
+        .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))?;
+    info!("Run report exported: {}", path.display());
+    Ok(path.to_string_lossy().to_string())
+}
@@ -0,0 +1,232 @@
+//! 截图历史
+//!
+//! 任务运行中每个 pipeline 节点结束时保存控制器的缓存截图到
+//! `debug/screenshots/<实例>/`，文件名为时间戳 + 节点名，每个实例按容量滚动删除最旧的截图。
+//! 运行失败后可通过 `maa_get_screenshot_history` 回看之前的画面。
+//! 回调线程只取出截图数据，写盘与滚动删除由后台写入线程完成。
+//!
+//! 通过 `settings.screenshotHistory` 配置：
+//! - `capacity`: 每个实例保留的截图数（默认 30，0 为关闭）
+//! - `minIntervalMs`: 两次保存的最小间隔（默认 1000ms），避免节点密集时频繁写盘
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, OnceLock};
+use std::time::Instant;
+
+use chrono::{DateTime, Local, NaiveDateTime};
+use log::warn;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use super::failure_capture::sanitize;
+use super::types::MaaState;
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+const DEFAULT_CAPACITY: u64 = 30;
+const DEFAULT_MIN_INTERVAL_MS: u64 = 1000;
+/// 文件名中的时间戳格式
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S-%3f";
+
+/// 每个实例保留的截图数（0 表示关闭）
+static CAPACITY: AtomicU64 = AtomicU64::new(DEFAULT_CAPACITY);
+/// 两次保存的最小间隔（ms）
+static MIN_INTERVAL_MS: AtomicU64 = AtomicU64::new(DEFAULT_MIN_INTERVAL_MS);
+/// 每个实例上次保存的时间
+static LAST_CAPTURE: Mutex<Option<HashMap<String, Instant>>> = Mutex::new(None);
+/// 后台写入线程的通知通道（首次保存时创建）
+static WRITER: OnceLock<Mutex<mpsc::Sender<PendingCapture>>> = OnceLock::new();
+
+/// 等待后台写入的一张截图
+struct PendingCapture {
+    instance_id: String,
+    node: String,
+    data: Vec<u8>,
+    captured_at: DateTime<Local>,
+    capacity: usize,
+}
+
+/// 一条截图历史
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenshotHistoryEntry {
+    pub instance_id: String,
+    /// 截图时间（`YYYY-MM-DD HH:MM:SS.mmm`）
+    pub captured_at: String,
+    /// 截图时所在的节点
+    pub node: String,
+    pub image_path: String,
+}
+
+fn history_dir(instance_id: &str) -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?
+        .join("debug")
+        .join("screenshots")
+        .join(sanitize(instance_id)))
+}
+
+/// 从配置读取 `settings.screenshotHistory`
+pub fn init_from_config(config: &serde_json::Value) {
+    let history = config
+        .get("settings")
+        .and_then(|s| s.get("screenshotHistory"));
+    let capacity = history
+        .and_then(|h| h.get("capacity"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_CAPACITY);
+    let min_interval = history
+        .and_then(|h| h.get("minIntervalMs"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_MIN_INTERVAL_MS);
+    CAPACITY.store(capacity, Ordering::Relaxed);
+    MIN_INTERVAL_MS.store(min_interval, Ordering::Relaxed);
+}
+
+/// 处理一条回调消息（由 `emit_callback_event` 调用），节点结束时保存截图
+pub fn on_callback(app: &AppHandle, instance_id: &str, message: &str, details: &str) {
+    if !matches!(
+        message,
+        "Node.PipelineNode.Succeeded" | "Node.PipelineNode.Failed"
+    ) {
+        return;
+    }
+    let capacity = CAPACITY.load(Ordering::Relaxed);
+    if capacity == 0 {
+        return;
+    }
+
+    {
+        let mut guard = LAST_CAPTURE.lock();
+        let last = guard.get_or_insert_with(HashMap::new);
+        let now = Instant::now();
+        let min_interval = MIN_INTERVAL_MS.load(Ordering::Relaxed);
+        if let Some(prev) = last.get(instance_id) {
+            if now.duration_since(*prev).as_millis() < min_interval as u128 {
+                return;
+            }
+        }
+        last.insert(instance_id.to_string(), now);
+    }
+
+    let node = serde_json::from_str::<serde_json::Value>(details)
+        .ok()
+        .and_then(|d| d.get("name").and_then(|n| n.as_str()).map(str::to_string))
+        .unwrap_or_default();
+
+    let Some(state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+    let data = {
+        // 回调线程上不阻塞等待实例锁（持锁方可能正在销毁句柄并等待回调线程退出）
+        let Some(instance) = state.instances.try_get(instance_id).try_unwrap() else {
+            return;
+        };
+        let Some(controller) = instance.controller.as_ref() else {
+            return;
+        };
+        match controller.cached_image().ok().and_then(|img| img.to_vec()) {
+            Some(data) if !data.is_empty() => data,
+            _ => return,
+        }
+    };
+
+    let writer = WRITER.get_or_init(|| {
+        let (tx, rx) = mpsc::channel::<PendingCapture>();
+        std::thread::spawn(move || {
+            while let Ok(pending) = rx.recv() {
+                if let Err(e) = save(&pending) {
+                    warn!(
+                        "Failed to save screenshot history for {}: {}",
+                        pending.instance_id, e
+                    );
+                }
+            }
+        });
+        Mutex::new(tx)
+    });
+    let _ = writer.lock().send(PendingCapture {
+        instance_id: instance_id.to_string(),
+        node,
+        data,
+        captured_at: Local::now(),
+        capacity: capacity as usize,
+    });
+}
+
+/// 写入截图并删除超出容量的旧截图（后台写入线程）
+fn save(pending: &PendingCapture) -> Result<(), String> {
+    let dir = history_dir(&pending.instance_id)?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| tr!("error.fs.create_dir", path = dir.display(), error = e))?;
+
+    let name = format!(
+        "{}_{}.png",
+        pending.captured_at.format(TIMESTAMP_FORMAT),
+        sanitize(&pending.node)
+    );
+    let path = dir.join(name);
+    std::fs::write(&path, &pending.data)
+        .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))?;
+
+    let capacity = pending.capacity;
+    let mut files = list_pngs(&dir);
+    if files.len() > capacity {
+        files.sort();
+        for old in &files[..files.len() - capacity] {
+            let _ = std::fs::remove_file(old);
+        }
+    }
+    Ok(())
+}
+
+fn list_pngs(dir: &std::path::Path) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|ext| ext == "png"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 由文件名解析截图历史（`<时间戳>_<节点>.png`）
+fn parse_entry(instance_id: &str, path: PathBuf) -> Option<ScreenshotHistoryEntry> {
+    let stem = path.file_stem()?.to_str()?;
+    let (timestamp, node) = stem.split_once('_').unwrap_or((stem, ""));
+    let captured_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT)
+        .ok()?
+        .format("%Y-%m-%d %H:%M:%S%.3f")
+        .to_string();
+    Some(ScreenshotHistoryEntry {
+        instance_id: instance_id.to_string(),
+        captured_at,
+        node: node.to_string(),
+        image_path: path.to_string_lossy().to_string(),
+    })
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取实例的截图历史（按时间倒序，`limit` 缺省时返回全部）
+#[tauri::command]
+pub fn maa_get_screenshot_history(
+    instance_id: String,
+    limit: Option<usize>,
+) -> Result<Vec<ScreenshotHistoryEntry>, String> {
+    let dir = history_dir(&instance_id)?;
+    let mut files = list_pngs(&dir);
+    // 文件名以时间戳开头，按字典序倒序即为时间倒序
+    files.sort_by(|a, b| b.cmp(a));
+    Ok(files
+        .into_iter()
+        .filter_map(|p| parse_entry(&instance_id, p))
+        .take(limit.unwrap_or(usize::MAX))
+        .collect())
+}
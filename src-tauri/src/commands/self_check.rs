@@ -0,0 +1,407 @@
+//! 启动自检
+//!
+//! 汇总运行环境的各项检查结果（MaaFramework、Agent、ADB、WebView2、VC++ 运行库、
+//! 目录写权限、路径长度、系统时钟），供首次运行向导展示清单并引导用户修复。
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use log::info;
+use tauri::State;
+
+use super::maa_agent::resolve_child_exec_path;
+use super::maa_core::check_version_compatibility;
+use super::system::webview2_runtime_info;
+use super::types::{AgentConfig, MaaState, SelfCheckItem, SelfCheckReport, SelfCheckStatus};
+use super::utils::{get_exe_directory, get_maafw_dir, get_maafw_library_path};
+use crate::i18n::tr;
+
+impl SelfCheckItem {
+    fn new(id: &str, status: SelfCheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// 在 PATH 中查找可执行文件（Windows 下自动补全 .exe）
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let file_name = if cfg!(windows) && Path::new(name).extension().is_none() {
+        format!("{}.exe", name)
+    } else {
+        name.to_string()
+    };
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(&file_name))
+        .find(|p| p.is_file())
+}
+
+/// 检查 maafw 目录与动态库文件是否存在
+fn check_maafw_dir(maafw_dir: &Path) -> SelfCheckItem {
+    let lib_path = get_maafw_library_path(maafw_dir);
+    if !maafw_dir.is_dir() {
+        SelfCheckItem::new(
+            "maafw_dir",
+            SelfCheckStatus::Fail,
+            tr!("self_check.maafw_dir_missing", path = maafw_dir.display()),
+        )
+    } else if !lib_path.is_file() {
+        SelfCheckItem::new(
+            "maafw_dir",
+            SelfCheckStatus::Fail,
+            tr!("self_check.maafw_lib_missing", path = lib_path.display()),
+        )
+    } else {
+        SelfCheckItem::new(
+            "maafw_dir",
+            SelfCheckStatus::Pass,
+            maafw_dir.display().to_string(),
+        )
+    }
+}
+
+/// 加载 MaaFramework 并检查版本兼容性，返回（库加载，版本兼容）两项
+fn check_maafw_library(maafw_dir: &Path) -> (SelfCheckItem, SelfCheckItem) {
+    let lib_path = get_maafw_library_path(maafw_dir);
+    if !lib_path.is_file() {
+        return (
+            SelfCheckItem::new(
+                "maafw_load",
+                SelfCheckStatus::Skip,
+                tr!("self_check.lib_missing"),
+            ),
+            SelfCheckItem::new(
+                "maafw_version",
+                SelfCheckStatus::Skip,
+                tr!("self_check.lib_missing"),
+            ),
+        );
+    }
+
+    if let Err(e) = maa_framework::load_library(&lib_path) {
+        if !e.contains("already loaded") {
+            return (
+                SelfCheckItem::new(
+                    "maafw_load",
+                    SelfCheckStatus::Fail,
+                    tr!("self_check.load_failed", error = e),
+                ),
+                SelfCheckItem::new(
+                    "maafw_version",
+                    SelfCheckStatus::Skip,
+                    tr!("self_check.lib_not_loaded"),
+                ),
+            );
+        }
+    }
+
+    let version = match std::panic::catch_unwind(|| maa_framework::maa_version().to_string()) {
+        Ok(v) if !v.is_empty() && v != "unknown" => v,
+        _ => {
+            return (
+                SelfCheckItem::new(
+                    "maafw_load",
+                    SelfCheckStatus::Fail,
+                    tr!("self_check.version_unreadable"),
+                ),
+                SelfCheckItem::new(
+                    "maafw_version",
+                    SelfCheckStatus::Skip,
+                    tr!("self_check.lib_not_loaded"),
+                ),
+            );
+        }
+    };
+
+    let load_item = SelfCheckItem::new("maafw_load", SelfCheckStatus::Pass, version.clone());
+    let version_item = match check_version_compatibility(version) {
+        Ok(r) if r.is_compatible => {
+            SelfCheckItem::new("maafw_version", SelfCheckStatus::Pass, r.current)
+        }
+        Ok(r) => SelfCheckItem::new(
+            "maafw_version",
+            SelfCheckStatus::Fail,
+            tr!(
+                "self_check.version_too_old",
+                current = r.current,
+                minimum = r.minimum
+            ),
+        ),
+        Err(e) => SelfCheckItem::new("maafw_version", SelfCheckStatus::Fail, e),
+    };
+    (load_item, version_item)
+}
+
+/// 检查 Agent 可执行文件是否存在（裸命令名在 PATH 中查找）
+fn check_agents(agents: &[AgentConfig], cwd: &str) -> SelfCheckItem {
+    if agents.is_empty() {
+        return SelfCheckItem::new("agent", SelfCheckStatus::Skip, tr!("self_check.no_agent"));
+    }
+
+    let mut missing = Vec::new();
+    for agent in agents {
+        // 远程 Agent 不在本机启动
+        if agent
+            .remote
+            .as_deref()
+            .is_some_and(|r| !r.trim().is_empty())
+        {
+            continue;
+        }
+        let child_exec = agent.child_exec.trim();
+        if child_exec.is_empty() {
+            missing.push(tr!("self_check.child_exec_empty"));
+            continue;
+        }
+        let exec_path = resolve_child_exec_path(child_exec, cwd);
+        let found = if exec_path.components().count() == 1 {
+            find_in_path(child_exec).is_some()
+        } else {
+            exec_path.is_file() || (cfg!(windows) && exec_path.with_extension("exe").is_file())
+        };
+        if !found {
+            missing.push(exec_path.display().to_string());
+        }
+    }
+
+    if missing.is_empty() {
+        SelfCheckItem::new(
+            "agent",
+            SelfCheckStatus::Pass,
+            tr!("self_check.agents_ok", count = agents.len()),
+        )
+    } else {
+        SelfCheckItem::new(
+            "agent",
+            SelfCheckStatus::Fail,
+            tr!("self_check.not_found", items = missing.join(", ")),
+        )
+    }
+}
+
+/// 检查 ADB 是否可用（PATH 或已搜索到的设备所用 adb）
+fn check_adb(state: &Arc<MaaState>) -> SelfCheckItem {
+    if let Some(path) = find_in_path("adb") {
+        return SelfCheckItem::new("adb", SelfCheckStatus::Pass, path.display().to_string());
+    }
+
+    let cached_adb = state
+        .cached_adb_devices
+        .lock()
+        .iter()
+        .map(|d| PathBuf::from(&d.adb_path))
+        .find(|p| p.is_file());
+    match cached_adb {
+        Some(path) => SelfCheckItem::new("adb", SelfCheckStatus::Pass, path.display().to_string()),
+        // 模拟器自带 adb 时无需 PATH 中存在，仅作提示
+        None => SelfCheckItem::new(
+            "adb",
+            SelfCheckStatus::Warn,
+            tr!("self_check.adb_not_in_path"),
+        ),
+    }
+}
+
+/// 检查 WebView2 运行时（仅 Windows）
+fn check_webview2() -> SelfCheckItem {
+    if !cfg!(windows) {
+        return SelfCheckItem::new(
+            "webview2",
+            SelfCheckStatus::Skip,
+            tr!("self_check.not_windows"),
+        );
+    }
+
+    let info = webview2_runtime_info();
+    if let Some(reason) = info.disabled_reason.filter(|_| info.channel != "fixed") {
+        return SelfCheckItem::new(
+            "webview2",
+            SelfCheckStatus::Warn,
+            tr!("self_check.webview2_disabled", reason = reason),
+        );
+    }
+    match info.channel.as_str() {
+        "fixed" => SelfCheckItem::new(
+            "webview2",
+            SelfCheckStatus::Pass,
+            tr!(
+                "self_check.webview2_fixed",
+                path = info.fixed_runtime_dir.unwrap_or_default()
+            ),
+        ),
+        "system" => SelfCheckItem::new(
+            "webview2",
+            SelfCheckStatus::Pass,
+            info.installed_version.unwrap_or_default(),
+        ),
+        _ => SelfCheckItem::new(
+            "webview2",
+            SelfCheckStatus::Fail,
+            tr!("self_check.webview2_missing"),
+        ),
+    }
+}
+
+/// 检查 VC++ 运行库（仅 Windows，检查 System32 下的关键 DLL）
+fn check_vcredist() -> SelfCheckItem {
+    #[cfg(windows)]
+    {
+        let Ok(system_dir) = winsafe::GetSystemDirectory() else {
+            return SelfCheckItem::new(
+                "vcredist",
+                SelfCheckStatus::Warn,
+                tr!("self_check.system_dir_unavailable"),
+            );
+        };
+        let missing: Vec<&str> = ["vcruntime140.dll", "vcruntime140_1.dll", "msvcp140.dll"]
+            .into_iter()
+            .filter(|dll| !Path::new(&system_dir).join(dll).is_file())
+            .collect();
+        if missing.is_empty() {
+            SelfCheckItem::new(
+                "vcredist",
+                SelfCheckStatus::Pass,
+                tr!("self_check.installed"),
+            )
+        } else {
+            SelfCheckItem::new(
+                "vcredist",
+                SelfCheckStatus::Fail,
+                tr!("self_check.missing", items = missing.join(", ")),
+            )
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        SelfCheckItem::new(
+            "vcredist",
+            SelfCheckStatus::Skip,
+            tr!("self_check.not_windows"),
+        )
+    }
+}
+
+/// 检查 exe 目录是否可写（更新、缓存、日志均依赖）
+fn check_exe_dir_writable() -> SelfCheckItem {
+    let exe_dir = match get_exe_directory() {
+        Ok(dir) => dir,
+        Err(e) => return SelfCheckItem::new("exe_dir_writable", SelfCheckStatus::Fail, e),
+    };
+
+    let probe = exe_dir.join(format!(".mxu_write_test_{}", std::process::id()));
+    match std::fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+            SelfCheckItem::new(
+                "exe_dir_writable",
+                SelfCheckStatus::Pass,
+                exe_dir.display().to_string(),
+            )
+        }
+        Err(e) => SelfCheckItem::new(
+            "exe_dir_writable",
+            SelfCheckStatus::Fail,
+            tr!(
+                "self_check.not_writable",
+                path = exe_dir.display(),
+                error = e
+            ),
+        ),
+    }
+}
+
+/// 检查系统时钟与 NTP 标准时间的偏差（无网络时跳过）
+fn check_clock_drift() -> SelfCheckItem {
+    match super::clock_drift::check_clock_drift_impl(None) {
+        Ok(report) if report.drifted => SelfCheckItem::new(
+            "clock_drift",
+            SelfCheckStatus::Warn,
+            tr!(
+                "self_check.clock_drifted",
+                seconds = format!("{:.1}", report.offset_ms as f64 / 1000.0)
+            ),
+        ),
+        Ok(report) => SelfCheckItem::new(
+            "clock_drift",
+            SelfCheckStatus::Pass,
+            tr!(
+                "self_check.clock_ok",
+                offset = report.offset_ms,
+                server = report.server
+            ),
+        ),
+        Err(e) => SelfCheckItem::new("clock_drift", SelfCheckStatus::Skip, e),
+    }
+}
+
+/// 检查安装目录路径长度（Windows 路径长度限制）
+fn check_long_paths() -> SelfCheckItem {
+    match super::long_path::check_long_paths_impl() {
+        Ok(report) => {
+            let status = match report.status.as_str() {
+                "fail" => SelfCheckStatus::Fail,
+                "warn" => SelfCheckStatus::Warn,
+                _ => SelfCheckStatus::Pass,
+            };
+            SelfCheckItem::new("long_path", status, report.message)
+        }
+        Err(e) => SelfCheckItem::new("long_path", SelfCheckStatus::Skip, e),
+    }
+}
+
+/// 执行启动自检，返回各检查项结果
+///
+/// `agents` / `cwd` 来自 interface.json 中的 agent 配置，未提供时跳过 Agent 检查。
+#[tauri::command]
+pub async fn mxu_self_check(
+    state: State<'_, Arc<MaaState>>,
+    agents: Option<Vec<AgentConfig>>,
+    cwd: Option<String>,
+) -> Result<SelfCheckReport, String> {
+    info!("mxu_self_check called");
+    let state = state.inner().clone();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let maafw_dir = match state.lib_dir.lock().clone() {
+            Some(dir) => dir,
+            None => get_maafw_dir()?,
+        };
+        let cwd = match cwd {
+            Some(cwd) => cwd,
+            None => get_exe_directory()?.to_string_lossy().to_string(),
+        };
+
+        let (load_item, version_item) = check_maafw_library(&maafw_dir);
+        let items = vec![
+            check_maafw_dir(&maafw_dir),
+            load_item,
+            version_item,
+            check_agents(agents.as_deref().unwrap_or_default(), &cwd),
+            check_adb(&state),
+            check_webview2(),
+            check_vcredist(),
+            check_exe_dir_writable(),
+            check_long_paths(),
+            check_clock_drift(),
+        ];
+
+        let passed = items.iter().all(|i| i.status != SelfCheckStatus::Fail);
+        info!(
+            "mxu_self_check done: passed={}, failed=[{}]",
+            passed,
+            items
+                .iter()
+                .filter(|i| i.status == SelfCheckStatus::Fail)
+                .map(|i| i.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        Ok(SelfCheckReport { passed, items })
+    })
+    .await
+    .map_err(|e| tr!("error.task_panicked", error = e))?
+}
@@ -0,0 +1,208 @@
+//! 游戏服务器每日重置时间
+//!
+//! 每个实例（配置）可在 `instances[].serverReset` 中设置服务器的每日重置时刻与时区，
+//! 格式为 `{ "hour": 4, "timezone": "UTC+8" }`：
+//! - `hour`: 重置整点（0-23，默认 4）
+//! - `timezone`: 服务器时区，固定 UTC 偏移（`UTC+8`、`+08:00`、`-0530` 等），缺省使用本机时区
+//!
+//! `mxu_time_until_reset` 据此计算距下次重置的时间；定时执行的"重置后执行"策略、
+//! MXU_COUNTER 的每日 / 每周重置也使用同一时钟。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+use log::warn;
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::State;
+
+use super::app_config::AppConfigState;
+use crate::i18n::tr;
+
+/// 未配置时的默认重置整点
+pub const DEFAULT_RESET_HOUR: u32 = 4;
+
+/// 服务器重置时钟
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerReset {
+    pub hour: u32,
+    /// 服务器 UTC 偏移，None 表示本机时区
+    pub offset: Option<FixedOffset>,
+}
+
+impl Default for ServerReset {
+    fn default() -> Self {
+        Self {
+            hour: DEFAULT_RESET_HOUR,
+            offset: None,
+        }
+    }
+}
+
+impl ServerReset {
+    /// 服务器时区下的当前时间
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        let local = chrono::Local::now();
+        local.with_timezone(&self.offset.unwrap_or(*local.offset()))
+    }
+
+    /// 服务器日（重置时刻前仍算前一天）
+    pub fn server_day(&self, now: DateTime<FixedOffset>) -> NaiveDate {
+        (now - chrono::Duration::hours(self.hour as i64)).date_naive()
+    }
+
+    /// 下一次重置时刻
+    pub fn next_reset(&self, now: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        let next_day = self.server_day(now) + chrono::Duration::days(1);
+        let naive = next_day
+            .and_hms_opt(self.hour, 0, 0)
+            .unwrap_or_else(|| next_day.and_time(chrono::NaiveTime::MIN));
+        now.offset()
+            .from_local_datetime(&naive)
+            .single()
+            .unwrap_or(now)
+    }
+}
+
+/// 解析固定偏移时区：`UTC+8`、`GMT-5`、`+08:00`、`+0530`
+pub fn parse_timezone(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    let rest = value
+        .strip_prefix("UTC")
+        .or_else(|| value.strip_prefix("GMT"))
+        .unwrap_or(value)
+        .trim();
+    if rest.is_empty() {
+        return FixedOffset::east_opt(0);
+    }
+    let (sign, digits) = match rest.as_bytes()[0] {
+        b'+' => (1, &rest[1..]),
+        b'-' => (-1, &rest[1..]),
+        _ => return None,
+    };
+    if !digits.is_ascii() {
+        return None;
+    }
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((h, m)) => (h, m),
+        None if digits.len() > 2 => digits.split_at(digits.len() - 2),
+        None => (digits, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// 实例 ID -> 重置时钟（由配置同步）
+static RESETS: Mutex<Option<HashMap<String, ServerReset>>> = Mutex::new(None);
+
+fn parse_entry(id: &str, value: &serde_json::Value) -> ServerReset {
+    let hour = value
+        .get("hour")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(DEFAULT_RESET_HOUR as u64)
+        .min(23) as u32;
+    let offset = match value.get("timezone").and_then(|v| v.as_str()) {
+        Some(tz) if !tz.trim().is_empty() => {
+            let parsed = parse_timezone(tz);
+            if parsed.is_none() {
+                warn!("Invalid serverReset.timezone for instance {}: {}", id, tz);
+            }
+            parsed
+        }
+        _ => None,
+    };
+    ServerReset { hour, offset }
+}
+
+/// 从配置读取所有实例的重置时钟（启动时及配置变更后调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let resets: HashMap<String, ServerReset> = config
+        .get("instances")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|inst| {
+                    let id = inst.get("id")?.as_str()?;
+                    let value = inst.get("serverReset")?;
+                    Some((id.to_string(), parse_entry(id, value)))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    *RESETS.lock() = Some(resets);
+}
+
+/// 获取实例的重置时钟（未配置时使用默认值）
+pub fn for_instance(instance_id: &str) -> ServerReset {
+    RESETS
+        .lock()
+        .as_ref()
+        .and_then(|resets| resets.get(instance_id).copied())
+        .unwrap_or_default()
+}
+
+/// 距下次重置的信息
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetInfo {
+    pub instance_id: String,
+    pub hour: u32,
+    /// 服务器 UTC 偏移（如 `+08:00`）
+    pub utc_offset: String,
+    /// 当前服务器日（`YYYY-MM-DD`）
+    pub server_day: String,
+    /// 下次重置时刻（RFC 3339）
+    pub next_reset: String,
+    pub seconds_until_reset: u64,
+}
+
+/// 按实例 ID 或名称查找实例 ID
+fn resolve_profile(config: &serde_json::Value, profile: &str) -> Option<String> {
+    let instances = config.get("instances")?.as_array()?;
+    let by = |key: &str| {
+        instances
+            .iter()
+            .find(|inst| inst.get(key).and_then(|v| v.as_str()) == Some(profile))
+            .and_then(|inst| inst.get("id")?.as_str())
+            .map(str::to_string)
+    };
+    by("id").or_else(|| by("name"))
+}
+
+/// 计算实例距下次服务器重置的时间
+pub fn time_until_reset_impl(config: &AppConfigState, profile: &str) -> Result<ResetInfo, String> {
+    let instance_id = {
+        let config = config.config.lock();
+        resolve_profile(&config, profile)
+            .ok_or_else(|| tr!("error.instance_not_found_named", name = profile))?
+    };
+    let clock = for_instance(&instance_id);
+    let now = clock.now();
+    let next = clock.next_reset(now);
+    Ok(ResetInfo {
+        instance_id,
+        hour: clock.hour,
+        utc_offset: now.format("%:z").to_string(),
+        server_day: clock.server_day(now).to_string(),
+        next_reset: next.to_rfc3339(),
+        seconds_until_reset: (next - now).num_seconds().max(0) as u64,
+    })
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 查询实例（按 ID 或名称）距下次服务器每日重置的时间
+#[tauri::command]
+pub fn mxu_time_until_reset(
+    config: State<Arc<AppConfigState>>,
+    profile: String,
+) -> Result<ResetInfo, String> {
+    time_until_reset_impl(&config, &profile)
+}
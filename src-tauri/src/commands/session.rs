@@ -0,0 +1,279 @@
+//! 会话快照与启动恢复
+//!
+//! 实例状态变化时将 `AllInstanceStates` 与各实例的控制器配置、资源路径写入
+//! `<数据目录>/cache/session.json`。崩溃或重启后调用 `maa_restore_session`
+//! 按快照重新创建实例、连接控制器并加载资源，无需手动重新配置。
+//!
+//! Win32 / Gamepad 控制器的窗口句柄在目标程序重启后会失效，此类实例恢复失败时
+//! 记录在结果的 `failed` 中，由用户重新选择窗口。
+
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc, OnceLock};
+use std::time::Duration;
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use super::types::{AllInstanceStates, ControllerConfig, MaaState};
+use super::utils::{emit_callback_event, get_app_data_dir};
+use crate::i18n::tr;
+
+const SESSION_FILE: &str = "session.json";
+
+/// 合并连续状态变化的等待时间，期间的多次变化只写一次快照
+const COALESCE_DELAY: Duration = Duration::from_millis(500);
+
+/// 后台写入线程的通知通道（首次保存时创建）
+static WRITER: OnceLock<Mutex<mpsc::Sender<AppHandle>>> = OnceLock::new();
+
+/// 单个实例的可恢复配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionInstance {
+    pub instance_id: String,
+    pub controller_config: Option<ControllerConfig>,
+    pub resource_paths: Vec<String>,
+}
+
+/// 会话快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    /// 保存时间（RFC 3339）
+    pub saved_at: String,
+    pub states: AllInstanceStates,
+    pub instances: Vec<SessionInstance>,
+}
+
+/// 恢复失败的实例
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRestoreFailure {
+    pub instance_id: String,
+    pub error: String,
+}
+
+/// 会话恢复结果
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionRestoreResult {
+    /// 快照保存时间，无快照时为 None
+    pub saved_at: Option<String>,
+    pub restored: Vec<String>,
+    pub failed: Vec<SessionRestoreFailure>,
+}
+
+fn session_path() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join("cache").join(SESSION_FILE))
+}
+
+fn build_snapshot(state: &MaaState) -> Result<SessionSnapshot, String> {
+    let states = super::state::collect_all_states(state)?;
+    let mut list: Vec<SessionInstance> = state
+        .instances
+        .iter()
+        .map(|item| {
+            let (id, runtime) = item.pair();
+            SessionInstance {
+                instance_id: id.clone(),
+                controller_config: runtime.controller_config.clone(),
+                resource_paths: runtime
+                    .resource_paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect(),
+            }
+        })
+        .collect();
+    list.sort_by(|a, b| a.instance_id.cmp(&b.instance_id));
+    Ok(SessionSnapshot {
+        saved_at: chrono::Local::now().to_rfc3339(),
+        states,
+        instances: list,
+    })
+}
+
+/// 请求保存当前会话（由 `emit_state_changed` 在状态变化时调用）
+///
+/// 只通知后台写入线程，不在调用线程上做序列化和文件 I/O。
+pub fn save_snapshot(app: &AppHandle) {
+    let writer = WRITER.get_or_init(|| {
+        let (tx, rx) = mpsc::channel::<AppHandle>();
+        std::thread::spawn(move || {
+            while let Ok(mut app) = rx.recv() {
+                std::thread::sleep(COALESCE_DELAY);
+                if let Some(latest) = rx.try_iter().last() {
+                    app = latest;
+                }
+                write_snapshot(&app);
+            }
+        });
+        Mutex::new(tx)
+    });
+    let _ = writer.lock().send(app.clone());
+}
+
+/// 立即将当前会话写入磁盘（后台写入线程，以及重启前需要同步落盘的场景）
+pub fn write_snapshot(app: &AppHandle) {
+    let Some(state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+    let result = (|| -> Result<(), String> {
+        let snapshot = build_snapshot(&state)?;
+        let path = session_path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| tr!("error.fs.create_dir", path = dir.display(), error = e))?;
+        }
+        let content = serde_json::to_string_pretty(&snapshot)
+            .map_err(|e| tr!("error.serialize", error = e))?;
+
+        // 原子写，避免进程被杀时留下半个文件
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, content).map_err(|e| {
+            let _ = std::fs::remove_file(&tmp_path);
+            tr!("error.fs.write_file", path = tmp_path.display(), error = e)
+        })?;
+        std::fs::rename(&tmp_path, &path).map_err(|e| {
+            let _ = std::fs::remove_file(&tmp_path);
+            tr!("error.fs.rename", path = path.display(), error = e)
+        })
+    })();
+    if let Err(e) = result {
+        warn!("Failed to save session snapshot: {}", e);
+    }
+}
+
+/// 读取磁盘上的会话快照
+pub fn load_snapshot() -> Result<Option<SessionSnapshot>, String> {
+    let path = session_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| tr!("error.fs.read_file", path = path.display(), error = e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| tr!("error.config.parse_file", file = path.display(), error = e))
+}
+
+/// 按快照恢复单个实例：创建实例、连接控制器、加载资源
+async fn restore_instance(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    entry: SessionInstance,
+) -> Result<(), String> {
+    state
+        .instances
+        .entry(entry.instance_id.clone())
+        .or_default();
+
+    if let Some(config) = entry.controller_config {
+        let already_connected = state
+            .instances
+            .get(&entry.instance_id)
+            .is_some_and(|i| i.controller.as_ref().is_some_and(|c| c.connected()));
+        if !already_connected {
+            let app_for_event = app.clone();
+            let id = entry.instance_id.clone();
+            super::maa_core::connect_controller_impl(
+                state.clone(),
+                entry.instance_id.clone(),
+                config,
+                Arc::new(move |msg, detail| emit_callback_event(&app_for_event, &id, msg, detail)),
+            )
+            .await?;
+        }
+    }
+
+    if !entry.resource_paths.is_empty() {
+        let app_for_event = app.clone();
+        let id = entry.instance_id.clone();
+        super::maa_core::load_resource_impl(
+            state,
+            &entry.instance_id,
+            &entry.resource_paths,
+            Arc::new(move |msg, detail| emit_callback_event(&app_for_event, &id, msg, detail)),
+            Some(app),
+        )?;
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 按上次保存的会话快照恢复实例（启动时调用）
+///
+/// 已存在且已连接的实例不会重复连接；资源路径重复加载时由 MaaFramework 覆盖。
+#[tauri::command]
+pub async fn maa_restore_session(
+    app: AppHandle,
+    state: State<'_, Arc<MaaState>>,
+) -> Result<SessionRestoreResult, String> {
+    let Some(snapshot) = load_snapshot()? else {
+        info!("maa_restore_session: no session snapshot");
+        return Ok(SessionRestoreResult::default());
+    };
+    info!(
+        "maa_restore_session: restoring {} instance(s) saved at {}",
+        snapshot.instances.len(),
+        snapshot.saved_at
+    );
+
+    // 恢复设备缓存，前端可直接展示上次搜索到的设备
+    {
+        let mut adb = state.cached_adb_devices.lock();
+        if adb.is_empty() {
+            *adb = snapshot.states.cached_adb_devices.clone();
+        }
+        let mut wlroots = state.cached_wlroots_sockets.lock();
+        if wlroots.is_empty() {
+            *wlroots = snapshot.states.cached_wlroots_sockets.clone();
+        }
+    }
+
+    let state = state.inner().clone();
+    let mut result = SessionRestoreResult {
+        saved_at: Some(snapshot.saved_at.clone()),
+        ..Default::default()
+    };
+    for entry in snapshot.instances {
+        let instance_id = entry.instance_id.clone();
+        match restore_instance(&app, &state, entry).await {
+            Ok(()) => {
+                super::utils::emit_state_changed(&app, &instance_id, "session-restored");
+                result.restored.push(instance_id);
+            }
+            Err(error) => {
+                warn!(
+                    "maa_restore_session: failed to restore {}: {}",
+                    instance_id, error
+                );
+                result
+                    .failed
+                    .push(SessionRestoreFailure { instance_id, error });
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// 获取磁盘上的会话快照（不执行恢复）
+#[tauri::command]
+pub fn maa_get_session_snapshot() -> Result<Option<SessionSnapshot>, String> {
+    load_snapshot()
+}
+
+/// 删除会话快照
+#[tauri::command]
+pub fn maa_clear_session() -> Result<(), String> {
+    let path = session_path()?;
+    if path.exists() {
+        std::fs::remove_file(&path)
+            .map_err(|e| tr!("error.fs.remove", path = path.display(), error = e))?;
+    }
+    Ok(())
+}
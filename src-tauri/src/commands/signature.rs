@@ -0,0 +1,63 @@
+//! 发布签名校验（Ed25519）
+//!
+//! 发布构建时通过环境变量 `MXU_RELEASE_PUBLIC_KEY`（Base64 编码的 32 字节 Ed25519 公钥）
+//! 将公钥编译进程序；签名为 Base64 编码的 64 字节 Ed25519 签名（分离式，独立存放在 `.sig` 文件中）。
+//! 本地开发构建未设置公钥时无法校验签名，调用方据此决定跳过或拒绝。
+//!
+//! - 小文件（如完整性清单）直接对文件内容签名
+//! - 更新包等大文件对其 SHA-256 摘要的小写十六进制字符串签名，以便流式计算
+
+use std::path::Path;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ring::signature::{UnparsedPublicKey, ED25519};
+
+use crate::i18n::tr;
+
+/// 编译时注入的发布公钥
+const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("MXU_RELEASE_PUBLIC_KEY");
+
+/// 签名文件扩展名
+pub const SIGNATURE_EXTENSION: &str = "sig";
+
+fn public_key() -> Option<Vec<u8>> {
+    let key = STANDARD.decode(RELEASE_PUBLIC_KEY?.trim()).ok()?;
+    (key.len() == 32).then_some(key)
+}
+
+/// 当前构建是否内置了发布公钥
+pub fn has_public_key() -> bool {
+    public_key().is_some()
+}
+
+/// 使用内置公钥校验 Base64 签名
+pub fn verify(message: &[u8], signature_b64: &str) -> Result<(), String> {
+    let key = public_key().ok_or_else(|| tr!("error.signature.no_public_key"))?;
+    let signature = STANDARD
+        .decode(signature_b64.trim())
+        .map_err(|e| tr!("error.signature.invalid_format", error = e))?;
+    UnparsedPublicKey::new(&ED25519, key)
+        .verify(message, &signature)
+        .map_err(|_| tr!("error.signature.mismatch"))
+}
+
+/// `path` 对应的签名文件路径（`xxx.zip` -> `xxx.zip.sig`）
+pub fn signature_path(path: &Path) -> std::path::PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(SIGNATURE_EXTENSION);
+    name.into()
+}
+
+/// 校验大文件的分离签名（签名对象为文件 SHA-256 摘要的小写十六进制字符串）
+pub fn verify_file_digest(path: &Path, signature_file: &Path) -> Result<(), String> {
+    let signature = std::fs::read_to_string(signature_file).map_err(|_| {
+        tr!(
+            "error.signature.missing_file",
+            path = signature_file.display()
+        )
+    })?;
+    let digest = super::file_ops::digest_file::<sha2::Sha256>(path)?;
+    verify(digest.as_bytes(), &signature)
+}
@@ -8,6 +8,7 @@ use std::sync::Arc;
 
 use tauri::State;
 
+use super::error::MxuError;
 use super::types::{AdbDevice, AllInstanceStates, InstanceState, MaaState, Win32Window};
 
 /// 获取单个实例的运行时状态
@@ -15,16 +16,17 @@ use super::types::{AdbDevice, AllInstanceStates, InstanceState, MaaState, Win32W
 pub fn maa_get_instance_state(
     state: State<Arc<MaaState>>,
     instance_id: String,
-) -> Result<InstanceState, String> {
+) -> Result<InstanceState, MxuError> {
     debug!(
         "maa_get_instance_state called, instance_id: {}",
         instance_id
     );
 
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let instance = instances
+    let mut guard = state
+        .instances
         .get_mut(&instance_id)
-        .ok_or("Instance not found")?;
+        .ok_or_else(MxuError::instance_not_found)?;
+    let instance = guard.value_mut();
 
     // 通过 Maa API 查询真实状态
     let is_running = instance.tasker.as_ref().is_some_and(|t| t.running());
@@ -43,25 +45,16 @@ pub fn maa_get_instance_state(
     })
 }
 
-/// 获取所有实例的状态快照（用于前端启动时恢复状态）
-#[tauri::command]
-pub fn maa_get_all_states(state: State<Arc<MaaState>>) -> Result<AllInstanceStates, String> {
-    debug!("maa_get_all_states called");
-
-    let mut instances = state.instances.lock().map_err(|e| e.to_string())?;
-    let cached_adb = state.cached_adb_devices.lock().map_err(|e| e.to_string())?;
-    let cached_win32 = state
-        .cached_win32_windows
-        .lock()
-        .map_err(|e| e.to_string())?;
-    let cached_wlroots = state
-        .cached_wlroots_sockets
-        .lock()
-        .map_err(|e| e.to_string())?;
+/// 收集所有实例的状态快照（供 Tauri 命令与会话持久化共享）
+pub fn collect_all_states(state: &MaaState) -> Result<AllInstanceStates, String> {
+    let cached_adb = state.cached_adb_devices.lock();
+    let cached_win32 = state.cached_win32_windows.lock();
+    let cached_wlroots = state.cached_wlroots_sockets.lock();
 
     let mut instance_states = HashMap::new();
 
-    for (id, instance) in instances.iter_mut() {
+    for mut item in state.instances.iter_mut() {
+        let (id, instance) = item.pair_mut();
         // 通过 Maa API 查询真实状态
         let is_running = instance.tasker.as_ref().is_some_and(|t| t.running());
 
@@ -90,11 +83,18 @@ pub fn maa_get_all_states(state: State<Arc<MaaState>>) -> Result<AllInstanceStat
     })
 }
 
+/// 获取所有实例的状态快照（用于前端启动时恢复状态）
+#[tauri::command]
+pub fn maa_get_all_states(state: State<Arc<MaaState>>) -> Result<AllInstanceStates, MxuError> {
+    debug!("maa_get_all_states called");
+    Ok(collect_all_states(&state)?)
+}
+
 /// 获取缓存的 ADB 设备列表
 #[tauri::command]
-pub fn maa_get_cached_adb_devices(state: State<Arc<MaaState>>) -> Result<Vec<AdbDevice>, String> {
+pub fn maa_get_cached_adb_devices(state: State<Arc<MaaState>>) -> Result<Vec<AdbDevice>, MxuError> {
     debug!("maa_get_cached_adb_devices called");
-    let cached = state.cached_adb_devices.lock().map_err(|e| e.to_string())?;
+    let cached = state.cached_adb_devices.lock();
     Ok(cached.clone())
 }
 
@@ -102,23 +102,19 @@ pub fn maa_get_cached_adb_devices(state: State<Arc<MaaState>>) -> Result<Vec<Adb
 #[tauri::command]
 pub fn maa_get_cached_win32_windows(
     state: State<Arc<MaaState>>,
-) -> Result<Vec<Win32Window>, String> {
+) -> Result<Vec<Win32Window>, MxuError> {
     debug!("maa_get_cached_win32_windows called");
-    let cached = state
-        .cached_win32_windows
-        .lock()
-        .map_err(|e| e.to_string())?;
+    let cached = state.cached_win32_windows.lock();
     Ok(cached.clone())
 }
 
 /// 获取缓存的 WlRoots socket 列表
 #[tauri::command]
-pub fn maa_get_cached_wlroots_sockets(state: State<Arc<MaaState>>) -> Result<Vec<String>, String> {
+pub fn maa_get_cached_wlroots_sockets(
+    state: State<Arc<MaaState>>,
+) -> Result<Vec<String>, MxuError> {
     debug!("maa_get_cached_wlroots_sockets called");
-    let cached = state
-        .cached_wlroots_sockets
-        .lock()
-        .map_err(|e| e.to_string())?;
+    let cached = state.cached_wlroots_sockets.lock();
     Ok(cached.clone())
 }
 
@@ -138,7 +134,7 @@ pub fn push_log(
     instance_id: String,
     entry: super::types::LogEntryDto,
 ) -> Result<(), String> {
-    let mut buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
+    let mut buffer = state.log_buffer.lock();
     buffer.push(&instance_id, entry);
     Ok(())
 }
@@ -148,7 +144,7 @@ pub fn push_log(
 pub fn get_all_logs(
     state: State<Arc<MaaState>>,
 ) -> Result<HashMap<String, Vec<super::types::LogEntryDto>>, String> {
-    let buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
+    let buffer = state.log_buffer.lock();
     Ok(buffer
         .get_all()
         .iter()
@@ -159,7 +155,33 @@ pub fn get_all_logs(
 /// 清空指定实例的运行日志
 #[tauri::command]
 pub fn clear_instance_logs(state: State<Arc<MaaState>>, instance_id: String) -> Result<(), String> {
-    let mut buffer = state.log_buffer.lock().map_err(|e| e.to_string())?;
+    let mut buffer = state.log_buffer.lock();
     buffer.clear_instance(&instance_id);
     Ok(())
 }
+
+/// 补取事件结果
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissedEvents {
+    /// 当前最新事件序号，下次补取时作为 `since_seq` 传入
+    pub last_seq: u64,
+    pub events: Vec<super::types::ReplayEvent>,
+}
+
+/// 补取序号大于 `since_seq` 的事件（页面刷新或组件晚挂载时调用）
+///
+/// 指定 `instance_id` 时只返回该实例的事件与下载进度等全局事件。
+/// 每个实例仅保留最近的事件，间隔过久时较早的事件会丢失。
+#[tauri::command]
+pub fn mxu_fetch_missed_events(
+    state: State<Arc<MaaState>>,
+    since_seq: u64,
+    instance_id: Option<String>,
+) -> MissedEvents {
+    let buffer = state.event_replay.lock();
+    MissedEvents {
+        last_seq: buffer.last_seq(),
+        events: buffer.since(since_seq, instance_id.as_deref()),
+    }
+}
@@ -0,0 +1,386 @@
+//! 运行统计导出
+//!
+//! 将运行报告（`run_report`）中的任务历史与按实例、按任务汇总的统计导出为 CSV / JSON，
+//! 便于在表格软件中统计掉落率、耗时等。
+//!
+//! - CSV：`dest` 为任务明细（每行一个任务），同目录下另写 `<文件名>-summary.csv`（按实例 + 任务汇总）
+//!   与 `<文件名>-instances.csv`（按实例汇总）
+//! - JSON：单个文件，包含 `instances`（按实例汇总）、`tasks`（按实例 + 任务汇总）与 `runs`（原始报告）
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use log::info;
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use super::app_config::AppConfigState;
+use super::run_report::{list_reports, RunReport};
+use crate::i18n::tr;
+
+/// 导出范围（日期为本地日期 `YYYY-MM-DD`，均包含边界）
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsRange {
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default)]
+    pub to: Option<String>,
+    /// 仅导出指定实例
+    #[serde(default)]
+    pub instance_id: Option<String>,
+}
+
+/// 按实例汇总的统计
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceStats {
+    pub instance_id: String,
+    pub instance_name: String,
+    pub runs: usize,
+    pub succeeded_runs: usize,
+    pub failed_runs: usize,
+    pub stopped_runs: usize,
+    pub total_duration_ms: u64,
+    pub avg_run_duration_ms: u64,
+}
+
+/// 按实例 + 任务汇总的统计
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskStats {
+    pub instance_id: String,
+    pub instance_name: String,
+    pub entry: String,
+    pub count: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub avg_duration_ms: u64,
+    pub max_duration_ms: u64,
+}
+
+/// 导出结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportStatsResult {
+    pub path: String,
+    /// CSV 格式下的按实例 + 任务汇总文件
+    pub summary_path: Option<String>,
+    /// CSV 格式下的按实例汇总文件
+    pub instances_path: Option<String>,
+    pub runs: usize,
+    pub tasks: usize,
+}
+
+/// 实例 ID -> 实例名称
+fn instance_names(config: &serde_json::Value) -> HashMap<String, String> {
+    config
+        .get("instances")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|inst| {
+                    let id = inst.get("id")?.as_str()?.to_string();
+                    let name = inst.get("name")?.as_str()?.to_string();
+                    Some((id, name))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 报告是否在范围内（按开始日期；进行中的运行不导出）
+fn in_range(report: &RunReport, range: &StatsRange) -> bool {
+    if report.status == "Running" {
+        return false;
+    }
+    let date = report.started_at.get(..10).unwrap_or(&report.started_at);
+    range.from.as_deref().is_none_or(|from| date >= from)
+        && range.to.as_deref().is_none_or(|to| date <= to)
+}
+
+fn summarize(
+    reports: &[RunReport],
+    names: &HashMap<String, String>,
+) -> (Vec<InstanceStats>, Vec<TaskStats>) {
+    let name_of = |id: &str| names.get(id).cloned().unwrap_or_else(|| id.to_string());
+    let mut instances: BTreeMap<String, InstanceStats> = BTreeMap::new();
+    // (instance_id, entry) -> (统计, 计时的任务数)
+    let mut tasks: BTreeMap<(String, String), (TaskStats, u64)> = BTreeMap::new();
+
+    for report in reports {
+        let stats = instances
+            .entry(report.instance_id.clone())
+            .or_insert_with(|| InstanceStats {
+                instance_id: report.instance_id.clone(),
+                instance_name: name_of(&report.instance_id),
+                ..Default::default()
+            });
+        stats.runs += 1;
+        match report.status.as_str() {
+            "Succeeded" => stats.succeeded_runs += 1,
+            "Stopped" => stats.stopped_runs += 1,
+            _ => stats.failed_runs += 1,
+        }
+        stats.total_duration_ms += report.duration_ms.unwrap_or(0);
+
+        for task in &report.tasks {
+            let (stats, timed) = tasks
+                .entry((report.instance_id.clone(), task.entry.clone()))
+                .or_insert_with(|| {
+                    (
+                        TaskStats {
+                            instance_id: report.instance_id.clone(),
+                            instance_name: name_of(&report.instance_id),
+                            entry: task.entry.clone(),
+                            ..Default::default()
+                        },
+                        0,
+                    )
+                });
+            stats.count += 1;
+            match task.status.as_str() {
+                "succeeded" => stats.succeeded += 1,
+                "failed" => stats.failed += 1,
+                _ => {}
+            }
+            if let Some(ms) = task.duration_ms {
+                // 暂存总耗时，最后换算为平均值
+                stats.avg_duration_ms += ms;
+                stats.max_duration_ms = stats.max_duration_ms.max(ms);
+                *timed += 1;
+            }
+        }
+    }
+
+    let instances = instances
+        .into_values()
+        .map(|mut s| {
+            s.avg_run_duration_ms = s.total_duration_ms / s.runs.max(1) as u64;
+            s
+        })
+        .collect();
+    let tasks = tasks
+        .into_values()
+        .map(|(mut s, timed)| {
+            s.avg_duration_ms /= timed.max(1);
+            s
+        })
+        .collect();
+    (instances, tasks)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_row(fields: &[String]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|f| csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// UTF-8 BOM，便于 Excel 正确识别中文
+const CSV_BOM: &str = "\u{feff}";
+
+fn render_details_csv(reports: &[RunReport], names: &HashMap<String, String>) -> String {
+    let mut out = String::from(CSV_BOM);
+    out.push_str(&csv_row(
+        &[
+            "run_id",
+            "instance_id",
+            "instance_name",
+            "run_status",
+            "entry",
+            "status",
+            "started_at",
+            "finished_at",
+            "duration_ms",
+        ]
+        .map(str::to_string),
+    ));
+    for report in reports {
+        let name = names
+            .get(&report.instance_id)
+            .map(String::as_str)
+            .unwrap_or(&report.instance_id);
+        for task in &report.tasks {
+            out.push_str(&csv_row(&[
+                report.run_id.clone(),
+                report.instance_id.clone(),
+                name.to_string(),
+                report.status.clone(),
+                task.entry.clone(),
+                task.status.clone(),
+                task.started_at.clone().unwrap_or_default(),
+                task.finished_at.clone().unwrap_or_default(),
+                task.duration_ms
+                    .map(|ms| ms.to_string())
+                    .unwrap_or_default(),
+            ]));
+        }
+    }
+    out
+}
+
+fn render_summary_csv(tasks: &[TaskStats]) -> String {
+    let mut out = String::from(CSV_BOM);
+    out.push_str(&csv_row(
+        &[
+            "instance_id",
+            "instance_name",
+            "entry",
+            "count",
+            "succeeded",
+            "failed",
+            "avg_duration_ms",
+            "max_duration_ms",
+        ]
+        .map(str::to_string),
+    ));
+    for s in tasks {
+        out.push_str(&csv_row(&[
+            s.instance_id.clone(),
+            s.instance_name.clone(),
+            s.entry.clone(),
+            s.count.to_string(),
+            s.succeeded.to_string(),
+            s.failed.to_string(),
+            s.avg_duration_ms.to_string(),
+            s.max_duration_ms.to_string(),
+        ]));
+    }
+    out
+}
+
+fn render_instances_csv(instances: &[InstanceStats]) -> String {
+    let mut out = String::from(CSV_BOM);
+    out.push_str(&csv_row(
+        &[
+            "instance_id",
+            "instance_name",
+            "runs",
+            "succeeded_runs",
+            "failed_runs",
+            "stopped_runs",
+            "total_duration_ms",
+            "avg_run_duration_ms",
+        ]
+        .map(str::to_string),
+    ));
+    for s in instances {
+        out.push_str(&csv_row(&[
+            s.instance_id.clone(),
+            s.instance_name.clone(),
+            s.runs.to_string(),
+            s.succeeded_runs.to_string(),
+            s.failed_runs.to_string(),
+            s.stopped_runs.to_string(),
+            s.total_duration_ms.to_string(),
+            s.avg_run_duration_ms.to_string(),
+        ]));
+    }
+    out
+}
+
+/// `report.csv` + `summary` -> `report-summary.csv`
+fn sibling_path(dest: &Path, suffix: &str) -> PathBuf {
+    let stem = dest
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "stats".to_string());
+    dest.with_file_name(format!("{}-{}.csv", stem, suffix))
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+    }
+    std::fs::write(path, content)
+        .map_err(|e| tr!("error.fs.write_file", path = path.display(), error = e))
+}
+
+/// 导出运行统计
+pub fn export_stats_impl(
+    config: &AppConfigState,
+    range: &StatsRange,
+    format: &str,
+    dest: &str,
+) -> Result<ExportStatsResult, String> {
+    let names = instance_names(&*config.config.lock());
+    let mut reports = list_reports(range.instance_id.as_deref())?;
+    reports.retain(|r| in_range(r, range));
+    // 明细按时间正序，便于在表格中阅读
+    reports.reverse();
+    let (instances, tasks) = summarize(&reports, &names);
+    let task_count = reports.iter().map(|r| r.tasks.len()).sum();
+
+    let dest = Path::new(dest);
+    let (summary, instances_path) = match format.to_ascii_lowercase().as_str() {
+        "csv" => {
+            write_file(dest, &render_details_csv(&reports, &names))?;
+            let summary = sibling_path(dest, "summary");
+            write_file(&summary, &render_summary_csv(&tasks))?;
+            let instances_path = sibling_path(dest, "instances");
+            write_file(&instances_path, &render_instances_csv(&instances))?;
+            (
+                Some(summary.to_string_lossy().to_string()),
+                Some(instances_path.to_string_lossy().to_string()),
+            )
+        }
+        "json" => {
+            let content = serde_json::to_string_pretty(&serde_json::json!({
+                "generatedAt": chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                "range": range,
+                "instances": instances,
+                "tasks": tasks,
+                "runs": reports,
+            }))
+            .map_err(|e| tr!("error.serialize", error = e))?;
+            write_file(dest, &content)?;
+            (None, None)
+        }
+        other => return Err(tr!("error.report.unsupported_format", format = other)),
+    };
+
+    info!(
+        "Stats exported to {} ({} runs, {} tasks)",
+        dest.display(),
+        reports.len(),
+        task_count
+    );
+    Ok(ExportStatsResult {
+        path: dest.to_string_lossy().to_string(),
+        summary_path: summary,
+        instances_path,
+        runs: reports.len(),
+        tasks: task_count,
+    })
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 导出任务历史与按实例统计为 CSV / JSON
+///
+/// `format` 为 `"csv"` 或 `"json"`，`dest` 为目标文件路径。
+#[tauri::command]
+pub fn mxu_export_stats(
+    config: State<Arc<AppConfigState>>,
+    range: Option<StatsRange>,
+    format: String,
+    dest: String,
+) -> Result<ExportStatsResult, String> {
+    export_stats_impl(&config, &range.unwrap_or_default(), &format, &dest)
+}
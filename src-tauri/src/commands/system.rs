@@ -2,10 +2,13 @@
 //!
 //! 提供权限检查、系统信息查询、全局选项设置等功能
 
+use super::error::{MxuError, MxuResult};
+use super::types::DiskSpaceInfo;
 use super::types::MaaState;
 use super::types::SystemInfo;
-use super::types::WebView2DirInfo;
+use super::types::{WebView2DirInfo, WebView2RuntimeInfo, WindowRect};
 use super::utils::get_maafw_dir;
+use crate::i18n::tr;
 use log::info;
 #[cfg(windows)]
 use log::warn;
@@ -53,22 +56,89 @@ pub fn is_elevated() -> bool {
     }
 }
 
-/// 以管理员权限重启应用
-#[tauri::command]
-pub fn restart_as_admin(app_handle: tauri::AppHandle) -> Result<(), String> {
+/// 重启后恢复会话的命令行参数
+pub const RESTORE_SESSION_FLAG: &str = "--restore-session";
+
+/// 按 Windows 命令行规则为参数加引号（含空白或引号时）
+#[cfg_attr(not(windows), allow(dead_code))]
+fn quote_windows_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        return arg.to_string();
+    }
+    let mut quoted = String::from('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                // 引号前的反斜杠需加倍，引号本身转义
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    // 结尾引号前的反斜杠同样需要加倍
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// 构造提升后进程的命令行参数：沿用当前参数（含 `--autostart`、`--instance` 等），
+/// 按需追加 `--restore-session`
+pub fn elevated_restart_args(restore_session: bool) -> String {
+    let mut args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| arg != RESTORE_SESSION_FLAG)
+        .collect();
+    if restore_session {
+        args.push(RESTORE_SESSION_FLAG.to_string());
+    }
+    args.iter()
+        .map(|arg| quote_windows_arg(arg))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 以管理员权限重启应用（内部实现）
+///
+/// 新进程继承当前命令行参数与工作目录；`restore_session` 为 true 时先写入会话快照，
+/// 并传入 `--restore-session` 使新进程恢复实例连接与资源。
+pub fn restart_as_admin_impl(
+    app_handle: &tauri::AppHandle,
+    restore_session: bool,
+) -> Result<(), String> {
     #[cfg(windows)]
     {
         use winsafe::co::{SEE_MASK, SW};
         use winsafe::{ShellExecuteEx, SHELLEXECUTEINFO};
 
-        let exe_path = std::env::current_exe().map_err(|e| format!("获取程序路径失败: {}", e))?;
+        let exe_path = std::env::current_exe().map_err(|e| tr!("error.fs.exe_path", error = e))?;
 
         let exe_path_str = exe_path.to_string_lossy().to_string();
+        let parameters = elevated_restart_args(restore_session);
+        let directory = std::env::current_dir()
+            .ok()
+            .map(|d| d.to_string_lossy().to_string());
 
-        info!("restart_as_admin: restarting with admin privileges");
+        if restore_session {
+            super::session::write_snapshot(app_handle);
+        }
+
+        info!(
+            "restart_as_admin: restarting with admin privileges, args: {}, cwd: {:?}",
+            parameters, directory
+        );
 
         let result = ShellExecuteEx(&SHELLEXECUTEINFO {
             file: &exe_path_str,
+            parameters: (!parameters.is_empty()).then_some(parameters.as_str()),
+            directory: directory.as_deref(),
             verb: Option::from("runas"),
             show: SW::SHOWNORMAL,
             mask: SEE_MASK::NOASYNC | SEE_MASK::FLAG_NO_UI,
@@ -77,7 +147,7 @@ pub fn restart_as_admin(app_handle: tauri::AppHandle) -> Result<(), String> {
 
         // ShellExecuteEx 返回 Result：Ok 表示成功，Err 表示失败
         if let Err(e) = result {
-            Err(format!("以管理员身份启动失败: 错误码 {}", e.raw()))
+            Err(tr!("error.system.elevate_failed", code = e.raw()))
         } else {
             info!("restart_as_admin: new process started, exiting current");
             // 退出当前进程
@@ -88,11 +158,20 @@ pub fn restart_as_admin(app_handle: tauri::AppHandle) -> Result<(), String> {
 
     #[cfg(not(windows))]
     {
-        let _ = app_handle;
-        Err("此功能仅在 Windows 上可用".to_string())
+        let _ = (app_handle, restore_session);
+        Err(tr!("error.system.windows_only"))
     }
 }
 
+/// 以管理员权限重启应用（`restore_session` 缺省为 true）
+#[tauri::command]
+pub fn restart_as_admin(
+    app_handle: tauri::AppHandle,
+    restore_session: Option<bool>,
+) -> Result<(), String> {
+    restart_as_admin_impl(&app_handle, restore_session.unwrap_or(true))
+}
+
 /// 设置全局选项 - 保存调试图像
 #[tauri::command]
 pub fn maa_set_save_draw(enabled: bool) -> Result<bool, String> {
@@ -101,7 +180,7 @@ pub fn maa_set_save_draw(enabled: bool) -> Result<bool, String> {
             info!("保存调试图像: {}", if enabled { "启用" } else { "禁用" });
             true
         })
-        .map_err(|e| format!("设置保存调试图像失败: {}", e))
+        .map_err(|e| tr!("error.system.save_draw", error = e))
 }
 
 /// 打开文件（使用系统默认程序）
@@ -387,6 +466,177 @@ pub fn get_process_path_from_hwnd(hwnd: u64) -> Result<String, String> {
     }
 }
 
+/// 窗口附加信息（用于区分多个同名窗口）
+#[derive(Debug, Default)]
+pub(crate) struct WindowDetails {
+    pub pid: Option<u32>,
+    pub process_name: Option<String>,
+    pub rect: Option<WindowRect>,
+    pub dpi_scale: Option<f64>,
+}
+
+/// 查询窗口所属进程、窗口矩形与 DPI 缩放（非 Windows 平台返回空信息）
+pub(crate) fn query_window_details(hwnd: u64) -> WindowDetails {
+    #[cfg(windows)]
+    {
+        #[repr(C)]
+        #[derive(Default)]
+        struct Rect {
+            left: i32,
+            top: i32,
+            right: i32,
+            bottom: i32,
+        }
+        extern "system" {
+            fn GetWindowThreadProcessId(hwnd: isize, process_id: *mut u32) -> u32;
+            fn GetWindowRect(hwnd: isize, rect: *mut Rect) -> i32;
+            fn GetDpiForWindow(hwnd: isize) -> u32;
+        }
+
+        if hwnd == 0 {
+            return WindowDetails::default();
+        }
+        let raw = hwnd as isize;
+
+        let mut pid = 0u32;
+        unsafe { GetWindowThreadProcessId(raw, &mut pid) };
+        let pid = (pid != 0).then_some(pid);
+
+        let process_name = get_process_path_from_hwnd(hwnd).ok().and_then(|path| {
+            std::path::Path::new(&path)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+        });
+
+        let mut r = Rect::default();
+        let rect = (unsafe { GetWindowRect(raw, &mut r) } != 0).then(|| WindowRect {
+            x: r.left,
+            y: r.top,
+            width: r.right - r.left,
+            height: r.bottom - r.top,
+        });
+
+        let dpi = unsafe { GetDpiForWindow(raw) };
+        let dpi_scale = (dpi != 0).then(|| dpi as f64 / 96.0);
+
+        WindowDetails {
+            pid,
+            process_name,
+            rect,
+            dpi_scale,
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = hwnd;
+        WindowDetails::default()
+    }
+}
+
+/// 检查进程是否以管理员权限（提升的令牌）运行，无法判断时返回 None
+///
+/// 非提升进程通常无法读取提升进程的令牌：能打开进程但无法打开令牌时视为已提升。
+pub(crate) fn is_process_elevated(pid: u32) -> Option<bool> {
+    #[cfg(windows)]
+    {
+        use std::ffi::c_void;
+
+        extern "system" {
+            fn OpenProcess(access: u32, inherit: i32, pid: u32) -> isize;
+            fn OpenProcessToken(process: isize, access: u32, token: *mut isize) -> i32;
+            fn GetTokenInformation(
+                token: isize,
+                class: i32,
+                info: *mut c_void,
+                len: u32,
+                ret_len: *mut u32,
+            ) -> i32;
+            fn CloseHandle(handle: isize) -> i32;
+        }
+        const PROCESS_QUERY_LIMITED_INFORMATION: u32 = 0x1000;
+        const TOKEN_QUERY: u32 = 0x0008;
+        const TOKEN_ELEVATION: i32 = 20;
+
+        unsafe {
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+            if process == 0 {
+                return None;
+            }
+            let mut token = 0isize;
+            if OpenProcessToken(process, TOKEN_QUERY, &mut token) == 0 {
+                CloseHandle(process);
+                return Some(true);
+            }
+            let mut elevated = 0u32;
+            let mut ret_len = 0u32;
+            let ok = GetTokenInformation(
+                token,
+                TOKEN_ELEVATION,
+                &mut elevated as *mut u32 as *mut c_void,
+                std::mem::size_of::<u32>() as u32,
+                &mut ret_len,
+            );
+            CloseHandle(token);
+            CloseHandle(process);
+            (ok != 0).then_some(elevated != 0)
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = pid;
+        None
+    }
+}
+
+/// 将窗口置于前台（最小化时先还原），用于连接前确认目标窗口
+#[tauri::command]
+pub fn maa_focus_window(handle: u64) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        extern "system" {
+            fn IsWindow(hwnd: isize) -> i32;
+            fn IsIconic(hwnd: isize) -> i32;
+            fn ShowWindow(hwnd: isize, cmd_show: i32) -> i32;
+            fn SetForegroundWindow(hwnd: isize) -> i32;
+            fn BringWindowToTop(hwnd: isize) -> i32;
+            fn keybd_event(vk: u8, scan: u8, flags: u32, extra_info: usize);
+        }
+        const SW_RESTORE: i32 = 9;
+        const VK_MENU: u8 = 0x12;
+        const KEYEVENTF_KEYUP: u32 = 0x0002;
+
+        let raw = handle as isize;
+        if handle == 0 || unsafe { IsWindow(raw) } == 0 {
+            return Err(tr!("error.system.invalid_window"));
+        }
+
+        unsafe {
+            if IsIconic(raw) != 0 {
+                ShowWindow(raw, SW_RESTORE);
+            }
+            // 前台锁定规则下 SetForegroundWindow 可能被拒绝，
+            // 模拟一次 Alt 按键让当前进程获得设置前台窗口的权限
+            keybd_event(VK_MENU, 0, 0, 0);
+            keybd_event(VK_MENU, 0, KEYEVENTF_KEYUP, 0);
+            let ok = SetForegroundWindow(raw) != 0;
+            BringWindowToTop(raw);
+            if !ok {
+                return Err(tr!("error.system.foreground_failed"));
+            }
+        }
+        info!("maa_focus_window: hwnd={}", handle);
+        Ok(())
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = handle;
+        Err("This command is only available on Windows".to_string())
+    }
+}
+
 /// Run pre-action (launch program and optionally wait for exit)
 /// program: 程序路径
 /// args: 附加参数（空格分隔）
@@ -398,10 +648,7 @@ pub fn set_pre_action_stop(
     instance_id: String,
     stop: bool,
 ) -> Result<(), String> {
-    let mut requests = state
-        .pre_action_stop_requests
-        .lock()
-        .map_err(|e| e.to_string())?;
+    let mut requests = state.pre_action_stop_requests.lock();
     if stop {
         requests.insert(instance_id);
     } else {
@@ -464,10 +711,7 @@ pub async fn run_action(
             }
 
             let stop_requested = {
-                let requests = state
-                    .pre_action_stop_requests
-                    .lock()
-                    .map_err(|e| e.to_string())?;
+                let requests = state.pre_action_stop_requests.lock();
                 requests.contains(&instance_id)
             };
 
@@ -574,6 +818,40 @@ fn detach_parent_console_for_cli() {
     }
 }
 
+/// 唤醒显示器并退出屏幕保护程序
+///
+/// 显示器关闭时 Win32 控制器的 DXGI 截图会返回黑屏，启动 Win32 任务前调用
+#[tauri::command]
+pub fn mxu_wake_display() -> Result<(), String> {
+    if crate::mxu_actions::execute_wake_display() {
+        Ok(())
+    } else {
+        Err(tr!("error.system.wake_display_failed"))
+    }
+}
+
+/// 写入主机剪贴板文本
+#[tauri::command]
+pub fn mxu_set_clipboard(text: String) -> Result<(), String> {
+    crate::mxu_actions::clipboard_set_text(&text)
+}
+
+/// 读取主机剪贴板文本
+#[tauri::command]
+pub fn mxu_get_clipboard() -> Result<String, String> {
+    crate::mxu_actions::clipboard_get_text()
+}
+
+/// 确认 MXU_WAIT_FOR_USER 的人工介入请求，使暂停的 pipeline 继续执行
+#[tauri::command]
+pub fn mxu_confirm_intervention(token: String) -> Result<(), String> {
+    if crate::mxu_actions::confirm_intervention(&token) {
+        Ok(())
+    } else {
+        Err(tr!("error.system.intervention_not_found"))
+    }
+}
+
 /// 检查命令行是否包含 -h/--help 参数
 pub fn has_help_flag() -> bool {
     std::env::args()
@@ -615,6 +893,10 @@ MXU 命令行参数
   -q, --quit-after-run
       当本次启动实际触发自动执行后，在任务完成时自动退出
 
+  --restore-session
+      启动后按上次的会话快照恢复实例连接与资源
+      以管理员身份重启时由 MXU 自动传入
+
 示例:
   {exe_name} --autostart --instance \"日常任务\"
   {exe_name} --autostart -i \"日常任务\" --quit-after-run
@@ -625,7 +907,7 @@ MXU 命令行参数
 /// 从命令行参数中获取指定选项的值
 /// 支持 `-x value`、`--name value`、`-x=value`、`--name=value` 格式
 /// 返回第一个匹配的值；若值缺失或以 `-` 开头则视为无效并跳过
-fn get_cli_arg_value(short: &str, long: &str) -> Option<String> {
+pub(crate) fn get_cli_arg_value(short: &str, long: &str) -> Option<String> {
     let short_eq = format!("{}=", short);
     let long_eq = format!("{}=", long);
     let args: Vec<String> = std::env::args().collect();
@@ -649,12 +931,54 @@ fn get_cli_arg_value(short: &str, long: &str) -> Option<String> {
     None
 }
 
+/// MaaFramework 探测子进程参数（内部使用，不出现在帮助文本中）
+pub const MAAFW_PROBE_FLAG: &str = "--maafw-probe";
+
+/// 获取命令行 --maafw-probe 参数指定的 maafw 目录
+pub fn get_maafw_probe_dir() -> Option<String> {
+    get_cli_arg_value(MAAFW_PROBE_FLAG, MAAFW_PROBE_FLAG)
+}
+
+/// 在独立进程中加载指定目录的 MaaFramework 并将版本号输出到 stdout
+///
+/// 当前进程已加载的库无法被替换，因此更新后通过子进程验证新库能否正常加载。
+/// 返回进程退出码：0 表示加载成功。
+pub fn run_maafw_probe(maafw_dir: &str) -> i32 {
+    let lib_path = super::utils::get_maafw_library_path(std::path::Path::new(maafw_dir));
+
+    if let Err(e) = maa_framework::load_library(&lib_path) {
+        eprintln!("load_library failed: {}", e);
+        return 1;
+    }
+
+    match std::panic::catch_unwind(|| maa_framework::maa_version().to_string()) {
+        Ok(version) if !version.is_empty() && version != "unknown" => {
+            println!("{}", version);
+            0
+        }
+        Ok(_) => {
+            eprintln!("MaaFramework not initialized");
+            2
+        }
+        Err(_) => {
+            eprintln!("maa_version panicked");
+            3
+        }
+    }
+}
+
 /// 获取命令行 -i/--instance 参数指定的启动实例名称
 #[tauri::command]
 pub fn get_start_instance() -> Option<String> {
     get_cli_arg_value("-i", "--instance")
 }
 
+/// 检查命令行是否包含 --restore-session 参数（以管理员身份重启后恢复会话）
+#[tauri::command]
+pub fn has_restore_session_flag() -> bool {
+    std::env::args().any(|arg| arg == RESTORE_SESSION_FLAG)
+}
+
 /// 检查命令行是否包含 -q/--quit-after-run 参数（任务完成后关闭自身）
 #[tauri::command]
 pub fn has_quit_after_run_flag() -> bool {
@@ -680,7 +1004,7 @@ pub fn migrate_legacy_autostart() {
 #[cfg(windows)]
 fn create_schtask_autostart() -> Result<(), String> {
     use std::os::windows::process::CommandExt;
-    let exe_path = std::env::current_exe().map_err(|e| format!("获取程序路径失败: {}", e))?;
+    let exe_path = std::env::current_exe().map_err(|e| tr!("error.fs.exe_path", error = e))?;
     let exe = exe_path.to_string_lossy();
     let output = std::process::Command::new("schtasks")
         .args([
@@ -702,10 +1026,10 @@ fn create_schtask_autostart() -> Result<(), String> {
         ])
         .creation_flags(CREATE_NO_WINDOW)
         .output()
-        .map_err(|e| format!("执行 schtasks 失败: {}", e))?;
+        .map_err(|e| tr!("error.system.schtasks_run", error = e))?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("创建计划任务失败: {}", stderr));
+        return Err(tr!("error.system.schtasks_create", error = stderr));
     }
     Ok(())
 }
@@ -799,7 +1123,7 @@ pub fn autostart_enable() -> Result<(), String> {
     }
     #[cfg(not(windows))]
     {
-        Err("此功能仅在 Windows 上可用".to_string())
+        Err(tr!("error.system.windows_only"))
     }
 }
 
@@ -819,7 +1143,7 @@ pub fn autostart_disable() -> Result<(), String> {
     }
     #[cfg(not(windows))]
     {
-        Err("此功能仅在 Windows 上可用".to_string())
+        Err(tr!("error.system.windows_only"))
     }
 }
 
@@ -879,6 +1203,105 @@ pub fn get_system_info() -> SystemInfo {
     }
 }
 
+/// 向上查找路径中第一个已存在的祖先目录（目标文件可能尚未创建）
+fn existing_ancestor(path: &std::path::Path) -> Option<&std::path::Path> {
+    path.ancestors()
+        .find(|p| !p.as_os_str().is_empty() && p.exists())
+}
+
+/// 获取路径所在卷对当前用户可用的剩余空间（字节）
+pub fn get_available_space(path: &std::path::Path) -> Result<u64, String> {
+    let dir = existing_ancestor(path)
+        .ok_or_else(|| tr!("error.system.path_not_exist", path = path.display()))?;
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetDiskFreeSpaceExW(
+                directory: *const u16,
+                free_bytes_available: *mut u64,
+                total_bytes: *mut u64,
+                total_free_bytes: *mut u64,
+            ) -> i32;
+        }
+
+        let wide: Vec<u16> = dir
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+        let mut available: u64 = 0;
+        // SAFETY: wide 为以 0 结尾的 UTF-16 路径；不需要的输出参数传空指针
+        let ok = unsafe {
+            GetDiskFreeSpaceExW(
+                wide.as_ptr(),
+                &mut available,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        if ok == 0 {
+            return Err(tr!(
+                "error.system.disk_space_query",
+                path = dir.display(),
+                error = std::io::Error::last_os_error()
+            ));
+        }
+        Ok(available)
+    }
+
+    #[cfg(not(windows))]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let c_path = std::ffi::CString::new(dir.as_os_str().as_bytes())
+            .map_err(|e| tr!("error.system.invalid_path_chars", error = e))?;
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        // SAFETY: c_path 为有效的 C 字符串，stat 为可写缓冲区
+        let rc = unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) };
+        if rc != 0 {
+            return Err(tr!(
+                "error.system.disk_space_query",
+                path = dir.display(),
+                error = std::io::Error::last_os_error()
+            ));
+        }
+        Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+    }
+}
+
+/// 确认路径所在卷剩余空间不少于 `required_bytes`，不足时返回 `InsufficientDiskSpace` 错误
+pub fn ensure_disk_space(path: &std::path::Path, required_bytes: u64) -> MxuResult<()> {
+    let info = query_disk_space(path, required_bytes)?;
+    if info.sufficient {
+        return Ok(());
+    }
+    Err(MxuError::insufficient_disk_space(
+        &info.path,
+        info.required_bytes,
+        info.available_bytes,
+    ))
+}
+
+fn query_disk_space(path: &std::path::Path, required_bytes: u64) -> Result<DiskSpaceInfo, String> {
+    let available_bytes = get_available_space(path)?;
+    Ok(DiskSpaceInfo {
+        path: path.display().to_string(),
+        required_bytes,
+        available_bytes,
+        sufficient: available_bytes >= required_bytes,
+    })
+}
+
+/// 检查路径所在卷的剩余空间是否满足需求
+#[tauri::command]
+pub fn check_disk_space(path: String, required_bytes: u64) -> Result<DiskSpaceInfo, String> {
+    query_disk_space(std::path::Path::new(&path), required_bytes)
+}
+
 /// 获取 Web 服务器实际监听端口
 ///
 /// 若服务器尚未完成绑定，最多等待 5 秒后返回（0 表示超时未启动）。
@@ -920,3 +1343,60 @@ pub fn get_webview2_dir() -> WebView2DirInfo {
         }
     }
 }
+
+/// 获取 WebView2 运行时诊断信息（版本、安装位置、Loader DLL 检测等），用于排查白屏问题
+#[tauri::command]
+pub fn webview2_runtime_info() -> WebView2RuntimeInfo {
+    #[cfg(windows)]
+    {
+        use crate::webview2;
+
+        let fixed_runtime_dir = std::env::var("WEBVIEW2_BROWSER_EXECUTABLE_FOLDER").ok();
+        let installed = webview2::get_installed_runtime();
+        let channel = if fixed_runtime_dir.is_some() {
+            "fixed"
+        } else if installed.is_some() {
+            "system"
+        } else {
+            "unavailable"
+        };
+
+        WebView2RuntimeInfo {
+            channel: channel.to_string(),
+            installed_version: installed.as_ref().map(|r| r.version.clone()),
+            install_location: installed.as_ref().and_then(|r| r.location.clone()),
+            install_scope: installed.as_ref().map(|r| r.scope.to_string()),
+            fixed_runtime_dir,
+            loader_dll_ok: webview2::is_loader_dll_present(),
+            disabled_reason: webview2::is_webview2_disabled(),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        WebView2RuntimeInfo {
+            channel: "unavailable".to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// 重新运行 WebView2 Evergreen Bootstrapper 修复系统 WebView2
+///
+/// 修复完成后需重启程序才能生效；使用固定版本运行时时不影响当前进程。
+#[tauri::command]
+pub async fn webview2_repair() -> Result<WebView2RuntimeInfo, String> {
+    #[cfg(windows)]
+    {
+        info!("webview2_repair: running Evergreen bootstrapper");
+        tokio::task::spawn_blocking(crate::webview2::run_evergreen_bootstrapper)
+            .await
+            .map_err(|e| tr!("error.task_panicked", error = e))??;
+        Ok(webview2_runtime_info())
+    }
+
+    #[cfg(not(windows))]
+    {
+        Err(tr!("error.system.webview2_windows_only"))
+    }
+}
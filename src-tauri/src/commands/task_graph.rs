@@ -0,0 +1,360 @@
+//! 任务依赖图与条件执行
+//!
+//! - `TaskConfig.depends_on` 指定任务依赖的前置任务（`maa_start_tasks` 任务列表中的下标）。
+//!   启动时按拓扑顺序提交任务；前置任务失败后，尚未执行的依赖任务（含间接依赖）被撤下
+//! - `TaskConfig.run_if` 为条件表达式（见 `crate::expr`），在任务即将执行时求值，
+//!   不满足则撤下。可引用变量存储 `vars.<名称>` 与同批任务结果
+//!   `task[<下标>].status / succeeded / failed / skipped / finished`
+//!
+//! 被撤下的任务通过 `task-skipped` 事件通知前端。
+
+use std::collections::{BTreeSet, HashMap};
+
+use log::{info, warn};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+use super::types::{InstanceRuntime, TaskConfig};
+use crate::expr::{Expr, PathSeg};
+use crate::i18n::tr;
+
+/// 已提交任务之间的依赖关系
+#[derive(Debug, Default)]
+pub struct TaskGraph {
+    /// task_id -> 前置任务 ID
+    deps: HashMap<i64, Vec<i64>>,
+    /// task_id -> 任务列表下标
+    indices: HashMap<i64, usize>,
+    /// 任务列表下标 -> 结果（succeeded / failed / skipped）
+    outcomes: HashMap<usize, String>,
+}
+
+/// 被跳过的任务
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedTask {
+    /// dependency-failed / condition-false
+    pub reason: &'static str,
+    /// 未提交的任务为 None
+    pub task_id: Option<i64>,
+    pub entry: String,
+    pub index: Option<usize>,
+    pub selected_task_id: Option<String>,
+    /// 失败的前置任务（dependency-failed）
+    pub dependency_task_id: Option<i64>,
+    pub dependency_index: Option<usize>,
+    /// 不满足的条件（condition-false）
+    pub condition: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskSkippedEvent {
+    instance_id: String,
+    #[serde(flatten)]
+    task: SkippedTask,
+}
+
+/// 计算任务的提交顺序（拓扑排序，无依赖约束时保持原顺序）
+///
+/// 下标越界或存在循环依赖时返回错误。
+pub fn topological_order(tasks: &[TaskConfig]) -> Result<Vec<usize>, String> {
+    let n = tasks.len();
+    let mut indegree = vec![0usize; n];
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (idx, task) in tasks.iter().enumerate() {
+        for &dep in task.depends_on.iter().flatten() {
+            if dep >= n {
+                return Err(tr!(
+                    "error.task_graph.dep_out_of_range",
+                    index = idx,
+                    entry = task.entry,
+                    dep = dep
+                ));
+            }
+            if dep == idx {
+                return Err(tr!(
+                    "error.task_graph.self_dep",
+                    index = idx,
+                    entry = task.entry
+                ));
+            }
+            indegree[idx] += 1;
+            dependents[dep].push(idx);
+        }
+    }
+
+    // 每次取下标最小的可执行任务，保证无依赖时顺序不变
+    let mut ready: BTreeSet<usize> = (0..n).filter(|i| indegree[*i] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(idx) = ready.pop_first() {
+        order.push(idx);
+        for &next in &dependents[idx] {
+            indegree[next] -= 1;
+            if indegree[next] == 0 {
+                ready.insert(next);
+            }
+        }
+    }
+
+    if order.len() != n {
+        let cyclic: Vec<String> = (0..n)
+            .filter(|i| indegree[*i] > 0)
+            .map(|i| i.to_string())
+            .collect();
+        return Err(tr!("error.task_graph.cycle", tasks = cyclic.join(", ")));
+    }
+    Ok(order)
+}
+
+impl TaskGraph {
+    /// 由已提交的任务（任务列表下标, task_id）构建依赖图
+    pub fn build(tasks: &[TaskConfig], posted: &[(usize, i64)]) -> Self {
+        let ids: HashMap<usize, i64> = posted.iter().copied().collect();
+        let mut graph = Self::default();
+        for &(idx, task_id) in posted {
+            graph.indices.insert(task_id, idx);
+            let deps: Vec<i64> = tasks[idx]
+                .depends_on
+                .iter()
+                .flatten()
+                .filter_map(|dep| ids.get(dep).copied())
+                .collect();
+            if !deps.is_empty() {
+                graph.deps.insert(task_id, deps);
+            }
+        }
+        graph
+    }
+
+    /// 记录任务结果（供 `run_if` 中的 `task[i]` 引用）
+    pub fn record_outcome(&mut self, task_id: i64, outcome: &str) {
+        if let Some(idx) = self.indices.get(&task_id) {
+            self.outcomes.insert(*idx, outcome.to_string());
+        }
+    }
+
+    /// 记录未提交任务的结果
+    pub fn record_index_outcome(&mut self, index: usize, outcome: &str) {
+        self.outcomes.insert(index, outcome.to_string());
+    }
+
+    /// 任务被重新提交后更新 ID，并清除旧任务（如被停止时）记录的结果
+    pub fn replace(&mut self, old_task_id: i64, new_task_id: i64) {
+        if let Some(idx) = self.indices.remove(&old_task_id) {
+            self.indices.insert(new_task_id, idx);
+            self.outcomes.remove(&idx);
+        }
+        if let Some(deps) = self.deps.remove(&old_task_id) {
+            self.deps.insert(new_task_id, deps);
+        }
+        for deps in self.deps.values_mut() {
+            for dep in deps.iter_mut().filter(|d| **d == old_task_id) {
+                *dep = new_task_id;
+            }
+        }
+    }
+
+    /// 直接或间接依赖 `task_id` 的任务
+    fn dependents_of(&self, task_id: i64) -> Vec<i64> {
+        let mut result = Vec::new();
+        let mut stack = vec![task_id];
+        while let Some(current) = stack.pop() {
+            for (id, deps) in &self.deps {
+                if deps.contains(&current) && !result.contains(id) {
+                    result.push(*id);
+                    stack.push(*id);
+                }
+            }
+        }
+        result.sort_unstable();
+        result
+    }
+}
+
+/// 前置任务失败时撤下其依赖任务（在持有实例锁时由 `handle_task_callback` 调用）
+pub fn skip_dependents(instance: &mut InstanceRuntime, failed_task_id: i64) -> Vec<SkippedTask> {
+    if instance.task_graph.deps.is_empty() {
+        return Vec::new();
+    }
+    let Some(tasker) = instance.tasker.clone() else {
+        return Vec::new();
+    };
+
+    let dependency_index = instance.task_graph.indices.get(&failed_task_id).copied();
+    let mut skipped = Vec::new();
+    for task_id in instance.task_graph.dependents_of(failed_task_id) {
+        let Some(task) = super::maa_core::withdraw_task(instance, &tasker, task_id) else {
+            continue;
+        };
+        if let Some(selected_id) = &task.selected_task_id {
+            instance
+                .task_run_state
+                .statuses
+                .insert(selected_id.clone(), "idle".to_string());
+        }
+        instance.task_graph.record_outcome(task_id, "skipped");
+        skipped.push(SkippedTask {
+            reason: "dependency-failed",
+            task_id: Some(task_id),
+            entry: task.entry,
+            index: instance.task_graph.indices.get(&task_id).copied(),
+            selected_task_id: task.selected_task_id,
+            dependency_task_id: Some(failed_task_id),
+            dependency_index,
+            condition: None,
+        });
+    }
+    skipped
+}
+
+/// 将 `run_if` 中的路径解析为值
+fn resolve_path(
+    segs: &[PathSeg],
+    instance_id: &str,
+    outcome: &dyn Fn(usize) -> Option<String>,
+) -> Value {
+    match segs {
+        [PathSeg::Key(root), PathSeg::Key(name), rest @ ..] if root == "vars" => {
+            let mut value = super::variables::get(instance_id, name).unwrap_or(Value::Null);
+            for seg in rest {
+                value = match seg {
+                    PathSeg::Key(k) => value.get(k).cloned().unwrap_or(Value::Null),
+                    PathSeg::Index(i) => value.get(i).cloned().unwrap_or(Value::Null),
+                };
+            }
+            value
+        }
+        [PathSeg::Key(root), PathSeg::Index(idx), PathSeg::Key(field)] if root == "task" => {
+            let status = outcome(*idx).unwrap_or_else(|| "pending".to_string());
+            match field.as_str() {
+                "status" => Value::String(status),
+                "succeeded" | "failed" | "skipped" => Value::Bool(status == *field),
+                "finished" => Value::Bool(status != "pending"),
+                _ => Value::Null,
+            }
+        }
+        _ => Value::Null,
+    }
+}
+
+/// 求值 `run_if` 条件，解析或求值失败时视为不满足
+pub fn evaluate_condition(
+    condition: &str,
+    instance_id: &str,
+    outcome: &dyn Fn(usize) -> Option<String>,
+) -> bool {
+    let result = Expr::parse(condition)
+        .and_then(|expr| expr.eval_bool(&|segs| resolve_path(segs, instance_id, outcome)));
+    match result {
+        Ok(met) => met,
+        Err(e) => {
+            warn!(
+                "[task_graph] Failed to evaluate run_if '{}': {}",
+                condition, e
+            );
+            false
+        }
+    }
+}
+
+/// 校验任务的 `run_if` 表达式语法
+pub fn validate_conditions(tasks: &[TaskConfig]) -> Result<(), String> {
+    for (idx, task) in tasks.iter().enumerate() {
+        if let Some(condition) = task.run_if.as_deref().filter(|c| !c.trim().is_empty()) {
+            Expr::parse(condition).map_err(|e| {
+                tr!(
+                    "error.task_graph.invalid_run_if",
+                    index = idx,
+                    entry = task.entry,
+                    error = e
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// 任务结束后，对队列中即将执行的任务求值 `run_if`，撤下条件不满足的任务
+///
+/// 在持有实例锁时由 `handle_task_callback` 调用。从当前任务之后逐个检查，
+/// 遇到第一个将要执行的任务为止。
+pub fn skip_unmet_conditions(
+    instance: &mut InstanceRuntime,
+    instance_id: &str,
+) -> Vec<SkippedTask> {
+    let Some(tasker) = instance.tasker.clone() else {
+        return Vec::new();
+    };
+    let run_state = &instance.task_run_state;
+    let queued: Vec<i64> = run_state
+        .pending_task_ids
+        .iter()
+        .skip(run_state.current_task_index + 1)
+        .copied()
+        .collect();
+
+    let mut skipped = Vec::new();
+    for task_id in queued {
+        if instance.skipped_task_ids.contains(&task_id) {
+            continue;
+        }
+        let Some(task) = instance.submitted_tasks.get(&task_id) else {
+            continue;
+        };
+        let Some(condition) = task.run_if.clone().filter(|c| !c.trim().is_empty()) else {
+            break;
+        };
+        let graph = &instance.task_graph;
+        let met = evaluate_condition(&condition, instance_id, &|idx| {
+            graph.outcomes.get(&idx).cloned()
+        });
+        if met {
+            break;
+        }
+        let Some(task) = super::maa_core::withdraw_task(instance, &tasker, task_id) else {
+            break;
+        };
+        if let Some(selected_id) = &task.selected_task_id {
+            instance
+                .task_run_state
+                .statuses
+                .insert(selected_id.clone(), "idle".to_string());
+        }
+        instance.task_graph.record_outcome(task_id, "skipped");
+        skipped.push(SkippedTask {
+            reason: "condition-false",
+            task_id: Some(task_id),
+            entry: task.entry,
+            index: instance.task_graph.indices.get(&task_id).copied(),
+            selected_task_id: task.selected_task_id,
+            dependency_task_id: None,
+            dependency_index: None,
+            condition: Some(condition),
+        });
+        // 依赖被跳过任务的任务同样不再执行
+        skipped.extend(skip_dependents(instance, task_id));
+    }
+    skipped
+}
+
+/// 通知前端并记录跳过的任务（释放实例锁后调用）
+pub fn emit_skipped(app: &AppHandle, instance_id: &str, skipped: Vec<SkippedTask>) {
+    for task in skipped {
+        info!(
+            "[task_graph] Task {} ({:?}) skipped: {}",
+            task.entry, task.task_id, task.reason
+        );
+        if let Some(task_id) = task.task_id {
+            super::run_report::on_task_event(instance_id, task_id, "skipped", None);
+        }
+        let event = TaskSkippedEvent {
+            instance_id: instance_id.to_string(),
+            task,
+        };
+        if let Err(e) = app.emit("task-skipped", event) {
+            warn!("Failed to emit task-skipped: {}", e);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! 任务超时看门狗
+//!
+//! `TaskConfig.timeout_secs` 设置单个任务的最长运行时间。任务开始时启动计时线程，
+//! 超时后停止 Tasker（卡死在某个节点无限循环时保护无人值守运行），
+//! 并按 `timeout_restarts`（最多 `MAX_TIMEOUT_RESTARTS` 次）重新提交该任务及其后尚未完成的任务。
+//! 重新提交的任务沿用原任务在依赖图中的位置。
+//!
+//! 超时通过 `task-timeout` 事件通知前端。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use super::types::MaaState;
+
+/// 计时线程的检查间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+/// 单个任务超时后重新执行的次数上限
+const MAX_TIMEOUT_RESTARTS: u32 = 5;
+
+/// 实例 -> 正在计时的任务 ID（任务结束或被新任务替换后计时线程退出）
+static ACTIVE: Mutex<Option<HashMap<String, i64>>> = Mutex::new(None);
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskTimeoutEvent {
+    instance_id: String,
+    task_id: i64,
+    entry: String,
+    timeout_secs: u64,
+    /// 是否已重新提交任务
+    restarted: bool,
+}
+
+fn is_active(instance_id: &str, task_id: i64) -> bool {
+    ACTIVE
+        .lock()
+        .as_ref()
+        .and_then(|m| m.get(instance_id).copied())
+        == Some(task_id)
+}
+
+/// 任务开始时调用（由 `handle_task_callback` 调用），任务设置了超时时启动计时
+pub fn on_task_started(app: &AppHandle, state: &Arc<MaaState>, instance_id: &str, task_id: i64) {
+    let timeout_secs = state
+        .instances
+        .get(instance_id)
+        .and_then(|instance| instance.submitted_tasks.get(&task_id)?.timeout_secs);
+
+    let mut guard = ACTIVE.lock();
+    let active = guard.get_or_insert_with(HashMap::new);
+    let Some(timeout_secs) = timeout_secs.filter(|s| *s > 0) else {
+        active.remove(instance_id);
+        return;
+    };
+    active.insert(instance_id.to_string(), task_id);
+    drop(guard);
+
+    let app = app.clone();
+    let state = state.clone();
+    let instance_id = instance_id.to_string();
+    std::thread::spawn(move || {
+        let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+        loop {
+            std::thread::sleep(CHECK_INTERVAL);
+            if !is_active(&instance_id, task_id) {
+                return;
+            }
+            if Instant::now() >= deadline {
+                break;
+            }
+        }
+        handle_timeout(&app, &state, &instance_id, task_id, timeout_secs);
+    });
+}
+
+/// 任务结束时调用（由 `handle_task_callback` 调用），取消计时
+pub fn on_task_finished(instance_id: &str, task_id: i64) {
+    let mut guard = ACTIVE.lock();
+    if let Some(active) = guard.as_mut() {
+        if active.get(instance_id) == Some(&task_id) {
+            active.remove(instance_id);
+        }
+    }
+}
+
+fn handle_timeout(
+    app: &AppHandle,
+    state: &Arc<MaaState>,
+    instance_id: &str,
+    task_id: i64,
+    timeout_secs: u64,
+) {
+    on_task_finished(instance_id, task_id);
+
+    let Some(task) = state
+        .instances
+        .get(instance_id)
+        .and_then(|instance| instance.submitted_tasks.get(&task_id).cloned())
+    else {
+        return;
+    };
+    warn!(
+        "[task_timeout] Task {} ({}) on instance {} exceeded {}s, stopping",
+        task_id, task.entry, instance_id, timeout_secs
+    );
+
+    // 停止前记录尚未完成的任务（超时任务本身及其后的任务）
+    let restarts = task.timeout_restarts.min(MAX_TIMEOUT_RESTARTS);
+    let pending = if restarts > 0 {
+        super::reconnect::unfinished_tasks(state, instance_id)
+    } else {
+        Vec::new()
+    };
+    if !super::reconnect::stop_and_wait(state, instance_id) {
+        warn!(
+            "[task_timeout] Tasker of instance {} did not stop in time",
+            instance_id
+        );
+    }
+
+    let mut restarted = false;
+    if !pending.is_empty() {
+        for (id, mut config) in pending {
+            if id == task_id {
+                config.timeout_restarts = restarts - 1;
+            }
+            match super::maa_core::resubmit_task_impl(app, state, instance_id, id, &config) {
+                Ok(_) => restarted = true,
+                Err(e) => warn!(
+                    "[task_timeout] Failed to resubmit task {}: {}",
+                    config.entry, e
+                ),
+            }
+        }
+    }
+
+    let event = TaskTimeoutEvent {
+        instance_id: instance_id.to_string(),
+        task_id,
+        entry: task.entry.clone(),
+        timeout_secs,
+        restarted,
+    };
+    if let Err(e) = app.emit("task-timeout", event) {
+        warn!("Failed to emit task-timeout: {}", e);
+    }
+
+    if restarted {
+        info!(
+            "[task_timeout] Restarted task {} on instance {}",
+            task.entry, instance_id
+        );
+        super::utils::emit_state_changed(app, instance_id, "task-started");
+    } else {
+        super::utils::emit_state_changed(app, instance_id, "task-stopped");
+    }
+}
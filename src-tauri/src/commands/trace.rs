@@ -0,0 +1,182 @@
+//! 命令级追踪
+//!
+//! 连接控制器、启动任务、重启 Agent 等跨越异步流程的命令，每次调用分配一个关联 ID
+//! 并绑定到对应实例。此后该实例的状态变更事件与任务回调都会记录到同一条追踪中，
+//! 日志行以 `[关联 ID]` 开头，`state-changed` 事件携带 `correlationId`。
+//! `mxu_get_trace` 按关联 ID 返回完整经过，便于还原问题现场。
+//!
+//! 追踪范围：所有改变实例生命周期的命令，Tauri 命令与 HTTP 接口（`web_server`）相同，
+//! 即销毁实例、连接控制器、加载/销毁资源、启动/运行/停止任务（含当前任务后停止）、停止/重启 Agent。
+//! 查询类命令（状态、控制器信息、截图缓存等）与点击、滑动等单次操作不分配关联 ID。
+//! 断线重连、超时停止、托盘等后台流程不单独开启追踪，其状态变更记录到该实例最近一次命令的追踪中。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::i18n::tr;
+
+/// 最多保留的追踪条数（超出后丢弃最早的）
+const MAX_TRACES: usize = 100;
+
+/// 单条追踪最多记录的条目数
+const MAX_ENTRIES_PER_TRACE: usize = 500;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// 追踪条目
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceEntry {
+    pub timestamp: String,
+    pub level: String,
+    pub message: String,
+}
+
+/// 一次命令调用的追踪
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trace {
+    pub correlation_id: String,
+    pub command: String,
+    pub instance_id: Option<String>,
+    pub started_at: String,
+    /// 命令返回时间（异步流程的后续事件仍会继续记录）
+    pub finished_at: Option<String>,
+    /// 命令返回的错误
+    pub error: Option<String>,
+    pub entries: Vec<TraceEntry>,
+}
+
+#[derive(Default)]
+struct TraceStore {
+    traces: VecDeque<Trace>,
+    /// 实例 ID -> 最近一次命令的关联 ID
+    by_instance: HashMap<String, String>,
+}
+
+static STORE: Mutex<Option<TraceStore>> = Mutex::new(None);
+
+fn with_store<R>(f: impl FnOnce(&mut TraceStore) -> R) -> Option<R> {
+    let mut guard = STORE.lock();
+    Some(f(guard.get_or_insert_with(TraceStore::default)))
+}
+
+fn now() -> String {
+    chrono::Local::now().to_rfc3339()
+}
+
+fn push_entry(store: &mut TraceStore, correlation_id: &str, level: log::Level, message: String) {
+    let Some(trace) = store
+        .traces
+        .iter_mut()
+        .rev()
+        .find(|t| t.correlation_id == correlation_id)
+    else {
+        return;
+    };
+    if trace.entries.len() < MAX_ENTRIES_PER_TRACE {
+        trace.entries.push(TraceEntry {
+            timestamp: now(),
+            level: level.to_string(),
+            message,
+        });
+    }
+}
+
+/// 开始一次追踪，返回关联 ID；指定实例时该实例后续的事件都会记录到这条追踪中
+pub fn begin(command: &str, instance_id: Option<&str>) -> String {
+    let correlation_id = format!(
+        "{:x}-{:04x}",
+        chrono::Local::now().timestamp_millis(),
+        NEXT_ID.fetch_add(1, Ordering::Relaxed) & 0xffff
+    );
+    log::info!(
+        "[{}] {} started (instance: {:?})",
+        correlation_id,
+        command,
+        instance_id
+    );
+
+    with_store(|store| {
+        if store.traces.len() >= MAX_TRACES {
+            store.traces.pop_front();
+        }
+        store.traces.push_back(Trace {
+            correlation_id: correlation_id.clone(),
+            command: command.to_string(),
+            instance_id: instance_id.map(str::to_string),
+            started_at: now(),
+            finished_at: None,
+            error: None,
+            entries: Vec::new(),
+        });
+        if let Some(id) = instance_id {
+            store
+                .by_instance
+                .insert(id.to_string(), correlation_id.clone());
+        }
+    });
+    correlation_id
+}
+
+/// 记录一条追踪日志（同时写入应用日志，以关联 ID 开头）
+pub fn record(correlation_id: &str, level: log::Level, message: impl Into<String>) {
+    let message = message.into();
+    log::log!(level, "[{}] {}", correlation_id, message);
+    with_store(|store| push_entry(store, correlation_id, level, message));
+}
+
+/// 记录到实例当前的追踪中（实例没有进行中的追踪时忽略，不写应用日志）
+pub fn record_instance(instance_id: &str, level: log::Level, message: impl Into<String>) {
+    with_store(|store| {
+        if let Some(correlation_id) = store.by_instance.get(instance_id).cloned() {
+            push_entry(store, &correlation_id, level, message.into());
+        }
+    });
+}
+
+/// 实例当前的关联 ID
+pub fn current_for_instance(instance_id: &str) -> Option<String> {
+    with_store(|store| store.by_instance.get(instance_id).cloned()).flatten()
+}
+
+/// 记录命令返回结果
+pub fn finish<T, E: std::fmt::Display>(correlation_id: &str, result: &Result<T, E>) {
+    let error = result.as_ref().err().map(|e| e.to_string());
+    match &error {
+        Some(e) => log::warn!("[{}] failed: {}", correlation_id, e),
+        None => log::info!("[{}] returned ok", correlation_id),
+    }
+    with_store(|store| {
+        if let Some(trace) = store
+            .traces
+            .iter_mut()
+            .rev()
+            .find(|t| t.correlation_id == correlation_id)
+        {
+            trace.finished_at = Some(now());
+            trace.error = error;
+        }
+    });
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 按关联 ID 获取追踪记录
+#[tauri::command]
+pub fn mxu_get_trace(correlation_id: String) -> Result<Trace, String> {
+    with_store(|store| {
+        store
+            .traces
+            .iter()
+            .find(|t| t.correlation_id == correlation_id)
+            .cloned()
+    })
+    .flatten()
+    .ok_or_else(|| tr!("error.trace.not_found", id = correlation_id))
+}
@@ -5,9 +5,11 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use std::process::Child;
-use std::sync::Mutex;
+use std::sync::Arc;
 use std::time::Instant;
 
+use dashmap::DashMap;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
 use maa_framework::agent_client::AgentClient;
@@ -58,6 +60,27 @@ pub struct Win32Window {
     pub handle: u64,
     pub class_name: String,
     pub window_name: String,
+    /// 所属进程 ID
+    #[serde(default)]
+    pub pid: Option<u32>,
+    /// 所属进程名（如 `game.exe`）
+    #[serde(default)]
+    pub process_name: Option<String>,
+    /// 窗口矩形（屏幕坐标）
+    #[serde(default)]
+    pub rect: Option<WindowRect>,
+    /// DPI 缩放比例（1.0 = 96 DPI）
+    #[serde(default)]
+    pub dpi_scale: Option<f64>,
+}
+
+/// 窗口矩形（屏幕坐标）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
 }
 
 /// 控制器类型
@@ -80,6 +103,9 @@ pub enum ControllerConfig {
         keyboard_method: u64,
         #[serde(default)]
         display_short_side: Option<i32>,
+        /// DXGI 桌面复制截取的显示器设备名（如 `\\.\DISPLAY2`），缺省为窗口当前所在显示器
+        #[serde(default)]
+        monitor: Option<String>,
     },
     WlRoots {
         wlr_socket_path: String,
@@ -96,6 +122,18 @@ pub enum ControllerConfig {
         screencap_method: Option<u64>,
         #[serde(default)]
         display_short_side: Option<i32>,
+        /// 摇杆死区（0-100，百分比）
+        #[serde(default)]
+        stick_dead_zone: Option<u32>,
+        /// 扳机灵敏度（1-100，百分比）
+        #[serde(default)]
+        trigger_sensitivity: Option<u32>,
+        /// 轮询频率（Hz）
+        #[serde(default)]
+        polling_rate: Option<u32>,
+        /// DXGI 桌面复制截取的显示器设备名，缺省为窗口当前所在显示器
+        #[serde(default)]
+        monitor: Option<String>,
     },
     PlayCover {
         address: String,
@@ -115,6 +153,44 @@ pub enum ConnectionStatus {
     Failed(String),
 }
 
+/// 屏幕方向（按截图宽高判断）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Orientation {
+    Landscape,
+    Portrait,
+}
+
+impl Orientation {
+    pub fn from_size(width: i32, height: i32) -> Self {
+        if width >= height {
+            Orientation::Landscape
+        } else {
+            Orientation::Portrait
+        }
+    }
+}
+
+/// 控制器信息（设备 UUID、截图分辨率、方向）
+#[derive(Debug, Clone, Serialize)]
+pub struct ControllerInfo {
+    /// 设备唯一标识（ADB 为序列号，Win32 为窗口句柄等，由 MaaFramework 提供）
+    pub uuid: Option<String>,
+    /// 原始截图分辨率（尚未截图时为 0）
+    pub width: i32,
+    pub height: i32,
+    pub orientation: Orientation,
+}
+
+/// 控制器分辨率变化事件（运行中横竖屏切换、模拟器改分辨率等）
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolutionChangedEvent {
+    pub instance_id: String,
+    pub previous: [i32; 2],
+    pub current: [i32; 2],
+    pub orientation: Orientation,
+}
+
 /// 任务状态
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TaskStatus {
@@ -167,12 +243,17 @@ pub struct AllInstanceStates {
 #[derive(Default)]
 pub struct InstanceRuntime {
     pub resource: Option<Resource>,
+    /// 已加载的资源包路径（按加载顺序，后加载的覆盖先加载的）
+    pub resource_paths: Vec<PathBuf>,
     pub controller: Option<Controller>,
     /// 当前控制器的配置（用于 ControllerPool 引用管理）
     pub controller_config: Option<ControllerConfig>,
     pub tasker: Option<Tasker>,
     pub agent_clients: Vec<AgentClient>,
-    pub agent_children: Vec<Child>,
+    /// Agent 子进程（远程 Agent 为 None）
+    pub agent_children: Vec<Option<Child>>,
+    /// 与 agent_clients / agent_children 一一对应的启动参数
+    pub agent_launches: Vec<AgentLaunch>,
     /// 当前运行的任务 ID 列表（用于刷新后恢复状态）
     pub task_ids: Vec<i64>,
     /// 是否正在停止任务（用于防重复 stop）
@@ -181,6 +262,18 @@ pub struct InstanceRuntime {
     pub stop_started_at: Option<Instant>,
     /// 任务运行状态（后端管理，单一真相来源）
     pub task_run_state: TaskRunState,
+    /// 实例级运行选项（运行速度等）
+    pub run_options: super::run_options::InstanceRunOptions,
+    /// 已提交的任务（task_id -> 任务配置，用于断线重连后恢复未完成的任务）
+    pub submitted_tasks: HashMap<i64, TaskConfig>,
+    /// 已从队列中撤下的任务 ID（仍会被 Tasker 空跑，其回调不再计入运行状态）
+    pub skipped_task_ids: HashSet<i64>,
+    /// 当前批次任务的依赖关系
+    pub task_graph: super::task_graph::TaskGraph,
+    /// 最近一次观测到的控制器分辨率（用于检测运行中分辨率变化）
+    pub last_resolution: Option<(i32, i32)>,
+    /// 运行期间保持亮屏前设备的 `stay_on_while_plugged_in` 原值（None 表示未启用保持亮屏）
+    pub adb_stay_on_restore: Option<String>,
 }
 
 impl Drop for InstanceRuntime {
@@ -192,7 +285,7 @@ impl Drop for InstanceRuntime {
         self.agent_clients.clear();
 
         // 终止并回收所有 agent 子进程
-        for mut child in self.agent_children.drain(..) {
+        for mut child in self.agent_children.drain(..).flatten() {
             let _ = child.kill();
             let _ = child.wait();
         }
@@ -270,12 +363,86 @@ impl LogBuffer {
     }
 }
 
+/// 每个实例保留的补发事件上限
+const MAX_REPLAY_EVENTS: usize = 200;
+
+/// 补发缓冲区中的一条事件
+///
+/// 序列化为 `{ seq, instanceId, timestamp, type, payload }`，`type` / `payload` 与 WebSocket 推送格式一致。
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayEvent {
+    pub seq: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    pub timestamp: String,
+    #[serde(flatten)]
+    pub event: crate::ws_broadcast::WsEvent,
+}
+
+/// 事件补发缓冲区
+///
+/// 按实例缓存最近的 `maa-callback`、Agent 输出与下载进度事件（下载进度不属于任何实例，单独一组），
+/// 前端页面刷新或组件晚于事件挂载时，可通过 `mxu_fetch_missed_events` 补取错过的事件。
+#[derive(Default)]
+pub struct EventReplayBuffer {
+    last_seq: u64,
+    events: HashMap<Option<String>, VecDeque<ReplayEvent>>,
+}
+
+impl EventReplayBuffer {
+    pub fn push(&mut self, instance_id: Option<&str>, event: crate::ws_broadcast::WsEvent) {
+        self.last_seq += 1;
+        let key = instance_id.map(str::to_string);
+        let entries = self.events.entry(key.clone()).or_default();
+        entries.push_back(ReplayEvent {
+            seq: self.last_seq,
+            instance_id: key,
+            timestamp: chrono::Local::now().to_rfc3339(),
+            event,
+        });
+        while entries.len() > MAX_REPLAY_EVENTS {
+            entries.pop_front();
+        }
+    }
+
+    /// 最新事件的序号（尚无事件时为 0）
+    pub fn last_seq(&self) -> u64 {
+        self.last_seq
+    }
+
+    /// 序号大于 `since_seq` 的事件，按序号排列；指定实例时只返回该实例与全局事件
+    pub fn since(&self, since_seq: u64, instance_id: Option<&str>) -> Vec<ReplayEvent> {
+        let mut events: Vec<ReplayEvent> = self
+            .events
+            .iter()
+            .filter(|(key, _)| match (instance_id, key.as_deref()) {
+                (Some(wanted), Some(id)) => wanted == id,
+                _ => true,
+            })
+            .flat_map(|(_, entries)| entries.iter().filter(|e| e.seq > since_seq).cloned())
+            .collect();
+        events.sort_by_key(|e| e.seq);
+        events
+    }
+}
+
 /// MaaFramework 运行时状态
+///
+/// MaaFramework 动态库由 `maa_framework::load_library` 全局加载一次，之后的 FFI 调用
+/// 不经过任何 MXU 侧的锁。`instances` 按实例分片加锁，访问某个实例不会阻塞其他实例；
+/// 实例锁只在取出/存回句柄时短暂持有，Agent 启动、控制器连接、等待任务等耗时操作都在锁外进行。
+///
+/// 注意：持有 `instances.get()` / `get_mut()` 返回的引用时不要再对 `instances`
+/// 做插入、删除或遍历，否则可能在同一分片上死锁。
+///
+/// 所有锁均不会中毒（`DashMap` 与 `parking_lot::Mutex`）：某个命令持锁时 panic
+/// 只会中断该命令，锁随栈展开释放，后续命令照常使用，无需重启应用。
 #[derive(Default)]
 pub struct MaaState {
     pub lib_dir: Mutex<Option<PathBuf>>,
     pub resource_dir: Mutex<Option<PathBuf>>,
-    pub instances: Mutex<HashMap<String, InstanceRuntime>>,
+    pub instances: DashMap<String, InstanceRuntime>,
     /// 前置程序停止请求（用于中断等待退出）
     pub pre_action_stop_requests: Mutex<HashSet<String>>,
     /// Controller 连接池：相同配置的 Controller 复用同一个 MaaControllerHandle
@@ -288,27 +455,36 @@ pub struct MaaState {
     pub cached_wlroots_sockets: Mutex<Vec<String>>,
     /// 运行日志缓冲区（前端推送，页面刷新后恢复）
     pub log_buffer: Mutex<LogBuffer>,
+    /// 事件补发缓冲区（前端错过的回调、Agent 输出与下载进度）
+    pub event_replay: Mutex<EventReplayBuffer>,
     /// 后端统一截图服务（确保每实例只有一份 post_screencap 在运行）
     pub screenshot_service: crate::screenshot_service::ScreenshotService,
 }
 
 impl MaaState {
+    /// 统计 tasker 正在运行的实例数
+    pub fn running_instance_count(&self) -> usize {
+        self.instances
+            .iter()
+            .filter(|r| r.tasker.as_ref().is_some_and(|t| t.running()))
+            .count()
+    }
+
     /// 清理所有实例的 agent 子进程
     pub fn cleanup_all_agent_children(&self) {
-        if let Ok(mut instances) = self.instances.lock() {
-            for (id, instance) in instances.iter_mut() {
-                for mut child in instance.agent_children.drain(..) {
-                    log::info!("Killing agent child process for instance: {}", id);
-                    if let Err(e) = child.kill() {
-                        log::warn!(
-                            "Failed to kill agent child process for instance {}: {:?}",
-                            id,
-                            e
-                        );
-                    }
-                    // 回收子进程，避免 *nix 上产生僵尸进程
-                    let _ = child.wait();
+        for mut entry in self.instances.iter_mut() {
+            let (id, instance) = entry.pair_mut();
+            for mut child in instance.agent_children.drain(..).flatten() {
+                log::info!("Killing agent child process for instance: {}", id);
+                if let Err(e) = child.kill() {
+                    log::warn!(
+                        "Failed to kill agent child process for instance {}: {:?}",
+                        id,
+                        e
+                    );
                 }
+                // 回收子进程，避免 *nix 上产生僵尸进程
+                let _ = child.wait();
             }
         }
     }
@@ -317,6 +493,8 @@ impl MaaState {
 /// Maa回调事件
 #[derive(Clone, Serialize, Deserialize)]
 pub struct MaaCallbackEvent {
+    /// 产生该回调的实例 ID
+    pub instance_id: String,
     pub message: String,
     pub details: String,
 }
@@ -326,26 +504,128 @@ pub struct MaaCallbackEvent {
 pub struct StateChangedEvent {
     pub instance_id: String,
     pub kind: String,
+    /// 触发该变更的命令关联 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
 }
 
 /// Agent 配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentConfig {
+    /// 远程 Agent 可为空
+    #[serde(default)]
     pub child_exec: String,
     pub child_args: Option<Vec<String>>,
     pub identifier: Option<String>,
     /// 连接超时时间（毫秒），-1 表示无限等待
     pub timeout: Option<i64>,
+    /// TCP 兼容模式下使用的固定端口（未设置时由系统分配）
+    pub tcp_port: Option<u16>,
+    /// TCP 兼容模式下等待 Agent 连接的地址（默认 127.0.0.1）。非回环地址时由 MXU 在该地址
+    /// 监听并转发到 MaaFramework 的回环端口
+    pub tcp_interface: Option<String>,
+    /// `tcp_interface` 为非回环地址时允许连接的 Agent 地址（IP 或主机名），必须填写
+    pub tcp_allowed_peers: Option<Vec<String>>,
+    /// 远程 Agent 地址（host:port）。设置后不启动子进程，MXU 主动连接该地址并转发到本机
+    /// AgentClient；远程端需在该端口接受连接并转发给其 Agent（如 socat 双端监听）
+    pub remote: Option<String>,
+}
+
+/// Agent 启动参数（用于重启单个 Agent）
+#[derive(Debug, Clone)]
+pub struct AgentLaunch {
+    pub config: AgentConfig,
+    pub cwd: String,
+    pub tcp_compat_mode: bool,
+    pub pi_envs: Arc<HashMap<String, String>>,
 }
 
 /// 任务配置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TaskConfig {
     pub entry: String,
     pub pipeline_override: String,
     /// 对应的前端选中任务 ID（用于后端跟踪 per-task 状态）
     #[serde(default)]
     pub selected_task_id: Option<String>,
+    /// 任务最长运行时间（秒），超时后由看门狗停止
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// 超时后自动重新执行的次数（0 表示超时后直接停止，最多 5 次）
+    #[serde(default)]
+    pub timeout_restarts: u32,
+    /// 依赖的前置任务（同批任务列表中的下标），前置任务失败时跳过本任务
+    #[serde(default)]
+    pub depends_on: Option<Vec<usize>>,
+    /// 执行条件表达式（如 `task[0].succeeded && vars.stamina > 100`），不满足时跳过本任务
+    #[serde(default)]
+    pub run_if: Option<String>,
+}
+
+/// 导出配置时被剥离的敏感字段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigSecretMeta {
+    /// 所在配置文件名
+    pub file: String,
+    /// 字段位置（JSON Pointer，如 /settings/mirrorChyan/cdk）
+    pub pointer: String,
+}
+
+/// 配置导出结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigExportResult {
+    /// 导出的 zip 路径
+    pub path: String,
+    /// 打包的配置文件名
+    pub files: Vec<String>,
+    /// 被剥离的敏感字段
+    pub stripped_secrets: Vec<ConfigSecretMeta>,
+}
+
+/// 配置导入结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigImportResult {
+    /// 已写入的配置文件名
+    pub files: Vec<String>,
+    /// 执行的 schema 迁移步骤
+    pub migrations: Vec<String>,
+    /// 导出时存在、但本机没有可回填值的敏感字段（需用户重新填写）
+    pub missing_secrets: Vec<ConfigSecretMeta>,
+    /// 导出包来源的 MXU 版本
+    pub source_mxu_version: String,
+}
+
+/// 自检项状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelfCheckStatus {
+    Pass,
+    /// 存在隐患但不影响启动
+    Warn,
+    Fail,
+    /// 当前平台或配置下不适用
+    Skip,
+}
+
+/// 单个自检项结果
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfCheckItem {
+    /// 检查项标识（maafw_dir / maafw_load / maafw_version / agent / adb / webview2 / vcredist / exe_dir_writable / long_path / clock_drift）
+    pub id: String,
+    pub status: SelfCheckStatus,
+    /// 详情（路径、版本号或失败原因）
+    pub message: String,
+}
+
+/// 启动自检结果
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfCheckReport {
+    /// 是否没有任何失败项
+    pub passed: bool,
+    pub items: Vec<SelfCheckItem>,
 }
 
 /// 版本检查结果
@@ -368,6 +648,53 @@ pub struct ChangesJson {
     pub deleted: Vec<String>,
     #[serde(default)]
     pub modified: Vec<String>,
+    /// 二进制差分补丁（基于目标目录中的旧文件生成新文件）
+    #[serde(default)]
+    pub patched: Vec<PatchEntry>,
+}
+
+/// 应用更新的结果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateApplyResult {
+    /// 因被占用而安排在下次开机时替换的文件（非空时需要提示用户重启）
+    pub pending_reboot_files: Vec<String>,
+}
+
+/// 更新包 MaaFramework 兼容性检查报告（同时作为 `update-compat-report` 事件负载）
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateCompatReport {
+    /// 新库能否在独立进程中正常加载
+    pub loaded: bool,
+    /// 新库版本是否满足最小版本要求
+    pub compatible: bool,
+    /// 探测到的 MaaFramework 版本
+    pub version: Option<String>,
+    /// 最小支持版本
+    pub minimum: Option<String>,
+    /// 加载失败时的错误输出
+    pub error: Option<String>,
+    /// 是否为安装后对安装目录的检查（否则为安装前对更新包的检查）
+    pub installed: bool,
+}
+
+/// changes.json 中的单个差分补丁条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchEntry {
+    /// 需要打补丁的文件（相对于目标目录）
+    pub file: String,
+    /// 补丁文件（相对于解压目录）
+    pub patch: String,
+    /// 补丁格式："bsdiff" | "hdiff"
+    #[serde(default = "default_patch_format")]
+    pub format: String,
+    /// 旧文件的 SHA-256（小写十六进制），用于确认补丁基准一致
+    pub old_sha256: String,
+    /// 打补丁后新文件的 SHA-256（小写十六进制）
+    pub new_sha256: String,
+}
+
+fn default_patch_format() -> String {
+    "bsdiff".to_string()
 }
 
 /// 下载进度事件数据
@@ -400,6 +727,16 @@ pub struct SystemInfo {
     pub tauri_version: String,
 }
 
+/// 磁盘空间检查结果
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskSpaceInfo {
+    pub path: String,
+    pub required_bytes: u64,
+    pub available_bytes: u64,
+    /// 可用空间是否满足需求
+    pub sufficient: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GitHubAsset {
     pub name: String,
@@ -416,6 +753,39 @@ pub struct GitHubRelease {
     pub assets: Vec<GitHubAsset>,
 }
 
+/// GitHub Release 更新检查结果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubUpdateCheckResult {
+    pub has_update: bool,
+    pub current_version: String,
+    /// 通道内最新版本（已去除 v 前缀）
+    pub latest_version: Option<String>,
+    pub tag_name: Option<String>,
+    pub release_note: Option<String>,
+    /// 已按当前 OS / 架构筛选的资产
+    pub assets: Vec<GitHubAsset>,
+}
+
+/// WebView2 运行时诊断信息
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WebView2RuntimeInfo {
+    /// 当前进程使用的运行时类型："system" | "fixed" | "unavailable"
+    pub channel: String,
+    /// 系统已安装的 WebView2 版本
+    pub installed_version: Option<String>,
+    /// 系统 WebView2 安装位置
+    pub install_location: Option<String>,
+    /// 系统 WebView2 安装范围："machine" | "user"
+    pub install_scope: Option<String>,
+    /// 固定版本运行时目录（channel 为 fixed 时有值）
+    pub fixed_runtime_dir: Option<String>,
+    /// WebView2Loader.dll 检测是否通过
+    pub loader_dll_ok: bool,
+    /// 被组策略 / IFEO 禁用的原因
+    pub disabled_reason: Option<String>,
+}
+
 /// WebView2 目录信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebView2DirInfo {
@@ -4,8 +4,15 @@
 
 use log::{info, warn};
 
+use super::error::MxuResult;
+use super::file_lock::{
+    describe_lockers, find_locking_processes, is_locked_error, schedule_replace_on_reboot,
+};
 use super::file_ops::get_exe_dir;
-use super::types::ChangesJson;
+use super::system::ensure_disk_space;
+use super::types::{ChangesJson, PatchEntry, UpdateApplyResult, UpdateCompatReport};
+use super::utils::is_safe_relative_path;
+use crate::i18n::tr;
 
 /// 解压压缩文件到指定目录，支持 zip 和 tar.gz/tgz 格式
 #[tauri::command]
@@ -25,18 +32,19 @@ pub fn extract_zip(zip_path: String, dest_dir: String) -> Result<(), String> {
 /// 解压 ZIP 文件
 fn extract_zip_file(zip_path: &str, dest_dir: &str) -> Result<(), String> {
     let file = std::fs::File::open(zip_path)
-        .map_err(|e| format!("无法打开 ZIP 文件 [{}]: {}", zip_path, e))?;
+        .map_err(|e| tr!("error.update.open_archive", path = zip_path, error = e))?;
 
     let mut archive =
-        zip::ZipArchive::new(file).map_err(|e| format!("无法解析 ZIP 文件: {}", e))?;
+        zip::ZipArchive::new(file).map_err(|e| tr!("error.update.parse_zip", error = e))?;
 
     // 确保目标目录存在
-    std::fs::create_dir_all(dest_dir).map_err(|e| format!("无法创建目录 [{}]: {}", dest_dir, e))?;
+    std::fs::create_dir_all(dest_dir)
+        .map_err(|e| tr!("error.fs.create_dir", path = dest_dir, error = e))?;
 
     for i in 0..archive.len() {
         let mut file = archive
             .by_index(i)
-            .map_err(|e| format!("无法读取 ZIP 条目 {}: {}", i, e))?;
+            .map_err(|e| tr!("error.update.read_zip_entry", index = i, error = e))?;
 
         let outpath = match file.enclosed_name() {
             Some(path) => std::path::Path::new(dest_dir).join(path),
@@ -46,19 +54,19 @@ fn extract_zip_file(zip_path: &str, dest_dir: &str) -> Result<(), String> {
         if file.name().ends_with('/') {
             // 目录
             std::fs::create_dir_all(&outpath)
-                .map_err(|e| format!("无法创建目录 [{}]: {}", outpath.display(), e))?;
+                .map_err(|e| tr!("error.fs.create_dir", path = outpath.display(), error = e))?;
         } else {
             // 文件
             if let Some(p) = outpath.parent() {
                 if !p.exists() {
                     std::fs::create_dir_all(p)
-                        .map_err(|e| format!("无法创建父目录 [{}]: {}", p.display(), e))?;
+                        .map_err(|e| tr!("error.fs.create_dir", path = p.display(), error = e))?;
                 }
             }
             let mut outfile = std::fs::File::create(&outpath)
-                .map_err(|e| format!("无法创建文件 [{}]: {}", outpath.display(), e))?;
+                .map_err(|e| tr!("error.fs.create_file", path = outpath.display(), error = e))?;
             std::io::copy(&mut file, &mut outfile)
-                .map_err(|e| format!("无法写入文件 [{}]: {}", outpath.display(), e))?;
+                .map_err(|e| tr!("error.fs.write_file", path = outpath.display(), error = e))?;
         }
     }
 
@@ -72,17 +80,18 @@ fn extract_tar_gz(tar_path: &str, dest_dir: &str) -> Result<(), String> {
     use tar::Archive;
 
     let file = std::fs::File::open(tar_path)
-        .map_err(|e| format!("无法打开 tar.gz 文件 [{}]: {}", tar_path, e))?;
+        .map_err(|e| tr!("error.update.open_archive", path = tar_path, error = e))?;
 
     let gz = GzDecoder::new(file);
     let mut archive = Archive::new(gz);
 
     // 确保目标目录存在
-    std::fs::create_dir_all(dest_dir).map_err(|e| format!("无法创建目录 [{}]: {}", dest_dir, e))?;
+    std::fs::create_dir_all(dest_dir)
+        .map_err(|e| tr!("error.fs.create_dir", path = dest_dir, error = e))?;
 
     archive
         .unpack(dest_dir)
-        .map_err(|e| format!("解压 tar.gz 失败: {}", e))?;
+        .map_err(|e| tr!("error.update.extract_failed", error = e))?;
 
     info!("extract_tar_gz success");
     Ok(())
@@ -98,15 +107,17 @@ pub fn check_changes_json(extract_dir: String) -> Result<Option<ChangesJson>, St
     }
 
     let content = std::fs::read_to_string(&changes_path)
-        .map_err(|e| format!("无法读取 changes.json: {}", e))?;
+        .map_err(|e| tr!("error.update.read_changes", error = e))?;
 
     let changes: ChangesJson =
-        serde_json::from_str(&content).map_err(|e| format!("无法解析 changes.json: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| tr!("error.update.parse_changes", error = e))?;
 
     Ok(Some(changes))
 }
 
-/// 递归清理目录内容，逐个删除文件和空目录，返回 (成功数, 失败数)
+/// 递归清理目录内容，返回 (成功数, 失败数)
+///
+/// 每个条目优先整体移入回收站；失败时（回收站不可用或文件被占用）逐个删除文件和空目录。
 pub fn cleanup_dir_contents(dir: &std::path::Path) -> (usize, usize) {
     let mut deleted = 0;
     let mut failed = 0;
@@ -114,7 +125,9 @@ pub fn cleanup_dir_contents(dir: &std::path::Path) -> (usize, usize) {
     if let Ok(entries) = std::fs::read_dir(dir) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if path.is_dir() {
+            if trash::delete(&path).is_ok() {
+                deleted += 1;
+            } else if path.is_dir() {
                 // 递归清理子目录
                 let (d, f) = cleanup_dir_contents(&path);
                 deleted += d;
@@ -174,11 +187,11 @@ pub fn move_to_old_folder(source: &std::path::Path) -> Result<(), String> {
 
     // 确保目录存在（刚删掉的话需要重新创建）
     std::fs::create_dir_all(&old_dir)
-        .map_err(|e| format!("无法创建 old 目录 [{}]: {}", old_dir.display(), e))?;
+        .map_err(|e| tr!("error.fs.create_dir", path = old_dir.display(), error = e))?;
 
     let file_name = source
         .file_name()
-        .ok_or_else(|| format!("无法获取文件名: {}", source.display()))?;
+        .ok_or_else(|| tr!("error.fs.no_file_name", path = source.display()))?;
 
     let mut dest = old_dir.join(file_name);
 
@@ -197,11 +210,11 @@ pub fn move_to_old_folder(source: &std::path::Path) -> Result<(), String> {
 
     // 执行移动（重命名）
     std::fs::rename(source, &dest).map_err(|e| {
-        format!(
-            "无法移动 [{}] -> [{}]: {}",
-            source.display(),
-            dest.display(),
-            e
+        tr!(
+            "error.fs.move",
+            from = source.display(),
+            to = dest.display(),
+            error = e
         )
     })?;
 
@@ -209,6 +222,21 @@ pub fn move_to_old_folder(source: &std::path::Path) -> Result<(), String> {
     Ok(())
 }
 
+/// 递归统计目录下所有文件的总大小（字节），读取失败的条目忽略
+fn dir_size(path: &std::path::Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(ft) if ft.is_dir() => dir_size(&entry.path()),
+            Ok(ft) if ft.is_file() => entry.metadata().map(|m| m.len()).unwrap_or(0),
+            _ => 0,
+        })
+        .sum()
+}
+
 /// 删除文件或目录（目录递归删除）
 fn remove_path(path: &std::path::Path) -> std::io::Result<()> {
     if path.is_dir() {
@@ -237,6 +265,54 @@ fn normalize_relative_path(raw: &str) -> &str {
     s
 }
 
+/// 规范化 changes.json 中的相对路径，并拒绝含 `..`、绝对路径或盘符前缀等会越出基准目录的路径
+fn checked_relative_path(raw: &str) -> Result<&str, String> {
+    let normalized = normalize_relative_path(raw);
+    if is_safe_relative_path(normalized) {
+        Ok(normalized)
+    } else {
+        Err(tr!("error.update.unsafe_path", path = raw))
+    }
+}
+
+/// 校验更新包的发布签名（`<更新包>.sig`），未内置发布公钥的开发构建跳过校验
+///
+/// 返回是否实际完成了签名校验。
+fn verify_package_signature(package_path: Option<&str>) -> Result<bool, String> {
+    if !super::signature::has_public_key() {
+        warn!("Release public key not embedded, skipping update signature check");
+        return Ok(false);
+    }
+    let package = std::path::Path::new(
+        package_path
+            .filter(|p| !p.is_empty())
+            .ok_or_else(|| tr!("error.update.signature_no_package"))?,
+    );
+    let sig_path = super::signature::signature_path(package);
+    if !sig_path.is_file() {
+        return Err(tr!(
+            "error.update.signature_missing",
+            path = sig_path.display()
+        ));
+    }
+    super::signature::verify_file_digest(package, &sig_path)
+        .map_err(|e| tr!("error.update.signature_rejected", error = e))?;
+    info!("Update package signature verified: {}", package.display());
+    Ok(true)
+}
+
+/// 校验下载的更新包签名（安装前调用，返回 false 表示当前构建未内置公钥而跳过校验）
+#[tauri::command]
+pub fn verify_update_signature(package_path: String) -> Result<bool, String> {
+    verify_package_signature(Some(&package_path))
+}
+
+/// 当前构建是否内置发布公钥（为 true 时更新包必须附带签名）
+#[tauri::command]
+pub fn update_signature_required() -> bool {
+    super::signature::has_public_key()
+}
+
 /// 应用增量更新：将 deleted 中的文件移动到 old 文件夹，然后复制新文件
 /// 即使移动旧文件失败，也会继续复制新文件，确保程序可用
 #[tauri::command]
@@ -244,18 +320,39 @@ pub fn apply_incremental_update(
     extract_dir: String,
     target_dir: String,
     deleted_files: Vec<String>,
-) -> Result<(), String> {
+    schedule_on_reboot: Option<bool>,
+    package_path: Option<String>,
+) -> Result<UpdateApplyResult, String> {
     info!("apply_incremental_update called");
     info!("extract_dir: {}, target_dir: {}", extract_dir, target_dir);
     info!("deleted_files: {:?}", deleted_files);
 
+    // 签名校验通过前不改动安装目录
+    verify_package_signature(package_path.as_deref())?;
+
     let target_path = std::path::Path::new(&target_dir);
     let mut move_errors: Vec<String> = Vec::new();
 
-    // 1. 尝试将 deleted 中列出的文件移动到 old 文件夹（失败时兜底直接删除）
+    // 1. 应用二进制差分补丁：校验全部旧文件与结果哈希，并在解压目录中生成完整新文件，
+    //    交由第 3 步统一复制。此步骤只读取安装目录，任一补丁校验失败直接返回错误，
+    //    安装目录保持原样，由前端回退到全量更新
+    let extract_path = std::path::Path::new(&extract_dir);
+    if let Some(changes) = check_changes_json(extract_dir.clone())? {
+        if !changes.patched.is_empty() {
+            apply_patches(extract_path, target_path, &changes.patched)?;
+        }
+    }
+
+    // 2. 尝试将 deleted 中列出的文件移动到 old 文件夹（失败时兜底直接删除）
     for file in &deleted_files {
-        // 规范化 changes.json 里的相对路径，避免前导分隔符导致 join 偏离 target_dir
-        let normalized = normalize_relative_path(file);
+        // 规范化 changes.json 里的相对路径，避免前导分隔符或 `..` 导致 join 偏离 target_dir
+        let normalized = match checked_relative_path(file) {
+            Ok(normalized) => normalized,
+            Err(e) => {
+                warn!("{}", e);
+                continue;
+            }
+        };
         let file_path = target_path.join(normalized);
         if file_path.exists() {
             if let Err(e) = move_to_old_folder(&file_path) {
@@ -276,8 +373,12 @@ pub fn apply_incremental_update(
         }
     }
 
-    // 2. 复制新包内容到目标目录（覆盖）- 这一步必须执行
-    copy_dir_contents(&extract_dir, &target_dir, Some(&["changes.json"]))?;
+    // 3. 复制新包内容到目标目录（覆盖）- 这一步必须执行
+    let mut ctx = CopyContext {
+        schedule_on_reboot: schedule_on_reboot.unwrap_or(false),
+        ..Default::default()
+    };
+    copy_dir_contents(&extract_dir, &target_dir, Some(&["changes.json"]), &mut ctx)?;
 
     if !move_errors.is_empty() {
         info!(
@@ -287,23 +388,199 @@ pub fn apply_incremental_update(
     } else {
         info!("apply_incremental_update success");
     }
+    Ok(UpdateApplyResult {
+        pending_reboot_files: ctx.pending_reboot,
+    })
+}
+
+// ============================================================================
+// 二进制差分补丁
+// ============================================================================
+
+/// 计算数据的 SHA-256（小写十六进制）
+fn sha256_hex(data: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// 查找 hpatchz 可执行文件（随程序分发，位于 exe 目录）
+fn find_hpatchz() -> Option<std::path::PathBuf> {
+    let exe_dir = get_exe_dir().ok()?;
+    let name = if cfg!(windows) {
+        "hpatchz.exe"
+    } else {
+        "hpatchz"
+    };
+    let path = std::path::Path::new(&exe_dir).join(name);
+    path.exists().then_some(path)
+}
+
+/// 使用指定格式将补丁应用到旧文件，返回新文件内容
+fn apply_patch_data(
+    format: &str,
+    old_path: &std::path::Path,
+    old_data: &[u8],
+    patch_path: &std::path::Path,
+) -> Result<Vec<u8>, String> {
+    match format {
+        "bsdiff" => {
+            let patch_data = std::fs::read(patch_path).map_err(|e| {
+                tr!(
+                    "error.update.read_patch",
+                    path = patch_path.display(),
+                    error = e
+                )
+            })?;
+            let mut new_data = Vec::new();
+            bsdiff::patch(old_data, &mut patch_data.as_slice(), &mut new_data).map_err(|e| {
+                tr!(
+                    "error.update.patch_failed",
+                    path = patch_path.display(),
+                    error = e
+                )
+            })?;
+            Ok(new_data)
+        }
+        "hdiff" | "hpatchz" => {
+            let hpatchz = find_hpatchz().ok_or_else(|| tr!("error.update.hpatchz_missing"))?;
+            let out_path = patch_path.with_extension("hpatchz.out");
+            let mut cmd = std::process::Command::new(&hpatchz);
+            cmd.arg("-f").arg(old_path).arg(patch_path).arg(&out_path);
+            #[cfg(windows)]
+            {
+                use std::os::windows::process::CommandExt;
+                const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+                cmd.creation_flags(CREATE_NO_WINDOW);
+            }
+            let status = cmd
+                .status()
+                .map_err(|e| tr!("error.update.hpatchz_run", error = e))?;
+            if !status.success() {
+                let _ = std::fs::remove_file(&out_path);
+                return Err(tr!(
+                    "error.update.hpatchz_failed",
+                    path = patch_path.display(),
+                    code = status.code().unwrap_or(-1)
+                ));
+            }
+            let new_data = std::fs::read(&out_path).map_err(|e| {
+                tr!(
+                    "error.update.read_patch",
+                    path = out_path.display(),
+                    error = e
+                )
+            });
+            let _ = std::fs::remove_file(&out_path);
+            new_data
+        }
+        other => Err(tr!("error.update.patch_format", format = other)),
+    }
+}
+
+/// 对单个条目应用差分补丁：校验旧文件哈希 → 应用补丁 → 校验新文件哈希，返回新文件内容
+///
+/// 不修改安装目录；hpatchz 格式会在解压目录中留下临时输出文件。
+fn patch_entry(
+    extract_dir: &std::path::Path,
+    target_dir: &std::path::Path,
+    entry: &PatchEntry,
+) -> Result<Vec<u8>, String> {
+    let file_rel = checked_relative_path(&entry.file)?;
+    let old_path = target_dir.join(file_rel);
+    let patch_path = extract_dir.join(checked_relative_path(&entry.patch)?);
+
+    let old_data = std::fs::read(&old_path)
+        .map_err(|e| tr!("error.fs.read_file", path = old_path.display(), error = e))?;
+    let old_hash = sha256_hex(&old_data);
+    if !old_hash.eq_ignore_ascii_case(&entry.old_sha256) {
+        return Err(tr!(
+            "error.update.source_hash_mismatch",
+            path = file_rel,
+            expected = entry.old_sha256,
+            actual = old_hash
+        ));
+    }
+
+    let new_data = apply_patch_data(&entry.format, &old_path, &old_data, &patch_path)?;
+    let new_hash = sha256_hex(&new_data);
+    if !new_hash.eq_ignore_ascii_case(&entry.new_sha256) {
+        return Err(tr!(
+            "error.update.result_hash_mismatch",
+            path = file_rel,
+            expected = entry.new_sha256,
+            actual = new_hash
+        ));
+    }
+    Ok(new_data)
+}
+
+/// 应用 changes.json 中列出的差分补丁
+///
+/// 对每个条目生成并校验新文件，写入解压目录中的同名路径，并删除补丁文件本身，
+/// 使后续复制步骤按普通文件覆盖处理。只读取安装目录，不做任何修改。
+fn apply_patches(
+    extract_dir: &std::path::Path,
+    target_dir: &std::path::Path,
+    patches: &[PatchEntry],
+) -> Result<(), String> {
+    info!("Applying {} binary patches", patches.len());
+
+    for entry in patches {
+        let file_rel = checked_relative_path(&entry.file)?;
+        let patch_path = extract_dir.join(checked_relative_path(&entry.patch)?);
+        let new_data = patch_entry(extract_dir, target_dir, entry)?;
+
+        let out_path = extract_dir.join(file_rel);
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+        }
+        std::fs::write(&out_path, &new_data)
+            .map_err(|e| tr!("error.fs.write_file", path = out_path.display(), error = e))?;
+
+        if patch_path != out_path {
+            let _ = std::fs::remove_file(&patch_path);
+        }
+
+        info!(
+            "Patched {} ({}, {} bytes)",
+            file_rel,
+            entry.format,
+            new_data.len()
+        );
+    }
+
     Ok(())
 }
 
 /// 应用全量更新：将与新包根目录同名的文件夹/文件移动到 old 文件夹，然后复制新文件
 /// 即使移动旧文件失败，也会继续复制新文件，确保程序可用
 #[tauri::command]
-pub fn apply_full_update(extract_dir: String, target_dir: String) -> Result<(), String> {
+pub fn apply_full_update(
+    extract_dir: String,
+    target_dir: String,
+    schedule_on_reboot: Option<bool>,
+    package_path: Option<String>,
+) -> MxuResult<UpdateApplyResult> {
     info!("apply_full_update called");
     info!("extract_dir: {}, target_dir: {}", extract_dir, target_dir);
 
+    // 签名校验通过前不改动安装目录
+    verify_package_signature(package_path.as_deref())?;
+
     let extract_path = std::path::Path::new(&extract_dir);
     let target_path = std::path::Path::new(&target_dir);
     let mut move_errors: Vec<String> = Vec::new();
 
+    // 0. 预检磁盘空间：旧文件移入 cache/old 不额外占用空间，只需容纳新包内容
+    ensure_disk_space(target_path, dir_size(extract_path))?;
+
     // 1. 获取解压目录中的根级条目
     let entries: Vec<_> = std::fs::read_dir(extract_path)
-        .map_err(|e| format!("无法读取解压目录: {}", e))?
+        .map_err(|e| tr!("error.update.read_extract_dir", error = e))?
         .filter_map(|e| e.ok())
         .collect();
 
@@ -336,7 +613,11 @@ pub fn apply_full_update(extract_dir: String, target_dir: String) -> Result<(),
     }
 
     // 3. 复制新包内容到目标目录 - 这一步必须执行
-    copy_dir_contents(&extract_dir, &target_dir, Some(&["changes.json"]))?;
+    let mut ctx = CopyContext {
+        schedule_on_reboot: schedule_on_reboot.unwrap_or(false),
+        ..Default::default()
+    };
+    copy_dir_contents(&extract_dir, &target_dir, Some(&["changes.json"]), &mut ctx)?;
 
     if !move_errors.is_empty() {
         info!(
@@ -346,12 +627,30 @@ pub fn apply_full_update(extract_dir: String, target_dir: String) -> Result<(),
     } else {
         info!("apply_full_update success");
     }
-    Ok(())
+    Ok(UpdateApplyResult {
+        pending_reboot_files: ctx.pending_reboot,
+    })
+}
+
+/// 复制过程中的选项与收集到的结果
+#[derive(Default)]
+struct CopyContext {
+    /// 目标文件被占用时，是否安排在下次开机时替换
+    schedule_on_reboot: bool,
+    /// 已安排在下次开机时替换的文件
+    pending_reboot: Vec<String>,
 }
 
 /// 复制单个文件，先尝试将目标文件移动到 old 目录再复制
 /// 如果移动失败，直接尝试覆盖（确保新文件能被复制）
-fn copy_file_with_move_old(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
+///
+/// 目标文件被占用导致复制失败时，报告占用进程；若启用了 `schedule_on_reboot`，
+/// 则将新文件暂存到目标旁边并安排下次开机时替换。
+fn copy_file_with_move_old(
+    src: &std::path::Path,
+    dst: &std::path::Path,
+    ctx: &mut CopyContext,
+) -> Result<(), String> {
     // 如果目标文件存在，先尝试移动到 old 目录
     if dst.exists() {
         if let Err(e) = move_to_old_folder(dst) {
@@ -364,30 +663,85 @@ fn copy_file_with_move_old(src: &std::path::Path, dst: &std::path::Path) -> Resu
     }
 
     // 复制新文件
-    std::fs::copy(src, dst).map_err(|e| {
-        format!(
-            "无法复制文件 [{}] -> [{}]: {}",
-            src.display(),
-            dst.display(),
-            e
-        )
-    })?;
+    let copy_err = match std::fs::copy(src, dst) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+
+    if !is_locked_error(&copy_err) {
+        return Err(tr!(
+            "error.fs.copy",
+            from = src.display(),
+            to = dst.display(),
+            error = copy_err
+        ));
+    }
 
-    Ok(())
+    let lockers = find_locking_processes(dst);
+    let locker_desc = if lockers.is_empty() {
+        tr!("error.update.unknown_process")
+    } else {
+        describe_lockers(&lockers)
+    };
+    warn!(
+        "目标文件被占用 [{}]，占用进程: {}",
+        dst.display(),
+        locker_desc
+    );
+
+    if ctx.schedule_on_reboot {
+        // 暂存文件需与目标位于同一卷，MoveFileEx 才能在开机时完成替换
+        let staged = dst.with_file_name(format!(
+            "{}.mxu_pending",
+            dst.file_name().unwrap_or_default().to_string_lossy()
+        ));
+        std::fs::copy(src, &staged).map_err(|e| {
+            tr!(
+                "error.update.stage_file",
+                path = staged.display(),
+                error = e
+            )
+        })?;
+        if let Err(e) = schedule_replace_on_reboot(&staged, dst) {
+            let _ = std::fs::remove_file(&staged);
+            return Err(tr!(
+                "error.update.in_use_suffix",
+                error = e,
+                lockers = locker_desc
+            ));
+        }
+        info!("已安排下次开机时替换: {}", dst.display());
+        ctx.pending_reboot.push(dst.display().to_string());
+        return Ok(());
+    }
+
+    Err(tr!(
+        "error.update.copy_locked",
+        from = src.display(),
+        to = dst.display(),
+        lockers = locker_desc,
+        error = copy_err
+    ))
 }
 
 /// 递归复制目录内容（不包含根目录本身）
-fn copy_dir_contents(src: &str, dst: &str, skip_files: Option<&[&str]>) -> Result<(), String> {
+fn copy_dir_contents(
+    src: &str,
+    dst: &str,
+    skip_files: Option<&[&str]>,
+    ctx: &mut CopyContext,
+) -> Result<(), String> {
     let src_path = std::path::Path::new(src);
     let dst_path = std::path::Path::new(dst);
 
     // 确保目标目录存在
-    std::fs::create_dir_all(dst_path).map_err(|e| format!("无法创建目录 [{}]: {}", dst, e))?;
+    std::fs::create_dir_all(dst_path)
+        .map_err(|e| tr!("error.fs.create_dir", path = dst, error = e))?;
 
     for entry in
-        std::fs::read_dir(src_path).map_err(|e| format!("无法读取目录 [{}]: {}", src, e))?
+        std::fs::read_dir(src_path).map_err(|e| tr!("error.fs.read_dir", path = src, error = e))?
     {
-        let entry = entry.map_err(|e| format!("无法读取目录条目: {}", e))?;
+        let entry = entry.map_err(|e| tr!("error.fs.read_dir_entry", error = e))?;
         let file_name = entry.file_name();
         let file_name_str = file_name.to_string_lossy();
 
@@ -402,9 +756,9 @@ fn copy_dir_contents(src: &str, dst: &str, skip_files: Option<&[&str]>) -> Resul
         let dst_item = dst_path.join(&file_name);
 
         if src_item.is_dir() {
-            copy_dir_recursive(&src_item, &dst_item)?;
+            copy_dir_recursive(&src_item, &dst_item, ctx)?;
         } else {
-            copy_file_with_move_old(&src_item, &dst_item)?;
+            copy_file_with_move_old(&src_item, &dst_item, ctx)?;
         }
     }
 
@@ -412,20 +766,25 @@ fn copy_dir_contents(src: &str, dst: &str, skip_files: Option<&[&str]>) -> Resul
 }
 
 /// 递归复制整个目录
-fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
-    std::fs::create_dir_all(dst).map_err(|e| format!("无法创建目录 [{}]: {}", dst.display(), e))?;
+fn copy_dir_recursive(
+    src: &std::path::Path,
+    dst: &std::path::Path,
+    ctx: &mut CopyContext,
+) -> Result<(), String> {
+    std::fs::create_dir_all(dst)
+        .map_err(|e| tr!("error.fs.create_dir", path = dst.display(), error = e))?;
 
-    for entry in
-        std::fs::read_dir(src).map_err(|e| format!("无法读取目录 [{}]: {}", src.display(), e))?
+    for entry in std::fs::read_dir(src)
+        .map_err(|e| tr!("error.fs.read_dir", path = src.display(), error = e))?
     {
-        let entry = entry.map_err(|e| format!("无法读取目录条目: {}", e))?;
+        let entry = entry.map_err(|e| tr!("error.fs.read_dir_entry", error = e))?;
         let src_item = entry.path();
         let dst_item = dst.join(entry.file_name());
 
         if src_item.is_dir() {
-            copy_dir_recursive(&src_item, &dst_item)?;
+            copy_dir_recursive(&src_item, &dst_item, ctx)?;
         } else {
-            copy_file_with_move_old(&src_item, &dst_item)?;
+            copy_file_with_move_old(&src_item, &dst_item, ctx)?;
         }
     }
 
@@ -476,7 +835,7 @@ pub fn cleanup_extract_dir(extract_dir: String) -> Result<(), String> {
     let path = std::path::Path::new(&extract_dir);
     if path.exists() {
         std::fs::remove_dir_all(path)
-            .map_err(|e| format!("无法清理目录 [{}]: {}", extract_dir, e))?;
+            .map_err(|e| tr!("error.fs.clean_dir", path = extract_dir, error = e))?;
     }
 
     Ok(())
@@ -489,12 +848,15 @@ pub fn fallback_update(
     extract_dir: String,
     target_dir: String,
     new_version: String,
+    package_path: Option<String>,
 ) -> Result<String, String> {
     info!(
         "fallback_update called: extract_dir={}, target_dir={}, new_version={}",
         extract_dir, target_dir, new_version
     );
 
+    verify_package_signature(package_path.as_deref())?;
+
     let target_path = std::path::Path::new(&target_dir);
 
     // 创建 v版本号 文件夹（如 v1.2.3）
@@ -512,20 +874,22 @@ pub fn fallback_update(
     info!("创建兜底目录: {}", final_fallback_dir.display());
 
     // 创建兜底目录
-    std::fs::create_dir_all(&final_fallback_dir).map_err(|e| format!("无法创建兜底目录: {}", e))?;
+    std::fs::create_dir_all(&final_fallback_dir)
+        .map_err(|e| tr!("error.update.create_fallback_dir", error = e))?;
 
     // 复制解压的新文件到兜底目录
     copy_dir_contents(
         &extract_dir,
         final_fallback_dir.to_str().unwrap_or(""),
         Some(&["changes.json"]),
+        &mut CopyContext::default(),
     )?;
 
     // 复制 config 文件夹（如果存在）
     let config_src = target_path.join("config");
     if config_src.exists() {
         let config_dst = final_fallback_dir.join("config");
-        if let Err(e) = copy_dir_recursive(&config_src, &config_dst) {
+        if let Err(e) = copy_dir_recursive(&config_src, &config_dst, &mut CopyContext::default()) {
             warn!("复制 config 文件夹失败: {}", e);
         } else {
             info!("已复制 config 文件夹到兜底目录");
@@ -537,3 +901,360 @@ pub fn fallback_update(
 
     Ok(result_path)
 }
+
+// ============================================================================
+// 更新包兼容性检查
+// ============================================================================
+
+/// 探测子进程的最长等待时间
+const MAAFW_PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+/// 以子进程方式加载 maafw 目录下的 MaaFramework，成功时返回版本号
+fn probe_maafw(maafw_dir: &std::path::Path) -> Result<String, String> {
+    use std::io::Read;
+    use std::process::{Command, Stdio};
+
+    let exe = std::env::current_exe().map_err(|e| tr!("error.fs.exe_path", error = e))?;
+    let mut cmd = Command::new(exe);
+    cmd.arg(super::system::MAAFW_PROBE_FLAG)
+        .arg(maafw_dir)
+        .current_dir(maafw_dir.parent().unwrap_or(maafw_dir))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| tr!("error.update.probe_spawn", error = e))?;
+
+    let started = std::time::Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() > MAAFW_PROBE_TIMEOUT => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(tr!("error.update.probe_timeout"));
+            }
+            Ok(None) => std::thread::sleep(std::time::Duration::from_millis(100)),
+            Err(e) => return Err(tr!("error.update.probe_wait", error = e)),
+        }
+    };
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    if let Some(mut out) = child.stdout.take() {
+        let _ = out.read_to_string(&mut stdout);
+    }
+    if let Some(mut err) = child.stderr.take() {
+        let _ = err.read_to_string(&mut stderr);
+    }
+
+    if status.success() {
+        Ok(stdout.trim().to_string())
+    } else {
+        Err(tr!(
+            "error.update.probe_failed",
+            code = status.code().unwrap_or(-1),
+            output = stderr.trim()
+        ))
+    }
+}
+
+/// 去掉相对路径中的 `maafw/` 前缀，非 maafw 目录下的路径返回 None
+fn strip_maafw_prefix(raw: &str) -> Option<String> {
+    let normalized = checked_relative_path(raw).ok()?.replace('\\', "/");
+    normalized
+        .strip_prefix("maafw/")
+        .filter(|rest| !rest.is_empty())
+        .map(str::to_string)
+}
+
+/// 递归复制目录（直接覆盖，不移动旧文件到 old 目录，仅用于临时探测目录）
+fn copy_dir_plain(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
+    std::fs::create_dir_all(dst)
+        .map_err(|e| tr!("error.fs.create_dir", path = dst.display(), error = e))?;
+
+    for entry in std::fs::read_dir(src)
+        .map_err(|e| tr!("error.fs.read_dir", path = src.display(), error = e))?
+    {
+        let entry = entry.map_err(|e| tr!("error.fs.read_dir_entry", error = e))?;
+        let src_item = entry.path();
+        let dst_item = dst.join(entry.file_name());
+
+        if src_item.is_dir() {
+            copy_dir_plain(&src_item, &dst_item)?;
+        } else {
+            std::fs::copy(&src_item, &dst_item).map_err(|e| {
+                tr!(
+                    "error.fs.copy",
+                    from = src_item.display(),
+                    to = dst_item.display(),
+                    error = e
+                )
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 在临时目录中组装更新后的 maafw 目录，更新包不涉及 maafw 时返回 None
+///
+/// - 全量包：直接使用解压目录中的 maafw
+/// - 增量包：复制安装目录中的 maafw，叠加新文件、应用差分补丁并移除 deleted 文件
+///
+/// 全程不修改安装目录。
+fn stage_updated_maafw(
+    extract_dir: &std::path::Path,
+    target_dir: &std::path::Path,
+    probe_dir: &std::path::Path,
+) -> Result<Option<std::path::PathBuf>, String> {
+    let new_maafw = extract_dir.join("maafw");
+    let changes = check_changes_json(extract_dir.to_string_lossy().to_string())?;
+
+    let Some(changes) = changes else {
+        return Ok(new_maafw.exists().then_some(new_maafw));
+    };
+
+    let patched: Vec<(&PatchEntry, String)> = changes
+        .patched
+        .iter()
+        .filter_map(|entry| strip_maafw_prefix(&entry.file).map(|rel| (entry, rel)))
+        .collect();
+    let deleted: Vec<String> = changes
+        .deleted
+        .iter()
+        .filter_map(|file| strip_maafw_prefix(file))
+        .collect();
+    if !new_maafw.exists() && patched.is_empty() && deleted.is_empty() {
+        return Ok(None);
+    }
+
+    if probe_dir.exists() {
+        let _ = std::fs::remove_dir_all(probe_dir);
+    }
+    let old_maafw = target_dir.join("maafw");
+    if old_maafw.exists() {
+        copy_dir_plain(&old_maafw, probe_dir)?;
+    }
+    if new_maafw.exists() {
+        copy_dir_plain(&new_maafw, probe_dir)?;
+    }
+    for (entry, rel) in patched {
+        let new_data = patch_entry(extract_dir, target_dir, entry)?;
+        let out_path = probe_dir.join(&rel);
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| tr!("error.fs.create_dir", path = parent.display(), error = e))?;
+        }
+        std::fs::write(&out_path, &new_data)
+            .map_err(|e| tr!("error.fs.write_file", path = out_path.display(), error = e))?;
+    }
+    for rel in deleted {
+        let _ = remove_path(&probe_dir.join(rel));
+    }
+
+    Ok(Some(probe_dir.to_path_buf()))
+}
+
+/// 安装前检查更新包中的 MaaFramework 兼容性
+///
+/// 在临时目录中组装更新后的 maafw，并在独立进程中加载、比较最小版本。
+/// 此检查不修改安装目录，不兼容时由前端取消本次更新；结果同时通过 `update-compat-report` 事件发送。
+#[tauri::command]
+pub async fn verify_update_compatibility(
+    app: tauri::AppHandle,
+    extract_dir: String,
+    target_dir: String,
+) -> Result<UpdateCompatReport, String> {
+    info!(
+        "verify_update_compatibility: extract_dir={}, target_dir={}",
+        extract_dir, target_dir
+    );
+
+    let extract_path = std::path::PathBuf::from(&extract_dir);
+    let probe_dir = extract_path
+        .parent()
+        .unwrap_or(&extract_path)
+        .join("maafw_probe");
+
+    let probe_result = tokio::task::spawn_blocking(move || {
+        let result =
+            stage_updated_maafw(&extract_path, std::path::Path::new(&target_dir), &probe_dir)
+                .and_then(|staged| match staged {
+                    Some(dir) if super::utils::get_maafw_library_path(&dir).exists() => {
+                        probe_maafw(&dir).map(Some)
+                    }
+                    _ => Ok(None),
+                });
+        if probe_dir.exists() {
+            let _ = std::fs::remove_dir_all(&probe_dir);
+        }
+        result
+    })
+    .await
+    .map_err(|e| tr!("error.update.probe_panicked", error = e))?;
+
+    let probe_result = match probe_result {
+        Ok(Some(version)) => Ok(version),
+        Ok(None) => {
+            // 更新包不涉及 MaaFramework，或项目未附带 MaaFramework，无需检查
+            info!("Update does not change maafw, skip compatibility check");
+            return Ok(UpdateCompatReport {
+                loaded: true,
+                compatible: true,
+                ..Default::default()
+            });
+        }
+        Err(e) => Err(e),
+    };
+
+    Ok(finish_compat_report(&app, probe_result, false))
+}
+
+/// 根据探测结果生成兼容性报告，并通过 `update-compat-report` 事件发送
+fn finish_compat_report(
+    app: &tauri::AppHandle,
+    probe_result: Result<String, String>,
+    installed: bool,
+) -> UpdateCompatReport {
+    use tauri::Emitter;
+
+    let mut report = UpdateCompatReport {
+        installed,
+        ..Default::default()
+    };
+    match probe_result {
+        Ok(version) => {
+            report.loaded = true;
+            match super::maa_core::check_version_compatibility(version.clone()) {
+                Ok(check) => {
+                    report.compatible = check.is_compatible;
+                    report.minimum = Some(check.minimum);
+                }
+                Err(e) => report.error = Some(e),
+            }
+            report.version = Some(version);
+        }
+        Err(e) => report.error = Some(e),
+    }
+
+    if !report.loaded || !report.compatible {
+        warn!(
+            "New MaaFramework is not usable: installed={}, loaded={}, version={:?}, error={:?}",
+            report.installed, report.loaded, report.version, report.error
+        );
+    } else {
+        info!(
+            "New MaaFramework is compatible: installed={}, version={}",
+            report.installed,
+            report.version.as_deref().unwrap_or("")
+        );
+    }
+
+    if let Err(e) = app.emit("update-compat-report", report.clone()) {
+        log::error!("Failed to emit update-compat-report: {}", e);
+    }
+
+    report
+}
+
+/// 应用更新前备份安装目录中的 maafw，返回备份目录；安装目录没有 maafw 时返回 None
+///
+/// 备份放在解压目录旁边的 `maafw_backup`，供安装后检查失败时回滚。
+#[tauri::command]
+pub fn backup_installed_maafw(
+    extract_dir: String,
+    target_dir: String,
+) -> Result<Option<String>, String> {
+    let installed = std::path::Path::new(&target_dir).join("maafw");
+    if !installed.exists() {
+        return Ok(None);
+    }
+
+    let extract_path = std::path::PathBuf::from(&extract_dir);
+    let backup_dir = extract_path
+        .parent()
+        .unwrap_or(&extract_path)
+        .join("maafw_backup");
+    if backup_dir.exists() {
+        remove_path(&backup_dir)
+            .map_err(|e| tr!("error.fs.clean_dir", path = backup_dir.display(), error = e))?;
+    }
+    copy_dir_plain(&installed, &backup_dir)?;
+
+    info!(
+        "backup_installed_maafw: {} -> {}",
+        installed.display(),
+        backup_dir.display()
+    );
+    Ok(Some(backup_dir.to_string_lossy().to_string()))
+}
+
+/// 安装后检查安装目录中的 MaaFramework 能否加载且版本兼容
+///
+/// maafw 中有文件需重启电脑后才能替换时，当前安装目录仍是旧库，跳过检查。
+/// 结果同时通过 `update-compat-report` 事件发送（`installed` 为 true）。
+#[tauri::command]
+pub async fn verify_installed_maafw(
+    app: tauri::AppHandle,
+    target_dir: String,
+    pending_reboot_files: Vec<String>,
+) -> Result<UpdateCompatReport, String> {
+    info!("verify_installed_maafw: target_dir={}", target_dir);
+
+    let maafw_dir = std::path::Path::new(&target_dir).join("maafw");
+    let pending_in_maafw = pending_reboot_files
+        .iter()
+        .any(|file| std::path::Path::new(file).starts_with(&maafw_dir));
+    if pending_in_maafw || !super::utils::get_maafw_library_path(&maafw_dir).exists() {
+        info!("Installed maafw cannot be verified now, skip post-install check");
+        return Ok(UpdateCompatReport {
+            loaded: true,
+            compatible: true,
+            installed: true,
+            ..Default::default()
+        });
+    }
+
+    let probe_result = tokio::task::spawn_blocking(move || probe_maafw(&maafw_dir))
+        .await
+        .map_err(|e| tr!("error.update.probe_panicked", error = e))?;
+
+    Ok(finish_compat_report(&app, probe_result, true))
+}
+
+/// 安装后检查失败时，用更新前的备份替换安装目录中的 maafw
+#[tauri::command]
+pub fn rollback_maafw_update(backup_dir: String, target_dir: String) -> Result<(), String> {
+    info!(
+        "rollback_maafw_update: backup_dir={}, target_dir={}",
+        backup_dir, target_dir
+    );
+
+    let backup = std::path::Path::new(&backup_dir);
+    if !backup.exists() {
+        return Err(tr!("error.update.maafw_backup_missing", path = backup_dir));
+    }
+
+    let installed = std::path::Path::new(&target_dir).join("maafw");
+    if installed.exists() {
+        remove_path(&installed)
+            .map_err(|e| tr!("error.fs.clean_dir", path = installed.display(), error = e))?;
+    }
+    copy_dir_plain(backup, &installed)?;
+
+    if let Err(e) = std::fs::remove_dir_all(backup) {
+        warn!("Failed to remove maafw backup [{}]: {}", backup_dir, e);
+    }
+    info!("MaaFramework rolled back to the version before the update");
+    Ok(())
+}
@@ -2,41 +2,102 @@
 //!
 //! 提供路径处理和其他通用工具函数
 
-use super::types::{MaaCallbackEvent, MaaState, StateChangedEvent};
+use super::maa_event::{MaaEvent, MaaEventPayload};
+use super::types::{DownloadProgressEvent, MaaCallbackEvent, MaaState, StateChangedEvent};
+use crate::i18n::tr;
 use crate::ws_broadcast::{WsBroadcast, WsEvent};
+use parking_lot::Mutex;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager};
 
 /// 发送回调事件到前端（Tauri WebView + WebSocket 浏览器客户端）
-pub fn emit_callback_event<S: Into<String>>(app: &AppHandle, message: S, details: S) {
+///
+/// `instance_id` 为注册该回调的实例，前端据此区分多实例同时连接/运行时的通知归属。
+pub fn emit_callback_event<S: Into<String>>(
+    app: &AppHandle,
+    instance_id: &str,
+    message: S,
+    details: S,
+) {
     let message = message.into();
     let details = details.into();
+    super::focus_log::on_callback(app, instance_id, &message, &details);
+    super::screenshot_history::on_callback(app, instance_id, &message, &details);
+    super::maa_core::check_resolution_change(app, instance_id, &message);
+    let typed = MaaEventPayload {
+        instance_id: instance_id.to_string(),
+        event: MaaEvent::parse(&message, &details),
+    };
 
-    // 广播到所有 WebSocket 客户端
-    if let Some(ws) = app.try_state::<Arc<WsBroadcast>>() {
-        ws.send(WsEvent::MaaCallback {
+    // 广播到所有 WebSocket 客户端，并留存供前端补取
+    broadcast_event(
+        app,
+        Some(instance_id),
+        WsEvent::MaaCallback {
+            instance_id: instance_id.to_string(),
             message: message.clone(),
             details: details.clone(),
-        });
-    }
+        },
+    );
 
     // 发送到 Tauri WebView
-    let event = MaaCallbackEvent { message, details };
+    let event = MaaCallbackEvent {
+        instance_id: instance_id.to_string(),
+        message,
+        details,
+    };
     if let Err(e) = app.emit("maa-callback", event) {
         log::error!("Failed to emit maa-callback: {}", e);
     }
+
+    // 类型化事件（不进入补发缓冲区，补取时使用原始回调即可）
+    if let Some(ws) = app.try_state::<Arc<WsBroadcast>>() {
+        ws.send(WsEvent::MaaEvent(typed.clone()));
+    }
+    if let Err(e) = app.emit("maa-event", typed) {
+        log::error!("Failed to emit maa-event: {}", e);
+    }
+}
+
+/// 广播到所有 WebSocket 客户端，同时写入事件补发缓冲区（见 `mxu_fetch_missed_events`）
+pub(crate) fn broadcast_event(app: &AppHandle, instance_id: Option<&str>, event: WsEvent) {
+    if let Some(state) = app.try_state::<Arc<MaaState>>() {
+        state.event_replay.lock().push(instance_id, event.clone());
+    }
+    if let Some(ws) = app.try_state::<Arc<WsBroadcast>>() {
+        ws.send(event);
+    }
 }
 
-/// 发送实例状态变更事件（双通道：WS 浏览器客户端 + Tauri WebView）
+/// 发送实例状态变更事件（WS 浏览器客户端 + Tauri WebView + MQTT）
 ///
 /// Tauri 端和 WebUI 端都会收到此事件，用于刷新 `isRunning`、连接状态等运行时信息。
 pub fn emit_state_changed(app: &AppHandle, instance_id: &str, kind: &str) {
+    // 发布到 MQTT（未启用时忽略），附带实例整体任务状态
+    let overall_status = app.try_state::<Arc<MaaState>>().and_then(|state| {
+        state
+            .instances
+            .get(instance_id)?
+            .task_run_state
+            .overall_status
+            .clone()
+    });
+    super::mqtt::publish_state_changed(instance_id, kind, overall_status.as_deref());
+
+    super::trace::record_instance(
+        instance_id,
+        log::Level::Info,
+        format!("state-changed: {}", kind),
+    );
+    let correlation_id = super::trace::current_for_instance(instance_id);
+
     // 广播到所有 WebSocket 客户端
     if let Some(ws) = app.try_state::<Arc<WsBroadcast>>() {
         ws.send(WsEvent::StateChanged {
             instance_id: instance_id.to_string(),
             kind: kind.to_string(),
+            correlation_id: correlation_id.clone(),
         });
     }
 
@@ -44,10 +105,49 @@ pub fn emit_state_changed(app: &AppHandle, instance_id: &str, kind: &str) {
     let event = StateChangedEvent {
         instance_id: instance_id.to_string(),
         kind: kind.to_string(),
+        correlation_id,
     };
     if let Err(e) = app.emit("state-changed", event) {
         log::error!("Failed to emit state-changed: {}", e);
     }
+
+    // 刷新托盘运行状态与防休眠请求
+    crate::tray::refresh_status(app);
+    crate::power_guard::refresh(app);
+
+    // 运行期间保持 ADB 设备亮屏
+    super::adb_keep_awake::on_state_changed(app, instance_id, kind);
+
+    // 推进运行结束后的电源操作计划
+    super::post_run::on_state_changed(app, instance_id, kind);
+
+    // 结束运行报告
+    super::run_report::on_state_changed(app, instance_id, kind);
+
+    // 持久化会话快照，供崩溃或重启后恢复
+    super::session::save_snapshot(app);
+}
+
+/// 发送下载进度事件（双通道：WS 订阅者 + Tauri WebView）
+pub fn emit_download_progress(app: &AppHandle, event: DownloadProgressEvent) {
+    // 广播到所有 WebSocket 客户端，并留存供前端补取
+    broadcast_event(
+        app,
+        None,
+        WsEvent::DownloadProgress {
+            session_id: event.session_id,
+            downloaded_size: event.downloaded_size,
+            total_size: event.total_size,
+            speed: event.speed,
+            progress: event.progress,
+        },
+    );
+
+    // 刷新下载进度通知（仅已跟踪的会话）
+    crate::notification::update_download(event.session_id, event.progress);
+
+    // 发送到 Tauri WebView
+    let _ = app.emit("download-progress", event);
 }
 
 /// 处理 MaaFramework 任务回调，在 Rust 侧更新 TaskRunState（单一真相来源）
@@ -69,25 +169,67 @@ pub fn handle_task_callback(
     if !is_started && !is_succeeded && !is_failed {
         return;
     }
+    super::trace::record_instance(
+        instance_id,
+        log::Level::Debug,
+        format!("{} {}", message, details),
+    );
 
     // 解析 task_id
-    let task_id: i64 = match serde_json::from_str::<serde_json::Value>(details)
-        .ok()
+    let details_value = serde_json::from_str::<serde_json::Value>(details).ok();
+    // 识别测试的临时任务不计入任务运行状态
+    if details_value
+        .as_ref()
+        .and_then(|v| v.get("entry").and_then(|e| e.as_str()))
+        == Some(super::authoring::QUICK_TEST_ENTRY)
+    {
+        return;
+    }
+
+    let task_id: i64 = match details_value
+        .as_ref()
         .and_then(|v| v.get("task_id").and_then(|id| id.as_i64()))
     {
         Some(id) => id,
         None => return,
     };
 
-    let all_done = {
-        let mut instances = match maa_state.instances.lock() {
-            Ok(g) => g,
-            Err(_) => return,
-        };
-        let instance = match instances.get_mut(instance_id) {
-            Some(i) => i,
+    // 已撤下的任务（“当前任务完成后停止”）不计入运行状态
+    let skipped = maa_state
+        .instances
+        .get(instance_id)
+        .is_some_and(|i| i.skipped_task_ids.contains(&task_id));
+    if skipped {
+        return;
+    }
+
+    if is_succeeded || is_failed {
+        let entry = details_value
+            .as_ref()
+            .and_then(|v| v.get("entry").and_then(|e| e.as_str()));
+        super::mqtt::publish_task_finished(instance_id, task_id, entry, is_succeeded);
+    }
+
+    let (all_done, skipped) = {
+        let mut guard = match maa_state.instances.get_mut(instance_id) {
+            Some(g) => g,
             None => return,
         };
+        let instance = guard.value_mut();
+        // 任务结束：先撤下依赖失败任务的任务以及执行条件不满足的任务，使其不计入完成判断
+        let mut skipped = Vec::new();
+        if !is_started {
+            instance
+                .task_graph
+                .record_outcome(task_id, if is_succeeded { "succeeded" } else { "failed" });
+            if is_failed {
+                skipped.extend(super::task_graph::skip_dependents(instance, task_id));
+            }
+            skipped.extend(super::task_graph::skip_unmet_conditions(
+                instance,
+                instance_id,
+            ));
+        }
         let state = &mut instance.task_run_state;
 
         if is_started {
@@ -98,7 +240,7 @@ pub fn handle_task_callback(
             if let Some(selected_id) = state.mappings.get(&task_id).cloned() {
                 state.statuses.insert(selected_id, "running".to_string());
             }
-            false // 未完成
+            (false, skipped) // 未完成
         } else {
             // 任务成功或失败
             let status_str = if is_succeeded { "succeeded" } else { "failed" };
@@ -123,10 +265,43 @@ pub fn handle_task_callback(
                 instance.task_ids.clear();
             }
 
-            all_completed
+            (all_completed, skipped)
         }
     }; // 锁在此处释放
 
+    super::task_graph::emit_skipped(app, instance_id, skipped);
+
+    // 任务失败时保存现场截图
+    let failure_capture = if is_failed {
+        let entry = details_value
+            .as_ref()
+            .and_then(|v| v.get("entry").and_then(|e| e.as_str()))
+            .unwrap_or_default();
+        super::failure_capture::capture(app, instance_id, task_id, entry)
+    } else {
+        None
+    };
+
+    if is_started {
+        super::task_timeout::on_task_started(app, maa_state, instance_id, task_id);
+    } else {
+        super::task_timeout::on_task_finished(instance_id, task_id);
+    }
+
+    let report_status = if is_started {
+        "running"
+    } else if is_succeeded {
+        "succeeded"
+    } else {
+        "failed"
+    };
+    super::run_report::on_task_event(
+        instance_id,
+        task_id,
+        report_status,
+        failure_capture.map(|c| c.image_path),
+    );
+
     // 通知前端刷新状态
     emit_state_changed(app, instance_id, "task-progress");
     if all_done {
@@ -147,6 +322,9 @@ pub fn emit_config_changed(app: &AppHandle) {
     if let Err(e) = app.emit("config-changed-external", ()) {
         log::error!("Failed to emit config-changed-external: {}", e);
     }
+
+    // 实例可能增删或改名，重建托盘菜单
+    crate::tray::refresh_menu(app);
 }
 
 /// 获取应用数据目录
@@ -155,7 +333,7 @@ pub fn emit_config_changed(app: &AppHandle) {
 pub fn get_app_data_dir() -> Result<PathBuf, String> {
     #[cfg(target_os = "macos")]
     {
-        let home = std::env::var("HOME").map_err(|_| "无法获取 HOME 环境变量".to_string())?;
+        let home = std::env::var("HOME").map_err(|_| tr!("error.fs.home_missing"))?;
         let path = PathBuf::from(home)
             .join("Library")
             .join("Application Support")
@@ -199,6 +377,19 @@ pub fn normalize_path(path: &str) -> PathBuf {
     components.into_iter().collect()
 }
 
+/// 判断路径是否为不会越出基准目录的相对路径（只含普通组件和 `.`）
+///
+/// 用于校验清单、changes.json 等外部数据中的路径，拒绝 `..`、根路径和盘符前缀。
+pub fn is_safe_relative_path(path: &str) -> bool {
+    use std::path::{Component, Path};
+
+    let path = Path::new(path);
+    !path.as_os_str().is_empty()
+        && path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
 /// 获取日志目录（应用数据目录下的 debug 子目录）
 pub fn get_logs_dir() -> PathBuf {
     get_app_data_dir()
@@ -215,11 +406,11 @@ pub fn get_logs_dir() -> PathBuf {
 
 /// 获取 exe 所在目录路径（内部使用）
 pub fn get_exe_directory() -> Result<PathBuf, String> {
-    let exe_path = std::env::current_exe().map_err(|e| format!("获取 exe 路径失败: {}", e))?;
+    let exe_path = std::env::current_exe().map_err(|e| tr!("error.fs.exe_path", error = e))?;
     exe_path
         .parent()
         .map(|p| p.to_path_buf())
-        .ok_or_else(|| "无法获取 exe 所在目录".to_string())
+        .ok_or_else(|| tr!("error.fs.exe_dir"))
 }
 
 /// 获取可执行文件所在目录下的 maafw 子目录
@@ -227,8 +418,53 @@ pub fn get_maafw_dir() -> Result<PathBuf, String> {
     Ok(get_exe_directory()?.join("maafw"))
 }
 
+/// 获取 maafw 目录下 MaaFramework 动态库的路径
+pub fn get_maafw_library_path(maafw_dir: &std::path::Path) -> PathBuf {
+    #[cfg(windows)]
+    let lib_name = "MaaFramework.dll";
+    #[cfg(target_os = "macos")]
+    let lib_name = "libMaaFramework.dylib";
+    #[cfg(target_os = "linux")]
+    let lib_name = "libMaaFramework.so";
+
+    maafw_dir.join(lib_name)
+}
+
+/// 获取 maafw 目录下 MaaAgentServer 动态库的路径
+pub fn get_agent_server_library_path(maafw_dir: &std::path::Path) -> PathBuf {
+    #[cfg(windows)]
+    let lib_name = "MaaAgentServer.dll";
+    #[cfg(target_os = "macos")]
+    let lib_name = "libMaaAgentServer.dylib";
+    #[cfg(target_os = "linux")]
+    let lib_name = "libMaaAgentServer.so";
+
+    maafw_dir.join(lib_name)
+}
+
+/// 用户自定义的 User-Agent（`settings.download.userAgent`），为 None 时使用默认值
+static USER_AGENT_OVERRIDE: Mutex<Option<String>> = Mutex::new(None);
+
+/// 设置自定义 User-Agent（空字符串视为清除）
+pub fn set_user_agent_override(user_agent: Option<String>) {
+    let user_agent = user_agent.filter(|ua| !ua.trim().is_empty());
+    *USER_AGENT_OVERRIDE.lock() = user_agent;
+}
+
 /// 构建 User-Agent 字符串
+///
+/// 配置了自定义 User-Agent 时直接返回，其中的 `{default}` 会被替换为默认值，
+/// 便于在默认标识后追加私有镜像要求的字段。
 pub fn build_user_agent() -> String {
+    let default = default_user_agent();
+    match USER_AGENT_OVERRIDE.lock().clone() {
+        Some(custom) => custom.replace("{default}", &default),
+        None => default,
+    }
+}
+
+/// 默认 User-Agent：`MXU/<版本> (<系统>; <架构>) Tauri/<版本>`
+fn default_user_agent() -> String {
     let version = env!("CARGO_PKG_VERSION");
     let os = std::env::consts::OS;
     let arch = std::env::consts::ARCH;
@@ -277,3 +513,33 @@ pub fn build_launch_command(
 
     cmd
 }
+
+/// 执行 `adb -s <address> <args>`，返回标准输出
+pub fn adb_command(adb_path: &str, address: &str, args: &[&str]) -> Result<String, String> {
+    let mut cmd = std::process::Command::new(adb_path);
+    cmd.arg("-s").arg(address).args(args);
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = cmd
+        .output()
+        .map_err(|e| tr!("error.adb.spawn", error = e))?;
+    if !output.status.success() {
+        return Err(tr!(
+            "error.adb.failed",
+            error = String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// 执行 `adb -s <address> shell <args>`，返回标准输出
+pub fn adb_shell(adb_path: &str, address: &str, args: &[&str]) -> Result<String, String> {
+    let mut full = Vec::with_capacity(args.len() + 1);
+    full.push("shell");
+    full.extend_from_slice(args);
+    adb_command(adb_path, address, &full)
+}
@@ -0,0 +1,125 @@
+//! 持久化变量存储
+//!
+//! 按实例保存的键值变量（JSON 值），供 MXU 内置动作（断言、计数器等）读写，
+//! 写入 `<数据目录>/variables.json`，重启后保留。
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use log::warn;
+use parking_lot::Mutex;
+use serde_json::{Map, Value};
+
+use super::utils::get_app_data_dir;
+use crate::i18n::tr;
+
+const VARIABLES_FILE: &str = "variables.json";
+
+/// instance_id -> (变量名 -> 值)，首次访问时从磁盘加载
+static STORE: Mutex<Option<HashMap<String, Map<String, Value>>>> = Mutex::new(None);
+
+fn variables_path() -> Result<PathBuf, String> {
+    Ok(get_app_data_dir()?.join(VARIABLES_FILE))
+}
+
+fn load() -> HashMap<String, Map<String, Value>> {
+    let Ok(path) = variables_path() else {
+        return HashMap::new();
+    };
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return HashMap::new();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        warn!("Invalid variables file {:?}, ignoring: {}", path, e);
+        HashMap::new()
+    })
+}
+
+fn save(store: &HashMap<String, Map<String, Value>>) -> Result<(), String> {
+    let path = variables_path()?;
+    let content =
+        serde_json::to_string_pretty(store).map_err(|e| tr!("error.serialize", error = e))?;
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, content).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        tr!("error.fs.write_file", path = tmp_path.display(), error = e)
+    })?;
+    std::fs::rename(&tmp_path, &path).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        tr!("error.fs.rename", path = path.display(), error = e)
+    })
+}
+
+/// 读取变量
+pub fn get(instance_id: &str, name: &str) -> Option<Value> {
+    let mut guard = STORE.lock();
+    guard
+        .get_or_insert_with(load)
+        .get(instance_id)?
+        .get(name)
+        .cloned()
+}
+
+/// 读取实例的全部变量
+pub fn get_all(instance_id: &str) -> Map<String, Value> {
+    let mut guard = STORE.lock();
+    guard
+        .get_or_insert_with(load)
+        .get(instance_id)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// 原子地读取并改写变量：`f` 接收当前值，返回新值（None 表示删除）
+///
+/// 返回改写后的值。
+pub fn update<F>(instance_id: &str, name: &str, f: F) -> Result<Option<Value>, String>
+where
+    F: FnOnce(Option<&Value>) -> Option<Value>,
+{
+    let mut guard = STORE.lock();
+    let store = guard.get_or_insert_with(load);
+    let vars = store.entry(instance_id.to_string()).or_default();
+    let new_value = f(vars.get(name));
+    match &new_value {
+        Some(v) => {
+            vars.insert(name.to_string(), v.clone());
+        }
+        None => {
+            vars.remove(name);
+        }
+    }
+    if vars.is_empty() {
+        store.remove(instance_id);
+    }
+    save(store)?;
+    Ok(new_value)
+}
+
+/// 设置变量（None 表示删除）
+pub fn set(instance_id: &str, name: &str, value: Option<Value>) -> Result<(), String> {
+    update(instance_id, name, |_| value).map(|_| ())
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取实例的全部变量
+#[tauri::command]
+pub fn mxu_get_variables(instance_id: String) -> Map<String, Value> {
+    get_all(&instance_id)
+}
+
+/// 设置实例变量（value 为 null 时删除）
+#[tauri::command]
+pub fn mxu_set_variable(
+    instance_id: String,
+    name: String,
+    value: Option<Value>,
+) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err(tr!("error.variables.empty_name"));
+    }
+    set(&instance_id, &name, value.filter(|v| !v.is_null()))
+}
@@ -0,0 +1,156 @@
+//! Win32 窗口 DPI 检测
+//!
+//! 系统缩放不为 100% 且目标窗口未声明 Per-Monitor DPI 感知时，窗口会被 DWM 位图拉伸，
+//! 截图尺寸与实际点击坐标不一致（常见的"125% 缩放下点击位置偏移"）。
+//! Win32 / Gamepad 控制器连接成功后检测目标窗口的 DPI，存在风险时发送 `win32-dpi-warning` 事件，
+//! 前端提示用户将缩放改为 100% 或在程序兼容性设置中"替代高 DPI 缩放行为"。
+
+use log::{info, warn};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use crate::i18n::tr;
+
+/// 100% 缩放对应的 DPI
+const DEFAULT_DPI: u32 = 96;
+
+/// 窗口 DPI 感知级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DpiAwareness {
+    /// 未感知 DPI（由系统拉伸）
+    Unaware,
+    /// 系统 DPI 感知（跨显示器移动时仍会被拉伸）
+    System,
+    /// Per-Monitor DPI 感知
+    PerMonitor,
+    /// 无法获取
+    Unknown,
+}
+
+/// 窗口 DPI 信息
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowDpiInfo {
+    pub dpi: u32,
+    /// 缩放百分比（96 DPI 为 100）
+    pub scale_percent: u32,
+    pub awareness: DpiAwareness,
+    /// 坐标或截图尺寸是否可能被系统缩放影响
+    pub scaling_risk: bool,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DpiWarningEvent {
+    instance_id: String,
+    #[serde(flatten)]
+    info: WindowDpiInfo,
+}
+
+#[cfg(windows)]
+fn query(handle: u64) -> Result<(u32, DpiAwareness), String> {
+    use std::ffi::c_void;
+
+    const MONITOR_DEFAULTTONEAREST: u32 = 2;
+    const MDT_EFFECTIVE_DPI: i32 = 0;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn IsWindow(hwnd: *mut c_void) -> i32;
+        fn GetDpiForWindow(hwnd: *mut c_void) -> u32;
+        fn GetWindowDpiAwarenessContext(hwnd: *mut c_void) -> *mut c_void;
+        fn GetAwarenessFromDpiAwarenessContext(context: *mut c_void) -> i32;
+        fn MonitorFromWindow(hwnd: *mut c_void, flags: u32) -> *mut c_void;
+    }
+    #[link(name = "shcore")]
+    extern "system" {
+        fn GetDpiForMonitor(monitor: *mut c_void, dpi_type: i32, x: *mut u32, y: *mut u32) -> i32;
+    }
+
+    let hwnd = handle as *mut c_void;
+    // SAFETY: 仅传递窗口句柄给 user32 / shcore 查询函数，句柄无效时 IsWindow 返回 0
+    unsafe {
+        if IsWindow(hwnd) == 0 {
+            return Err(tr!("error.system.window_closed"));
+        }
+        // 未感知 DPI 的窗口 GetDpiForWindow 恒为 96，需以所在显示器的有效 DPI 判断实际缩放
+        let (mut dpi_x, mut dpi_y) = (0u32, 0u32);
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+        let dpi = if !monitor.is_null()
+            && GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) == 0
+        {
+            dpi_x
+        } else {
+            GetDpiForWindow(hwnd)
+        };
+        let context = GetWindowDpiAwarenessContext(hwnd);
+        let awareness = if context.is_null() {
+            DpiAwareness::Unknown
+        } else {
+            match GetAwarenessFromDpiAwarenessContext(context) {
+                0 => DpiAwareness::Unaware,
+                1 => DpiAwareness::System,
+                2 => DpiAwareness::PerMonitor,
+                _ => DpiAwareness::Unknown,
+            }
+        };
+        Ok((if dpi == 0 { DEFAULT_DPI } else { dpi }, awareness))
+    }
+}
+
+#[cfg(not(windows))]
+fn query(_handle: u64) -> Result<(u32, DpiAwareness), String> {
+    Err(tr!("error.system.windows_only"))
+}
+
+/// 获取窗口的 DPI 信息
+pub fn window_dpi_impl(handle: u64) -> Result<WindowDpiInfo, String> {
+    let (dpi, awareness) = query(handle)?;
+    let scale_percent = (dpi * 100 + DEFAULT_DPI / 2) / DEFAULT_DPI;
+    Ok(WindowDpiInfo {
+        dpi,
+        scale_percent,
+        awareness,
+        scaling_risk: scale_percent != 100 && awareness != DpiAwareness::PerMonitor,
+    })
+}
+
+/// 控制器连接后检查目标窗口 DPI，存在缩放风险时发送 `win32-dpi-warning` 事件
+pub fn check_after_connect(app: &AppHandle, instance_id: &str, handle: u64) {
+    let info = match window_dpi_impl(handle) {
+        Ok(info) => info,
+        Err(e) => {
+            warn!("Failed to query DPI of window {:#x}: {}", handle, e);
+            return;
+        }
+    };
+    if !info.scaling_risk {
+        info!(
+            "Window {:#x} DPI: {} ({}%, {:?})",
+            handle, info.dpi, info.scale_percent, info.awareness
+        );
+        return;
+    }
+    warn!(
+        "Window {:#x} of instance {} is scaled to {}% ({:?}), clicks may be offset",
+        handle, instance_id, info.scale_percent, info.awareness
+    );
+    let event = DpiWarningEvent {
+        instance_id: instance_id.to_string(),
+        info,
+    };
+    if let Err(e) = app.emit("win32-dpi-warning", event) {
+        warn!("Failed to emit win32-dpi-warning: {}", e);
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 获取窗口的 DPI、缩放比例与 DPI 感知级别
+#[tauri::command]
+pub fn maa_get_window_dpi(handle: u64) -> Result<WindowDpiInfo, String> {
+    window_dpi_impl(handle)
+}
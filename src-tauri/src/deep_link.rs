@@ -0,0 +1,218 @@
+//! `mxu://` 深度链接
+//!
+//! 资源作者可分享一键链接，例如：
+//! - `mxu://run?profile=daily`：切换到名为 `daily` 的实例并开始任务
+//! - `mxu://import-resource?url=https://...`：下载并导入资源包（前端确认后执行）
+//!
+//! 启动时在 Windows 上注册 `mxu` 协议（HKCU，无需管理员权限），系统以 `mxu.exe "<链接>"` 启动；
+//! 启动时自动提权会原样转发命令行参数，链接不会丢失。
+//! 已有 MXU 在运行时，新进程把链接转发到其本地 Web 服务（`POST /api/deep-link`）后退出；
+//! 否则链接暂存，待前端就绪后通过 `mxu_take_deep_links` 取走。运行中收到的链接通过 `deep-link` 事件推送。
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::i18n::tr;
+
+/// 协议名
+pub const SCHEME: &str = "mxu";
+
+/// 解析后的深度链接动作
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum DeepLinkAction {
+    /// 按名称切换实例（配置）并开始任务
+    Run { profile: String },
+    /// 从 URL 导入资源包
+    ImportResource { url: String },
+}
+
+/// 启动参数中携带、尚未被前端取走的链接
+static PENDING: Mutex<Vec<DeepLinkAction>> = Mutex::new(Vec::new());
+
+/// 解析 `mxu://<动作>?<参数>`
+pub fn parse(link: &str) -> Result<DeepLinkAction, String> {
+    let url = reqwest::Url::parse(link).map_err(|e| tr!("error.deep_link.invalid", error = e))?;
+    if url.scheme() != SCHEME {
+        return Err(tr!("error.deep_link.scheme", scheme = url.scheme()));
+    }
+    let query = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    // `mxu://run?...` 中动作位于 host；兼容 `mxu:run?...` 写法
+    let action = url
+        .host_str()
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| url.path().trim_matches('/'))
+        .to_ascii_lowercase();
+
+    match action.as_str() {
+        "run" => {
+            let profile = query("profile")
+                .ok_or_else(|| tr!("error.deep_link.missing_param", name = "profile"))?;
+            Ok(DeepLinkAction::Run { profile })
+        }
+        "import-resource" => {
+            let source =
+                query("url").ok_or_else(|| tr!("error.deep_link.missing_param", name = "url"))?;
+            let parsed = reqwest::Url::parse(&source)
+                .map_err(|e| tr!("error.deep_link.invalid_url", error = e))?;
+            if !matches!(parsed.scheme(), "http" | "https") {
+                return Err(tr!("error.deep_link.http_only"));
+            }
+            Ok(DeepLinkAction::ImportResource { url: source })
+        }
+        other => Err(tr!("error.deep_link.unknown_action", action = other)),
+    }
+}
+
+/// 命令行参数中的深度链接
+pub fn link_from_args() -> Option<String> {
+    std::env::args()
+        .skip(1)
+        .find(|arg| arg.starts_with(&format!("{}:", SCHEME)))
+}
+
+/// 启动时暂存命令行中的链接，等待前端取走
+pub fn queue_from_args() {
+    let Some(link) = link_from_args() else {
+        return;
+    };
+    match parse(&link) {
+        Ok(action) => {
+            info!("Deep link queued: {:?}", action);
+            PENDING.lock().push(action);
+        }
+        Err(e) => warn!("Ignoring invalid deep link {}: {}", link, e),
+    }
+}
+
+/// 处理运行中收到的链接：显示主窗口并推送 `deep-link` 事件
+pub fn dispatch(app: &AppHandle, link: &str) -> Result<DeepLinkAction, String> {
+    let action = parse(link)?;
+    info!("Deep link received: {:?}", action);
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    app.emit("deep-link", &action)
+        .map_err(|e| tr!("error.deep_link.emit", error = e))?;
+    Ok(action)
+}
+
+/// 将链接转发给已在运行的 MXU（通过本地 Web 服务），成功返回 true
+pub fn forward_to_running_instance(link: &str, port: u16) -> bool {
+    let client = match reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(3))
+        .build()
+    {
+        Ok(client) => client,
+        Err(_) => return false,
+    };
+    let url = format!("http://127.0.0.1:{}/api/deep-link", port);
+    match client
+        .post(&url)
+        .json(&serde_json::json!({ "url": link }))
+        .send()
+    {
+        Ok(resp) if resp.status().is_success() => {
+            info!("Deep link forwarded to running instance on port {}", port);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// 在当前用户下注册 `mxu://` 协议（仅 Windows；macOS / Linux 由安装包声明）
+///
+/// 已注册且指向当前程序时跳过，避免每次启动都改写注册表。
+pub fn register_scheme() {
+    #[cfg(windows)]
+    {
+        let Ok(exe) = std::env::current_exe() else {
+            return;
+        };
+        let command = format!("\"{}\" \"%1\"", exe.to_string_lossy());
+        if registered_command().as_deref() == Some(command.as_str()) {
+            return;
+        }
+        match write_scheme_keys(&command) {
+            Ok(()) => info!("Registered {}:// protocol handler", SCHEME),
+            Err(e) => warn!("Failed to register {}:// protocol: {}", SCHEME, e),
+        }
+    }
+}
+
+/// 协议在 HKCU 下的注册表路径
+#[cfg(windows)]
+fn scheme_key_path() -> String {
+    format!(r"Software\Classes\{}", SCHEME)
+}
+
+/// 读取当前注册的 `shell\open\command` 默认值
+#[cfg(windows)]
+fn registered_command() -> Option<String> {
+    use winsafe::co::{KEY, REG_OPTION, RRF};
+    use winsafe::{RegistryValue, HKEY};
+
+    let key = HKEY::CURRENT_USER
+        .RegOpenKeyEx(
+            Some(&format!(r"{}\shell\open\command", scheme_key_path())),
+            REG_OPTION::NoValue,
+            KEY::QUERY_VALUE,
+        )
+        .ok()?;
+    match key.RegGetValue(None, None, RRF::RT_REG_SZ) {
+        Ok(RegistryValue::Sz(value)) => Some(value),
+        _ => None,
+    }
+}
+
+/// 写入协议注册表项
+#[cfg(windows)]
+fn write_scheme_keys(command: &str) -> winsafe::SysResult<()> {
+    use winsafe::co::{KEY, REG_OPTION};
+    use winsafe::{RegistryValue, HKEY};
+
+    let root = scheme_key_path();
+    let (key, _) = HKEY::CURRENT_USER.RegCreateKeyEx(
+        &root,
+        None,
+        REG_OPTION::NoValue,
+        KEY::SET_VALUE,
+        None,
+    )?;
+    key.RegSetValueEx(None, RegistryValue::Sz("URL:MXU Protocol".to_string()))?;
+    key.RegSetValueEx(Some("URL Protocol"), RegistryValue::Sz(String::new()))?;
+
+    let (command_key, _) = HKEY::CURRENT_USER.RegCreateKeyEx(
+        &format!(r"{}\shell\open\command", root),
+        None,
+        REG_OPTION::NoValue,
+        KEY::SET_VALUE,
+        None,
+    )?;
+    command_key.RegSetValueEx(None, RegistryValue::Sz(command.to_string()))
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 取走启动参数中暂存的深度链接（前端就绪后调用一次）
+#[tauri::command]
+pub fn mxu_take_deep_links() -> Vec<DeepLinkAction> {
+    std::mem::take(&mut *PENDING.lock())
+}
+
+/// 解析深度链接（不执行），用于前端校验用户粘贴的链接
+#[tauri::command]
+pub fn mxu_parse_deep_link(url: String) -> Result<DeepLinkAction, String> {
+    parse(&url)
+}
@@ -0,0 +1,527 @@
+//! 条件表达式引擎
+//!
+//! 供任务 `run_if` 等场景使用的小型表达式求值器，值类型为 JSON。
+//!
+//! 支持的语法（优先级从低到高）：
+//! - 逻辑：`||`、`&&`
+//! - 比较：`==`、`!=`、`>`、`>=`、`<`、`<=`
+//! - 算术：`+`、`-`、`*`、`/`、`%`（`+` 也用于字符串拼接）
+//! - 一元：`!`、`-`
+//! - 字面量：数字、字符串（单引号或双引号）、`true`、`false`、`null`
+//! - 路径：`vars.stamina`、`task[0].succeeded`、`vars["名称"]`，由调用方解析
+//! - 括号分组
+//!
+//! 示例：`task[0].succeeded && vars.stamina > 100`
+//!
+//! 为避免恶意或错误的表达式耗尽栈空间，括号与一元运算符的嵌套层数、运算符总数均有上限。
+
+use serde_json::Value;
+
+use crate::i18n::tr;
+
+/// 路径片段
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSeg {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Str(String),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Dot,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Literal(Value),
+    Path(Vec<PathSeg>),
+    Not(Box<Node>),
+    Neg(Box<Node>),
+    Binary(&'static str, Box<Node>, Box<Node>),
+}
+
+/// 已解析的表达式
+#[derive(Debug, Clone)]
+pub struct Expr {
+    root: Node,
+}
+
+/// 括号与一元运算符的最大嵌套层数
+const MAX_DEPTH: usize = 32;
+/// 运算符的最大数量（同时限制求值时的递归深度）
+const MAX_OPERATORS: usize = 256;
+
+const OPERATORS: [&str; 15] = [
+    "&&", "||", "==", "!=", ">=", "<=", ">", "<", "!", "+", "-", "*", "/", "%", "=",
+];
+
+fn tokenize(src: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        match c {
+            '(' => tokens.push(Token::LParen),
+            ')' => tokens.push(Token::RParen),
+            '[' => tokens.push(Token::LBracket),
+            ']' => tokens.push(Token::RBracket),
+            '.' if !chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()) => tokens.push(Token::Dot),
+            '\'' | '"' => {
+                let quote = c;
+                let mut s = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(tr!("error.expr.unterminated_string")),
+                        Some('\\') => {
+                            let escaped = chars
+                                .get(i + 1)
+                                .ok_or_else(|| tr!("error.expr.incomplete_escape"))?;
+                            s.push(match escaped {
+                                'n' => '\n',
+                                't' => '\t',
+                                other => *other,
+                            });
+                            i += 2;
+                        }
+                        Some(ch) if *ch == quote => break,
+                        Some(ch) => {
+                            s.push(*ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::Str(s));
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let n = text
+                    .parse::<f64>()
+                    .map_err(|_| tr!("error.expr.invalid_number", text = text))?;
+                tokens.push(Token::Number(n));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+                continue;
+            }
+            _ => {
+                let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+                let op = OPERATORS
+                    .iter()
+                    .find(|op| rest.starts_with(**op))
+                    .ok_or_else(|| tr!("error.expr.unknown_char", char = c))?;
+                if *op == "=" {
+                    return Err(tr!("error.expr.use_double_equals"));
+                }
+                tokens.push(Token::Op(op));
+                i += op.len();
+                continue;
+            }
+        }
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// 当前嵌套层数
+    depth: usize,
+    /// 已解析的运算符数量
+    operators: usize,
+}
+
+impl Parser {
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(tr!("error.expr.too_deep", max = MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn count_operator(&mut self) -> Result<(), String> {
+        self.operators += 1;
+        if self.operators > MAX_OPERATORS {
+            return Err(tr!("error.expr.too_many_operators", max = MAX_OPERATORS));
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat_op(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Op(op)) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn binary(
+        &mut self,
+        ops: &[&'static str],
+        next: fn(&mut Self) -> Result<Node, String>,
+    ) -> Result<Node, String> {
+        let mut left = next(self)?;
+        while let Some(op) = self.eat_op(ops) {
+            self.count_operator()?;
+            let right = next(self)?;
+            left = Node::Binary(op, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn or(&mut self) -> Result<Node, String> {
+        self.binary(&["||"], Self::and)
+    }
+
+    fn and(&mut self) -> Result<Node, String> {
+        self.binary(&["&&"], Self::comparison)
+    }
+
+    fn comparison(&mut self) -> Result<Node, String> {
+        self.binary(&["==", "!=", ">=", "<=", ">", "<"], Self::additive)
+    }
+
+    fn additive(&mut self) -> Result<Node, String> {
+        self.binary(&["+", "-"], Self::multiplicative)
+    }
+
+    fn multiplicative(&mut self) -> Result<Node, String> {
+        self.binary(&["*", "/", "%"], Self::unary)
+    }
+
+    fn unary(&mut self) -> Result<Node, String> {
+        let Some(op) = self.eat_op(&["!", "-"]) else {
+            return self.primary();
+        };
+        self.count_operator()?;
+        self.enter()?;
+        let inner = Box::new(self.unary()?);
+        self.leave();
+        Ok(if op == "!" {
+            Node::Not(inner)
+        } else {
+            Node::Neg(inner)
+        })
+    }
+
+    fn primary(&mut self) -> Result<Node, String> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Node::Literal(Value::from(n))),
+            Some(Token::Str(s)) => Ok(Node::Literal(Value::String(s))),
+            Some(Token::LParen) => {
+                self.enter()?;
+                let node = self.or()?;
+                self.leave();
+                match self.next() {
+                    Some(Token::RParen) => Ok(node),
+                    _ => Err(tr!("error.expr.missing_rparen")),
+                }
+            }
+            Some(Token::Ident(name)) => match name.as_str() {
+                "true" => Ok(Node::Literal(Value::Bool(true))),
+                "false" => Ok(Node::Literal(Value::Bool(false))),
+                "null" => Ok(Node::Literal(Value::Null)),
+                _ => self.path(name),
+            },
+            Some(token) => Err(tr!(
+                "error.expr.unexpected_token",
+                token = format!("{:?}", token)
+            )),
+            None => Err(tr!("error.expr.incomplete")),
+        }
+    }
+
+    fn path(&mut self, head: String) -> Result<Node, String> {
+        let mut segs = vec![PathSeg::Key(head)];
+        loop {
+            match self.peek() {
+                Some(Token::Dot) => {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Ident(name)) => segs.push(PathSeg::Key(name)),
+                        _ => return Err(tr!("error.expr.expected_name")),
+                    }
+                }
+                Some(Token::LBracket) => {
+                    self.pos += 1;
+                    let seg = match self.next() {
+                        Some(Token::Number(n)) if n >= 0.0 && n.fract() == 0.0 => {
+                            PathSeg::Index(n as usize)
+                        }
+                        Some(Token::Str(s)) => PathSeg::Key(s),
+                        _ => return Err(tr!("error.expr.invalid_index")),
+                    };
+                    if self.next() != Some(Token::RBracket) {
+                        return Err(tr!("error.expr.missing_rbracket"));
+                    }
+                    segs.push(seg);
+                }
+                _ => return Ok(Node::Path(segs)),
+            }
+        }
+    }
+}
+
+/// 判断 JSON 值的真假：null、false、0、空字符串、空数组为假
+pub fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|f| f != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+fn as_number(value: &Value, op: &str) -> Result<f64, String> {
+    value
+        .as_f64()
+        .ok_or_else(|| tr!("error.expr.not_numeric", op = op, actual = value))
+}
+
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x == y,
+        _ => a == b,
+    }
+}
+
+fn compare(op: &str, a: &Value, b: &Value) -> Result<bool, String> {
+    let ord = match (a, b) {
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        _ => as_number(a, op)?
+            .partial_cmp(&as_number(b, op)?)
+            .ok_or_else(|| tr!("error.expr.nan"))?,
+    };
+    Ok(match op {
+        ">" => ord.is_gt(),
+        ">=" => ord.is_ge(),
+        "<" => ord.is_lt(),
+        _ => ord.is_le(),
+    })
+}
+
+fn eval_node(node: &Node, resolve: &dyn Fn(&[PathSeg]) -> Value) -> Result<Value, String> {
+    Ok(match node {
+        Node::Literal(v) => v.clone(),
+        Node::Path(segs) => resolve(segs),
+        Node::Not(inner) => Value::Bool(!truthy(&eval_node(inner, resolve)?)),
+        Node::Neg(inner) => Value::from(-as_number(&eval_node(inner, resolve)?, "-")?),
+        Node::Binary(op, left, right) => {
+            // 逻辑运算短路求值
+            match *op {
+                "&&" => {
+                    return Ok(Value::Bool(
+                        truthy(&eval_node(left, resolve)?) && truthy(&eval_node(right, resolve)?),
+                    ))
+                }
+                "||" => {
+                    return Ok(Value::Bool(
+                        truthy(&eval_node(left, resolve)?) || truthy(&eval_node(right, resolve)?),
+                    ))
+                }
+                _ => {}
+            }
+            let a = eval_node(left, resolve)?;
+            let b = eval_node(right, resolve)?;
+            match *op {
+                "==" => Value::Bool(values_equal(&a, &b)),
+                "!=" => Value::Bool(!values_equal(&a, &b)),
+                ">" | ">=" | "<" | "<=" => Value::Bool(compare(op, &a, &b)?),
+                "+" => match (&a, &b) {
+                    (Value::String(x), y) => Value::String(format!("{}{}", x, display(y))),
+                    (x, Value::String(y)) => Value::String(format!("{}{}", display(x), y)),
+                    _ => Value::from(as_number(&a, op)? + as_number(&b, op)?),
+                },
+                "-" => Value::from(as_number(&a, op)? - as_number(&b, op)?),
+                "*" => Value::from(as_number(&a, op)? * as_number(&b, op)?),
+                "/" | "%" => {
+                    let divisor = as_number(&b, op)?;
+                    if divisor == 0.0 {
+                        return Err(tr!("error.expr.divide_by_zero"));
+                    }
+                    let dividend = as_number(&a, op)?;
+                    Value::from(if *op == "/" {
+                        dividend / divisor
+                    } else {
+                        dividend % divisor
+                    })
+                }
+                other => return Err(tr!("error.expr.unknown_operator", op = other)),
+            }
+        }
+    })
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl Expr {
+    /// 解析表达式
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            tokens: tokenize(src)?,
+            pos: 0,
+            depth: 0,
+            operators: 0,
+        };
+        if parser.tokens.is_empty() {
+            return Err(tr!("error.expr.empty"));
+        }
+        let root = parser.or()?;
+        if let Some(token) = parser.peek() {
+            return Err(tr!(
+                "error.expr.trailing_input",
+                token = format!("{:?}", token)
+            ));
+        }
+        Ok(Self { root })
+    }
+
+    /// 求值，`resolve` 负责把路径解析为值（未知路径返回 null）
+    pub fn eval(&self, resolve: &dyn Fn(&[PathSeg]) -> Value) -> Result<Value, String> {
+        eval_node(&self.root, resolve)
+    }
+
+    /// 求值并按真假判断
+    pub fn eval_bool(&self, resolve: &dyn Fn(&[PathSeg]) -> Value) -> Result<bool, String> {
+        self.eval(resolve).map(|v| truthy(&v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn eval(src: &str) -> Result<Value, String> {
+        let vars = json!({ "stamina": 120, "name": "mxu" });
+        let tasks = json!([{ "succeeded": true }, { "succeeded": false }]);
+        Expr::parse(src)?.eval(&|segs: &[PathSeg]| {
+            let root = match segs.first() {
+                Some(PathSeg::Key(k)) if k == "vars" => &vars,
+                Some(PathSeg::Key(k)) if k == "task" => &tasks,
+                _ => return Value::Null,
+            };
+            segs[1..]
+                .iter()
+                .try_fold(root, |v, seg| match seg {
+                    PathSeg::Key(k) => v.get(k),
+                    PathSeg::Index(i) => v.get(i),
+                })
+                .cloned()
+                .unwrap_or(Value::Null)
+        })
+    }
+
+    #[test]
+    fn arithmetic_precedence() {
+        assert_eq!(eval("1 + 2 * 3").unwrap(), json!(7.0));
+        assert_eq!(eval("(1 + 2) * 3").unwrap(), json!(9.0));
+        assert_eq!(eval("-2 - -3").unwrap(), json!(1.0));
+        assert_eq!(eval("7 % 4").unwrap(), json!(3.0));
+    }
+
+    #[test]
+    fn logic_and_comparison() {
+        assert_eq!(eval("1 < 2 && 3 >= 3").unwrap(), json!(true));
+        assert_eq!(eval("!(1 == 1) || 'a' != 'b'").unwrap(), json!(true));
+        assert_eq!(eval("'abc' < 'abd'").unwrap(), json!(true));
+        assert_eq!(eval("1 == '1'").unwrap(), json!(false));
+    }
+
+    #[test]
+    fn short_circuit_skips_errors() {
+        assert_eq!(eval("false && 1 / 0").unwrap(), json!(false));
+        assert_eq!(eval("true || 1 / 0").unwrap(), json!(true));
+        assert!(eval("1 / 0").is_err());
+    }
+
+    #[test]
+    fn paths_and_strings() {
+        assert_eq!(
+            eval("task[0].succeeded && vars.stamina > 100").unwrap(),
+            json!(true)
+        );
+        assert_eq!(eval("task[1].succeeded").unwrap(), json!(false));
+        assert_eq!(
+            eval("vars[\"name\"] + '-' + vars.stamina").unwrap(),
+            json!("mxu-120")
+        );
+        assert_eq!(eval("vars.missing").unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Expr::parse("").is_err());
+        assert!(Expr::parse("(1 + 2").is_err());
+        assert!(Expr::parse("1 2").is_err());
+        assert!(Expr::parse("vars.").is_err());
+        assert!(Expr::parse("task[-1]").is_err());
+    }
+
+    #[test]
+    fn nesting_depth_is_limited() {
+        let ok = format!("{}1{}", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH));
+        assert!(Expr::parse(&ok).is_ok());
+
+        let parens = format!("{}1{}", "(".repeat(10_000), ")".repeat(10_000));
+        assert!(Expr::parse(&parens).is_err());
+        assert!(Expr::parse(&format!("{}true", "!".repeat(10_000))).is_err());
+    }
+
+    #[test]
+    fn operator_count_is_limited() {
+        let ok = vec!["1"; MAX_OPERATORS + 1].join(" + ");
+        assert!(Expr::parse(&ok).is_ok());
+
+        let chain = vec!["1"; 100_000].join(" + ");
+        assert!(Expr::parse(&chain).is_err());
+    }
+}
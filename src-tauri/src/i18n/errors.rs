@@ -0,0 +1,377 @@
+//! 错误消息文案
+//!
+//! 键以 `error.` 开头，按模块分组；`{name}` 为占位符，由 [`tr!`](super::tr) 填充。
+
+/// 文案表：键 -> [zh-CN, zh-TW, en-US, ja-JP, ko-KR]
+pub(super) const CATALOG: &[(&str, [&str; 5])] = &[
+    ("error.instance_not_found", ["实例不存在", "實例不存在", "Instance not found", "インスタンスが見つかりません", "인스턴스를 찾을 수 없습니다"]),
+    ("error.controller_not_connected", ["控制器未连接", "控制器未連接", "Controller not connected", "コントローラーが接続されていません", "컨트롤러가 연결되지 않았습니다"]),
+    ("error.resource_not_loaded", ["资源未加载", "資源未載入", "Resource not loaded", "リソースが読み込まれていません", "리소스가 로드되지 않았습니다"]),
+    ("error.adb_unauthorized", ["设备未授权 USB 调试", "裝置未授權 USB 偵錯", "The device has not authorized USB debugging", "デバイスで USB デバッグが許可されていません", "기기에서 USB 디버깅이 허용되지 않았습니다"]),
+    ("error.insufficient_disk_space", ["磁盘空间不足 [{path}]: 需要 {required} MB，可用 {available} MB", "磁碟空間不足 [{path}]: 需要 {required} MB，可用 {available} MB", "Not enough disk space [{path}]: {required} MB required, {available} MB available", "ディスク容量が不足しています [{path}]: 必要 {required} MB、空き {available} MB", "디스크 공간이 부족합니다 [{path}]: 필요 {required} MB, 사용 가능 {available} MB"]),
+    ("error.task_panicked", ["后台任务异常: {error}", "背景工作異常: {error}", "Background task failed: {error}", "バックグラウンド処理でエラーが発生しました: {error}", "백그라운드 작업 오류: {error}"]),
+    ("error.serialize", ["序列化数据失败: {error}", "序列化資料失敗: {error}", "Failed to serialize data: {error}", "データのシリアライズに失敗しました: {error}", "데이터 직렬화 실패: {error}"]),
+    ("error.instance_not_found_named", ["实例不存在: {name}", "實例不存在: {name}", "Instance not found: {name}", "インスタンスが見つかりません: {name}", "인스턴스를 찾을 수 없습니다: {name}"]),
+    ("error.tasker_not_created", ["任务器未创建，请先连接设备并加载资源", "任務器未建立，請先連接裝置並載入資源", "The tasker has not been created. Connect a device and load a resource first", "タスカーが作成されていません。先にデバイスに接続してリソースを読み込んでください", "태스커가 생성되지 않았습니다. 먼저 장치를 연결하고 리소스를 로드하세요"]),
+    ("error.hint.not_initialized", ["请检查 maafw 目录是否完整，或重新安装 MaaFramework", "請檢查 maafw 目錄是否完整，或重新安裝 MaaFramework", "Check that the maafw folder is complete, or reinstall MaaFramework", "maafw フォルダーが揃っているか確認するか、MaaFramework を再インストールしてください", "maafw 폴더가 온전한지 확인하거나 MaaFramework를 다시 설치하세요"]),
+    ("error.hint.instance_not_found", ["实例可能已被关闭，请重新打开该实例", "實例可能已被關閉，請重新開啟該實例", "The instance may have been closed. Reopen it and try again", "インスタンスが閉じられた可能性があります。開き直してください", "인스턴스가 닫혔을 수 있습니다. 다시 열어 주세요"]),
+    ("error.hint.controller_not_connected", ["请先连接设备或窗口", "請先連接裝置或視窗", "Connect a device or window first", "先にデバイスまたはウィンドウに接続してください", "먼저 장치나 창을 연결하세요"]),
+    ("error.hint.resource_not_loaded", ["请先加载资源", "請先載入資源", "Load a resource first", "先にリソースを読み込んでください", "먼저 리소스를 로드하세요"]),
+    ("error.hint.target_elevated", ["请以管理员身份重启 MXU 后再连接", "請以系統管理員身分重新啟動 MXU 後再連接", "Restart MXU as administrator and connect again", "MXU を管理者として再起動してから接続してください", "MXU를 관리자 권한으로 다시 시작한 후 연결하세요"]),
+    ("error.hint.agent_failed", ["请查看 Agent 输出日志排查问题", "請查看 Agent 輸出日誌排查問題", "Check the agent output log for details", "Agent の出力ログを確認してください", "Agent 출력 로그를 확인하세요"]),
+    ("error.hint.adb_unauthorized", ["请在设备上允许 USB 调试授权（可勾选“始终允许”），然后重新连接", "請在裝置上允許 USB 偵錯授權（可勾選「一律允許」），然後重新連接", "Allow USB debugging on the device (tick \"Always allow\") and connect again", "デバイスで USB デバッグを許可し（「常に許可」を推奨）、再接続してください", "기기에서 USB 디버깅을 허용한 후(\"항상 허용\" 권장) 다시 연결하세요"]),
+    ("error.hint.insufficient_disk_space", ["请清理该磁盘或将 MXU 移动到空间充足的磁盘后重试", "請清理該磁碟或將 MXU 移動到空間充足的磁碟後重試", "Free up space on this drive or move MXU to a drive with enough space, then try again", "このドライブの空き容量を増やすか、MXU を空きのあるドライブに移動してから再試行してください", "이 드라이브의 공간을 확보하거나 MXU를 여유 공간이 있는 드라이브로 옮긴 후 다시 시도하세요"]),
+    ("error.hint.path_too_long", ["请将 MXU 移动到较短的目录（如 D:\\MXU），或开启系统长路径支持", "請將 MXU 移動到較短的目錄（如 D:\\MXU），或開啟系統長路徑支援", "Move MXU to a shorter folder (e.g. D:\\MXU) or enable Windows long path support", "MXU を短いフォルダー（例: D:\\MXU）に移動するか、長いパスのサポートを有効にしてください", "MXU를 짧은 경로(예: D:\\MXU)로 옮기거나 Windows 긴 경로 지원을 켜세요"]),
+    ("error.ffi.discarded", ["{label} 已超时，结果已丢弃", "{label} 已逾時，結果已捨棄", "{label} timed out; its result was discarded", "{label} はタイムアウトしたため、結果を破棄しました", "{label} 시간 초과로 결과를 버렸습니다"]),
+    ("error.ffi.timeout", ["{label} 超时（{secs} 秒未返回），设备或 MaaFramework 可能无响应", "{label} 逾時（{secs} 秒未返回），裝置或 MaaFramework 可能無回應", "{label} timed out (no response in {secs}s); the device or MaaFramework may be unresponsive", "{label} がタイムアウトしました（{secs} 秒応答なし）。デバイスまたは MaaFramework が応答していない可能性があります", "{label} 시간 초과({secs}초 동안 응답 없음). 장치 또는 MaaFramework가 응답하지 않을 수 있습니다"]),
+    ("error.ffi.panicked", ["{label} 执行异常: {error}", "{label} 執行異常: {error}", "{label} crashed: {error}", "{label} の実行中に異常が発生しました: {error}", "{label} 실행 중 오류: {error}"]),
+    ("error.preview.invalid_handle", ["无效的窗口句柄", "無效的視窗控制代碼", "Invalid window handle", "無効なウィンドウハンドルです", "잘못된 창 핸들입니다"]),
+    ("error.preview.connect_timeout", ["连接窗口超时", "連接視窗逾時", "Timed out connecting to the window", "ウィンドウへの接続がタイムアウトしました", "창 연결 시간이 초과되었습니다"]),
+    ("error.preview.screencap_timeout", ["截图超时，请尝试其他截图方式", "截圖逾時，請嘗試其他截圖方式", "Screenshot timed out. Try another screencap method", "スクリーンショットがタイムアウトしました。別のキャプチャ方式を試してください", "스크린샷 시간이 초과되었습니다. 다른 캡처 방식을 시도하세요"]),
+    ("error.agent.port_unavailable", ["Agent 端口 {host}:{port} 不可用（可能已被占用）: {error}", "Agent 連接埠 {host}:{port} 無法使用（可能已被佔用）: {error}", "Agent port {host}:{port} is unavailable (it may be in use): {error}", "Agent ポート {host}:{port} は使用できません（使用中の可能性があります）: {error}", "Agent 포트 {host}:{port}를 사용할 수 없습니다(이미 사용 중일 수 있음): {error}"]),
+    ("error.agent.listen_failed", ["监听失败: {error}", "監聽失敗: {error}", "Failed to listen: {error}", "待ち受けに失敗しました: {error}", "수신 대기 실패: {error}"]),
+    ("error.agent.local_addr_failed", ["获取监听地址失败: {error}", "取得監聽位址失敗: {error}", "Failed to get the listening address: {error}", "待ち受けアドレスの取得に失敗しました: {error}", "수신 주소를 가져오지 못했습니다: {error}"]),
+    ("error.agent.connect_failed", ["连接 {addr} 失败: {error}", "連接 {addr} 失敗: {error}", "Failed to connect to {addr}: {error}", "{addr} への接続に失敗しました: {error}", "{addr} 연결 실패: {error}"]),
+    ("error.agent.diag_loopback_blocked", ["诊断: 本机 TCP 回环连接被拦截（{error}），请在 Windows 防火墙或安全软件中允许 MXU 与 Agent 程序的本地连接", "診斷: 本機 TCP 回送連線被攔截（{error}），請在 Windows 防火牆或安全軟體中允許 MXU 與 Agent 程式的本機連線", "Diagnosis: local TCP loopback connections are blocked ({error}). Allow local connections between MXU and the agent in Windows Firewall or your security software", "診断: ローカルの TCP ループバック接続がブロックされています（{error}）。Windows ファイアウォールまたはセキュリティソフトで MXU と Agent のローカル接続を許可してください", "진단: 로컬 TCP 루프백 연결이 차단되었습니다({error}). Windows 방화벽이나 보안 프로그램에서 MXU와 Agent의 로컬 연결을 허용하세요"]),
+    ("error.agent.diag_loopback_failed", ["诊断: 本机 TCP 回环连接失败（{error}），请检查防火墙设置", "診斷: 本機 TCP 回送連線失敗（{error}），請檢查防火牆設定", "Diagnosis: local TCP loopback connection failed ({error}). Check your firewall settings", "診断: ローカルの TCP ループバック接続に失敗しました（{error}）。ファイアウォールの設定を確認してください", "진단: 로컬 TCP 루프백 연결에 실패했습니다({error}). 방화벽 설정을 확인하세요"]),
+    ("error.agent.diag_loopback_ok", ["诊断: 本机回环连接正常，请确认 Agent 程序使用端口 {host}:{port} 连接且未提前退出", "診斷: 本機回送連線正常，請確認 Agent 程式使用連接埠 {host}:{port} 連線且未提前結束", "Diagnosis: local loopback works. Make sure the agent connects to {host}:{port} and does not exit early", "診断: ローカルのループバック接続は正常です。Agent がポート {host}:{port} に接続しており、途中で終了していないか確認してください", "진단: 로컬 루프백 연결은 정상입니다. Agent가 {host}:{port} 포트로 연결하며 먼저 종료되지 않았는지 확인하세요"]),
+    ("error.agent.diag_remote_unreachable", ["诊断: 无法连接远程 Agent {host}:{port}，请确认远程端已在该端口监听且网络与防火墙允许连接", "診斷: 無法連接遠端 Agent {host}:{port}，請確認遠端已在該連接埠監聽且網路與防火牆允許連線", "Diagnosis: cannot reach the remote agent at {host}:{port}. Make sure it is listening on that port and the network and firewall allow the connection", "診断: リモート Agent {host}:{port} に接続できません。リモート側がそのポートで待ち受けており、ネットワークとファイアウォールが接続を許可しているか確認してください", "진단: 원격 Agent {host}:{port}에 연결할 수 없습니다. 원격 측이 해당 포트에서 수신 대기 중이며 네트워크와 방화벽이 연결을 허용하는지 확인하세요"]),
+    ("error.agent.remote_addr_invalid", ["远程 Agent 地址无效（应为 host:port）: {addr}", "遠端 Agent 位址無效（應為 host:port）: {addr}", "Invalid remote agent address (expected host:port): {addr}", "リモート Agent のアドレスが無効です（host:port の形式）: {addr}", "원격 Agent 주소가 잘못되었습니다(host:port 형식): {addr}"]),
+    ("error.agent.remote_port_invalid", ["远程 Agent 端口无效: {addr}", "遠端 Agent 連接埠無效: {addr}", "Invalid remote agent port: {addr}", "リモート Agent のポートが無効です: {addr}", "원격 Agent 포트가 잘못되었습니다: {addr}"]),
+    ("error.agent.remote_host_missing", ["远程 Agent 地址缺少主机名: {addr}", "遠端 Agent 位址缺少主機名稱: {addr}", "Remote agent address has no host name: {addr}", "リモート Agent のアドレスにホスト名がありません: {addr}", "원격 Agent 주소에 호스트 이름이 없습니다: {addr}"]),
+    ("error.agent.port_listen_failed", ["Agent 端口 {host}:{port} 监听失败: {error}", "Agent 連接埠 {host}:{port} 監聽失敗: {error}", "Failed to listen on agent port {host}:{port}: {error}", "Agent ポート {host}:{port} での待ち受けに失敗しました: {error}", "Agent 포트 {host}:{port} 수신 대기 실패: {error}"]),
+    ("error.agent.not_found", ["Agent #{index} 不存在", "Agent #{index} 不存在", "Agent #{index} does not exist", "Agent #{index} は存在しません", "Agent #{index}이(가) 없습니다"]),
+    ("error.agent.restart_failed", ["Agent #{index} 重启失败: {error}", "Agent #{index} 重新啟動失敗: {error}", "Failed to restart agent #{index}: {error}", "Agent #{index} の再起動に失敗しました: {error}", "Agent #{index} 재시작 실패: {error}"]),
+    ("error.agent.input_remote", ["Agent #{index} 为远程 Agent，不支持输入", "Agent #{index} 為遠端 Agent，不支援輸入", "Agent #{index} is remote and does not accept input", "Agent #{index} はリモート Agent のため入力できません", "Agent #{index}은(는) 원격 Agent라 입력을 지원하지 않습니다"]),
+    ("error.agent.input_busy", ["Agent #{index} 的标准输入不可用或正在写入", "Agent #{index} 的標準輸入無法使用或正在寫入", "Standard input of agent #{index} is unavailable or busy", "Agent #{index} の標準入力は使用できないか書き込み中です", "Agent #{index}의 표준 입력을 사용할 수 없거나 쓰는 중입니다"]),
+    ("error.agent.input_write_failed", ["写入 Agent #{index} 标准输入失败: {error}", "寫入 Agent #{index} 標準輸入失敗: {error}", "Failed to write to the standard input of agent #{index}: {error}", "Agent #{index} の標準入力への書き込みに失敗しました: {error}", "Agent #{index} 표준 입력 쓰기 실패: {error}"]),
+    ("error.agent.resolve_failed", ["解析远程 Agent 地址 {host}:{port} 失败: {error}", "解析遠端 Agent 位址 {host}:{port} 失敗: {error}", "Failed to resolve the remote agent address {host}:{port}: {error}", "リモート Agent のアドレス {host}:{port} を解決できません: {error}", "원격 Agent 주소 {host}:{port} 확인 실패: {error}"]),
+    ("error.agent.resolve_empty", ["远程 Agent 地址 {host}:{port} 未解析到任何地址", "遠端 Agent 位址 {host}:{port} 未解析到任何位址", "The remote agent address {host}:{port} did not resolve to any address", "リモート Agent のアドレス {host}:{port} からアドレスを解決できませんでした", "원격 Agent 주소 {host}:{port}에서 확인된 주소가 없습니다"]),
+    ("error.agent.allowed_peers_missing", ["在非回环地址 {host} 上等待 Agent 连接时，需要在 tcp_allowed_peers 中填写允许连接的 Agent 地址", "在非回環位址 {host} 上等待 Agent 連線時，需要在 tcp_allowed_peers 中填寫允許連線的 Agent 位址", "Waiting for the agent on the non-loopback address {host} requires listing the allowed agent addresses in tcp_allowed_peers", "ループバック以外のアドレス {host} で Agent の接続を待つには、tcp_allowed_peers に接続を許可する Agent のアドレスを指定してください", "루프백이 아닌 주소 {host}에서 Agent 연결을 기다리려면 tcp_allowed_peers에 연결을 허용할 Agent 주소를 지정하세요"]),
+    ("error.agent.allowed_peer_invalid", ["无法解析允许连接的 Agent 地址 {peer}: {error}", "無法解析允許連線的 Agent 位址 {peer}: {error}", "Failed to resolve the allowed agent address {peer}: {error}", "接続を許可する Agent のアドレス {peer} を解決できません: {error}", "연결을 허용할 Agent 주소 {peer}를 확인할 수 없습니다: {error}"]),
+    ("error.fs.read_file", ["读取文件失败 [{path}]: {error}", "讀取檔案失敗 [{path}]: {error}", "Failed to read file [{path}]: {error}", "ファイルの読み込みに失敗しました [{path}]: {error}", "파일 읽기 실패 [{path}]: {error}"]),
+    ("error.fs.write_file", ["写入文件失败 [{path}]: {error}", "寫入檔案失敗 [{path}]: {error}", "Failed to write file [{path}]: {error}", "ファイルの書き込みに失敗しました [{path}]: {error}", "파일 쓰기 실패 [{path}]: {error}"]),
+    ("error.fs.rename", ["重命名文件失败 [{path}]: {error}", "重新命名檔案失敗 [{path}]: {error}", "Failed to rename file [{path}]: {error}", "ファイル名の変更に失敗しました [{path}]: {error}", "파일 이름 변경 실패 [{path}]: {error}"]),
+    ("error.fs.create_dir", ["无法创建目录 [{path}]: {error}", "無法建立目錄 [{path}]: {error}", "Failed to create folder [{path}]: {error}", "フォルダーを作成できません [{path}]: {error}", "폴더를 만들 수 없습니다 [{path}]: {error}"]),
+    ("error.fs.path_not_allowed", ["不允许访问的路径: {path}", "不允許存取的路徑: {path}", "Access to this path is not allowed: {path}", "このパスへのアクセスは許可されていません: {path}", "허용되지 않은 경로입니다: {path}"]),
+    ("error.crypto.write_machine_key", ["写入本机密钥失败: {error}", "寫入本機金鑰失敗: {error}", "Failed to write the machine key: {error}", "マシンキーの書き込みに失敗しました: {error}", "기기 키 쓰기 실패: {error}"]),
+    ("error.crypto.init_failed", ["初始化加密失败: {error}", "初始化加密失敗: {error}", "Failed to initialize encryption: {error}", "暗号化の初期化に失敗しました: {error}", "암호화 초기화 실패: {error}"]),
+    ("error.crypto.encrypt_failed", ["加密失败", "加密失敗", "Encryption failed", "暗号化に失敗しました", "암호화 실패"]),
+    ("error.crypto.bad_format", ["加密数据格式错误", "加密資料格式錯誤", "Encrypted data is malformed", "暗号化データの形式が正しくありません", "암호화된 데이터 형식이 잘못되었습니다"]),
+    ("error.crypto.decrypt_failed", ["解密失败（密钥不匹配或数据已损坏）", "解密失敗（金鑰不符或資料已損毀）", "Decryption failed (wrong key or corrupted data)", "復号に失敗しました（キーが一致しないかデータが破損しています）", "복호화 실패(키가 일치하지 않거나 데이터가 손상됨)"]),
+    ("error.crypto.not_text", ["解密数据不是有效文本: {error}", "解密資料不是有效文字: {error}", "Decrypted data is not valid text: {error}", "復号したデータが有効なテキストではありません: {error}", "복호화된 데이터가 올바른 텍스트가 아닙니다: {error}"]),
+    ("error.crypto.locked", ["配置已加密，请先输入密码解锁", "設定已加密，請先輸入密碼解鎖", "Configuration is encrypted. Enter the passphrase to unlock it first", "設定は暗号化されています。先にパスワードを入力してロックを解除してください", "설정이 암호화되어 있습니다. 먼저 암호를 입력해 잠금을 해제하세요"]),
+    ("error.crypto.backup_failed", ["备份文件失败 [{path}]: {error}", "備份檔案失敗 [{path}]: {error}", "Failed to back up file [{path}]: {error}", "ファイルのバックアップに失敗しました [{path}]: {error}", "파일 백업 실패 [{path}]: {error}"]),
+    ("error.crypto.replace_failed", ["替换文件失败 [{path}]: {error}", "取代檔案失敗 [{path}]: {error}", "Failed to replace file [{path}]: {error}", "ファイルの置き換えに失敗しました [{path}]: {error}", "파일 교체 실패 [{path}]: {error}"]),
+    ("error.crypto.write_settings", ["写入加密设置失败: {error}", "寫入加密設定失敗: {error}", "Failed to save encryption settings: {error}", "暗号化設定の保存に失敗しました: {error}", "암호화 설정 저장 실패: {error}"]),
+    ("error.crypto.cannot_decrypt", ["无法解密文件: {path}", "無法解密檔案: {path}", "Cannot decrypt file: {path}", "ファイルを復号できません: {path}", "파일을 복호화할 수 없습니다: {path}"]),
+    ("error.crypto.settings_corrupted", ["加密设置已损坏: {error}", "加密設定已損毀: {error}", "Encryption settings are corrupted: {error}", "暗号化設定が破損しています: {error}", "암호화 설정이 손상되었습니다: {error}"]),
+    ("error.crypto.wrong_passphrase", ["密码错误", "密碼錯誤", "Wrong passphrase", "パスワードが違います", "암호가 틀렸습니다"]),
+    ("error.web.interface_not_loaded", ["interface.json 尚未加载", "interface.json 尚未載入", "interface.json has not been loaded yet", "interface.json はまだ読み込まれていません", "interface.json이 아직 로드되지 않았습니다"]),
+    ("error.web.screencap_timeout", ["截图超时", "截圖逾時", "Screenshot timed out", "スクリーンショットがタイムアウトしました", "스크린샷 시간이 초과되었습니다"]),
+    ("error.web.background_read", ["背景图读取失败: {error}", "背景圖讀取失敗: {error}", "Failed to read the background image: {error}", "背景画像を読み込めませんでした: {error}", "배경 이미지를 읽지 못했습니다: {error}"]),
+    ("error.web.background_unset", ["未设置背景图片", "未設定背景圖片", "No background image is set", "背景画像が設定されていません", "배경 이미지가 설정되지 않았습니다"]),
+    ("error.web.missing_path", ["缺少 path 参数", "缺少 path 參數", "Missing path parameter", "path パラメーターがありません", "path 매개변수가 없습니다"]),
+    ("error.web.file_not_found", ["文件不存在", "檔案不存在", "File not found", "ファイルが見つかりません", "파일을 찾을 수 없습니다"]),
+    ("error.web.missing_url", ["缺少 url", "缺少 url", "Missing url", "url がありません", "url이 없습니다"]),
+    ("error.fs.remove", ["删除失败 [{path}]: {error}", "刪除失敗 [{path}]: {error}", "Failed to delete [{path}]: {error}", "削除に失敗しました [{path}]: {error}", "삭제 실패 [{path}]: {error}"]),
+    ("error.fs.create_file", ["无法创建文件 [{path}]: {error}", "無法建立檔案 [{path}]: {error}", "Failed to create file [{path}]: {error}", "ファイルを作成できません [{path}]: {error}", "파일을 만들 수 없습니다 [{path}]: {error}"]),
+    ("error.fs.no_file_name", ["无法获取文件名: {path}", "無法取得檔案名稱: {path}", "Cannot get the file name: {path}", "ファイル名を取得できません: {path}", "파일 이름을 가져올 수 없습니다: {path}"]),
+    ("error.fs.move", ["无法移动 [{from}] -> [{to}]: {error}", "無法移動 [{from}] -> [{to}]: {error}", "Failed to move [{from}] -> [{to}]: {error}", "移動できません [{from}] -> [{to}]: {error}", "이동할 수 없습니다 [{from}] -> [{to}]: {error}"]),
+    ("error.fs.copy", ["无法复制文件 [{from}] -> [{to}]: {error}", "無法複製檔案 [{from}] -> [{to}]: {error}", "Failed to copy file [{from}] -> [{to}]: {error}", "ファイルをコピーできません [{from}] -> [{to}]: {error}", "파일을 복사할 수 없습니다 [{from}] -> [{to}]: {error}"]),
+    ("error.fs.read_dir", ["无法读取目录 [{path}]: {error}", "無法讀取目錄 [{path}]: {error}", "Failed to read folder [{path}]: {error}", "フォルダーを読み込めません [{path}]: {error}", "폴더를 읽을 수 없습니다 [{path}]: {error}"]),
+    ("error.fs.read_dir_entry", ["无法读取目录条目: {error}", "無法讀取目錄項目: {error}", "Failed to read a folder entry: {error}", "フォルダーの項目を読み込めません: {error}", "폴더 항목을 읽을 수 없습니다: {error}"]),
+    ("error.fs.clean_dir", ["无法清理目录 [{path}]: {error}", "無法清理目錄 [{path}]: {error}", "Failed to clean folder [{path}]: {error}", "フォルダーを整理できません [{path}]: {error}", "폴더를 정리할 수 없습니다 [{path}]: {error}"]),
+    ("error.fs.exe_path", ["获取程序路径失败: {error}", "取得程式路徑失敗: {error}", "Failed to get the program path: {error}", "プログラムのパスを取得できません: {error}", "프로그램 경로를 가져오지 못했습니다: {error}"]),
+    ("error.update.open_archive", ["无法打开压缩包 [{path}]: {error}", "無法開啟壓縮檔 [{path}]: {error}", "Failed to open archive [{path}]: {error}", "アーカイブを開けません [{path}]: {error}", "압축 파일을 열 수 없습니다 [{path}]: {error}"]),
+    ("error.update.parse_zip", ["无法解析 ZIP 文件: {error}", "無法解析 ZIP 檔案: {error}", "Failed to parse ZIP file: {error}", "ZIP ファイルを解析できません: {error}", "ZIP 파일을 분석할 수 없습니다: {error}"]),
+    ("error.update.read_zip_entry", ["无法读取 ZIP 条目 {index}: {error}", "無法讀取 ZIP 項目 {index}: {error}", "Failed to read ZIP entry {index}: {error}", "ZIP の項目 {index} を読み込めません: {error}", "ZIP 항목 {index}을(를) 읽을 수 없습니다: {error}"]),
+    ("error.update.extract_failed", ["解压失败: {error}", "解壓縮失敗: {error}", "Failed to extract: {error}", "展開に失敗しました: {error}", "압축 해제 실패: {error}"]),
+    ("error.update.read_changes", ["无法读取 changes.json: {error}", "無法讀取 changes.json: {error}", "Failed to read changes.json: {error}", "changes.json を読み込めません: {error}", "changes.json을 읽을 수 없습니다: {error}"]),
+    ("error.update.parse_changes", ["无法解析 changes.json: {error}", "無法解析 changes.json: {error}", "Failed to parse changes.json: {error}", "changes.json を解析できません: {error}", "changes.json을 분석할 수 없습니다: {error}"]),
+    ("error.update.signature_no_package", ["缺少更新包路径，无法校验更新签名", "缺少更新套件路徑，無法驗證更新簽章", "The update package path is missing, so its signature cannot be verified", "更新パッケージのパスがないため、署名を検証できません", "업데이트 패키지 경로가 없어 서명을 확인할 수 없습니다"]),
+    ("error.update.signature_missing", ["未找到更新包签名（{path}），当前更新源未提供签名，已拒绝安装。请改用 GitHub 下载完整包后重试", "找不到更新套件簽章（{path}），目前的更新來源未提供簽章，已拒絕安裝。請改用 GitHub 下載完整套件後重試", "The update package signature ({path}) was not found because the update source does not provide one, so the install was refused. Download the full package from GitHub and try again", "更新パッケージの署名（{path}）が見つかりません。現在の更新元は署名を提供していないため、インストールを拒否しました。GitHub から完全パッケージをダウンロードして再試行してください", "업데이트 패키지 서명({path})을 찾을 수 없습니다. 현재 업데이트 소스가 서명을 제공하지 않아 설치를 거부했습니다. GitHub에서 전체 패키지를 내려받아 다시 시도하세요"]),
+    ("error.update.signature_rejected", ["更新包签名校验未通过，已拒绝安装: {error}", "更新套件簽章驗證未通過，已拒絕安裝: {error}", "Update package signature check failed; installation refused: {error}", "更新パッケージの署名検証に失敗したため、インストールを拒否しました: {error}", "업데이트 패키지 서명 확인에 실패하여 설치를 거부했습니다: {error}"]),
+    ("error.update.read_patch", ["无法读取补丁 [{path}]: {error}", "無法讀取修補檔 [{path}]: {error}", "Failed to read patch [{path}]: {error}", "パッチを読み込めません [{path}]: {error}", "패치를 읽을 수 없습니다 [{path}]: {error}"]),
+    ("error.update.patch_failed", ["补丁应用失败 [{path}]: {error}", "修補檔套用失敗 [{path}]: {error}", "Failed to apply patch [{path}]: {error}", "パッチの適用に失敗しました [{path}]: {error}", "패치 적용 실패 [{path}]: {error}"]),
+    ("error.update.hpatchz_missing", ["未找到 hpatchz，无法应用 hdiff 补丁", "找不到 hpatchz，無法套用 hdiff 修補檔", "hpatchz was not found, so the hdiff patch cannot be applied", "hpatchz が見つからないため、hdiff パッチを適用できません", "hpatchz를 찾을 수 없어 hdiff 패치를 적용할 수 없습니다"]),
+    ("error.update.hpatchz_run", ["运行 hpatchz 失败: {error}", "執行 hpatchz 失敗: {error}", "Failed to run hpatchz: {error}", "hpatchz の実行に失敗しました: {error}", "hpatchz 실행 실패: {error}"]),
+    ("error.update.hpatchz_failed", ["hpatchz 补丁应用失败 [{path}]，退出码: {code}", "hpatchz 修補檔套用失敗 [{path}]，結束代碼: {code}", "hpatchz failed to apply patch [{path}], exit code: {code}", "hpatchz によるパッチの適用に失敗しました [{path}]、終了コード: {code}", "hpatchz 패치 적용 실패 [{path}], 종료 코드: {code}"]),
+    ("error.update.patch_format", ["不支持的补丁格式: {format}", "不支援的修補檔格式: {format}", "Unsupported patch format: {format}", "サポートされていないパッチ形式です: {format}", "지원하지 않는 패치 형식입니다: {format}"]),
+    ("error.update.unsafe_path", ["更新清单包含不安全的路径: {path}", "更新清單包含不安全的路徑: {path}", "Update manifest contains an unsafe path: {path}", "更新マニフェストに安全でないパスが含まれています: {path}", "업데이트 매니페스트에 안전하지 않은 경로가 있습니다: {path}"]),
+    ("error.update.source_hash_mismatch", ["待补丁文件哈希不匹配 [{path}]: 期望 {expected}，实际 {actual}", "待修補檔案雜湊不符 [{path}]: 預期 {expected}，實際 {actual}", "Hash mismatch for the file to patch [{path}]: expected {expected}, got {actual}", "パッチ対象ファイルのハッシュが一致しません [{path}]: 期待値 {expected}、実際 {actual}", "패치할 파일의 해시가 일치하지 않습니다 [{path}]: 예상 {expected}, 실제 {actual}"]),
+    ("error.update.result_hash_mismatch", ["补丁结果哈希不匹配 [{path}]: 期望 {expected}，实际 {actual}", "修補結果雜湊不符 [{path}]: 預期 {expected}，實際 {actual}", "Hash mismatch for the patched file [{path}]: expected {expected}, got {actual}", "パッチ適用後のハッシュが一致しません [{path}]: 期待値 {expected}、実際 {actual}", "패치 결과 해시가 일치하지 않습니다 [{path}]: 예상 {expected}, 실제 {actual}"]),
+    ("error.update.read_extract_dir", ["无法读取解压目录: {error}", "無法讀取解壓縮目錄: {error}", "Failed to read the extracted folder: {error}", "展開先フォルダーを読み込めません: {error}", "압축 해제 폴더를 읽을 수 없습니다: {error}"]),
+    ("error.update.unknown_process", ["未知进程", "未知處理程序", "unknown process", "不明なプロセス", "알 수 없는 프로세스"]),
+    ("error.update.stage_file", ["无法暂存文件 [{path}]: {error}", "無法暫存檔案 [{path}]: {error}", "Failed to stage file [{path}]: {error}", "ファイルを一時保存できません [{path}]: {error}", "파일을 임시 저장할 수 없습니다 [{path}]: {error}"]),
+    ("error.update.in_use_suffix", ["{error}；文件被占用: {lockers}", "{error}；檔案被佔用: {lockers}", "{error}; file is in use by: {lockers}", "{error}。ファイルは使用中です: {lockers}", "{error}; 파일이 사용 중입니다: {lockers}"]),
+    ("error.update.copy_locked", ["无法复制文件 [{from}] -> [{to}]: 文件被占用（{lockers}），请关闭相关程序后重试: {error}", "無法複製檔案 [{from}] -> [{to}]: 檔案被佔用（{lockers}），請關閉相關程式後重試: {error}", "Failed to copy file [{from}] -> [{to}]: the file is in use ({lockers}). Close those programs and try again: {error}", "ファイルをコピーできません [{from}] -> [{to}]: ファイルが使用中です（{lockers}）。該当するプログラムを閉じてから再試行してください: {error}", "파일을 복사할 수 없습니다 [{from}] -> [{to}]: 파일이 사용 중입니다({lockers}). 해당 프로그램을 닫고 다시 시도하세요: {error}"]),
+    ("error.update.create_fallback_dir", ["无法创建兜底目录: {error}", "無法建立備援目錄: {error}", "Failed to create the fallback folder: {error}", "フォールバック用フォルダーを作成できません: {error}", "대체 폴더를 만들 수 없습니다: {error}"]),
+    ("error.update.probe_spawn", ["启动探测进程失败: {error}", "啟動偵測處理程序失敗: {error}", "Failed to start the probe process: {error}", "検査プロセスの起動に失敗しました: {error}", "검사 프로세스 시작 실패: {error}"]),
+    ("error.update.probe_timeout", ["探测进程超时（加载 MaaFramework 无响应）", "偵測處理程序逾時（載入 MaaFramework 無回應）", "The probe process timed out (MaaFramework did not respond while loading)", "検査プロセスがタイムアウトしました（MaaFramework の読み込みが応答しません）", "검사 프로세스 시간 초과(MaaFramework 로드가 응답하지 않음)"]),
+    ("error.update.probe_wait", ["等待探测进程失败: {error}", "等待偵測處理程序失敗: {error}", "Failed to wait for the probe process: {error}", "検査プロセスの待機に失敗しました: {error}", "검사 프로세스 대기 실패: {error}"]),
+    ("error.update.probe_failed", ["MaaFramework 加载失败（退出码 {code}）: {output}", "MaaFramework 載入失敗（結束代碼 {code}）: {output}", "MaaFramework failed to load (exit code {code}): {output}", "MaaFramework の読み込みに失敗しました（終了コード {code}）: {output}", "MaaFramework 로드 실패(종료 코드 {code}): {output}"]),
+    ("error.update.probe_panicked", ["探测任务异常: {error}", "偵測工作異常: {error}", "The probe task crashed: {error}", "検査タスクで異常が発生しました: {error}", "검사 작업 오류: {error}"]),
+    ("error.update.maafw_backup_missing", ["未找到更新前的 MaaFramework 备份 [{path}]", "找不到更新前的 MaaFramework 備份 [{path}]", "The MaaFramework backup from before the update was not found [{path}]", "更新前の MaaFramework のバックアップが見つかりません [{path}]", "업데이트 전 MaaFramework 백업을 찾을 수 없습니다 [{path}]"]),
+    ("error.download.header_name", ["无效的请求头名称: {name}", "無效的請求標頭名稱: {name}", "Invalid request header name: {name}", "無効なリクエストヘッダー名です: {name}", "잘못된 요청 헤더 이름입니다: {name}"]),
+    ("error.download.header_value", ["请求头 {name} 的值无效", "請求標頭 {name} 的值無效", "Invalid value for request header {name}", "リクエストヘッダー {name} の値が無効です", "요청 헤더 {name}의 값이 잘못되었습니다"]),
+    ("error.download.proxy", ["代理配置失败: {error}。请检查代理格式是否正确（支持 http:// 或 socks5://）", "代理設定失敗: {error}。請檢查代理格式是否正確（支援 http:// 或 socks5://）", "Invalid proxy setting: {error}. Check the proxy format (http:// and socks5:// are supported)", "プロキシの設定に失敗しました: {error}。プロキシの形式を確認してください（http:// と socks5:// に対応）", "프록시 설정 실패: {error}. 프록시 형식을 확인하세요(http://, socks5:// 지원)"]),
+    ("error.download.client", ["创建 HTTP 客户端失败: {error}", "建立 HTTP 用戶端失敗: {error}", "Failed to create the HTTP client: {error}", "HTTP クライアントの作成に失敗しました: {error}", "HTTP 클라이언트 생성 실패: {error}"]),
+    ("error.download.request", ["请求失败: {error}", "請求失敗: {error}", "Request failed: {error}", "リクエストに失敗しました: {error}", "요청 실패: {error}"]),
+    ("error.download.github_rate_limit", ["GitHub API 请求次数已达上限，将于 {reset_at} 重置，可在设置中填写 GitHub PAT", "GitHub API 請求次數已達上限，將於 {reset_at} 重設，可在設定中填寫 GitHub PAT", "GitHub API rate limit reached; it resets at {reset_at}. You can add a GitHub PAT in settings", "GitHub API のリクエスト上限に達しました。{reset_at} にリセットされます。設定で GitHub PAT を入力できます", "GitHub API 요청 한도에 도달했습니다. {reset_at}에 초기화됩니다. 설정에서 GitHub PAT를 입력할 수 있습니다"]),
+    ("error.download.github_status", ["GitHub API 错误: {status}", "GitHub API 錯誤: {status}", "GitHub API error: {status}", "GitHub API エラー: {status}", "GitHub API 오류: {status}"]),
+    ("error.download.parse_json", ["解析 JSON 失败: {error}", "解析 JSON 失敗: {error}", "Failed to parse JSON: {error}", "JSON の解析に失敗しました: {error}", "JSON 분석 실패: {error}"]),
+    ("error.download.invalid_repo", ["仓库格式无效，应为 owner/repo: {repo}", "儲存庫格式無效，應為 owner/repo: {repo}", "Invalid repository (expected owner/repo): {repo}", "リポジトリの形式が無効です（owner/repo の形式）: {repo}", "저장소 형식이 잘못되었습니다(owner/repo 형식): {repo}"]),
+    ("error.download.create_dir", ["无法创建目录: {error}", "無法建立目錄: {error}", "Failed to create folder: {error}", "フォルダーを作成できません: {error}", "폴더를 만들 수 없습니다: {error}"]),
+    ("error.download.http_status", ["HTTP 错误: {status}", "HTTP 錯誤: {status}", "HTTP error: {status}", "HTTP エラー: {status}", "HTTP 오류: {status}"]),
+    ("error.download.create_file", ["无法创建文件: {error}", "無法建立檔案: {error}", "Failed to create file: {error}", "ファイルを作成できません: {error}", "파일을 만들 수 없습니다: {error}"]),
+    ("error.download.write_file", ["写入文件失败: {error}", "寫入檔案失敗: {error}", "Failed to write file: {error}", "ファイルの書き込みに失敗しました: {error}", "파일 쓰기 실패: {error}"]),
+    ("error.download.flush", ["刷新写入缓冲区失败: {error}", "排清寫入緩衝區失敗: {error}", "Failed to flush the write buffer: {error}", "書き込みバッファーのフラッシュに失敗しました: {error}", "쓰기 버퍼 비우기 실패: {error}"]),
+    ("error.download.sync", ["同步文件失败: {error}", "同步檔案失敗: {error}", "Failed to sync file to disk: {error}", "ファイルのディスクへの同期に失敗しました: {error}", "파일 디스크 동기화 실패: {error}"]),
+    ("error.download.cancelled", ["下载已取消", "下載已取消", "Download cancelled", "ダウンロードはキャンセルされました", "다운로드가 취소되었습니다"]),
+    ("error.download.read_body", ["下载数据失败: {error}", "下載資料失敗: {error}", "Failed to download data: {error}", "データのダウンロードに失敗しました: {error}", "데이터 다운로드 실패: {error}"]),
+    ("error.download.writer_exited", ["磁盘写入线程异常退出", "磁碟寫入執行緒異常結束", "The disk writer thread exited unexpectedly", "ディスク書き込みスレッドが異常終了しました", "디스크 쓰기 스레드가 비정상 종료되었습니다"]),
+    ("error.download.writer_panicked", ["写入任务异常: {error}", "寫入工作異常: {error}", "The write task crashed: {error}", "書き込みタスクで異常が発生しました: {error}", "쓰기 작업 오류: {error}"]),
+    ("error.download.rename", ["重命名文件失败: {error}", "重新命名檔案失敗: {error}", "Failed to rename file: {error}", "ファイル名の変更に失敗しました: {error}", "파일 이름 변경 실패: {error}"]),
+    ("error.download.preallocate", ["预分配文件失败: {error}", "預先配置檔案失敗: {error}", "Failed to preallocate file: {error}", "ファイルの事前割り当てに失敗しました: {error}", "파일 사전 할당 실패: {error}"]),
+    ("error.download.chunk_task_panicked", ["分块下载任务异常: {error}", "分段下載工作異常: {error}", "A chunk download task crashed: {error}", "分割ダウンロードのタスクで異常が発生しました: {error}", "분할 다운로드 작업 오류: {error}"]),
+    ("error.download.open_file", ["无法打开文件: {error}", "無法開啟檔案: {error}", "Failed to open file: {error}", "ファイルを開けません: {error}", "파일을 열 수 없습니다: {error}"]),
+    ("error.download.seek", ["定位文件失败: {error}", "定位檔案失敗: {error}", "Failed to seek in file: {error}", "ファイル内の位置移動に失敗しました: {error}", "파일 위치 이동 실패: {error}"]),
+    ("error.download.no_partial_content", ["服务器未返回分块内容: {status}", "伺服器未回傳分段內容: {status}", "The server did not return partial content: {status}", "サーバーが部分コンテンツを返しませんでした: {status}", "서버가 부분 콘텐츠를 반환하지 않았습니다: {status}"]),
+    ("error.download.connection_closed", ["连接提前结束", "連線提前結束", "The connection closed early", "接続が途中で切断されました", "연결이 일찍 끊어졌습니다"]),
+    ("error.download.chunk_failed", ["分块 {start}-{end} 下载失败: {error}", "分段 {start}-{end} 下載失敗: {error}", "Failed to download chunk {start}-{end}: {error}", "分割 {start}-{end} のダウンロードに失敗しました: {error}", "분할 {start}-{end} 다운로드 실패: {error}"]),
+    ("error.fs.exe_dir", ["无法获取程序目录", "無法取得程式目錄", "Cannot get the program folder", "プログラムのフォルダーを取得できません", "프로그램 폴더를 가져올 수 없습니다"]),
+    ("error.fs.file_type", ["无法获取文件类型: {error}", "無法取得檔案類型: {error}", "Cannot get the file type: {error}", "ファイルの種類を取得できません: {error}", "파일 형식을 가져올 수 없습니다: {error}"]),
+    ("error.fs.open_file", ["打开文件失败 [{path}]: {error}", "開啟檔案失敗 [{path}]: {error}", "Failed to open file [{path}]: {error}", "ファイルを開けません [{path}]: {error}", "파일 열기 실패 [{path}]: {error}"]),
+    ("error.fs.not_found", ["文件不存在: {path}", "檔案不存在: {path}", "File not found: {path}", "ファイルが見つかりません: {path}", "파일을 찾을 수 없습니다: {path}"]),
+    ("error.fs.trash", ["移入回收站失败 [{path}]: {error}", "移至資源回收筒失敗 [{path}]: {error}", "Failed to move to the Recycle Bin [{path}]: {error}", "ごみ箱への移動に失敗しました [{path}]: {error}", "휴지통으로 이동 실패 [{path}]: {error}"]),
+    ("error.fs.metadata", ["无法获取文件信息 [{path}]: {error}", "無法取得檔案資訊 [{path}]: {error}", "Cannot read file metadata [{path}]: {error}", "ファイル情報を取得できません [{path}]: {error}", "파일 정보를 가져올 수 없습니다 [{path}]: {error}"]),
+    ("error.fs.set_executable", ["无法设置执行权限 [{path}]: {error}", "無法設定執行權限 [{path}]: {error}", "Cannot make the file executable [{path}]: {error}", "実行権限を設定できません [{path}]: {error}", "실행 권한을 설정할 수 없습니다 [{path}]: {error}"]),
+    ("error.fs.unsupported_hash", ["不支持的哈希算法: {name}", "不支援的雜湊演算法: {name}", "Unsupported hash algorithm: {name}", "サポートされていないハッシュアルゴリズム: {name}", "지원하지 않는 해시 알고리즘: {name}"]),
+    ("error.fs.write_archive", ["写入压缩包失败 [{path}]: {error}", "寫入壓縮檔失敗 [{path}]: {error}", "Failed to write archive [{path}]: {error}", "アーカイブの書き込みに失敗しました [{path}]: {error}", "압축 파일 쓰기 실패 [{path}]: {error}"]),
+    ("error.fs.no_logs_dir", ["日志目录不存在", "日誌目錄不存在", "The log folder does not exist", "ログフォルダーが存在しません", "로그 폴더가 없습니다"]),
+    ("error.fs.no_logs", ["没有可导出的日志文件", "沒有可匯出的日誌檔案", "There are no log files to export", "エクスポートできるログファイルがありません", "내보낼 로그 파일이 없습니다"]),
+    ("error.fs.home_missing", ["无法获取 HOME 环境变量", "無法取得 HOME 環境變數", "Cannot read the HOME environment variable", "HOME 環境変数を取得できません", "HOME 환경 변수를 가져올 수 없습니다"]),
+    ("error.webview2.unsupported_arch", ["不支持的 CPU 架构: {arch}。当前应用仅支持 64 位 Windows（x64、ARM64），请在 64 位系统上运行。", "不支援的 CPU 架構: {arch}。目前應用程式僅支援 64 位元 Windows（x64、ARM64），請在 64 位元系統上執行。", "Unsupported CPU architecture: {arch}. This app only supports 64-bit Windows (x64, ARM64). Run it on a 64-bit system.", "サポートされていない CPU アーキテクチャです: {arch}。このアプリは 64 ビット版 Windows（x64、ARM64）のみに対応しています。64 ビットのシステムで実行してください。", "지원하지 않는 CPU 아키텍처입니다: {arch}. 이 앱은 64비트 Windows(x64, ARM64)만 지원합니다. 64비트 시스템에서 실행하세요."]),
+    ("error.webview2.runtime_incomplete", ["解压后的 WebView2 运行时目录不完整（未找到 msedgewebview2.exe）。\n请删除 cache/webview2_runtime/ 目录后重启程序重试。", "解壓縮後的 WebView2 執行階段目錄不完整（找不到 msedgewebview2.exe）。\n請刪除 cache/webview2_runtime/ 目錄後重新啟動程式再試。", "The extracted WebView2 runtime folder is incomplete (msedgewebview2.exe not found).\nDelete the cache/webview2_runtime/ folder and restart the app.", "展開した WebView2 ランタイムのフォルダーが不完全です（msedgewebview2.exe が見つかりません）。\ncache/webview2_runtime/ フォルダーを削除してからアプリを再起動してください。", "압축 해제한 WebView2 런타임 폴더가 불완전합니다(msedgewebview2.exe 없음).\ncache/webview2_runtime/ 폴더를 삭제한 후 앱을 다시 시작하세요."]),
+    ("error.webview2.download_failed_manual", ["系统 WebView2 不可用，下载独立 WebView2 运行时失败：\r\n{error}\r\n\r\n【方法一】检查网络连接后重启程序重试\r\n\r\n【方法二】手动下载 cab 文件并放到程序同目录\r\n1. 前往 https://aka.ms/webview2installer\r\n   选择 \"Fixed Version\" 下载对应架构（{arch}）的 cab 文件\r\n2. 将下载的 cab 文件（文件名类似 {cab_name}）\r\n   放到本程序 exe 所在目录下\r\n3. 重启程序，将自动检测并解压使用\r\n\r\n【方法三】手动安装系统 WebView2 运行时\r\n前往 https://aka.ms/webview2installer\r\n下载 Evergreen Bootstrapper，运行安装后重启电脑即可", "系統 WebView2 無法使用，下載獨立 WebView2 執行階段失敗：\r\n{error}\r\n\r\n【方法一】檢查網路連線後重新啟動程式再試\r\n\r\n【方法二】手動下載 cab 檔案並放到程式同目錄\r\n1. 前往 https://aka.ms/webview2installer\r\n   選擇 \"Fixed Version\" 下載對應架構（{arch}）的 cab 檔案\r\n2. 將下載的 cab 檔案（檔名類似 {cab_name}）\r\n   放到本程式 exe 所在目錄下\r\n3. 重新啟動程式，將自動偵測並解壓縮使用\r\n\r\n【方法三】手動安裝系統 WebView2 執行階段\r\n前往 https://aka.ms/webview2installer\r\n下載 Evergreen Bootstrapper，執行安裝後重新啟動電腦即可", "The system WebView2 is unavailable and downloading the standalone WebView2 runtime failed:\r\n{error}\r\n\r\n[Option 1] Check your network connection and restart the app\r\n\r\n[Option 2] Download the cab file manually and put it next to the app\r\n1. Go to https://aka.ms/webview2installer\r\n   Choose \"Fixed Version\" and download the cab file for your architecture ({arch})\r\n2. Put the downloaded cab file (named like {cab_name})\r\n   in the folder that contains this app's exe\r\n3. Restart the app; it will detect and extract the file automatically\r\n\r\n[Option 3] Install the system WebView2 runtime manually\r\nGo to https://aka.ms/webview2installer\r\nDownload the Evergreen Bootstrapper, run it, then restart your computer", "システムの WebView2 が使用できず、独立版 WebView2 ランタイムのダウンロードにも失敗しました：\r\n{error}\r\n\r\n【方法 1】ネットワーク接続を確認してからアプリを再起動する\r\n\r\n【方法 2】cab ファイルを手動でダウンロードしてアプリと同じフォルダーに置く\r\n1. https://aka.ms/webview2installer を開く\r\n   \"Fixed Version\" を選び、アーキテクチャ（{arch}）に合った cab ファイルをダウンロード\r\n2. ダウンロードした cab ファイル（{cab_name} のような名前）を\r\n   このアプリの exe があるフォルダーに置く\r\n3. アプリを再起動すると自動的に検出して展開します\r\n\r\n【方法 3】システムの WebView2 ランタイムを手動でインストールする\r\nhttps://aka.ms/webview2installer を開き\r\nEvergreen Bootstrapper をダウンロードして実行し、PC を再起動してください", "시스템 WebView2를 사용할 수 없고 독립 WebView2 런타임 다운로드도 실패했습니다:\r\n{error}\r\n\r\n[방법 1] 네트워크 연결을 확인한 후 앱을 다시 시작\r\n\r\n[방법 2] cab 파일을 직접 내려받아 앱과 같은 폴더에 두기\r\n1. https://aka.ms/webview2installer 로 이동\r\n   \"Fixed Version\"을 선택해 아키텍처({arch})에 맞는 cab 파일 다운로드\r\n2. 내려받은 cab 파일({cab_name}과 비슷한 이름)을\r\n   이 앱의 exe가 있는 폴더에 두기\r\n3. 앱을 다시 시작하면 자동으로 감지해 압축을 풉니다\r\n\r\n[방법 3] 시스템 WebView2 런타임 직접 설치\r\nhttps://aka.ms/webview2installer 에서\r\nEvergreen Bootstrapper를 내려받아 실행한 후 컴퓨터를 다시 시작하세요"]),
+    ("error.webview2.download_failed_no_arch", ["系统 WebView2 不可用，下载独立 WebView2 运行时失败：\r\n{error}\r\n\r\n此外，无法判断当前系统架构：{arch_error}\r\n\r\n【手动安装系统 WebView2 运行时】\r\n前往 https://aka.ms/webview2installer\r\n下载 Evergreen Bootstrapper，运行安装后重启电脑即可", "系統 WebView2 無法使用，下載獨立 WebView2 執行階段失敗：\r\n{error}\r\n\r\n此外，無法判斷目前系統架構：{arch_error}\r\n\r\n【手動安裝系統 WebView2 執行階段】\r\n前往 https://aka.ms/webview2installer\r\n下載 Evergreen Bootstrapper，執行安裝後重新啟動電腦即可", "The system WebView2 is unavailable and downloading the standalone WebView2 runtime failed:\r\n{error}\r\n\r\nThe system architecture could not be determined either: {arch_error}\r\n\r\n[Install the system WebView2 runtime manually]\r\nGo to https://aka.ms/webview2installer\r\nDownload the Evergreen Bootstrapper, run it, then restart your computer", "システムの WebView2 が使用できず、独立版 WebView2 ランタイムのダウンロードにも失敗しました：\r\n{error}\r\n\r\nまた、システムのアーキテクチャを判別できませんでした：{arch_error}\r\n\r\n【システムの WebView2 ランタイムを手動でインストール】\r\nhttps://aka.ms/webview2installer を開き\r\nEvergreen Bootstrapper をダウンロードして実行し、PC を再起動してください", "시스템 WebView2를 사용할 수 없고 독립 WebView2 런타임 다운로드도 실패했습니다:\r\n{error}\r\n\r\n또한 시스템 아키텍처를 확인할 수 없습니다: {arch_error}\r\n\r\n[시스템 WebView2 런타임 직접 설치]\r\nhttps://aka.ms/webview2installer 에서\r\nEvergreen Bootstrapper를 내려받아 실행한 후 컴퓨터를 다시 시작하세요"]),
+    ("error.webview2.expand_missing", ["未找到 expand.exe，请确认系统完整性。\n预期路径: {path}", "找不到 expand.exe，請確認系統完整性。\n預期路徑: {path}", "expand.exe was not found. Check that Windows is intact.\nExpected path: {path}", "expand.exe が見つかりません。システムが破損していないか確認してください。\n想定パス: {path}", "expand.exe를 찾을 수 없습니다. 시스템 무결성을 확인하세요.\n예상 경로: {path}"]),
+    ("error.webview2.system_dir", ["GetSystemDirectory 调用失败，无法获取系统目录", "GetSystemDirectory 呼叫失敗，無法取得系統目錄", "GetSystemDirectory failed; cannot get the system folder", "GetSystemDirectory の呼び出しに失敗したため、システムフォルダーを取得できません", "GetSystemDirectory 호출에 실패하여 시스템 폴더를 가져올 수 없습니다"]),
+    ("error.webview2.create_temp_dir", ["创建临时目录失败: {error}", "建立暫存目錄失敗: {error}", "Failed to create a temporary folder: {error}", "一時フォルダーの作成に失敗しました: {error}", "임시 폴더 생성 실패: {error}"]),
+    ("error.webview2.expand_run", ["运行 expand.exe 失败: {error}", "執行 expand.exe 失敗: {error}", "Failed to run expand.exe: {error}", "expand.exe の実行に失敗しました: {error}", "expand.exe 실행 실패: {error}"]),
+    ("error.webview2.expand_failed", ["解压失败，退出码: {code}", "解壓縮失敗，結束代碼: {code}", "Extraction failed, exit code: {code}", "展開に失敗しました。終了コード: {code}", "압축 해제 실패, 종료 코드: {code}"]),
+    ("error.webview2.runtime_metadata", ["读取运行时目录元数据失败: {error}", "讀取執行階段目錄中繼資料失敗: {error}", "Failed to read the runtime folder metadata: {error}", "ランタイムフォルダーのメタデータを読み込めませんでした: {error}", "런타임 폴더 메타데이터를 읽지 못했습니다: {error}"]),
+    ("error.webview2.runtime_is_link", ["运行时目录 [{path}] 是符号链接或重解析点，出于安全原因拒绝操作。\n请手动删除该链接后重试。", "執行階段目錄 [{path}] 是符號連結或重新剖析點，基於安全考量拒絕操作。\n請手動刪除該連結後重試。", "The runtime folder [{path}] is a symbolic link or reparse point and was refused for security reasons.\nDelete the link manually and try again.", "ランタイムフォルダー [{path}] はシンボリックリンクまたは再解析ポイントのため、安全上の理由で操作を拒否しました。\nリンクを手動で削除してから再試行してください。", "런타임 폴더 [{path}]가 심볼릭 링크 또는 재분석 지점이라 보안상 작업을 거부했습니다.\n링크를 직접 삭제한 후 다시 시도하세요."]),
+    ("error.webview2.remove_runtime_in_use", ["删除旧的 WebView2 运行时目录失败，可能有正在运行的程序正在使用该目录。\n\n请关闭所有已运行的本应用实例后重试。\n\n系统错误: {error}", "刪除舊的 WebView2 執行階段目錄失敗，可能有正在執行的程式正在使用該目錄。\n\n請關閉所有已執行的本應用程式執行個體後重試。\n\n系統錯誤: {error}", "Failed to delete the old WebView2 runtime folder; a running program may be using it.\n\nClose all running copies of this app and try again.\n\nSystem error: {error}", "古い WebView2 ランタイムフォルダーを削除できませんでした。実行中のプログラムが使用している可能性があります。\n\n起動中のこのアプリをすべて終了してから再試行してください。\n\nシステムエラー: {error}", "이전 WebView2 런타임 폴더를 삭제하지 못했습니다. 실행 중인 프로그램이 사용 중일 수 있습니다.\n\n실행 중인 이 앱을 모두 닫은 후 다시 시도하세요.\n\n시스템 오류: {error}"]),
+    ("error.webview2.remove_runtime", ["删除旧的 WebView2 运行时目录失败: {error}", "刪除舊的 WebView2 執行階段目錄失敗: {error}", "Failed to delete the old WebView2 runtime folder: {error}", "古い WebView2 ランタイムフォルダーを削除できませんでした: {error}", "이전 WebView2 런타임 폴더를 삭제하지 못했습니다: {error}"]),
+    ("error.webview2.create_runtime_denied", ["创建运行时目录失败，可能缺少权限或有程序占用该路径。\n\n请关闭所有已运行的本应用实例或以管理员身份重新运行。\n\n系统错误: {error}", "建立執行階段目錄失敗，可能缺少權限或有程式佔用該路徑。\n\n請關閉所有已執行的本應用程式執行個體，或以系統管理員身分重新執行。\n\n系統錯誤: {error}", "Failed to create the runtime folder; permission may be missing or a program may be using the path.\n\nClose all running copies of this app or run it again as administrator.\n\nSystem error: {error}", "ランタイムフォルダーを作成できませんでした。権限が不足しているか、プログラムがそのパスを使用している可能性があります。\n\n起動中のこのアプリをすべて終了するか、管理者として再実行してください。\n\nシステムエラー: {error}", "런타임 폴더를 만들지 못했습니다. 권한이 없거나 프로그램이 해당 경로를 사용 중일 수 있습니다.\n\n실행 중인 이 앱을 모두 닫거나 관리자 권한으로 다시 실행하세요.\n\n시스템 오류: {error}"]),
+    ("error.webview2.create_runtime", ["创建运行时目录失败: {error}", "建立執行階段目錄失敗: {error}", "Failed to create the runtime folder: {error}", "ランタイムフォルダーを作成できませんでした: {error}", "런타임 폴더를 만들지 못했습니다: {error}"]),
+    ("error.webview2.arch_mismatch", ["检测到本地 WebView2 运行时 cab 文件，但架构不匹配：\r\n文件架构: {cab_arch}\r\n系统架构: {expected_arch}\r\n\r\n将忽略该文件并尝试在线下载正确版本。", "偵測到本機 WebView2 執行階段 cab 檔案，但架構不符：\r\n檔案架構: {cab_arch}\r\n系統架構: {expected_arch}\r\n\r\n將忽略該檔案並嘗試線上下載正確版本。", "A local WebView2 runtime cab file was found, but its architecture does not match:\r\nFile architecture: {cab_arch}\r\nSystem architecture: {expected_arch}\r\n\r\nThe file will be ignored and the correct version will be downloaded.", "ローカルに WebView2 ランタイムの cab ファイルがありますが、アーキテクチャが一致しません：\r\nファイルのアーキテクチャ: {cab_arch}\r\nシステムのアーキテクチャ: {expected_arch}\r\n\r\nこのファイルは無視し、正しいバージョンをオンラインでダウンロードします。", "로컬 WebView2 런타임 cab 파일을 찾았지만 아키텍처가 일치하지 않습니다:\r\n파일 아키텍처: {cab_arch}\r\n시스템 아키텍처: {expected_arch}\r\n\r\n이 파일은 무시하고 올바른 버전을 온라인으로 내려받습니다."]),
+    ("error.webview2.cancelled", ["用户已取消下载", "使用者已取消下載", "Download cancelled by the user", "ユーザーがダウンロードをキャンセルしました", "사용자가 다운로드를 취소했습니다"]),
+    ("error.webview2.installer_run", ["运行 WebView2 安装程序失败: {error}", "執行 WebView2 安裝程式失敗: {error}", "Failed to run the WebView2 installer: {error}", "WebView2 インストーラーの実行に失敗しました: {error}", "WebView2 설치 프로그램 실행 실패: {error}"]),
+    ("error.webview2.installer_exit", ["WebView2 安装程序退出码: {code}", "WebView2 安裝程式結束代碼: {code}", "WebView2 installer exit code: {code}", "WebView2 インストーラーの終了コード: {code}", "WebView2 설치 프로그램 종료 코드: {code}"]),
+    ("error.webview2.installer_no_runtime", ["WebView2 安装程序已完成，但未检测到可用的运行时", "WebView2 安裝程式已完成，但未偵測到可用的執行階段", "The WebView2 installer finished, but no usable runtime was detected", "WebView2 インストーラーは完了しましたが、使用可能なランタイムが検出されませんでした", "WebView2 설치 프로그램이 끝났지만 사용할 수 있는 런타임을 찾지 못했습니다"]),
+    ("error.webview2.bootstrapper_run", ["运行 WebView2 Bootstrapper 失败: {error}", "執行 WebView2 Bootstrapper 失敗: {error}", "Failed to run the WebView2 Bootstrapper: {error}", "WebView2 Bootstrapper の実行に失敗しました: {error}", "WebView2 Bootstrapper 실행 실패: {error}"]),
+    ("error.webview2.bootstrapper_exit", ["WebView2 Bootstrapper 退出码: {code}", "WebView2 Bootstrapper 結束代碼: {code}", "WebView2 Bootstrapper exit code: {code}", "WebView2 Bootstrapper の終了コード: {code}", "WebView2 Bootstrapper 종료 코드: {code}"]),
+    ("error.webview2.bootstrapper_no_runtime", ["Bootstrapper 已完成，但未检测到可用的 WebView2 运行时", "Bootstrapper 已完成，但未偵測到可用的 WebView2 執行階段", "The Bootstrapper finished, but no usable WebView2 runtime was detected", "Bootstrapper は完了しましたが、使用可能な WebView2 ランタイムが検出されませんでした", "Bootstrapper가 끝났지만 사용할 수 있는 WebView2 런타임을 찾지 못했습니다"]),
+    ("error.webview2.disabled", ["检测到系统 WebView2 已被禁用：\r\n{reason}\r\n\r\n【什么是 WebView2？】\r\nWebView2 是微软提供的网页渲染组件，本程序依赖它来\r\n显示界面。如果 WebView2 被禁用，程序将无法正常运行。\r\n\r\n【如何解决？】\r\n方法一：如果使用了 Edge Blocker 等工具\r\n- 打开 Edge Blocker，点击\"Unblock\"解除禁用\r\n- 或删除注册表中的 IFEO 拦截项\r\n\r\n方法二：修改组策略（需要管理员权限）\r\n1. 按 Win + R，输入 gpedit.msc\r\n2. 导航到：计算机配置 > 管理模板 > Microsoft Edge WebView2\r\n3. 将相关策略设置为\"未配置\"或\"已启用\"\r\n\r\n方法三：加入我们的 QQ 群，获取帮助和支持\r\n- 群号可在我们的官网或文档底部找到\r\n\r\n点击确定后将尝试下载独立 WebView2 运行时以继续运行。\r\n若想恢复使用系统 WebView2，请删除 exe 目录下的 cache/webview2_runtime 文件夹", "偵測到系統 WebView2 已被停用：\r\n{reason}\r\n\r\n【什麼是 WebView2？】\r\nWebView2 是微軟提供的網頁轉譯元件，本程式依賴它來\r\n顯示介面。如果 WebView2 被停用，程式將無法正常執行。\r\n\r\n【如何解決？】\r\n方法一：如果使用了 Edge Blocker 等工具\r\n- 開啟 Edge Blocker，點擊\"Unblock\"解除停用\r\n- 或刪除登錄檔中的 IFEO 攔截項目\r\n\r\n方法二：修改群組原則（需要系統管理員權限）\r\n1. 按 Win + R，輸入 gpedit.msc\r\n2. 瀏覽至：電腦設定 > 系統管理範本 > Microsoft Edge WebView2\r\n3. 將相關原則設定為\"尚未設定\"或\"已啟用\"\r\n\r\n方法三：加入我們的 QQ 群，取得協助與支援\r\n- 群號可在我們的官網或文件底部找到\r\n\r\n點擊確定後將嘗試下載獨立 WebView2 執行階段以繼續執行。\r\n若想恢復使用系統 WebView2，請刪除 exe 目錄下的 cache/webview2_runtime 資料夾", "The system WebView2 has been disabled:\r\n{reason}\r\n\r\n[What is WebView2?]\r\nWebView2 is Microsoft's web rendering component. This app uses it to\r\ndisplay its interface and cannot run properly while it is disabled.\r\n\r\n[How to fix it]\r\nOption 1: if you use a tool such as Edge Blocker\r\n- Open Edge Blocker and click \"Unblock\"\r\n- Or delete the IFEO entry from the registry\r\n\r\nOption 2: change Group Policy (requires administrator rights)\r\n1. Press Win + R and enter gpedit.msc\r\n2. Go to: Computer Configuration > Administrative Templates > Microsoft Edge WebView2\r\n3. Set the related policies to \"Not Configured\" or \"Enabled\"\r\n\r\nOption 3: join our QQ group for help and support\r\n- The group number is on our website or at the bottom of the docs\r\n\r\nAfter you click OK, the app will try to download the standalone WebView2 runtime.\r\nTo go back to the system WebView2, delete the cache/webview2_runtime folder next to the exe", "システムの WebView2 が無効化されています：\r\n{reason}\r\n\r\n【WebView2 とは？】\r\nWebView2 は Microsoft の Web 描画コンポーネントで、このアプリは\r\n画面表示に使用しています。無効化されているとアプリは正常に動作しません。\r\n\r\n【解決方法】\r\n方法 1：Edge Blocker などのツールを使用している場合\r\n- Edge Blocker を開き、\"Unblock\" をクリックして解除する\r\n- またはレジストリの IFEO エントリを削除する\r\n\r\n方法 2：グループポリシーを変更する（管理者権限が必要）\r\n1. Win + R を押して gpedit.msc と入力\r\n2. コンピューターの構成 > 管理用テンプレート > Microsoft Edge WebView2 を開く\r\n3. 関連するポリシーを \"未構成\" または \"有効\" に設定する\r\n\r\n方法 3：QQ グループに参加してサポートを受ける\r\n- グループ番号は公式サイトまたはドキュメントの末尾に記載しています\r\n\r\nOK をクリックすると、独立版 WebView2 ランタイムのダウンロードを試みます。\r\nシステムの WebView2 に戻すには、exe と同じフォルダーの cache/webview2_runtime フォルダーを削除してください", "시스템 WebView2가 비활성화되어 있습니다:\r\n{reason}\r\n\r\n[WebView2란?]\r\nWebView2는 Microsoft의 웹 렌더링 구성 요소로, 이 앱은 화면을\r\n표시하는 데 사용합니다. 비활성화되어 있으면 앱이 정상적으로 실행되지 않습니다.\r\n\r\n[해결 방법]\r\n방법 1: Edge Blocker 같은 도구를 사용하는 경우\r\n- Edge Blocker를 열고 \"Unblock\"을 클릭해 해제\r\n- 또는 레지스트리의 IFEO 항목 삭제\r\n\r\n방법 2: 그룹 정책 변경(관리자 권한 필요)\r\n1. Win + R을 누르고 gpedit.msc 입력\r\n2. 컴퓨터 구성 > 관리 템플릿 > Microsoft Edge WebView2 로 이동\r\n3. 관련 정책을 \"구성되지 않음\" 또는 \"사용\"으로 설정\r\n\r\n방법 3: QQ 그룹에 참여해 도움 받기\r\n- 그룹 번호는 공식 사이트나 문서 하단에서 확인할 수 있습니다\r\n\r\n확인을 누르면 독립 WebView2 런타임 다운로드를 시도합니다.\r\n시스템 WebView2로 돌아가려면 exe 폴더의 cache/webview2_runtime 폴더를 삭제하세요"]),
+    ("error.webview2.confirm_installer", ["独立 WebView2 运行时下载失败：\r\n{error}\r\n\r\n是否下载并安装 WebView2 完整安装包（约 200 MB）？\r\n安装完成后将使用系统 WebView2 运行本程序。", "獨立 WebView2 執行階段下載失敗：\r\n{error}\r\n\r\n是否下載並安裝 WebView2 完整安裝套件（約 200 MB）？\r\n安裝完成後將使用系統 WebView2 執行本程式。", "Downloading the standalone WebView2 runtime failed:\r\n{error}\r\n\r\nDownload and install the full WebView2 installer (about 200 MB)?\r\nAfter installation, the app will run on the system WebView2.", "独立版 WebView2 ランタイムのダウンロードに失敗しました：\r\n{error}\r\n\r\nWebView2 のフルインストーラー（約 200 MB）をダウンロードしてインストールしますか？\r\nインストール後はシステムの WebView2 でこのアプリを実行します。", "독립 WebView2 런타임 다운로드에 실패했습니다:\r\n{error}\r\n\r\nWebView2 전체 설치 패키지(약 200 MB)를 내려받아 설치할까요?\r\n설치가 끝나면 시스템 WebView2로 이 앱을 실행합니다."]),
+    ("error.webview2.fallback_also_failed", ["{error}\r\n（完整安装包兜底也失败：{fallback_error}）", "{error}\r\n（完整安裝套件備援也失敗：{fallback_error}）", "{error}\r\n(The full installer fallback also failed: {fallback_error})", "{error}\r\n（フルインストーラーによる代替手段も失敗しました：{fallback_error}）", "{error}\r\n(전체 설치 패키지 대체 방법도 실패했습니다: {fallback_error})"]),
+    ("error.download.invalid_url", ["无效的下载地址: {error}", "無效的下載網址: {error}", "Invalid download URL: {error}", "ダウンロード URL が無効です: {error}", "잘못된 다운로드 URL입니다: {error}"]),
+    ("error.download.redirect_no_location", ["服务器返回重定向但未提供目标地址", "伺服器回傳重新導向但未提供目標網址", "The server returned a redirect without a target URL", "サーバーがリダイレクト先を示さずにリダイレクトを返しました", "서버가 대상 주소 없이 리디렉션을 반환했습니다"]),
+    ("error.download.too_many_redirects", ["重定向次数过多", "重新導向次數過多", "Too many redirects", "リダイレクトが多すぎます", "리디렉션이 너무 많습니다"]),
+    ("error.config.not_object", ["配置文件 {file} 不是 JSON 对象", "設定檔 {file} 不是 JSON 物件", "Config file {file} is not a JSON object", "設定ファイル {file} が JSON オブジェクトではありません", "설정 파일 {file}이(가) JSON 객체가 아닙니다"]),
+    ("error.config.version_too_new", ["配置文件 {file} 的版本 {version} 高于当前支持的 {current}，请先升级程序", "設定檔 {file} 的版本 {version} 高於目前支援的 {current}，請先更新程式", "Config file {file} has version {version}, newer than the supported {current}. Update MXU first", "設定ファイル {file} のバージョン {version} は対応している {current} より新しいため、先にアプリを更新してください", "설정 파일 {file}의 버전 {version}이(가) 지원하는 {current}보다 높습니다. 먼저 프로그램을 업데이트하세요"]),
+    ("error.config.nothing_to_export", ["没有可导出的配置文件", "沒有可匯出的設定檔", "There are no config files to export", "エクスポートできる設定ファイルがありません", "내보낼 설정 파일이 없습니다"]),
+    ("error.config.open_archive", ["无法打开配置包 [{path}]: {error}", "無法開啟設定包 [{path}]: {error}", "Cannot open the config package [{path}]: {error}", "設定パッケージを開けません [{path}]: {error}", "설정 패키지를 열 수 없습니다 [{path}]: {error}"]),
+    ("error.config.parse_archive", ["无法解析配置包: {error}", "無法解析設定包: {error}", "Cannot read the config package: {error}", "設定パッケージを解析できません: {error}", "설정 패키지를 해석할 수 없습니다: {error}"]),
+    ("error.config.no_manifest", ["配置包缺少 manifest.json，不是有效的 MXU 配置包", "設定包缺少 manifest.json，不是有效的 MXU 設定包", "The package has no manifest.json and is not a valid MXU config package", "manifest.json がないため、有効な MXU 設定パッケージではありません", "manifest.json이 없어 올바른 MXU 설정 패키지가 아닙니다"]),
+    ("error.config.parse_file", ["解析 {file} 失败: {error}", "解析 {file} 失敗: {error}", "Failed to parse {file}: {error}", "{file} の解析に失敗しました: {error}", "{file} 해석 실패: {error}"]),
+    ("error.config.format_too_new", ["配置包格式版本 {version} 高于当前支持的 {current}，请先升级程序", "設定包格式版本 {version} 高於目前支援的 {current}，請先更新程式", "The config package format version {version} is newer than the supported {current}. Update MXU first", "設定パッケージの形式バージョン {version} は対応している {current} より新しいため、先にアプリを更新してください", "설정 패키지 형식 버전 {version}이(가) 지원하는 {current}보다 높습니다. 먼저 프로그램을 업데이트하세요"]),
+    ("error.config.archive_empty", ["配置包中没有配置文件", "設定包中沒有設定檔", "The package contains no config files", "パッケージに設定ファイルが含まれていません", "패키지에 설정 파일이 없습니다"]),
+    ("error.config.backup_failed", ["备份原配置失败 [{path}]: {error}", "備份原設定失敗 [{path}]: {error}", "Failed to back up the current config [{path}]: {error}", "現在の設定のバックアップに失敗しました [{path}]: {error}", "기존 설정 백업 실패 [{path}]: {error}"]),
+    ("error.config.data_path_unset", ["数据路径未初始化", "資料路徑尚未初始化", "The data path has not been initialized", "データパスが初期化されていません", "데이터 경로가 초기화되지 않았습니다"]),
+    ("error.config.empty_instances", ["拒绝用空实例列表覆盖已有配置", "拒絕以空的實例清單覆寫既有設定", "Refusing to overwrite the existing config with an empty instance list", "空のインスタンス一覧で既存の設定を上書きすることはできません", "빈 인스턴스 목록으로 기존 설정을 덮어쓸 수 없습니다"]),
+    ("error.config.invalid_instance", ["实例配置格式无效", "實例設定格式無效", "Invalid instance config format", "インスタンス設定の形式が無効です", "인스턴스 설정 형식이 올바르지 않습니다"]),
+    ("error.expr.not_numeric", ["运算符 {op} 需要数值，实际值 {actual}", "運算子 {op} 需要數值，實際值 {actual}", "Operator {op} needs a number, got {actual}", "演算子 {op} には数値が必要です。実際の値: {actual}", "연산자 {op}에는 숫자가 필요합니다. 실제 값: {actual}"]),
+    ("error.expr.nan", ["无法比较 NaN", "無法比較 NaN", "Cannot compare NaN", "NaN は比較できません", "NaN은 비교할 수 없습니다"]),
+    ("error.expr.unknown_operator", ["未知运算符: {op}", "未知運算子: {op}", "Unknown operator: {op}", "不明な演算子: {op}", "알 수 없는 연산자: {op}"]),
+    ("error.expr.unterminated_string", ["字符串缺少结束引号", "字串缺少結束引號", "Unterminated string", "文字列の終わりの引用符がありません", "문자열 끝 따옴표가 없습니다"]),
+    ("error.expr.incomplete_escape", ["字符串转义不完整", "字串跳脫不完整", "Incomplete escape sequence in string", "文字列のエスケープが不完全です", "문자열 이스케이프가 불완전합니다"]),
+    ("error.expr.invalid_number", ["无效的数字: {text}", "無效的數字: {text}", "Invalid number: {text}", "無効な数値: {text}", "잘못된 숫자: {text}"]),
+    ("error.expr.unknown_char", ["无法识别的字符: {char}", "無法辨識的字元: {char}", "Unrecognized character: {char}", "認識できない文字: {char}", "알 수 없는 문자: {char}"]),
+    ("error.expr.use_double_equals", ["比较请使用 ==", "比較請使用 ==", "Use == for comparison", "比較には == を使用してください", "비교에는 ==를 사용하세요"]),
+    ("error.expr.too_deep", ["表达式嵌套超过 {max} 层", "運算式巢狀超過 {max} 層", "Expression is nested more than {max} levels deep", "式のネストが {max} 階層を超えています", "식 중첩이 {max}단계를 넘었습니다"]),
+    ("error.expr.too_many_operators", ["表达式运算符超过 {max} 个", "運算式運算子超過 {max} 個", "Expression has more than {max} operators", "式の演算子が {max} 個を超えています", "식의 연산자가 {max}개를 넘었습니다"]),
+    ("error.expr.missing_rparen", ["缺少右括号", "缺少右括號", "Missing closing parenthesis", "閉じ括弧がありません", "닫는 괄호가 없습니다"]),
+    ("error.expr.unexpected_token", ["意外的符号: {token}", "非預期的符號: {token}", "Unexpected token: {token}", "予期しないトークン: {token}", "예상치 못한 토큰: {token}"]),
+    ("error.expr.incomplete", ["表达式不完整", "運算式不完整", "Incomplete expression", "式が不完全です", "식이 불완전합니다"]),
+    ("error.expr.expected_name", ["'.' 后应为名称", "'.' 後應為名稱", "Expected a name after '.'", "'.' の後には名前が必要です", "'.' 뒤에는 이름이 와야 합니다"]),
+    ("error.expr.invalid_index", ["'[' 内应为非负整数或字符串", "'[' 內應為非負整數或字串", "Expected a non-negative integer or string inside '['", "'[' の中には 0 以上の整数または文字列が必要です", "'[' 안에는 0 이상의 정수나 문자열이 와야 합니다"]),
+    ("error.expr.missing_rbracket", ["缺少 ']'", "缺少 ']'", "Missing ']'", "']' がありません", "']'가 없습니다"]),
+    ("error.expr.divide_by_zero", ["除数为 0", "除數為 0", "Division by zero", "0 で除算しました", "0으로 나눌 수 없습니다"]),
+    ("error.expr.empty", ["表达式为空", "運算式為空", "Empty expression", "式が空です", "식이 비어 있습니다"]),
+    ("error.expr.trailing_input", ["表达式末尾有多余内容: {token}", "運算式結尾有多餘內容: {token}", "Unexpected content at the end of the expression: {token}", "式の末尾に余分な内容があります: {token}", "식 끝에 불필요한 내용이 있습니다: {token}"]),
+    ("error.action.any_failed", ["any 中的条件均不满足: [{errors}]", "any 中的條件均不滿足: [{errors}]", "None of the conditions in any are met: [{errors}]", "any の条件がどれも満たされていません: [{errors}]", "any의 조건을 하나도 만족하지 않습니다: [{errors}]"]),
+    ("error.action.not_failed", ["not 条件不满足: {condition}", "not 條件不滿足: {condition}", "The not condition is not met: {condition}", "not 条件が満たされていません: {condition}", "not 조건을 만족하지 않습니다: {condition}"]),
+    ("error.action.label_var", ["变量 {name}", "變數 {name}", "Variable {name}", "変数 {name}", "변수 {name}"]),
+    ("error.action.label_reco", ["识别结果 {pointer}", "辨識結果 {pointer}", "Recognition result {pointer}", "認識結果 {pointer}", "인식 결과 {pointer}"]),
+    ("error.action.invalid_condition", ["无效的断言条件: {condition}", "無效的斷言條件: {condition}", "Invalid assertion condition: {condition}", "無効なアサーション条件: {condition}", "잘못된 단언 조건: {condition}"]),
+    ("error.action.assert_failed", ["{label} 不满足 {op} {expected}（实际值: {actual}）", "{label} 不滿足 {op} {expected}（實際值: {actual}）", "{label} does not satisfy {op} {expected} (actual: {actual})", "{label} が {op} {expected} を満たしていません（実際の値: {actual}）", "{label}이(가) {op} {expected}을(를) 만족하지 않습니다 (실제 값: {actual})"]),
+    ("error.action.no_value", ["无", "無", "none", "なし", "없음"]),
+    ("error.action.unknown_mode", ["未知模式: {mode}", "未知模式: {mode}", "Unknown mode: {mode}", "不明なモード: {mode}", "알 수 없는 모드: {mode}"]),
+    ("error.action.clipboard_open", ["无法访问剪贴板: {error}", "無法存取剪貼簿: {error}", "Cannot access the clipboard: {error}", "クリップボードにアクセスできません: {error}", "클립보드에 접근할 수 없습니다: {error}"]),
+    ("error.action.clipboard_read", ["读取剪贴板失败: {error}", "讀取剪貼簿失敗: {error}", "Failed to read the clipboard: {error}", "クリップボードの読み取りに失敗しました: {error}", "클립보드 읽기 실패: {error}"]),
+    ("error.action.clipboard_write", ["写入剪贴板失败: {error}", "寫入剪貼簿失敗: {error}", "Failed to write the clipboard: {error}", "クリップボードへの書き込みに失敗しました: {error}", "클립보드 쓰기 실패: {error}"]),
+    ("error.action.clipboard_empty", ["剪贴板为空", "剪貼簿為空", "The clipboard is empty", "クリップボードが空です", "클립보드가 비어 있습니다"]),
+    ("error.action.nothing_to_copy", ["没有可复制的文本（未提供 text 且识别结果中无 OCR 文本）", "沒有可複製的文字（未提供 text 且辨識結果中無 OCR 文字）", "Nothing to copy (no text was given and the recognition result has no OCR text)", "コピーするテキストがありません（text が未指定で、認識結果に OCR テキストもありません）", "복사할 텍스트가 없습니다 (text가 없고 인식 결과에 OCR 텍스트도 없음)"]),
+    ("error.action.audio_device", ["无法打开音频输出设备: {error}", "無法開啟音訊輸出裝置: {error}", "Cannot open the audio output device: {error}", "オーディオ出力デバイスを開けません: {error}", "오디오 출력 장치를 열 수 없습니다: {error}"]),
+    ("error.action.audio_player", ["创建播放器失败: {error}", "建立播放器失敗: {error}", "Failed to create the audio player: {error}", "プレーヤーの作成に失敗しました: {error}", "플레이어 생성 실패: {error}"]),
+    ("error.action.audio_decode", ["解码音频文件失败 [{path}]: {error}", "解碼音訊檔案失敗 [{path}]: {error}", "Failed to decode audio file [{path}]: {error}", "音声ファイルのデコードに失敗しました [{path}]: {error}", "오디오 파일 디코딩 실패 [{path}]: {error}"]),
+    ("error.action.audio_stopped", ["播放被中止", "播放已中止", "Playback was stopped", "再生が中止されました", "재생이 중지되었습니다"]),
+    ("error.action.state_unavailable", ["MaaState 不可用", "MaaState 無法使用", "MaaState is unavailable", "MaaState が利用できません", "MaaState를 사용할 수 없습니다"]),
+    ("error.node.unsupported_os", ["不支持自动下载 Node.js 的系统: {os}", "不支援自動下載 Node.js 的系統: {os}", "Automatic Node.js download is not supported on this OS: {os}", "この OS では Node.js の自動ダウンロードに対応していません: {os}", "이 운영체제에서는 Node.js 자동 다운로드를 지원하지 않습니다: {os}"]),
+    ("error.node.unsupported_arch", ["不支持自动下载 Node.js 的架构: {arch}", "不支援自動下載 Node.js 的架構: {arch}", "Automatic Node.js download is not supported on this architecture: {arch}", "このアーキテクチャでは Node.js の自動ダウンロードに対応していません: {arch}", "이 아키텍처에서는 Node.js 자동 다운로드를 지원하지 않습니다: {arch}"]),
+    ("error.node.checksum_download", ["下载 Node.js 校验文件失败: {error}", "下載 Node.js 校驗檔案失敗: {error}", "Failed to download the Node.js checksum file: {error}", "Node.js のチェックサムファイルのダウンロードに失敗しました: {error}", "Node.js 체크섬 파일 다운로드 실패: {error}"]),
+    ("error.node.checksum_missing", ["Node.js 校验文件中没有 {file}", "Node.js 校驗檔案中沒有 {file}", "{file} is not listed in the Node.js checksum file", "Node.js のチェックサムファイルに {file} がありません", "Node.js 체크섬 파일에 {file}이(가) 없습니다"]),
+    ("error.node.download", ["下载 Node.js 失败: {error}", "下載 Node.js 失敗: {error}", "Failed to download Node.js: {error}", "Node.js のダウンロードに失敗しました: {error}", "Node.js 다운로드 실패: {error}"]),
+    ("error.node.hash_mismatch", ["Node.js 校验失败: 期望 SHA-256 {expected}，实际 {actual}", "Node.js 校驗失敗: 預期 SHA-256 {expected}，實際 {actual}", "Node.js checksum mismatch: expected SHA-256 {expected}, got {actual}", "Node.js の検証に失敗しました: 期待値 SHA-256 {expected}、実際 {actual}", "Node.js 검증 실패: 예상 SHA-256 {expected}, 실제 {actual}"]),
+    ("error.node.missing_after_extract", ["解压后未找到 node: {path}", "解壓縮後未找到 node: {path}", "node was not found after extraction: {path}", "展開後に node が見つかりません: {path}", "압축 해제 후 node를 찾을 수 없습니다: {path}"]),
+    ("error.authoring.decode_screenshot", ["解码截图失败: {error}", "解碼截圖失敗: {error}", "Failed to decode the screenshot: {error}", "スクリーンショットのデコードに失敗しました: {error}", "스크린샷 디코딩 실패: {error}"]),
+    ("error.authoring.read_image", ["读取图片失败 [{path}]: {error}", "讀取圖片失敗 [{path}]: {error}", "Failed to read image [{path}]: {error}", "画像の読み込みに失敗しました [{path}]: {error}", "이미지 읽기 실패 [{path}]: {error}"]),
+    ("error.authoring.invalid_roi", ["无效的 ROI: {roi}", "無效的 ROI: {roi}", "Invalid ROI: {roi}", "無効な ROI: {roi}", "잘못된 ROI: {roi}"]),
+    ("error.authoring.roi_out_of_bounds", ["ROI {roi} 超出截图范围 {width}x{height}", "ROI {roi} 超出截圖範圍 {width}x{height}", "ROI {roi} is outside the {width}x{height} screenshot", "ROI {roi} がスクリーンショットの範囲 {width}x{height} を超えています", "ROI {roi}이(가) 스크린샷 범위 {width}x{height}를 벗어났습니다"]),
+    ("error.authoring.point_out_of_bounds", ["坐标 ({x}, {y}) 超出截图范围 {width}x{height}", "座標 ({x}, {y}) 超出截圖範圍 {width}x{height}", "Point ({x}, {y}) is outside the {width}x{height} screenshot", "座標 ({x}, {y}) がスクリーンショットの範囲 {width}x{height} を超えています", "좌표 ({x}, {y})이(가) 스크린샷 범위 {width}x{height}를 벗어났습니다"]),
+    ("error.authoring.template_name_empty", ["模板名称不能为空", "範本名稱不能為空", "The template name cannot be empty", "テンプレート名を入力してください", "템플릿 이름을 입력하세요"]),
+    ("error.authoring.invalid_template_name", ["无效的模板名称: {name}", "無效的範本名稱: {name}", "Invalid template name: {name}", "無効なテンプレート名: {name}", "잘못된 템플릿 이름: {name}"]),
+    ("error.authoring.template_exists", ["模板已存在: {name}", "範本已存在: {name}", "Template already exists: {name}", "テンプレートは既に存在します: {name}", "템플릿이 이미 있습니다: {name}"]),
+    ("error.authoring.save_image", ["保存图片失败 [{path}]: {error}", "儲存圖片失敗 [{path}]: {error}", "Failed to save image [{path}]: {error}", "画像の保存に失敗しました [{path}]: {error}", "이미지 저장 실패 [{path}]: {error}"]),
+    ("error.authoring.tasker_running", ["任务运行中，无法进行识别测试", "任務執行中，無法進行辨識測試", "Tasks are running; recognition tests are unavailable", "タスク実行中のため認識テストを実行できません", "작업 실행 중에는 인식 테스트를 할 수 없습니다"]),
+    ("error.authoring.recognition_timeout", ["识别测试超时", "辨識測試逾時", "The recognition test timed out", "認識テストがタイムアウトしました", "인식 테스트 시간이 초과되었습니다"]),
+    ("error.authoring.reco_not_run", ["识别未执行", "辨識未執行", "The recognition did not run", "認識が実行されませんでした", "인식이 실행되지 않았습니다"]),
+    ("error.authoring.reco_detail_missing", ["未找到识别详情", "未找到辨識詳情", "Recognition details not found", "認識の詳細が見つかりません", "인식 세부 정보를 찾을 수 없습니다"]),
+    ("error.system.elevate_failed", ["以管理员身份启动失败: 错误码 {code}", "以系統管理員身分啟動失敗: 錯誤碼 {code}", "Failed to start as administrator: error code {code}", "管理者として起動できませんでした: エラーコード {code}", "관리자 권한으로 시작하지 못했습니다: 오류 코드 {code}"]),
+    ("error.system.windows_only", ["此功能仅在 Windows 上可用", "此功能僅在 Windows 上可用", "This feature is only available on Windows", "この機能は Windows でのみ利用できます", "이 기능은 Windows에서만 사용할 수 있습니다"]),
+    ("error.system.save_draw", ["设置保存调试图像失败: {error}", "設定儲存偵錯影像失敗: {error}", "Failed to set debug image saving: {error}", "デバッグ画像の保存設定に失敗しました: {error}", "디버그 이미지 저장 설정 실패: {error}"]),
+    ("error.system.invalid_window", ["无效的窗口句柄", "無效的視窗控制代碼", "Invalid window handle", "無効なウィンドウハンドル", "잘못된 창 핸들"]),
+    ("error.system.foreground_failed", ["无法将窗口置于前台", "無法將視窗置於前景", "Cannot bring the window to the foreground", "ウィンドウを前面に表示できません", "창을 앞으로 가져올 수 없습니다"]),
+    ("error.system.wake_display_failed", ["唤醒显示器失败", "喚醒顯示器失敗", "Failed to wake the display", "ディスプレイの復帰に失敗しました", "디스플레이를 깨우지 못했습니다"]),
+    ("error.system.intervention_not_found", ["介入请求不存在或已超时", "介入請求不存在或已逾時", "The intervention request does not exist or has timed out", "介入リクエストが存在しないか、タイムアウトしました", "개입 요청이 없거나 시간이 초과되었습니다"]),
+    ("error.system.schtasks_run", ["执行 schtasks 失败: {error}", "執行 schtasks 失敗: {error}", "Failed to run schtasks: {error}", "schtasks の実行に失敗しました: {error}", "schtasks 실행 실패: {error}"]),
+    ("error.system.schtasks_create", ["创建计划任务失败: {error}", "建立排程工作失敗: {error}", "Failed to create the scheduled task: {error}", "タスクスケジューラへの登録に失敗しました: {error}", "예약 작업 만들기 실패: {error}"]),
+    ("error.system.path_not_exist", ["路径及其上级目录均不存在: {path}", "路徑及其上層目錄皆不存在: {path}", "Neither the path nor any of its parent folders exist: {path}", "パスとその親フォルダーがいずれも存在しません: {path}", "경로와 상위 폴더가 모두 없습니다: {path}"]),
+    ("error.system.disk_space_query", ["查询磁盘空间失败 [{path}]: {error}", "查詢磁碟空間失敗 [{path}]: {error}", "Failed to query disk space [{path}]: {error}", "ディスク容量の取得に失敗しました [{path}]: {error}", "디스크 공간 조회 실패 [{path}]: {error}"]),
+    ("error.system.invalid_path_chars", ["路径包含非法字符: {error}", "路徑包含非法字元: {error}", "The path contains invalid characters: {error}", "パスに無効な文字が含まれています: {error}", "경로에 잘못된 문자가 있습니다: {error}"]),
+    ("error.system.webview2_windows_only", ["WebView2 仅在 Windows 上使用", "WebView2 僅在 Windows 上使用", "WebView2 is only used on Windows", "WebView2 は Windows でのみ使用されます", "WebView2는 Windows에서만 사용됩니다"]),
+    ("error.system.window_closed", ["窗口句柄无效或窗口已关闭", "視窗控制代碼無效或視窗已關閉", "Invalid window handle or the window has been closed", "ウィンドウハンドルが無効か、ウィンドウが閉じられています", "창 핸들이 올바르지 않거나 창이 닫혔습니다"]),
+    ("error.api.unauthorized", ["令牌无效或缺失", "權杖無效或缺失", "The token is missing or invalid", "トークンが無効または指定されていません", "토큰이 없거나 올바르지 않습니다"]),
+    ("error.api.instance_running", ["实例正在运行", "實例正在執行", "The instance is already running", "インスタンスは実行中です", "인스턴스가 이미 실행 중입니다"]),
+    ("error.api.window_not_loaded", ["主窗口未加载", "主視窗未載入", "The main window is not loaded", "メインウィンドウが読み込まれていません", "메인 창이 로드되지 않았습니다"]),
+    ("error.api.events_unavailable", ["事件广播未初始化", "事件廣播未初始化", "Event broadcasting is not initialized", "イベント配信が初期化されていません", "이벤트 브로드캐스트가 초기화되지 않았습니다"]),
+    ("error.report.invalid_run_id", ["无效的 run_id: {id}", "無效的 run_id: {id}", "Invalid run_id: {id}", "無効な run_id: {id}", "잘못된 run_id: {id}"]),
+    ("error.report.not_found", ["报告不存在: {id}", "報告不存在: {id}", "Report not found: {id}", "レポートが見つかりません: {id}", "보고서를 찾을 수 없습니다: {id}"]),
+    ("error.report.parse", ["解析报告失败: {error}", "解析報告失敗: {error}", "Failed to parse the report: {error}", "レポートの解析に失敗しました: {error}", "보고서 해석 실패: {error}"]),
+    ("error.report.unsupported_format", ["不支持的导出格式: {format}", "不支援的匯出格式: {format}", "Unsupported export format: {format}", "サポートされていないエクスポート形式: {format}", "지원하지 않는 내보내기 형식: {format}"]),
+    ("error.backup.invalid_package", ["无效的包名: {package}", "無效的套件名稱: {package}", "Invalid package name: {package}", "無効なパッケージ名: {package}", "잘못된 패키지 이름: {package}"]),
+    ("error.backup.not_adb", ["实例未使用 ADB 控制器", "實例未使用 ADB 控制器", "The instance does not use an ADB controller", "インスタンスは ADB コントローラーを使用していません", "인스턴스가 ADB 컨트롤러를 사용하지 않습니다"]),
+    ("error.backup.no_package", ["未指定要备份的应用包名", "未指定要備份的應用程式套件名稱", "No app package name was given for the backup", "バックアップするアプリのパッケージ名が指定されていません", "백업할 앱 패키지 이름이 지정되지 않았습니다"]),
+    ("error.backup.exists", ["备份目录已存在: {path}", "備份目錄已存在: {path}", "The backup folder already exists: {path}", "バックアップフォルダーは既に存在します: {path}", "백업 폴더가 이미 있습니다: {path}"]),
+    ("error.backup.failed", ["备份失败: {reasons}", "備份失敗: {reasons}", "Backup failed: {reasons}", "バックアップに失敗しました: {reasons}", "백업 실패: {reasons}"]),
+    ("error.backup.invalid_id", ["无效的备份 ID: {id}", "無效的備份 ID: {id}", "Invalid backup ID: {id}", "無効なバックアップ ID: {id}", "잘못된 백업 ID: {id}"]),
+    ("error.backup.tasker_running", ["任务运行中，无法恢复应用数据", "任務執行中，無法還原應用程式資料", "Tasks are running; app data cannot be restored now", "タスク実行中のためアプリデータを復元できません", "작업 실행 중에는 앱 데이터를 복원할 수 없습니다"]),
+    ("error.backup.invalid_manifest", ["备份说明格式无效: {error}", "備份說明格式無效: {error}", "The backup manifest is invalid: {error}", "バックアップ情報の形式が無効です: {error}", "백업 정보 형식이 올바르지 않습니다: {error}"]),
+    ("error.backup.restore_failed", ["恢复 {target} 失败: {error}", "還原 {target} 失敗: {error}", "Failed to restore {target}: {error}", "{target} の復元に失敗しました: {error}", "{target} 복원 실패: {error}"]),
+    ("error.python.spawn", ["启动 Python 失败: {error}", "啟動 Python 失敗: {error}", "Failed to start Python: {error}", "Python の起動に失敗しました: {error}", "Python 실행 실패: {error}"]),
+    ("error.python.wait", ["等待 Python 进程失败: {error}", "等待 Python 處理程序失敗: {error}", "Failed to wait for the Python process: {error}", "Python プロセスの待機に失敗しました: {error}", "Python 프로세스 대기 실패: {error}"]),
+    ("error.python.exit", ["Python 进程异常退出: {status}", "Python 處理程序異常結束: {status}", "The Python process exited abnormally: {status}", "Python プロセスが異常終了しました: {status}", "Python 프로세스가 비정상 종료되었습니다: {status}"]),
+    ("error.python.not_found", ["未找到 Python 解释器，请指定 python_path", "未找到 Python 直譯器，請指定 python_path", "No Python interpreter found; set python_path", "Python インタープリターが見つかりません。python_path を指定してください", "Python 인터프리터를 찾을 수 없습니다. python_path를 지정하세요"]),
+    ("error.python.venv_failed", ["创建虚拟环境失败: {error}", "建立虛擬環境失敗: {error}", "Failed to create the virtual environment: {error}", "仮想環境の作成に失敗しました: {error}", "가상 환경 만들기 실패: {error}"]),
+    ("error.python.venv_no_python", ["虚拟环境中未找到解释器: {path}", "虛擬環境中未找到直譯器: {path}", "No interpreter found in the virtual environment: {path}", "仮想環境にインタープリターが見つかりません: {path}", "가상 환경에서 인터프리터를 찾을 수 없습니다: {path}"]),
+    ("error.python.pip_failed", ["安装依赖失败: {error}", "安裝相依套件失敗: {error}", "Failed to install dependencies: {error}", "依存関係のインストールに失敗しました: {error}", "의존성 설치 실패: {error}"]),
+    ("error.deep_link.invalid", ["无效的链接: {error}", "無效的連結: {error}", "Invalid link: {error}", "無効なリンク: {error}", "잘못된 링크: {error}"]),
+    ("error.deep_link.scheme", ["不支持的协议: {scheme}", "不支援的協定: {scheme}", "Unsupported scheme: {scheme}", "サポートされていないスキーム: {scheme}", "지원하지 않는 스킴: {scheme}"]),
+    ("error.deep_link.missing_param", ["缺少 {name} 参数", "缺少 {name} 參數", "Missing {name} parameter", "{name} パラメーターがありません", "{name} 매개변수가 없습니다"]),
+    ("error.deep_link.invalid_url", ["无效的资源地址: {error}", "無效的資源位址: {error}", "Invalid resource URL: {error}", "無効なリソース URL: {error}", "잘못된 리소스 주소: {error}"]),
+    ("error.deep_link.http_only", ["资源地址仅支持 http / https", "資源位址僅支援 http / https", "Resource URLs must use http or https", "リソース URL は http / https のみ対応しています", "리소스 주소는 http / https만 지원합니다"]),
+    ("error.deep_link.unknown_action", ["未知的链接动作: {action}", "未知的連結動作: {action}", "Unknown link action: {action}", "不明なリンク動作: {action}", "알 수 없는 링크 동작: {action}"]),
+    ("error.deep_link.emit", ["发送深度链接事件失败: {error}", "傳送深層連結事件失敗: {error}", "Failed to send the deep link event: {error}", "ディープリンクイベントの送信に失敗しました: {error}", "딥 링크 이벤트 전송 실패: {error}"]),
+    ("error.mirrorchyan.read_response", ["读取响应失败: {error}", "讀取回應失敗: {error}", "Failed to read the response: {error}", "レスポンスの読み取りに失敗しました: {error}", "응답 읽기 실패: {error}"]),
+    ("error.mirrorchyan.parse_response", ["解析响应失败 (HTTP {status}): {error}", "解析回應失敗 (HTTP {status}): {error}", "Failed to parse the response (HTTP {status}): {error}", "レスポンスの解析に失敗しました (HTTP {status}): {error}", "응답 해석 실패 (HTTP {status}): {error}"]),
+    ("error.mirrorchyan.no_rid", ["未配置 mirrorchyan_rid", "未設定 mirrorchyan_rid", "mirrorchyan_rid is not configured", "mirrorchyan_rid が設定されていません", "mirrorchyan_rid가 설정되지 않았습니다"]),
+    ("error.mirrorchyan.all_unavailable", ["所有 MirrorChyan 站点均不可用", "所有 MirrorChyan 站點皆無法使用", "All MirrorChyan endpoints are unavailable", "すべての MirrorChyan サーバーに接続できません", "모든 MirrorChyan 서버를 사용할 수 없습니다"]),
+    ("error.mirrorchyan.no_download_url", ["MirrorChyan 未返回下载链接", "MirrorChyan 未回傳下載連結", "MirrorChyan did not return a download link", "MirrorChyan がダウンロードリンクを返しませんでした", "MirrorChyan이 다운로드 링크를 반환하지 않았습니다"]),
+    ("error.monitor.none", ["未找到显示器", "未找到顯示器", "No display found", "ディスプレイが見つかりません", "디스플레이를 찾을 수 없습니다"]),
+    ("error.monitor.not_found", ["显示器不存在: {name}", "顯示器不存在: {name}", "Display not found: {name}", "ディスプレイが見つかりません: {name}", "디스플레이를 찾을 수 없습니다: {name}"]),
+    ("error.monitor.invalid_window", ["窗口句柄无效或窗口已关闭", "視窗控制代碼無效或視窗已關閉", "The window handle is invalid or the window was closed", "ウィンドウハンドルが無効か、ウィンドウが閉じられています", "창 핸들이 잘못되었거나 창이 닫혔습니다"]),
+    ("error.monitor.window_monitor", ["获取窗口所在显示器失败", "取得視窗所在顯示器失敗", "Failed to get the window's display", "ウィンドウのディスプレイを取得できませんでした", "창이 있는 디스플레이를 가져오지 못했습니다"]),
+    ("error.monitor.window_rect", ["获取窗口位置失败", "取得視窗位置失敗", "Failed to get the window position", "ウィンドウの位置を取得できませんでした", "창 위치를 가져오지 못했습니다"]),
+    ("error.monitor.move_failed", ["移动窗口失败", "移動視窗失敗", "Failed to move the window", "ウィンドウの移動に失敗しました", "창 이동 실패"]),
+    ("error.reconnect.invalid_screencap", ["无效的截图方式: {value}", "無效的截圖方式: {value}", "Invalid screencap method: {value}", "無効なスクリーンショット方式: {value}", "잘못된 스크린샷 방식: {value}"]),
+    ("error.reconnect.invalid_input", ["无效的输入方式: {value}", "無效的輸入方式: {value}", "Invalid input method: {value}", "無効な入力方式: {value}", "잘못된 입력 방식: {value}"]),
+    ("error.reconnect.tasker_running", ["任务运行中，无法切换控制方式", "任務執行中，無法切換控制方式", "Tasks are running; the control method cannot be switched now", "タスク実行中のため操作方式を切り替えられません", "작업 실행 중에는 제어 방식을 바꿀 수 없습니다"]),
+    ("error.reconnect.adb_only", ["仅 ADB 控制器支持切换截图 / 输入方式", "僅 ADB 控制器支援切換截圖 / 輸入方式", "Only ADB controllers can switch screencap / input methods", "スクリーンショット / 入力方式の切り替えは ADB コントローラーのみ対応しています", "ADB 컨트롤러만 스크린샷 / 입력 방식을 바꿀 수 있습니다"]),
+    ("error.reconnect.exhausted", ["重连 {attempts} 次均失败", "重新連線 {attempts} 次皆失敗", "Reconnection failed after {attempts} attempt(s)", "{attempts} 回再接続しましたがすべて失敗しました", "{attempts}회 재연결했지만 모두 실패했습니다"]),
+    ("error.agent_server.invalid_identifier", ["Agent Server 标识符无效", "Agent Server 識別碼無效", "Invalid Agent Server identifier", "Agent Server の識別子が無効です", "Agent Server 식별자가 올바르지 않습니다"]),
+    ("error.agent_server.already_running", ["Agent Server 已在运行（{identifier}）", "Agent Server 已在執行（{identifier}）", "Agent Server is already running ({identifier})", "Agent Server はすでに実行中です（{identifier}）", "Agent Server가 이미 실행 중입니다 ({identifier})"]),
+    ("error.agent_server.lib_missing", ["未找到 MaaAgentServer 库: {path}", "找不到 MaaAgentServer 函式庫: {path}", "MaaAgentServer library not found: {path}", "MaaAgentServer ライブラリが見つかりません: {path}", "MaaAgentServer 라이브러리를 찾을 수 없습니다: {path}"]),
+    ("error.agent_server.log_file", ["创建 Agent Server 日志失败: {error}", "建立 Agent Server 日誌失敗: {error}", "Failed to create the Agent Server log: {error}", "Agent Server のログを作成できません: {error}", "Agent Server 로그 생성 실패: {error}"]),
+    ("error.agent_server.spawn", ["启动 Agent Server 失败: {error}", "啟動 Agent Server 失敗: {error}", "Failed to start Agent Server: {error}", "Agent Server の起動に失敗しました: {error}", "Agent Server 시작 실패: {error}"]),
+    ("error.clock.socket", ["创建 UDP 套接字失败: {error}", "建立 UDP 通訊端失敗: {error}", "Failed to create a UDP socket: {error}", "UDP ソケットを作成できません: {error}", "UDP 소켓 생성 실패: {error}"]),
+    ("error.clock.connect", ["无法连接 {server}: {error}", "無法連線 {server}: {error}", "Cannot connect to {server}: {error}", "{server} に接続できません: {error}", "{server}에 연결할 수 없습니다: {error}"]),
+    ("error.clock.send", ["发送 NTP 请求失败: {error}", "傳送 NTP 請求失敗: {error}", "Failed to send the NTP request: {error}", "NTP リクエストの送信に失敗しました: {error}", "NTP 요청 전송 실패: {error}"]),
+    ("error.clock.no_response", ["{server} 无响应: {error}", "{server} 無回應: {error}", "{server} did not respond: {error}", "{server} から応答がありません: {error}", "{server} 응답 없음: {error}"]),
+    ("error.clock.invalid_response", ["{server} 返回了无效的 NTP 响应", "{server} 傳回了無效的 NTP 回應", "{server} returned an invalid NTP response", "{server} から無効な NTP 応答が返されました", "{server}에서 잘못된 NTP 응답을 반환했습니다"]),
+    ("error.clock.unavailable", ["无法获取标准时间: {error}", "無法取得標準時間: {error}", "Cannot get the reference time: {error}", "標準時刻を取得できません: {error}", "표준 시간을 가져올 수 없습니다: {error}"]),
+    ("error.long_path.powershell", ["无法启动 PowerShell: {error}", "無法啟動 PowerShell: {error}", "Cannot start PowerShell: {error}", "PowerShell を起動できません: {error}", "PowerShell을 시작할 수 없습니다: {error}"]),
+    ("error.long_path.enable_failed", ["开启长路径支持失败（可能已取消管理员授权）: {error}", "開啟長路徑支援失敗（可能已取消系統管理員授權）: {error}", "Failed to enable long path support (administrator approval may have been cancelled): {error}", "長いパスのサポートを有効にできませんでした（管理者の承認が取り消された可能性があります）: {error}", "긴 경로 지원을 켜지 못했습니다(관리자 승인이 취소되었을 수 있음): {error}"]),
+    ("error.long_path.windows_only", ["仅 Windows 需要开启长路径支持", "僅 Windows 需要開啟長路徑支援", "Only Windows needs long path support enabled", "長いパスのサポートの有効化が必要なのは Windows のみです", "긴 경로 지원은 Windows에서만 켜야 합니다"]),
+    ("error.power.thread", ["创建防休眠线程失败: {error}", "建立防休眠執行緒失敗: {error}", "Failed to create the keep-awake thread: {error}", "スリープ防止スレッドを作成できません: {error}", "절전 방지 스레드 생성 실패: {error}"]),
+    ("error.power.execution_state", ["SetThreadExecutionState 调用失败", "SetThreadExecutionState 呼叫失敗", "SetThreadExecutionState failed", "SetThreadExecutionState の呼び出しに失敗しました", "SetThreadExecutionState 호출 실패"]),
+    ("error.power.spawn", ["启动 {program} 失败: {error}", "啟動 {program} 失敗: {error}", "Failed to start {program}: {error}", "{program} の起動に失敗しました: {error}", "{program} 시작 실패: {error}"]),
+    ("error.integrity.invalid_manifest", ["完整性清单格式无效: {error}", "完整性清單格式無效: {error}", "Invalid integrity manifest: {error}", "整合性マニフェストの形式が無効です: {error}", "무결성 매니페스트 형식이 올바르지 않습니다: {error}"]),
+    ("error.integrity.failed", ["程序文件完整性校验未通过（缺失 {missing} 个、被修改 {mismatched} 个，清单签名: {signature}），已拒绝启动任务，请重新安装或完成更新", "程式檔案完整性驗證未通過（缺少 {missing} 個、被修改 {mismatched} 個，清單簽章: {signature}），已拒絕啟動工作，請重新安裝或完成更新", "Program file integrity check failed ({missing} missing, {mismatched} modified, manifest signature: {signature}); tasks will not start. Reinstall or finish the update", "プログラムファイルの整合性チェックに失敗しました（欠落 {missing} 件、改変 {mismatched} 件、マニフェスト署名: {signature}）。タスクの開始を拒否しました。再インストールするか更新を完了してください", "프로그램 파일 무결성 검사에 실패했습니다(누락 {missing}개, 변경 {mismatched}개, 매니페스트 서명: {signature}). 작업 시작을 거부했습니다. 다시 설치하거나 업데이트를 완료하세요"]),
+    ("error.file_lock.windows_only", ["仅 Windows 支持重启后替换文件", "僅 Windows 支援重新啟動後取代檔案", "Replacing files on reboot is only supported on Windows", "再起動時のファイル置き換えは Windows のみ対応しています", "재부팅 시 파일 교체는 Windows에서만 지원됩니다"]),
+    ("error.file_lock.restart_manager", ["{api} 失败: {code}", "{api} 失敗: {code}", "{api} failed: {code}", "{api} に失敗しました: {code}", "{api} 실패: {code}"]),
+    ("error.file_lock.schedule_replace", ["安排重启后替换失败 [{src}] -> [{dst}]: {error}（可能需要管理员权限）", "安排重新啟動後取代失敗 [{src}] -> [{dst}]: {error}（可能需要系統管理員權限）", "Failed to schedule replacement on reboot [{src}] -> [{dst}]: {error} (administrator rights may be required)", "再起動時の置き換えを予約できませんでした [{src}] -> [{dst}]: {error}（管理者権限が必要な可能性があります）", "재부팅 시 교체 예약 실패 [{src}] -> [{dst}]: {error} (관리자 권한이 필요할 수 있음)"]),
+    ("error.adb.spawn", ["执行 adb 失败: {error}", "執行 adb 失敗: {error}", "Failed to run adb: {error}", "adb の実行に失敗しました: {error}", "adb 실행 실패: {error}"]),
+    ("error.adb.failed", ["adb 命令失败: {error}", "adb 命令失敗: {error}", "adb command failed: {error}", "adb コマンドが失敗しました: {error}", "adb 명령 실패: {error}"]),
+    ("error.launch.invalid_regex", ["无效的窗口标题正则: {error}", "無效的視窗標題正規表示式: {error}", "Invalid window title regex: {error}", "ウィンドウタイトルの正規表現が無効です: {error}", "잘못된 창 제목 정규식: {error}"]),
+    ("error.launch.unsupported_controller", ["仅支持 Win32 / Gamepad 控制器", "僅支援 Win32 / Gamepad 控制器", "Only Win32 / Gamepad controllers are supported", "Win32 / Gamepad コントローラーのみ対応しています", "Win32 / Gamepad 컨트롤러만 지원합니다"]),
+    ("error.launch.exe_missing", ["程序不存在: {path}", "程式不存在: {path}", "Program not found: {path}", "プログラムが見つかりません: {path}", "프로그램을 찾을 수 없습니다: {path}"]),
+    ("error.launch.spawn", ["启动程序失败: {path} - {error}", "啟動程式失敗: {path} - {error}", "Failed to start the program: {path} - {error}", "プログラムの起動に失敗しました: {path} - {error}", "프로그램 시작 실패: {path} - {error}"]),
+    ("error.launch.window_timeout", ["等待窗口超时（{secs} 秒）", "等待視窗逾時（{secs} 秒）", "Timed out waiting for the window ({secs} s)", "ウィンドウの待機がタイムアウトしました（{secs} 秒）", "창 대기 시간 초과({secs}초)"]),
+    ("error.capture.short_side", ["设置截图短边失败: {error}", "設定截圖短邊失敗: {error}", "Failed to set the screenshot short side: {error}", "スクリーンショットの短辺を設定できません: {error}", "스크린샷 짧은 변 설정 실패: {error}"]),
+    ("error.capture.raw_size", ["设置原始分辨率截图失败: {error}", "設定原始解析度截圖失敗: {error}", "Failed to enable raw-resolution screenshots: {error}", "元の解像度でのスクリーンショットを設定できません: {error}", "원본 해상도 스크린샷 설정 실패: {error}"]),
+    ("error.capture.invalid_short_side", ["无效的截图短边 {value}（允许范围 {min}-{max}）", "無效的截圖短邊 {value}（允許範圍 {min}-{max}）", "Invalid screenshot short side {value} (allowed range {min}-{max})", "スクリーンショットの短辺 {value} が無効です（許容範囲 {min}-{max}）", "잘못된 스크린샷 짧은 변 {value} (허용 범위 {min}-{max})"]),
+    ("error.variables.empty_name", ["变量名不能为空", "變數名稱不能為空", "Variable name cannot be empty", "変数名を空にすることはできません", "변수 이름은 비워 둘 수 없습니다"]),
+    ("error.tags.too_many", ["标签数量超过上限 {max}", "標籤數量超過上限 {max}", "Too many tags (maximum {max})", "タグの数が上限 {max} を超えています", "태그 수가 최대 {max}개를 넘었습니다"]),
+    ("error.task_graph.dep_out_of_range", ["任务 {index}（{entry}）依赖的任务下标 {dep} 超出范围", "工作 {index}（{entry}）相依的工作索引 {dep} 超出範圍", "Task {index} ({entry}) depends on task index {dep}, which is out of range", "タスク {index}（{entry}）が依存するタスク番号 {dep} は範囲外です", "작업 {index}({entry})이(가) 의존하는 작업 번호 {dep}이(가) 범위를 벗어났습니다"]),
+    ("error.task_graph.self_dep", ["任务 {index}（{entry}）不能依赖自身", "工作 {index}（{entry}）不能相依於自身", "Task {index} ({entry}) cannot depend on itself", "タスク {index}（{entry}）は自分自身に依存できません", "작업 {index}({entry})은(는) 자기 자신에 의존할 수 없습니다"]),
+    ("error.task_graph.cycle", ["任务依赖存在循环（涉及任务 {tasks}）", "工作相依存在循環（涉及工作 {tasks}）", "Task dependencies contain a cycle (tasks {tasks})", "タスクの依存関係が循環しています（対象タスク {tasks}）", "작업 의존 관계에 순환이 있습니다(관련 작업 {tasks})"]),
+    ("error.task_graph.invalid_run_if", ["任务 {index}（{entry}）的 run_if 无效: {error}", "工作 {index}（{entry}）的 run_if 無效: {error}", "Invalid run_if for task {index} ({entry}): {error}", "タスク {index}（{entry}）の run_if が無効です: {error}", "작업 {index}({entry})의 run_if가 올바르지 않습니다: {error}"]),
+    ("error.path_compat.program_data", ["ProgramData 路径不可用", "ProgramData 路徑無法使用", "The ProgramData path is unavailable", "ProgramData のパスを使用できません", "ProgramData 경로를 사용할 수 없습니다"]),
+    ("error.path_compat.junction", ["创建目录联接失败: {error}", "建立目錄連結失敗: {error}", "Failed to create the directory junction: {error}", "ディレクトリジャンクションの作成に失敗しました: {error}", "디렉터리 정션 생성 실패: {error}"]),
+    ("error.signature.no_public_key", ["当前构建未内置发布公钥，无法校验签名", "目前建置未內建發布公鑰，無法驗證簽章", "This build has no release public key, so signatures cannot be verified", "このビルドにはリリース公開鍵が含まれていないため、署名を検証できません", "이 빌드에는 릴리스 공개 키가 없어 서명을 검증할 수 없습니다"]),
+    ("error.signature.invalid_format", ["签名格式无效: {error}", "簽章格式無效: {error}", "Invalid signature format: {error}", "署名の形式が無効です: {error}", "서명 형식이 올바르지 않습니다: {error}"]),
+    ("error.signature.mismatch", ["签名校验失败", "簽章驗證失敗", "Signature verification failed", "署名の検証に失敗しました", "서명 검증 실패"]),
+    ("error.signature.missing_file", ["缺少签名文件: {path}", "缺少簽章檔案: {path}", "Signature file missing: {path}", "署名ファイルがありません: {path}", "서명 파일이 없습니다: {path}"]),
+    ("error.recorder.not_recording", ["该实例未在录制", "該實例未在錄製", "This instance is not recording", "このインスタンスは記録中ではありません", "이 인스턴스는 녹화 중이 아닙니다"]),
+    ("error.recorder.unsupported_version", ["不支持的脚本版本: {version}", "不支援的腳本版本: {version}", "Unsupported script version: {version}", "サポートされていないスクリプトバージョンです: {version}", "지원하지 않는 스크립트 버전: {version}"]),
+    ("error.recorder.step_failed", ["第 {step} 步执行失败: {error}", "第 {step} 步執行失敗: {error}", "Step {step} failed: {error}", "ステップ {step} の実行に失敗しました: {error}", "{step}단계 실행 실패: {error}"]),
+    ("error.run_options.invalid_multiplier", ["无效的速度倍率: {value}", "無效的速度倍率: {value}", "Invalid speed multiplier: {value}", "速度倍率が無効です: {value}", "잘못된 속도 배율: {value}"]),
+    ("error.run_options.interval_too_large", ["操作间隔过大: {value}ms（上限 {max}ms）", "操作間隔過大: {value}ms（上限 {max}ms）", "Action interval too large: {value}ms (maximum {max}ms)", "操作間隔が大きすぎます: {value}ms（上限 {max}ms）", "작업 간격이 너무 깁니다: {value}ms (최대 {max}ms)"]),
+    ("error.elevation.target_elevated", ["目标窗口以管理员权限运行，MXU 无法向其发送输入", "目標視窗以系統管理員權限執行，MXU 無法向其傳送輸入", "The target window runs as administrator, so MXU cannot send input to it", "対象ウィンドウは管理者権限で実行されているため、MXU から入力を送信できません", "대상 창이 관리자 권한으로 실행 중이라 MXU가 입력을 보낼 수 없습니다"]),
+    ("error.elevation.restarting", ["正在以管理员身份重启 MXU", "正在以系統管理員身分重新啟動 MXU", "Restarting MXU as administrator", "MXU を管理者として再起動しています", "MXU를 관리자 권한으로 다시 시작하는 중입니다"]),
+    ("error.maintenance.refused", ["当前处于维护时段「{name}」，预计 {ends_at} 结束", "目前處於維護時段「{name}」，預計 {ends_at} 結束", "Currently in maintenance window \"{name}\", expected to end at {ends_at}", "現在メンテナンス時間「{name}」中です。{ends_at} に終了予定です", "현재 점검 시간 '{name}'이며 {ends_at}에 종료될 예정입니다"]),
+    ("error.maintenance.too_long", ["维护时段「{name}」预计 {ends_at} 结束，超过最长等待时间 {minutes} 分钟", "維護時段「{name}」預計 {ends_at} 結束，超過最長等待時間 {minutes} 分鐘", "Maintenance window \"{name}\" ends at {ends_at}, which exceeds the maximum wait of {minutes} minutes", "メンテナンス時間「{name}」は {ends_at} に終了予定で、最大待機時間 {minutes} 分を超えています", "점검 시간 '{name}'이(가) {ends_at}에 끝나 최대 대기 시간 {minutes}분을 넘습니다"]),
+    ("error.gamepad.not_gamepad", ["当前控制器不是 Gamepad", "目前控制器不是 Gamepad", "The current controller is not a Gamepad", "現在のコントローラーは Gamepad ではありません", "현재 컨트롤러는 Gamepad가 아닙니다"]),
+    ("error.gamepad.unknown_input", ["未知的手柄输入: {name}", "未知的手把輸入: {name}", "Unknown gamepad input: {name}", "不明なゲームパッド入力です: {name}", "알 수 없는 게임패드 입력: {name}"]),
+    ("error.mqtt.empty_host", ["MQTT 服务器地址不能为空", "MQTT 伺服器位址不能為空", "The MQTT server address cannot be empty", "MQTT サーバーのアドレスを空にすることはできません", "MQTT 서버 주소는 비워 둘 수 없습니다"]),
+    ("error.mqtt.not_configured", ["MQTT 尚未配置", "MQTT 尚未設定", "MQTT is not configured", "MQTT が設定されていません", "MQTT가 설정되지 않았습니다"]),
+    ("error.trace.not_found", ["追踪记录不存在或已过期: {id}", "追蹤記錄不存在或已過期: {id}", "Trace not found or expired: {id}", "トレース記録が存在しないか期限切れです: {id}", "추적 기록이 없거나 만료되었습니다: {id}"]),
+    ("error.device_health.not_adb", ["实例未使用 ADB 控制器", "實例未使用 ADB 控制器", "The instance does not use an ADB controller", "インスタンスは ADB コントローラーを使用していません", "인스턴스가 ADB 컨트롤러를 사용하지 않습니다"]),
+    ("error.post_run.unsupported_action", ["不支持的电源操作: {action}", "不支援的電源操作: {action}", "Unsupported power action: {action}", "サポートされていない電源操作です: {action}", "지원하지 않는 전원 작업: {action}"]),
+    ("error.screenshot.convert_failed", ["截图数据转换失败", "截圖資料轉換失敗", "Failed to convert the screenshot buffer", "スクリーンショットデータの変換に失敗しました", "스크린샷 데이터 변환 실패"]),
+    ("error.screenshot.no_data", ["暂无截图数据", "尚無截圖資料", "No screenshot data available", "スクリーンショットデータがありません", "스크린샷 데이터가 없습니다"]),
+];
@@ -0,0 +1,226 @@
+//! 后端文案本地化
+//!
+//! 托盘菜单、原生对话框按钮、系统通知等由后端直接展示的文案通过 [`t`] 按键查表，
+//! 带参数的文案用 [`tr!`] 填充 `{name}` 占位符。
+//! 语言与前端 `settings.language` 保持一致：启动时由 [`init_from_config`] 读取，
+//! 切换语言时前端调用 `mxu_set_locale` 同步。`system` 按系统语言环境推断。
+//!
+//! 返回给前端的错误消息文案集中在 [`errors`] 中，覆盖 MaaFramework 调用、Agent、
+//! 配置加密、更新与下载、Web 接口及 WebView2 安装等用户可见的错误；
+//! 日志内容不做本地化，始终为中文或英文原文以便排查。
+
+mod errors;
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use log::info;
+
+/// 支持的语言（与前端 i18n 一致）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Locale {
+    ZhCn = 0,
+    ZhTw = 1,
+    EnUs = 2,
+    JaJp = 3,
+    KoKr = 4,
+}
+
+impl Locale {
+    /// 解析语言标识（`zh-CN`、`en_US.UTF-8`、`ja` 等），`system` 或无法识别时返回 None
+    fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.split('.').next().unwrap_or(tag).replace('_', "-");
+        let lower = tag.to_ascii_lowercase();
+        if lower.starts_with("zh") {
+            let traditional = ["-tw", "-hk", "-mo", "-hant"]
+                .iter()
+                .any(|s| lower.contains(s));
+            return Some(if traditional { Self::ZhTw } else { Self::ZhCn });
+        }
+        match lower.split('-').next()? {
+            "en" => Some(Self::EnUs),
+            "ja" => Some(Self::JaJp),
+            "ko" => Some(Self::KoKr),
+            _ => None,
+        }
+    }
+
+    fn from_u8(v: u8) -> Self {
+        match v {
+            1 => Self::ZhTw,
+            2 => Self::EnUs,
+            3 => Self::JaJp,
+            4 => Self::KoKr,
+            _ => Self::ZhCn,
+        }
+    }
+
+    /// 从系统语言环境推断
+    fn from_system() -> Self {
+        #[cfg(windows)]
+        {
+            extern "system" {
+                fn GetUserDefaultLocaleName(name: *mut u16, len: i32) -> i32;
+            }
+            let mut buf = [0u16; 85];
+            // SAFETY: 缓冲区长度与传入的 len 一致（LOCALE_NAME_MAX_LENGTH = 85）
+            let len = unsafe { GetUserDefaultLocaleName(buf.as_mut_ptr(), buf.len() as i32) };
+            if len > 1 {
+                let name = String::from_utf16_lossy(&buf[..(len - 1) as usize]);
+                if let Some(locale) = Self::parse(&name) {
+                    return locale;
+                }
+            }
+        }
+
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|k| std::env::var(k).ok())
+            .find_map(|v| Self::parse(&v))
+            .unwrap_or(Self::ZhCn)
+    }
+}
+
+/// 当前语言
+static LOCALE: AtomicU8 = AtomicU8::new(Locale::ZhCn as u8);
+
+/// 文案表：键 -> [zh-CN, zh-TW, en-US, ja-JP, ko-KR]
+const CATALOG: &[(&str, [&str; 5])] = &[
+    ("dialog.ok", ["确定", "確定", "OK", "OK", "확인"]),
+    ("dialog.cancel", ["取消", "取消", "Cancel", "キャンセル", "취소"]),
+    ("dialog.cancelling", ["正在取消...", "正在取消...", "Cancelling...", "キャンセル中...", "취소 중..."]),
+    ("tray.show", ["显示主窗口", "顯示主視窗", "Show Window", "メインウィンドウを表示", "메인 창 표시"]),
+    ("tray.start_tasks", ["开始任务", "開始任務", "Start Tasks", "タスク開始", "작업 시작"]),
+    ("tray.stop_tasks", ["停止任务", "停止任務", "Stop Tasks", "タスク停止", "작업 중지"]),
+    ("tray.start", ["开始", "開始", "Start", "開始", "시작"]),
+    ("tray.stop", ["停止", "停止", "Stop", "停止", "중지"]),
+    ("tray.running_instances", ["{n} 个实例运行中", "{n} 個實例執行中", "{n} instance(s) running", "{n} 個のインスタンスが実行中", "{n}개 인스턴스 실행 중"]),
+    ("tray.quit", ["退出", "退出", "Quit", "終了", "종료"]),
+    ("notification.open_log", ["打开日志", "開啟日誌", "Open Log", "ログを開く", "로그 열기"]),
+    ("notification.stop_tasks", ["停止任务", "停止任務", "Stop Tasks", "タスク停止", "작업 중지"]),
+    ("notification.show_window", ["显示窗口", "顯示視窗", "Show Window", "ウィンドウを表示", "창 표시"]),
+    ("notification.downloading", ["正在下载...", "正在下載...", "Downloading...", "ダウンロード中...", "다운로드 중..."]),
+    ("notification.download_complete", ["下载完成", "下載完成", "Download complete", "ダウンロード完了", "다운로드 완료"]),
+    ("notification.send_failed", ["发送通知失败", "發送通知失敗", "Failed to send notification", "通知の送信に失敗しました", "알림 전송 실패"]),
+    ("webview2.extracting_title", ["正在解压 WebView2", "正在解壓縮 WebView2", "Extracting WebView2", "WebView2 を展開中", "WebView2 압축 해제 중"]),
+    ("webview2.extracting_local", ["检测到本地 WebView2 运行时 cab 文件，正在解压...", "偵測到本機 WebView2 執行階段 cab 檔案，正在解壓縮...", "Found a local WebView2 runtime cab file, extracting...", "ローカルの WebView2 ランタイム cab ファイルを検出しました。展開中...", "로컬 WebView2 런타임 cab 파일을 찾았습니다. 압축 해제 중..."]),
+    ("webview2.downloading_title", ["正在下载 WebView2", "正在下載 WebView2", "Downloading WebView2", "WebView2 をダウンロード中", "WebView2 다운로드 중"]),
+    ("webview2.downloading_runtime", ["系统 WebView2 不可用，正在下载独立 WebView2...", "系統 WebView2 無法使用，正在下載獨立 WebView2...", "The system WebView2 is unavailable, downloading the standalone WebView2...", "システムの WebView2 が使用できないため、独立版 WebView2 をダウンロード中...", "시스템 WebView2를 사용할 수 없어 독립 WebView2를 다운로드 중..."]),
+    ("webview2.downloading_runtime_status", ["正在下载独立 WebView2...", "正在下載獨立 WebView2...", "Downloading the standalone WebView2...", "独立版 WebView2 をダウンロード中...", "독립 WebView2 다운로드 중..."]),
+    ("webview2.extracting_status", ["正在解压...", "正在解壓縮...", "Extracting...", "展開中...", "압축 해제 중..."]),
+    ("webview2.downloading_installer_title", ["正在下载 WebView2 安装包", "正在下載 WebView2 安裝套件", "Downloading the WebView2 installer", "WebView2 インストーラーをダウンロード中", "WebView2 설치 패키지 다운로드 중"]),
+    ("webview2.downloading_installer", ["独立运行时下载失败，正在下载 WebView2 完整安装包...", "獨立執行階段下載失敗，正在下載 WebView2 完整安裝套件...", "Downloading the standalone runtime failed, downloading the full WebView2 installer...", "独立版ランタイムのダウンロードに失敗したため、WebView2 のフルインストーラーをダウンロード中...", "독립 런타임 다운로드에 실패해 WebView2 전체 설치 패키지를 다운로드 중..."]),
+    ("webview2.downloading_installer_status", ["正在下载 WebView2 安装包...", "正在下載 WebView2 安裝套件...", "Downloading the WebView2 installer...", "WebView2 インストーラーをダウンロード中...", "WebView2 설치 패키지 다운로드 중..."]),
+    ("webview2.installing_status", ["正在安装 WebView2，请稍候...", "正在安裝 WebView2，請稍候...", "Installing WebView2, please wait...", "WebView2 をインストール中です。しばらくお待ちください...", "WebView2 설치 중입니다. 잠시 기다려 주세요..."]),
+    ("webview2.download_failed_title", ["WebView2 下载失败", "WebView2 下載失敗", "WebView2 download failed", "WebView2 のダウンロードに失敗しました", "WebView2 다운로드 실패"]),
+    ("webview2.arch_mismatch_title", ["WebView2 架构不匹配", "WebView2 架構不符", "WebView2 architecture mismatch", "WebView2 のアーキテクチャが一致しません", "WebView2 아키텍처 불일치"]),
+    ("webview2.disabled_title", ["系统 WebView2 已被禁用", "系統 WebView2 已被停用", "System WebView2 is disabled", "システムの WebView2 が無効化されています", "시스템 WebView2가 비활성화됨"]),
+    ("webview2.install", ["安装", "安裝", "Install", "インストール", "설치"]),
+    ("webview2.exit", ["退出", "退出", "Exit", "終了", "종료"]),
+    ("webview2.reason.policy_empty_folder", ["通过组策略禁用 (BrowserExecutableFolder 为空)", "透過群組原則停用 (BrowserExecutableFolder 為空)", "Disabled by Group Policy (BrowserExecutableFolder is empty)", "グループポリシーで無効化されています (BrowserExecutableFolder が空)", "그룹 정책으로 비활성화됨 (BrowserExecutableFolder가 비어 있음)"]),
+    ("webview2.reason.policy_disabled", ["WebView2 已被组策略禁用", "WebView2 已被群組原則停用", "WebView2 is disabled by Group Policy", "WebView2 はグループポリシーで無効化されています", "WebView2가 그룹 정책으로 비활성화됨"]),
+    ("webview2.reason.process_name", ["WebView2 进程 (msedgewebview2.exe)", "WebView2 處理程序 (msedgewebview2.exe)", "The WebView2 process (msedgewebview2.exe)", "WebView2 プロセス (msedgewebview2.exe)", "WebView2 프로세스 (msedgewebview2.exe)"]),
+    ("webview2.reason.ifeo", ["{name} 已被 IFEO 禁用\r\n(可能使用了 Edge Blocker 等工具)", "{name} 已被 IFEO 停用\r\n(可能使用了 Edge Blocker 等工具)", "{name} is blocked by IFEO\r\n(a tool such as Edge Blocker may be in use)", "{name} は IFEO で無効化されています\r\n(Edge Blocker などのツールが使用されている可能性があります)", "{name}이(가) IFEO로 차단됨\r\n(Edge Blocker 같은 도구를 사용 중일 수 있음)"]),
+    ("config.migrate.fill_fields", ["{file}: 补齐 instances / settings 字段", "{file}: 補齊 instances / settings 欄位", "{file}: added missing instances / settings fields", "{file}: instances / settings フィールドを補完しました", "{file}: instances / settings 필드를 채웠습니다"]),
+    ("config.migrate.pre_actions", ["{file}: {count} 个实例的 preAction 已迁移为 preActions", "{file}: {count} 個實例的 preAction 已遷移為 preActions", "{file}: migrated preAction to preActions for {count} instance(s)", "{file}: {count} 個のインスタンスの preAction を preActions に移行しました", "{file}: 인스턴스 {count}개의 preAction을 preActions로 옮겼습니다"]),
+    ("config.migrate.version", ["{file}: 版本 {from} -> {to}", "{file}: 版本 {from} -> {to}", "{file}: version {from} -> {to}", "{file}: バージョン {from} -> {to}", "{file}: 버전 {from} -> {to}"]),
+    ("self_check.maafw_dir_missing", ["未找到 maafw 目录: {path}", "未找到 maafw 目錄: {path}", "maafw folder not found: {path}", "maafw フォルダーが見つかりません: {path}", "maafw 폴더를 찾을 수 없습니다: {path}"]),
+    ("self_check.maafw_lib_missing", ["maafw 目录中缺少 {path}", "maafw 目錄中缺少 {path}", "{path} is missing from the maafw folder", "maafw フォルダーに {path} がありません", "maafw 폴더에 {path}이(가) 없습니다"]),
+    ("self_check.lib_missing", ["动态库不存在", "動態函式庫不存在", "The library file does not exist", "ライブラリファイルが存在しません", "라이브러리 파일이 없습니다"]),
+    ("self_check.load_failed", ["加载失败: {error}", "載入失敗: {error}", "Failed to load: {error}", "読み込みに失敗しました: {error}", "로드 실패: {error}"]),
+    ("self_check.lib_not_loaded", ["动态库未加载", "動態函式庫未載入", "The library is not loaded", "ライブラリが読み込まれていません", "라이브러리가 로드되지 않았습니다"]),
+    ("self_check.version_unreadable", ["无法读取版本号", "無法讀取版本號", "Cannot read the version", "バージョンを取得できません", "버전을 읽을 수 없습니다"]),
+    ("self_check.version_too_old", ["当前版本 {current} 低于最低要求 {minimum}", "目前版本 {current} 低於最低需求 {minimum}", "Version {current} is older than the required {minimum}", "現在のバージョン {current} は最低要件 {minimum} を下回っています", "현재 버전 {current}이(가) 최소 요구 버전 {minimum}보다 낮습니다"]),
+    ("self_check.no_agent", ["未配置 Agent", "未設定 Agent", "No agent configured", "Agent が設定されていません", "Agent가 설정되지 않았습니다"]),
+    ("self_check.child_exec_empty", ["(child_exec 为空)", "(child_exec 為空)", "(child_exec is empty)", "(child_exec が空です)", "(child_exec가 비어 있음)"]),
+    ("self_check.agents_ok", ["{count} 个 Agent 可执行文件均存在", "{count} 個 Agent 執行檔皆存在", "All {count} agent executable(s) found", "{count} 個の Agent 実行ファイルがすべて存在します", "Agent 실행 파일 {count}개가 모두 있습니다"]),
+    ("self_check.not_found", ["未找到: {items}", "未找到: {items}", "Not found: {items}", "見つかりません: {items}", "찾을 수 없음: {items}"]),
+    ("self_check.adb_not_in_path", ["PATH 中未找到 adb，可在搜索设备时由模拟器提供", "PATH 中未找到 adb，可在搜尋裝置時由模擬器提供", "adb is not on PATH; an emulator can provide it when searching for devices", "PATH に adb が見つかりません。デバイス検索時にエミュレーターの adb を使用できます", "PATH에서 adb를 찾을 수 없습니다. 장치 검색 시 에뮬레이터의 adb를 사용할 수 있습니다"]),
+    ("self_check.not_windows", ["非 Windows 平台", "非 Windows 平台", "Not Windows", "Windows 以外のプラットフォーム", "Windows가 아닌 플랫폼"]),
+    ("self_check.webview2_disabled", ["系统 WebView2 已被禁用: {reason}", "系統 WebView2 已被停用: {reason}", "System WebView2 is disabled: {reason}", "システムの WebView2 が無効化されています: {reason}", "시스템 WebView2가 비활성화됨: {reason}"]),
+    ("self_check.webview2_fixed", ["固定版本运行时: {path}", "固定版本執行階段: {path}", "Fixed version runtime: {path}", "固定バージョンのランタイム: {path}", "고정 버전 런타임: {path}"]),
+    ("self_check.webview2_missing", ["未检测到 WebView2 运行时", "未偵測到 WebView2 執行階段", "WebView2 runtime not detected", "WebView2 ランタイムが検出されません", "WebView2 런타임을 찾을 수 없습니다"]),
+    ("self_check.system_dir_unavailable", ["无法获取系统目录", "無法取得系統目錄", "Cannot get the system folder", "システムフォルダーを取得できません", "시스템 폴더를 가져올 수 없습니다"]),
+    ("self_check.installed", ["已安装", "已安裝", "Installed", "インストール済み", "설치됨"]),
+    ("self_check.missing", ["缺少: {items}", "缺少: {items}", "Missing: {items}", "不足: {items}", "없음: {items}"]),
+    ("self_check.not_writable", ["{path} 不可写: {error}", "{path} 無法寫入: {error}", "{path} is not writable: {error}", "{path} に書き込めません: {error}", "{path}에 쓸 수 없습니다: {error}"]),
+    ("self_check.clock_drifted", ["系统时钟偏差 {seconds} 秒，定时执行可能不准确", "系統時鐘偏差 {seconds} 秒，排程執行可能不準確", "The system clock is off by {seconds}s; scheduled runs may be inaccurate", "システム時計が {seconds} 秒ずれています。スケジュール実行が不正確になる可能性があります", "시스템 시계가 {seconds}초 어긋나 예약 실행이 부정확할 수 있습니다"]),
+    ("self_check.clock_ok", ["偏差 {offset} ms（{server}）", "偏差 {offset} ms（{server}）", "Offset {offset} ms ({server})", "ずれ {offset} ms（{server}）", "오차 {offset} ms ({server})"]),
+    ("long_path.too_long", ["安装路径过深：最长的文件路径有 {len} 个字符，超过 Windows 的 {limit} 字符限制。请将 MXU 移动到较短的目录（如 D:\\MXU），或开启系统长路径支持后重启 MXU", "安裝路徑過深：最長的檔案路徑有 {len} 個字元，超過 Windows 的 {limit} 字元限制。請將 MXU 移動到較短的目錄（如 D:\\MXU），或開啟系統長路徑支援後重新啟動 MXU", "The install path is too deep: the longest file path has {len} characters, over the Windows limit of {limit}. Move MXU to a shorter directory (e.g. D:\\MXU), or enable long path support and restart MXU", "インストール先が深すぎます：最長のファイルパスが {len} 文字で、Windows の {limit} 文字制限を超えています。MXU を短いフォルダー（例: D:\\MXU）に移動するか、長いパスのサポートを有効にして MXU を再起動してください", "설치 경로가 너무 깊습니다: 가장 긴 파일 경로가 {len}자로 Windows의 {limit}자 제한을 넘습니다. MXU를 더 짧은 폴더(예: D:\\MXU)로 옮기거나 긴 경로 지원을 켠 뒤 MXU를 다시 시작하세요"]),
+    ("long_path.long_but_enabled", ["最长的文件路径有 {len} 个字符，已开启系统长路径支持，但部分组件仍可能无法访问，建议将 MXU 移动到较短的目录", "最長的檔案路徑有 {len} 個字元，已開啟系統長路徑支援，但部分元件仍可能無法存取，建議將 MXU 移動到較短的目錄", "The longest file path has {len} characters. Long path support is enabled, but some components may still fail to access it; consider moving MXU to a shorter directory", "最長のファイルパスは {len} 文字です。長いパスのサポートは有効ですが、一部のコンポーネントはアクセスできない可能性があります。MXU を短いフォルダーに移動することをおすすめします", "가장 긴 파일 경로가 {len}자입니다. 긴 경로 지원이 켜져 있지만 일부 구성 요소는 접근하지 못할 수 있으니 MXU를 더 짧은 폴더로 옮기는 것을 권장합니다"]),
+    ("long_path.exe_dir_long", ["安装目录路径有 {len} 个字符，运行时生成的调试图像、缓存等文件可能超出 {limit} 字符限制，建议将 MXU 移动到较短的目录", "安裝目錄路徑有 {len} 個字元，執行時產生的偵錯圖片、快取等檔案可能超出 {limit} 字元限制，建議將 MXU 移動到較短的目錄", "The install directory path has {len} characters; debug images, caches and other files created at runtime may exceed the {limit}-character limit. Consider moving MXU to a shorter directory", "インストール先のパスは {len} 文字です。実行時に生成されるデバッグ画像やキャッシュなどが {limit} 文字の制限を超える可能性があります。MXU を短いフォルダーに移動することをおすすめします", "설치 폴더 경로가 {len}자입니다. 실행 중 생성되는 디버그 이미지, 캐시 등이 {limit}자 제한을 넘을 수 있으니 MXU를 더 짧은 폴더로 옮기는 것을 권장합니다"]),
+    ("long_path.ok", ["最长路径 {len} 个字符", "最長路徑 {len} 個字元", "Longest path: {len} characters", "最長パス {len} 文字", "가장 긴 경로 {len}자"]),
+    ("antivirus.restore_quarantined", ["Windows Defender 已隔离安装目录下的文件：打开「Windows 安全中心 > 病毒和威胁防护 > 保护历史记录」，对相应条目选择「还原」", "Windows Defender 已隔離安裝目錄下的檔案：開啟「Windows 安全性 > 病毒與威脅防護 > 保護歷程記錄」，對相應項目選擇「還原」", "Windows Defender has quarantined files in the install folder: open \"Windows Security > Virus & threat protection > Protection history\" and choose \"Restore\" for those entries", "Windows Defender がインストール先のファイルを検疫しました：「Windows セキュリティ > ウイルスと脅威の防止 > 保護の履歴」を開き、該当する項目で「復元」を選択してください", "Windows Defender가 설치 폴더의 파일을 격리했습니다: 'Windows 보안 > 바이러스 및 위협 방지 > 보호 기록'을 열고 해당 항목에서 '복원'을 선택하세요"]),
+    ("antivirus.add_exclusions", ["在「病毒和威胁防护设置 > 排除项」中添加以下文件夹：{paths}", "在「病毒與威脅防護設定 > 排除項目」中新增以下資料夾：{paths}", "Add these folders under \"Virus & threat protection settings > Exclusions\": {paths}", "「ウイルスと脅威の防止の設定 > 除外」に次のフォルダーを追加してください：{paths}", "'바이러스 및 위협 방지 설정 > 제외'에 다음 폴더를 추가하세요: {paths}"]),
+    ("antivirus.reinstall", ["添加排除项后重新解压或更新 MXU，以恢复被删除的文件", "新增排除項目後重新解壓縮或更新 MXU，以還原被刪除的檔案", "After adding the exclusions, extract or update MXU again to restore the deleted files", "除外を追加した後、MXU を再展開または更新して削除されたファイルを復元してください", "제외 항목을 추가한 뒤 MXU를 다시 압축 해제하거나 업데이트해 삭제된 파일을 복구하세요"]),
+    ("antivirus.third_party", ["如使用第三方杀毒软件，请在其信任区 / 白名单中添加上述文件夹", "如使用第三方防毒軟體，請在其信任區 / 白名單中新增上述資料夾", "If you use third-party antivirus software, add the folders above to its trusted list / whitelist", "サードパーティ製のウイルス対策ソフトを使用している場合は、上記のフォルダーを信頼リスト / ホワイトリストに追加してください", "타사 백신을 사용 중이라면 위 폴더를 신뢰 목록 / 허용 목록에 추가하세요"]),
+    ("antivirus.run_as_admin", ["以管理员身份运行 MXU 后可检查安装目录是否已在 Defender 排除项中", "以系統管理員身分執行 MXU 後可檢查安裝目錄是否已在 Defender 排除項目中", "Run MXU as administrator to check whether the install folder is in the Defender exclusions", "MXU を管理者として実行すると、インストール先が Defender の除外に含まれているか確認できます", "MXU를 관리자 권한으로 실행하면 설치 폴더가 Defender 제외 항목에 있는지 확인할 수 있습니다"]),
+    ("antivirus.already_excluded", ["安装目录已在 Defender 排除项中，文件缺失可能由其他杀毒软件或解压不完整导致", "安裝目錄已在 Defender 排除項目中，檔案缺失可能由其他防毒軟體或解壓縮不完整導致", "The install folder is already excluded in Defender; the missing files may be caused by other antivirus software or an incomplete extraction", "インストール先はすでに Defender の除外に含まれています。ファイルの欠落は他のウイルス対策ソフトか不完全な展開が原因の可能性があります", "설치 폴더가 이미 Defender 제외 항목에 있습니다. 파일 누락은 다른 백신이나 불완전한 압축 해제 때문일 수 있습니다"]),
+];
+
+/// 当前语言
+pub fn locale() -> Locale {
+    Locale::from_u8(LOCALE.load(Ordering::Relaxed))
+}
+
+/// 设置语言（`system` 或无法识别的值按系统语言推断）
+pub fn set_locale(tag: &str) -> Locale {
+    let locale = Locale::parse(tag).unwrap_or_else(Locale::from_system);
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+    locale
+}
+
+/// 按当前语言查找文案，键不存在时原样返回键名
+pub fn t(key: &'static str) -> &'static str {
+    CATALOG
+        .iter()
+        .chain(errors::CATALOG)
+        .find(|(k, _)| *k == key)
+        .map(|(_, texts)| texts[locale() as usize])
+        .unwrap_or(key)
+}
+
+/// 按当前语言查找文案并填充 `{name}` 占位符，返回 `String`
+///
+/// ```ignore
+/// tr!("error.agent.not_found", index = agent_index)
+/// ```
+macro_rules! tr {
+    ($key:literal) => {
+        $crate::i18n::t($key).to_string()
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut text = $crate::i18n::t($key).to_string();
+        $(
+            text = text.replace(concat!("{", stringify!($name), "}"), &$value.to_string());
+        )+
+        text
+    }};
+}
+pub(crate) use tr;
+
+/// 启动时从配置读取 `settings.language`
+pub fn init_from_config(config: &serde_json::Value) {
+    let tag = config
+        .get("settings")
+        .and_then(|s| s.get("language"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("system");
+    set_locale(tag);
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 同步前端语言到后端（托盘菜单随之重建）
+#[tauri::command]
+pub fn mxu_set_locale(app: tauri::AppHandle, locale: String) -> Result<(), String> {
+    let resolved = set_locale(&locale);
+    info!(
+        "Backend locale set to {:?} (requested: {})",
+        resolved, locale
+    );
+    crate::tray::refresh_menu(&app);
+    Ok(())
+}
@@ -1,8 +1,17 @@
+#[cfg(feature = "api-server")]
+mod api_server;
 pub mod commands;
+mod deep_link;
+mod expr;
+mod i18n;
 mod mxu_actions;
+pub mod notification;
+mod power_guard;
 pub mod screenshot_service;
 mod tray;
 mod web_server;
+#[cfg(target_os = "windows")]
+pub mod webview2;
 pub mod ws_broadcast;
 
 use commands::{AppConfigState, MaaState};
@@ -24,6 +33,26 @@ pub fn run() {
     #[cfg(windows)]
     commands::system::migrate_legacy_autostart();
 
+    // 通过 mxu:// 链接启动且已有实例在运行时，转发链接后直接退出
+    if let Some(link) = deep_link::link_from_args() {
+        if let Ok(data_dir) = commands::utils::get_app_data_dir() {
+            let app_config = AppConfigState::default();
+            app_config.load_config(&data_dir);
+            let port = app_config
+                .config
+                .lock()
+                .get("settings")
+                .and_then(|s| s.get("webServerPort"))
+                .and_then(|v| v.as_u64())
+                .and_then(|v| u16::try_from(v).ok())
+                .filter(|&p| p > 0)
+                .unwrap_or(web_server::DEFAULT_PORT);
+            if deep_link::forward_to_running_instance(&link, port) {
+                return;
+            }
+        }
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_fs::init())
@@ -96,10 +125,7 @@ pub fn run() {
                 let ws_clone = ws_broadcast.clone();
                 let app_handle = app.handle().clone();
 
-                let settings = app_config
-                    .config
-                    .lock()
-                    .unwrap();
+                let settings = app_config.config.lock();
                 let settings_obj = settings.get("settings");
 
                 let allow_lan_access = settings_obj
@@ -129,6 +155,71 @@ pub fn run() {
                 });
             }
 
+            // 按配置启动 MQTT 状态发布
+            commands::mqtt::init_from_config(&app_config.config.lock());
+
+            // 后端文案语言跟随 settings.language
+            i18n::init_from_config(&app_config.config.lock());
+
+            // 读取运行期间防休眠开关
+            power_guard::init_from_config(&app_config.config.lock());
+
+            // 读取下载设置（自定义 User-Agent、附加请求头）
+            commands::download::init_from_config(&app_config.config.lock());
+
+            // 截图历史容量与间隔
+            commands::screenshot_history::init_from_config(&app_config.config.lock());
+
+            // 实例截图分辨率预设
+            commands::capture_resolution::init_from_config(&app_config.config.lock());
+
+            // 运行期间保持 ADB 设备亮屏开关
+            commands::adb_keep_awake::init_from_config(&app_config.config.lock());
+
+            // ADB 设备电量与温度监控
+            commands::device_health::init_from_config(&app_config.config.lock());
+            commands::device_health::start(app.handle());
+
+            // 控制器断线重连看门狗
+            commands::reconnect::init_from_config(&app_config.config.lock());
+            commands::reconnect::start(app.handle());
+
+            // Win32 目标窗口权限检测策略
+            commands::elevation::init_from_config(&app_config.config.lock());
+
+            // 游戏维护时段
+            commands::maintenance::init_from_config(&app_config.config.lock());
+
+            // 实例的服务器每日重置时间
+            commands::server_reset::init_from_config(&app_config.config.lock());
+
+            // 系统时钟偏差检测（启动时后台检查一次）
+            commands::clock_drift::init_from_config(&app_config.config.lock());
+            commands::clock_drift::start(app.handle());
+
+            // 程序文件完整性校验（启动时后台校验一次）
+            commands::integrity::init_from_config(&app_config.config.lock());
+            commands::integrity::start(app.handle());
+
+            // 非 ASCII 安装路径兼容（传给 MaaFramework 的路径映射为 ASCII）
+            commands::path_compat::init_from_config(&app_config.config.lock());
+            commands::path_compat::detect();
+
+            // 注册 mxu:// 协议，并暂存启动参数中的链接
+            deep_link::register_scheme();
+            deep_link::queue_from_args();
+
+            // 按 settings.cachePrune 自动清理缓存（后台线程）
+            commands::cache::init_from_config(&app_config.config.lock());
+
+            // 启动本地 REST 控制服务（settings.apiServer 启用且配置了令牌时）
+            #[cfg(feature = "api-server")]
+            api_server::spawn_from_config(
+                app_config.clone(),
+                maa_state.clone(),
+                app.handle().clone(),
+            );
+
             // Windows 下移除系统标题栏（使用自定义标题栏）
             // macOS/Linux 保留完整的原生标题栏
             #[cfg(target_os = "windows")]
@@ -173,7 +264,7 @@ pub fn run() {
                         Ok(()) => {
                             log::info!("MaaFramework loaded from {:?}", dll_path);
                             // 预先设置 lib_dir，使 HTTP /api/maa/initialized 立即反映加载状态
-                            *maa_state.lib_dir.lock().unwrap() = Some(maafw_dir.clone());
+                            *maa_state.lib_dir.lock() = Some(maafw_dir.clone());
                         }
                         Err(e) => {
                             log::error!("Failed to load MaaFramework: {}", e);
@@ -216,27 +307,84 @@ pub fn run() {
             commands::maa_core::maa_destroy_instance,
             commands::maa_core::maa_connect_controller,
             commands::maa_core::maa_get_connection_status,
+            commands::maa_core::maa_get_controller_info,
+            commands::capture_resolution::maa_set_capture_resolution,
+            commands::capture_resolution::maa_get_capture_resolution,
+            commands::reconnect::maa_reconnect_controller,
+            commands::reconnect::maa_switch_adb_methods,
+            commands::device_health::maa_get_device_health,
+            commands::app_backup::maa_backup_app_data,
+            commands::app_backup::maa_list_app_backups,
+            commands::app_backup::maa_restore_app_data,
+            commands::win32_dpi::maa_get_window_dpi,
+            commands::monitors::maa_list_monitors,
+            commands::launch_attach::maa_launch_and_attach,
+            commands::maintenance::mxu_get_maintenance_status,
+            commands::server_reset::mxu_time_until_reset,
+            commands::clock_drift::mxu_check_clock_drift,
+            commands::clock_drift::mxu_take_clock_drift_warning,
+            commands::integrity::mxu_get_integrity_report,
+            commands::integrity::mxu_verify_integrity,
             commands::maa_core::maa_load_resource,
             commands::maa_core::maa_is_resource_loaded,
             commands::maa_core::maa_get_resource_hash,
             commands::maa_core::maa_destroy_resource,
             commands::maa_core::maa_run_task,
+            commands::maa_core::maa_run_task_next,
             commands::maa_core::maa_get_task_status,
             commands::maa_core::maa_stop_task,
+            commands::maa_core::maa_stop_after_current,
             commands::maa_core::maa_override_pipeline,
             commands::maa_core::maa_is_running,
             commands::maa_core::maa_post_click,
+            commands::maa_core::maa_post_swipe,
             commands::maa_core::maa_post_screencap,
             commands::maa_core::maa_get_cached_image,
+            commands::maa_core::maa_preview_window,
             commands::maa_core::maa_screenshot_subscribe,
             commands::maa_core::maa_screenshot_unsubscribe,
+            // Gamepad 命令
+            commands::gamepad::maa_gamepad_test_input,
+            // 输入录制与回放命令
+            commands::input_recorder::maa_record_inputs_start,
+            commands::input_recorder::maa_record_inputs_stop,
+            commands::input_recorder::maa_replay_inputs,
+            commands::input_recorder::maa_replay_inputs_stop,
+            // 资源编写辅助命令
+            commands::authoring::maa_capture_template,
+            commands::authoring::maa_inspect_pixels,
+            commands::authoring::maa_test_ocr,
+            commands::authoring::maa_test_template,
+            commands::authoring::mxu_diff_images,
             // Agent 命令
             commands::maa_agent::maa_start_tasks,
             commands::maa_agent::maa_stop_agent,
+            commands::maa_agent::maa_stop_single_agent,
+            commands::maa_agent::maa_restart_agent,
+            commands::maa_agent::maa_agent_send_input,
+            commands::agent_server::mxu_agent_server_start,
+            commands::agent_server::mxu_agent_server_stop,
+            commands::agent_server::mxu_agent_server_status,
+            commands::python_env::agent_setup_python_env,
+            commands::run_options::maa_set_run_speed,
+            commands::run_options::maa_set_action_interval,
+            // 实例分组与标签命令
+            commands::instance_tags::mxu_set_instance_tags,
+            commands::instance_tags::mxu_list_instances,
+            commands::instance_tags::mxu_start_tasks_by_tag,
+            commands::instance_tags::mxu_stop_tasks_by_tag,
             // 文件操作命令
             commands::file_ops::read_local_file,
             commands::file_ops::read_local_file_base64,
             commands::file_ops::local_file_exists,
+            commands::file_ops::write_local_file,
+            commands::file_ops::list_local_dir,
+            commands::file_ops::hash_file,
+            commands::file_ops::move_to_trash,
+            commands::file_ops::remove_local_file,
+            commands::file_ops::read_image_file,
+            commands::cache::purge_cache,
+            commands::cache::mxu_cache_stats,
             commands::file_ops::get_exe_dir,
             commands::file_ops::get_data_dir,
             commands::file_ops::clear_log_files,
@@ -247,6 +395,23 @@ pub fn run() {
             // 状态查询命令
             commands::state::maa_get_instance_state,
             commands::state::maa_get_all_states,
+            commands::session::maa_restore_session,
+            commands::session::maa_get_session_snapshot,
+            commands::session::maa_clear_session,
+            commands::trace::mxu_get_trace,
+            commands::focus_log::mxu_get_focus_log,
+            // 运行报告命令
+            commands::run_report::mxu_get_run_report,
+            commands::run_report::mxu_list_run_reports,
+            commands::run_report::mxu_export_run_report,
+            commands::stats_export::mxu_export_stats,
+            commands::results::mxu_query_results,
+            commands::audit_log::mxu_get_audit_log,
+            commands::failure_capture::mxu_list_failure_captures,
+            commands::screenshot_history::maa_get_screenshot_history,
+            // 变量存储命令
+            commands::variables::mxu_get_variables,
+            commands::variables::mxu_set_variable,
             commands::state::maa_get_cached_adb_devices,
             commands::state::maa_get_cached_win32_windows,
             commands::state::maa_get_cached_wlroots_sockets,
@@ -254,6 +419,7 @@ pub fn run() {
             commands::state::push_log,
             commands::state::get_all_logs,
             commands::state::clear_instance_logs,
+            commands::state::mxu_fetch_missed_events,
             // 更新安装命令
             commands::update::extract_zip,
             commands::update::check_changes_json,
@@ -263,23 +429,42 @@ pub fn run() {
             commands::update::fallback_update,
             commands::update::move_file_to_old,
             commands::update::cleanup_update_artifacts,
+            commands::update::verify_update_compatibility,
+            commands::update::backup_installed_maafw,
+            commands::update::verify_installed_maafw,
+            commands::update::rollback_maafw_update,
+            commands::update::verify_update_signature,
+            commands::update::update_signature_required,
             // 下载命令
             commands::download::get_github_release_by_version,
+            commands::download::check_update,
             commands::download::download_file,
             commands::download::cancel_download,
+            // MirrorChyan 更新源命令
+            commands::mirrorchyan::mirrorchyan_check_update,
+            commands::mirrorchyan::mirrorchyan_get_download_url,
             // 系统相关命令
             commands::system::is_elevated,
             commands::system::is_autostart,
+            commands::system::has_restore_session_flag,
             commands::system::get_start_instance,
             commands::system::has_quit_after_run_flag,
             commands::system::restart_as_admin,
+            // 深度链接
+            deep_link::mxu_take_deep_links,
+            deep_link::mxu_parse_deep_link,
             commands::system::maa_set_save_draw,
             commands::system::open_file,
+            commands::system::mxu_wake_display,
+            commands::system::mxu_set_clipboard,
+            commands::system::mxu_get_clipboard,
+            commands::system::mxu_confirm_intervention,
             commands::system::run_and_wait,
             commands::system::set_pre_action_stop,
             commands::system::run_action,
             commands::system::is_process_running,
             commands::system::get_process_path_from_hwnd,
+            commands::system::maa_focus_window,
             commands::system::retry_load_maa_library,
             commands::system::check_vcredist_missing,
             commands::system::autostart_enable,
@@ -288,9 +473,17 @@ pub fn run() {
             commands::system::get_arch,
             commands::system::get_os,
             commands::system::get_system_info,
+            commands::system::check_disk_space,
             commands::system::get_web_server_port,
             commands::system::get_local_lan_ip,
             commands::system::get_webview2_dir,
+            commands::system::webview2_runtime_info,
+            commands::system::webview2_repair,
+            commands::self_check::mxu_self_check,
+            commands::antivirus::mxu_diagnose_antivirus,
+            commands::long_path::mxu_check_long_paths,
+            commands::long_path::mxu_enable_long_paths,
+            commands::path_compat::mxu_get_path_compat,
             // 托盘相关命令
             commands::tray::set_minimize_to_tray,
             commands::tray::get_minimize_to_tray,
@@ -298,6 +491,28 @@ pub fn run() {
             commands::tray::update_tray_tooltip,
             // 配置同步命令（WebUI 实时同步）
             commands::app_config::notify_config_changed,
+            commands::config_transfer::config_export,
+            commands::config_transfer::config_import,
+            commands::config_crypto::mxu_get_config_encryption,
+            commands::config_crypto::mxu_set_config_encryption,
+            commands::config_crypto::mxu_unlock_config,
+            commands::config_crypto::mxu_read_protected_file,
+            commands::config_crypto::mxu_write_protected_file,
+            // 运行结束后电源操作命令
+            commands::post_run::mxu_set_post_run_action,
+            commands::post_run::mxu_get_post_run_action,
+            commands::post_run::mxu_cancel_post_run_action,
+            // 防休眠命令
+            power_guard::power_guard_set_enabled,
+            power_guard::power_guard_get_status,
+            i18n::mxu_set_locale,
+            // 系统通知命令
+            notification::notification_show,
+            notification::notification_track_download,
+            // MQTT 状态发布命令
+            commands::mqtt::mqtt_configure,
+            commands::mqtt::mqtt_enable,
+            commands::mqtt::mqtt_get_status,
         ])
         .on_window_event(|window, event| {
             match event {
@@ -307,11 +522,14 @@ pub fn run() {
                         api.prevent_close();
                     }
                 }
-                // 窗口销毁时清理所有 agent 子进程
+                // 窗口销毁时清理所有 agent 子进程，并恢复保持亮屏的设备
                 tauri::WindowEvent::Destroyed => {
                     if let Some(state) = window.try_state::<Arc<MaaState>>() {
                         state.cleanup_all_agent_children();
+                        commands::adb_keep_awake::release_all(&state);
                     }
+                    let _ = commands::agent_server::mxu_agent_server_stop();
+                    power_guard::release();
                 }
                 _ => {}
             }
@@ -1,15 +1,24 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-#[cfg(target_os = "windows")]
-mod webview2;
-
 fn main() {
     if mxu_lib::commands::system::has_help_flag() {
         mxu_lib::commands::system::print_cli_help_text();
         std::process::exit(0);
     }
 
+    // 更新后兼容性检查：以子进程方式加载新的 MaaFramework 并输出版本号
+    if let Some(maafw_dir) = mxu_lib::commands::system::get_maafw_probe_dir() {
+        std::process::exit(mxu_lib::commands::system::run_maafw_probe(&maafw_dir));
+    }
+
+    // Agent Server 模式：作为 MaaFramework Agent 对外提供 MXU 内置动作
+    if let Some(identifier) = mxu_lib::commands::agent_server::get_agent_server_identifier() {
+        std::process::exit(mxu_lib::commands::agent_server::run_agent_server(
+            &identifier,
+        ));
+    }
+
     #[cfg(target_os = "windows")]
     {
         // 设置 WebView2 数据目录为程序所在目录下的 webview_data 文件夹
@@ -23,7 +32,7 @@ fn main() {
 
                 // 检测已缓存的 WebView2 固定版本运行时
                 // 验证目录包含关键文件以确保运行时完整可用
-                if let Ok(webview2_runtime_dir) = webview2::get_webview2_runtime_dir() {
+                if let Ok(webview2_runtime_dir) = mxu_lib::webview2::get_webview2_runtime_dir() {
                     if webview2_runtime_dir.is_dir()
                         && webview2_runtime_dir.join("msedgewebview2.exe").exists()
                     {
@@ -38,12 +47,13 @@ fn main() {
 
         // 已有本地运行时时跳过检测，否则检测系统安装或自动下载
         if std::env::var_os("WEBVIEW2_BROWSER_EXECUTABLE_FOLDER").is_none()
-            && !webview2::ensure_webview2()
+            && !mxu_lib::webview2::ensure_webview2()
         {
             std::process::exit(1);
         }
 
         // 启动时自动请求管理员权限：如果当前不是管理员，则自提权重启并退出当前进程
+        // 新进程沿用当前命令行参数（含 --autostart、--instance、深链接等）与工作目录
         // 说明：用户在 UAC 对话框中取消时，ShellExecuteEx 会返回 Err，此时继续以普通权限启动。
         // 调试模式下不请求管理员权限，方便开发调试
         if !cfg!(debug_assertions) && !mxu_lib::commands::system::is_elevated() {
@@ -59,8 +69,15 @@ fn main() {
             use winsafe::co::{SEE_MASK, SW};
             use winsafe::{ShellExecuteEx, SHELLEXECUTEINFO};
 
+            let parameters = mxu_lib::commands::system::elevated_restart_args(false);
+            let directory = std::env::current_dir()
+                .ok()
+                .map(|d| d.to_string_lossy().to_string());
+
             let result = ShellExecuteEx(&SHELLEXECUTEINFO {
                 file: &exe_path.to_string_lossy(),
+                parameters: (!parameters.is_empty()).then_some(parameters.as_str()),
+                directory: directory.as_deref(),
                 verb: Option::from("runas"),
                 show: SW::SHOWNORMAL,
                 mask: SEE_MASK::NOASYNC | SEE_MASK::FLAG_NO_UI,
@@ -6,9 +6,12 @@ use chrono::TimeZone;
 use log::{info, warn};
 use maa_framework::custom::FnAction;
 use maa_framework::resource::Resource;
+use parking_lot::Mutex;
 use serde::Serialize;
 use tauri::{AppHandle, Emitter};
 
+use crate::i18n::tr;
+
 // ============================================================================
 // MXU_SLEEP Custom Action
 // ============================================================================
@@ -42,8 +45,14 @@ fn request_tasker_stop(ctx: &maa_framework::context::Context) -> bool {
 }
 
 fn wait_with_stop_check(ctx: &maa_framework::context::Context, total_secs: u64) -> bool {
+    wait_duration_with_stop_check(ctx, std::time::Duration::from_secs(total_secs))
+}
+
+fn wait_duration_with_stop_check(
+    ctx: &maa_framework::context::Context,
+    total: std::time::Duration,
+) -> bool {
     const STEP: std::time::Duration = std::time::Duration::from_millis(200);
-    let total = std::time::Duration::from_secs(total_secs);
     let start = std::time::Instant::now();
 
     while start.elapsed() < total {
@@ -89,6 +98,93 @@ fn mxu_sleep_action_fn(
     true
 }
 
+// ============================================================================
+// MXU_RANDOM_DELAY Custom Action
+// ============================================================================
+
+/// MXU_RANDOM_DELAY 动作名称常量
+const MXU_RANDOM_DELAY_ACTION: &str = "MXU_RANDOM_DELAY_ACTION";
+
+/// MXU_RANDOM_DELAY 允许的最长延迟（秒）
+const MXU_RANDOM_DELAY_MAX_SECS: f64 = 86400.0;
+
+/// 在 [min, max] 内随机取一个时长（秒）
+///
+/// `gaussian` 为 true 时按正态分布取值（均值为区间中点，3σ 覆盖整个区间），
+/// 越界的样本截断到区间边界，使大部分延迟集中在中间附近。
+fn random_delay_secs(min: f64, max: f64, gaussian: bool) -> f64 {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    if max <= min {
+        return min;
+    }
+    if !gaussian {
+        return rng.gen_range(min..=max);
+    }
+    // Box-Muller 变换
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen();
+    let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+    let mean = (min + max) / 2.0;
+    let std_dev = (max - min) / 6.0;
+    (mean + z * std_dev).clamp(min, max)
+}
+
+/// MXU_RANDOM_DELAY custom action 回调函数
+/// 从 custom_action_param 中读取 min、max（秒，可为小数）和 gaussian（可选），
+/// 随机等待一段时间，用于模拟人工操作的节奏
+fn mxu_random_delay_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_RANDOM_DELAY] Received param: {}", param_str);
+
+    let json: serde_json::Value = match serde_json::from_str(param_str) {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("[MXU_RANDOM_DELAY] Failed to parse param JSON: {}", e);
+            return false;
+        }
+    };
+
+    let min = json.get("min").and_then(|v| v.as_f64()).unwrap_or(1.0);
+    let max = json.get("max").and_then(|v| v.as_f64()).unwrap_or(min);
+    if !min.is_finite()
+        || !max.is_finite()
+        || min < 0.0
+        || max < min
+        || max > MXU_RANDOM_DELAY_MAX_SECS
+    {
+        warn!("[MXU_RANDOM_DELAY] Invalid range: min={}, max={}", min, max);
+        return false;
+    }
+    let gaussian = json
+        .get("gaussian")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let secs = random_delay_secs(min, max, gaussian);
+    info!(
+        "[MXU_RANDOM_DELAY] Sleeping for {:.3} seconds (range {}-{}, gaussian={})",
+        secs, min, max, gaussian
+    );
+
+    let duration = match std::time::Duration::try_from_secs_f64(secs) {
+        Ok(d) => d,
+        Err(e) => {
+            warn!("[MXU_RANDOM_DELAY] Invalid delay {}: {}", secs, e);
+            return false;
+        }
+    };
+    if !wait_duration_with_stop_check(ctx, duration) {
+        warn!("[MXU_RANDOM_DELAY] Interrupted by stop request");
+        return false;
+    }
+    true
+}
+
 // ============================================================================
 // MXU_WAITUNTIL Custom Action
 // ============================================================================
@@ -378,10 +474,13 @@ fn mxu_webhook_action_fn(
 const MXU_NOTIFY_ACTION: &str = "MXU_NOTIFY_ACTION";
 
 /// MXU_NOTIFY custom action 回调函数
-/// 从 custom_action_param 中读取 title, body，发送系统通知
+/// 从 custom_action_param 中读取 title, body, actions（可选，如 ["open_log", "stop_tasks"]），
+/// 发送系统通知；Windows 下点击按钮会回调到后端执行对应操作
 fn mxu_notify_action_fn(
     _ctx: &maa_framework::context::Context,
     args: &maa_framework::custom::ActionArgs,
+    app_handle: &AppHandle,
+    instance_id: &str,
 ) -> bool {
     let param_str = args.param;
     info!("[MXU_NOTIFY] Received param: {}", param_str);
@@ -406,16 +505,25 @@ fn mxu_notify_action_fn(
         .unwrap_or("")
         .to_string();
 
+    let actions: Vec<crate::notification::NotificationAction> = json
+        .get("actions")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|a| a.as_str())
+                .filter_map(|name| {
+                    crate::notification::NotificationAction::from_name(name, Some(instance_id))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     info!(
-        "[MXU_NOTIFY] Sending notification: title={}, body={}",
-        title, body
+        "[MXU_NOTIFY] Sending notification: title={}, body={}, actions={:?}",
+        title, body, actions
     );
 
-    match notify_rust::Notification::new()
-        .summary(&title)
-        .body(&body)
-        .show()
-    {
+    match crate::notification::show(app_handle, &title, &body, &actions) {
         Ok(_) => {
             info!("[MXU_NOTIFY] Notification sent successfully");
             true
@@ -608,6 +716,7 @@ fn mxu_power_action_fn(
         "restart" => execute_power_restart(),
         "screenoff" => execute_power_screenoff(),
         "sleep" => execute_power_sleep(),
+        "hibernate" => execute_power_hibernate(),
         _ => {
             warn!("[MXU_POWER] Unknown power action: {}", action);
             false
@@ -615,7 +724,7 @@ fn mxu_power_action_fn(
     }
 }
 
-fn execute_power_shutdown() -> bool {
+pub(crate) fn execute_power_shutdown() -> bool {
     use std::process::Command;
 
     #[cfg(windows)]
@@ -670,7 +779,7 @@ fn execute_power_shutdown() -> bool {
     }
 }
 
-fn execute_power_restart() -> bool {
+pub(crate) fn execute_power_restart() -> bool {
     use std::process::Command;
 
     #[cfg(windows)]
@@ -781,7 +890,7 @@ fn execute_power_screenoff() -> bool {
     }
 }
 
-fn execute_power_sleep() -> bool {
+pub(crate) fn execute_power_sleep() -> bool {
     use std::process::Command;
 
     #[cfg(windows)]
@@ -833,10 +942,1025 @@ fn execute_power_sleep() -> bool {
     }
 }
 
+pub(crate) fn execute_power_hibernate() -> bool {
+    use std::process::Command;
+
+    #[cfg(windows)]
+    let mut cmd = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        let mut cmd = Command::new("shutdown");
+        cmd.args(["/h"]).creation_flags(CREATE_NO_WINDOW);
+        cmd
+    };
+
+    // macOS 没有独立的休眠命令，按 hibernatemode 设置进入睡眠
+    #[cfg(target_os = "macos")]
+    let mut cmd = {
+        let mut cmd = Command::new("pmset");
+        cmd.arg("sleepnow");
+        cmd
+    };
+
+    #[cfg(not(any(windows, target_os = "macos")))]
+    let mut cmd = {
+        let mut cmd = Command::new("systemctl");
+        cmd.arg("hibernate");
+        cmd
+    };
+
+    match cmd.spawn() {
+        Ok(_) => {
+            info!("[MXU_POWER] Hibernate command issued");
+            true
+        }
+        Err(e) => {
+            log::error!("[MXU_POWER] Hibernate failed: {}", e);
+            false
+        }
+    }
+}
+
+// ============================================================================
+// MXU_WAKE Custom Action
+// ============================================================================
+
+/// MXU_WAKE 动作名称常量
+const MXU_WAKE_ACTION: &str = "MXU_WAKE_ACTION";
+
+/// 唤醒后默认等待时长（毫秒），给显示器与截图管线恢复的时间
+const WAKE_DEFAULT_WAIT_MS: u64 = 1500;
+
+/// MXU_WAKE custom action 回调函数
+/// 唤醒显示器并退出屏幕保护程序（显示器关闭时 DXGI 截图会返回黑屏），
+/// 可选参数 wait_ms 指定唤醒后等待时长
+fn mxu_wake_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_WAKE] Received param: {}", param_str);
+
+    let wait_ms = serde_json::from_str::<serde_json::Value>(param_str)
+        .ok()
+        .and_then(|v| v.get("wait_ms").and_then(|w| w.as_u64()))
+        .unwrap_or(WAKE_DEFAULT_WAIT_MS);
+
+    if !execute_wake_display() {
+        return false;
+    }
+
+    // 分段等待，期间响应停止请求
+    let deadline = std::time::Instant::now() + std::time::Duration::from_millis(wait_ms);
+    while std::time::Instant::now() < deadline {
+        if is_tasker_stopping(ctx) {
+            info!("[MXU_WAKE] Stop requested, aborting wait");
+            return false;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    true
+}
+
+/// 唤醒显示器并重置系统空闲计时（退出屏幕保护程序）
+pub(crate) fn execute_wake_display() -> bool {
+    #[cfg(windows)]
+    {
+        extern "system" {
+            fn SetThreadExecutionState(es_flags: u32) -> u32;
+            fn PostMessageW(hwnd: isize, msg: u32, wparam: usize, lparam: isize) -> i32;
+            fn mouse_event(flags: u32, dx: i32, dy: i32, data: u32, extra_info: usize);
+        }
+        const ES_DISPLAY_REQUIRED: u32 = 0x0000_0002;
+        const ES_SYSTEM_REQUIRED: u32 = 0x0000_0001;
+        const HWND_BROADCAST: isize = 0xFFFF;
+        const WM_SYSCOMMAND: u32 = 0x0112;
+        const SC_MONITORPOWER: usize = 0xF170;
+        const MOUSEEVENTF_MOVE: u32 = 0x0001;
+
+        unsafe {
+            // 不带 ES_CONTINUOUS：仅重置一次显示器与系统空闲计时
+            SetThreadExecutionState(ES_DISPLAY_REQUIRED | ES_SYSTEM_REQUIRED);
+            // lParam = -1 表示打开显示器
+            PostMessageW(HWND_BROADCAST, WM_SYSCOMMAND, SC_MONITORPOWER, -1);
+            // 模拟一次零位移鼠标输入，退出屏幕保护程序
+            mouse_event(MOUSEEVENTF_MOVE, 0, 0, 0, 0);
+        }
+        info!("[MXU_WAKE] Display wake issued (Windows)");
+        true
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use std::process::Command;
+        // -u 声明用户活动，会点亮显示器并退出屏保
+        match Command::new("caffeinate").args(["-u", "-t", "2"]).spawn() {
+            Ok(_) => {
+                info!("[MXU_WAKE] Display wake issued (macOS)");
+                true
+            }
+            Err(e) => {
+                log::error!("[MXU_WAKE] Display wake failed: {}", e);
+                false
+            }
+        }
+    }
+
+    #[cfg(not(any(windows, target_os = "macos")))]
+    {
+        use std::env;
+        use std::process::Command;
+        if let Ok(value) = env::var("XDG_SESSION_TYPE") {
+            if value == "wayland" {
+                log::error!("[MXU_WAKE] Display wake on Wayland is not available");
+                return false;
+            }
+        }
+        let dpms_ok = Command::new("xset")
+            .args(["dpms", "force", "on"])
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+        let _ = Command::new("xset").args(["s", "reset"]).status();
+        if dpms_ok {
+            info!("[MXU_WAKE] Display wake issued (Linux)");
+        } else {
+            log::error!("[MXU_WAKE] Display wake failed");
+        }
+        dpms_ok
+    }
+}
+
+// ============================================================================
+// MXU_ASSERT Custom Action
+// ============================================================================
+
+/// MXU_ASSERT 动作名称常量
+const MXU_ASSERT_ACTION: &str = "MXU_ASSERT_ACTION";
+
+/// 断言失败事件名
+const MXU_ASSERT_FAILED_EVENT: &str = "mxu-assert-failed";
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AssertFailedEvent {
+    instance_id: String,
+    node_name: String,
+    message: String,
+}
+
+/// 读取当前节点的识别结果：`{ "hit": bool, "detail": 算法输出 }`
+fn assert_reco_value(app_handle: &AppHandle, instance_id: &str, reco_id: i64) -> serde_json::Value {
+    use tauri::Manager;
+
+    let detail = app_handle
+        .try_state::<std::sync::Arc<crate::commands::MaaState>>()
+        .and_then(|state| {
+            let tasker = state.instances.get(instance_id)?.tasker.clone()?;
+            tasker.get_recognition_detail(reco_id).ok().flatten()
+        });
+    match detail {
+        Some(d) => serde_json::json!({
+            "hit": d.hit,
+            "detail": serde_json::from_str::<serde_json::Value>(&d.detail.to_string())
+                .unwrap_or(serde_json::Value::Null),
+        }),
+        None => serde_json::Value::Null,
+    }
+}
+
+/// 比较两个 JSON 值（数字按数值比较，其余仅支持相等判断）
+fn assert_compare(
+    actual: Option<&serde_json::Value>,
+    op: &str,
+    expected: &serde_json::Value,
+) -> Result<bool, String> {
+    let ord = || -> Result<std::cmp::Ordering, String> {
+        let (Some(a), Some(b)) = (actual.and_then(|v| v.as_f64()), expected.as_f64()) else {
+            return Err(tr!(
+                "error.expr.not_numeric",
+                op = op,
+                actual = format!("{:?}", actual)
+            ));
+        };
+        a.partial_cmp(&b).ok_or_else(|| tr!("error.expr.nan"))
+    };
+    Ok(match op {
+        "exists" => actual.is_some_and(|v| !v.is_null()),
+        "not_exists" => actual.is_none_or(|v| v.is_null()),
+        "eq" | "==" => actual == Some(expected),
+        "ne" | "!=" => actual != Some(expected),
+        "gt" | ">" => ord()?.is_gt(),
+        "ge" | ">=" => ord()?.is_ge(),
+        "lt" | "<" => ord()?.is_lt(),
+        "le" | "<=" => ord()?.is_le(),
+        "contains" => match (actual, expected) {
+            (Some(serde_json::Value::String(a)), serde_json::Value::String(b)) => {
+                a.contains(b.as_str())
+            }
+            (Some(serde_json::Value::Array(a)), b) => a.contains(b),
+            _ => false,
+        },
+        other => return Err(tr!("error.expr.unknown_operator", op = other)),
+    })
+}
+
+/// 求值断言条件，不满足时返回描述信息
+///
+/// 条件格式：
+/// - `{ "var": "name", "op": "ge", "value": 3 }`：比较变量存储中的值
+/// - `{ "reco": "/detail/best/score", "op": "ge", "value": 0.8 }`：比较识别结果（JSON Pointer）
+/// - `{ "all": [...] }` / `{ "any": [...] }` / `{ "not": {...} }`：组合条件
+///
+/// `op` 省略时：有 `value` 为 `eq`，否则为 `exists`。
+fn assert_eval(
+    cond: &serde_json::Value,
+    instance_id: &str,
+    reco: &mut dyn FnMut() -> serde_json::Value,
+) -> Result<(), String> {
+    if let Some(list) = cond.get("all").and_then(|v| v.as_array()) {
+        return list
+            .iter()
+            .try_for_each(|c| assert_eval(c, instance_id, reco));
+    }
+    if let Some(list) = cond.get("any").and_then(|v| v.as_array()) {
+        let mut errors = Vec::new();
+        for c in list {
+            match assert_eval(c, instance_id, reco) {
+                Ok(()) => return Ok(()),
+                Err(e) => errors.push(e),
+            }
+        }
+        return Err(tr!("error.action.any_failed", errors = errors.join("; ")));
+    }
+    if let Some(inner) = cond.get("not") {
+        return match assert_eval(inner, instance_id, reco) {
+            Ok(()) => Err(tr!("error.action.not_failed", condition = inner)),
+            Err(_) => Ok(()),
+        };
+    }
+
+    let expected = cond
+        .get("value")
+        .cloned()
+        .unwrap_or(serde_json::Value::Null);
+    let op = cond
+        .get("op")
+        .and_then(|v| v.as_str())
+        .unwrap_or(if cond.get("value").is_some() {
+            "eq"
+        } else {
+            "exists"
+        });
+
+    let (label, actual) = if let Some(name) = cond.get("var").and_then(|v| v.as_str()) {
+        (
+            tr!("error.action.label_var", name = name),
+            crate::commands::variables::get(instance_id, name),
+        )
+    } else if let Some(pointer) = cond.get("reco").and_then(|v| v.as_str()) {
+        let value = reco();
+        let pointer = if pointer.is_empty() || pointer.starts_with('/') {
+            pointer.to_string()
+        } else {
+            format!("/{}", pointer)
+        };
+        (
+            tr!("error.action.label_reco", pointer = pointer),
+            value.pointer(&pointer).cloned(),
+        )
+    } else {
+        return Err(tr!("error.action.invalid_condition", condition = cond));
+    };
+
+    if assert_compare(actual.as_ref(), op, &expected)? {
+        Ok(())
+    } else {
+        Err(tr!(
+            "error.action.assert_failed",
+            label = label,
+            op = op,
+            expected = expected,
+            actual = actual
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| tr!("error.action.no_value"))
+        ))
+    }
+}
+
+/// MXU_ASSERT custom action 回调函数
+/// 从 custom_action_param 中读取 condition、message（可选）、stop（可选，失败时停止任务），
+/// 条件不满足时动作失败，并发射 `mxu-assert-failed` 事件
+fn mxu_assert_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+    app_handle: &AppHandle,
+    instance_id: &str,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_ASSERT] Received param: {}", param_str);
+
+    let json: serde_json::Value = match serde_json::from_str(param_str) {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("[MXU_ASSERT] Failed to parse param JSON: {}", e);
+            return false;
+        }
+    };
+    let Some(condition) = json.get("condition") else {
+        warn!("[MXU_ASSERT] Missing 'condition' parameter");
+        return false;
+    };
+
+    // 识别结果按需读取，且只读取一次
+    let reco_id = args.reco_id;
+    let mut reco_cache: Option<serde_json::Value> = None;
+    let mut reco = || {
+        reco_cache
+            .get_or_insert_with(|| assert_reco_value(app_handle, instance_id, reco_id))
+            .clone()
+    };
+
+    let Err(reason) = assert_eval(condition, instance_id, &mut reco) else {
+        info!("[MXU_ASSERT] Assertion passed");
+        return true;
+    };
+
+    let message = match json.get("message").and_then(|v| v.as_str()) {
+        Some(m) if !m.is_empty() => format!("{}: {}", m, reason),
+        _ => reason,
+    };
+    warn!(
+        "[MXU_ASSERT] Assertion failed at node {}: {}",
+        args.node_name, message
+    );
+    let event = AssertFailedEvent {
+        instance_id: instance_id.to_string(),
+        node_name: args.node_name.to_string(),
+        message,
+    };
+    if let Err(e) = app_handle.emit(MXU_ASSERT_FAILED_EVENT, event) {
+        warn!("[MXU_ASSERT] Failed to emit event: {}", e);
+    }
+
+    if json.get("stop").and_then(|v| v.as_bool()).unwrap_or(false) {
+        request_tasker_stop(ctx);
+    }
+    false
+}
+
+// ============================================================================
+// MXU_COUNTER Custom Action
+// ============================================================================
+
+/// MXU_COUNTER 动作名称常量
+const MXU_COUNTER_ACTION: &str = "MXU_COUNTER_ACTION";
+
+/// 计数器当前周期的标识（周期内不变，跨过重置时间点后改变）
+///
+/// - `daily`：每天 `reset_hour` 点重置
+/// - `weekly`：每周 `reset_weekday`（1 = 周一 … 7 = 周日）的 `reset_hour` 点重置
+///
+/// `now` 为服务器时区下的当前时间。
+fn counter_period_key(
+    reset: &str,
+    reset_hour: u32,
+    reset_weekday: u32,
+    now: chrono::DateTime<chrono::FixedOffset>,
+) -> Option<String> {
+    use chrono::Datelike;
+
+    // 将时间向前平移 reset_hour 小时，使重置时间点落在 0 点
+    let shifted = (now - chrono::Duration::hours(reset_hour as i64)).date_naive();
+    match reset {
+        "daily" => Some(shifted.to_string()),
+        "weekly" => {
+            let start_day = reset_weekday.clamp(1, 7) - 1;
+            let offset = (shifted.weekday().num_days_from_monday() + 7 - start_day) % 7;
+            Some((shifted - chrono::Duration::days(offset as i64)).to_string())
+        }
+        _ => None,
+    }
+}
+
+/// MXU_COUNTER custom action 回调函数
+/// 从 custom_action_param 中读取 name、op（inc / dec / reset，默认 inc）、step（默认 1）、
+/// max / min（可选上下限）、reset（none / daily / weekly）、reset_hour、reset_weekday（默认 1）。
+/// 重置时间使用实例的服务器重置时钟（`instances[].serverReset`），reset_hour 可覆盖其整点。
+/// 计数器保存在实例变量存储中；达到上限（或下限）时动作失败，便于 pipeline 停止刷取
+fn mxu_counter_action_fn(
+    _ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+    _app_handle: &AppHandle,
+    instance_id: &str,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_COUNTER] Received param: {}", param_str);
+
+    let json: serde_json::Value = match serde_json::from_str(param_str) {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("[MXU_COUNTER] Failed to parse param JSON: {}", e);
+            return false;
+        }
+    };
+    let Some(name) = json
+        .get("name")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.trim().is_empty())
+    else {
+        warn!("[MXU_COUNTER] Missing or empty 'name' parameter");
+        return false;
+    };
+    let op = json.get("op").and_then(|v| v.as_str()).unwrap_or("inc");
+    if !matches!(op, "inc" | "dec" | "reset") {
+        warn!("[MXU_COUNTER] Unknown op '{}' for counter {}", op, name);
+        return false;
+    }
+    let step = json.get("step").and_then(|v| v.as_i64()).unwrap_or(1);
+    let max = json.get("max").and_then(|v| v.as_i64());
+    let min = json.get("min").and_then(|v| v.as_i64());
+    let reset = json.get("reset").and_then(|v| v.as_str()).unwrap_or("none");
+    let clock = crate::commands::server_reset::for_instance(instance_id);
+    let reset_hour = json
+        .get("reset_hour")
+        .and_then(|v| v.as_u64())
+        .map_or(clock.hour, |h| h.min(23) as u32);
+    let reset_weekday = json
+        .get("reset_weekday")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1) as u32;
+
+    // 周期变化时先清零
+    let period_var = format!("{}.__period", name);
+    if let Some(period) = counter_period_key(reset, reset_hour, reset_weekday, clock.now()) {
+        let stored = crate::commands::variables::get(instance_id, &period_var);
+        if stored.as_ref().and_then(|v| v.as_str()) != Some(period.as_str()) {
+            info!(
+                "[MXU_COUNTER] Counter {} reset for new period {}",
+                name, period
+            );
+            let result = crate::commands::variables::set(instance_id, name, Some(0.into()))
+                .and_then(|_| {
+                    crate::commands::variables::set(instance_id, &period_var, Some(period.into()))
+                });
+            if let Err(e) = result {
+                warn!("[MXU_COUNTER] Failed to reset counter: {}", e);
+                return false;
+            }
+        }
+    }
+
+    let mut limit_reached = false;
+    let mut not_integer = false;
+    let mut overflow = false;
+    let result = crate::commands::variables::update(instance_id, name, |current| {
+        let value = match current {
+            None => 0,
+            Some(v) => match v.as_i64() {
+                Some(value) => value,
+                None => {
+                    // 保留原值，不覆盖用户写入的非整数变量
+                    not_integer = true;
+                    return Some(v.clone());
+                }
+            },
+        };
+        let next = match op {
+            "inc" => {
+                if max.is_some_and(|m| value >= m) {
+                    limit_reached = true;
+                    value
+                } else if let Some(next) = value.checked_add(step) {
+                    max.map_or(next, |m| next.min(m))
+                } else {
+                    // 溢出时保留原值，动作失败
+                    overflow = true;
+                    value
+                }
+            }
+            "dec" => {
+                if min.is_some_and(|m| value <= m) {
+                    limit_reached = true;
+                    value
+                } else if let Some(next) = value.checked_sub(step) {
+                    min.map_or(next, |m| next.max(m))
+                } else {
+                    overflow = true;
+                    value
+                }
+            }
+            // reset（op 已在前面校验）
+            _ => 0,
+        };
+        Some(next.into())
+    });
+
+    match result {
+        Ok(value) if not_integer => {
+            warn!(
+                "[MXU_COUNTER] Counter {} holds a non-integer value {:?}, not updated",
+                name, value
+            );
+            false
+        }
+        Ok(value) if overflow => {
+            warn!(
+                "[MXU_COUNTER] Counter {} would overflow (value={:?}, op={}, step={}), not updated",
+                name, value, op, step
+            );
+            false
+        }
+        Ok(value) => {
+            let value = value.and_then(|v| v.as_i64()).unwrap_or(0);
+            if limit_reached {
+                info!(
+                    "[MXU_COUNTER] Counter {} reached its limit ({}), failing",
+                    name, value
+                );
+                false
+            } else {
+                info!("[MXU_COUNTER] Counter {} = {} (op={})", name, value, op);
+                true
+            }
+        }
+        Err(e) => {
+            warn!("[MXU_COUNTER] Failed to update counter {}: {}", name, e);
+            false
+        }
+    }
+}
+
+// ============================================================================
+// MXU_REPORT_RESULT Custom Action
+// ============================================================================
+
+/// MXU_REPORT_RESULT 动作名称常量
+const MXU_REPORT_RESULT_ACTION: &str = "MXU_REPORT_RESULT_ACTION";
+
+/// MXU_REPORT_RESULT custom action 回调函数
+/// 从 custom_action_param 中读取 key（必填）、value（任意 JSON，缺省时为整个参数）、
+/// task（可选，缺省为当前执行的任务入口），写入结构化结果记录
+fn mxu_report_result_action_fn(
+    _ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+    app_handle: &AppHandle,
+    instance_id: &str,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_REPORT_RESULT] Received param: {}", param_str);
+
+    let json: serde_json::Value = match serde_json::from_str(param_str) {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("[MXU_REPORT_RESULT] Failed to parse param JSON: {}", e);
+            return false;
+        }
+    };
+    let Some(key) = json
+        .get("key")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.trim().is_empty())
+    else {
+        warn!("[MXU_REPORT_RESULT] Missing or empty 'key' parameter");
+        return false;
+    };
+    let task = json
+        .get("task")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    let value = json.get("value").cloned().unwrap_or_else(|| json.clone());
+
+    match crate::commands::results::record(
+        app_handle,
+        instance_id,
+        task,
+        args.node_name,
+        key,
+        value,
+    ) {
+        Ok(record) => {
+            info!(
+                "[MXU_REPORT_RESULT] Recorded {} for task {:?} at node {}",
+                key, record.task, record.node
+            );
+            true
+        }
+        Err(e) => {
+            warn!("[MXU_REPORT_RESULT] Failed to record result: {}", e);
+            false
+        }
+    }
+}
+
+// ============================================================================
+// MXU_CLIPBOARD Custom Action
+// ============================================================================
+
+/// MXU_CLIPBOARD 动作名称常量
+const MXU_CLIPBOARD_ACTION: &str = "MXU_CLIPBOARD_ACTION";
+
+/// 读取主机剪贴板文本
+pub(crate) fn clipboard_get_text() -> Result<String, String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| tr!("error.action.clipboard_open", error = e))?;
+    clipboard
+        .get_text()
+        .map_err(|e| tr!("error.action.clipboard_read", error = e))
+}
+
+/// 写入主机剪贴板文本
+pub(crate) fn clipboard_set_text(text: &str) -> Result<(), String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| tr!("error.action.clipboard_open", error = e))?;
+    clipboard
+        .set_text(text.to_string())
+        .map_err(|e| tr!("error.action.clipboard_write", error = e))
+}
+
+/// 通过实例控制器输入文本
+fn clipboard_input_text(
+    app_handle: &AppHandle,
+    instance_id: &str,
+    text: &str,
+) -> Result<(), String> {
+    use tauri::Manager;
+
+    let state = app_handle
+        .try_state::<std::sync::Arc<crate::commands::MaaState>>()
+        .ok_or_else(|| tr!("error.action.state_unavailable"))?;
+    let controller = state
+        .instances
+        .get(instance_id)
+        .and_then(|i| i.controller.clone())
+        .ok_or_else(|| tr!("error.controller_not_connected"))?;
+    controller
+        .post_input_text(text)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// MXU_CLIPBOARD custom action 回调函数
+/// 从 custom_action_param 中读取 mode（copy / paste，默认 copy）与 text（可选）。
+/// - copy：将 text 写入主机剪贴板；未提供 text 时复制当前节点的 OCR 识别文本
+/// - paste：读取主机剪贴板，通过控制器 input_text 输入到游戏中
+fn mxu_clipboard_action_fn(
+    _ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+    app_handle: &AppHandle,
+    instance_id: &str,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_CLIPBOARD] Received param: {}", param_str);
+
+    let json: serde_json::Value = serde_json::from_str(param_str).unwrap_or_default();
+    let mode = json.get("mode").and_then(|v| v.as_str()).unwrap_or("copy");
+
+    let result = match mode {
+        "copy" => {
+            let text = match json.get("text").and_then(|v| v.as_str()) {
+                Some(t) => Some(t.to_string()),
+                None => assert_reco_value(app_handle, instance_id, args.reco_id)
+                    .pointer("/detail/best/text")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string()),
+            };
+            match text {
+                Some(t) if !t.is_empty() => clipboard_set_text(&t).map(|_| {
+                    info!("[MXU_CLIPBOARD] Copied {} chars", t.chars().count());
+                }),
+                _ => Err(tr!("error.action.nothing_to_copy")),
+            }
+        }
+        "paste" => clipboard_get_text().and_then(|t| {
+            if t.is_empty() {
+                return Err(tr!("error.action.clipboard_empty"));
+            }
+            clipboard_input_text(app_handle, instance_id, &t).map(|_| {
+                info!("[MXU_CLIPBOARD] Pasted {} chars", t.chars().count());
+            })
+        }),
+        other => Err(tr!("error.action.unknown_mode", mode = other)),
+    };
+
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            warn!("[MXU_CLIPBOARD] {}", e);
+            false
+        }
+    }
+}
+
+// ============================================================================
+// MXU_SOUND Custom Action
+// ============================================================================
+
+/// MXU_SOUND 动作名称常量
+const MXU_SOUND_ACTION: &str = "MXU_SOUND_ACTION";
+
+/// 播放音频，`file` 为 None 时播放内置提示音；`should_stop` 返回 true 时中止播放
+fn play_sound(
+    file: Option<&std::path::Path>,
+    volume: f32,
+    repeat: u32,
+    should_stop: &dyn Fn() -> bool,
+) -> Result<(), String> {
+    use rodio::Source;
+    use std::time::Duration;
+
+    let (_stream, handle) = rodio::OutputStream::try_default()
+        .map_err(|e| tr!("error.action.audio_device", error = e))?;
+    let sink =
+        rodio::Sink::try_new(&handle).map_err(|e| tr!("error.action.audio_player", error = e))?;
+    sink.set_volume(volume);
+
+    for _ in 0..repeat.max(1) {
+        match file {
+            Some(path) => {
+                let f = std::fs::File::open(path)
+                    .map_err(|e| tr!("error.fs.open_file", path = path.display(), error = e))?;
+                let source = rodio::Decoder::new(std::io::BufReader::new(f)).map_err(|e| {
+                    tr!(
+                        "error.action.audio_decode",
+                        path = path.display(),
+                        error = e
+                    )
+                })?;
+                sink.append(source);
+            }
+            None => {
+                // 内置提示音：三声短促的 880Hz 蜂鸣
+                for _ in 0..3 {
+                    sink.append(
+                        rodio::source::SineWave::new(880.0)
+                            .take_duration(Duration::from_millis(180))
+                            .amplify(0.4),
+                    );
+                    sink.append(
+                        rodio::source::Zero::<f32>::new(1, 48000)
+                            .take_duration(Duration::from_millis(120)),
+                    );
+                }
+            }
+        }
+    }
+
+    while !sink.empty() {
+        if should_stop() {
+            sink.stop();
+            return Err(tr!("error.action.audio_stopped"));
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    Ok(())
+}
+
+/// MXU_SOUND custom action 回调函数
+/// 从 custom_action_param 中读取 file（可选，相对路径基于程序所在目录，省略时播放内置提示音）、
+/// volume（0.0 ~ 1.0，默认 1.0）、repeat（播放次数，默认 1）、wait（是否等待播放结束，默认 true）
+fn mxu_sound_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+) -> bool {
+    let param_str = args.param;
+    info!("[MXU_SOUND] Received param: {}", param_str);
+
+    let json: serde_json::Value = serde_json::from_str(param_str).unwrap_or_default();
+    let volume = json
+        .get("volume")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(1.0)
+        .clamp(0.0, 1.0) as f32;
+    let repeat = json.get("repeat").and_then(|v| v.as_u64()).unwrap_or(1) as u32;
+    let wait = json.get("wait").and_then(|v| v.as_bool()).unwrap_or(true);
+
+    let file = match json.get("file").and_then(|v| v.as_str()) {
+        Some(f) if !f.is_empty() => {
+            let path = std::path::PathBuf::from(f);
+            let path = if path.is_absolute() {
+                path
+            } else {
+                match crate::commands::utils::get_exe_directory() {
+                    Ok(dir) => {
+                        crate::commands::utils::normalize_path(&dir.join(path).to_string_lossy())
+                    }
+                    Err(e) => {
+                        warn!("[MXU_SOUND] {}", e);
+                        return false;
+                    }
+                }
+            };
+            if !path.exists() {
+                warn!("[MXU_SOUND] Audio file not found: {}", path.display());
+                return false;
+            }
+            Some(path)
+        }
+        _ => None,
+    };
+
+    if !wait {
+        // 后台播放，不阻塞 pipeline
+        std::thread::spawn(move || {
+            if let Err(e) = play_sound(file.as_deref(), volume, repeat, &|| false) {
+                warn!("[MXU_SOUND] {}", e);
+            }
+        });
+        return true;
+    }
+
+    match play_sound(file.as_deref(), volume, repeat, &|| is_tasker_stopping(ctx)) {
+        Ok(()) => {
+            info!("[MXU_SOUND] Playback finished");
+            true
+        }
+        Err(e) => {
+            warn!("[MXU_SOUND] {}", e);
+            false
+        }
+    }
+}
+
+// ============================================================================
+// MXU_WAIT_FOR_USER Custom Action
+// ============================================================================
+
+/// MXU_WAIT_FOR_USER 动作名称常量
+const MXU_WAIT_FOR_USER_ACTION: &str = "MXU_WAIT_FOR_USER_ACTION";
+
+/// 需要人工介入事件名
+const MXU_INTERVENTION_REQUIRED_EVENT: &str = "maa-intervention-required";
+
+/// 默认等待超时（秒）
+const WAIT_FOR_USER_DEFAULT_TIMEOUT_SECS: u64 = 600;
+
+/// 等待确认的介入请求：token -> 是否已确认
+static PENDING_INTERVENTIONS: Mutex<Option<std::collections::HashMap<String, bool>>> =
+    Mutex::new(None);
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InterventionRequiredEvent {
+    instance_id: String,
+    node_name: String,
+    token: String,
+    message: String,
+    /// 当前截图（data URL），未请求或不可用时为 None
+    screenshot: Option<String>,
+    timeout_secs: u64,
+}
+
+/// 确认介入请求，token 不存在（已超时或已确认）时返回 false
+pub(crate) fn confirm_intervention(token: &str) -> bool {
+    let mut guard = PENDING_INTERVENTIONS.lock();
+    match guard.as_mut().and_then(|m| m.get_mut(token)) {
+        Some(confirmed) => {
+            *confirmed = true;
+            true
+        }
+        None => false,
+    }
+}
+
+/// 取出介入请求的确认状态（同时移除记录）
+fn take_intervention(token: &str) -> bool {
+    PENDING_INTERVENTIONS
+        .lock()
+        .as_mut()
+        .and_then(|m| m.remove(token))
+        .unwrap_or(false)
+}
+
+/// MXU_WAIT_FOR_USER custom action 回调函数
+/// 从 custom_action_param 中读取 message、screenshot（是否附带当前截图，默认 true）、
+/// timeout（秒，默认 600，0 表示不超时）。发射 `maa-intervention-required` 事件后阻塞，
+/// 直到前端调用 `mxu_confirm_intervention(token)`；超时或任务停止时动作失败
+fn mxu_wait_for_user_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+    app_handle: &AppHandle,
+    instance_id: &str,
+) -> bool {
+    use std::time::{Duration, Instant};
+
+    let param_str = args.param;
+    info!("[MXU_WAIT_FOR_USER] Received param: {}", param_str);
+
+    let json: serde_json::Value = serde_json::from_str(param_str).unwrap_or_default();
+    let message = json
+        .get("message")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let with_screenshot = json
+        .get("screenshot")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    let timeout_secs = json
+        .get("timeout")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(WAIT_FOR_USER_DEFAULT_TIMEOUT_SECS);
+
+    let screenshot = if with_screenshot {
+        use tauri::Manager;
+        app_handle
+            .try_state::<std::sync::Arc<crate::commands::MaaState>>()
+            .and_then(|state| {
+                crate::commands::maa_core::get_cached_image_impl(&state, instance_id).ok()
+            })
+    } else {
+        None
+    };
+
+    let token = format!("{}-{:016x}", instance_id, rand::random::<u64>());
+    PENDING_INTERVENTIONS
+        .lock()
+        .get_or_insert_with(std::collections::HashMap::new)
+        .insert(token.clone(), false);
+
+    let event = InterventionRequiredEvent {
+        instance_id: instance_id.to_string(),
+        node_name: args.node_name.to_string(),
+        token: token.clone(),
+        message,
+        screenshot,
+        timeout_secs,
+    };
+    if let Err(e) = app_handle.emit(MXU_INTERVENTION_REQUIRED_EVENT, event) {
+        warn!("[MXU_WAIT_FOR_USER] Failed to emit event: {}", e);
+        take_intervention(&token);
+        return false;
+    }
+    info!(
+        "[MXU_WAIT_FOR_USER] Waiting for user confirmation (token={}, timeout={}s)",
+        token, timeout_secs
+    );
+
+    let deadline = (timeout_secs > 0).then(|| Instant::now() + Duration::from_secs(timeout_secs));
+    loop {
+        let confirmed = PENDING_INTERVENTIONS
+            .lock()
+            .as_ref()
+            .and_then(|m| m.get(&token).copied())
+            .unwrap_or(false);
+        if confirmed {
+            take_intervention(&token);
+            info!("[MXU_WAIT_FOR_USER] Confirmed by user");
+            return true;
+        }
+        if is_tasker_stopping(ctx) {
+            take_intervention(&token);
+            info!("[MXU_WAIT_FOR_USER] Stop requested, aborting wait");
+            return false;
+        }
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            // 超时前的最后一刻可能刚好被确认
+            let confirmed = take_intervention(&token);
+            if !confirmed {
+                warn!("[MXU_WAIT_FOR_USER] Timed out after {}s", timeout_secs);
+            }
+            return confirmed;
+        }
+        std::thread::sleep(Duration::from_millis(200));
+    }
+}
+
+// ============================================================================
+// MXU_QUICK_RECO Custom Action（内部使用）
+// ============================================================================
+
+/// 识别测试动作名称常量（由 `authoring::run_quick_recognition` 的临时任务使用）
+pub(crate) const MXU_QUICK_RECO_ACTION: &str = "MXU_QUICK_RECO_ACTION";
+
+/// MXU_QUICK_RECO custom action 回调函数
+/// 从 custom_action_param 中读取 token，执行对应的识别请求
+fn mxu_quick_reco_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+) -> bool {
+    crate::commands::authoring::run_quick_recognition_in_context(ctx, args.param)
+}
+
 // ============================================================================
 // 注册入口
 // ============================================================================
 
+/// 执行 custom action 回调，捕获 panic 并记录日志（panic 视为动作失败）
+fn catch_action_panic(name: &str, f: impl FnOnce() -> bool) -> bool {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|e| {
+        let msg = if let Some(s) = e.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = e.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Unknown panic payload".to_string()
+        };
+        log::error!("[MXU] Custom action {} panicked: {}", name, msg);
+        false
+    })
+}
+
 /// 为资源注册所有 MXU 内置 custom actions
 /// 在资源创建后调用此函数
 pub fn register_all_mxu_actions(
@@ -851,20 +1975,7 @@ pub fn register_all_mxu_actions(
         ($name:expr, $fn_name:expr) => {
             let wrapper = move |ctx: &maa_framework::context::Context,
                                 args: &maa_framework::custom::ActionArgs|
-                  -> bool {
-                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $fn_name(ctx, args)))
-                    .unwrap_or_else(|e| {
-                        let msg = if let Some(s) = e.downcast_ref::<&str>() {
-                            s.to_string()
-                        } else if let Some(s) = e.downcast_ref::<String>() {
-                            s.clone()
-                        } else {
-                            "Unknown panic payload".to_string()
-                        };
-                        log::error!("[MXU] Custom action {} panicked: {}", $name, msg);
-                        false
-                    })
-            };
+                  -> bool { catch_action_panic($name, || $fn_name(ctx, args)) };
 
             if let Err(e) = resource.register_custom_action($name, Box::new(FnAction::new(wrapper)))
             {
@@ -876,40 +1987,48 @@ pub fn register_all_mxu_actions(
         };
     }
 
+    // 需要 AppHandle 与实例 ID 的动作，回调签名为 (ctx, args, &AppHandle, &str)
+    macro_rules! reg_instance_action {
+        ($name:expr, $fn_name:expr) => {
+            let action_app_handle = app_handle.clone();
+            let action_instance_id = instance_id.to_string();
+            let wrapper = move |ctx: &maa_framework::context::Context,
+                                args: &maa_framework::custom::ActionArgs|
+                  -> bool {
+                $fn_name(ctx, args, &action_app_handle, &action_instance_id)
+            };
+            reg_action!($name, wrapper);
+        };
+    }
+
     reg_action!(MXU_SLEEP_ACTION, mxu_sleep_action_fn);
+    reg_action!(MXU_RANDOM_DELAY_ACTION, mxu_random_delay_action_fn);
     reg_action!(MXU_WAITUNTIL_ACTION, mxu_waituntil_action_fn);
     reg_action!(MXU_LAUNCH_ACTION, mxu_launch_action_fn);
     reg_action!(MXU_WEBHOOK_ACTION, mxu_webhook_action_fn);
-    reg_action!(MXU_NOTIFY_ACTION, mxu_notify_action_fn);
+    reg_instance_action!(MXU_NOTIFY_ACTION, mxu_notify_action_fn);
     reg_action!(MXU_POWER_ACTION, mxu_power_action_fn);
+    reg_action!(MXU_WAKE_ACTION, mxu_wake_action_fn);
+    reg_action!(MXU_SOUND_ACTION, mxu_sound_action_fn);
+    reg_instance_action!(MXU_ASSERT_ACTION, mxu_assert_action_fn);
+    reg_instance_action!(MXU_COUNTER_ACTION, mxu_counter_action_fn);
+    reg_instance_action!(MXU_REPORT_RESULT_ACTION, mxu_report_result_action_fn);
+    reg_instance_action!(MXU_CLIPBOARD_ACTION, mxu_clipboard_action_fn);
+    reg_instance_action!(MXU_WAIT_FOR_USER_ACTION, mxu_wait_for_user_action_fn);
+    reg_action!(MXU_QUICK_RECO_ACTION, mxu_quick_reco_action_fn);
 
     let killproc_app_handle = app_handle.clone();
     let killproc_instance_id = instance_id.to_string();
     let killproc_wrapper = move |ctx: &maa_framework::context::Context,
                                  args: &maa_framework::custom::ActionArgs|
           -> bool {
-        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        catch_action_panic(MXU_KILLPROC_ACTION, || {
             mxu_killproc_action_impl(
                 ctx,
                 args,
                 Some(&killproc_app_handle),
                 Some(&killproc_instance_id),
             )
-        }))
-        .unwrap_or_else(|e| {
-            let msg = if let Some(s) = e.downcast_ref::<&str>() {
-                s.to_string()
-            } else if let Some(s) = e.downcast_ref::<String>() {
-                s.clone()
-            } else {
-                "Unknown panic payload".to_string()
-            };
-            log::error!(
-                "[MXU] Custom action {} panicked: {}",
-                MXU_KILLPROC_ACTION,
-                msg
-            );
-            false
         })
     };
 
@@ -935,3 +2054,54 @@ pub fn register_all_mxu_actions(
 
     Ok(())
 }
+
+fn mxu_killproc_standalone_action_fn(
+    ctx: &maa_framework::context::Context,
+    args: &maa_framework::custom::ActionArgs,
+) -> bool {
+    mxu_killproc_action_impl(ctx, args, None, None)
+}
+
+/// 以 Agent Server 身份注册 MXU 内置 custom actions
+///
+/// Agent Server 进程中没有 MXU 前端与实例，依赖 AppHandle 的动作（通知、断言、计数器、
+/// 结果上报、剪贴板、等待用户确认）不注册；MXU_KILLPROC 的自停止功能不可用。
+/// 返回注册失败的数量。
+pub fn register_agent_server_actions() -> usize {
+    use maa_framework::agent_server::AgentServer;
+
+    let mut failed_count = 0;
+
+    macro_rules! reg_action {
+        ($name:expr, $fn_name:expr) => {
+            let wrapper = move |ctx: &maa_framework::context::Context,
+                                args: &maa_framework::custom::ActionArgs|
+                  -> bool { catch_action_panic($name, || $fn_name(ctx, args)) };
+
+            if let Err(e) =
+                AgentServer::register_custom_action($name, Box::new(FnAction::new(wrapper)))
+            {
+                warn!(
+                    "[MXU] Failed to register {} to agent server: {:?}",
+                    $name, e
+                );
+                failed_count += 1;
+            } else {
+                info!("[MXU] Custom action {} registered to agent server", $name);
+            }
+        };
+    }
+
+    reg_action!(MXU_SLEEP_ACTION, mxu_sleep_action_fn);
+    reg_action!(MXU_RANDOM_DELAY_ACTION, mxu_random_delay_action_fn);
+    reg_action!(MXU_WAITUNTIL_ACTION, mxu_waituntil_action_fn);
+    reg_action!(MXU_LAUNCH_ACTION, mxu_launch_action_fn);
+    reg_action!(MXU_WEBHOOK_ACTION, mxu_webhook_action_fn);
+    reg_action!(MXU_POWER_ACTION, mxu_power_action_fn);
+    reg_action!(MXU_WAKE_ACTION, mxu_wake_action_fn);
+    reg_action!(MXU_SOUND_ACTION, mxu_sound_action_fn);
+    reg_action!(MXU_QUICK_RECO_ACTION, mxu_quick_reco_action_fn);
+    reg_action!(MXU_KILLPROC_ACTION, mxu_killproc_standalone_action_fn);
+
+    failed_count
+}
@@ -0,0 +1,269 @@
+//! 系统通知
+//!
+//! Windows 下使用 WinRT Toast：支持操作按钮（打开日志、停止任务），点击后回调到后端
+//! 执行对应操作；支持带进度条的下载通知。其他平台回退到 notify-rust 普通通知
+//! （不含按钮与进度）。
+
+#[cfg(windows)]
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use log::{info, warn};
+#[cfg(windows)]
+use parking_lot::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::MaaState;
+use crate::i18n;
+
+/// 通知上的操作按钮
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationAction {
+    /// 打开日志目录
+    OpenLog,
+    /// 停止指定实例的任务
+    StopTasks(String),
+    /// 显示主窗口
+    ShowWindow,
+}
+
+impl NotificationAction {
+    /// 从名称解析（供 MXU_NOTIFY 参数与前端调用使用），`instance_id` 用于 stop_tasks
+    pub fn from_name(name: &str, instance_id: Option<&str>) -> Option<Self> {
+        match name {
+            "open_log" => Some(Self::OpenLog),
+            "stop_tasks" => instance_id.map(|id| Self::StopTasks(id.to_string())),
+            "show_window" => Some(Self::ShowWindow),
+            _ => None,
+        }
+    }
+
+    #[cfg_attr(not(windows), allow(dead_code))]
+    fn label(&self) -> &'static str {
+        match self {
+            Self::OpenLog => i18n::t("notification.open_log"),
+            Self::StopTasks(_) => i18n::t("notification.stop_tasks"),
+            Self::ShowWindow => i18n::t("notification.show_window"),
+        }
+    }
+
+    /// 编码为 Toast 激活参数
+    #[cfg_attr(not(windows), allow(dead_code))]
+    fn to_argument(&self) -> String {
+        match self {
+            Self::OpenLog => "open_log".to_string(),
+            Self::StopTasks(id) => format!("stop_tasks:{}", id),
+            Self::ShowWindow => "show_window".to_string(),
+        }
+    }
+
+    #[cfg_attr(not(windows), allow(dead_code))]
+    fn from_argument(arg: &str) -> Option<Self> {
+        match arg.split_once(':') {
+            Some(("stop_tasks", id)) => Some(Self::StopTasks(id.to_string())),
+            _ => Self::from_name(arg, None),
+        }
+    }
+}
+
+/// 执行通知按钮（或点击通知主体）对应的操作
+#[cfg_attr(not(windows), allow(dead_code))]
+fn handle_activation(app: &AppHandle, action: Option<NotificationAction>) {
+    info!("Notification activated: {:?}", action);
+    match action {
+        Some(NotificationAction::OpenLog) => {
+            let logs_dir = crate::commands::utils::get_logs_dir();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) =
+                    crate::commands::system::open_file(logs_dir.to_string_lossy().to_string()).await
+                {
+                    warn!("Failed to open logs dir from notification: {}", e);
+                }
+            });
+        }
+        Some(NotificationAction::StopTasks(instance_id)) => {
+            let Some(maa_state) = app.try_state::<Arc<MaaState>>() else {
+                return;
+            };
+            match crate::commands::maa_core::stop_task_impl(&maa_state, &instance_id) {
+                Ok(()) => {
+                    crate::commands::utils::emit_state_changed(app, &instance_id, "task-stopped")
+                }
+                Err(e) => warn!("Failed to stop tasks from notification: {}", e),
+            }
+        }
+        // 点击通知主体或"显示窗口"
+        Some(NotificationAction::ShowWindow) | None => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.unminimize();
+                let _ = window.set_focus();
+            }
+        }
+    }
+}
+
+/// 发送通知
+pub fn show(
+    app: &AppHandle,
+    title: &str,
+    body: &str,
+    actions: &[NotificationAction],
+) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        use tauri_winrt_notification::Toast;
+
+        let mut toast = Toast::new(Toast::POWERSHELL_APP_ID)
+            .title(title)
+            .text1(body);
+        for action in actions {
+            toast = toast.add_button(action.label(), &action.to_argument());
+        }
+        let app = app.clone();
+        toast
+            .on_activated(move |arg| {
+                handle_activation(
+                    &app,
+                    arg.as_deref().and_then(NotificationAction::from_argument),
+                );
+                Ok(())
+            })
+            .show()
+            .map_err(|e| format!("{}: {}", i18n::t("notification.send_failed"), e))
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (app, actions);
+        notify_rust::Notification::new()
+            .summary(title)
+            .body(body)
+            .show()
+            .map(|_| ())
+            .map_err(|e| format!("{}: {}", i18n::t("notification.send_failed"), e))
+    }
+}
+
+// ============================================================================
+// 下载进度通知
+// ============================================================================
+
+/// 下载进度通知状态
+///
+/// WinRT 的 `Toast` 不能跨线程保存；刷新进度只需要通知的 tag，每次按 tag 重新构造即可。
+#[cfg(windows)]
+struct DownloadToast {
+    progress: tauri_winrt_notification::Progress,
+    /// 上次更新的整数百分比，避免频繁刷新通知
+    last_percent: u32,
+}
+
+#[cfg(windows)]
+static DOWNLOAD_TOASTS: Mutex<Option<HashMap<u64, DownloadToast>>> = Mutex::new(None);
+
+/// 为下载会话显示进度通知，之后由 `update_download` 根据进度事件刷新
+pub fn track_download(session_id: u64, title: &str) -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        use tauri_winrt_notification::{Progress, Toast};
+
+        let progress = Progress {
+            tag: format!("mxu-download-{}", session_id),
+            title: title.to_string(),
+            status: i18n::t("notification.downloading").to_string(),
+            value: 0.0,
+            value_string: "0%".to_string(),
+        };
+        let toast = Toast::new(Toast::POWERSHELL_APP_ID)
+            .title(title)
+            .progress(&progress);
+        toast
+            .show()
+            .map_err(|e| format!("{}: {}", i18n::t("notification.send_failed"), e))?;
+
+        let mut guard = DOWNLOAD_TOASTS.lock();
+        guard.get_or_insert_with(HashMap::new).insert(
+            session_id,
+            DownloadToast {
+                progress,
+                last_percent: 0,
+            },
+        );
+        Ok(())
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (session_id, title);
+        Ok(())
+    }
+}
+
+/// 刷新下载进度通知（未调用 `track_download` 的会话忽略），完成后移除
+pub fn update_download(session_id: u64, progress: f64) {
+    #[cfg(windows)]
+    {
+        let mut guard = DOWNLOAD_TOASTS.lock();
+        let Some(toasts) = guard.as_mut() else {
+            return;
+        };
+        let Some(entry) = toasts.get_mut(&session_id) else {
+            return;
+        };
+
+        let percent = progress.clamp(0.0, 100.0) as u32;
+        if percent == entry.last_percent && percent < 100 {
+            return;
+        }
+        entry.last_percent = percent;
+        entry.progress.value = percent as f32 / 100.0;
+        entry.progress.value_string = format!("{}%", percent);
+        if percent >= 100 {
+            entry.progress.status = i18n::t("notification.download_complete").to_string();
+        }
+        let toast = tauri_winrt_notification::Toast::new(
+            tauri_winrt_notification::Toast::POWERSHELL_APP_ID,
+        );
+        if let Err(e) = toast.set_progress(&entry.progress) {
+            warn!("Failed to update download toast: {}", e);
+        }
+        if percent >= 100 {
+            toasts.remove(&session_id);
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        let _ = (session_id, progress);
+    }
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 发送带操作按钮的系统通知
+///
+/// `actions` 可选值：`open_log` / `stop_tasks`（需提供 instance_id）/ `show_window`
+#[tauri::command]
+pub fn notification_show(
+    app: AppHandle,
+    title: String,
+    body: String,
+    actions: Option<Vec<String>>,
+    instance_id: Option<String>,
+) -> Result<(), String> {
+    let actions: Vec<NotificationAction> = actions
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|name| NotificationAction::from_name(name, instance_id.as_deref()))
+        .collect();
+    show(&app, &title, &body, &actions)
+}
+
+/// 为下载会话显示进度通知（仅 Windows，其他平台忽略）
+#[tauri::command]
+pub fn notification_track_download(session_id: u64, title: String) -> Result<(), String> {
+    track_download(session_id, &title)
+}
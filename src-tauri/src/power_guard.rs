@@ -0,0 +1,182 @@
+//! 运行期间阻止系统休眠
+//!
+//! 有任意实例的 tasker 正在运行时持有"保持唤醒"请求，全部结束后自动释放：
+//! - Windows: 专用线程调用 `SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED)`
+//! - macOS: 启动 `caffeinate -i -w <pid>` 子进程
+//! - Linux: 启动 `systemd-inhibit --what=sleep ... sleep infinity` 子进程
+//!
+//! 通过 `settings.preventSleep`（默认 true）或 `power_guard_set_enabled` 命令开关。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::MaaState;
+use crate::i18n::tr;
+
+/// 是否启用（用户开关）
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// 当前持有的唤醒请求（None 表示未持有）
+static GUARD: Mutex<Option<WakeLock>> = Mutex::new(None);
+
+/// 平台相关的唤醒请求，Drop 时释放
+struct WakeLock {
+    #[cfg(windows)]
+    release_tx: std::sync::mpsc::Sender<()>,
+    #[cfg(not(windows))]
+    child: std::process::Child,
+}
+
+impl WakeLock {
+    #[cfg(windows)]
+    fn acquire() -> Result<Self, String> {
+        // SetThreadExecutionState 作用于调用线程，需在独立线程中持有直到释放
+        extern "system" {
+            fn SetThreadExecutionState(es_flags: u32) -> u32;
+        }
+        const ES_CONTINUOUS: u32 = 0x8000_0000;
+        const ES_SYSTEM_REQUIRED: u32 = 0x0000_0001;
+
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel::<bool>();
+        std::thread::Builder::new()
+            .name("mxu-power-guard".to_string())
+            .spawn(move || {
+                let prev = unsafe { SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED) };
+                let _ = ready_tx.send(prev != 0);
+                // 发送端被 drop 时 recv 返回 Err，同样视为释放
+                let _ = release_rx.recv();
+                unsafe {
+                    SetThreadExecutionState(ES_CONTINUOUS);
+                }
+            })
+            .map_err(|e| tr!("error.power.thread", error = e))?;
+
+        match ready_rx.recv() {
+            Ok(true) => Ok(Self { release_tx }),
+            _ => Err(tr!("error.power.execution_state")),
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn acquire() -> Result<Self, String> {
+        let child = std::process::Command::new("caffeinate")
+            .args(["-i", "-w", &std::process::id().to_string()])
+            .spawn()
+            .map_err(|e| tr!("error.power.spawn", program = "caffeinate", error = e))?;
+        Ok(Self { child })
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn acquire() -> Result<Self, String> {
+        let child = std::process::Command::new("systemd-inhibit")
+            .args([
+                "--what=sleep:idle",
+                "--who=MXU",
+                "--why=任务运行中",
+                "--mode=block",
+                "sleep",
+                "infinity",
+            ])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .map_err(|e| tr!("error.power.spawn", program = "systemd-inhibit", error = e))?;
+        Ok(Self { child })
+    }
+}
+
+impl Drop for WakeLock {
+    fn drop(&mut self) {
+        #[cfg(windows)]
+        {
+            let _ = self.release_tx.send(());
+        }
+        #[cfg(not(windows))]
+        {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+/// 防休眠状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerGuardStatus {
+    pub enabled: bool,
+    /// 当前是否持有唤醒请求
+    pub active: bool,
+}
+
+fn status() -> PowerGuardStatus {
+    PowerGuardStatus {
+        enabled: ENABLED.load(Ordering::SeqCst),
+        active: GUARD.lock().is_some(),
+    }
+}
+
+/// 按运行中实例数获取或释放唤醒请求
+fn update(running: usize) {
+    let mut guard = GUARD.lock();
+    let want = running > 0 && ENABLED.load(Ordering::SeqCst);
+    if want && guard.is_none() {
+        match WakeLock::acquire() {
+            Ok(lock) => {
+                info!("Power guard acquired ({} instance(s) running)", running);
+                *guard = Some(lock);
+            }
+            Err(e) => warn!("Failed to acquire power guard: {}", e),
+        }
+    } else if !want && guard.is_some() {
+        *guard = None;
+        info!("Power guard released");
+    }
+}
+
+/// 根据当前运行状态刷新（由状态变更事件触发）
+pub fn refresh(app: &AppHandle) {
+    let Some(maa_state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+    update(maa_state.running_instance_count());
+}
+
+/// 从 `settings.preventSleep` 读取开关（应用启动时调用）
+pub fn init_from_config(config: &serde_json::Value) {
+    let enabled = config
+        .get("settings")
+        .and_then(|s| s.get("preventSleep"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// 释放唤醒请求（应用退出时调用）
+pub fn release() {
+    *GUARD.lock() = None;
+}
+
+// ============================================================================
+// Tauri 命令
+// ============================================================================
+
+/// 设置是否在任务运行期间阻止系统休眠
+#[tauri::command]
+pub fn power_guard_set_enabled(app: AppHandle, enabled: bool) -> PowerGuardStatus {
+    ENABLED.store(enabled, Ordering::SeqCst);
+    info!("Power guard enabled: {}", enabled);
+    refresh(&app);
+    status()
+}
+
+/// 获取防休眠状态
+#[tauri::command]
+pub fn power_guard_get_status() -> PowerGuardStatus {
+    status()
+}
@@ -14,10 +14,12 @@
 use std::collections::HashMap;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
-    Arc, Mutex,
+    Arc,
 };
 use std::time::Instant;
 
+use parking_lot::Mutex;
+
 use crate::commands::types::MaaState;
 
 /// 订阅者超时时长：超过此时间无任何活动的订阅者将被自动清理。
@@ -66,7 +68,7 @@ impl ScreenshotService {
         handle: tokio::runtime::Handle,
     ) {
         let stream = {
-            let mut streams = self.streams.lock().unwrap();
+            let mut streams = self.streams.lock();
             streams
                 .entry(instance_id.clone())
                 .or_insert_with(|| {
@@ -79,7 +81,7 @@ impl ScreenshotService {
         };
 
         {
-            let mut subs = stream.subscribers.lock().unwrap();
+            let mut subs = stream.subscribers.lock();
             subs.insert(
                 subscriber_id,
                 ScreenshotSubscriber {
@@ -101,9 +103,9 @@ impl ScreenshotService {
 
     /// 取消订阅。截图循环将在下次迭代检测到无订阅者时自动退出。
     pub fn unsubscribe(&self, instance_id: &str, subscriber_id: &str) {
-        let streams = self.streams.lock().unwrap();
+        let streams = self.streams.lock();
         if let Some(stream) = streams.get(instance_id) {
-            stream.subscribers.lock().unwrap().remove(subscriber_id);
+            stream.subscribers.lock().remove(subscriber_id);
         }
     }
 
@@ -111,9 +113,9 @@ impl ScreenshotService {
     ///
     /// Web UI 可在每次 GET /screenshot 时调用，也可不调用（依赖显式 unsubscribe）。
     pub fn touch(&self, instance_id: &str, subscriber_id: &str) {
-        let streams = self.streams.lock().unwrap();
+        let streams = self.streams.lock();
         if let Some(stream) = streams.get(instance_id) {
-            let mut subs = stream.subscribers.lock().unwrap();
+            let mut subs = stream.subscribers.lock();
             if let Some(sub) = subs.get_mut(subscriber_id) {
                 sub.last_active = Instant::now();
             }
@@ -141,7 +143,7 @@ fn capture_loop(
             // 1. 清理超时订阅者，计算当前最小间隔
             //    MutexGuard 在块末尾 drop，不跨越 .await
             let min_interval_ms: u64 = {
-                let mut subs = stream.subscribers.lock().unwrap();
+                let mut subs = stream.subscribers.lock();
                 let now = Instant::now();
                 subs.retain(|_, sub| {
                     now.duration_since(sub.last_active).as_secs() < SUBSCRIBER_TIMEOUT_SECS
@@ -157,32 +159,26 @@ fn capture_loop(
             };
 
             // 2. 检查实例状态，获取截图决策（仅用 bool 传递，不持有 Controller）
-            //    MutexGuard 在块末尾 drop，不跨越 .await
+            //    实例引用在块末尾 drop，不跨越 .await
             let (should_exit, should_screencap): (bool, bool) = {
-                match maa_state.instances.lock() {
-                    Err(_) => (true, false),
-                    Ok(instances) => match instances.get(&instance_id) {
-                        None => {
+                match maa_state.instances.get(&instance_id) {
+                    None => {
+                        log::debug!("[screenshot] instance {} destroyed, stopping", instance_id);
+                        (true, false)
+                    }
+                    Some(inst) => {
+                        if inst.controller.is_none() {
                             log::debug!(
-                                "[screenshot] instance {} destroyed, stopping",
+                                "[screenshot] controller not connected for {}, stopping",
                                 instance_id
                             );
                             (true, false)
+                        } else {
+                            let is_running = inst.tasker.as_ref().is_some_and(|t| t.running());
+                            // 任务运行中，管线自动更新缓存，跳过主动截图
+                            (false, !is_running)
                         }
-                        Some(inst) => {
-                            if inst.controller.is_none() {
-                                log::debug!(
-                                    "[screenshot] controller not connected for {}, stopping",
-                                    instance_id
-                                );
-                                (true, false)
-                            } else {
-                                let is_running = inst.tasker.as_ref().is_some_and(|t| t.running());
-                                // 任务运行中，管线自动更新缓存，跳过主动截图
-                                (false, !is_running)
-                            }
-                        }
-                    },
+                    }
                 }
             };
 
@@ -191,13 +187,11 @@ fn capture_loop(
             }
 
             // 3. 需要截图时，重新加锁获取 Controller 引用并调用 post_screencap
-            //    MutexGuard 及 Controller 引用均在块末尾 drop，不跨越 .await
+            //    实例引用及 Controller 引用均在块末尾 drop，不跨越 .await
             if should_screencap {
-                if let Ok(instances) = maa_state.instances.lock() {
-                    if let Some(inst) = instances.get(&instance_id) {
-                        if let Some(ctrl) = inst.controller.as_ref() {
-                            let _ = ctrl.post_screencap();
-                        }
+                if let Some(inst) = maa_state.instances.get(&instance_id) {
+                    if let Some(ctrl) = inst.controller.as_ref() {
+                        let _ = ctrl.post_screencap();
                     }
                 }
             }
@@ -211,7 +205,7 @@ fn capture_loop(
 
         // 竞态处理：若在「判断退出」与「写入 false」之间有新订阅者加入，
         // 重新检查并在必要时重启循环，防止订阅者存在但无循环运行的窗口期。
-        let still_has_subs = !stream.subscribers.lock().unwrap().is_empty();
+        let still_has_subs = !stream.subscribers.lock().is_empty();
         if still_has_subs
             && stream
                 .capture_running
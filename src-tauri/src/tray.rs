@@ -1,20 +1,34 @@
+use parking_lot::Mutex;
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Mutex, OnceLock,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc, OnceLock,
 };
 use tauri::{
     image::Image,
-    menu::{Menu, MenuItem},
+    menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu},
     tray::{MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent},
     AppHandle, Emitter, Manager, Wry,
 };
 
+use crate::commands::{AppConfigState, MaaState};
+use crate::i18n;
+
 /// 全局设置：关闭时是否最小化到托盘
 static MINIMIZE_TO_TRAY: AtomicBool = AtomicBool::new(false);
 
 /// 全局托盘图标引用，用于动态更新图标
 static TRAY_ICON: OnceLock<Mutex<Option<TrayIcon>>> = OnceLock::new();
 
+/// 前端设置的基础 tooltip（运行状态会追加在其后）
+static BASE_TOOLTIP: Mutex<Option<String>> = Mutex::new(None);
+
+/// 上次刷新时的运行中实例数，避免重复设置 tooltip
+static RUNNING_COUNT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// 按实例启动 / 停止的菜单项 ID 前缀
+const START_INSTANCE_PREFIX: &str = "start:";
+const STOP_INSTANCE_PREFIX: &str = "stop:";
+
 /// 设置最小化到托盘选项
 pub fn set_minimize_to_tray(enabled: bool) {
     MINIMIZE_TO_TRAY.store(enabled, Ordering::SeqCst);
@@ -27,13 +41,7 @@ pub fn get_minimize_to_tray() -> bool {
 
 /// 初始化系统托盘
 pub fn init_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-    // 创建托盘菜单项
-    let show_i = MenuItem::with_id(app, "show", "显示主窗口", true, None::<&str>)?;
-    let start_i = MenuItem::with_id(app, "start", "开始任务", true, None::<&str>)?;
-    let stop_i = MenuItem::with_id(app, "stop", "停止任务", true, None::<&str>)?;
-    let quit_i = MenuItem::with_id(app, "quit", "退出", true, None::<&str>)?;
-
-    let menu = Menu::with_items(app, &[&show_i, &start_i, &stop_i, &quit_i])?;
+    let menu = build_menu(app, &configured_instances(app))?;
 
     // 获取图标
     let icon = app
@@ -69,7 +77,19 @@ pub fn init_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                     // 真正退出应用
                     app.exit(0);
                 }
-                _ => {}
+                _ => {
+                    if let Some(instance_id) = id.strip_prefix(START_INSTANCE_PREFIX) {
+                        // 按实例开始：交给前端复用完整的启动流程
+                        if let Some(window) = app.get_webview_window("main") {
+                            let _ = window.emit(
+                                "tray-start-tasks",
+                                serde_json::json!({ "instanceId": instance_id }),
+                            );
+                        }
+                    } else if let Some(instance_id) = id.strip_prefix(STOP_INSTANCE_PREFIX) {
+                        stop_instance(app, instance_id);
+                    }
+                }
             }
         })
         .on_tray_icon_event(|tray, event| {
@@ -87,15 +107,154 @@ pub fn init_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
 
     // 保存托盘引用，用于后续动态更新图标
     let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
-    let mut guard = tray_mutex.lock().map_err(|e| {
-        log::error!("Failed to lock tray mutex during init: {}", e);
-        format!("Failed to initialize tray: {}", e)
-    })?;
-    *guard = Some(tray);
+    *tray_mutex.lock() = Some(tray);
 
     Ok(())
 }
 
+/// 从当前配置读取实例列表（id, 名称）
+fn configured_instances(app: &AppHandle) -> Vec<(String, String)> {
+    let Some(app_config) = app.try_state::<Arc<AppConfigState>>() else {
+        return Vec::new();
+    };
+    let config = app_config.config.lock();
+    config
+        .get("instances")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|inst| {
+                    let id = inst.get("id")?.as_str()?.to_string();
+                    let name = inst
+                        .get("name")
+                        .and_then(|n| n.as_str())
+                        .filter(|n| !n.is_empty())
+                        .unwrap_or(&id)
+                        .to_string();
+                    Some((id, name))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 构建托盘菜单：显示主窗口、开始/停止（当前实例）、每个实例的开始/停止子菜单、退出
+fn build_menu(app: &AppHandle, instances: &[(String, String)]) -> Result<Menu<Wry>, tauri::Error> {
+    let show_i = MenuItem::with_id(app, "show", i18n::t("tray.show"), true, None::<&str>)?;
+    let start_i = MenuItem::with_id(
+        app,
+        "start",
+        i18n::t("tray.start_tasks"),
+        true,
+        None::<&str>,
+    )?;
+    let stop_i = MenuItem::with_id(app, "stop", i18n::t("tray.stop_tasks"), true, None::<&str>)?;
+    let quit_i = MenuItem::with_id(app, "quit", i18n::t("tray.quit"), true, None::<&str>)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+    let separator2 = PredefinedMenuItem::separator(app)?;
+
+    let mut instance_menus = Vec::with_capacity(instances.len());
+    for (id, name) in instances {
+        let start = MenuItem::with_id(
+            app,
+            format!("{}{}", START_INSTANCE_PREFIX, id),
+            i18n::t("tray.start"),
+            true,
+            None::<&str>,
+        )?;
+        let stop = MenuItem::with_id(
+            app,
+            format!("{}{}", STOP_INSTANCE_PREFIX, id),
+            i18n::t("tray.stop"),
+            true,
+            None::<&str>,
+        )?;
+        instance_menus.push(Submenu::with_items(app, name, true, &[&start, &stop])?);
+    }
+
+    let mut items: Vec<&dyn IsMenuItem<Wry>> = vec![&show_i, &start_i, &stop_i];
+    if !instance_menus.is_empty() {
+        items.push(&separator);
+        items.extend(instance_menus.iter().map(|m| m as &dyn IsMenuItem<Wry>));
+    }
+    items.push(&separator2);
+    items.push(&quit_i);
+
+    Menu::with_items(app, &items)
+}
+
+/// 配置变更后重建托盘菜单（实例增删、改名）
+pub fn refresh_menu(app: &AppHandle) {
+    let instances = configured_instances(app);
+    let menu = match build_menu(app, &instances) {
+        Ok(m) => m,
+        Err(e) => {
+            log::warn!("Failed to build tray menu: {}", e);
+            return;
+        }
+    };
+    let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
+    let guard = tray_mutex.lock();
+    if let Some(tray) = guard.as_ref() {
+        if let Err(e) = tray.set_menu(Some(menu)) {
+            log::warn!("Failed to set tray menu: {}", e);
+        }
+    }
+}
+
+/// 停止指定实例的任务（直接调用后端实现，不依赖主窗口）
+fn stop_instance(app: &AppHandle, instance_id: &str) {
+    let Some(maa_state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+    match crate::commands::maa_core::stop_task_impl(&maa_state, instance_id) {
+        Ok(()) => {
+            log::info!("Tray: stop requested for instance {}", instance_id);
+            crate::commands::utils::emit_state_changed(app, instance_id, "task-stopped");
+        }
+        Err(e) => log::warn!("Tray: failed to stop instance {}: {}", instance_id, e),
+    }
+}
+
+/// 按运行中实例数刷新托盘 tooltip（由状态变更事件触发）
+pub fn refresh_status(app: &AppHandle) {
+    let Some(maa_state) = app.try_state::<Arc<MaaState>>() else {
+        return;
+    };
+    let running = maa_state.running_instance_count();
+    if RUNNING_COUNT.swap(running, Ordering::SeqCst) == running {
+        return;
+    }
+    if let Err(e) = apply_tooltip() {
+        log::debug!("Failed to refresh tray status: {}", e);
+    }
+}
+
+/// 组合基础 tooltip 与运行状态并应用到托盘
+fn apply_tooltip() -> Result<(), String> {
+    let base = BASE_TOOLTIP
+        .lock()
+        .clone()
+        .unwrap_or_else(|| "MXU".to_string());
+    let tooltip = match RUNNING_COUNT.load(Ordering::SeqCst) {
+        0 | usize::MAX => base,
+        n => format!(
+            "{}\n{}",
+            base,
+            i18n::t("tray.running_instances").replace("{n}", &n.to_string())
+        ),
+    };
+
+    let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
+    let guard = tray_mutex.lock();
+    if let Some(tray) = guard.as_ref() {
+        tray.set_tooltip(Some(&tooltip))
+            .map_err(|e| format!("Failed to set tray tooltip: {}", e))
+    } else {
+        Err("Tray icon not initialized".to_string())
+    }
+}
+
 /// 显示主窗口
 fn show_main_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
@@ -156,9 +315,7 @@ pub fn update_tray_icon(icon_path: &str) -> Result<(), String> {
 
     // 更新托盘图标
     let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
-    let guard = tray_mutex
-        .lock()
-        .map_err(|e| format!("Failed to lock tray mutex: {}", e))?;
+    let guard = tray_mutex.lock();
 
     if let Some(tray) = guard.as_ref() {
         tray.set_icon(Some(icon))
@@ -170,19 +327,10 @@ pub fn update_tray_icon(icon_path: &str) -> Result<(), String> {
     }
 }
 
-/// 更新托盘 tooltip
+/// 更新托盘 tooltip（作为基础文本，运行中实例数会追加在其后）
 pub fn update_tray_tooltip(tooltip: &str) -> Result<(), String> {
-    let tray_mutex = TRAY_ICON.get_or_init(|| Mutex::new(None));
-    let guard = tray_mutex
-        .lock()
-        .map_err(|e| format!("Failed to lock tray mutex: {}", e))?;
-
-    if let Some(tray) = guard.as_ref() {
-        tray.set_tooltip(Some(tooltip))
-            .map_err(|e| format!("Failed to set tray tooltip: {}", e))?;
-        log::info!("Tray tooltip updated: {}", tooltip);
-        Ok(())
-    } else {
-        Err("Tray icon not initialized".to_string())
-    }
+    *BASE_TOOLTIP.lock() = Some(tooltip.to_string());
+    apply_tooltip()?;
+    log::info!("Tray tooltip updated: {}", tooltip);
+    Ok(())
 }
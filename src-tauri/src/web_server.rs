@@ -24,16 +24,22 @@ use tower_http::cors::CorsLayer;
 
 use crate::commands::{
     app_config::AppConfigState,
-    maa_agent::{start_tasks_impl, stop_agent_impl},
+    maa_agent::{
+        restart_agent_impl, send_agent_input_impl, start_tasks_impl, stop_agent_impl,
+        stop_single_agent_impl,
+    },
     maa_core::{
         connect_controller_impl, destroy_instance_impl, find_adb_devices_impl,
         find_win32_windows_impl, find_wlroots_sockets_impl, get_cached_image_impl,
-        load_resource_impl, override_pipeline_impl, post_click_impl, post_screencap_impl,
-        run_task_impl, stop_task_impl,
+        get_controller_info_impl, load_resource_impl, override_pipeline_impl, post_click_impl,
+        post_screencap_impl, run_task_impl, stop_task_impl,
     },
+    maa_ffi::{with_ffi_timeout, FFI_DESTROY_TIMEOUT, FFI_LOAD_TIMEOUT},
+    trace,
     types::{AgentConfig, ControllerConfig, MaaState, TaskConfig},
     utils::{emit_callback_event, emit_config_changed, emit_state_changed},
 };
+use crate::i18n::tr;
 use crate::ws_broadcast::WsBroadcast;
 
 /// Web 服务器默认监听端口
@@ -245,11 +251,27 @@ pub async fn start_web_server(
             "/maa/instances/:id/agent/stop",
             axum::routing::post(handle_stop_agent),
         )
+        .route(
+            "/maa/instances/:id/agent/:index/stop",
+            axum::routing::post(handle_stop_single_agent),
+        )
+        .route(
+            "/maa/instances/:id/agent/:index/restart",
+            axum::routing::post(handle_restart_agent),
+        )
+        .route(
+            "/maa/instances/:id/agent/:index/input",
+            axum::routing::post(handle_agent_input),
+        )
         .route(
             "/maa/instances/:id/click",
             axum::routing::post(handle_post_click),
         )
         .route("/maa/instances/:id/screenshot", get(handle_get_screenshot))
+        .route(
+            "/maa/instances/:id/controller-info",
+            get(handle_get_controller_info),
+        )
         .route(
             "/maa/instances/:id/screenshot/subscribe",
             axum::routing::post(handle_screenshot_subscribe),
@@ -264,6 +286,8 @@ pub async fn start_web_server(
             "/logs/:id",
             axum::routing::post(handle_push_log).delete(handle_clear_instance_logs),
         )
+        // 事件补发（页面刷新后补取错过的回调与输出）
+        .route("/events/missed", get(handle_fetch_missed_events))
         // 心跳
         .route("/heartbeat", get(handle_heartbeat))
         // 系统信息
@@ -272,6 +296,8 @@ pub async fn start_web_server(
             "/system/restart-as-admin",
             axum::routing::post(handle_restart_as_admin),
         )
+        // 深度链接（新启动的进程转发 mxu:// 链接）
+        .route("/deep-link", axum::routing::post(handle_deep_link))
         // 本地文件代理（浏览器通过此端点访问 exe 目录下的资源文件）
         .route("/local-file", get(handle_serve_local_file))
         .with_state(state);
@@ -517,26 +543,10 @@ async fn handle_ws_connection(mut socket: WebSocket, state: WebState) {
 /// GET /api/interface
 /// 返回已处理的 interface.json 内容、翻译文件及路径信息
 async fn handle_get_interface(State(state): State<WebState>) -> impl IntoResponse {
-    let (pi, translations, base_path, data_path) = match (
-        state.app_config.project_interface.lock(),
-        state.app_config.translations.lock(),
-        state.app_config.base_path.lock(),
-        state.app_config.data_path.lock(),
-    ) {
-        (Ok(pi), Ok(translations), Ok(base_path), Ok(data_path)) => (
-            pi.clone(),
-            translations.clone(),
-            base_path.clone(),
-            data_path.clone(),
-        ),
-        _ => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({ "error": "读取 interface 状态失败" })),
-            )
-                .into_response();
-        }
-    };
+    let pi = state.app_config.project_interface.lock().clone();
+    let translations = state.app_config.translations.lock().clone();
+    let base_path = state.app_config.base_path.lock().clone();
+    let data_path = state.app_config.data_path.lock().clone();
 
     match pi {
         Some(interface) => Json(serde_json::json!({
@@ -549,7 +559,7 @@ async fn handle_get_interface(State(state): State<WebState>) -> impl IntoRespons
         .into_response(),
         None => (
             StatusCode::SERVICE_UNAVAILABLE,
-            Json(serde_json::json!({ "error": "interface.json 尚未加载" })),
+            Json(serde_json::json!({ "error": tr!("error.web.interface_not_loaded") })),
         )
             .into_response(),
     }
@@ -558,16 +568,7 @@ async fn handle_get_interface(State(state): State<WebState>) -> impl IntoRespons
 /// GET /api/config
 /// 返回当前 MXU 配置（JSON 原文）
 async fn handle_get_config(State(state): State<WebState>) -> impl IntoResponse {
-    let config = match state.app_config.config.lock() {
-        Ok(config) => config.clone(),
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({ "error": "读取配置状态失败" })),
-            )
-                .into_response();
-        }
-    };
+    let config = state.app_config.config.lock().clone();
     Json(config).into_response()
 }
 
@@ -577,7 +578,7 @@ async fn handle_put_config(
     State(state): State<WebState>,
     Json(new_config): Json<serde_json::Value>,
 ) -> impl IntoResponse {
-    match state.app_config.save_config(new_config) {
+    match state.app_config.save_config_from(new_config, "web") {
         Ok(()) => {
             // 通知所有客户端（WS 浏览器 + Tauri 桌面端）配置已变更
             emit_config_changed(&state.app_handle);
@@ -596,67 +597,49 @@ async fn handle_put_config(
 async fn handle_get_maa_state(State(state): State<WebState>) -> impl IntoResponse {
     use std::collections::HashMap;
 
-    let instances_result = state.maa_state.instances.lock();
-    let adb_result = state.maa_state.cached_adb_devices.lock();
-    let win32_result = state.maa_state.cached_win32_windows.lock();
-    let wlroots_result = state.maa_state.cached_wlroots_sockets.lock();
+    let adb = state.maa_state.cached_adb_devices.lock();
+    let win32 = state.maa_state.cached_win32_windows.lock();
+    let wlroots = state.maa_state.cached_wlroots_sockets.lock();
 
-    match (instances_result, adb_result, win32_result, wlroots_result) {
-        (Ok(mut instances), Ok(adb), Ok(win32), Ok(wlroots)) => {
-            let mut instance_states: HashMap<String, serde_json::Value> = HashMap::new();
+    let mut instance_states: HashMap<String, serde_json::Value> = HashMap::new();
 
-            for (id, runtime) in instances.iter_mut() {
-                let is_running = runtime.tasker.as_ref().is_some_and(|t| t.running());
-
-                // 与 state.rs 的 maa_get_all_states 保持一致：清理停止标志
-                if !is_running && runtime.stop_in_progress {
-                    runtime.stop_in_progress = false;
-                    runtime.stop_started_at = None;
-                }
+    for mut entry in state.maa_state.instances.iter_mut() {
+        let (id, runtime) = entry.pair_mut();
+        let is_running = runtime.tasker.as_ref().is_some_and(|t| t.running());
 
-                // 字段名使用 snake_case，与 Tauri invoke 返回格式保持一致，
-                // 前端 maaService.getAllStates 会统一做 camelCase 转换
-                instance_states.insert(
-                    id.clone(),
-                    serde_json::json!({
-                        "connected": runtime.controller.as_ref().is_some_and(|c| c.connected()),
-                        "resource_loaded": runtime.resource.as_ref().is_some_and(|r| r.loaded()),
-                        "tasker_inited": runtime.tasker.as_ref().is_some_and(|t| t.inited()),
-                        "is_running": is_running,
-                        "task_run_state": serde_json::to_value(&runtime.task_run_state).unwrap_or_default(),
-                    }),
-                );
-            }
-
-            Json(serde_json::json!({
-                "instances": instance_states,
-                "cached_adb_devices": serde_json::to_value(&*adb).unwrap_or(serde_json::Value::Array(vec![])),
-                "cached_win32_windows": serde_json::to_value(&*win32).unwrap_or(serde_json::Value::Array(vec![])),
-                "cached_wlroots_sockets": serde_json::to_value(&*wlroots).unwrap_or(serde_json::Value::Array(vec![])),
-            }))
-            .into_response()
+        // 与 state.rs 的 maa_get_all_states 保持一致：清理停止标志
+        if !is_running && runtime.stop_in_progress {
+            runtime.stop_in_progress = false;
+            runtime.stop_started_at = None;
         }
-        _ => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({ "error": "获取状态锁失败" })),
-        )
-            .into_response(),
+
+        // 字段名使用 snake_case，与 Tauri invoke 返回格式保持一致，
+        // 前端 maaService.getAllStates 会统一做 camelCase 转换
+        instance_states.insert(
+            id.clone(),
+            serde_json::json!({
+                "connected": runtime.controller.as_ref().is_some_and(|c| c.connected()),
+                "resource_loaded": runtime.resource.as_ref().is_some_and(|r| r.loaded()),
+                "tasker_inited": runtime.tasker.as_ref().is_some_and(|t| t.inited()),
+                "is_running": is_running,
+                "task_run_state": serde_json::to_value(&runtime.task_run_state).unwrap_or_default(),
+            }),
+        );
     }
+
+    Json(serde_json::json!({
+        "instances": instance_states,
+        "cached_adb_devices": serde_json::to_value(&*adb).unwrap_or(serde_json::Value::Array(vec![])),
+        "cached_win32_windows": serde_json::to_value(&*win32).unwrap_or(serde_json::Value::Array(vec![])),
+        "cached_wlroots_sockets": serde_json::to_value(&*wlroots).unwrap_or(serde_json::Value::Array(vec![])),
+    }))
+    .into_response()
 }
 
 /// GET /api/maa/initialized
 /// 返回 Maa 库初始化状态及版本号
 async fn handle_get_maa_initialized(State(state): State<WebState>) -> impl IntoResponse {
-    let lib_dir_set = match state.maa_state.lib_dir.lock() {
-        Ok(lib_dir) => lib_dir.is_some(),
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({ "error": "读取 Maa 初始化状态失败" })),
-            )
-                .into_response();
-        }
-    };
+    let lib_dir_set = state.maa_state.lib_dir.lock().is_some();
 
     // 库已加载时尝试获取版本号（load_library 后才可调用 maa_version）
     let version = if lib_dir_set {
@@ -746,7 +729,14 @@ async fn handle_destroy_instance(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
 ) -> impl IntoResponse {
-    match destroy_instance_impl(&state.maa_state, &instance_id) {
+    let correlation_id = trace::begin("maa_destroy_instance", Some(&instance_id));
+    let maa_state = state.maa_state.clone();
+    let result = with_ffi_timeout("destroy_instance", FFI_DESTROY_TIMEOUT, move || {
+        destroy_instance_impl(&maa_state, &instance_id)
+    })
+    .await;
+    trace::finish(&correlation_id, &result);
+    match result {
         Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -758,11 +748,10 @@ async fn handle_destroy_instance(
 
 /// 确保指定实例存在，不存在则自动创建
 fn ensure_instance_exists(maa_state: &Arc<MaaState>, instance_id: &str) {
-    if let Ok(mut instances) = maa_state.instances.lock() {
-        instances
-            .entry(instance_id.to_string())
-            .or_insert_with(crate::commands::types::InstanceRuntime::default);
-    }
+    maa_state
+        .instances
+        .entry(instance_id.to_string())
+        .or_default();
 }
 
 /// POST /api/maa/instances/:id/connect
@@ -774,12 +763,19 @@ async fn handle_connect_controller(
 ) -> impl IntoResponse {
     ensure_instance_exists(&state.maa_state, &instance_id);
 
+    let correlation_id = trace::begin("maa_connect_controller", Some(&instance_id));
     let app_handle = state.app_handle.clone();
+    let id = instance_id.clone();
+    let cid = correlation_id.clone();
     let on_event = Arc::new(move |msg: &str, detail: &str| {
-        emit_callback_event(&app_handle, msg, detail);
+        trace::record(&cid, log::Level::Debug, format!("{} {}", msg, detail));
+        emit_callback_event(&app_handle, &id, msg, detail);
     });
 
-    match connect_controller_impl(state.maa_state, instance_id.clone(), config, on_event).await {
+    let result =
+        connect_controller_impl(state.maa_state, instance_id.clone(), config, on_event).await;
+    trace::finish(&correlation_id, &result);
+    match result {
         Ok(conn_id) => {
             emit_state_changed(&state.app_handle, &instance_id, "connected");
             Json(serde_json::json!({ "connId": conn_id })).into_response()
@@ -817,18 +813,22 @@ async fn handle_load_resource(
     };
 
     let app_handle = state.app_handle.clone();
+    let id = instance_id.clone();
     let on_event: Arc<dyn Fn(&str, &str) + Send + Sync + 'static> =
         Arc::new(move |msg: &str, detail: &str| {
-            emit_callback_event(&app_handle, msg, detail);
+            emit_callback_event(&app_handle, &id, msg, detail);
         });
 
-    match load_resource_impl(
-        &state.maa_state,
-        &instance_id,
-        &paths,
-        on_event,
-        Some(&state.app_handle),
-    ) {
+    let correlation_id = trace::begin("maa_load_resource", Some(&instance_id));
+    let maa_state = state.maa_state.clone();
+    let app_for_load = state.app_handle.clone();
+    let id = instance_id.clone();
+    let result = with_ffi_timeout("load_resource", FFI_LOAD_TIMEOUT, move || {
+        load_resource_impl(&maa_state, &id, &paths, on_event, Some(&app_for_load))
+    })
+    .await;
+    trace::finish(&correlation_id, &result);
+    match result {
         Ok(res_ids) => {
             emit_state_changed(&state.app_handle, &instance_id, "resource-loading");
             Json(serde_json::json!({ "resIds": res_ids })).into_response()
@@ -849,20 +849,23 @@ async fn handle_run_task(
     axum::extract::Path(instance_id): axum::extract::Path<String>,
     Json(tasks): Json<Vec<TaskConfig>>,
 ) -> impl IntoResponse {
+    let correlation_id = trace::begin("maa_run_task", Some(&instance_id));
     let mut task_ids = Vec::new();
     let maa = state.maa_state;
 
     for task in &tasks {
-        match run_task_impl(
+        let result = run_task_impl(
             &state.app_handle,
             &maa,
             &instance_id,
             &task.entry,
             &task.pipeline_override,
             task.selected_task_id.as_deref(),
-        ) {
+        );
+        match result {
             Ok(id) => task_ids.push(id),
             Err(e) => {
+                trace::finish(&correlation_id, &Err::<(), _>(&e));
                 return (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(serde_json::json!({ "error": e })),
@@ -872,6 +875,7 @@ async fn handle_run_task(
         }
     }
 
+    trace::finish(&correlation_id, &Ok::<_, String>(()));
     emit_state_changed(&state.app_handle, &instance_id, "task-started");
 
     Json(serde_json::json!({ "taskIds": task_ids })).into_response()
@@ -889,6 +893,8 @@ struct StartTasksRequest {
     tcp_compat_mode: Option<bool>,
     #[serde(default)]
     pi_envs: Option<std::collections::HashMap<String, String>>,
+    #[serde(default)]
+    dry_run: Option<bool>,
 }
 
 /// POST /api/maa/instances/:id/tasks/start
@@ -902,19 +908,11 @@ async fn handle_start_tasks(
 
     let cwd = match body.cwd {
         Some(cwd) => cwd,
-        None => match state.app_config.base_path.lock() {
-            Ok(base_path) => base_path.clone(),
-            Err(_) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(serde_json::json!({ "error": "读取基础路径失败" })),
-                )
-                    .into_response();
-            }
-        },
+        None => state.app_config.base_path.lock().clone(),
     };
 
-    match start_tasks_impl(
+    let correlation_id = trace::begin("maa_start_tasks", Some(&instance_id));
+    let result = start_tasks_impl(
         state.app_handle,
         &state.maa_state,
         instance_id,
@@ -923,9 +921,11 @@ async fn handle_start_tasks(
         cwd,
         body.tcp_compat_mode.unwrap_or(false),
         body.pi_envs,
+        body.dry_run.unwrap_or(false),
     )
-    .await
-    {
+    .await;
+    trace::finish(&correlation_id, &result);
+    match result {
         Ok(task_ids) => Json(serde_json::json!({ "taskIds": task_ids })).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -941,7 +941,10 @@ async fn handle_stop_task(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
 ) -> impl IntoResponse {
-    match stop_task_impl(&state.maa_state, &instance_id) {
+    let correlation_id = trace::begin("maa_stop_task", Some(&instance_id));
+    let result = stop_task_impl(&state.maa_state, &instance_id);
+    trace::finish(&correlation_id, &result);
+    match result {
         Ok(()) => {
             emit_state_changed(&state.app_handle, &instance_id, "task-stopped");
             Json(serde_json::json!({ "ok": true })).into_response()
@@ -989,7 +992,54 @@ async fn handle_stop_agent(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
 ) -> impl IntoResponse {
-    match stop_agent_impl(&state.maa_state, &instance_id) {
+    let correlation_id = trace::begin("maa_stop_agent", Some(&instance_id));
+    let result = stop_agent_impl(&state.maa_state, &instance_id);
+    trace::finish(&correlation_id, &result);
+    match result {
+        Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// POST /api/maa/instances/:id/agent/:index/stop
+/// 停止单个 Agent，与 Tauri invoke `maa_stop_single_agent` 使用同一套实现
+async fn handle_stop_single_agent(
+    State(state): State<WebState>,
+    axum::extract::Path((instance_id, agent_index)): axum::extract::Path<(String, usize)>,
+) -> impl IntoResponse {
+    let correlation_id = trace::begin("maa_stop_single_agent", Some(&instance_id));
+    let result = stop_single_agent_impl(&state.maa_state, &instance_id, agent_index);
+    trace::finish(&correlation_id, &result);
+    match result {
+        Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// POST /api/maa/instances/:id/agent/:index/restart
+/// 重启单个 Agent，与 Tauri invoke `maa_restart_agent` 使用同一套实现
+async fn handle_restart_agent(
+    State(state): State<WebState>,
+    axum::extract::Path((instance_id, agent_index)): axum::extract::Path<(String, usize)>,
+) -> impl IntoResponse {
+    let correlation_id = trace::begin("maa_restart_agent", Some(&instance_id));
+    let result = restart_agent_impl(
+        state.app_handle.clone(),
+        &state.maa_state,
+        &instance_id,
+        agent_index,
+    )
+    .await;
+    trace::finish(&correlation_id, &result);
+    match result {
         Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -999,6 +1049,30 @@ async fn handle_stop_agent(
     }
 }
 
+/// POST /api/maa/instances/:id/agent/:index/input
+///
+/// Body: `{ "line": "123456" }`
+#[derive(serde::Deserialize)]
+struct AgentInputRequest {
+    line: String,
+}
+
+/// 向 Agent 标准输入写入一行，与 Tauri invoke `maa_agent_send_input` 使用同一套实现
+async fn handle_agent_input(
+    State(state): State<WebState>,
+    axum::extract::Path((instance_id, agent_index)): axum::extract::Path<(String, usize)>,
+    Json(body): Json<AgentInputRequest>,
+) -> impl IntoResponse {
+    match send_agent_input_impl(&state.maa_state, &instance_id, agent_index, &body.line) {
+        Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
 /// POST /api/maa/instances/:id/click
 ///
 /// Body: `{ "x": 100, "y": 200 }`
@@ -1034,6 +1108,21 @@ async fn handle_post_click(
     }
 }
 
+/// GET /api/maa/instances/:id/controller-info — 设备 UUID、分辨率与方向
+async fn handle_get_controller_info(
+    State(state): State<WebState>,
+    axum::extract::Path(instance_id): axum::extract::Path<String>,
+) -> impl IntoResponse {
+    match get_controller_info_impl(&state.maa_state, &instance_id) {
+        Ok(info) => Json(info).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
 /// GET /api/maa/instances/:id/screenshot
 ///
 /// 返回该实例的最新缓存截图（PNG 二进制）。
@@ -1067,7 +1156,7 @@ async fn handle_get_screenshot(
         if std::time::Instant::now() > deadline {
             return (
                 StatusCode::GATEWAY_TIMEOUT,
-                Json(serde_json::json!({ "error": "截图超时" })),
+                Json(serde_json::json!({ "error": tr!("error.web.screencap_timeout") })),
             )
                 .into_response();
         }
@@ -1147,16 +1236,7 @@ async fn handle_screenshot_unsubscribe(
 /// GET /api/background-image
 /// 读取配置中的背景图路径并返回图片二进制数据
 async fn handle_get_background_image(State(state): State<WebState>) -> impl IntoResponse {
-    let config = match state.app_config.config.lock() {
-        Ok(config) => config.clone(),
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({ "error": "读取背景图配置失败" })),
-            )
-                .into_response();
-        }
-    };
+    let config = state.app_config.config.lock().clone();
 
     let image_path = config
         .get("settings")
@@ -1172,26 +1252,30 @@ async fn handle_get_background_image(State(state): State<WebState>) -> impl Into
                 let content_type = mime_from_extension(ext);
                 (StatusCode::OK, [(header::CONTENT_TYPE, content_type)], data).into_response()
             }
-            Err(e) => (StatusCode::NOT_FOUND, format!("背景图读取失败: {}", e)).into_response(),
+            Err(e) => (
+                StatusCode::NOT_FOUND,
+                tr!("error.web.background_read", error = e),
+            )
+                .into_response(),
         },
-        None => (StatusCode::NOT_FOUND, "未设置背景图片").into_response(),
+        None => (StatusCode::NOT_FOUND, tr!("error.web.background_unset")).into_response(),
     }
 }
 
 /// GET /api/local-file?path=relative/path
 /// 代理 exe 目录下的本地资源文件（图标、描述、翻译等），供浏览器客户端使用。
-/// 包含路径穿越保护，仅允许访问 exe 目录内的文件。
+/// 包含路径穿越保护，仅允许访问 exe 目录与资源目录内的资源文件，不提供配置等用户数据。
 async fn handle_serve_local_file(
     axum::extract::Query(params): axum::extract::Query<std::collections::HashMap<String, String>>,
 ) -> impl IntoResponse {
-    use crate::commands::file_ops::resolve_local_file_path;
+    use crate::commands::file_ops::resolve_served_file_path;
 
     let file_path = match params.get("path") {
         Some(p) if !p.is_empty() => p.as_str(),
-        _ => return (StatusCode::BAD_REQUEST, "缺少 path 参数").into_response(),
+        _ => return (StatusCode::BAD_REQUEST, tr!("error.web.missing_path")).into_response(),
     };
 
-    let resolved = match resolve_local_file_path(file_path) {
+    let resolved = match resolve_served_file_path(file_path) {
         Ok(p) => p,
         Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
     };
@@ -1202,16 +1286,13 @@ async fn handle_serve_local_file(
             let content_type = mime_from_extension(ext);
             (StatusCode::OK, [(header::CONTENT_TYPE, content_type)], data).into_response()
         }
-        Err(_) => (StatusCode::NOT_FOUND, "文件不存在").into_response(),
+        Err(_) => (StatusCode::NOT_FOUND, tr!("error.web.file_not_found")).into_response(),
     }
 }
 
 /// GET /api/logs — 获取所有实例的运行日志
 async fn handle_get_all_logs(State(state): State<WebState>) -> impl IntoResponse {
-    match state.maa_state.log_buffer.lock() {
-        Ok(buffer) => Json(buffer.get_all().clone()).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
-    }
+    Json(state.maa_state.log_buffer.lock().get_all().clone()).into_response()
 }
 
 /// POST /api/logs/:id — 推送一条运行日志
@@ -1220,13 +1301,8 @@ async fn handle_push_log(
     axum::extract::Path(instance_id): axum::extract::Path<String>,
     Json(entry): Json<crate::commands::types::LogEntryDto>,
 ) -> impl IntoResponse {
-    match state.maa_state.log_buffer.lock() {
-        Ok(mut buffer) => {
-            buffer.push(&instance_id, entry);
-            StatusCode::NO_CONTENT.into_response()
-        }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
-    }
+    state.maa_state.log_buffer.lock().push(&instance_id, entry);
+    StatusCode::NO_CONTENT.into_response()
 }
 
 /// DELETE /api/logs/:id — 清空指定实例的运行日志
@@ -1234,13 +1310,29 @@ async fn handle_clear_instance_logs(
     State(state): State<WebState>,
     axum::extract::Path(instance_id): axum::extract::Path<String>,
 ) -> impl IntoResponse {
-    match state.maa_state.log_buffer.lock() {
-        Ok(mut buffer) => {
-            buffer.clear_instance(&instance_id);
-            StatusCode::NO_CONTENT.into_response()
-        }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
-    }
+    state
+        .maa_state
+        .log_buffer
+        .lock()
+        .clear_instance(&instance_id);
+    StatusCode::NO_CONTENT.into_response()
+}
+
+/// GET /api/events/missed?since_seq=0&instance_id=xxx — 补取错过的事件
+async fn handle_fetch_missed_events(
+    State(state): State<WebState>,
+    axum::extract::Query(params): axum::extract::Query<std::collections::HashMap<String, String>>,
+) -> impl IntoResponse {
+    let since_seq = params
+        .get("since_seq")
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(0);
+    let buffer = state.maa_state.event_replay.lock();
+    Json(crate::commands::state::MissedEvents {
+        last_seq: buffer.last_seq(),
+        events: buffer.since(since_seq, params.get("instance_id").map(String::as_str)),
+    })
+    .into_response()
 }
 
 /// GET /api/heartbeat
@@ -1261,7 +1353,7 @@ async fn handle_is_elevated() -> impl IntoResponse {
 /// POST /api/system/restart-as-admin
 /// 以管理员权限重启应用
 async fn handle_restart_as_admin(State(state): State<WebState>) -> impl IntoResponse {
-    match crate::commands::system::restart_as_admin(state.app_handle) {
+    match crate::commands::system::restart_as_admin_impl(&state.app_handle, true) {
         Ok(()) => Json(serde_json::json!({ "ok": true })).into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -1270,3 +1362,26 @@ async fn handle_restart_as_admin(State(state): State<WebState>) -> impl IntoResp
             .into_response(),
     }
 }
+
+/// POST /api/deep-link
+/// 接收新启动进程转发的 mxu:// 链接，推送给前端处理
+async fn handle_deep_link(
+    State(state): State<WebState>,
+    Json(body): Json<serde_json::Value>,
+) -> impl IntoResponse {
+    let Some(url) = body.get("url").and_then(|v| v.as_str()) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": tr!("error.web.missing_url") })),
+        )
+            .into_response();
+    };
+    match crate::deep_link::dispatch(&state.app_handle, url) {
+        Ok(action) => Json(action).into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
@@ -5,6 +5,8 @@ use std::path::PathBuf;
 use winsafe::co::{KEY, REG_OPTION, RRF};
 use winsafe::{GetSystemDirectory, GetSystemWow64Directory, RegistryValue, HKEY};
 
+use crate::i18n::tr;
+
 /// 使用 Win32 API 获取系统目录路径
 fn get_system_directory() -> Option<PathBuf> {
     GetSystemDirectory().map(PathBuf::from).ok()
@@ -15,62 +17,101 @@ fn get_system_wow64_directory() -> Option<PathBuf> {
     GetSystemWow64Directory().map(PathBuf::from).ok()
 }
 
-/// 检测 WebView2 是否已安装（注册表 + DLL 双重检测）
-///
-/// 根据微软官方文档，检查 pv (REG_SZ) 注册表值：
-/// - HKLM 用于 per-machine 安装（管理员权限安装）
-/// - HKCU 用于 per-user 安装（标准用户权限安装）
-/// - pv 值必须存在且不为空、不为 "0.0.0.0"
-///
-/// 参考: https://learn.microsoft.com/en-us/microsoft-edge/webview2/concepts/distribution#detect-if-a-suitable-webview2-runtime-is-already-installed
-pub fn is_webview2_installed() -> bool {
-    // // 测试：强制视为未安装，以调试下载/安装流程。调试完请删除或注释下面这行。
-    // return false;
+/// WebView2 Runtime 在 EdgeUpdate 中的客户端 GUID
+const WEBVIEW2_CLIENT_GUID: &str = "{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}";
+
+/// 注册表中记录的已安装 WebView2 运行时信息
+pub struct InstalledRuntime {
+    /// 版本号（pv 值）
+    pub version: String,
+    /// 安装位置（location 值，部分旧版本不存在）
+    pub location: Option<String>,
+    /// 安装范围："machine" | "user"
+    pub scope: &'static str,
+}
 
-    let registry_locations: &[(HKEY, &str)] = &[
+/// 从注册表读取已安装的 WebView2 运行时版本与安装位置
+pub fn get_installed_runtime() -> Option<InstalledRuntime> {
+    let registry_locations: [(HKEY, String, &'static str); 3] = [
         (
             HKEY::LOCAL_MACHINE,
-            r"SOFTWARE\WOW6432Node\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+            format!(
+                r"SOFTWARE\WOW6432Node\Microsoft\EdgeUpdate\Clients\{}",
+                WEBVIEW2_CLIENT_GUID
+            ),
+            "machine",
         ),
         (
             HKEY::LOCAL_MACHINE,
-            r"SOFTWARE\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+            format!(
+                r"SOFTWARE\Microsoft\EdgeUpdate\Clients\{}",
+                WEBVIEW2_CLIENT_GUID
+            ),
+            "machine",
         ),
         (
             HKEY::CURRENT_USER,
-            r"Software\Microsoft\EdgeUpdate\Clients\{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}",
+            format!(
+                r"Software\Microsoft\EdgeUpdate\Clients\{}",
+                WEBVIEW2_CLIENT_GUID
+            ),
+            "user",
         ),
     ];
 
-    let mut registry_found = false;
-    for (root, path) in registry_locations {
-        let result = root.RegOpenKeyEx(Some(path), REG_OPTION::NoValue, KEY::READ);
-        if let Ok(hkey) = result {
-            let value_result = hkey.RegGetValue(None, Some("pv"), RRF::RT_REG_SZ);
-            if let Ok(RegistryValue::Sz(version)) = value_result {
-                if !version.is_empty() && version != "0.0.0.0" {
-                    registry_found = true;
-                    break;
-                }
-            }
+    for (root, path, scope) in &registry_locations {
+        let Ok(hkey) = root.RegOpenKeyEx(Some(path.as_str()), REG_OPTION::NoValue, KEY::READ)
+        else {
+            continue;
+        };
+        let Ok(RegistryValue::Sz(version)) = hkey.RegGetValue(None, Some("pv"), RRF::RT_REG_SZ)
+        else {
+            continue;
+        };
+        if version.is_empty() || version == "0.0.0.0" {
+            continue;
         }
+        let location = match hkey.RegGetValue(None, Some("location"), RRF::RT_REG_SZ) {
+            Ok(RegistryValue::Sz(loc)) if !loc.is_empty() => Some(loc),
+            _ => None,
+        };
+        return Some(InstalledRuntime {
+            version,
+            location,
+            scope,
+        });
     }
+    None
+}
+
+/// 检测 System32 / SysWOW64 下是否存在 WebView2Loader.dll
+pub fn is_loader_dll_present() -> bool {
+    [get_system_directory(), get_system_wow64_directory()]
+        .into_iter()
+        .flatten()
+        .any(|dir| dir.join("WebView2Loader.dll").exists())
+}
+
+/// 检测 WebView2 是否已安装（注册表 + DLL 双重检测）
+///
+/// 根据微软官方文档，检查 pv (REG_SZ) 注册表值：
+/// - HKLM 用于 per-machine 安装（管理员权限安装）
+/// - HKCU 用于 per-user 安装（标准用户权限安装）
+/// - pv 值必须存在且不为空、不为 "0.0.0.0"
+///
+/// 参考: https://learn.microsoft.com/en-us/microsoft-edge/webview2/concepts/distribution#detect-if-a-suitable-webview2-runtime-is-already-installed
+pub fn is_webview2_installed() -> bool {
+    // // 测试：强制视为未安装，以调试下载/安装流程。调试完请删除或注释下面这行。
+    // return false;
+
+    let registry_found = get_installed_runtime().is_some();
 
     if !registry_found {
         return false;
     }
 
-    let mut dll_paths = Vec::new();
-    if let Some(sys_dir) = get_system_directory() {
-        dll_paths.push(sys_dir.join("WebView2Loader.dll"));
-    }
-    if let Some(wow64_dir) = get_system_wow64_directory() {
-        dll_paths.push(wow64_dir.join("WebView2Loader.dll"));
-    }
-    for dll_path in &dll_paths {
-        if dll_path.exists() {
-            return true;
-        }
+    if is_loader_dll_present() {
+        return true;
     }
 
     registry_found
@@ -104,7 +145,7 @@ pub fn is_webview2_disabled() -> Option<String> {
     ];
 
     for (root, path) in &policy_paths {
-        let result = root.RegOpenKeyEx(Some(path), REG_OPTION::NoValue, KEY::READ);
+        let result = root.RegOpenKeyEx(Some(*path), REG_OPTION::NoValue, KEY::READ);
 
         if let Ok(hkey) = result {
             // 检查 BrowserExecutableFolder 值 - 如果设置为空字符串，表示禁用
@@ -114,7 +155,7 @@ pub fn is_webview2_disabled() -> Option<String> {
             if let Ok(RegistryValue::Sz(value)) = value_result {
                 // 如果值为空字符串，表示通过策略禁用了 WebView2
                 if value.is_empty() {
-                    return Some("通过组策略禁用 (BrowserExecutableFolder 为空)".to_string());
+                    return Some(tr!("webview2.reason.policy_empty_folder"));
                 }
             }
 
@@ -139,7 +180,7 @@ pub fn is_webview2_disabled() -> Option<String> {
     )];
 
     for (root, path) in &feature_paths {
-        let result = root.RegOpenKeyEx(Some(path), REG_OPTION::NoValue, KEY::READ);
+        let result = root.RegOpenKeyEx(Some(*path), REG_OPTION::NoValue, KEY::READ);
 
         if let Ok(hkey) = result {
             // 检查 Enabled 值
@@ -147,7 +188,7 @@ pub fn is_webview2_disabled() -> Option<String> {
 
             if let Ok(RegistryValue::Dword(dword_value)) = value_result {
                 if dword_value == 0 {
-                    return Some("WebView2 已被组策略禁用".to_string());
+                    return Some(tr!("webview2.reason.policy_disabled"));
                 }
             }
         }
@@ -157,7 +198,7 @@ pub fn is_webview2_disabled() -> Option<String> {
     // Edge Blocker v2.0 等工具使用这种方式禁用 Edge/WebView2
     // 通过设置 Debugger 值来阻止进程启动
     // 注意：我们只检查 WebView2 进程，不检查 Edge 浏览器进程
-    let ifeo_targets = [("msedgewebview2.exe", "WebView2 进程 (msedgewebview2.exe)")];
+    let ifeo_targets = [("msedgewebview2.exe", "webview2.reason.process_name")];
 
     for (exe_name, display_name) in &ifeo_targets {
         let ifeo_path = format!(
@@ -175,9 +216,9 @@ pub fn is_webview2_disabled() -> Option<String> {
                 // 存在 Debugger 值，表示进程被 IFEO 拦截
                 if !debugger_value.is_empty() {
                     // 如果 Debugger 值不为空，说明被拦截了
-                    return Some(format!(
-                        "{} 已被 IFEO 禁用\r\n(可能使用了 Edge Blocker 等工具)",
-                        display_name
+                    return Some(tr!(
+                        "webview2.reason.ifeo",
+                        name = crate::i18n::t(display_name)
                     ));
                 }
             }
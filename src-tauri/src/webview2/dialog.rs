@@ -1,6 +1,7 @@
-//! 原生 Win32 对话框（进度、成功、错误）
+//! 原生 Win32 对话框（进度、成功、错误、确认）
 
 use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::mpsc;
 use std::time::Duration;
 
@@ -20,6 +21,18 @@ pub(crate) enum DialogType {
     #[allow(dead_code)]
     Success,
     Error,
+    Confirm,
+}
+
+/// 对话框的附加行为：确认框按钮、进度框取消回调
+#[derive(Default)]
+struct DialogExtras {
+    /// 确认框按钮文本（从左到右）
+    buttons: Vec<String>,
+    /// 确认框点击结果回传（按钮索引）
+    result_tx: Option<mpsc::Sender<usize>>,
+    /// 进度框取消回调，设置后显示"取消"按钮，点 X 也走取消而非退出进程
+    on_cancel: Option<Box<dyn Fn() + Send>>,
 }
 
 #[derive(Default)]
@@ -28,6 +41,8 @@ struct DialogState {
     status_hwnd: Option<Label>,
     edit_hwnd: Option<Edit>,
     button_hwnd: Option<Button>,
+    confirm_buttons: Vec<Button>,
+    on_cancel: Option<Rc<dyn Fn()>>,
     dialog_type: Option<DialogType>,
 }
 
@@ -36,6 +51,8 @@ impl DialogState {
         self.progress_hwnd = None;
         self.status_hwnd = None;
         self.button_hwnd = None;
+        self.confirm_buttons.clear();
+        self.on_cancel = None;
         self.dialog_type = None;
         self.edit_hwnd = None;
     }
@@ -45,6 +62,38 @@ thread_local! {
     static DIALOG_STATE: RefCell<DialogState> = RefCell::new(DialogState::default());
 }
 
+/// 触发进度框的取消回调（仅首次生效），返回是否存在取消回调
+fn trigger_cancel() -> bool {
+    let (callback, button, status) = DIALOG_STATE.with(|s| {
+        let mut g = s.borrow_mut();
+        if g.dialog_type != Some(DialogType::Progress) {
+            return (None, None, None);
+        }
+        let has_cancel = g.on_cancel.is_some();
+        (
+            g.on_cancel.take(),
+            g.button_hwnd.clone(),
+            has_cancel.then(|| g.status_hwnd.clone()).flatten(),
+        )
+    });
+    let had_button = button.is_some();
+
+    let Some(callback) = callback else {
+        // 回调已触发过（正在取消中）时吞掉重复的关闭请求
+        return had_button;
+    };
+    if let Some(btn) = button {
+        btn.hwnd().EnableWindow(false);
+    }
+    if let Some(status) = status {
+        let _ = status
+            .hwnd()
+            .SetWindowText(crate::i18n::t("dialog.cancelling"));
+    }
+    callback();
+    true
+}
+
 pub(crate) struct CustomDialog {
     hwnd: WindowMain,
     handle: Option<std::thread::JoinHandle<()>>,
@@ -52,18 +101,83 @@ pub(crate) struct CustomDialog {
 
 impl CustomDialog {
     pub(crate) fn new_progress(title: &str, initial_status: &str) -> Option<Self> {
-        Self::create(DialogType::Progress, title, initial_status, 440, 150)
+        Self::create(
+            DialogType::Progress,
+            title,
+            initial_status,
+            440,
+            150,
+            DialogExtras::default(),
+        )
+    }
+
+    /// 创建带"取消"按钮的进度对话框
+    ///
+    /// 点击取消或关闭窗口时调用 `on_cancel`（在对话框 UI 线程上执行），
+    /// 由调用方自行中止任务并调用 `close`。
+    pub(crate) fn new_progress_cancellable(
+        title: &str,
+        initial_status: &str,
+        on_cancel: impl Fn() + Send + 'static,
+    ) -> Option<Self> {
+        Self::create(
+            DialogType::Progress,
+            title,
+            initial_status,
+            440,
+            190,
+            DialogExtras {
+                on_cancel: Some(Box::new(on_cancel)),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// 显示确认对话框并阻塞等待用户选择
+    ///
+    /// 返回被点击按钮在 `buttons` 中的索引；用户直接关闭窗口或对话框创建失败时返回 None。
+    /// 第一个按钮为默认按钮。
+    pub(crate) fn show_confirm(title: &str, message: &str, buttons: &[&str]) -> Option<usize> {
+        let (tx, rx) = mpsc::channel();
+        let dialog = Self::create(
+            DialogType::Confirm,
+            title,
+            message,
+            480,
+            240,
+            DialogExtras {
+                buttons: buttons.iter().map(|b| b.to_string()).collect(),
+                result_tx: Some(tx),
+                ..Default::default()
+            },
+        )?;
+        dialog.wait();
+        rx.try_recv().ok()
     }
 
     #[allow(dead_code)]
     pub(crate) fn show_success(title: &str, message: &str) {
-        if let Some(dialog) = Self::create(DialogType::Success, title, message, 420, 170) {
+        if let Some(dialog) = Self::create(
+            DialogType::Success,
+            title,
+            message,
+            420,
+            170,
+            DialogExtras::default(),
+        ) {
             dialog.wait();
         }
     }
 
     pub(crate) fn show_error(title: &str, message: &str) {
-        if let Some(dialog) = Self::create(DialogType::Error, title, message, 560, 500) {
+        if let Some(dialog) = Self::create(
+            DialogType::Error,
+            title,
+            message,
+            560,
+            500,
+            DialogExtras::default(),
+        ) {
             dialog.wait();
         }
     }
@@ -74,6 +188,7 @@ impl CustomDialog {
         message: &str,
         width: i32,
         height: i32,
+        extras: DialogExtras,
     ) -> Option<Self> {
         let title_owned = title.to_string();
         let message_owned = message.to_string();
@@ -131,10 +246,89 @@ impl CustomDialog {
                         },
                     );
 
+                    let on_cancel: Option<Rc<dyn Fn()>> =
+                        extras.on_cancel.map(|f| Rc::new(f) as Rc<dyn Fn()>);
+
+                    let cancel_btn = on_cancel.as_ref().map(|_| {
+                        let btn = Button::new(
+                            &hwnd,
+                            ButtonOpts {
+                                text: crate::i18n::t("dialog.cancel"),
+                                position: ((width - BTN_W) / 2, height - 12 - BTN_H),
+                                width: BTN_W,
+                                height: BTN_H,
+                                ..Default::default()
+                            },
+                        );
+                        btn.on().bn_clicked(move || {
+                            trigger_cancel();
+                            Ok(())
+                        });
+                        btn
+                    });
+
                     DIALOG_STATE.with(|s| {
                         let mut g = s.borrow_mut();
                         g.status_hwnd = Some(status_hwnd);
                         g.progress_hwnd = Some(progressbar_hwnd);
+                        g.button_hwnd = cancel_btn;
+                        g.on_cancel = on_cancel;
+                        g.dialog_type = Some(dialog_type);
+                    });
+                }
+                DialogType::Confirm => {
+                    let text_height = height - (MARGIN + 12 + BTN_H + 12);
+                    let message_hwnd = Edit::new(
+                        &hwnd,
+                        EditOpts {
+                            text: &message_owned,
+                            control_style: ES::MULTILINE | ES::READONLY | ES::AUTOVSCROLL,
+                            position: (MARGIN, MARGIN),
+                            width: width - 2 * MARGIN,
+                            height: text_height,
+                            ..Default::default()
+                        },
+                    );
+
+                    // 按钮靠右排列，间距 8
+                    const BTN_GAP: i32 = 8;
+                    let count = extras.buttons.len() as i32;
+                    let mut x = width - MARGIN - count * BTN_W - (count - 1).max(0) * BTN_GAP;
+                    let mut buttons = Vec::with_capacity(extras.buttons.len());
+                    for (index, text) in extras.buttons.iter().enumerate() {
+                        let btn = Button::new(
+                            &hwnd,
+                            ButtonOpts {
+                                text,
+                                position: (x, height - 12 - BTN_H),
+                                width: BTN_W,
+                                height: BTN_H,
+                                control_style: if index == 0 {
+                                    BS::DEFPUSHBUTTON
+                                } else {
+                                    BS::PUSHBUTTON
+                                },
+                                ..Default::default()
+                            },
+                        );
+                        x += BTN_W + BTN_GAP;
+
+                        let evt_hwnd = hwnd.clone();
+                        let result_tx = extras.result_tx.clone();
+                        btn.on().bn_clicked(move || {
+                            if let Some(tx) = &result_tx {
+                                let _ = tx.send(index);
+                            }
+                            evt_hwnd.close();
+                            Ok(())
+                        });
+                        buttons.push(btn);
+                    }
+
+                    DIALOG_STATE.with(|s| {
+                        let mut g = s.borrow_mut();
+                        g.edit_hwnd = Some(message_hwnd);
+                        g.confirm_buttons = buttons;
                         g.dialog_type = Some(dialog_type);
                     });
                 }
@@ -155,7 +349,7 @@ impl CustomDialog {
                     let btn_hwnd = Button::new(
                         &hwnd,
                         ButtonOpts {
-                            text: "确定",
+                            text: crate::i18n::t("dialog.ok"),
                             position: ((width - BTN_W) / 2, height - 12 - BTN_H),
                             width: BTN_W,
                             height: BTN_H,
@@ -182,11 +376,15 @@ impl CustomDialog {
             let _ = tx_hwnd.send(hwnd.clone());
 
             hwnd.on().wm_close(move || {
-                DIALOG_STATE.with(|s| {
-                    if s.borrow().dialog_type == Some(DialogType::Progress) {
-                        std::process::exit(0);
+                let is_progress =
+                    DIALOG_STATE.with(|s| s.borrow().dialog_type == Some(DialogType::Progress));
+                if is_progress {
+                    // 可取消的进度框：交给调用方中止任务，由其调用 close
+                    if trigger_cancel() {
+                        return Ok(());
                     }
-                });
+                    std::process::exit(0);
+                }
                 PostQuitMessage(0);
                 Ok(())
             });
@@ -10,8 +10,12 @@ use log::{info, warn};
 use std::io::Read;
 use std::os::windows::process::CommandExt;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use winsafe::GetSystemDirectory;
 
+use crate::i18n::{self, tr};
+
 /// WebView2 Fixed Version Runtime 版本号及对应的下载 GUID。
 /// **三者必须保持一致**——更新版本时需同时更新 `WEBVIEW2_VERSION`、`GUID_X64` 和 `GUID_ARM64`。
 /// GUID 可在 https://developer.microsoft.com/en-us/microsoft-edge/webview2/ 页面
@@ -26,35 +30,41 @@ const GUID_ARM64: &str = "2d2cf37b-d24c-4c72-b5bc-e8061e7a7583";
 /// 隐藏控制台窗口标志
 const CREATE_NO_WINDOW: u32 = 0x08000000;
 
+/// Evergreen Standalone 安装包下载链接（完整离线安装包，不依赖 bootstrapper 在线拉取）
+const STANDALONE_URL_X64: &str = "https://go.microsoft.com/fwlink/?linkid=2124701";
+const STANDALONE_URL_ARM64: &str = "https://go.microsoft.com/fwlink/?linkid=2099616";
+
+/// Evergreen Bootstrapper 下载链接（小体积在线安装器）
+const BOOTSTRAPPER_URL: &str = "https://go.microsoft.com/fwlink/p/?LinkId=2124703";
+
+/// 获取当前架构对应的 Standalone 安装包下载链接
+fn get_standalone_url() -> Result<&'static str, String> {
+    match get_arch_info()?.0 {
+        "arm64" => Ok(STANDALONE_URL_ARM64),
+        _ => Ok(STANDALONE_URL_X64),
+    }
+}
+
 /// 获取当前架构对应的下载标签和 GUID
 fn get_arch_info() -> Result<(&'static str, &'static str), String> {
     match std::env::consts::ARCH {
         "x86_64" => Ok(("x64", GUID_X64)),
         "aarch64" => Ok(("arm64", GUID_ARM64)),
-        other => Err(format!(
-            "不支持的 CPU 架构: {}。当前应用仅支持 64 位 Windows（x64、ARM64），请在 64 位系统上运行。",
-            other
-        )),
+        other => Err(tr!("error.webview2.unsupported_arch", arch = other)),
     }
 }
 
 /// 获取 WebView2 固定版本运行时的目录路径（exe 同级 cache 目录下）
 pub fn get_webview2_runtime_dir() -> Result<PathBuf, String> {
-    let exe_path = std::env::current_exe().map_err(|e| format!("获取程序路径失败: {}", e))?;
-    let exe_dir = exe_path
-        .parent()
-        .ok_or_else(|| "无法获取程序目录".to_string())?;
+    let exe_path = std::env::current_exe().map_err(|e| tr!("error.fs.exe_path", error = e))?;
+    let exe_dir = exe_path.parent().ok_or_else(|| tr!("error.fs.exe_dir"))?;
     Ok(exe_dir.join("cache").join("webview2_runtime"))
 }
 
 /// 验证运行时目录包含关键可执行文件
 fn validate_runtime_dir(runtime_dir: &std::path::Path) -> Result<(), String> {
     if !runtime_dir.join("msedgewebview2.exe").exists() {
-        return Err(
-            "解压后的 WebView2 运行时目录不完整（未找到 msedgewebview2.exe）。\n\
-            请删除 cache/webview2_runtime/ 目录后重启程序重试。"
-                .to_string(),
-        );
+        return Err(tr!("error.webview2.runtime_incomplete"));
     }
     Ok(())
 }
@@ -66,52 +76,40 @@ fn show_download_failed_dialog(error: &str) {
                 "Microsoft.WebView2.FixedVersionRuntime.{}.{}.cab",
                 WEBVIEW2_VERSION, arch_label
             );
-            let message = format!(
-                "系统 WebView2 不可用，下载独立 WebView2 运行时失败：\r\n\
-                 {}\r\n\r\n\
-                 【方法一】检查网络连接后重启程序重试\r\n\r\n\
-                 【方法二】手动下载 cab 文件并放到程序同目录\r\n\
-                 1. 前往 https://aka.ms/webview2installer\r\n\
-                    选择 \"Fixed Version\" 下载对应架构（{}）的 cab 文件\r\n\
-                 2. 将下载的 cab 文件（文件名类似 {}）\r\n\
-                    放到本程序 exe 所在目录下\r\n\
-                 3. 重启程序，将自动检测并解压使用\r\n\r\n\
-                 【方法三】手动安装系统 WebView2 运行时\r\n\
-                 前往 https://aka.ms/webview2installer\r\n\
-                 下载 Evergreen Bootstrapper，运行安装后重启电脑即可",
-                error, arch_label, cab_name
+            let message = tr!(
+                "error.webview2.download_failed_manual",
+                error = error,
+                arch = arch_label,
+                cab_name = cab_name
             );
-            CustomDialog::show_error("WebView2 下载失败", &message);
+            CustomDialog::show_error(i18n::t("webview2.download_failed_title"), &message);
         }
         Err(arch_err) => {
-            let message = format!(
-                "系统 WebView2 不可用，下载独立 WebView2 运行时失败：\r\n\
-                 {}\r\n\r\n\
-                 此外，无法判断当前系统架构：{}\r\n\r\n\
-                 【手动安装系统 WebView2 运行时】\r\n\
-                 前往 https://aka.ms/webview2installer\r\n\
-                 下载 Evergreen Bootstrapper，运行安装后重启电脑即可",
-                error, arch_err
+            let message = tr!(
+                "error.webview2.download_failed_no_arch",
+                error = error,
+                arch_error = arch_err
             );
-            CustomDialog::show_error("WebView2 下载失败", &message);
+            CustomDialog::show_error(i18n::t("webview2.download_failed_title"), &message);
         }
     }
 }
 
 /// 递归复制目录内容
 fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<(), String> {
-    std::fs::create_dir_all(dst).map_err(|e| format!("无法创建目录 [{}]: {}", dst.display(), e))?;
+    std::fs::create_dir_all(dst)
+        .map_err(|e| tr!("error.fs.create_dir", path = dst.display(), error = e))?;
 
-    for entry in
-        std::fs::read_dir(src).map_err(|e| format!("无法读取目录 [{}]: {}", src.display(), e))?
+    for entry in std::fs::read_dir(src)
+        .map_err(|e| tr!("error.fs.read_dir", path = src.display(), error = e))?
     {
-        let entry = entry.map_err(|e| format!("无法读取目录条目: {}", e))?;
+        let entry = entry.map_err(|e| tr!("error.fs.read_dir_entry", error = e))?;
         let src_item = entry.path();
         let dst_item = dst.join(entry.file_name());
 
         let file_type = entry
             .file_type()
-            .map_err(|e| format!("无法获取文件类型: {}", e))?;
+            .map_err(|e| tr!("error.fs.file_type", error = e))?;
         if file_type.is_symlink() {
             // WebView2 cab 中不应包含符号链接，跳过以避免安全风险
             continue;
@@ -121,11 +119,11 @@ fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Result<()
             copy_dir_recursive(&src_item, &dst_item)?;
         } else {
             std::fs::copy(&src_item, &dst_item).map_err(|e| {
-                format!(
-                    "无法复制文件 [{}] -> [{}]: {}",
-                    src_item.display(),
-                    dst_item.display(),
-                    e
+                tr!(
+                    "error.fs.copy",
+                    from = src_item.display(),
+                    to = dst_item.display(),
+                    error = e
                 )
             })?;
         }
@@ -141,13 +139,13 @@ fn get_expand_exe_path() -> Result<std::path::PathBuf, String> {
         if expand_path.exists() {
             Ok(expand_path)
         } else {
-            Err(format!(
-                "未找到 expand.exe，请确认系统完整性。\n预期路径: {}",
-                expand_path.display()
+            Err(tr!(
+                "error.webview2.expand_missing",
+                path = expand_path.display()
             ))
         }
     } else {
-        Err("GetSystemDirectory 调用失败，无法获取系统目录".to_string())
+        Err(tr!("error.webview2.system_dir"))
     }
 }
 
@@ -162,7 +160,8 @@ fn extract_cab_to_runtime(
     let extract_temp = temp_dir.join(format!("mxu_webview2_extract_{}", std::process::id()));
 
     let _ = std::fs::remove_dir_all(&extract_temp);
-    std::fs::create_dir_all(&extract_temp).map_err(|e| format!("创建临时目录失败: {}", e))?;
+    std::fs::create_dir_all(&extract_temp)
+        .map_err(|e| tr!("error.webview2.create_temp_dir", error = e))?;
 
     let result = do_extract(&expand_exe, cab_path, &extract_temp, runtime_dir);
 
@@ -185,10 +184,13 @@ fn do_extract(
         .arg(extract_temp)
         .creation_flags(CREATE_NO_WINDOW)
         .status()
-        .map_err(|e| format!("运行 expand.exe 失败: {}", e))?;
+        .map_err(|e| tr!("error.webview2.expand_run", error = e))?;
 
     if !status.success() {
-        return Err(format!("解压失败，退出码: {}", status.code().unwrap_or(-1)));
+        return Err(tr!(
+            "error.webview2.expand_failed",
+            code = status.code().unwrap_or(-1)
+        ));
     }
 
     // cab 解压后文件可能在版本子目录中
@@ -211,41 +213,32 @@ fn do_extract(
     // 防止通过构造链接删除任意目录
     if runtime_dir.exists() {
         let meta = std::fs::symlink_metadata(runtime_dir)
-            .map_err(|e| format!("读取运行时目录元数据失败: {}", e))?;
+            .map_err(|e| tr!("error.webview2.runtime_metadata", error = e))?;
         let is_reparse = {
             use std::os::windows::fs::MetadataExt;
             const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
             meta.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
         };
         if meta.file_type().is_symlink() || is_reparse {
-            return Err(format!(
-                "运行时目录 [{}] 是符号链接或重解析点，出于安全原因拒绝操作。\n\
-                请手动删除该链接后重试。",
-                runtime_dir.display()
+            return Err(tr!(
+                "error.webview2.runtime_is_link",
+                path = runtime_dir.display()
             ));
         }
         if let Err(e) = std::fs::remove_dir_all(runtime_dir) {
             let msg = if e.kind() == std::io::ErrorKind::PermissionDenied {
-                format!(
-                    "删除旧的 WebView2 运行时目录失败，可能有正在运行的程序正在使用该目录。\n\n\
-                    请关闭所有已运行的本应用实例后重试。\n\n系统错误: {}",
-                    e
-                )
+                tr!("error.webview2.remove_runtime_in_use", error = e)
             } else {
-                format!("删除旧的 WebView2 运行时目录失败: {}", e)
+                tr!("error.webview2.remove_runtime", error = e)
             };
             return Err(msg);
         }
     }
     std::fs::create_dir_all(runtime_dir).map_err(|e| {
         if e.kind() == std::io::ErrorKind::PermissionDenied {
-            format!(
-                "创建运行时目录失败，可能缺少权限或有程序占用该路径。\n\n\
-                请关闭所有已运行的本应用实例或以管理员身份重新运行。\n\n系统错误: {}",
-                e
-            )
+            tr!("error.webview2.create_runtime_denied", error = e)
         } else {
-            format!("创建运行时目录失败: {}", e)
+            tr!("error.webview2.create_runtime", error = e)
         }
     })?;
 
@@ -326,8 +319,8 @@ fn try_extract_local_cab(runtime_dir: &std::path::Path) -> Option<Result<(), Str
     if let Some(cab_path) = matched {
         info!("检测到本地 WebView2 cab 文件: {}", cab_path.display());
         let progress_dialog = CustomDialog::new_progress(
-            "正在解压 WebView2",
-            "检测到本地 WebView2 运行时 cab 文件，正在解压...",
+            i18n::t("webview2.extracting_title"),
+            i18n::t("webview2.extracting_local"),
         );
 
         let result = extract_cab_to_runtime(&cab_path, runtime_dir);
@@ -354,13 +347,11 @@ fn try_extract_local_cab(runtime_dir: &std::path::Path) -> Option<Result<(), Str
     // 仅存在不匹配的 cab，弹窗提示
     if let Some(cab_arch) = mismatched_arch {
         CustomDialog::show_error(
-            "WebView2 架构不匹配",
-            &format!(
-                "检测到本地 WebView2 运行时 cab 文件，但架构不匹配：\r\n\
-                 文件架构: {}\r\n\
-                 系统架构: {}\r\n\r\n\
-                 将忽略该文件并尝试在线下载正确版本。",
-                cab_arch, expected_arch
+            i18n::t("webview2.arch_mismatch_title"),
+            &tr!(
+                "error.webview2.arch_mismatch",
+                cab_arch = cab_arch,
+                expected_arch = expected_arch
             ),
         );
     }
@@ -368,6 +359,95 @@ fn try_extract_local_cab(runtime_dir: &std::path::Path) -> Option<Result<(), Str
     None
 }
 
+/// 下载被用户取消时返回的错误
+fn cancelled_error() -> String {
+    tr!("error.webview2.cancelled")
+}
+
+/// 流式下载文件到指定路径，并在进度对话框中显示进度
+///
+/// `cancel_flag` 被置位时中止下载并返回 [`cancelled_error`]。
+fn download_with_progress(
+    url: &str,
+    dest: &std::path::Path,
+    progress_dialog: Option<&CustomDialog>,
+    status_prefix: &str,
+    cancel_flag: Option<&AtomicBool>,
+) -> Result<(), String> {
+    let client = reqwest::blocking::Client::builder()
+        .danger_accept_invalid_certs(false)
+        .tls_built_in_root_certs(true)
+        .connect_timeout(std::time::Duration::from_secs(30))
+        .timeout(std::time::Duration::from_secs(600))
+        .build()
+        .map_err(|e| tr!("error.download.client", error = e))?;
+
+    let response = client
+        .get(url)
+        .send()
+        .map_err(|e| tr!("error.download.request", error = e))?;
+
+    if !response.status().is_success() {
+        return Err(tr!(
+            "error.download.http_status",
+            status = response.status()
+        ));
+    }
+
+    let total_size = response.content_length().unwrap_or(0);
+    let mut downloaded: u64 = 0;
+    let mut reader = std::io::BufReader::with_capacity(256 * 1024, response);
+    let mut file =
+        std::fs::File::create(dest).map_err(|e| tr!("error.download.create_file", error = e))?;
+    let mut chunk = [0u8; 256 * 1024];
+    let mut last_ui_update = std::time::Instant::now();
+
+    loop {
+        if cancel_flag.is_some_and(|f| f.load(Ordering::Relaxed)) {
+            return Err(cancelled_error());
+        }
+
+        let bytes_read = reader
+            .read(&mut chunk)
+            .map_err(|e| tr!("error.download.read_body", error = e))?;
+
+        if bytes_read == 0 {
+            break;
+        }
+
+        std::io::Write::write_all(&mut file, &chunk[..bytes_read])
+            .map_err(|e| tr!("error.download.write_file", error = e))?;
+        downloaded += bytes_read as u64;
+
+        // 节流 UI 更新，避免 run_ui_thread 跨线程同步调用阻塞下载
+        if last_ui_update.elapsed() >= std::time::Duration::from_millis(200) {
+            last_ui_update = std::time::Instant::now();
+            if let Some(pw) = progress_dialog {
+                if total_size > 0 {
+                    let percent = ((downloaded as f64 / total_size as f64) * 100.0) as u32;
+                    pw.set_progress(percent);
+                    pw.set_status(format!(
+                        "{} {:.1} MB / {:.1} MB",
+                        status_prefix,
+                        downloaded as f64 / 1024.0 / 1024.0,
+                        total_size as f64 / 1024.0 / 1024.0
+                    ));
+                } else {
+                    pw.set_status(format!(
+                        "{} {:.1} MB",
+                        status_prefix,
+                        downloaded as f64 / 1024.0 / 1024.0
+                    ));
+                }
+            }
+        }
+    }
+
+    std::io::Write::flush(&mut file).map_err(|e| tr!("error.download.flush", error = e))?;
+
+    Ok(())
+}
+
 /// 下载或解压 WebView2 Fixed Version Runtime 到本地
 pub fn download_and_extract() -> Result<(), String> {
     let (arch_label, guid) = get_arch_info()?;
@@ -397,79 +477,21 @@ pub fn download_and_extract() -> Result<(), String> {
         download_url
     );
     let progress_dialog = CustomDialog::new_progress(
-        "正在下载 WebView2",
-        "系统 WebView2 不可用，正在下载独立 WebView2...",
+        i18n::t("webview2.downloading_title"),
+        i18n::t("webview2.downloading_runtime"),
     );
 
     let temp_dir = std::env::temp_dir();
     let cab_path = temp_dir.join(format!("{}_{}", std::process::id(), &cab_name));
 
     // 下载 cab 文件（流式写入磁盘）
-    let download_result = (|| -> Result<(), String> {
-        let client = reqwest::blocking::Client::builder()
-            .danger_accept_invalid_certs(false)
-            .tls_built_in_root_certs(true)
-            .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(600))
-            .build()
-            .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
-
-        let response = client
-            .get(&download_url)
-            .send()
-            .map_err(|e| format!("网络请求失败: {}", e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("服务器返回错误: {}", response.status()));
-        }
-
-        let total_size = response.content_length().unwrap_or(0);
-        let mut downloaded: u64 = 0;
-        let mut reader = std::io::BufReader::with_capacity(256 * 1024, response);
-        let mut file =
-            std::fs::File::create(&cab_path).map_err(|e| format!("创建下载文件失败: {}", e))?;
-        let mut chunk = [0u8; 256 * 1024];
-        let mut last_ui_update = std::time::Instant::now();
-
-        loop {
-            let bytes_read = reader
-                .read(&mut chunk)
-                .map_err(|e| format!("读取下载内容失败: {}", e))?;
-
-            if bytes_read == 0 {
-                break;
-            }
-
-            std::io::Write::write_all(&mut file, &chunk[..bytes_read])
-                .map_err(|e| format!("写入文件失败: {}", e))?;
-            downloaded += bytes_read as u64;
-
-            // 节流 UI 更新，避免 run_ui_thread 跨线程同步调用阻塞下载
-            if last_ui_update.elapsed() >= std::time::Duration::from_millis(200) {
-                last_ui_update = std::time::Instant::now();
-                if let Some(ref pw) = progress_dialog {
-                    if total_size > 0 {
-                        let percent = ((downloaded as f64 / total_size as f64) * 100.0) as u32;
-                        pw.set_progress(percent);
-                        pw.set_status(format!(
-                            "正在下载独立 WebView2... {:.1} MB / {:.1} MB",
-                            downloaded as f64 / 1024.0 / 1024.0,
-                            total_size as f64 / 1024.0 / 1024.0
-                        ));
-                    } else {
-                        pw.set_status(format!(
-                            "正在下载独立 WebView2... {:.1} MB",
-                            downloaded as f64 / 1024.0 / 1024.0
-                        ));
-                    }
-                }
-            }
-        }
-
-        std::io::Write::flush(&mut file).map_err(|e| format!("刷新文件缓冲失败: {}", e))?;
-
-        Ok(())
-    })();
+    let download_result = download_with_progress(
+        &download_url,
+        &cab_path,
+        progress_dialog.as_ref(),
+        i18n::t("webview2.downloading_runtime_status"),
+        None,
+    );
 
     let download_err = download_result.err();
     if let Some(ref e) = download_err {
@@ -483,7 +505,7 @@ pub fn download_and_extract() -> Result<(), String> {
     // 更新进度：解压中
     if let Some(ref pw) = progress_dialog {
         pw.set_progress(100);
-        pw.set_status("正在解压...".to_string());
+        pw.set_status(tr!("webview2.extracting_status"));
     }
 
     // 解压 cab 文件
@@ -511,32 +533,116 @@ pub fn download_and_extract() -> Result<(), String> {
     Ok(())
 }
 
+/// 下载并静默运行 Evergreen Standalone 安装包
+///
+/// 作为固定版本运行时下载失败后的兜底：Standalone 包体积较大但为完整离线包，
+/// 在部分 CDN 不可达的代理环境下仍可通过 go.microsoft.com 跳转下载。
+/// 非管理员权限下安装程序会自动按用户安装。
+fn install_standalone_runtime() -> Result<(), String> {
+    let url = get_standalone_url()?;
+    info!("尝试下载 WebView2 Standalone 安装包: {}", url);
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let cancelled_cb = cancelled.clone();
+    let progress_dialog = CustomDialog::new_progress_cancellable(
+        i18n::t("webview2.downloading_installer_title"),
+        i18n::t("webview2.downloading_installer"),
+        move || cancelled_cb.store(true, Ordering::Relaxed),
+    );
+
+    let installer_path = std::env::temp_dir().join(format!(
+        "{}_MicrosoftEdgeWebView2RuntimeInstaller.exe",
+        std::process::id()
+    ));
+
+    let result = (|| -> Result<(), String> {
+        download_with_progress(
+            url,
+            &installer_path,
+            progress_dialog.as_ref(),
+            i18n::t("webview2.downloading_installer_status"),
+            Some(&cancelled),
+        )?;
+
+        if let Some(ref pw) = progress_dialog {
+            pw.set_progress(100);
+            pw.set_status(tr!("webview2.installing_status"));
+        }
+
+        let status = std::process::Command::new(&installer_path)
+            .args(["/silent", "/install"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .status()
+            .map_err(|e| tr!("error.webview2.installer_run", error = e))?;
+
+        if !status.success() {
+            return Err(tr!(
+                "error.webview2.installer_exit",
+                code = status.code().unwrap_or(-1)
+            ));
+        }
+
+        if !is_webview2_installed() {
+            return Err(tr!("error.webview2.installer_no_runtime"));
+        }
+        Ok(())
+    })();
+
+    if let Some(pw) = progress_dialog {
+        pw.close();
+    }
+    let _ = std::fs::remove_file(&installer_path);
+
+    result
+}
+
+/// 下载并静默运行 Evergreen Bootstrapper，用于修复损坏或缺失的系统 WebView2
+///
+/// 不显示进度对话框（由前端展示状态），返回安装程序退出后的检测结果。
+pub fn run_evergreen_bootstrapper() -> Result<(), String> {
+    let bootstrapper_path = std::env::temp_dir().join(format!(
+        "{}_MicrosoftEdgeWebview2Setup.exe",
+        std::process::id()
+    ));
+
+    let result = (|| -> Result<(), String> {
+        info!("下载 WebView2 Bootstrapper: {}", BOOTSTRAPPER_URL);
+        download_with_progress(BOOTSTRAPPER_URL, &bootstrapper_path, None, "", None)?;
+
+        let status = std::process::Command::new(&bootstrapper_path)
+            .args(["/silent", "/install"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .status()
+            .map_err(|e| tr!("error.webview2.bootstrapper_run", error = e))?;
+
+        if !status.success() {
+            return Err(tr!(
+                "error.webview2.bootstrapper_exit",
+                code = status.code().unwrap_or(-1)
+            ));
+        }
+
+        if !is_webview2_installed() {
+            return Err(tr!("error.webview2.bootstrapper_no_runtime"));
+        }
+        Ok(())
+    })();
+
+    let _ = std::fs::remove_file(&bootstrapper_path);
+    result
+}
+
 /// 确保 WebView2 可用：优先使用系统安装，不可用时自动下载独立运行时
 pub fn ensure_webview2() -> bool {
+    // 此时尚未读取配置，按系统语言显示对话框
+    i18n::set_locale("system");
+
     // 检测 WebView2 是否被禁用，弹窗提示后继续走独立运行时流程
     if let Some(reason) = is_webview2_disabled() {
         info!("系统 WebView2 已被禁用: {}", reason);
         CustomDialog::show_error(
-            "系统 WebView2 已被禁用",
-            &format!(
-                "检测到系统 WebView2 已被禁用：\r\n{}\r\n\r\n\
-                 【什么是 WebView2？】\r\n\
-                 WebView2 是微软提供的网页渲染组件，本程序依赖它来\r\n\
-                 显示界面。如果 WebView2 被禁用，程序将无法正常运行。\r\n\r\n\
-                 【如何解决？】\r\n\
-                 方法一：如果使用了 Edge Blocker 等工具\r\n\
-                 - 打开 Edge Blocker，点击\"Unblock\"解除禁用\r\n\
-                 - 或删除注册表中的 IFEO 拦截项\r\n\r\n\
-                 方法二：修改组策略（需要管理员权限）\r\n\
-                 1. 按 Win + R，输入 gpedit.msc\r\n\
-                 2. 导航到：计算机配置 > 管理模板 > Microsoft Edge WebView2\r\n\
-                 3. 将相关策略设置为\"未配置\"或\"已启用\"\r\n\r\n\
-                 方法三：加入我们的 QQ 群，获取帮助和支持\r\n\
-                 - 群号可在我们的官网或文档底部找到\r\n\r\n\
-                 点击确定后将尝试下载独立 WebView2 运行时以继续运行。\r\n\
-                 若想恢复使用系统 WebView2，请删除 exe 目录下的 cache/webview2_runtime 文件夹",
-                reason
-            ),
+            i18n::t("webview2.disabled_title"),
+            &tr!("error.webview2.disabled", reason = reason),
         );
     } else if is_webview2_installed() {
         // 系统 WebView2 可用且未被禁用，直接使用
@@ -546,10 +652,46 @@ pub fn ensure_webview2() -> bool {
 
     // 系统不可用或被禁用，下载独立 WebView2 运行时
     info!("系统 WebView2 不可用，尝试下载独立运行时");
-    match download_and_extract() {
-        Ok(()) => true,
+    let runtime_err = match download_and_extract() {
+        Ok(()) => return true,
+        Err(e) => e,
+    };
+
+    // 被组策略/IFEO 禁用时安装系统运行时也无法使用，直接提示
+    if is_webview2_disabled().is_some() {
+        show_download_failed_dialog(&runtime_err);
+        return false;
+    }
+
+    warn!("下载独立运行时失败: {}", runtime_err);
+
+    // Standalone 安装包约 200MB 且会安装到系统，先征得用户同意
+    let choice = CustomDialog::show_confirm(
+        i18n::t("webview2.download_failed_title"),
+        &tr!("error.webview2.confirm_installer", error = runtime_err),
+        &[i18n::t("webview2.install"), i18n::t("webview2.exit")],
+    );
+    if choice != Some(0) {
+        info!("用户拒绝安装 WebView2 Standalone 安装包");
+        return false;
+    }
+
+    match install_standalone_runtime() {
+        Ok(()) => {
+            info!("已通过 Standalone 安装包安装系统 WebView2");
+            true
+        }
+        Err(e) if e == cancelled_error() => {
+            info!("用户取消了 WebView2 Standalone 安装包下载");
+            false
+        }
         Err(e) => {
-            show_download_failed_dialog(&e);
+            warn!("Standalone 安装包安装失败: {}", e);
+            show_download_failed_dialog(&tr!(
+                "error.webview2.fallback_also_failed",
+                error = runtime_err,
+                fallback_error = e
+            ));
             false
         }
     }
@@ -4,5 +4,7 @@ mod detection;
 mod dialog;
 mod install;
 
+pub use detection::{get_installed_runtime, is_loader_dll_present, is_webview2_disabled};
 pub use install::ensure_webview2;
 pub use install::get_webview2_runtime_dir;
+pub use install::run_evergreen_bootstrapper;
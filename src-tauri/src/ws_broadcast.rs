@@ -1,7 +1,7 @@
 //! WebSocket 广播基础设施
 //!
-//! 提供发布-订阅模型，将 Maa 回调、Agent 输出、配置变更等事件
-//! 广播给所有已连接的 WebSocket 客户端。
+//! 提供发布-订阅模型，将 Maa 回调、Agent 输出、下载进度、配置变更等事件
+//! 广播给所有已连接的 WebSocket 客户端（Web UI 与外部订阅者）。
 
 use serde::Serialize;
 use tokio::sync::broadcast;
@@ -14,7 +14,23 @@ use tokio::sync::broadcast;
 pub enum WsEvent {
     /// Maa 框架回调（对应 Tauri `maa-callback` 事件）
     #[serde(rename = "maa-callback")]
-    MaaCallback { message: String, details: String },
+    MaaCallback {
+        instance_id: String,
+        message: String,
+        details: String,
+    },
+
+    /// 类型化的 Maa 回调（对应 Tauri `maa-event` 事件）
+    #[serde(rename = "maa-event")]
+    MaaEvent(crate::commands::maa_event::MaaEventPayload),
+
+    /// focus 运行日志（对应 Tauri `maa-focus-log` 事件）
+    #[serde(rename = "maa-focus-log")]
+    FocusLog(crate::commands::focus_log::FocusLogEntry),
+
+    /// 控制器分辨率变化（对应 Tauri `maa-resolution-changed` 事件）
+    #[serde(rename = "maa-resolution-changed")]
+    ResolutionChanged(crate::commands::types::ResolutionChangedEvent),
 
     /// Agent 子进程输出（对应 Tauri `maa-agent-output` 事件）
     #[serde(rename = "maa-agent-output")]
@@ -24,13 +40,45 @@ pub enum WsEvent {
         line: String,
     },
 
+    /// 下载进度（对应 Tauri `download-progress` 事件）
+    #[serde(rename = "download-progress")]
+    DownloadProgress {
+        session_id: u64,
+        downloaded_size: u64,
+        total_size: u64,
+        speed: u64,
+        progress: f64,
+    },
+
     /// 配置被某个客户端修改，其它客户端需重新拉取
     #[serde(rename = "config-changed")]
     ConfigChanged,
 
     /// Maa 实例状态变更（连接状态、任务状态等）
     #[serde(rename = "state-changed")]
-    StateChanged { instance_id: String, kind: String },
+    StateChanged {
+        instance_id: String,
+        kind: String,
+        /// 触发该变更的命令关联 ID（见 `commands::trace`）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        correlation_id: Option<String>,
+    },
+}
+
+impl WsEvent {
+    /// 事件类型名（与序列化后的 `type` 字段一致）
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WsEvent::MaaCallback { .. } => "maa-callback",
+            WsEvent::MaaEvent(_) => "maa-event",
+            WsEvent::FocusLog(_) => "maa-focus-log",
+            WsEvent::ResolutionChanged(_) => "maa-resolution-changed",
+            WsEvent::AgentOutput { .. } => "maa-agent-output",
+            WsEvent::DownloadProgress { .. } => "download-progress",
+            WsEvent::ConfigChanged => "config-changed",
+            WsEvent::StateChanged { .. } => "state-changed",
+        }
+    }
 }
 
 /// 全局广播器，包装 `broadcast::Sender<WsEvent>`
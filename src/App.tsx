@@ -31,6 +31,7 @@ import {
 } from '@/services';
 import { loadIconAsDataUrl } from '@/services/contentResolver';
 import * as wsService from '@/services/wsService';
+import type { ClockDriftReport, DeepLinkAction, IntegrityReport } from '@/services/maaService';
 import {
   downloadUpdate,
   getUpdateSavePath,
@@ -58,7 +59,7 @@ import { getAllLogsFromBackend } from '@/utils/logStdout';
 import { useMaaCallbackLogger, useMaaAgentLogger } from '@/utils/useMaaCallbackLogger';
 import { getInterfaceLangKey } from '@/i18n';
 import { applyTheme, resolveThemeMode, registerCustomAccent, clearCustomAccents } from '@/themes';
-import { Toaster } from 'sonner';
+import { Toaster, toast } from 'sonner';
 import { loadWebUIAppearance, loadWebUILayout } from '@/services/appearanceStorage';
 import {
   clearPersistedRuntimeLogs,
@@ -80,6 +81,7 @@ import {
   MIN_LEFT_PANEL_WIDTH,
 } from '@/utils/windowUtils';
 import { LoadingScreen } from './components/app';
+import { ConfigUnlockModal } from './components/app/ConfigUnlockModal';
 import { ConnectionLostOverlay } from './components/app/ConnectionLostOverlay';
 import { WebUIBetaBanner } from './components/app/WebUIBetaBanner';
 import { startGlobalCallbackListener } from './components/connection/callbackCache';
@@ -141,6 +143,9 @@ function App() {
   );
   const [showVCRedistModal, setShowVCRedistModal] = useState(false);
   const [showBadPathModal, setShowBadPathModal] = useState(false);
+  // 配置加密（密码模式）锁定时，加载配置前等待用户解锁
+  const [showConfigUnlock, setShowConfigUnlock] = useState(false);
+  const configUnlockResolverRef = useRef<(() => void) | null>(null);
   const [badPathType, setBadPathType] = useState<BadPathType>('root');
   const [backgroundImageDataUrl, setBackgroundImageDataUrl] = useState<string | undefined>(
     undefined,
@@ -266,8 +271,9 @@ function App() {
         let ext: string;
 
         if (isTauri()) {
-          const { readFile } = await import('@tauri-apps/plugin-fs');
-          fileData = await readFile(backgroundImage);
+          // 经后端读取：对话框授予的文件访问权限在重启后失效
+          const base64 = await invoke<string>('read_image_file', { path: backgroundImage });
+          fileData = Uint8Array.from(atob(base64), (c) => c.charCodeAt(0));
           ext = backgroundImage.split('.').pop()?.toLowerCase() || 'png';
         } else {
           const resp = await fetch(`${getApiBase()}/background-image`);
@@ -455,6 +461,7 @@ function App() {
         const result = await downloadUpdate({
           url: updateResult.downloadUrl,
           savePath,
+          signatureUrl: updateResult.signatureUrl,
           totalSize: updateResult.fileSize,
           proxySettings: proxyForDownload,
           onProgress: (progress: DownloadProgress) => {
@@ -594,6 +601,15 @@ function App() {
 
       // 加载用户配置（mxu-{项目名}.json）- 从数据目录加载
       const projectName = result.interface.name;
+      if (isTauri()) {
+        const encryption = await maaService.getConfigEncryption().catch(() => null);
+        if (encryption?.locked) {
+          await new Promise<void>((resolve) => {
+            configUnlockResolverRef.current = resolve;
+            setShowConfigUnlock(true);
+          });
+        }
+      }
       let config = await loadConfig(result.dataPath, projectName);
 
       // 浏览器环境下，如果没有从 public 目录加载到配置，尝试从 localStorage 加载
@@ -649,9 +665,11 @@ function App() {
       showWindow();
 
       // 从后端恢复 MAA 运行时状态（连接状态、资源加载状态、设备缓存等）
+      let backendInstanceCount = 0;
       try {
         const backendStates = await maaService.getAllStates();
         if (backendStates) {
+          backendInstanceCount = Object.keys(backendStates.instances).length;
           restoreBackendStates(backendStates);
           log.info('已恢复后端状态:', Object.keys(backendStates.instances).length, '个实例');
         }
@@ -735,6 +753,26 @@ function App() {
         log.warn('版本检查失败:', err);
       }
 
+      // 后端为全新进程（崩溃或重启后）或以管理员身份重启时，按上次的会话快照重新连接控制器并加载资源
+      const restoreRequested = isTauri() && (await maaService.hasRestoreSessionFlag());
+      if (isTauri() && (backendInstanceCount === 0 || restoreRequested)) {
+        try {
+          const restoreResult = await maaService.restoreSession();
+          if (restoreResult.restored.length > 0 || restoreResult.failed.length > 0) {
+            log.info(
+              '已恢复会话:',
+              restoreResult.restored.length,
+              '个实例, 失败:',
+              restoreResult.failed.length,
+            );
+            const backendStates = await maaService.getAllStates();
+            if (backendStates) restoreBackendStates(backendStates);
+          }
+        } catch (err) {
+          log.warn('恢复会话失败:', err);
+        }
+      }
+
       log.info('加载完成, 项目:', result.interface.name);
       setLoadingState('success');
       // 完成配置加载后，允许后续状态变更自动保存
@@ -1599,18 +1637,49 @@ function App() {
 
     let unlistenStart: (() => void) | null = null;
     let unlistenStop: (() => void) | null = null;
+    let unlistenApiStart: (() => void) | null = null;
+    let unlistenTagStart: (() => void) | null = null;
 
     const setupTrayListeners = async () => {
       try {
         const { listen } = await import('@tauri-apps/api/event');
 
-        unlistenStart = await listen('tray-start-tasks', () => {
-          log.info('收到托盘开始任务事件');
+        // 托盘实例子菜单会携带 instanceId，顶层"开始任务"则启动当前激活实例
+        unlistenStart = await listen<{ instanceId?: string } | null>(
+          'tray-start-tasks',
+          (event) => {
+            log.info('收到托盘开始任务事件', event.payload?.instanceId ?? '');
+            document.dispatchEvent(
+              new CustomEvent('mxu-start-tasks', {
+                detail: { source: 'tray', instanceId: event.payload?.instanceId },
+              }),
+            );
+          },
+        );
+
+        // 本地 REST 控制服务请求开始指定实例的任务
+        unlistenApiStart = await listen<{ instanceId: string }>('api-start-tasks', (event) => {
+          log.info('收到 API 开始任务事件:', event.payload.instanceId);
           document.dispatchEvent(
-            new CustomEvent('mxu-start-tasks', { detail: { source: 'tray' } }),
+            new CustomEvent('mxu-start-tasks', {
+              detail: { source: 'api', instanceId: event.payload.instanceId },
+            }),
           );
         });
 
+        // 按标签批量启动：依次启动带有该标签的实例
+        unlistenTagStart = await listen<{ tag: string; instanceIds: string[] }>(
+          'tag-start-tasks',
+          (event) => {
+            log.info('收到按标签开始任务事件:', event.payload.tag, event.payload.instanceIds);
+            document.dispatchEvent(
+              new CustomEvent('mxu-start-tasks', {
+                detail: { source: 'tag', instanceIds: event.payload.instanceIds },
+              }),
+            );
+          },
+        );
+
         unlistenStop = await listen('tray-stop-tasks', () => {
           log.info('收到托盘停止任务事件');
           document.dispatchEvent(new CustomEvent('mxu-stop-tasks', { detail: { source: 'tray' } }));
@@ -1627,6 +1696,8 @@ function App() {
     return () => {
       if (unlistenStart) unlistenStart();
       if (unlistenStop) unlistenStop();
+      if (unlistenApiStart) unlistenApiStart();
+      if (unlistenTagStart) unlistenTagStart();
     };
   }, []);
 
@@ -1660,6 +1731,417 @@ function App() {
     };
   }, []);
 
+  // MXU_ASSERT 断言失败时写入实例日志
+  useEffect(() => {
+    if (!isTauri()) return;
+
+    let unlisten: (() => void) | null = null;
+
+    const setupAssertListener = async () => {
+      try {
+        unlisten = await maaService.onAssertFailed(({ instanceId, nodeName, message }) => {
+          log.warn(`[assert#${instanceId}] ${nodeName}: ${message}`);
+          useAppStore.getState().addLog(instanceId, {
+            type: 'error',
+            message: `[${nodeName}] ${message}`,
+          });
+        });
+      } catch (error) {
+        log.warn('注册断言失败事件监听失败:', error);
+      }
+    };
+
+    void setupAssertListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, []);
+
+  // 任务超时：后端看门狗已停止超时任务，记录到运行日志
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupTaskTimeoutListener = async () => {
+      try {
+        unlisten = await maaService.onTaskTimeout(
+          ({ instanceId, entry, timeoutSecs, restarted }) => {
+            log.warn(`[timeout#${instanceId}] ${entry} exceeded ${timeoutSecs}s`);
+            useAppStore.getState().addLog(instanceId, {
+              type: 'warning',
+              message: t(restarted ? 'maa.taskTimeoutRestarted' : 'maa.taskTimeout', {
+                entry,
+                seconds: timeoutSecs,
+              }),
+            });
+          },
+        );
+      } catch (error) {
+        log.warn('注册任务超时事件监听失败:', error);
+      }
+    };
+
+    void setupTaskTimeoutListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // 任务跳过：前置任务失败或执行条件不满足
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupTaskSkippedListener = async () => {
+      try {
+        unlisten = await maaService.onTaskSkipped(({ instanceId, entry, reason, condition }) => {
+          log.info(`[skipped#${instanceId}] ${entry} (${reason})`);
+          useAppStore.getState().addLog(instanceId, {
+            type: 'warning',
+            message:
+              reason === 'condition-false'
+                ? t('maa.taskSkippedByCondition', { entry, condition })
+                : t('maa.taskSkipped', { entry }),
+          });
+        });
+      } catch (error) {
+        log.warn('注册任务跳过事件监听失败:', error);
+      }
+    };
+
+    void setupTaskSkippedListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // 运行中截图分辨率变化：资源可能不支持新分辨率，提醒用户检查设备设置
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupResolutionChangedListener = async () => {
+      try {
+        unlisten = await maaService.onResolutionChanged(({ instance_id, previous, current }) => {
+          const text = t('maa.resolutionChanged', {
+            previous: previous.join('x'),
+            current: current.join('x'),
+          });
+          log.warn(`[resolution#${instance_id}] ${previous.join('x')} -> ${current.join('x')}`);
+          useAppStore.getState().addLog(instance_id, { type: 'warning', message: text });
+          toast.warning(text, { id: `resolution-${instance_id}` });
+        });
+      } catch (error) {
+        log.warn('注册分辨率变化事件监听失败:', error);
+      }
+    };
+
+    void setupResolutionChangedListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // 设备健康告警：后端已因电量过低或温度过高停止任务
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupDeviceHealthListener = async () => {
+      try {
+        unlisten = await maaService.onDeviceHealthAlert(({ instanceId, reason, health }) => {
+          const text =
+            reason === 'low_battery'
+              ? t('maa.deviceLowBattery', { level: health.batteryLevel ?? '?' })
+              : t('maa.deviceOverheat', {
+                  temperature:
+                    health.batteryTemperature !== null
+                      ? `${health.batteryTemperature.toFixed(1)}°C`
+                      : `thermal ${health.thermalStatus ?? '?'}`,
+                });
+          log.warn(`[device-health#${instanceId}] ${reason}`, health);
+          useAppStore.getState().addLog(instanceId, { type: 'error', message: text });
+          toast.error(text, { id: `device-health-${instanceId}` });
+        });
+      } catch (error) {
+        log.warn('注册设备健康告警监听失败:', error);
+      }
+    };
+
+    void setupDeviceHealthListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // 游戏维护时段：启动任务被拒绝或延后到维护结束
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupMaintenanceListener = async () => {
+      try {
+        unlisten = await maaService.onMaintenanceWindow(({ instanceId, policy, name, endsAt }) => {
+          const text =
+            policy === 'delay'
+              ? t('maa.maintenanceDelayed', { name, endsAt })
+              : t('maa.maintenanceRefused', { name, endsAt });
+          log.info(`[maintenance#${instanceId}] ${policy} until ${endsAt}`);
+          useAppStore.getState().addLog(instanceId, { type: 'warning', message: text });
+          toast.warning(text, { id: `maintenance-${instanceId}` });
+        });
+      } catch (error) {
+        log.warn('注册维护时段监听失败:', error);
+      }
+    };
+
+    void setupMaintenanceListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // 系统时钟偏差：启动检查可能早于监听注册，注册后再取走暂存的结果
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const showClockDriftWarning = ({ offsetMs, server }: ClockDriftReport) => {
+      log.warn(`[clock-drift] ${offsetMs} ms (${server})`);
+      const text = t('maa.clockDriftWarning', { seconds: Math.round(Math.abs(offsetMs) / 1000) });
+      toast.warning(text, { id: 'clock-drift' });
+    };
+
+    const setupClockDriftListener = async () => {
+      try {
+        unlisten = await maaService.onClockDriftWarning(showClockDriftWarning);
+        const pending = await maaService.takeClockDriftWarning();
+        if (pending) showClockDriftWarning(pending);
+      } catch (error) {
+        log.warn('注册时钟偏差警告监听失败:', error);
+      }
+    };
+
+    void setupClockDriftListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // 程序文件完整性：启动校验可能早于监听注册，注册后再读取最近一次校验结果
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const showIntegrityWarning = (report: IntegrityReport) => {
+      if (report.ok) return;
+      log.warn('[integrity] 校验未通过:', report);
+      const text = !report.manifestFound
+        ? t('maa.integrityManifestMissing')
+        : report.signature === 'invalid' || report.signature === 'missing'
+          ? t('maa.integrityManifestInvalid')
+          : t('maa.integrityWarning', {
+              missing: report.missing.length,
+              modified: report.mismatched.length,
+            });
+      toast.error(text, { id: 'integrity', duration: Infinity });
+    };
+
+    const setupIntegrityListener = async () => {
+      try {
+        unlisten = await maaService.onIntegrityWarning(showIntegrityWarning);
+        const last = await maaService.getIntegrityReport();
+        if (last) showIntegrityWarning(last);
+      } catch (error) {
+        log.warn('注册完整性警告监听失败:', error);
+      }
+    };
+
+    void setupIntegrityListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // Win32 目标窗口被系统缩放：点击坐标可能偏移
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupDpiWarningListener = async () => {
+      try {
+        unlisten = await maaService.onWin32DpiWarning(({ instanceId, scalePercent, awareness }) => {
+          const text = t('maa.win32DpiWarning', { scale: scalePercent });
+          log.warn(`[dpi#${instanceId}] ${scalePercent}% (${awareness})`);
+          useAppStore.getState().addLog(instanceId, { type: 'warning', message: text });
+          toast.warning(text, { id: `dpi-${instanceId}` });
+        });
+      } catch (error) {
+        log.warn('注册 DPI 缩放提示监听失败:', error);
+      }
+    };
+
+    void setupDpiWarningListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // Win32 目标进程以管理员权限运行：输入会被 UIPI 拦截，提示以管理员身份重启
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupElevationWarningListener = async () => {
+      try {
+        unlisten = await maaService.onWin32ElevationWarning(
+          ({ instanceId, pid, processName, policy }) => {
+            const text = t('maa.win32ElevationWarning', {
+              process: processName ?? `pid ${pid}`,
+            });
+            log.warn(`[elevation#${instanceId}] ${processName ?? pid} (${policy})`);
+            useAppStore.getState().addLog(instanceId, { type: 'warning', message: text });
+            // restart 策略下后端已自动重启，无需再提供操作
+            if (policy === 'restart') return;
+            toast.warning(text, {
+              id: `elevation-${instanceId}`,
+              action: {
+                label: t('maa.restartAsAdmin'),
+                onClick: () => {
+                  maaService.restartAsAdmin().catch((err) => {
+                    log.error('以管理员身份重启失败:', err);
+                  });
+                },
+              },
+            });
+          },
+        );
+      } catch (error) {
+        log.warn('注册管理员权限提示监听失败:', error);
+      }
+    };
+
+    void setupElevationWarningListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
+  // mxu:// 深度链接：启动参数中暂存的链接在加载完成后取走，运行中收到的链接通过事件推送
+  // 链接可能来自任意网页，执行前均需用户确认
+  useEffect(() => {
+    if (!isTauri() || loadingState !== 'success') return;
+
+    let unlisten: (() => void) | null = null;
+
+    const handleDeepLink = (action: DeepLinkAction) => {
+      log.info('收到深度链接:', action);
+      if (action.action === 'run') {
+        const instance = useAppStore.getState().instances.find((i) => i.name === action.profile);
+        if (!instance) {
+          toast.error(t('maa.deepLinkProfileNotFound', { name: action.profile }));
+          return;
+        }
+        toast.info(t('maa.deepLinkRun', { name: instance.name }), {
+          id: `deep-link-run-${instance.id}`,
+          duration: Infinity,
+          action: {
+            label: t('maa.deepLinkRunConfirm'),
+            onClick: () => {
+              useAppStore.getState().setActiveInstance(instance.id);
+              document.dispatchEvent(
+                new CustomEvent('mxu-start-tasks', {
+                  detail: { source: 'deep-link', instanceId: instance.id },
+                }),
+              );
+            },
+          },
+        });
+      } else {
+        // 资源包走更新安装流程（下载、签名校验、解压覆盖）
+        const url = new URL(action.url);
+        const filename = decodeURIComponent(url.pathname.split('/').pop() || '') || undefined;
+        toast.warning(t('maa.deepLinkImport', { host: url.host }), {
+          id: 'deep-link-import',
+          duration: Infinity,
+          action: {
+            label: t('maa.deepLinkImportConfirm'),
+            onClick: () => {
+              const importInfo = {
+                hasUpdate: true,
+                versionName: filename || url.host,
+                releaseNote: action.url,
+                downloadUrl: action.url,
+                updateType: 'full' as const,
+                filename,
+              };
+              setUpdateInfo(importInfo);
+              setShowUpdateDialog(true);
+              void startAutoDownload(importInfo);
+            },
+          },
+        });
+      }
+    };
+
+    const setupDeepLinkListener = async () => {
+      try {
+        unlisten = await maaService.onDeepLink(handleDeepLink);
+        const pending = await maaService.takeDeepLinks();
+        pending.forEach(handleDeepLink);
+      } catch (error) {
+        log.warn('注册深度链接监听失败:', error);
+      }
+    };
+
+    void setupDeepLinkListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [loadingState, t, setUpdateInfo, setShowUpdateDialog, startAutoDownload]);
+
+  // 人工介入请求（MXU_WAIT_FOR_USER）：记录日志并弹出确认提示
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+
+    const setupInterventionListener = async () => {
+      try {
+        unlisten = await maaService.onInterventionRequired(
+          ({ instanceId, nodeName, token, message, timeoutSecs }) => {
+            const text = message || t('maa.interventionRequired');
+            log.info(`[intervention#${instanceId}] ${nodeName}: ${text}`);
+            useAppStore.getState().addLog(instanceId, {
+              type: 'warning',
+              message: `[${nodeName}] ${text}`,
+            });
+            toast.warning(text, {
+              id: token,
+              duration: timeoutSecs > 0 ? timeoutSecs * 1000 : Infinity,
+              action: {
+                label: t('maa.interventionConfirm'),
+                onClick: () => {
+                  maaService.confirmIntervention(token).catch((err) => {
+                    log.warn('确认人工介入失败:', err);
+                  });
+                },
+              },
+            });
+          },
+        );
+      } catch (error) {
+        log.warn('注册人工介入事件监听失败:', error);
+      }
+    };
+
+    void setupInterventionListener();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
   const toaster = (
     <Toaster
       theme={resolveThemeMode(theme)}
@@ -1742,15 +2224,26 @@ function App() {
     const { title: displayTitle, subtitle: displaySubtitle } = getDisplayTitle();
 
     return (
-      <LoadingScreen
-        loadingState={loadingState}
-        errorMessage={errorMessage}
-        showBadPathModal={showBadPathModal}
-        badPathType={badPathType}
-        displayTitle={displayTitle}
-        displaySubtitle={displaySubtitle}
-        onRetry={loadInterface}
-      />
+      <>
+        <LoadingScreen
+          loadingState={loadingState}
+          errorMessage={errorMessage}
+          showBadPathModal={showBadPathModal}
+          badPathType={badPathType}
+          displayTitle={displayTitle}
+          displaySubtitle={displaySubtitle}
+          onRetry={loadInterface}
+        />
+        {showConfigUnlock && (
+          <ConfigUnlockModal
+            onUnlocked={() => {
+              setShowConfigUnlock(false);
+              configUnlockResolverRef.current?.();
+              configUnlockResolverRef.current = null;
+            }}
+          />
+        )}
+      </>
     );
   }
 
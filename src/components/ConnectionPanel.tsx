@@ -24,7 +24,8 @@ import type { AdbDevice, Win32Window, ControllerConfig } from '@/types/maa';
 import type { ControllerItem, ResourceItem } from '@/types/interface';
 import { computeResourcePaths } from '@/utils/resourcePath';
 import { getProcessNameFromPath } from '@/utils/paths';
-import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
+import { describeError } from '@/utils/mxuError';
+import { parseWin32ScreencapMethod, parseWin32InputMethod, getGamepadTuning } from '@/types/maa';
 import { getInterfaceLangKey } from '@/i18n';
 import { generateId } from '@/stores/helpers';
 import {
@@ -492,7 +493,7 @@ export function ConnectionPanel() {
         }
       }
     } catch (err) {
-      setDeviceError(err instanceof Error ? err.message : t('controller.connectionFailed'));
+      setDeviceError(describeError(err, t, t('controller.connectionFailed')));
     } finally {
       setIsSearching(false);
     }
@@ -601,6 +602,7 @@ export function ConnectionPanel() {
           type: 'Gamepad',
           handle: selectedWindow.handle,
           display_short_side: currentController?.display_short_side,
+          ...getGamepadTuning(currentController?.gamepad),
         };
         deviceName = selectedWindow.window_name || selectedWindow.class_name;
         targetType = 'window';
@@ -614,7 +616,7 @@ export function ConnectionPanel() {
 
       await connectControllerInternal(config, deviceName, targetType);
     } catch (err) {
-      setDeviceError(err instanceof Error ? err.message : t('controller.connectionFailed'));
+      setDeviceError(describeError(err, t, t('controller.connectionFailed')));
       setIsConnected(false);
       setInstanceConnectionStatus(instanceId, 'Disconnected');
       setIsConnecting(false);
@@ -669,7 +671,7 @@ export function ConnectionPanel() {
         setIsLoadingResource(false);
       }
     } catch (err) {
-      setResourceError(err instanceof Error ? err.message : t('resource.loadFailed'));
+      setResourceError(describeError(err, t, t('resource.loadFailed')));
       setIsResourceLoaded(false);
       setInstanceResourceLoaded(instanceId, false);
       setIsLoadingResource(false);
@@ -691,7 +693,7 @@ export function ConnectionPanel() {
       // 加载新资源
       await loadResourceInternal(newResource);
     } catch (err) {
-      setResourceError(err instanceof Error ? err.message : t('resource.switchFailed'));
+      setResourceError(describeError(err, t, t('resource.switchFailed')));
       setIsLoadingResource(false);
       lastLoadedResourceRef.current = null;
     }
@@ -820,7 +822,7 @@ export function ConnectionPanel() {
 
       await connectControllerInternal(config, device.name || device.address, 'device');
     } catch (err) {
-      setDeviceError(err instanceof Error ? err.message : t('controller.connectionFailed'));
+      setDeviceError(describeError(err, t, t('controller.connectionFailed')));
       setIsConnected(false);
       setInstanceConnectionStatus(instanceId, 'Disconnected');
       setIsConnecting(false);
@@ -909,6 +911,7 @@ export function ConnectionPanel() {
           type: 'Gamepad',
           handle: win.handle,
           display_short_side: currentController?.display_short_side,
+          ...getGamepadTuning(currentController?.gamepad),
         };
       }
 
@@ -917,7 +920,7 @@ export function ConnectionPanel() {
       // 连接成功后异步获取进程路径（Win32 和 Gamepad 都基于窗口句柄）
       void fetchAndStoreProcessPath(win.handle);
     } catch (err) {
-      setDeviceError(err instanceof Error ? err.message : t('controller.connectionFailed'));
+      setDeviceError(describeError(err, t, t('controller.connectionFailed')));
       setIsConnected(false);
       setInstanceConnectionStatus(instanceId, 'Disconnected');
       setIsConnecting(false);
@@ -959,7 +962,7 @@ export function ConnectionPanel() {
 
       await connectControllerInternal(config, socketPath, 'device');
     } catch (err) {
-      setDeviceError(err instanceof Error ? err.message : t('controller.connectionFailed'));
+      setDeviceError(describeError(err, t, t('controller.connectionFailed')));
       setIsConnected(false);
       setInstanceConnectionStatus(instanceId, 'Disconnected');
       setIsConnecting(false);
@@ -1051,7 +1054,7 @@ export function ConnectionPanel() {
         }
       }
     } catch (err) {
-      setDeviceError(err instanceof Error ? err.message : t('controller.connectionFailed'));
+      setDeviceError(describeError(err, t, t('controller.connectionFailed')));
     } finally {
       setIsSearching(false);
     }
@@ -236,6 +236,8 @@ function InstanceCard({ instanceId, instanceName, isActive, onSelect }: Instance
               ),
               // 传递 selectedTaskId，后端用于建立 maaTaskId -> selectedTaskId 映射
               selected_task_id: selectedTask.id,
+              timeout_secs: selectedTask.timeoutSecs,
+              timeout_restarts: selectedTask.timeoutRestarts,
             });
             // MXU 特殊任务的 label 是 MXU i18n key，需要用 t() 翻译
             const taskDisplayName =
@@ -19,7 +19,7 @@ import { maaService } from '@/services/maaService';
 import { useAppStore } from '@/stores/appStore';
 import type { AdbDevice, Win32Window, ControllerConfig } from '@/types/maa';
 import type { ControllerItem } from '@/types/interface';
-import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
+import { parseWin32ScreencapMethod, parseWin32InputMethod, getGamepadTuning } from '@/types/maa';
 import { loggers } from '@/utils/logger';
 
 const log = loggers.device;
@@ -263,6 +263,7 @@ export function DeviceSelector({
           type: 'Gamepad',
           handle: selectedWindow.handle,
           display_short_side: controllerDef.display_short_side,
+          ...getGamepadTuning(controllerDef.gamepad),
         };
       } else {
         throw new Error('请先选择设备');
@@ -401,6 +402,7 @@ export function DeviceSelector({
           type: 'Gamepad',
           handle: win.handle,
           display_short_side: controllerDef.display_short_side,
+          ...getGamepadTuning(controllerDef.gamepad),
         };
       }
 
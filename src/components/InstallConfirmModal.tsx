@@ -16,14 +16,55 @@ import {
   saveUpdateCompleteInfo,
   clearPendingUpdateInfo,
   FallbackUpdateError,
+  InsufficientDiskSpaceError,
+  UpdateCompatError,
   isExecutableInstaller,
 } from '@/services/updateService';
 import { ReleaseNotes, DownloadProgressBar } from './UpdateInfoCard';
 import { loggers } from '@/utils/logger';
+import { getErrorMessage } from '@/utils/mxuError';
+
+const toMB = (bytes: number) => (bytes / 1024 / 1024).toFixed(1);
+
+/** 安装失败时显示的文本 */
+function describeInstallError(
+  error: unknown,
+  t: (key: string, options?: Record<string, unknown>) => string,
+): string {
+  if (error instanceof InsufficientDiskSpaceError) {
+    return t('mirrorChyan.insufficientDiskSpace', {
+      required: toMB(error.requiredBytes),
+      available: toMB(error.availableBytes),
+      path: error.path,
+    });
+  }
+  if (error instanceof FallbackUpdateError) {
+    return t('mirrorChyan.fallbackInstalled', { path: error.fallbackDir });
+  }
+  if (error instanceof UpdateCompatError) {
+    const { report } = error;
+    const reason =
+      report.error ??
+      t('mirrorChyan.maafwVersionTooLow', {
+        version: report.version ?? '',
+        minimum: report.minimum ?? '',
+      });
+    if (error.stage === 'rolledBack') {
+      return t('mirrorChyan.maafwRolledBack', { reason });
+    }
+    if (error.stage === 'rollbackFailed') {
+      return t('mirrorChyan.maafwRollbackFailed', { reason, error: error.rollbackError ?? '' });
+    }
+    return t('mirrorChyan.maafwIncompatible', { reason });
+  }
+  return getErrorMessage(error);
+}
 
 export function InstallConfirmModal() {
   const { t } = useTranslation();
   const [installStage, setInstallStage] = useState<string>('');
+  // 被占用、需重启电脑后才能替换的文件
+  const [pendingRebootFiles, setPendingRebootFiles] = useState<string[]>([]);
 
   const {
     updateInfo,
@@ -58,6 +99,7 @@ export function InstallConfirmModal() {
     setInstallStatus('installing');
     setInstallError(null);
     setInstallStage('');
+    setPendingRebootFiles([]);
 
     try {
       const success = await installUpdate({
@@ -74,6 +116,7 @@ export function InstallConfirmModal() {
             setInstallStage(stageText);
           }
         },
+        onPendingReboot: setPendingRebootFiles,
       });
 
       if (success) {
@@ -85,12 +128,7 @@ export function InstallConfirmModal() {
     } catch (error) {
       loggers.ui.error('安装失败:', error);
       setInstallStatus('failed');
-      // 兜底更新成功时显示特殊提示
-      if (error instanceof FallbackUpdateError) {
-        setInstallError(error.message);
-      } else {
-        setInstallError(error instanceof Error ? error.message : String(error));
-      }
+      setInstallError(describeInstallError(error, t));
     }
   }, [downloadSavePath, basePath, updateInfo, projectName, setInstallStatus, setInstallError, t]);
 
@@ -157,6 +195,7 @@ export function InstallConfirmModal() {
 
         setInstallError(null);
         setInstallStage('');
+        setPendingRebootFiles([]);
 
         try {
           const success = await installUpdate({
@@ -173,6 +212,7 @@ export function InstallConfirmModal() {
                 setInstallStage(stageText);
               }
             },
+            onPendingReboot: setPendingRebootFiles,
           });
 
           if (success) {
@@ -184,12 +224,7 @@ export function InstallConfirmModal() {
         } catch (error) {
           loggers.ui.error('安装失败:', error);
           setInstallStatus('failed');
-          // 兜底更新成功时显示特殊提示
-          if (error instanceof FallbackUpdateError) {
-            setInstallError(error.message);
-          } else {
-            setInstallError(error instanceof Error ? error.message : String(error));
-          }
+          setInstallError(describeInstallError(error, t));
         }
       })();
     }
@@ -251,9 +286,22 @@ export function InstallConfirmModal() {
         // 不关闭弹窗、不重启
         return;
       }
+      // 有文件需重启电脑后才能替换时，提示用户重启电脑而不是立即重启应用
+      if (pendingRebootFiles.length > 0) {
+        clearPendingUpdateInfo();
+        return;
+      }
       handleRestart();
     }
-  }, [installStatus, isJustUpdatedMode, handleRestart, isExeInstaller, updateInfo, currentVersion]);
+  }, [
+    installStatus,
+    isJustUpdatedMode,
+    handleRestart,
+    isExeInstaller,
+    updateInfo,
+    currentVersion,
+    pendingRebootFiles,
+  ]);
 
   // 如果没有打开模态框，或者既没有更新信息也没有刚更新完成信息，则不渲染
   if (!showInstallConfirmModal || (!updateInfo && !justUpdatedInfo)) return null;
@@ -269,6 +317,7 @@ export function InstallConfirmModal() {
   const isInstalling = installStatus === 'installing';
   const isInstallComplete = installStatus === 'completed';
   const isInstallFailed = installStatus === 'failed';
+  const needsReboot = isInstallComplete && !isExeInstaller && pendingRebootFiles.length > 0;
 
   // 判断是否需要显示更新日志（需要大尺寸模态框）
   const showReleaseNotes =
@@ -334,13 +383,29 @@ export function InstallConfirmModal() {
           )}
 
           {/* 安装完成状态（正在重启）- 对于可执行安装程序不显示 */}
-          {!isJustUpdatedMode && isInstallComplete && !isExeInstaller && (
+          {!isJustUpdatedMode && isInstallComplete && !isExeInstaller && !needsReboot && (
             <div className="flex flex-col items-center gap-4 py-4">
               <Loader2 className="w-12 h-12 text-accent animate-spin" />
               <p className="text-sm text-text-primary font-medium">{t('mirrorChyan.restarting')}</p>
             </div>
           )}
 
+          {/* 部分文件被占用，需重启电脑完成替换 */}
+          {!isJustUpdatedMode && needsReboot && (
+            <div className="flex flex-col items-center gap-4 py-4">
+              <AlertCircle className="w-12 h-12 text-warning" />
+              <div className="text-center space-y-2">
+                <p className="text-sm text-text-primary font-medium">
+                  {t('mirrorChyan.pendingReboot', { count: pendingRebootFiles.length })}
+                </p>
+                <p className="text-xs text-text-muted">{t('mirrorChyan.pendingRebootHint')}</p>
+                <p className="text-xs text-text-muted font-mono break-all">
+                  {pendingRebootFiles.join(', ')}
+                </p>
+              </div>
+            </div>
+          )}
+
           {/* 可执行安装程序已打开状态 - 提示用户手动操作 */}
           {!isJustUpdatedMode && isInstallComplete && isExeInstaller && (
             <div className="flex flex-col items-center gap-4 py-4">
@@ -417,6 +482,16 @@ export function InstallConfirmModal() {
             </button>
           )}
 
+          {/* 需重启电脑 - 只显示关闭按钮 */}
+          {!isJustUpdatedMode && needsReboot && (
+            <button
+              onClick={handleClose}
+              className="px-4 py-2 text-sm bg-accent text-white hover:bg-accent-hover rounded-lg transition-colors"
+            >
+              {t('mirrorChyan.gotIt')}
+            </button>
+          )}
+
           {/* 关闭/取消按钮（安装失败时由下方单独处理，避免重复） */}
           {!isJustUpdatedMode && !isInstalling && !isInstallComplete && !isInstallFailed && (
             <button
@@ -87,6 +87,7 @@ function PolicyCard({
 
   // 格式化显示已选时间
   const formatHours = () => {
+    if (policy.afterReset) return t('schedule.afterReset');
     if (policy.hours.length === 0) return t('schedule.noHours');
     if (policy.hours.length === 24) return t('schedule.everyHour');
     if (policy.hours.length <= 3) {
@@ -217,8 +218,25 @@ function PolicyCard({
                 ({t('schedule.multiSelect')})
               </span>
             </label>
+            {/* 服务器重置后执行（替代整点选择） */}
+            <button
+              onClick={() => onUpdate({ afterReset: !policy.afterReset })}
+              className={clsx(
+                'w-full px-2 py-1.5 text-xs rounded border transition-colors',
+                policy.afterReset
+                  ? 'bg-accent text-white border-accent'
+                  : 'bg-bg-primary text-text-secondary border-border hover:border-accent hover:text-accent',
+              )}
+            >
+              {t('schedule.afterReset')}
+            </button>
             {/* 时间网格 */}
-            <div className="grid grid-cols-7 gap-1">
+            <div
+              className={clsx(
+                'grid grid-cols-7 gap-1',
+                policy.afterReset && 'opacity-50 pointer-events-none',
+              )}
+            >
               <button
                 onClick={handleSelectAllHours}
                 className={clsx(
@@ -245,6 +263,9 @@ function PolicyCard({
                 </button>
               ))}
             </div>
+            {policy.afterReset && (
+              <p className="text-xs text-text-muted">{t('schedule.afterResetHint')}</p>
+            )}
             <p className="text-xs text-text-muted">
               {t('schedule.timeZoneHint')} (
               {(() => {
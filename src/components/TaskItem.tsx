@@ -2,7 +2,16 @@ import { useState, useCallback, useMemo, useEffect, useRef } from 'react';
 import { useTranslation } from 'react-i18next';
 import { useSortable } from '@dnd-kit/sortable';
 import { CSS } from '@dnd-kit/utilities';
-import { GripVertical, ChevronRight, X, Loader2, FileText, Link, AlertCircle } from 'lucide-react';
+import {
+  GripVertical,
+  ChevronRight,
+  X,
+  Loader2,
+  FileText,
+  Link,
+  AlertCircle,
+  Timer,
+} from 'lucide-react';
 import { useAppStore, type TaskRunStatus } from '@/stores/appStore';
 import { maaService } from '@/services/maaService';
 import { useResolvedContent } from '@/services/contentResolver';
@@ -11,6 +20,7 @@ import { OptionEditor, SwitchGrid, switchHasNestedOptions } from './OptionEditor
 import { ContextMenu, useContextMenu } from './ContextMenu';
 import { Tooltip } from './ui/Tooltip';
 import { ConfirmDialog } from './ConfirmDialog';
+import { NumberField } from './FormControls';
 import { buildListItemMenuItems, InlineNameEditor } from './listItemShared';
 import type { SelectedTask } from '@/types/interface';
 import { isMxuSpecialTask, getMxuSpecialTask, findMxuOptionByKey } from '@/types/specialTasks';
@@ -300,6 +310,9 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
   const [isEditing, setIsEditing] = useState(false);
   const [showDeleteConfirm, setShowDeleteConfirm] = useState(false);
   const [editName, setEditName] = useState('');
+  const [timeoutDraft, setTimeoutDraft] = useState<{ secs: number; restarts: number } | null>(
+    null,
+  );
 
   const {
     projectInterface,
@@ -308,6 +321,7 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
     removeTaskFromInstance,
     confirmBeforeDelete,
     renameTask,
+    setTaskTimeout,
     duplicateTask,
     moveTaskUp,
     moveTaskDown,
@@ -691,6 +705,16 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
           setShowDeleteConfirm(true);
         },
       });
+      // 任务超时设置紧跟在重命名之后
+      const renameIndex = menuItems.findIndex((item) => item.id === 'rename');
+      menuItems.splice(renameIndex + 1, 0, {
+        id: 'timeout',
+        label: t('contextMenu.taskTimeout'),
+        icon: Timer,
+        disabled: isInstanceRunning,
+        onClick: () =>
+          setTimeoutDraft({ secs: task.timeoutSecs ?? 0, restarts: task.timeoutRestarts ?? 0 }),
+      });
 
       showMenu(e, menuItems);
     },
@@ -1016,6 +1040,43 @@ export function TaskItem({ instanceId, task }: TaskItemProps) {
           removeTaskFromInstance(instanceId, task.id);
         }}
       />
+
+      {/* 任务超时设置弹窗 */}
+      <ConfirmDialog
+        open={timeoutDraft !== null}
+        title={t('taskItem.timeoutTitle')}
+        message={t('taskItem.timeoutMessage')}
+        cancelText={t('common.cancel')}
+        confirmText={t('common.confirm')}
+        onCancel={() => setTimeoutDraft(null)}
+        onConfirm={() => {
+          if (timeoutDraft) {
+            setTaskTimeout(instanceId, task.id, timeoutDraft.secs, timeoutDraft.restarts);
+          }
+          setTimeoutDraft(null);
+        }}
+      >
+        {timeoutDraft && (
+          <div className="space-y-3">
+            <NumberField
+              label={t('taskItem.timeoutSecs')}
+              value={timeoutDraft.secs}
+              onChange={(secs) => setTimeoutDraft({ ...timeoutDraft, secs: Math.floor(secs) })}
+              min={0}
+            />
+            <NumberField
+              label={t('taskItem.timeoutRestarts')}
+              value={timeoutDraft.restarts}
+              onChange={(restarts) =>
+                setTimeoutDraft({ ...timeoutDraft, restarts: Math.floor(restarts) })
+              }
+              min={0}
+              max={5}
+              disabled={timeoutDraft.secs === 0}
+            />
+          </div>
+        )}
+      </ConfirmDialog>
     </div>
   );
 }
@@ -14,11 +14,18 @@ import { useAppStore } from '@/stores/appStore';
 import { isTaskCompatible } from '@/stores/helpers';
 import { maaService } from '@/services/maaService';
 import clsx from 'clsx';
-import { loggers, generateTaskPipelineOverride, computeResourcePaths } from '@/utils';
+import {
+  loggers,
+  generateTaskPipelineOverride,
+  computeResourcePaths,
+  getErrorMessage,
+  getErrorCode,
+  describeError,
+} from '@/utils';
 import { getMxuSpecialTask } from '@/types/specialTasks';
 import type { TaskConfig, ControllerConfig } from '@/types/maa';
 import { normalizeAgentConfigs } from '@/types/interface';
-import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
+import { parseWin32ScreencapMethod, parseWin32InputMethod, getGamepadTuning } from '@/types/maa';
 import { SchedulePanel } from './SchedulePanel';
 import type { Instance, TaskItem } from '@/types/interface';
 import { resolveI18nText } from '@/services/contentResolver';
@@ -225,9 +232,11 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
         schedulePolicyName?: string;
         /** 自动连接阶段变化回调（用于 UI 状态更新） */
         onPhaseChange?: (phase: AutoConnectPhase) => void;
+        /** 试运行：所有节点不执行动作，只验证识别 */
+        dryRun?: boolean;
       },
     ): Promise<boolean> => {
-      const { schedulePolicyName, onPhaseChange } = options || {};
+      const { schedulePolicyName, onPhaseChange, dryRun } = options || {};
       const targetId = targetInstance.id;
       const targetTasks = targetInstance.selectedTasks || [];
       lastStartCancelledRef.current = false;
@@ -538,13 +547,13 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
               }
             }
           } catch (err) {
-            if ((err instanceof Error ? err.message : String(err)) === PRE_ACTION_CANCELLED_ERROR) {
+            if (getErrorMessage(err) === PRE_ACTION_CANCELLED_ERROR) {
               throw err;
             }
             log.error(`实例 ${targetInstance.name}: 前置动作执行失败:`, err);
             addLog(targetId, {
               type: 'error',
-              message: t('action.preActionFailed', { error: String(err) }),
+              message: t('action.preActionFailed', { error: getErrorMessage(err) }),
             });
           } finally {
             if (preActionControlStarted) {
@@ -634,6 +643,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
                   type: 'Gamepad',
                   handle: matchedWindow.handle,
                   display_short_side: controller.display_short_side,
+                  ...getGamepadTuning(controller.gamepad),
                 };
               }
               deviceName = matchedWindow.window_name || matchedWindow.class_name;
@@ -733,6 +743,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
                   type: 'Gamepad',
                   handle: firstWindow.handle,
                   display_short_side: controller.display_short_side,
+                  ...getGamepadTuning(controller.gamepad),
                 };
               }
               deviceName = firstWindow.window_name || firstWindow.class_name;
@@ -1027,6 +1038,8 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
               ),
               // 传递 selectedTaskId，后端用于建立 maaTaskId -> selectedTaskId 映射
               selected_task_id: selectedTask.id,
+              timeout_secs: selectedTask.timeoutSecs,
+              timeout_restarts: selectedTask.timeoutRestarts,
             };
           },
         );
@@ -1061,6 +1074,14 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
         // 任务可能在 startTasks 返回前就瞬时结束，先启动全局回调缓存再提交。
         await startGlobalCallbackListener();
 
+        // 显示器关闭时 Win32 截图会返回黑屏，启动前先唤醒显示器
+        if (controller?.type === 'Win32') {
+          await maaService.wakeDisplay();
+        }
+
+        await maaService.setRunSpeed(targetId, targetInstance.runSpeed ?? 1);
+        await maaService.setActionInterval(targetId, targetInstance.actionIntervalMs ?? 0);
+
         // 启动任务
         const taskIds = await maaService.startTasks(
           targetId,
@@ -1069,6 +1090,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
           basePath,
           tcpCompatMode,
           piEnvs,
+          dryRun,
         );
 
         log.info(`实例 ${targetInstance.name}: 任务已提交, task_ids:`, taskIds);
@@ -1097,17 +1119,24 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
       } catch (err) {
         log.error(`实例 ${targetInstance.name}: 任务启动异常:`, err);
 
-        const errMsg = err instanceof Error ? err.message : String(err);
+        const errMsg = getErrorMessage(err);
         const cancelled = errMsg === PRE_ACTION_CANCELLED_ERROR;
         if (!cancelled) {
           addLog(targetId, {
             type: 'error',
-            message: `${t('taskList.autoConnect.startFailed')}: ${errMsg}`,
+            message: `${t('taskList.autoConnect.startFailed')}: ${describeError(err, t)}`,
           });
         }
 
+        const errCode = getErrorCode(err);
+        if (errCode === 'CONTROLLER_NOT_CONNECTED') {
+          setInstanceConnectionStatus(targetId, 'Disconnected');
+        }
+
+        // 仅在 Agent 启动失败或未归类的错误时输出 Agent 启动参数并清理 Agent
         const failedAgentConfigs = normalizeAgentConfigs(projectInterface?.agent);
-        if (failedAgentConfigs && failedAgentConfigs.length > 0) {
+        const agentMayHaveFailed = errCode === undefined || errCode === 'AGENT_FAILED';
+        if (agentMayHaveFailed && failedAgentConfigs && failedAgentConfigs.length > 0) {
           for (let i = 0; i < failedAgentConfigs.length; i++) {
             const agentCfg = failedAgentConfigs[i];
             const args = agentCfg.child_args?.join(' ') ?? '';
@@ -1316,7 +1345,7 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
         }
       } catch (err) {
         log.error('任务启动异常:', err);
-        setAutoConnectError(err instanceof Error ? err.message : String(err));
+        setAutoConnectError(describeError(err, t));
         setAutoConnectPhase('idle');
       } finally {
         setIsStarting(false);
@@ -1328,14 +1357,18 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
 
   // 监听来自 App 的全局快捷键事件：F10 开始任务，F11 结束任务
   useEffect(() => {
-    const handleStartTasks = async (evt: Event) => {
-      if (hotkeyStartingRef.current) return;
-      const currentInstance = useAppStore.getState().getActiveInstance();
-      if (!currentInstance) return;
+    type StartTasksDetail = {
+      source?: string;
+      combo?: string;
+      instanceId?: string;
+      instanceIds?: string[];
+      dryRun?: boolean;
+    };
 
-      const detail = (evt as CustomEvent | undefined)?.detail as
-        | { source?: string; combo?: string }
-        | undefined;
+    const startInstanceFromEvent = async (
+      currentInstance: Instance,
+      detail: StartTasksDetail | undefined,
+    ) => {
       const combo = detail?.combo || '';
       addLog(currentInstance.id, {
         type: 'info',
@@ -1356,18 +1389,42 @@ export function Toolbar({ showAddPanel, onToggleAddPanel, className }: ToolbarPr
         return;
       }
 
-      // 直接使用从 store 获取的最新 instance，避免闭包捕获旧的 selectedTasks
+      const success = await startTasksForInstance(currentInstance, {
+        onPhaseChange: setAutoConnectPhase,
+        dryRun: detail?.dryRun,
+      });
+      addLog(currentInstance.id, {
+        type: success ? 'success' : 'error',
+        message: success
+          ? t('logs.messages.hotkeyStartSuccess')
+          : t('logs.messages.hotkeyStartFailed'),
+      });
+    };
+
+    const handleStartTasks = async (evt: Event) => {
+      if (hotkeyStartingRef.current) return;
+      const detail = (evt as CustomEvent | undefined)?.detail as StartTasksDetail | undefined;
+      // 指定了 instanceIds（如按标签启动）时依次启动这些实例；
+      // 指定了 instanceId（如 REST API 触发）时启动该实例，否则启动当前激活实例
+      const storeState = useAppStore.getState();
+      const targetIds = detail?.instanceIds ?? (detail?.instanceId ? [detail.instanceId] : null);
+      const activeInstance = storeState.getActiveInstance();
+      const targetInstances = targetIds
+        ? targetIds
+            .map((id) => storeState.instances.find((i) => i.id === id))
+            .filter((i): i is Instance => !!i)
+        : activeInstance
+          ? [activeInstance]
+          : [];
+      if (targetInstances.length === 0) return;
+
       hotkeyStartingRef.current = true;
       try {
-        const success = await startTasksForInstance(currentInstance, {
-          onPhaseChange: setAutoConnectPhase,
-        });
-        addLog(currentInstance.id, {
-          type: success ? 'success' : 'error',
-          message: success
-            ? t('logs.messages.hotkeyStartSuccess')
-            : t('logs.messages.hotkeyStartFailed'),
-        });
+        for (const target of targetInstances) {
+          // 每次都从 store 获取最新 instance，避免使用旧的 selectedTasks
+          const latest = useAppStore.getState().instances.find((i) => i.id === target.id);
+          if (latest) await startInstanceFromEvent(latest, detail);
+        }
       } finally {
         hotkeyStartingRef.current = false;
       }
@@ -70,6 +70,7 @@ export function UpdatePanel({ onClose, anchorRef }: UpdatePanelProps) {
       const result = await downloadUpdate({
         url: updateInfo.downloadUrl,
         savePath,
+        signatureUrl: updateInfo.signatureUrl,
         totalSize: updateInfo.fileSize,
         proxySettings: proxyForDownload,
         onProgress: (progress: DownloadProgress) => {
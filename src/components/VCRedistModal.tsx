@@ -5,6 +5,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { getCacheDir, joinPath } from '@/utils/paths';
 import { loggers } from '@/utils/logger';
+import { describeError } from '@/utils/mxuError';
 
 const log = loggers.app;
 
@@ -118,7 +119,7 @@ export function VCRedistModal({ show, onClose }: VCRedistModalProps) {
     } catch (err) {
       log.error('VC++ 运行库安装流程失败:', err);
       setStatus('download_failed');
-      setError(err instanceof Error ? err.message : String(err));
+      setError(describeError(err, t));
     }
   }, [t]);
 
@@ -0,0 +1,86 @@
+import { useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Lock } from 'lucide-react';
+import { maaService } from '@/services/maaService';
+
+interface ConfigUnlockModalProps {
+  onUnlocked: () => void;
+}
+
+/**
+ * 配置加密（密码模式）的解锁弹窗
+ * 启动时配置处于锁定状态则必须解锁后才能加载配置，否则只能退出
+ */
+export function ConfigUnlockModal({ onUnlocked }: ConfigUnlockModalProps) {
+  const { t } = useTranslation();
+  const [passphrase, setPassphrase] = useState('');
+  const [error, setError] = useState('');
+  const [busy, setBusy] = useState(false);
+
+  const handleUnlock = async () => {
+    if (!passphrase || busy) return;
+    setBusy(true);
+    setError('');
+    try {
+      const status = await maaService.unlockConfig(passphrase);
+      if (!status.locked) {
+        onUnlocked();
+      }
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleExit = async () => {
+    const { exit } = await import('@tauri-apps/plugin-process');
+    await exit(0);
+  };
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm">
+      <div
+        role="dialog"
+        aria-modal="true"
+        aria-label={t('configEncryption.unlockTitle')}
+        className="relative bg-bg-secondary rounded-2xl shadow-2xl w-full max-w-md mx-4 flex flex-col animate-in fade-in zoom-in-95 duration-200"
+      >
+        <div className="flex items-center gap-3 px-6 py-4 border-b border-border">
+          <Lock className="w-6 h-6 text-accent" />
+          <h2 className="text-lg font-semibold text-text-primary">
+            {t('configEncryption.unlockTitle')}
+          </h2>
+        </div>
+        <div className="px-6 py-5 space-y-3">
+          <p className="text-text-secondary text-sm">{t('configEncryption.unlockMessage')}</p>
+          <input
+            type="password"
+            autoFocus
+            value={passphrase}
+            placeholder={t('configEncryption.unlockPassphrase')}
+            onChange={(e) => setPassphrase(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && handleUnlock()}
+            className="w-full px-3 py-2.5 rounded-lg bg-bg-tertiary border border-border text-sm text-text-primary placeholder:text-text-muted focus:outline-none focus:ring-2 focus:ring-accent/50"
+          />
+          {error && <p className="text-xs text-error">{error}</p>}
+        </div>
+        <div className="flex justify-end gap-2 px-6 py-4 border-t border-border">
+          <button
+            onClick={handleExit}
+            className="px-4 py-2 rounded-lg text-sm font-medium bg-bg-tertiary hover:bg-bg-hover text-text-secondary transition-colors"
+          >
+            {t('configEncryption.exit')}
+          </button>
+          <button
+            onClick={handleUnlock}
+            disabled={!passphrase || busy}
+            className="px-4 py-2 bg-accent text-white rounded-lg hover:bg-accent/90 transition-colors disabled:opacity-50"
+          >
+            {t('configEncryption.unlock')}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -3,7 +3,7 @@ import { useTranslation } from 'react-i18next';
 import { maaService } from '@/services/maaService';
 import { useAppStore } from '@/stores/appStore';
 import type { AdbDevice, Win32Window, ControllerConfig } from '@/types/maa';
-import { parseWin32ScreencapMethod, parseWin32InputMethod } from '@/types/maa';
+import { parseWin32ScreencapMethod, parseWin32InputMethod, getGamepadTuning } from '@/types/maa';
 import type { ControllerItem } from '@/types/interface';
 import { startGlobalCallbackListener, waitForCtrlResult } from './callbackCache';
 
@@ -273,6 +273,7 @@ export function useDeviceConnection({
             type: 'Gamepad',
             handle: win.handle,
             display_short_side: currentController?.display_short_side,
+            ...getGamepadTuning(currentController?.gamepad),
           };
         }
 
@@ -12,12 +12,15 @@ import {
   ChevronDown,
   Check,
   BrushCleaning,
+  Lock,
 } from 'lucide-react';
 
 import { useAppStore } from '@/stores/appStore';
 import { defaultAddTaskPanelHeight, defaultWindowSize } from '@/types/config';
 import { isTauri } from '@/utils/paths';
+import { maaService, type ConfigEncryptionStatus } from '@/services/maaService';
 import { SwitchButton } from '@/components/FormControls';
+import { ConfirmDialog } from '@/components/ConfirmDialog';
 import { DesktopOnlyWrapper } from '@/components/ui/DesktopOnlyWrapper';
 import { FrameRateSelector } from '../FrameRateSelector';
 
@@ -48,6 +51,14 @@ export function GeneralSection() {
   const [autoStartLoading, setAutoStartLoading] = useState(false);
   const isWindowsRef = useRef(false);
 
+  // 配置加密状态
+  const [encryption, setEncryption] = useState<ConfigEncryptionStatus | null>(null);
+  const [encryptionDialog, setEncryptionDialog] = useState<'enable' | 'disable' | null>(null);
+  const [passphrase, setPassphrase] = useState('');
+  const [passphraseConfirm, setPassphraseConfirm] = useState('');
+  const [encryptionError, setEncryptionError] = useState('');
+  const [encryptionBusy, setEncryptionBusy] = useState(false);
+
   // 自定义下拉框状态
   const [instanceDropdownOpen, setInstanceDropdownOpen] = useState(false);
   const dropdownRef = useRef<HTMLDivElement>(null);
@@ -74,6 +85,14 @@ export function GeneralSection() {
     });
   }, []);
 
+  useEffect(() => {
+    if (!isTauri()) return;
+    maaService
+      .getConfigEncryption()
+      .then(setEncryption)
+      .catch(() => {});
+  }, []);
+
   // 点击外部关闭下拉框
   useEffect(() => {
     if (!instanceDropdownOpen) return;
@@ -109,6 +128,33 @@ export function GeneralSection() {
     }
   }, []);
 
+  const closeEncryptionDialog = useCallback(() => {
+    setEncryptionDialog(null);
+    setPassphrase('');
+    setPassphraseConfirm('');
+    setEncryptionError('');
+  }, []);
+
+  const handleEncryptionConfirm = useCallback(async () => {
+    if (encryptionDialog === 'enable' && passphrase !== passphraseConfirm) {
+      setEncryptionError(t('configEncryption.passphraseMismatch'));
+      return;
+    }
+    setEncryptionBusy(true);
+    try {
+      const status = await maaService.setConfigEncryption(
+        encryptionDialog === 'enable',
+        encryptionDialog === 'enable' ? passphrase : undefined,
+      );
+      setEncryption(status);
+      closeEncryptionDialog();
+    } catch (err) {
+      setEncryptionError(t('configEncryption.failed', { error: String(err) }));
+    } finally {
+      setEncryptionBusy(false);
+    }
+  }, [encryptionDialog, passphrase, passphraseConfirm, closeEncryptionDialog, t]);
+
   const handleResetWindowLayout = useCallback(async () => {
     if (!isTauri()) return;
 
@@ -346,6 +392,81 @@ export function GeneralSection() {
         </div>
       </div>
 
+      {/* 配置加密 */}
+      {isTauri() && encryption && (
+        <div className="bg-bg-secondary rounded-xl p-4 border border-border">
+          <div className="flex items-center justify-between">
+            <div className="flex items-center gap-3">
+              <Lock className="w-5 h-5 text-accent" />
+              <div>
+                <span className="font-medium text-text-primary">
+                  {t('configEncryption.title')}
+                </span>
+                <p className="text-xs text-text-muted mt-0.5">
+                  {encryption.enabled
+                    ? t(
+                        encryption.mode === 'passphrase'
+                          ? 'configEncryption.modePassphrase'
+                          : 'configEncryption.modeMachine',
+                      )
+                    : t('configEncryption.hint')}
+                </p>
+              </div>
+            </div>
+            <SwitchButton
+              value={encryption.enabled}
+              onChange={(v) => setEncryptionDialog(v ? 'enable' : 'disable')}
+              disabled={encryption.locked}
+            />
+          </div>
+        </div>
+      )}
+
+      <ConfirmDialog
+        open={encryptionDialog !== null}
+        title={t(
+          encryptionDialog === 'enable'
+            ? 'configEncryption.enableTitle'
+            : 'configEncryption.disableTitle',
+        )}
+        message={t(
+          encryptionDialog === 'enable'
+            ? 'configEncryption.enableMessage'
+            : 'configEncryption.disableMessage',
+        )}
+        confirmText={t('common.confirm')}
+        cancelText={t('common.cancel')}
+        confirmDisabled={encryptionBusy}
+        onConfirm={handleEncryptionConfirm}
+        onCancel={closeEncryptionDialog}
+      >
+        {(encryptionDialog === 'enable' || encryptionError) && (
+          <div className="space-y-2">
+            {encryptionDialog === 'enable' && (
+              <>
+                <input
+                  type="password"
+                  value={passphrase}
+                  placeholder={t('configEncryption.passphrase')}
+                  onChange={(e) => setPassphrase(e.target.value)}
+                  className="w-full px-3 py-2.5 rounded-lg bg-bg-tertiary border border-border text-sm text-text-primary placeholder:text-text-muted focus:outline-none focus:ring-2 focus:ring-accent/50"
+                />
+                {passphrase && (
+                  <input
+                    type="password"
+                    value={passphraseConfirm}
+                    placeholder={t('configEncryption.passphraseConfirm')}
+                    onChange={(e) => setPassphraseConfirm(e.target.value)}
+                    className="w-full px-3 py-2.5 rounded-lg bg-bg-tertiary border border-border text-sm text-text-primary placeholder:text-text-muted focus:outline-none focus:ring-2 focus:ring-accent/50"
+                  />
+                )}
+              </>
+            )}
+            {encryptionError && <p className="text-xs text-error">{encryptionError}</p>}
+          </div>
+        )}
+      </ConfirmDialog>
+
       {/* ⑧ 重置窗口布局 */}
       {isTauri() && (
         <div className="bg-bg-secondary rounded-xl p-4 border border-border">
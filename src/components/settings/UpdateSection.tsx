@@ -141,6 +141,7 @@ export function UpdateSection() {
         const result = await downloadUpdate({
           url: info.downloadUrl,
           savePath,
+          signatureUrl: info.signatureUrl,
           totalSize: info.fileSize,
           proxySettings: proxyForDownload,
           onProgress: (progress) => {
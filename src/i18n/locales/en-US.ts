@@ -247,6 +247,10 @@ export default {
     remove: 'Remove task',
     removeConfirmTitle: 'Delete task',
     removeConfirmMessage: 'Are you sure you want to delete this task?',
+    timeoutTitle: 'Task Timeout',
+    timeoutMessage: 'Stop the task when it runs longer than this. 0 means no limit.',
+    timeoutSecs: 'Max run time (seconds)',
+    timeoutRestarts: 'Restarts after timeout (up to 5)',
     rename: 'Rename',
     clickToToggle: 'Click to toggle',
     renameTask: 'Rename Task',
@@ -405,6 +409,41 @@ export default {
     version: 'Version',
     needConnection: 'Please connect a device first',
     needResource: 'Please load resources first',
+    interventionRequired: 'Manual intervention required. Confirm to continue when done',
+    interventionConfirm: 'Continue',
+    taskTimeout: 'Task {{entry}} exceeded {{seconds}}s and was stopped',
+    taskTimeoutRestarted: 'Task {{entry}} exceeded {{seconds}}s and was restarted',
+    taskSkipped: 'Skipped task {{entry}} because a prerequisite task failed',
+    taskSkippedByCondition: 'Skipped task {{entry}} because run_if {{condition}} was not met',
+    resolutionChanged:
+      'Screenshot resolution changed from {{previous}} to {{current}}; the current resource may not support it. Check the emulator or window settings',
+    deviceLowBattery:
+      'Device battery is low ({{level}}%); tasks were stopped to protect the device',
+    deviceOverheat:
+      'Device is overheating ({{temperature}}); tasks were stopped to protect the device',
+    win32DpiWarning:
+      'The target window is scaled to {{scale}}% and is not DPI-aware, so clicks may be offset. Set display scaling to 100% or enable "Override high DPI scaling behavior" in the program\'s compatibility settings',
+    win32ElevationWarning:
+      '{{process}} is running as administrator, so Windows blocks the clicks and key presses MXU sends. Restart MXU as administrator',
+    restartAsAdmin: 'Restart as administrator',
+    deepLinkRun: 'A link asks to run the configuration "{{name}}"',
+    deepLinkRunConfirm: 'Run',
+    deepLinkProfileNotFound: 'The configuration "{{name}}" from the link does not exist',
+    deepLinkImport:
+      'A link asks to download and install a resource package from {{host}}. Make sure you trust the source',
+    deepLinkImportConfirm: 'Download and install',
+    maintenanceRefused:
+      'Maintenance window "{{name}}" is in effect until {{endsAt}}; the run was not started',
+    maintenanceDelayed:
+      'Maintenance window "{{name}}" is in effect; tasks will start after it ends at {{endsAt}}',
+    clockDriftWarning:
+      'System clock is off by {{seconds}} s; schedules and waits may misfire. Sync your system time',
+    integrityWarning:
+      'Program file check failed: {{missing}} missing, {{modified}} modified. Reinstall MXU or finish the update',
+    integrityManifestInvalid:
+      'The program file manifest has an invalid signature and files may have been tampered with. Download MXU again from an official source',
+    integrityManifestMissing:
+      'The program file manifest is missing and the installation may be incomplete or tampered with. Download MXU again from an official source',
   },
 
   // Screenshot preview
@@ -663,6 +702,7 @@ export default {
     installNow: 'Install Now',
     installUpdate: 'Install Update',
     installStages: {
+      verifying: 'Verifying package signature...',
       extracting: 'Extracting...',
       checking: 'Checking update type...',
       applying: 'Applying update...',
@@ -671,12 +711,29 @@ export default {
       incremental: 'Incremental update',
       full: 'Full update',
       fallback: 'Performing fallback update...',
+      rollback: 'Restoring the previous MaaFramework...',
     },
     restartRequired: 'Update installed. Please restart to apply changes.',
     restartNow: 'Restart Now',
     restarting: 'Restarting...',
     installerOpened: 'Installer Opened',
     installerOpenedHint: 'Please complete the installer, then restart this app after installation',
+    pendingReboot:
+      'Update installed. {{count}} file(s) are in use and will be replaced the next time the computer restarts',
+    pendingRebootHint:
+      'Restart your computer to finish the update. Do not run the update again before restarting',
+    insufficientDiskSpace:
+      'Not enough disk space: {{required}} MB required, {{available}} MB available ({{path}})',
+    fallbackInstalled:
+      'Update failed, but the new version was extracted to {{path}}. You can use the program in that folder for now',
+    maafwVersionTooLow:
+      'version {{version}} is older than the minimum supported version {{minimum}}',
+    maafwIncompatible:
+      'The new MaaFramework is not usable. The update was cancelled and the current version was not changed: {{reason}}',
+    maafwRolledBack:
+      'The new MaaFramework does not work after installing. The previous MaaFramework has been restored: {{reason}}',
+    maafwRollbackFailed:
+      'The new MaaFramework does not work after installing, and restoring the previous MaaFramework failed ({{error}}). Please download the full package again: {{reason}}',
     // After update complete
     updateCompleteTitle: 'Update Complete',
     updateCompleteMessage: 'Successfully updated to the latest version',
@@ -721,6 +778,9 @@ export default {
     hoursSelected: 'hours selected',
     timeZoneHint: 'Using local timezone',
     multiSelect: 'multi-select',
+    afterReset: 'After daily reset',
+    afterResetHint:
+      "Runs at the first full hour after the instance's daily server reset (04:00 by default; set serverReset in the config)",
     enable: 'Enable schedule',
     disable: 'Disable schedule',
     enableAll: 'Enable all schedules',
@@ -742,6 +802,31 @@ export default {
     taskNotFound: 'Task not found',
     controllerNotFound: 'Controller not found',
     resourceNotFound: 'Resource not found',
+    codes: {
+      NOT_INITIALIZED: 'MaaFramework is not initialized',
+      INSTANCE_NOT_FOUND: 'Instance not found',
+      CONTROLLER_NOT_CONNECTED: 'Controller not connected',
+      RESOURCE_NOT_LOADED: 'Resource not loaded',
+      TARGET_ELEVATED: 'Target window runs as administrator',
+      AGENT_FAILED: 'Agent failed to start',
+      ADB_UNAUTHORIZED: 'Device has not authorized USB debugging',
+      INSUFFICIENT_DISK_SPACE: 'Not enough disk space',
+      PATH_TOO_LONG: 'Install path is too long',
+      INVALID_ARGUMENT: 'Invalid argument',
+    },
+    hints: {
+      NOT_INITIALIZED: 'Check that the maafw folder is complete, or reinstall MaaFramework',
+      INSTANCE_NOT_FOUND: 'The instance may have been closed. Reopen it and try again',
+      CONTROLLER_NOT_CONNECTED: 'Connect a device or window first',
+      RESOURCE_NOT_LOADED: 'Load a resource first',
+      TARGET_ELEVATED: 'Restart MXU as administrator and connect again',
+      AGENT_FAILED: 'Check the agent output log for details',
+      ADB_UNAUTHORIZED: 'Allow USB debugging on the device (tick "Always allow") and connect again',
+      INSUFFICIENT_DISK_SPACE:
+        'Free up space on this drive or move MXU to a drive with enough space, then try again',
+      PATH_TOO_LONG:
+        'Move MXU to a shorter folder (e.g. D:\\MXU) or enable Windows long path support',
+    },
   },
 
   // Context Menu
@@ -782,6 +867,7 @@ export default {
     moveToBottom: 'Move to Bottom',
     expandOptions: 'Expand Options',
     collapseOptions: 'Collapse Options',
+    taskTimeout: 'Task Timeout…',
     selectAll: 'Select All Tasks',
     deselectAll: 'Deselect All',
     expandAllTasks: 'Expand All',
@@ -830,6 +916,28 @@ export default {
     retry: 'Retry',
   },
 
+  // Config encryption
+  configEncryption: {
+    title: 'Config Encryption',
+    hint: 'Encrypt config files and run reports on disk',
+    modeMachine: 'Enabled (machine key)',
+    modePassphrase: 'Enabled (passphrase)',
+    enableTitle: 'Enable Config Encryption',
+    enableMessage:
+      'Leave the passphrase empty to use a machine key: no prompt, but the config cannot be read on another computer. With a passphrase you must unlock on every launch.',
+    passphrase: 'Passphrase (optional)',
+    passphraseConfirm: 'Confirm passphrase',
+    passphraseMismatch: 'Passphrases do not match',
+    disableTitle: 'Disable Config Encryption',
+    disableMessage: 'Config files and run reports will be stored as plain text again.',
+    unlockTitle: 'Unlock Config',
+    unlockMessage: 'The config is encrypted with a passphrase. Enter it to unlock.',
+    unlockPassphrase: 'Passphrase',
+    unlock: 'Unlock',
+    exit: 'Exit',
+    failed: 'Operation failed: {{error}}',
+  },
+
   // VC++ Runtime
   vcredist: {
     title: 'Missing Runtime',
@@ -241,6 +241,10 @@ export default {
     remove: 'タスクを削除',
     removeConfirmTitle: 'タスクを削除',
     removeConfirmMessage: 'このタスクを削除してもよろしいですか？',
+    timeoutTitle: 'タスクのタイムアウト',
+    timeoutMessage: '実行時間を超えるとタスクを停止します。0 は無制限です。',
+    timeoutSecs: '最大実行時間（秒）',
+    timeoutRestarts: 'タイムアウト後の再実行回数（最大 5 回）',
     rename: '名前を変更',
     clickToToggle: 'クリックで切替',
     renameTask: 'タスク名を変更',
@@ -401,6 +405,41 @@ export default {
     version: 'バージョン',
     needConnection: '先にデバイスを接続してください',
     needResource: '先にリソースを読み込んでください',
+    interventionRequired: '手動での対応が必要です。完了したら続行を確認してください',
+    interventionConfirm: '続行',
+    taskTimeout: 'タスク {{entry}} が {{seconds}} 秒を超えたため停止しました',
+    taskTimeoutRestarted: 'タスク {{entry}} が {{seconds}} 秒を超えたため再実行しました',
+    taskSkipped: '前提タスクが失敗したため、タスク {{entry}} をスキップしました',
+    taskSkippedByCondition: '実行条件 {{condition}} を満たさないため、タスク {{entry}} をスキップしました',
+    resolutionChanged:
+      'スクリーンショットの解像度が {{previous}} から {{current}} に変わりました。現在のリソースはこの解像度に対応していない可能性があります。エミュレーターまたはウィンドウの設定を確認してください',
+    deviceLowBattery:
+      'デバイスのバッテリー残量が少ないため（{{level}}%）、デバイス保護のためタスクを停止しました',
+    deviceOverheat:
+      'デバイスの温度が高すぎるため（{{temperature}}）、デバイス保護のためタスクを停止しました',
+    win32DpiWarning:
+      '対象ウィンドウは {{scale}}% に拡大され、高 DPI に対応していないため、クリック位置がずれる可能性があります。表示スケールを 100% にするか、互換性設定で「高 DPI スケールの動作を上書きします」を有効にしてください',
+    win32ElevationWarning:
+      '{{process}} は管理者権限で実行されているため、MXU のクリックやキー入力はシステムにブロックされます。MXU を管理者として再起動してください',
+    restartAsAdmin: '管理者として再起動',
+    deepLinkRun: 'リンクから設定「{{name}}」の実行が要求されました',
+    deepLinkRunConfirm: '実行',
+    deepLinkProfileNotFound: 'リンクの設定「{{name}}」は存在しません',
+    deepLinkImport:
+      'リンクから {{host}} のリソースパッケージのダウンロードとインストールが要求されました。信頼できる提供元か確認してください',
+    deepLinkImportConfirm: 'ダウンロードしてインストール',
+    maintenanceRefused:
+      'メンテナンス時間「{{name}}」中のため開始しませんでした（{{endsAt}} 終了予定）',
+    maintenanceDelayed:
+      'メンテナンス時間「{{name}}」中のため、{{endsAt}} の終了後にタスクを開始します',
+    clockDriftWarning:
+      'システム時刻が標準時刻と {{seconds}} 秒ずれています。スケジュールや待機が正しく動作しない可能性があるため、時刻を同期してください',
+    integrityWarning:
+      'プログラムファイルの整合性チェックに失敗しました（欠落 {{missing}} 件、変更 {{modified}} 件）。再インストールするか更新を完了してください',
+    integrityManifestInvalid:
+      'プログラムファイルのマニフェスト署名が無効です。ファイルが改ざんされている可能性があるため、公式の配布元から再ダウンロードしてください',
+    integrityManifestMissing:
+      'プログラムファイルのマニフェストが見つかりません。インストールが不完全か改ざんされている可能性があるため、公式の配布元から再ダウンロードしてください',
   },
 
   // スクリーンショットプレビュー
@@ -661,6 +700,7 @@ export default {
     installNow: '今すぐインストール',
     installUpdate: 'アップデートをインストール',
     installStages: {
+      verifying: '更新パッケージの署名を検証中...',
       extracting: '解凍中...',
       checking: 'アップデートタイプを確認中...',
       applying: 'アップデートを適用中...',
@@ -669,6 +709,7 @@ export default {
       incremental: '差分アップデート',
       full: 'フルアップデート',
       fallback: 'フォールバック更新を実行中...',
+      rollback: '以前の MaaFramework を復元中...',
     },
     restartRequired: 'アップデートがインストールされました。変更を適用するには再起動してください',
     restartNow: '今すぐ再起動',
@@ -676,6 +717,21 @@ export default {
     installerOpened: 'インストーラーが開きました',
     installerOpenedHint:
       'インストーラーの操作を完了してください。インストール完了後、このアプリを再起動してください',
+    pendingReboot:
+      '更新をインストールしました。使用中の {{count}} 個のファイルは次回の PC 再起動時に置き換えられます',
+    pendingRebootHint:
+      '更新を完了するには PC を再起動してください。再起動前に更新を再実行しないでください',
+    insufficientDiskSpace:
+      'ディスク容量が不足しています。必要: {{required}} MB、空き: {{available}} MB（{{path}}）',
+    fallbackInstalled:
+      'アップデートに失敗しましたが、新しいバージョンを {{path}} に展開しました。一時的にこのフォルダー内のプログラムを使用できます',
+    maafwVersionTooLow: 'バージョン {{version}} は最小サポートバージョン {{minimum}} より古いです',
+    maafwIncompatible:
+      '新しい MaaFramework は使用できないため、アップデートを中止しました。現在のバージョンは変更されていません：{{reason}}',
+    maafwRolledBack:
+      '新しい MaaFramework はインストール後に動作しなかったため、以前の MaaFramework を復元しました：{{reason}}',
+    maafwRollbackFailed:
+      '新しい MaaFramework はインストール後に動作せず、以前の MaaFramework の復元にも失敗しました（{{error}}）。完全なパッケージを再ダウンロードしてください：{{reason}}',
     // アップデート完了後
     updateCompleteTitle: 'アップデート完了',
     updateCompleteMessage: '最新バージョンへのアップデートに成功しました',
@@ -720,6 +776,9 @@ export default {
     hoursSelected: '件の時刻',
     timeZoneHint: 'ローカルタイムゾーンを使用',
     multiSelect: '複数選択可',
+    afterReset: 'サーバーリセット後',
+    afterResetHint:
+      'インスタンスのサーバー日次リセット時刻（既定 04:00、設定ファイルの serverReset で変更可）直後の正時に実行します',
     enable: 'スケジュールを有効化',
     disable: 'スケジュールを無効化',
     enableAll: 'すべてのスケジュールを有効化',
@@ -741,6 +800,30 @@ export default {
     taskNotFound: 'タスクが見つかりません',
     controllerNotFound: 'コントローラーが見つかりません',
     resourceNotFound: 'リソースパックが見つかりません',
+    codes: {
+      NOT_INITIALIZED: 'MaaFramework が初期化されていません',
+      INSTANCE_NOT_FOUND: 'インスタンスが見つかりません',
+      CONTROLLER_NOT_CONNECTED: 'コントローラーが接続されていません',
+      RESOURCE_NOT_LOADED: 'リソースが読み込まれていません',
+      TARGET_ELEVATED: '対象ウィンドウは管理者権限で実行されています',
+      AGENT_FAILED: 'Agent の起動に失敗しました',
+      ADB_UNAUTHORIZED: 'デバイスで USB デバッグが許可されていません',
+      INSUFFICIENT_DISK_SPACE: 'ディスク容量が不足しています',
+      PATH_TOO_LONG: 'インストールパスが長すぎます',
+      INVALID_ARGUMENT: '無効な引数です',
+    },
+    hints: {
+      NOT_INITIALIZED: 'maafw フォルダーが揃っているか確認するか、MaaFramework を再インストールしてください',
+      INSTANCE_NOT_FOUND: 'インスタンスが閉じられた可能性があります。開き直してください',
+      CONTROLLER_NOT_CONNECTED: '先にデバイスまたはウィンドウに接続してください',
+      RESOURCE_NOT_LOADED: '先にリソースを読み込んでください',
+      TARGET_ELEVATED: 'MXU を管理者として再起動してから接続してください',
+      AGENT_FAILED: 'Agent の出力ログを確認してください',
+      ADB_UNAUTHORIZED: 'デバイスで USB デバッグを許可し（「常に許可」を推奨）、再接続してください',
+      INSUFFICIENT_DISK_SPACE:
+        'このドライブの空き容量を増やすか、MXU を空きのあるドライブに移動してから再試行してください',
+      PATH_TOO_LONG: 'MXU を短いフォルダー（例: D:\\MXU）に移動するか、長いパスのサポートを有効にしてください',
+    },
   },
 
   // コンテキストメニュー
@@ -781,6 +864,7 @@ export default {
     moveToBottom: '最下部に移動',
     expandOptions: 'オプションを展開',
     collapseOptions: 'オプションを折りたたむ',
+    taskTimeout: 'タスクのタイムアウト…',
     selectAll: 'すべて選択',
     deselectAll: 'すべて解除',
     expandAllTasks: 'すべて展開',
@@ -830,6 +914,28 @@ export default {
     retry: '再試行',
   },
 
+  // 設定の暗号化
+  configEncryption: {
+    title: '設定の暗号化',
+    hint: '設定ファイルと実行レポートを暗号化して保存します',
+    modeMachine: '有効（マシンキー）',
+    modePassphrase: '有効（パスワード）',
+    enableTitle: '設定の暗号化を有効にする',
+    enableMessage:
+      'パスワードを空にするとマシンキーを使用します（入力不要ですが、他の PC では読み込めません）。パスワードを設定すると起動のたびにロック解除が必要です。',
+    passphrase: 'パスワード（任意）',
+    passphraseConfirm: 'パスワードの確認',
+    passphraseMismatch: 'パスワードが一致しません',
+    disableTitle: '設定の暗号化を無効にする',
+    disableMessage: '設定ファイルと実行レポートは平文で保存されます。',
+    unlockTitle: '設定のロック解除',
+    unlockMessage: '設定はパスワードで暗号化されています。パスワードを入力してください。',
+    unlockPassphrase: 'パスワード',
+    unlock: 'ロック解除',
+    exit: '終了',
+    failed: '操作に失敗しました：{{error}}',
+  },
+
   // VC++ ランタイム
   vcredist: {
     title: 'ランタイムが見つかりません',
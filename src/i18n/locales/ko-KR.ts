@@ -239,6 +239,10 @@ export default {
     remove: '작업 삭제',
     removeConfirmTitle: '작업 삭제',
     removeConfirmMessage: '이 작업을 삭제하시겠습니까?',
+    timeoutTitle: '작업 시간 제한',
+    timeoutMessage: '실행 시간을 초과하면 작업을 중지합니다. 0은 제한 없음입니다.',
+    timeoutSecs: '최대 실행 시간(초)',
+    timeoutRestarts: '시간 초과 후 재실행 횟수(최대 5회)',
     rename: '이름 변경',
     clickToToggle: '클릭하여 전환',
     renameTask: '작업 이름 변경',
@@ -398,6 +402,38 @@ export default {
     version: '버전',
     needConnection: '먼저 기기를 연결하세요',
     needResource: '먼저 리소스를 로드하세요',
+    interventionRequired: '수동 조치가 필요합니다. 완료 후 계속을 확인하세요',
+    interventionConfirm: '계속',
+    taskTimeout: '작업 {{entry}}이(가) {{seconds}}초를 초과하여 중지되었습니다',
+    taskTimeoutRestarted: '작업 {{entry}}이(가) {{seconds}}초를 초과하여 다시 실행되었습니다',
+    taskSkipped: '선행 작업이 실패하여 작업 {{entry}}을(를) 건너뛰었습니다',
+    taskSkippedByCondition: '실행 조건 {{condition}}을(를) 충족하지 않아 작업 {{entry}}을(를) 건너뛰었습니다',
+    resolutionChanged:
+      '스크린샷 해상도가 {{previous}}에서 {{current}}(으)로 바뀌었습니다. 현재 리소스가 이 해상도를 지원하지 않을 수 있으니 에뮬레이터나 창 설정을 확인하세요',
+    deviceLowBattery: '기기 배터리가 부족하여({{level}}%) 기기 보호를 위해 작업을 중지했습니다',
+    deviceOverheat: '기기 온도가 너무 높아({{temperature}}) 기기 보호를 위해 작업을 중지했습니다',
+    win32DpiWarning:
+      '대상 창이 {{scale}}%로 확대되어 있고 고 DPI를 지원하지 않아 클릭 위치가 어긋날 수 있습니다. 디스플레이 배율을 100%로 설정하거나 프로그램 호환성 설정에서 "높은 DPI 배율 조정 동작 재정의"를 켜세요',
+    win32ElevationWarning:
+      '{{process}}이(가) 관리자 권한으로 실행 중이어서 MXU가 보내는 클릭과 키 입력이 시스템에 의해 차단됩니다. MXU를 관리자 권한으로 다시 시작하세요',
+    restartAsAdmin: '관리자 권한으로 다시 시작',
+    deepLinkRun: '링크에서 구성 "{{name}}" 실행을 요청했습니다',
+    deepLinkRunConfirm: '실행',
+    deepLinkProfileNotFound: '링크의 구성 "{{name}}"이(가) 존재하지 않습니다',
+    deepLinkImport:
+      '링크에서 {{host}}의 리소스 패키지 다운로드 및 설치를 요청했습니다. 신뢰할 수 있는 출처인지 확인하세요',
+    deepLinkImportConfirm: '다운로드 및 설치',
+    maintenanceRefused:
+      '점검 시간 "{{name}}" 중이라 작업을 시작하지 않았습니다({{endsAt}} 종료 예정)',
+    maintenanceDelayed: '점검 시간 "{{name}}" 중입니다. {{endsAt}}에 종료된 후 작업을 시작합니다',
+    clockDriftWarning:
+      '시스템 시간이 표준 시간과 {{seconds}}초 차이 납니다. 예약 실행과 대기가 어긋날 수 있으니 시간을 동기화하세요',
+    integrityWarning:
+      '프로그램 파일 무결성 검사 실패: 누락 {{missing}}개, 변경 {{modified}}개. 다시 설치하거나 업데이트를 완료하세요',
+    integrityManifestInvalid:
+      '프로그램 파일 목록의 서명이 잘못되었습니다. 파일이 변조되었을 수 있으니 공식 경로에서 다시 내려받으세요',
+    integrityManifestMissing:
+      '프로그램 파일 목록이 없습니다. 설치가 불완전하거나 변조되었을 수 있으니 공식 경로에서 다시 내려받으세요',
   },
 
   // 스크린샷 미리보기
@@ -655,6 +691,7 @@ export default {
     installNow: '지금 설치',
     installUpdate: '업데이트 설치',
     installStages: {
+      verifying: '업데이트 패키지 서명 확인 중...',
       extracting: '압축 해제 중...',
       checking: '업데이트 유형 확인 중...',
       applying: '업데이트 적용 중...',
@@ -663,12 +700,28 @@ export default {
       incremental: '증분 업데이트',
       full: '전체 업데이트',
       fallback: '대체 업데이트 수행 중...',
+      rollback: '이전 MaaFramework 복원 중...',
     },
     restartRequired: '업데이트가 설치되었습니다. 변경 사항을 적용하려면 재시작하세요',
     restartNow: '지금 재시작',
     restarting: '재시작 중...',
     installerOpened: '설치 프로그램이 열렸습니다',
     installerOpenedHint: '설치 프로그램을 완료한 후 이 앱을 다시 시작하세요',
+    pendingReboot:
+      '업데이트를 설치했습니다. 사용 중인 파일 {{count}}개는 다음에 컴퓨터를 다시 시작할 때 교체됩니다',
+    pendingRebootHint:
+      '업데이트를 완료하려면 컴퓨터를 다시 시작하세요. 다시 시작하기 전에는 업데이트를 다시 실행하지 마세요',
+    insufficientDiskSpace:
+      '디스크 공간이 부족합니다. 필요: {{required}} MB, 사용 가능: {{available}} MB ({{path}})',
+    fallbackInstalled:
+      '업데이트에 실패했지만 새 버전을 {{path}}에 압축 해제했습니다. 임시로 해당 폴더의 프로그램을 사용할 수 있습니다',
+    maafwVersionTooLow: '버전 {{version}}이(가) 최소 지원 버전 {{minimum}}보다 낮습니다',
+    maafwIncompatible:
+      '새 MaaFramework를 사용할 수 없어 업데이트를 취소했습니다. 현재 버전은 변경되지 않았습니다: {{reason}}',
+    maafwRolledBack:
+      '새 MaaFramework가 설치 후 동작하지 않아 이전 MaaFramework를 복원했습니다: {{reason}}',
+    maafwRollbackFailed:
+      '새 MaaFramework가 설치 후 동작하지 않으며 이전 MaaFramework 복원에도 실패했습니다({{error}}). 전체 패키지를 다시 다운로드하세요: {{reason}}',
     // 업데이트 완료 후
     updateCompleteTitle: '업데이트 완료',
     updateCompleteMessage: '최신 버전으로 성공적으로 업데이트되었습니다',
@@ -713,6 +766,9 @@ export default {
     hoursSelected: '개의 시간',
     timeZoneHint: '로컬 시간대 사용',
     multiSelect: '다중 선택',
+    afterReset: '서버 초기화 후',
+    afterResetHint:
+      '인스턴스의 서버 일일 초기화 시각(기본 04:00, 설정 파일의 serverReset으로 변경) 이후 첫 정시에 실행합니다',
     enable: '예약 활성화',
     disable: '예약 비활성화',
     enableAll: '모든 예약 활성화',
@@ -734,6 +790,30 @@ export default {
     taskNotFound: '작업을 찾을 수 없습니다',
     controllerNotFound: '컨트롤러를 찾을 수 없습니다',
     resourceNotFound: '리소스 팩을 찾을 수 없습니다',
+    codes: {
+      NOT_INITIALIZED: 'MaaFramework가 초기화되지 않았습니다',
+      INSTANCE_NOT_FOUND: '인스턴스를 찾을 수 없습니다',
+      CONTROLLER_NOT_CONNECTED: '컨트롤러가 연결되지 않았습니다',
+      RESOURCE_NOT_LOADED: '리소스가 로드되지 않았습니다',
+      TARGET_ELEVATED: '대상 창이 관리자 권한으로 실행 중입니다',
+      AGENT_FAILED: 'Agent 시작 실패',
+      ADB_UNAUTHORIZED: '기기에서 USB 디버깅이 허용되지 않았습니다',
+      INSUFFICIENT_DISK_SPACE: '디스크 공간이 부족합니다',
+      PATH_TOO_LONG: '설치 경로가 너무 깁니다',
+      INVALID_ARGUMENT: '잘못된 인수입니다',
+    },
+    hints: {
+      NOT_INITIALIZED: 'maafw 폴더가 온전한지 확인하거나 MaaFramework를 다시 설치하세요',
+      INSTANCE_NOT_FOUND: '인스턴스가 닫혔을 수 있습니다. 다시 열어 주세요',
+      CONTROLLER_NOT_CONNECTED: '먼저 장치나 창을 연결하세요',
+      RESOURCE_NOT_LOADED: '먼저 리소스를 로드하세요',
+      TARGET_ELEVATED: 'MXU를 관리자 권한으로 다시 시작한 후 연결하세요',
+      AGENT_FAILED: 'Agent 출력 로그를 확인하세요',
+      ADB_UNAUTHORIZED: '기기에서 USB 디버깅을 허용한 후("항상 허용" 권장) 다시 연결하세요',
+      INSUFFICIENT_DISK_SPACE:
+        '이 드라이브의 공간을 확보하거나 MXU를 여유 공간이 있는 드라이브로 옮긴 후 다시 시도하세요',
+      PATH_TOO_LONG: 'MXU를 짧은 경로(예: D:\\MXU)로 옮기거나 Windows 긴 경로 지원을 켜세요',
+    },
   },
 
   // 컨텍스트 메뉴
@@ -774,6 +854,7 @@ export default {
     moveToBottom: '맨 아래로 이동',
     expandOptions: '옵션 펼치기',
     collapseOptions: '옵션 접기',
+    taskTimeout: '작업 시간 제한…',
     selectAll: '모두 선택',
     deselectAll: '모두 선택 해제',
     expandAllTasks: '모두 펼치기',
@@ -822,6 +903,28 @@ export default {
     retry: '다시 시도',
   },
 
+  // 설정 암호화
+  configEncryption: {
+    title: '설정 암호화',
+    hint: '설정 파일과 실행 보고서를 암호화하여 저장합니다',
+    modeMachine: '사용 중 (기기 키)',
+    modePassphrase: '사용 중 (비밀번호)',
+    enableTitle: '설정 암호화 사용',
+    enableMessage:
+      '비밀번호를 비워 두면 기기 키를 사용합니다(입력 불필요, 다른 PC에서는 읽을 수 없음). 비밀번호를 설정하면 실행할 때마다 잠금 해제가 필요합니다.',
+    passphrase: '비밀번호 (선택)',
+    passphraseConfirm: '비밀번호 확인',
+    passphraseMismatch: '비밀번호가 일치하지 않습니다',
+    disableTitle: '설정 암호화 해제',
+    disableMessage: '설정 파일과 실행 보고서가 다시 평문으로 저장됩니다.',
+    unlockTitle: '설정 잠금 해제',
+    unlockMessage: '설정이 비밀번호로 암호화되어 있습니다. 비밀번호를 입력하세요.',
+    unlockPassphrase: '비밀번호',
+    unlock: '잠금 해제',
+    exit: '종료',
+    failed: '작업 실패: {{error}}',
+  },
+
   // VC++ 런타임
   vcredist: {
     title: '런타임 누락',
@@ -239,6 +239,10 @@ export default {
     remove: '移除任务',
     removeConfirmTitle: '删除任务',
     removeConfirmMessage: '确定要删除这个任务吗？',
+    timeoutTitle: '任务超时',
+    timeoutMessage: '超时后停止任务，0 表示不限制。',
+    timeoutSecs: '最长运行时间（秒）',
+    timeoutRestarts: '超时后重新执行次数（最多 5 次）',
     rename: '重命名',
     clickToToggle: '单击选中/取消',
     renameTask: '重命名任务',
@@ -394,6 +398,36 @@ export default {
     version: '版本',
     needConnection: '请先连接设备',
     needResource: '请先加载资源',
+    interventionRequired: '需要人工处理，请完成后确认继续',
+    interventionConfirm: '继续',
+    taskTimeout: '任务 {{entry}} 运行超过 {{seconds}} 秒，已停止',
+    taskTimeoutRestarted: '任务 {{entry}} 运行超过 {{seconds}} 秒，已重新执行',
+    taskSkipped: '前置任务失败，已跳过任务 {{entry}}',
+    taskSkippedByCondition: '执行条件 {{condition}} 不满足，已跳过任务 {{entry}}',
+    resolutionChanged:
+      '截图分辨率由 {{previous}} 变为 {{current}}，当前资源可能不支持该分辨率，请检查模拟器或窗口设置',
+    deviceLowBattery: '设备电量过低（{{level}}%），已停止任务以保护设备',
+    deviceOverheat: '设备温度过高（{{temperature}}），已停止任务以保护设备',
+    win32DpiWarning:
+      '目标窗口缩放为 {{scale}}% 且未适配高 DPI，点击位置可能偏移。请将系统缩放改为 100%，或在程序兼容性设置中勾选“替代高 DPI 缩放行为”',
+    win32ElevationWarning:
+      '目标程序 {{process}} 以管理员权限运行，MXU 发送的点击和按键会被系统拦截，请以管理员身份重启 MXU',
+    restartAsAdmin: '以管理员身份重启',
+    deepLinkRun: '链接请求开始运行配置「{{name}}」',
+    deepLinkRunConfirm: '开始运行',
+    deepLinkProfileNotFound: '链接中的配置「{{name}}」不存在',
+    deepLinkImport: '链接请求从 {{host}} 下载并安装资源包，请确认来源可信',
+    deepLinkImportConfirm: '下载并安装',
+    maintenanceRefused: '当前处于维护时段「{{name}}」，预计 {{endsAt}} 结束，已取消启动',
+    maintenanceDelayed: '当前处于维护时段「{{name}}」，将在 {{endsAt}} 结束后自动启动任务',
+    clockDriftWarning:
+      '系统时间与标准时间相差 {{seconds}} 秒，定时执行与等待可能不准，请校准系统时钟',
+    integrityWarning:
+      '程序文件完整性校验未通过：缺失 {{missing}} 个、被修改 {{modified}} 个文件，请重新安装或完成更新',
+    integrityManifestInvalid:
+      '程序文件完整性清单签名无效，文件可能被篡改，请从官方渠道重新下载安装',
+    integrityManifestMissing:
+      '程序文件完整性清单缺失，安装包可能不完整或被篡改，请从官方渠道重新下载安装',
   },
 
   // 截图预览
@@ -646,6 +680,7 @@ export default {
     installNow: '立即安装',
     installUpdate: '安装更新',
     installStages: {
+      verifying: '正在校验更新包签名...',
       extracting: '正在解压...',
       checking: '检查更新类型...',
       applying: '正在应用更新...',
@@ -654,12 +689,22 @@ export default {
       incremental: '增量更新',
       full: '全量更新',
       fallback: '正在执行兜底更新...',
+      rollback: '正在恢复原 MaaFramework...',
     },
     restartRequired: '更新已安装，请重启应用以生效',
     restartNow: '立即重启',
     restarting: '正在重启...',
     installerOpened: '安装程序已打开',
     installerOpenedHint: '请完成安装程序的操作，安装完成后重新启动本应用即可',
+    pendingReboot: '更新已安装，{{count}} 个文件被占用，将在下次重启电脑时替换',
+    pendingRebootHint: '请重启电脑以完成更新，重启前请勿再次运行更新',
+    insufficientDiskSpace: '磁盘空间不足，需要 {{required}} MB，可用 {{available}} MB（{{path}}）',
+    fallbackInstalled: '更新失败，但已将新版本文件解压到 {{path}}，您可以临时使用该文件夹中的程序',
+    maafwVersionTooLow: '版本 {{version}} 低于最小支持版本 {{minimum}}',
+    maafwIncompatible: '新版本 MaaFramework 不可用，已取消更新，当前版本未做改动：{{reason}}',
+    maafwRolledBack: '新版本 MaaFramework 安装后无法使用，已恢复更新前的 MaaFramework：{{reason}}',
+    maafwRollbackFailed:
+      '新版本 MaaFramework 安装后无法使用，且恢复原 MaaFramework 失败（{{error}}），请重新下载完整安装包：{{reason}}',
     // 更新完成后
     updateCompleteTitle: '更新完成',
     updateCompleteMessage: '已成功更新到最新版本',
@@ -715,6 +760,9 @@ export default {
     hoursSelected: '个时间点',
     timeZoneHint: '使用本地时区',
     multiSelect: '可多选',
+    afterReset: '服务器重置后',
+    afterResetHint:
+      '在实例的服务器每日重置时刻（默认 04:00，可在配置中设置 serverReset）后的第一个整点执行',
     enable: '启用策略',
     disable: '禁用策略',
     enableAll: '启用所有策略',
@@ -736,6 +784,29 @@ export default {
     taskNotFound: '任务不存在',
     controllerNotFound: '控制器不存在',
     resourceNotFound: '资源包不存在',
+    codes: {
+      NOT_INITIALIZED: 'MaaFramework 未初始化',
+      INSTANCE_NOT_FOUND: '实例不存在',
+      CONTROLLER_NOT_CONNECTED: '控制器未连接',
+      RESOURCE_NOT_LOADED: '资源未加载',
+      TARGET_ELEVATED: '目标窗口以管理员身份运行',
+      AGENT_FAILED: 'Agent 启动失败',
+      ADB_UNAUTHORIZED: '设备未授权 USB 调试',
+      INSUFFICIENT_DISK_SPACE: '磁盘空间不足',
+      PATH_TOO_LONG: '安装路径过长',
+      INVALID_ARGUMENT: '参数无效',
+    },
+    hints: {
+      NOT_INITIALIZED: '请检查 maafw 目录是否完整，或重新安装 MaaFramework',
+      INSTANCE_NOT_FOUND: '实例可能已被关闭，请重新打开该实例',
+      CONTROLLER_NOT_CONNECTED: '请先连接设备或窗口',
+      RESOURCE_NOT_LOADED: '请先加载资源',
+      TARGET_ELEVATED: '请以管理员身份重启 MXU 后再连接',
+      AGENT_FAILED: '请查看 Agent 输出日志排查问题',
+      ADB_UNAUTHORIZED: '请在设备上允许 USB 调试授权（可勾选“始终允许”），然后重新连接',
+      INSUFFICIENT_DISK_SPACE: '请清理该磁盘或将 MXU 移动到空间充足的磁盘后重试',
+      PATH_TOO_LONG: '请将 MXU 移动到较短的目录（如 D:\\MXU），或开启系统长路径支持',
+    },
   },
 
   // 右键菜单
@@ -776,6 +847,7 @@ export default {
     moveToBottom: '置底',
     expandOptions: '展开选项',
     collapseOptions: '折叠选项',
+    taskTimeout: '任务超时…',
     selectAll: '全选任务',
     deselectAll: '取消全选',
     expandAllTasks: '展开全部',
@@ -823,6 +895,28 @@ export default {
     retry: '重试',
   },
 
+  // 配置加密
+  configEncryption: {
+    title: '配置加密',
+    hint: '加密保存配置文件与运行报告',
+    modeMachine: '已开启（本机密钥）',
+    modePassphrase: '已开启（密码）',
+    enableTitle: '开启配置加密',
+    enableMessage:
+      '留空密码时使用本机密钥，无需输入但配置无法在其他电脑上读取；设置密码后每次启动需输入密码解锁。',
+    passphrase: '密码（可选）',
+    passphraseConfirm: '确认密码',
+    passphraseMismatch: '两次输入的密码不一致',
+    disableTitle: '关闭配置加密',
+    disableMessage: '配置文件与运行报告将还原为明文保存。',
+    unlockTitle: '解锁配置',
+    unlockMessage: '配置已使用密码加密，请输入密码解锁。',
+    unlockPassphrase: '密码',
+    unlock: '解锁',
+    exit: '退出',
+    failed: '操作失败：{{error}}',
+  },
+
   // VC++ 运行库
   vcredist: {
     title: '缺少运行库',
@@ -235,6 +235,10 @@ export default {
     remove: '移除任務',
     removeConfirmTitle: '刪除任務',
     removeConfirmMessage: '確定要刪除這個任務嗎？',
+    timeoutTitle: '任務逾時',
+    timeoutMessage: '逾時後停止任務，0 表示不限制。',
+    timeoutSecs: '最長執行時間（秒）',
+    timeoutRestarts: '逾時後重新執行次數（最多 5 次）',
     rename: '重新命名',
     clickToToggle: '單擊選中/取消',
     renameTask: '重新命名任務',
@@ -390,6 +394,36 @@ export default {
     version: '版本',
     needConnection: '請先連接裝置',
     needResource: '請先載入資源',
+    interventionRequired: '需要人工處理，請完成後確認繼續',
+    interventionConfirm: '繼續',
+    taskTimeout: '任務 {{entry}} 執行超過 {{seconds}} 秒，已停止',
+    taskTimeoutRestarted: '任務 {{entry}} 執行超過 {{seconds}} 秒，已重新執行',
+    taskSkipped: '前置任務失敗，已略過任務 {{entry}}',
+    taskSkippedByCondition: '執行條件 {{condition}} 不成立，已略過任務 {{entry}}',
+    resolutionChanged:
+      '截圖解析度由 {{previous}} 變為 {{current}}，目前資源可能不支援此解析度，請檢查模擬器或視窗設定',
+    deviceLowBattery: '裝置電量過低（{{level}}%），已停止任務以保護裝置',
+    deviceOverheat: '裝置溫度過高（{{temperature}}），已停止任務以保護裝置',
+    win32DpiWarning:
+      '目標視窗縮放為 {{scale}}% 且未支援高 DPI，點擊位置可能偏移。請將系統縮放改為 100%，或在程式相容性設定中勾選「覆寫高 DPI 縮放行為」',
+    win32ElevationWarning:
+      '目標程式 {{process}} 以系統管理員權限執行，MXU 送出的點擊與按鍵會被系統攔截，請以系統管理員身分重新啟動 MXU',
+    restartAsAdmin: '以系統管理員身分重新啟動',
+    deepLinkRun: '連結要求開始執行設定「{{name}}」',
+    deepLinkRunConfirm: '開始執行',
+    deepLinkProfileNotFound: '連結中的設定「{{name}}」不存在',
+    deepLinkImport: '連結要求從 {{host}} 下載並安裝資源包，請確認來源可信',
+    deepLinkImportConfirm: '下載並安裝',
+    maintenanceRefused: '目前處於維護時段「{{name}}」，預計 {{endsAt}} 結束，已取消啟動',
+    maintenanceDelayed: '目前處於維護時段「{{name}}」，將在 {{endsAt}} 結束後自動啟動任務',
+    clockDriftWarning:
+      '系統時間與標準時間相差 {{seconds}} 秒，定時執行與等待可能不準，請校正系統時鐘',
+    integrityWarning:
+      '程式檔案完整性校驗未通過：缺少 {{missing}} 個、被修改 {{modified}} 個檔案，請重新安裝或完成更新',
+    integrityManifestInvalid:
+      '程式檔案完整性清單簽章無效，檔案可能遭到竄改，請從官方管道重新下載安裝',
+    integrityManifestMissing:
+      '程式檔案完整性清單遺失，安裝包可能不完整或遭到竄改，請從官方管道重新下載安裝',
   },
 
   // 截圖預覽
@@ -642,6 +676,7 @@ export default {
     installNow: '立即安裝',
     installUpdate: '安裝更新',
     installStages: {
+      verifying: '正在驗證更新套件簽章...',
       extracting: '正在解壓...',
       checking: '檢查更新類型...',
       applying: '正在應用程式更新...',
@@ -650,12 +685,23 @@ export default {
       incremental: '增量更新',
       full: '全量更新',
       fallback: '正在執行兜底更新...',
+      rollback: '正在還原原 MaaFramework...',
     },
     restartRequired: '更新已安裝，請重啟應用程式以生效',
     restartNow: '立即重啟',
     restarting: '正在重啟...',
     installerOpened: '安裝程式已開啟',
     installerOpenedHint: '請完成安裝程式的操作，安裝完成後重新啟動本應用程式即可',
+    pendingReboot: '更新已安裝，{{count}} 個檔案被占用，將在下次重新啟動電腦時替換',
+    pendingRebootHint: '請重新啟動電腦以完成更新，重新啟動前請勿再次執行更新',
+    insufficientDiskSpace: '磁碟空間不足，需要 {{required}} MB，可用 {{available}} MB（{{path}}）',
+    fallbackInstalled:
+      '更新失敗，但已將新版本檔案解壓縮到 {{path}}，您可以暫時使用該資料夾中的程式',
+    maafwVersionTooLow: '版本 {{version}} 低於最低支援版本 {{minimum}}',
+    maafwIncompatible: '新版本 MaaFramework 無法使用，已取消更新，目前版本未做變更：{{reason}}',
+    maafwRolledBack: '新版本 MaaFramework 安裝後無法使用，已還原更新前的 MaaFramework：{{reason}}',
+    maafwRollbackFailed:
+      '新版本 MaaFramework 安裝後無法使用，且還原原 MaaFramework 失敗（{{error}}），請重新下載完整安裝包：{{reason}}',
     // 更新完成後
     updateCompleteTitle: '更新完成',
     updateCompleteMessage: '已成功更新到最新版本',
@@ -700,6 +746,9 @@ export default {
     hoursSelected: '個時間点',
     timeZoneHint: '使用本地時區',
     multiSelect: '可多選',
+    afterReset: '伺服器重置後',
+    afterResetHint:
+      '在實例的伺服器每日重置時刻（預設 04:00，可在設定檔中設定 serverReset）後的第一個整點執行',
     enable: '啟用策略',
     disable: '停用策略',
     enableAll: '啟用所有策略',
@@ -721,6 +770,29 @@ export default {
     taskNotFound: '任務不存在',
     controllerNotFound: '控制器不存在',
     resourceNotFound: '資源包不存在',
+    codes: {
+      NOT_INITIALIZED: 'MaaFramework 未初始化',
+      INSTANCE_NOT_FOUND: '實例不存在',
+      CONTROLLER_NOT_CONNECTED: '控制器未連接',
+      RESOURCE_NOT_LOADED: '資源未載入',
+      TARGET_ELEVATED: '目標視窗以系統管理員身分執行',
+      AGENT_FAILED: 'Agent 啟動失敗',
+      ADB_UNAUTHORIZED: '裝置未授權 USB 偵錯',
+      INSUFFICIENT_DISK_SPACE: '磁碟空間不足',
+      PATH_TOO_LONG: '安裝路徑過長',
+      INVALID_ARGUMENT: '參數無效',
+    },
+    hints: {
+      NOT_INITIALIZED: '請檢查 maafw 目錄是否完整，或重新安裝 MaaFramework',
+      INSTANCE_NOT_FOUND: '實例可能已被關閉，請重新開啟該實例',
+      CONTROLLER_NOT_CONNECTED: '請先連接裝置或視窗',
+      RESOURCE_NOT_LOADED: '請先載入資源',
+      TARGET_ELEVATED: '請以系統管理員身分重新啟動 MXU 後再連接',
+      AGENT_FAILED: '請查看 Agent 輸出日誌排查問題',
+      ADB_UNAUTHORIZED: '請在裝置上允許 USB 偵錯授權（可勾選「一律允許」），然後重新連接',
+      INSUFFICIENT_DISK_SPACE: '請清理該磁碟或將 MXU 移動到空間充足的磁碟後重試',
+      PATH_TOO_LONG: '請將 MXU 移動到較短的目錄（如 D:\\MXU），或開啟系統長路徑支援',
+    },
   },
 
   // 右鍵選單
@@ -761,6 +833,7 @@ export default {
     moveToBottom: '置底',
     expandOptions: '展開選項',
     collapseOptions: '摺疊選項',
+    taskTimeout: '任務逾時…',
     selectAll: '全選任務',
     deselectAll: '取消全選',
     expandAllTasks: '展開全部',
@@ -808,6 +881,28 @@ export default {
     retry: '重試',
   },
 
+  // 配置加密
+  configEncryption: {
+    title: '配置加密',
+    hint: '加密保存配置檔案與運行報告',
+    modeMachine: '已開啟（本機金鑰）',
+    modePassphrase: '已開啟（密碼）',
+    enableTitle: '開啟配置加密',
+    enableMessage:
+      '密碼留空時使用本機金鑰，無需輸入但配置無法在其他電腦上讀取；設定密碼後每次啟動需輸入密碼解鎖。',
+    passphrase: '密碼（可選）',
+    passphraseConfirm: '確認密碼',
+    passphraseMismatch: '兩次輸入的密碼不一致',
+    disableTitle: '關閉配置加密',
+    disableMessage: '配置檔案與運行報告將還原為明文保存。',
+    unlockTitle: '解鎖配置',
+    unlockMessage: '配置已使用密碼加密，請輸入密碼解鎖。',
+    unlockPassphrase: '密碼',
+    unlock: '解鎖',
+    exit: '退出',
+    failed: '操作失敗：{{error}}',
+  },
+
   // VC++ 執行库
   vcredist: {
     title: '缺少執行库',
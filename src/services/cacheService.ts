@@ -9,6 +9,7 @@
  * 4. 如果服务器返回新数据，更新缓存
  */
 
+import { invoke } from '@tauri-apps/api/core';
 import { loggers } from '@/utils/logger';
 import { getCacheDir, joinPath, isTauri } from '@/utils/paths';
 
@@ -88,11 +89,10 @@ async function loadCacheIndex(): Promise<CacheIndex> {
   }
 
   try {
-    const { readTextFile, exists } = await import('@tauri-apps/plugin-fs');
     const indexPath = await getCacheIndexPath();
 
-    if (await exists(indexPath)) {
-      const content = await readTextFile(indexPath);
+    if (await invoke<boolean>('local_file_exists', { filename: indexPath })) {
+      const content = await invoke<string>('read_local_file', { filename: indexPath });
       cacheIndex = JSON.parse(content) as CacheIndex;
       log.debug('缓存索引加载成功，条目数:', Object.keys(cacheIndex.entries).length);
     } else {
@@ -114,15 +114,11 @@ async function saveCacheIndex(): Promise<void> {
   if (!isTauri() || !cacheIndex) return;
 
   try {
-    const { writeTextFile, mkdir, exists } = await import('@tauri-apps/plugin-fs');
-    const cacheDir = await getCacheDir();
     const indexPath = await getCacheIndexPath();
-
-    if (!(await exists(cacheDir))) {
-      await mkdir(cacheDir, { recursive: true });
-    }
-
-    await writeTextFile(indexPath, JSON.stringify(cacheIndex, null, 2));
+    await invoke('write_local_file', {
+      filename: indexPath,
+      content: JSON.stringify(cacheIndex, null, 2),
+    });
   } catch (err) {
     log.warn('保存缓存索引失败:', err);
   }
@@ -135,11 +131,10 @@ async function readCacheData(filename: string): Promise<string | null> {
   if (!isTauri()) return null;
 
   try {
-    const { readTextFile, exists } = await import('@tauri-apps/plugin-fs');
     const filePath = await getCacheDataPath(filename);
 
-    if (await exists(filePath)) {
-      return await readTextFile(filePath);
+    if (await invoke<boolean>('local_file_exists', { filename: filePath })) {
+      return await invoke<string>('read_local_file', { filename: filePath });
     }
   } catch (err) {
     log.warn('读取缓存数据失败:', err);
@@ -154,15 +149,8 @@ async function writeCacheData(filename: string, data: string): Promise<void> {
   if (!isTauri()) return;
 
   try {
-    const { writeTextFile, mkdir, exists } = await import('@tauri-apps/plugin-fs');
-    const cacheDir = await getCacheDir();
-
-    if (!(await exists(cacheDir))) {
-      await mkdir(cacheDir, { recursive: true });
-    }
-
     const filePath = await getCacheDataPath(filename);
-    await writeTextFile(filePath, data);
+    await invoke('write_local_file', { filename: filePath, content: data });
   } catch (err) {
     log.warn('写入缓存数据失败:', err);
   }
@@ -314,15 +302,16 @@ export async function cleanExpiredCache(maxAge: number = 7 * 24 * 60 * 60 * 1000
 
     if (expiredUrls.length === 0) return;
 
-    const { remove, exists } = await import('@tauri-apps/plugin-fs');
-
-    // 删除过期的缓存文件和索引条目
+    // 删除过期的缓存文件和索引条目（优先移入回收站）
     for (const url of expiredUrls) {
       const entry = index.entries[url];
       const filePath = await getCacheDataPath(entry.filename);
 
-      if (await exists(filePath)) {
-        await remove(filePath);
+      try {
+        await invoke('move_to_trash', { path: filePath });
+      } catch (err) {
+        log.debug('移入回收站失败，直接删除:', err);
+        await invoke('remove_local_file', { path: filePath });
       }
       delete index.entries[url];
     }
@@ -333,3 +322,34 @@ export async function cleanExpiredCache(maxAge: number = 7 * 24 * 60 * 60 * 1000
     log.warn('清理过期缓存失败:', err);
   }
 }
+
+/** 缓存清理结果 */
+export interface PurgeCacheResult {
+  deletedFiles: number;
+  freedBytes: number;
+  failed: number;
+}
+
+/**
+ * 永久清理 cache/old 与 debug 日志
+ * @param olderThanDays 只删除早于该天数的文件，不传则全部删除
+ */
+export async function purgeCache(olderThanDays?: number): Promise<PurgeCacheResult> {
+  return await invoke<PurgeCacheResult>('purge_cache', { olderThanDays: olderThanDays ?? null });
+}
+
+/** 缓存占用统计（字节） */
+export interface CacheStats {
+  oldBytes: number;
+  logBytes: number;
+  archiveBytes: number;
+  debugImageBytes: number;
+  totalBytes: number;
+}
+
+/**
+ * 统计 cache/old、debug 日志、下载包与调试图片的占用
+ */
+export async function getCacheStats(): Promise<CacheStats> {
+  return await invoke<CacheStats>('mxu_cache_stats');
+}
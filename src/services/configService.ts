@@ -36,11 +36,6 @@ function getConfigFileName(projectName?: string): string {
   return projectName ? `mxu-${projectName}.json` : 'mxu.json';
 }
 
-/** 获取配置目录路径（同步版本，用于已知 dataPath 的场景） */
-function getConfigDirSync(dataPath: string): string {
-  return joinPath(dataPath || '.', CONFIG_DIR);
-}
-
 /** 获取配置文件完整路径（同步版本，用于已知 dataPath 的场景） */
 function getConfigPathSync(dataPath: string, projectName?: string): string {
   return joinPath(dataPath || '.', CONFIG_DIR, getConfigFileName(projectName));
@@ -57,11 +52,12 @@ export async function loadConfig(basePath: string, projectName?: string): Promis
 
     log.debug('加载配置, 路径:', configPath);
 
-    const { readTextFile, exists } = await import('@tauri-apps/plugin-fs');
+    const { invoke } = await import('@tauri-apps/api/core');
 
-    if (await exists(configPath)) {
+    if (await invoke<boolean>('local_file_exists', { filename: configPath })) {
       try {
-        const content = await readTextFile(configPath);
+        // 经后端读取，开启配置加密时自动解密
+        const content = await invoke<string>('mxu_read_protected_file', { path: configPath });
         const config = parseJsonc<MxuConfig>(content, configPath);
         log.info('配置加载成功');
         return config;
@@ -140,25 +136,22 @@ export async function saveConfig(
     }
   }
 
-  const configDir = getConfigDirSync(basePath);
   const configPath = getConfigPathSync(basePath, projectName);
 
   log.debug('保存配置, 路径:', configPath);
 
   try {
-    const { writeTextFile, mkdir, exists, readTextFile, rename, remove } =
-      await import('@tauri-apps/plugin-fs');
-
-    // 确保 config 目录存在
-    if (!(await exists(configDir))) {
-      log.debug('创建配置目录:', configDir);
-      await mkdir(configDir, { recursive: true });
-    }
+    const { invoke } = await import('@tauri-apps/api/core');
 
     // 保护：拒绝用空实例覆盖已有的非空配置，避免“配置被清空”
-    if (config.instances.length === 0 && (await exists(configPath))) {
+    if (
+      config.instances.length === 0 &&
+      (await invoke<boolean>('local_file_exists', { filename: configPath }))
+    ) {
       try {
-        const existingContent = await readTextFile(configPath);
+        const existingContent = await invoke<string>('mxu_read_protected_file', {
+          path: configPath,
+        });
         const existingConfig = parseJsonc<Partial<MxuConfig>>(existingContent, configPath);
         const existingInstances = Array.isArray(existingConfig.instances)
           ? existingConfig.instances
@@ -175,32 +168,16 @@ export async function saveConfig(
     }
 
     const content = JSON.stringify(config, null, 2);
-    // 原子写：先写到 .tmp，再 rename 覆盖正式文件。
+    // 经后端原子写（先写 .tmp 再 rename，自动创建 config 目录），开启配置加密时写入密文。
     // 这样即使进程在写入中途被杀（典型场景：自动更新后 Tauri relaunch
-    // 触发 beforeunload，writeTextFile 已经把目标文件截断为 0 字节但内容还没
-    // 落盘），目标文件也只会停留在上一份完整内容，不会出现空 / 损坏的
+    // 触发 beforeunload），目标文件也只会停留在上一份完整内容，不会出现空 / 损坏的
     // mxu-{projectName}.json。
-    const tempPath = configPath + '.tmp';
-    try {
-      await writeTextFile(tempPath, content);
-      await rename(tempPath, configPath);
-    } catch (err) {
-      // 写入或重命名失败时清理半成品 .tmp，避免遗留垃圾
-      try {
-        if (await exists(tempPath)) {
-          await remove(tempPath);
-        }
-      } catch (cleanupErr) {
-        log.debug('清理临时配置文件失败（忽略）:', cleanupErr);
-      }
-      throw err;
-    }
+    await invoke('mxu_write_protected_file', { path: configPath, content });
     log.info('配置保存成功');
 
     // 通知 Rust 后端更新内存缓存并广播 config-changed 给所有其他客户端
     try {
       markSelfSave();
-      const { invoke } = await import('@tauri-apps/api/core');
       await invoke('notify_config_changed', { config });
     } catch (err) {
       consumeSelfSave();
@@ -263,10 +240,9 @@ export async function backupConfigBeforeUpdate(
   const configPath = getConfigPathSync(basePath, projectName);
 
   try {
-    const { exists, readTextFile, writeTextFile, mkdir, readDir, remove } =
-      await import('@tauri-apps/plugin-fs');
+    const { invoke } = await import('@tauri-apps/api/core');
 
-    if (!(await exists(configPath))) {
+    if (!(await invoke<boolean>('local_file_exists', { filename: configPath }))) {
       log.info('配置文件不存在，跳过备份');
       return;
     }
@@ -274,32 +250,33 @@ export async function backupConfigBeforeUpdate(
     const cacheDir = await getCacheDir();
     const backupDir = joinPath(cacheDir, BACKUP_SUBDIR);
 
-    if (!(await exists(backupDir))) {
-      await mkdir(backupDir, { recursive: true });
-    }
-
     const configFileName = getConfigFileName(projectName);
     const baseName = configFileName.replace(/\.json$/, '');
     const timestamp = formatTimestamp(new Date());
     const backupFileName = `${baseName}-${timestamp}.json`;
     const backupPath = joinPath(backupDir, backupFileName);
 
-    const content = await readTextFile(configPath);
-    await writeTextFile(backupPath, content);
+    // 原样复制（开启配置加密时备份的也是密文），写入时自动创建备份目录
+    const content = await invoke<string>('read_local_file', { filename: configPath });
+    await invoke('write_local_file', { filename: backupPath, content });
     log.info(`配置文件已备份: ${backupPath}`);
 
     // 清理超过一周的旧备份
     const now = Date.now();
-    const entries = await readDir(backupDir);
+    const entries = await invoke<{ name: string; path: string; isFile: boolean }[]>(
+      'list_local_dir',
+      { dirname: backupDir },
+    );
     for (const entry of entries) {
-      if (!entry.name || entry.isDirectory) continue;
+      if (!entry.isFile) continue;
       const fileDate = parseTimestampFromFilename(entry.name);
       if (fileDate && now - fileDate.getTime() > BACKUP_MAX_AGE_MS) {
-        const oldPath = joinPath(backupDir, entry.name);
-        await remove(oldPath).catch((e: unknown) => {
-          log.warn(`删除过期备份失败: ${oldPath}`, e);
-        });
-        log.info(`已删除过期备份: ${entry.name}`);
+        try {
+          await invoke('remove_local_file', { path: entry.path });
+          log.info(`已删除过期备份: ${entry.name}`);
+        } catch (e) {
+          log.warn(`删除过期备份失败: ${entry.path}`, e);
+        }
       }
     }
   } catch (error) {
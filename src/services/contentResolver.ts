@@ -180,6 +180,39 @@ export async function localFileExists(filename: string): Promise<boolean> {
   }
 }
 
+/** 目录条目信息 */
+export interface DirEntryInfo {
+  /** 相对于所列目录的路径 */
+  name: string;
+  /** 绝对路径 */
+  path: string;
+  isDir: boolean;
+  isFile: boolean;
+  /** 文件大小（字节），目录为 0 */
+  size: number;
+  /** 最后修改时间（Unix 毫秒时间戳） */
+  mtime: number | null;
+}
+
+/**
+ * 列出本地目录（仅 Tauri 环境，路径受后端沙箱限制），目录不存在时返回空列表
+ * @param path 目录路径（相对 exe 目录或位于数据目录内的绝对路径）
+ * @param glob 文件名通配符，如 `*.png`
+ * @param recursive 是否递归子目录
+ */
+export async function listDir(
+  path: string,
+  glob?: string,
+  recursive = false,
+): Promise<DirEntryInfo[]> {
+  if (!isTauri()) return [];
+  return await invoke<DirEntryInfo[]>('list_local_dir', {
+    dirname: path,
+    glob: glob ?? null,
+    recursive,
+  });
+}
+
 export interface ResolveOptions {
   /** 翻译映射表 */
   translations?: Record<string, string>;
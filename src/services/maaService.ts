@@ -12,7 +12,25 @@ import type {
   AgentConfig,
   TaskConfig,
   InstanceRuntimeInfo,
+  InputScript,
+  QuickRecognitionResult,
+  ImageDiffResult,
+  ControllerInfo,
+  ResolutionChangedEvent,
+  InstanceSummary,
+  InstanceFilter,
+  SessionRestoreResult,
+  RunReport,
+  FailureCapture,
+  ScreenshotHistoryEntry,
+  DeviceHealth,
+  AppBackupInfo,
+  AppBackupTarget,
+  WindowDpiInfo,
+  MonitorInfo,
+  LaunchAttachResult,
 } from '@/types/maa';
+import type { CaptureResolution } from '@/types/config';
 import { loggers } from '@/utils/logger';
 import { isTauri } from '@/utils/paths';
 import { apiDelete, apiGet, apiPost, apiPut, getApiBase } from '@/utils/backendApi';
@@ -54,6 +72,8 @@ async function syncMaaVersionToStore(version: string): Promise<void> {
 
 /** MaaFramework 回调事件载荷 */
 export interface MaaCallbackEvent {
+  /** 产生该回调的实例 ID */
+  instance_id: string;
   /** 消息类型，如 "Resource.Loading.Succeeded", "Controller.Action.Succeeded", "Tasker.Task.Succeeded" */
   message: string;
   /** 详细数据 JSON 字符串 */
@@ -79,6 +99,327 @@ export interface SelfStopRequestedEvent {
   instanceId: string;
 }
 
+/** MXU_ASSERT 断言失败事件 */
+export interface AssertFailedEvent {
+  instanceId: string;
+  nodeName: string;
+  message: string;
+}
+
+/** 任务超时事件 */
+export interface TaskTimeoutEvent {
+  instanceId: string;
+  taskId: number;
+  entry: string;
+  timeoutSecs: number;
+  /** 是否已自动重新执行 */
+  restarted: boolean;
+}
+
+/** 设备健康告警事件（已停止该实例的任务） */
+export interface DeviceHealthAlertEvent {
+  instanceId: string;
+  reason: 'low_battery' | 'overheat';
+  health: DeviceHealth;
+}
+
+/** 当前生效的游戏维护时段 */
+export interface ActiveMaintenance {
+  name: string;
+  /** 结束时间（本地时间 YYYY-MM-DD HH:MM） */
+  endsAt: string;
+  remainingSecs: number;
+}
+
+/** 维护时段内启动任务被拒绝或延后 */
+export interface MaintenanceWindowEvent extends ActiveMaintenance {
+  instanceId: string;
+  policy: 'refuse' | 'delay';
+}
+
+/** 距服务器每日重置的时间 */
+export interface ResetInfo {
+  instanceId: string;
+  hour: number;
+  /** 服务器 UTC 偏移（如 +08:00） */
+  utcOffset: string;
+  /** 当前服务器日（YYYY-MM-DD） */
+  serverDay: string;
+  /** 下次重置时刻（RFC 3339） */
+  nextReset: string;
+  secondsUntilReset: number;
+}
+
+/** 系统时钟偏差检测结果 */
+export interface ClockDriftReport {
+  server: string;
+  /** 本机时钟相对标准时间的偏差（毫秒，正值表示本机偏快） */
+  offsetMs: number;
+  roundTripMs: number;
+  thresholdSecs: number;
+  drifted: boolean;
+}
+
+/** 程序文件完整性校验结果 */
+export interface IntegrityReport {
+  checkedAt: string;
+  /** 是否找到清单（开发构建没有清单） */
+  manifestFound: boolean;
+  version: string | null;
+  /** 清单签名状态 */
+  signature: 'valid' | 'invalid' | 'missing' | 'noKey';
+  checked: number;
+  missing: string[];
+  mismatched: string[];
+  ok: boolean;
+}
+
+/** 杀毒软件误报自诊断中缺失的关键文件 */
+export interface AntivirusMissingFile {
+  path: string;
+  /** maafw / agent / manifest（完整性清单中的二进制文件） */
+  kind: 'maafw' | 'agent' | 'manifest';
+}
+
+/** Windows Defender 状态 */
+export interface DefenderStatus {
+  /** 实时保护是否开启（未安装或被第三方杀毒软件接管时为 null） */
+  realtimeEnabled: boolean | null;
+  /** 排除项是否可读（非管理员运行时不可读） */
+  exclusionsReadable: boolean;
+  /** 安装目录是否已在排除项中 */
+  excluded: boolean | null;
+  /** 威胁记录中位于安装目录下的文件 */
+  detections: string[];
+}
+
+/** 杀毒软件误报自诊断报告 */
+export interface AntivirusReport {
+  missing: AntivirusMissingFile[];
+  /** 仅 Windows */
+  defender: DefenderStatus | null;
+  suspected: boolean;
+  pathsToWhitelist: string[];
+  suggestions: string[];
+}
+
+/** Windows 长路径检查结果 */
+export interface LongPathReport {
+  exeDir: string;
+  exeDirLen: number;
+  /** 安装目录下最长的文件路径 */
+  longestPath: string | null;
+  longestLen: number;
+  limit: number;
+  /** 系统是否开启长路径支持（非 Windows 为 null） */
+  longPathsEnabled: boolean | null;
+  status: 'ok' | 'warn' | 'fail';
+  message: string;
+}
+
+/** 非 ASCII 路径兼容状态 */
+export interface PathCompatStatus {
+  exeDir: string;
+  /** 安装目录是否含非 ASCII 字符 */
+  nonAscii: boolean;
+  enabled: boolean;
+  /** 已建立的映射（原路径 -> 传给 MaaFramework 的 ASCII 路径） */
+  mappings: { original: string; mapped: string }[];
+}
+
+/** 运行统计导出范围（本地日期 YYYY-MM-DD，包含边界） */
+export interface StatsRange {
+  from?: string;
+  to?: string;
+  instanceId?: string;
+}
+
+/** 运行统计导出结果 */
+export interface ExportStatsResult {
+  path: string;
+  /** CSV 格式下的按实例 + 任务汇总文件 */
+  summaryPath: string | null;
+  /** CSV 格式下的按实例汇总文件 */
+  instancesPath: string | null;
+  runs: number;
+  tasks: number;
+}
+
+/** MXU_REPORT_RESULT 上报的结构化结果 */
+export interface ResultRecord {
+  time: string;
+  instanceId: string;
+  runId: string | null;
+  /** 上报时正在执行的任务入口 */
+  task: string | null;
+  node: string;
+  key: string;
+  value: unknown;
+}
+
+/** 配置变更审计记录中的单个字段变更 */
+export interface AuditChange {
+  /** JSON Pointer（带 id 的数组元素按 id 定位） */
+  pointer: string;
+  category: 'profile' | 'schedule' | 'secret' | 'settings' | 'other';
+  /** 敏感字段记录为 "***" */
+  before: unknown;
+  after: unknown;
+}
+
+/** 一次配置保存的审计记录 */
+export interface AuditEntry {
+  time: string;
+  /** 操作系统用户名 */
+  user: string;
+  source: 'desktop' | 'web';
+  changes: AuditChange[];
+  truncated: boolean;
+}
+
+/** Win32 窗口缩放风险提示事件（控制器连接后触发） */
+export interface Win32DpiWarningEvent extends WindowDpiInfo {
+  instanceId: string;
+}
+
+/** Win32 目标窗口权限警告（目标进程以管理员权限运行而 MXU 未提升） */
+export interface Win32ElevationWarningEvent {
+  instanceId: string;
+  handle: number;
+  pid: number;
+  processName: string | null;
+  /** 当前处理策略：warn 继续连接 / block 拒绝连接 / restart 自动以管理员身份重启 */
+  policy: 'warn' | 'block' | 'restart';
+}
+
+/** mxu:// 深度链接动作 */
+export type DeepLinkAction =
+  | { action: 'run'; profile: string }
+  | { action: 'importResource'; url: string };
+
+/** 任务跳过事件（前置任务失败或执行条件不满足） */
+export interface TaskSkippedEvent {
+  instanceId: string;
+  reason: 'dependency-failed' | 'condition-false';
+  /** 未提交的任务为 null */
+  taskId: number | null;
+  entry: string;
+  /** 任务列表下标 */
+  index: number | null;
+  selectedTaskId: string | null;
+  dependencyTaskId: number | null;
+  dependencyIndex: number | null;
+  /** 不满足的执行条件 */
+  condition: string | null;
+}
+
+/** MXU_WAIT_FOR_USER 人工介入请求事件 */
+export interface InterventionRequiredEvent {
+  instanceId: string;
+  nodeName: string;
+  token: string;
+  message: string;
+  /** 当前截图（data URL） */
+  screenshot: string | null;
+  /** 超时时间（秒），0 表示不超时 */
+  timeoutSecs: number;
+}
+
+/** TCP 兼容模式下 Agent 监听端口已确定 */
+export interface AgentTcpBoundEvent {
+  instanceId: string;
+  agentIndex: number;
+  interface: string;
+  port: number;
+}
+
+/** Agent Server 运行状态 */
+export interface AgentServerStatus {
+  running: boolean;
+  identifier: string | null;
+  pid: number | null;
+}
+
+/** Python 环境设置进度事件 */
+export interface PythonEnvProgressEvent {
+  stage: 'venv' | 'pip' | 'done';
+  line: string;
+}
+
+/** Python 环境设置结果 */
+export interface PythonEnvResult {
+  /** 虚拟环境中的解释器路径 */
+  pythonPath: string;
+  /** 是否执行了依赖安装 */
+  installed: boolean;
+}
+
+/** 配置加密状态 */
+export interface ConfigEncryptionStatus {
+  enabled: boolean;
+  mode: 'machine' | 'passphrase' | null;
+  /** 密码模式下尚未解锁 */
+  locked: boolean;
+}
+
+/** 命令追踪条目 */
+export interface TraceEntry {
+  timestamp: string;
+  level: string;
+  message: string;
+}
+
+/** 命令追踪记录（按关联 ID 查询） */
+export interface CommandTrace {
+  correlationId: string;
+  command: string;
+  instanceId: string | null;
+  startedAt: string;
+  finishedAt: string | null;
+  error: string | null;
+  entries: TraceEntry[];
+}
+
+/** focus 运行日志条目 */
+export interface FocusLogEntry {
+  instanceId: string;
+  timestamp: string;
+  /** 节点名称 */
+  node: string;
+  /** 触发该条日志的回调消息，如 Node.Action.Succeeded */
+  message: string;
+  level: 'start' | 'succeeded' | 'failed' | 'tip';
+  /** 已替换占位符的文本（国际化键与 {image} 仍需前端处理） */
+  text: string;
+  display: string[];
+}
+
+/** 补发缓冲区中的事件（type / payload 与 WebSocket 推送格式一致） */
+export type ReplayEvent = {
+  seq: number;
+  instanceId?: string;
+  timestamp: string;
+} & (
+  | wsService.WsMessage
+  | {
+      type: 'download-progress';
+      payload: {
+        session_id: number;
+        downloaded_size: number;
+        total_size: number;
+        speed: number;
+        progress: number;
+      };
+    }
+);
+
+/** 补取事件结果 */
+export interface MissedEvents {
+  /** 最新事件序号，下次补取时作为 sinceSeq 传入 */
+  lastSeq: number;
+  events: ReplayEvent[];
+}
+
 /** MaaFramework 服务 */
 export const maaService = {
   /**
@@ -306,6 +647,249 @@ export const maaService = {
     return status;
   },
 
+  /**
+   * 获取控制器信息（设备 UUID、截图分辨率与方向）
+   * @param instanceId 实例 ID
+   */
+  async getControllerInfo(instanceId: string): Promise<ControllerInfo> {
+    if (!isTauri()) {
+      return await apiGet<ControllerInfo>(`/maa/instances/${instanceId}/controller-info`);
+    }
+    return await invoke<ControllerInfo>('maa_get_controller_info', { instanceId });
+  },
+
+  /**
+   * 监听控制器分辨率变化（运行中横竖屏切换、模拟器改分辨率等）
+   * @param callback 回调函数
+   * @returns 取消监听的函数
+   */
+  async onResolutionChanged(
+    callback: (event: ResolutionChangedEvent) => void,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return wsService.onResolutionChanged(callback);
+    }
+    return await listen<ResolutionChangedEvent>('maa-resolution-changed', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 按上次保存的会话快照恢复实例（重新连接控制器、加载资源）
+   */
+  async restoreSession(): Promise<SessionRestoreResult> {
+    log.info('恢复会话快照');
+    return await invoke<SessionRestoreResult>('maa_restore_session');
+  },
+
+  /**
+   * 重连控制器（幂等：已连接时直接返回 true）
+   * @param instanceId 实例 ID
+   * @returns 是否已连接
+   */
+  async reconnectController(instanceId: string): Promise<boolean> {
+    log.info('重连控制器, 实例:', instanceId);
+    return await invoke<boolean>('maa_reconnect_controller', { instanceId });
+  },
+
+  /**
+   * 以新的截图 / 输入方式重新连接 ADB 控制器（不修改实例配置）
+   * @param instanceId 实例 ID
+   * @param screencap 截图方式位掩码（字符串，避免 u64 精度丢失）
+   * @param input 输入方式位掩码（字符串）
+   * @returns 连接请求 ID，通过 maa-callback 获取连接结果
+   */
+  async switchAdbMethods(instanceId: string, screencap: string, input: string): Promise<number> {
+    log.info('切换 ADB 控制方式, 实例:', instanceId, 'screencap:', screencap, 'input:', input);
+    return await invoke<number>('maa_switch_adb_methods', { instanceId, screencap, input });
+  },
+
+  /**
+   * 获取 ADB 设备的电量与温度
+   * @param instanceId 实例 ID
+   */
+  async getDeviceHealth(instanceId: string): Promise<DeviceHealth> {
+    return await invoke<DeviceHealth>('maa_get_device_health', { instanceId });
+  },
+
+  /**
+   * 查询当前生效的游戏维护时段（不在维护时段内时返回 null）
+   */
+  async getMaintenanceStatus(): Promise<ActiveMaintenance | null> {
+    if (!isTauri()) return null;
+    return await invoke<ActiveMaintenance | null>('mxu_get_maintenance_status');
+  },
+
+  /**
+   * 查询实例距下次服务器每日重置的时间
+   * @param profile 实例 ID 或名称
+   */
+  async timeUntilReset(profile: string): Promise<ResetInfo> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<ResetInfo>('mxu_time_until_reset', { profile });
+  },
+
+  /**
+   * 检查本机时钟与 NTP 标准时间的偏差
+   * @param server NTP 服务器（缺省使用配置或内置列表）
+   */
+  async checkClockDrift(server?: string): Promise<ClockDriftReport> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<ClockDriftReport>('mxu_check_clock_drift', { server });
+  },
+
+  /**
+   * 取走启动时检查到的时钟偏差警告（前端就绪后调用一次）
+   */
+  async takeClockDriftWarning(): Promise<ClockDriftReport | null> {
+    if (!isTauri()) return null;
+    return await invoke<ClockDriftReport | null>('mxu_take_clock_drift_warning');
+  },
+
+  /**
+   * 诊断关键文件是否被杀毒软件误报隔离，并给出需要加入白名单的路径
+   * @param agents interface.json 中的 agent 配置（缺省只检查 MaaFramework 文件）
+   * @param cwd Agent 工作目录
+   */
+  async diagnoseAntivirus(agents?: AgentConfig[], cwd?: string): Promise<AntivirusReport> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<AntivirusReport>('mxu_diagnose_antivirus', { agents, cwd });
+  },
+
+  /**
+   * 检查安装目录路径长度与系统长路径支持状态
+   */
+  async checkLongPaths(): Promise<LongPathReport> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<LongPathReport>('mxu_check_long_paths');
+  },
+
+  /**
+   * 开启 Windows 系统长路径支持（会弹出管理员授权窗口）
+   * @returns 开启后的状态
+   */
+  async enableLongPaths(): Promise<boolean> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<boolean>('mxu_enable_long_paths');
+  },
+
+  /**
+   * 获取非 ASCII 路径兼容状态与已建立的映射
+   */
+  async getPathCompat(): Promise<PathCompatStatus> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<PathCompatStatus>('mxu_get_path_compat');
+  },
+
+  /**
+   * 获取最近一次程序文件完整性校验结果（尚未校验时为 null）
+   */
+  async getIntegrityReport(): Promise<IntegrityReport | null> {
+    if (!isTauri()) {
+      return null;
+    }
+    return await invoke<IntegrityReport | null>('mxu_get_integrity_report');
+  },
+
+  /**
+   * 立即重新校验程序文件完整性
+   */
+  async verifyIntegrity(): Promise<IntegrityReport> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<IntegrityReport>('mxu_verify_integrity');
+  },
+
+  /**
+   * 获取 Win32 窗口的 DPI、缩放比例与 DPI 感知级别
+   * @param handle 窗口句柄
+   */
+  async getWindowDpi(handle: number): Promise<WindowDpiInfo> {
+    return await invoke<WindowDpiInfo>('maa_get_window_dpi', { handle });
+  },
+
+  /**
+   * 启动程序，等待标题匹配的窗口出现后自动连接控制器
+   * @param instanceId 实例 ID
+   * @param exePath 程序路径
+   * @param windowRegex 窗口标题正则
+   * @param config Win32 / Gamepad 控制器配置（handle 会被替换为找到的窗口句柄）
+   * @param timeout 等待窗口的超时秒数（默认 60）
+   */
+  async launchAndAttach(
+    instanceId: string,
+    exePath: string,
+    windowRegex: string,
+    config: ControllerConfig,
+    timeout?: number,
+  ): Promise<LaunchAttachResult> {
+    log.info('启动并连接窗口, 实例:', instanceId, 'exe:', exePath, 'window:', windowRegex);
+    return await invoke<LaunchAttachResult>('maa_launch_and_attach', {
+      instanceId,
+      exePath,
+      windowRegex,
+      timeout: timeout ?? null,
+      config,
+    });
+  },
+
+  /**
+   * 枚举显示器（主显示器在前），用于选择 DXGI 桌面复制截取的显示器
+   */
+  async listMonitors(): Promise<MonitorInfo[]> {
+    return await invoke<MonitorInfo[]>('maa_list_monitors');
+  },
+
+  /**
+   * 通过 adb 备份目标应用数据
+   * @param instanceId 实例 ID
+   * @param pkg 应用包名（缺省时使用实例的 appBackup 配置）
+   * @param targets 备份范围（缺省时使用实例配置，均未设置时为 data）
+   */
+  async backupAppData(
+    instanceId: string,
+    pkg?: string,
+    targets?: AppBackupTarget[],
+  ): Promise<AppBackupInfo> {
+    log.info('备份应用数据, 实例:', instanceId, 'package:', pkg, 'targets:', targets);
+    return await invoke<AppBackupInfo>('maa_backup_app_data', {
+      instanceId,
+      package: pkg ?? null,
+      targets: targets ?? null,
+    });
+  },
+
+  /**
+   * 列出实例的应用数据备份（按时间倒序）
+   * @param instanceId 实例 ID
+   */
+  async listAppBackups(instanceId: string): Promise<AppBackupInfo[]> {
+    return await invoke<AppBackupInfo[]>('maa_list_app_backups', { instanceId });
+  },
+
+  /**
+   * 将应用数据备份恢复到设备（恢复前会强制停止目标应用）
+   * @param instanceId 实例 ID
+   * @param backupId 备份 ID
+   */
+  async restoreAppData(instanceId: string, backupId: string): Promise<AppBackupInfo> {
+    log.info('恢复应用数据, 实例:', instanceId, 'backup:', backupId);
+    return await invoke<AppBackupInfo>('maa_restore_app_data', { instanceId, backupId });
+  },
+
   /**
    * 加载资源（异步，通过回调通知完成状态）
    * @param instanceId 实例 ID
@@ -411,6 +995,31 @@ export const maaService = {
     return taskId;
   },
 
+  /**
+   * 插队运行任务：在当前任务之后立即执行，排在其余等待中的任务之前
+   * @param instanceId 实例 ID
+   * @param entry 任务入口
+   * @param pipelineOverride Pipeline 覆盖 JSON
+   * @param selectedTaskId 对应的前端选中任务 ID
+   * @returns 任务 ID
+   */
+  async runTaskNext(
+    instanceId: string,
+    entry: string,
+    pipelineOverride: string = '{}',
+    selectedTaskId?: string,
+  ): Promise<number> {
+    log.info('插队运行任务, 实例:', instanceId, ', 入口:', entry);
+    const taskId = await invoke<number>('maa_run_task_next', {
+      instanceId,
+      entry,
+      pipelineOverride,
+      selectedTaskId: selectedTaskId ?? null,
+    });
+    log.info('任务已插队提交, taskId:', taskId);
+    return taskId;
+  },
+
   /**
    * 获取任务状态
    * @param instanceId 实例 ID
@@ -439,6 +1048,16 @@ export const maaService = {
     log.info('停止任务请求已发送');
   },
 
+  /**
+   * 当前任务完成后停止：清空等待中的任务，不打断正在执行的任务
+   * @param instanceId 实例 ID
+   * @returns 撤下的任务数
+   */
+  async stopAfterCurrent(instanceId: string): Promise<number> {
+    log.info('当前任务完成后停止, 实例:', instanceId);
+    return await invoke<number>('maa_stop_after_current', { instanceId });
+  },
+
   /**
    * 覆盖已提交任务的 Pipeline 配置（用于运行中修改尚未执行的任务选项）
    * @param instanceId 实例 ID
@@ -509,26 +1128,198 @@ export const maaService = {
   },
 
   /**
-   * 发起截图请求（异步，通过回调通知完成状态）
+   * 发起滑动请求
    * @param instanceId 实例 ID
-   * @returns 截图请求 ID（Tauri）或 0（浏览器，实际截图异步进行并写入缓存）
+   * @param duration 滑动时长（毫秒）
+   * @returns 滑动请求 ID
    */
-  async postScreencap(instanceId: string): Promise<number> {
+  async postSwipe(
+    instanceId: string,
+    x1: number,
+    y1: number,
+    x2: number,
+    y2: number,
+    duration: number,
+  ): Promise<number> {
     if (!isTauri()) {
-      // 浏览器模式：截图由后端 ScreenshotService 统一驱动，此处无需额外触发
-      return 0;
+      throw new Error('此功能仅在 Tauri 环境中可用');
     }
-    const screencapId = await invoke<number>('maa_post_screencap', { instanceId });
-    return screencapId;
+    return await invoke<number>('maa_post_swipe', { instanceId, x1, y1, x2, y2, duration });
   },
 
   /**
-   * 获取缓存的截图
+   * 开始录制手动输入（点击 / 滑动）
    * @param instanceId 实例 ID
-   * @returns base64 编码的图像 data URL
    */
-  async getCachedImage(instanceId: string): Promise<string> {
-    if (!isTauri()) {
+  async recordInputsStart(instanceId: string): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('maa_record_inputs_start', { instanceId });
+  },
+
+  /**
+   * 停止录制并返回输入脚本
+   * @param instanceId 实例 ID
+   */
+  async recordInputsStop(instanceId: string): Promise<InputScript> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<InputScript>('maa_record_inputs_stop', { instanceId });
+  },
+
+  /**
+   * 回放输入脚本
+   * @param instanceId 实例 ID
+   * @param script 输入脚本
+   * @param speed 回放倍速（默认 1.0）
+   * @returns 已执行的步数
+   */
+  async replayInputs(instanceId: string, script: InputScript, speed?: number): Promise<number> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<number>('maa_replay_inputs', { instanceId, script, speed: speed ?? null });
+  },
+
+  /**
+   * 停止正在进行的回放
+   * @param instanceId 实例 ID
+   */
+  async replayInputsStop(instanceId: string): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('maa_replay_inputs_stop', { instanceId });
+  },
+
+  /**
+   * 从缓存截图裁剪 ROI，保存为当前资源 image 目录下的模板
+   * @param instanceId 实例 ID
+   * @param roi [x, y, width, height]
+   * @param name 模板文件名（相对 image 目录）
+   * @param overwrite 是否覆盖已存在的文件
+   */
+  async captureTemplate(
+    instanceId: string,
+    roi: [number, number, number, number],
+    name: string,
+    overwrite = false,
+  ): Promise<{ relativePath: string; fullPath: string; width: number; height: number }> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke('maa_capture_template', { instanceId, roi, name, overwrite });
+  },
+
+  /**
+   * 读取缓存截图中指定坐标的颜色
+   * @param instanceId 实例 ID
+   * @param points 坐标列表 [x, y]
+   * @param imagePath 本地图片路径（可选，代替实时截图）
+   * @returns 每个坐标的 RGB 与 HSV（H 0-180，S/V 0-255）
+   */
+  async inspectPixels(
+    instanceId: string,
+    points: [number, number][],
+    imagePath?: string,
+  ): Promise<{ x: number; y: number; rgb: number[]; hsv: number[] }[]> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke('maa_inspect_pixels', {
+      instanceId,
+      points,
+      imagePath: imagePath ?? null,
+    });
+  },
+
+  /**
+   * 对当前截图执行一次 OCR 识别（用于调整 OCR 区域）
+   * @param instanceId 实例 ID
+   * @param roi 识别区域 [x, y, width, height]，为空时识别全图
+   * @param expected 期望文本
+   * @param imagePath 本地图片路径（可选，代替实时截图）
+   */
+  async testOcr(
+    instanceId: string,
+    roi?: [number, number, number, number],
+    expected?: string[],
+    imagePath?: string,
+  ): Promise<QuickRecognitionResult> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<QuickRecognitionResult>('maa_test_ocr', {
+      instanceId,
+      roi: roi ?? null,
+      expected: expected ?? null,
+      imagePath: imagePath ?? null,
+    });
+  },
+
+  /**
+   * 对当前截图执行一次模板匹配（用于调整模板与阈值）
+   * @param instanceId 实例 ID
+   * @param templatePath 模板路径（相对资源 image 目录）
+   * @param threshold 匹配阈值
+   * @param roi 识别区域 [x, y, width, height]，为空时识别全图
+   * @param imagePath 本地图片路径（可选，代替实时截图）
+   */
+  async testTemplate(
+    instanceId: string,
+    templatePath: string,
+    threshold?: number,
+    roi?: [number, number, number, number],
+    imagePath?: string,
+  ): Promise<QuickRecognitionResult> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<QuickRecognitionResult>('maa_test_template', {
+      instanceId,
+      templatePath,
+      threshold: threshold ?? null,
+      roi: roi ?? null,
+      imagePath: imagePath ?? null,
+    });
+  },
+
+  /**
+   * 对比两张本地图片，返回差异分数与热力图（排查识别结果变化）
+   * @param imageA 基准图片路径
+   * @param imageB 对比图片路径（分辨率不同时缩放到 imageA 的尺寸）
+   * @param threshold 像素差异阈值 0-255（默认 16）
+   */
+  async diffImages(imageA: string, imageB: string, threshold?: number): Promise<ImageDiffResult> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<ImageDiffResult>('mxu_diff_images', {
+      imageA,
+      imageB,
+      threshold: threshold ?? null,
+    });
+  },
+
+  /**
+   * 发起截图请求（异步，通过回调通知完成状态）
+   * @param instanceId 实例 ID
+   * @returns 截图请求 ID（Tauri）或 0（浏览器，实际截图异步进行并写入缓存）
+   */
+  async postScreencap(instanceId: string): Promise<number> {
+    if (!isTauri()) {
+      // 浏览器模式：截图由后端 ScreenshotService 统一驱动，此处无需额外触发
+      return 0;
+    }
+    const screencapId = await invoke<number>('maa_post_screencap', { instanceId });
+    return screencapId;
+  },
+
+  /**
+   * 获取缓存的截图
+   * @param instanceId 实例 ID
+   * @returns base64 编码的图像 data URL
+   */
+  async getCachedImage(instanceId: string): Promise<string> {
+    if (!isTauri()) {
       // 浏览器模式：后端截图循环已在运行，直接读取最新缓存
       return fetchScreenshotDataUrl(instanceId).catch(() => '');
     }
@@ -593,6 +1384,7 @@ export const maaService = {
     cwd?: string,
     tcpCompatMode?: boolean,
     piEnvs?: Record<string, string>,
+    dryRun?: boolean,
   ): Promise<number[]> {
     log.info('启动任务, 实例:', instanceId, ', 任务数:', tasks.length, ', cwd:', cwd || '.');
     tasks.forEach((task, i) => {
@@ -617,6 +1409,7 @@ export const maaService = {
           cwd: cwd || null,
           tcp_compat_mode: tcpCompatMode || false,
           pi_envs: agentConfigs && agentConfigs.length > 0 && piEnvs ? piEnvs : null,
+          dry_run: dryRun || false,
         },
       );
       log.info('任务已提交 (HTTP), taskIds:', result.taskIds);
@@ -630,6 +1423,7 @@ export const maaService = {
       cwd: cwd || '.',
       tcpCompatMode: tcpCompatMode || false,
       piEnvs: hasAgent && piEnvs ? piEnvs : null,
+      dryRun: dryRun || false,
     });
     log.info('任务已提交, taskIds:', taskIds);
     return taskIds;
@@ -650,9 +1444,124 @@ export const maaService = {
     log.info('停止 Agent 成功');
   },
 
+  /**
+   * 监听 TCP 兼容模式下 Agent 监听端口
+   */
+  async onAgentTcpBound(
+    callback: (payload: AgentTcpBoundEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<AgentTcpBoundEvent>('agent-tcp-bound', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 停止单个 Agent（其后的 Agent 下标依次前移）
+   * @param instanceId 实例 ID
+   * @param agentIndex Agent 下标
+   */
+  async stopSingleAgent(instanceId: string, agentIndex: number): Promise<void> {
+    log.info('停止 Agent, 实例:', instanceId, '下标:', agentIndex);
+    if (!isTauri()) {
+      await apiPost(`/maa/instances/${instanceId}/agent/${agentIndex}/stop`, {});
+      return;
+    }
+    await invoke('maa_stop_single_agent', { instanceId, agentIndex });
+  },
+
+  /**
+   * 重启单个 Agent
+   * @param instanceId 实例 ID
+   * @param agentIndex Agent 下标
+   */
+  async restartAgent(instanceId: string, agentIndex: number): Promise<void> {
+    log.info('重启 Agent, 实例:', instanceId, '下标:', agentIndex);
+    if (!isTauri()) {
+      await apiPost(`/maa/instances/${instanceId}/agent/${agentIndex}/restart`, {});
+      return;
+    }
+    await invoke('maa_restart_agent', { instanceId, agentIndex });
+  },
+
+  /**
+   * 向 Agent 子进程标准输入发送一行（用于需要交互输入的 Agent）
+   * @param instanceId 实例 ID
+   * @param agentIndex Agent 下标
+   * @param line 输入内容
+   */
+  async sendAgentInput(instanceId: string, agentIndex: number, line: string): Promise<void> {
+    if (!isTauri()) {
+      await apiPost(`/maa/instances/${instanceId}/agent/${agentIndex}/input`, { line });
+      return;
+    }
+    await invoke('maa_agent_send_input', { instanceId, agentIndex, line });
+  },
+
+  /**
+   * 启动 Agent Server（MXU 作为 Agent 对外提供内置动作）
+   * @param identifier 宿主连接时使用的标识符
+   */
+  async startAgentServer(identifier: string): Promise<AgentServerStatus> {
+    log.info('启动 Agent Server:', identifier);
+    return await invoke<AgentServerStatus>('mxu_agent_server_start', { identifier });
+  },
+
+  /**
+   * 停止 Agent Server
+   */
+  async stopAgentServer(): Promise<void> {
+    await invoke('mxu_agent_server_stop');
+  },
+
+  /**
+   * 查询 Agent Server 运行状态
+   */
+  async getAgentServerStatus(): Promise<AgentServerStatus> {
+    return await invoke<AgentServerStatus>('mxu_agent_server_status');
+  },
+
+  /**
+   * 在资源目录下创建/更新 Python 虚拟环境并安装依赖
+   * 环境就绪后，python 解释器类型的 child_exec 会自动使用虚拟环境中的解释器
+   * @param cwd 资源目录
+   * @param requirementsPath requirements 文件路径（相对资源目录）
+   * @param pythonPath 创建虚拟环境使用的解释器（可选）
+   */
+  async setupPythonEnv(
+    cwd: string,
+    requirementsPath: string,
+    pythonPath?: string,
+  ): Promise<PythonEnvResult> {
+    log.info('设置 Python 环境:', cwd, requirementsPath);
+    return await invoke<PythonEnvResult>('agent_setup_python_env', {
+      cwd,
+      requirementsPath,
+      pythonPath: pythonPath || null,
+    });
+  },
+
+  /**
+   * 监听 Python 环境设置进度（venv / pip 输出）
+   */
+  async onPythonEnvProgress(
+    callback: (payload: PythonEnvProgressEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<PythonEnvProgressEvent>('python-env-progress', (event) => {
+      void callback(event.payload);
+    });
+  },
+
   /**
    * 监听 MaaFramework 回调事件
-   * @param callback 回调函数，接收消息类型和详情
+   * @param callback 回调函数，接收消息类型、详情与产生该回调的实例 ID
    * @returns 取消监听的函数
    *
    * 常见消息类型：
@@ -663,34 +1572,51 @@ export const maaService = {
    * - Node.Action.Starting/Succeeded/Failed - 节点动作状态
    */
   async onCallback(
-    callback: (message: string, details: MaaCallbackDetails) => void,
+    callback: (message: string, details: MaaCallbackDetails, instanceId: string) => void,
   ): Promise<UnlistenFn> {
     if (!isTauri()) {
       // 浏览器环境：通过 WebSocket 接收 maa-callback 事件
-      return wsService.onMaaCallback((message, details) => {
+      return wsService.onMaaCallback((message, details, instanceId) => {
         try {
           const parsedDetails = JSON.parse(details) as MaaCallbackDetails;
-          callback(message, parsedDetails);
+          callback(message, parsedDetails, instanceId);
         } catch {
           log.warn('Failed to parse WS callback details:', details);
-          callback(message, {});
+          callback(message, {}, instanceId);
         }
       });
     }
 
     return await listen<MaaCallbackEvent>('maa-callback', (event) => {
-      const { message, details } = event.payload;
+      const { instance_id, message, details } = event.payload;
 
       try {
         const parsedDetails = JSON.parse(details) as MaaCallbackDetails;
-        callback(message, parsedDetails);
+        callback(message, parsedDetails, instance_id);
       } catch {
         log.warn('Failed to parse callback details:', details);
-        callback(message, {});
+        callback(message, {}, instance_id);
       }
     });
   },
 
+  /**
+   * 监听类型化的 MaaFramework 回调事件（maa-event）
+   *
+   * 后端已将回调消息解析为带 kind 的结构（如 task_started、node_recognized、connect_failed），
+   * 无需再解析消息名与 details 字符串。
+   * @param callback 回调函数
+   * @returns 取消监听的函数
+   */
+  async onMaaEvent(callback: (event: wsService.MaaEventPayload) => void): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return wsService.onMaaEvent(callback);
+    }
+    return await listen<wsService.MaaEventPayload>('maa-event', (event) => {
+      callback(event.payload);
+    });
+  },
+
   async onSelfStopRequested(
     callback: (payload: SelfStopRequestedEvent) => void | Promise<void>,
   ): Promise<UnlistenFn> {
@@ -703,6 +1629,197 @@ export const maaService = {
     });
   },
 
+  /**
+   * 监听 MXU_ASSERT 断言失败事件
+   */
+  async onAssertFailed(
+    callback: (payload: AssertFailedEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<AssertFailedEvent>('mxu-assert-failed', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听任务超时事件（后端看门狗停止超时任务后触发）
+   */
+  async onTaskTimeout(
+    callback: (payload: TaskTimeoutEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<TaskTimeoutEvent>('task-timeout', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听设备健康告警（电量过低或温度过高时后端停止任务后触发）
+   */
+  async onDeviceHealthAlert(
+    callback: (payload: DeviceHealthAlertEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<DeviceHealthAlertEvent>('device-health-alert', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听维护时段事件（维护时段内启动任务被拒绝或延后时触发）
+   */
+  async onMaintenanceWindow(
+    callback: (payload: MaintenanceWindowEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<MaintenanceWindowEvent>('maintenance-window', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听系统时钟偏差警告（启动时检查到偏差超过阈值后触发）
+   */
+  async onClockDriftWarning(
+    callback: (payload: ClockDriftReport) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<ClockDriftReport>('clock-drift-warning', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听程序文件完整性警告（文件缺失、被修改或清单签名无效时触发）
+   */
+  async onIntegrityWarning(
+    callback: (payload: IntegrityReport) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<IntegrityReport>('integrity-warning', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听 Win32 窗口缩放风险提示（系统缩放不为 100% 且窗口未感知 DPI 时点击可能偏移）
+   */
+  async onWin32DpiWarning(
+    callback: (payload: Win32DpiWarningEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<Win32DpiWarningEvent>('win32-dpi-warning', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听 Win32 目标窗口权限警告（输入会被系统拦截，需以管理员身份运行 MXU）
+   */
+  async onWin32ElevationWarning(
+    callback: (payload: Win32ElevationWarningEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<Win32ElevationWarningEvent>('win32-elevation-warning', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听运行中收到的 mxu:// 深度链接
+   */
+  async onDeepLink(
+    callback: (payload: DeepLinkAction) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<DeepLinkAction>('deep-link', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听任务跳过事件（前置任务失败或执行条件不满足）
+   */
+  async onTaskSkipped(
+    callback: (payload: TaskSkippedEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<TaskSkippedEvent>('task-skipped', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 监听 MXU_WAIT_FOR_USER 人工介入请求事件
+   */
+  async onInterventionRequired(
+    callback: (payload: InterventionRequiredEvent) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<InterventionRequiredEvent>('maa-intervention-required', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 确认人工介入请求，使暂停的 pipeline 继续执行
+   * @param token 介入请求 token
+   */
+  async confirmIntervention(token: string): Promise<void> {
+    await invoke('mxu_confirm_intervention', { token });
+  },
+
+  /**
+   * 获取实例的持久化变量
+   * @param instanceId 实例 ID
+   */
+  async getVariables(instanceId: string): Promise<Record<string, unknown>> {
+    return await invoke<Record<string, unknown>>('mxu_get_variables', { instanceId });
+  },
+
+  /**
+   * 设置实例变量（value 为 null 时删除）
+   * @param instanceId 实例 ID
+   * @param name 变量名
+   * @param value 变量值
+   */
+  async setVariable(instanceId: string, name: string, value: unknown): Promise<void> {
+    await invoke('mxu_set_variable', { instanceId, name, value: value ?? null });
+  },
+
   /**
    * 等待单个操作完成的一次性回调（适用于截图等需要立即获取结果的场景）
    * 注意：此函数会阻塞调用者直到回调到达，适合在非 UI 线程或循环中使用
@@ -942,15 +2059,367 @@ export const maaService = {
   },
 
   /**
-   * 以管理员权限重启应用
+   * 以管理员权限重启应用（沿用当前命令行参数与工作目录）
+   * @param restoreSession 新进程是否恢复当前会话（默认 true）
    * @returns 如果成功启动新进程会退出当前进程，否则返回错误信息
    */
-  async restartAsAdmin(): Promise<void> {
+  async restartAsAdmin(restoreSession = true): Promise<void> {
     if (!isTauri()) {
       await apiPost('/system/restart-as-admin');
       return;
     }
-    await invoke('restart_as_admin');
+    await invoke('restart_as_admin', { restoreSession });
+  },
+
+  /**
+   * 本次启动是否带有 --restore-session 参数（以管理员身份重启后）
+   */
+  async hasRestoreSessionFlag(): Promise<boolean> {
+    if (!isTauri()) return false;
+    return await invoke<boolean>('has_restore_session_flag');
+  },
+
+  /**
+   * 取走启动参数中携带的 mxu:// 深度链接（前端就绪后调用一次）
+   */
+  async takeDeepLinks(): Promise<DeepLinkAction[]> {
+    if (!isTauri()) return [];
+    return await invoke<DeepLinkAction[]>('mxu_take_deep_links');
+  },
+
+  /**
+   * 解析 mxu:// 深度链接（不执行）
+   * @param url 链接，如 mxu://run?profile=daily
+   */
+  async parseDeepLink(url: string): Promise<DeepLinkAction> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<DeepLinkAction>('mxu_parse_deep_link', { url });
+  },
+
+  /**
+   * 预览窗口截图（连接前使用，临时创建控制器截取一帧）
+   * @param handle 窗口句柄
+   * @param screencapMethod Win32 截图方式
+   * @returns base64 编码的 PNG 图像
+   */
+  async previewWindow(handle: number, screencapMethod: number): Promise<string> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<string>('maa_preview_window', { handle, screencapMethod });
+  },
+
+  /**
+   * 发送手柄测试输入序列，确认游戏能识别虚拟手柄
+   * @param instanceId 实例 ID
+   * @param sequence 输入序列（如 ['A', 'B', 'LS_UP', 'RT']），为空时使用默认序列
+   * @returns 已发送的输入数
+   */
+  async gamepadTestInput(instanceId: string, sequence?: string[]): Promise<number> {
+    if (!isTauri()) {
+      throw new Error('此功能仅在 Tauri 环境中可用');
+    }
+    return await invoke<number>('maa_gamepad_test_input', {
+      instanceId,
+      sequence: sequence ?? null,
+    });
+  },
+
+  /**
+   * 将窗口置于前台（最小化时先还原）
+   * @param handle 窗口句柄
+   */
+  async focusWindow(handle: number): Promise<void> {
+    if (!isTauri()) return;
+    await invoke('maa_focus_window', { handle });
+  },
+
+  /**
+   * 设置实例运行速度倍率（按倍率缩放各节点的 pre_delay / post_delay）
+   * @param instanceId 实例 ID
+   * @param multiplier 倍率，1 为原速，大于 1 更慢
+   */
+  async setRunSpeed(instanceId: string, multiplier: number): Promise<void> {
+    if (!isTauri()) return;
+    try {
+      await invoke('maa_set_run_speed', { instanceId, multiplier });
+    } catch (err) {
+      log.warn('设置运行速度失败:', err);
+    }
+  },
+
+  /**
+   * 设置实例最小操作间隔（每个节点动作后至少等待该时长）
+   * @param instanceId 实例 ID
+   * @param intervalMs 间隔毫秒数，0 表示不限制
+   */
+  async setActionInterval(instanceId: string, intervalMs: number): Promise<void> {
+    if (!isTauri()) return;
+    try {
+      await invoke('maa_set_action_interval', { instanceId, intervalMs });
+    } catch (err) {
+      log.warn('设置操作间隔失败:', err);
+    }
+  },
+
+  /**
+   * 设置实例标签（写入配置，空数组表示清除）
+   * @param instanceId 实例 ID
+   * @param tags 标签列表
+   * @returns 规范化后的标签
+   */
+  async setInstanceTags(instanceId: string, tags: string[]): Promise<string[]> {
+    return await invoke<string[]>('mxu_set_instance_tags', { instanceId, tags });
+  },
+
+  /**
+   * 设置实例截图分辨率预设（写入配置，连接时生效；已连接时立即生效）
+   * @param instanceId 实例 ID
+   * @param resolution `{ shortSide: 1080 }`、`'raw'` 或 null（清除预设）
+   */
+  async setCaptureResolution(
+    instanceId: string,
+    resolution: CaptureResolution | null,
+  ): Promise<void> {
+    await invoke('maa_set_capture_resolution', { instanceId, resolution });
+  },
+
+  /**
+   * 列出实例（可按标签、名称、运行状态筛选）
+   * @param filter 筛选条件
+   */
+  async listInstances(filter?: InstanceFilter): Promise<InstanceSummary[]> {
+    return await invoke<InstanceSummary[]>('mxu_list_instances', { filter: filter ?? null });
+  },
+
+  /**
+   * 启动带有指定标签的所有未运行实例
+   * @returns 将要启动的实例 ID 列表
+   */
+  async startTasksByTag(tag: string): Promise<string[]> {
+    log.info('按标签启动任务:', tag);
+    return await invoke<string[]>('mxu_start_tasks_by_tag', { tag });
+  },
+
+  /**
+   * 停止带有指定标签的所有运行中实例
+   * @returns 已停止的实例 ID 列表
+   */
+  async stopTasksByTag(tag: string): Promise<string[]> {
+    log.info('按标签停止任务:', tag);
+    return await invoke<string[]>('mxu_stop_tasks_by_tag', { tag });
+  },
+
+  /**
+   * 获取运行报告
+   * @param runId 运行 ID
+   */
+  async getRunReport(runId: string): Promise<RunReport> {
+    return await invoke<RunReport>('mxu_get_run_report', { runId });
+  },
+
+  /**
+   * 列出运行报告（按时间倒序）
+   * @param instanceId 仅返回该实例的报告（可选）
+   */
+  async listRunReports(instanceId?: string): Promise<RunReport[]> {
+    return await invoke<RunReport[]>('mxu_list_run_reports', { instanceId: instanceId ?? null });
+  },
+
+  /**
+   * 导出运行报告
+   * @param runId 运行 ID
+   * @param format 导出格式
+   * @returns 导出文件路径
+   */
+  async exportRunReport(runId: string, format: 'html' | 'markdown'): Promise<string> {
+    return await invoke<string>('mxu_export_run_report', { runId, format });
+  },
+
+  /**
+   * 导出任务历史与按实例统计
+   * @param range 导出范围（缺省导出全部）
+   * @param format 导出格式
+   * @param dest 目标文件路径
+   */
+  async exportStats(
+    range: StatsRange | undefined,
+    format: 'csv' | 'json',
+    dest: string,
+  ): Promise<ExportStatsResult> {
+    return await invoke<ExportStatsResult>('mxu_export_stats', { range, format, dest });
+  },
+
+  /**
+   * 获取最近的配置变更审计记录（新的在前）
+   * @param limit 最大条数（默认 100）
+   */
+  async getAuditLog(limit?: number): Promise<AuditEntry[]> {
+    return await invoke<AuditEntry[]>('mxu_get_audit_log', { limit });
+  },
+
+  /**
+   * 查询 MXU_REPORT_RESULT 上报的结构化结果（按时间倒序）
+   * @param task 任务入口（可选）
+   * @param key 结果键（可选）
+   * @param instanceId 实例 ID（可选）
+   * @param limit 最大条数（默认 1000）
+   */
+  async queryResults(
+    task?: string,
+    key?: string,
+    instanceId?: string,
+    limit?: number,
+  ): Promise<ResultRecord[]> {
+    return await invoke<ResultRecord[]>('mxu_query_results', { task, key, instanceId, limit });
+  },
+
+  /**
+   * 监听结构化结果上报（MXU_REPORT_RESULT 执行时触发）
+   */
+  async onTaskResult(
+    callback: (payload: ResultRecord) => void | Promise<void>,
+  ): Promise<UnlistenFn> {
+    if (!isTauri()) {
+      return () => {};
+    }
+
+    return await listen<ResultRecord>('task-result', (event) => {
+      void callback(event.payload);
+    });
+  },
+
+  /**
+   * 列出任务失败截图（按时间倒序）
+   * @param instanceId 仅返回该实例的截图（可选）
+   */
+  async listFailureCaptures(instanceId?: string): Promise<FailureCapture[]> {
+    return await invoke<FailureCapture[]>('mxu_list_failure_captures', {
+      instanceId: instanceId ?? null,
+    });
+  },
+
+  /**
+   * 获取实例的截图历史（按时间倒序）
+   * @param instanceId 实例 ID
+   * @param limit 最多返回条数（可选）
+   */
+  async getScreenshotHistory(
+    instanceId: string,
+    limit?: number,
+  ): Promise<ScreenshotHistoryEntry[]> {
+    return await invoke<ScreenshotHistoryEntry[]>('maa_get_screenshot_history', {
+      instanceId,
+      limit: limit ?? null,
+    });
+  },
+
+  /**
+   * 唤醒显示器并退出屏幕保护程序（显示器关闭时 Win32 截图会返回黑屏）
+   */
+  async wakeDisplay(): Promise<void> {
+    if (!isTauri()) return;
+    try {
+      await invoke('mxu_wake_display');
+    } catch (err) {
+      log.warn('唤醒显示器失败:', err);
+    }
+  },
+
+  /**
+   * 写入主机剪贴板文本
+   * @param text 文本内容
+   */
+  async setClipboard(text: string): Promise<void> {
+    await invoke('mxu_set_clipboard', { text });
+  },
+
+  /**
+   * 读取主机剪贴板文本
+   */
+  async getClipboard(): Promise<string> {
+    return await invoke<string>('mxu_get_clipboard');
+  },
+
+  /**
+   * 按关联 ID 获取命令追踪记录（关联 ID 来自 state-changed 事件的 correlation_id）
+   * @param correlationId 关联 ID
+   */
+  async getTrace(correlationId: string): Promise<CommandTrace> {
+    return await invoke<CommandTrace>('mxu_get_trace', { correlationId });
+  },
+
+  /**
+   * 获取实例的 focus 运行日志
+   * @param instanceId 实例 ID
+   */
+  async getFocusLog(instanceId: string): Promise<FocusLogEntry[]> {
+    return await invoke<FocusLogEntry[]>('mxu_get_focus_log', { instanceId });
+  },
+
+  /**
+   * 监听 focus 运行日志（每条命中的 focus 消息推送一次）
+   * @param callback 回调函数
+   * @returns 取消监听的函数
+   */
+  async onFocusLog(callback: (entry: FocusLogEntry) => void): Promise<UnlistenFn> {
+    return await listen<FocusLogEntry>('maa-focus-log', (event) => {
+      callback(event.payload);
+    });
+  },
+
+  /**
+   * 同步界面语言到后端（托盘菜单、原生对话框、系统通知文案）
+   * @param locale 语言标识，如 zh-CN、en-US
+   */
+  async setBackendLocale(locale: string): Promise<void> {
+    await invoke('mxu_set_locale', { locale });
+  },
+
+  /**
+   * 补取序号大于 sinceSeq 的事件（页面刷新或组件晚于事件挂载时使用）
+   * @param sinceSeq 上次补取返回的 lastSeq，首次传 0
+   * @param instanceId 只取该实例的事件（下载进度等全局事件始终返回）
+   */
+  async fetchMissedEvents(sinceSeq: number, instanceId?: string): Promise<MissedEvents> {
+    if (isTauri()) {
+      return await invoke<MissedEvents>('mxu_fetch_missed_events', { sinceSeq, instanceId });
+    }
+    const query = new URLSearchParams({ since_seq: String(sinceSeq) });
+    if (instanceId) query.set('instance_id', instanceId);
+    return await apiGet<MissedEvents>(`/events/missed?${query.toString()}`);
+  },
+
+  /**
+   * 获取配置加密状态
+   */
+  async getConfigEncryption(): Promise<ConfigEncryptionStatus> {
+    return await invoke<ConfigEncryptionStatus>('mxu_get_config_encryption');
+  },
+
+  /**
+   * 开启或关闭配置加密（提供密码时使用密码模式，否则使用本机密钥）
+   * @param enabled 是否开启
+   * @param passphrase 密码（可选）
+   */
+  async setConfigEncryption(
+    enabled: boolean,
+    passphrase?: string,
+  ): Promise<ConfigEncryptionStatus> {
+    return await invoke<ConfigEncryptionStatus>('mxu_set_config_encryption', {
+      enabled,
+      passphrase: passphrase || null,
+    });
+  },
+
+  /**
+   * 使用密码解锁加密配置
+   * @param passphrase 密码
+   */
+  async unlockConfig(passphrase: string): Promise<ConfigEncryptionStatus> {
+    return await invoke<ConfigEncryptionStatus>('mxu_unlock_config', { passphrase });
   },
 
   /**
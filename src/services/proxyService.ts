@@ -171,6 +171,10 @@ export async function downloadWithProxy(
   options?: {
     totalSize?: number;
     proxyUrl?: string | null;
+    /** 附加请求头（如 Authorization、Referer），只发往 url 所在主机，与 settings.download.headers 中同主机的请求头合并 */
+    headers?: Record<string, string>;
+    /** 本次下载限速（KB/s，0 为不限速），缺省使用 settings.download.maxKbps */
+    maxKbps?: number;
   },
 ): Promise<DownloadResult> {
   const hasProxy = options?.proxyUrl && options.proxyUrl.trim() !== '';
@@ -190,5 +194,7 @@ export async function downloadWithProxy(
     savePath,
     totalSize: options?.totalSize || null,
     proxyUrl: options?.proxyUrl || null,
+    headers: options?.headers || null,
+    maxKbps: options?.maxKbps ?? null,
   });
 }
@@ -1,6 +1,7 @@
 import { useAppStore } from '@/stores/appStore';
 import { loggers } from '@/utils';
 import type { Instance } from '@/types/interface';
+import type { ServerResetSettings } from '@/types/config';
 
 const log = loggers.task;
 
@@ -32,6 +33,25 @@ function hourStart(date: Date): Date {
   return new Date(date.getFullYear(), date.getMonth(), date.getDate(), date.getHours());
 }
 
+/** 解析固定偏移时区（UTC+8、+08:00、-0530），返回相对 UTC 的分钟数 */
+function parseUtcOffsetMinutes(timezone: string): number | null {
+  const match = /^(?:UTC|GMT)?\s*(?:([+-])(\d{1,2})(?::?(\d{2}))?)?$/.exec(timezone.trim());
+  if (!match) return null;
+  if (!match[1]) return 0;
+  const minutes = Number(match[2]) * 60 + Number(match[3] ?? 0);
+  return match[1] === '-' ? -minutes : minutes;
+}
+
+/** 服务器每日重置时刻对应的本地整点（重置不在整点时取其后的第一个整点） */
+function localResetHour(reset: ServerResetSettings | undefined, date: Date): number {
+  const localOffset = -date.getTimezoneOffset();
+  const serverOffset =
+    (reset?.timezone ? parseUtcOffsetMinutes(reset.timezone) : null) ?? localOffset;
+  const resetMinutes = (reset?.hour ?? 4) * 60 - serverOffset + localOffset;
+  const localMinutes = ((resetMinutes % 1440) + 1440) % 1440;
+  return Math.ceil(localMinutes / 60) % 24;
+}
+
 function buildTriggeredSlotKey(instanceId: string, slotStr: string): string {
   return `${instanceId}:${slotStr}`;
 }
@@ -292,7 +312,11 @@ class ScheduleService {
           for (const policy of policies) {
             if (!policy.enabled) continue;
             if (!policy.weekdays.includes(weekday)) continue;
-            if (!policy.hours.includes(hour)) continue;
+            if (policy.afterReset) {
+              if (hour !== localResetHour(inst.serverReset, slotDate)) continue;
+            } else if (!policy.hours.includes(hour)) {
+              continue;
+            }
 
             const slotKey = buildTriggeredSlotKey(inst.id, slotStr);
             if (triggeredSlots.has(slotKey)) break;
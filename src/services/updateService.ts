@@ -4,10 +4,10 @@
 import type { DownloadProgress, UpdateInfo } from '@/stores/appStore';
 import type { ProxySettings, UpdateChannel } from '@/types/config';
 import { loggers } from '@/utils/logger';
+import { getErrorCode, getErrorMessage, isMxuError } from '@/utils/mxuError';
 import { getCacheDir, joinPath } from '@/utils/paths';
 import { invoke } from '@tauri-apps/api/core';
 import { dirname } from '@tauri-apps/api/path';
-import { exists } from '@tauri-apps/plugin-fs';
 import { fetch as tauriFetch } from '@tauri-apps/plugin-http';
 import { openPath, openUrl } from '@tauri-apps/plugin-opener';
 import * as semver from 'semver';
@@ -70,11 +70,6 @@ export async function cancelDownload(): Promise<boolean> {
   return true;
 }
 
-const MIRRORCHYAN_API_BASES = [
-  'https://mirrorchyan.com/api/resources',
-  'https://mirrorchyan.net/api/resources',
-];
-
 // MirrorChyan API 错误码定义
 // 参考: https://github.com/MirrorChyan/docs/blob/main/ErrorCode.md
 export const MIRRORCHYAN_ERROR_CODES = {
@@ -92,24 +87,28 @@ export const MIRRORCHYAN_ERROR_CODES = {
   UNDIVIDED: 1, // 未区分的业务错误
 } as const;
 
-// MirrorChyan API 响应类型
-interface MirrorChyanApiResponse {
-  code: number;
-  msg: string;
-  data?: {
-    version_name: string;
-    version_number?: number;
-    url?: string;
-    sha256?: string;
-    release_note?: string;
-    custom_data?: string;
-    update_type?: 'incremental' | 'full';
-    channel?: string;
-    filesize?: number;
-    cdk_expired_time?: number;
-    os?: string;
-    arch?: string;
-  };
+// 后端 mirrorchyan_check_update 返回的查询结果
+interface MirrorChyanUpdateInfo {
+  versionName: string;
+  releaseNote: string;
+  downloadUrl?: string | null;
+  sha256?: string | null;
+  updateType?: 'incremental' | 'full' | null;
+  channel?: string | null;
+  fileSize?: number | null;
+  cdkExpiredTime?: number | null;
+  errorCode: number;
+  errorMessage?: string | null;
+  errorKind?:
+    | 'cdk'
+    | 'quota'
+    | 'not_found'
+    | 'invalid_params'
+    | 'rate_limited'
+    | 'network'
+    | 'unknown'
+    | null;
+  retryAfter?: number | null;
 }
 
 // GitHub Release API 响应类型
@@ -127,6 +126,16 @@ interface GitHubAsset {
   browser_download_url: string;
 }
 
+// 后端 check_update 返回的 GitHub Release 检查结果（assets 已按当前系统和架构筛选）
+interface GitHubUpdateCheckResult {
+  hasUpdate: boolean;
+  currentVersion: string;
+  latestVersion?: string | null;
+  tagName?: string | null;
+  releaseNote?: string | null;
+  assets: GitHubAsset[];
+}
+
 // 获取操作系统类型
 function getOS(): string {
   const platform = navigator.platform.toLowerCase();
@@ -215,25 +224,10 @@ export interface CheckUpdateOptions {
   userAgent?: string; // 客户端标识
 }
 
-/**
- * 向单个 API 基础 URL 发送更新检查请求
- */
-async function fetchUpdateFromBase(
-  apiBase: string,
-  resourceId: string,
-  params: URLSearchParams,
-): Promise<MirrorChyanApiResponse> {
-  const url = `${apiBase}/${resourceId}/latest?${params.toString()}`;
-  const response = await tauriFetch(url, {
-    headers: {
-      'User-Agent': buildUserAgent(),
-    },
-  });
-  return await response.json();
-}
-
 /**
  * 检查更新
+ *
+ * 请求由后端 `mirrorchyan_check_update` 发出（主备站切换、429 限流冷却均在后端处理）。
  * @returns UpdateInfo 或 null（检查失败时或正在下载时）
  */
 export async function checkUpdate(options: CheckUpdateOptions): Promise<UpdateInfo | null> {
@@ -250,112 +244,75 @@ export async function checkUpdate(options: CheckUpdateOptions): Promise<UpdateIn
     return null;
   }
 
-  const params = new URLSearchParams();
-  params.set('current_version', currentVersion);
-  params.set('user_agent', userAgent);
-  params.set('channel', channel);
-
-  // 添加系统信息
-  const os = getOS();
-  const arch = getArch();
-  if (os) params.set('os', os);
-  if (arch) params.set('arch', arch);
-
-  // CDK 是可选的，无 CDK 时也可以检查版本（但无法获取下载链接）
-  if (cdk) {
-    params.set('cdk', cdk);
-  }
-
   log.info(`检查更新: ${resourceId}, 当前版本: ${currentVersion}, 频道: ${channel}`);
 
-  let data: MirrorChyanApiResponse | null = null;
-  let lastError: unknown = null;
-
-  // 依次尝试主站和备用站
-  for (let i = 0; i < MIRRORCHYAN_API_BASES.length; i++) {
-    const apiBase = MIRRORCHYAN_API_BASES[i];
-    try {
-      data = await fetchUpdateFromBase(apiBase, resourceId, params);
-      // 请求成功且 code 为 0，直接使用结果
-      if (data.code === 0) {
-        break;
-      }
-      // code 非 0 视为 API 层面的错误，尝试备用站
-      log.warn(`${apiBase} 返回错误: code=${data.code}, msg=${data.msg}，尝试备用站...`);
-      lastError = new Error(`API error: code=${data.code}, msg=${data.msg}`);
-    } catch (error) {
-      log.warn(`${apiBase} 请求失败:`, error);
-      lastError = error;
-      // 网络错误，继续尝试备用站
-    }
+  let data: MirrorChyanUpdateInfo;
+  try {
+    data = await invoke<MirrorChyanUpdateInfo>('mirrorchyan_check_update', {
+      resourceId,
+      currentVersion,
+      // CDK 是可选的，无 CDK 时也可以检查版本（但无法获取下载链接）
+      cdk: cdk || null,
+      channel,
+      userAgent,
+    });
+  } catch (error) {
+    log.error('检查更新失败:', error);
+    return null;
   }
 
-  // 所有站点都失败或返回错误
-  if (!data || data.code !== 0) {
-    if (data && data.code !== 0) {
-      log.warn(`更新检查返回错误: code=${data.code}, msg=${data.msg}`);
-      // code 非 0 但仍可能有版本信息，根据版本比较判断是否有更新
-      if (data.data?.version_name) {
-        const hasUpdate = compareVersions(data.data.version_name, currentVersion) > 0;
-        log.info(
-          `更新检查完成（带错误码）: 最新版本=${data.data.version_name}, 有更新=${hasUpdate}`,
-        );
-        return {
-          hasUpdate,
-          versionName: data.data.version_name,
-          releaseNote: data.data.release_note || '',
-          channel: data.data.channel,
-          fileSize: data.data.filesize,
-          updateType: data.data.update_type,
-          // 注意：code != 0 时没有下载链接
-          errorCode: data.code,
-          errorMessage: data.msg,
-        };
-      }
-      // 没有版本信息但有错误码，仍然返回错误信息
-      return {
-        hasUpdate: false,
-        versionName: '',
-        releaseNote: '',
-        errorCode: data.code,
-        errorMessage: data.msg,
-      };
+  // 网络错误 / 限流：没有可用的版本信息
+  if (data.errorKind === 'network' || data.errorKind === 'rate_limited') {
+    if (data.errorKind === 'rate_limited') {
+      log.warn(`更新检查被限流，${data.retryAfter ?? 0}s 后可重试`);
     } else {
-      log.error('检查更新失败:', lastError);
+      log.error('检查更新失败:', data.errorMessage);
     }
     return null;
   }
 
-  if (!data.data) {
-    log.warn('更新检查响应缺少 data 字段');
-    return null;
-  }
+  // 比较版本号判断是否有更新（code 非 0 时仍可能有版本信息）
+  const hasUpdate = !!data.versionName && compareVersions(data.versionName, currentVersion) > 0;
 
-  const {
-    version_name,
-    url: downloadUrl,
-    release_note,
-    update_type,
-    channel: respChannel,
-    filesize,
-  } = data.data;
+  if (data.errorCode !== 0) {
+    log.warn(`更新检查返回错误: code=${data.errorCode}, msg=${data.errorMessage}`);
+    if (data.versionName) {
+      log.info(
+        `更新检查完成（带错误码）: 最新版本=${data.versionName}, 有更新=${hasUpdate}`,
+      );
+    }
+    return {
+      hasUpdate,
+      versionName: data.versionName,
+      releaseNote: data.releaseNote,
+      channel: data.channel ?? undefined,
+      fileSize: data.fileSize ?? undefined,
+      updateType: data.updateType ?? undefined,
+      // 注意：code != 0 时没有下载链接
+      errorCode: data.errorCode,
+      errorMessage: data.errorMessage ?? undefined,
+    };
+  }
 
-  // 比较版本号判断是否有更新
-  const hasUpdate = compareVersions(version_name, currentVersion) > 0;
+  if (data.errorKind) {
+    log.warn('更新检查失败:', data.errorMessage);
+    return null;
+  }
 
-  log.info(`更新检查完成: 最新版本=${version_name}, 有更新=${hasUpdate}`);
+  log.info(`更新检查完成: 最新版本=${data.versionName}, 有更新=${hasUpdate}`);
 
+  const downloadUrl = data.downloadUrl ?? undefined;
   // 从下载 URL 中提取文件名
   const filename = downloadUrl ? extractFilenameFromUrl(downloadUrl) : undefined;
 
   return {
     hasUpdate,
-    versionName: version_name,
-    releaseNote: release_note || '',
+    versionName: data.versionName,
+    releaseNote: data.releaseNote,
     downloadUrl,
-    updateType: update_type,
-    channel: respChannel,
-    fileSize: filesize,
+    updateType: data.updateType ?? undefined,
+    channel: data.channel ?? undefined,
+    fileSize: data.fileSize ?? undefined,
     filename,
     downloadSource: downloadUrl ? 'mirrorchyan' : undefined,
   };
@@ -577,6 +534,16 @@ async function tryDirectDownloadUrls(
   return null;
 }
 
+/** 更新包分离签名的文件名后缀 */
+const SIGNATURE_SUFFIX = '.sig';
+
+/**
+ * 查找与更新包同名的签名 asset（`<包名>.sig`）
+ */
+function findSignatureAsset(assets: GitHubAsset[], packageName: string): GitHubAsset | null {
+  return assets.find((asset) => asset.name === `${packageName}${SIGNATURE_SUFFIX}`) ?? null;
+}
+
 /**
  * 根据 OS 和架构匹配合适的 GitHub Asset
  * 优先匹配 OS + 架构，多个匹配时优先选择名字带 mxu 的，否则选体积最大的
@@ -590,6 +557,7 @@ function matchGitHubAsset(assets: GitHubAsset[]): GitHubAsset | null {
 
   for (const asset of assets) {
     const name = asset.name.toLowerCase();
+    if (name.endsWith(SIGNATURE_SUFFIX)) continue;
 
     // 检查 OS 匹配
     const osMatch = osAliases.some((alias) => name.includes(alias.toLowerCase()));
@@ -632,6 +600,15 @@ export interface GetGitHubDownloadUrlOptions {
   proxyUrl?: string; // 代理 URL，用于 GitHub API 请求
 }
 
+/** GitHub 下载信息 */
+export interface GitHubDownloadInfo {
+  url: string;
+  size: number;
+  filename: string;
+  /** 同一 release 下的签名 asset 链接，release 未发布签名时为空 */
+  signatureUrl?: string;
+}
+
 /**
  * 获取 GitHub 下载链接
  * 根据 Mirror酱返回的版本号在 GitHub releases 中查找对应的 release
@@ -640,7 +617,7 @@ export interface GetGitHubDownloadUrlOptions {
  */
 export async function getGitHubDownloadUrl(
   options: GetGitHubDownloadUrlOptions,
-): Promise<{ url: string; size: number; filename: string } | null> {
+): Promise<GitHubDownloadInfo | null> {
   const { githubUrl, targetVersion, githubPat, projectName, proxyUrl } = options;
   const parsed = parseGitHubUrl(githubUrl);
   if (!parsed) {
@@ -662,6 +639,7 @@ export async function getGitHubDownloadUrl(
         url: asset.browser_download_url,
         size: asset.size,
         filename: asset.name,
+        signatureUrl: findSignatureAsset(release.assets, asset.name)?.browser_download_url,
       };
     }
     log.warn('未找到匹配当前系统的下载文件');
@@ -678,6 +656,8 @@ export async function getGitHubDownloadUrl(
         url: directResult.url,
         size: 0, // 直接链接无法获取文件大小
         filename: directResult.filename,
+        // 签名与更新包发布在同一 release 下
+        signatureUrl: `${directResult.url}${SIGNATURE_SUFFIX}`,
       };
     }
     log.warn('直接下载链接也不可用');
@@ -691,6 +671,8 @@ export async function getGitHubDownloadUrl(
 interface DownloadUpdateOptions {
   url: string;
   savePath: string;
+  /** 更新包分离签名的下载链接（见 UpdateInfo.signatureUrl），缺省时不下载签名 */
+  signatureUrl?: string;
   totalSize?: number;
   onProgress?: (progress: DownloadProgress) => void;
   proxySettings?: ProxySettings; // 代理设置
@@ -739,7 +721,7 @@ export async function downloadUpdate(
     return { success: false };
   }
 
-  const { url, savePath, totalSize, onProgress, proxySettings } = options;
+  const { url, savePath, signatureUrl, totalSize, onProgress, proxySettings } = options;
 
   log.info(`开始下载更新: ${url}`);
   log.info(`保存路径: ${savePath}`);
@@ -793,6 +775,12 @@ export async function downloadUpdate(
       log.info(`检测到文件名: ${result.detected_filename}`);
     }
 
+    if (signatureUrl) {
+      await downloadSignature(signatureUrl, result.actual_save_path, proxySettings?.url);
+    } else {
+      log.info('更新源未提供签名，跳过签名下载');
+    }
+
     return {
       success: true,
       actualSavePath: result.actual_save_path,
@@ -803,7 +791,7 @@ export async function downloadUpdate(
     if (downloadCancelled) {
       log.info('下载已被用户取消');
     } else {
-      log.error('下载失败:', error);
+      log.error('下载失败:', toInsufficientDiskSpaceError(error)?.message ?? error);
     }
     return { success: false };
   } finally {
@@ -819,6 +807,19 @@ export async function downloadUpdate(
   }
 }
 
+/**
+ * 下载更新包的分离签名，保存为 `<更新包>.sig`
+ * 下载失败时仅记录日志，安装前的签名校验会给出缺少签名的错误
+ */
+async function downloadSignature(sigUrl: string, packagePath: string, proxyUrl?: string) {
+  try {
+    await downloadWithProxy(sigUrl, `${packagePath}.sig`, { proxyUrl });
+    log.info(`已下载更新包签名: ${sigUrl}`);
+  } catch (error) {
+    log.warn('下载更新包签名失败:', error);
+  }
+}
+
 export interface CheckAndDownloadOptions extends CheckUpdateOptions {
   githubUrl?: string;
   githubPat?: string; // GitHub Personal Access Token
@@ -826,9 +827,59 @@ export interface CheckAndDownloadOptions extends CheckUpdateOptions {
   projectName?: string; // 项目名称，用于 GitHub API 失败时拼接直接下载链接
 }
 
+/**
+ * 通过 GitHub Release 检查更新（后端 `check_update`，按更新频道筛选 release）
+ * 仅在 Mirror酱 无法检查时使用
+ * @returns UpdateInfo 或 null（检查失败或没有可用 release 时）
+ */
+async function checkGitHubUpdate(options: CheckAndDownloadOptions): Promise<UpdateInfo | null> {
+  const { githubUrl, currentVersion, channel = 'stable', githubPat, proxyUrl } = options;
+  const parsed = githubUrl ? parseGitHubUrl(githubUrl) : null;
+  if (!parsed) {
+    log.warn('无法解析 GitHub URL:', githubUrl);
+    return null;
+  }
+
+  let result: GitHubUpdateCheckResult;
+  try {
+    result = await invoke<GitHubUpdateCheckResult>('check_update', {
+      repo: `${parsed.owner}/${parsed.repo}`,
+      channel,
+      currentVersion,
+      githubPat: githubPat || null,
+      proxyUrl: proxyUrl || null,
+    });
+  } catch (error) {
+    log.error('通过 GitHub 检查更新失败:', error);
+    return null;
+  }
+
+  if (!result.latestVersion) {
+    return null;
+  }
+
+  log.info(`GitHub 更新检查完成: 最新版本=${result.latestVersion}, 有更新=${result.hasUpdate}`);
+
+  const asset = matchGitHubAsset(result.assets);
+  return {
+    hasUpdate: result.hasUpdate,
+    versionName: result.tagName ?? result.latestVersion,
+    releaseNote: result.releaseNote ?? '',
+    channel,
+    downloadUrl: asset?.browser_download_url,
+    fileSize: asset?.size,
+    filename: asset?.name,
+    downloadSource: asset ? 'github' : undefined,
+    signatureUrl: asset
+      ? findSignatureAsset(result.assets, asset.name)?.browser_download_url
+      : undefined,
+  };
+}
+
 /**
  * 检查更新并获取下载信息
- * 始终使用 Mirror酱 检查更新，根据是否有 CDK 决定下载来源
+ * 优先使用 Mirror酱 检查更新，根据是否有 CDK 决定下载来源；
+ * Mirror酱 无法检查（网络错误、限流等）时改用 GitHub Release 检查
  */
 export async function checkAndPrepareDownload(
   options: CheckAndDownloadOptions,
@@ -841,17 +892,29 @@ export async function checkAndPrepareDownload(
 
   const { githubUrl, cdk, channel, githubPat, projectName, proxyUrl, ...checkOptions } = options;
 
-  // 始终使用 Mirror酱 检查更新
   const updateInfo = await checkUpdate({ ...checkOptions, cdk, channel });
 
-  if (!updateInfo || !updateInfo.hasUpdate) {
+  if (!updateInfo) {
+    if (!githubUrl) return null;
+    log.info('Mirror酱 检查更新失败，改用 GitHub Release 检查');
+    return await checkGitHubUpdate(options);
+  }
+
+  if (!updateInfo.hasUpdate) {
     return updateInfo;
   }
 
   // 如果有 CDK 且返回了下载链接，直接使用
   if (cdk && updateInfo.downloadUrl) {
     log.info('使用 Mirror酱 下载链接');
-    return updateInfo;
+    if (!githubUrl) return updateInfo;
+    return await withMirrorChyanSignature(updateInfo, {
+      githubUrl,
+      targetVersion: updateInfo.versionName,
+      githubPat,
+      projectName,
+      proxyUrl,
+    });
   }
 
   // 如果有错误码（如 CDK 问题），不尝试 GitHub，直接返回更新信息（包含错误）
@@ -878,6 +941,7 @@ export async function checkAndPrepareDownload(
         fileSize: githubDownload.size,
         filename: githubDownload.filename,
         downloadSource: 'github',
+        signatureUrl: githubDownload.signatureUrl,
       };
     }
 
@@ -888,6 +952,45 @@ export async function checkAndPrepareDownload(
   return updateInfo;
 }
 
+/**
+ * 为 Mirror酱 下载补充签名来源
+ * Mirror酱 不提供签名文件，当前构建内置发布公钥时：
+ * - 全量包与 GitHub release 中的同名包一致，使用该 release 的签名 asset
+ * - 增量包没有对应签名，改为从 GitHub 下载带签名的全量包
+ * 未内置公钥或 GitHub 上找不到签名时原样返回，安装时由签名校验给出错误
+ */
+async function withMirrorChyanSignature(
+  updateInfo: UpdateInfo,
+  github: GetGitHubDownloadUrlOptions,
+): Promise<UpdateInfo> {
+  try {
+    if (!(await invoke<boolean>('update_signature_required'))) return updateInfo;
+  } catch (error) {
+    log.warn('查询签名要求失败:', error);
+    return updateInfo;
+  }
+
+  const githubDownload = await getGitHubDownloadUrl(github);
+  if (!githubDownload?.signatureUrl) {
+    log.warn('GitHub release 未提供更新包签名，Mirror酱 更新包将无法通过签名校验');
+    return updateInfo;
+  }
+
+  if (updateInfo.updateType === 'incremental') {
+    log.info('Mirror酱 增量包没有签名，改用 GitHub 全量包');
+    return {
+      ...updateInfo,
+      downloadUrl: githubDownload.url,
+      fileSize: githubDownload.size,
+      filename: githubDownload.filename,
+      updateType: 'full',
+      downloadSource: 'github',
+      signatureUrl: githubDownload.signatureUrl,
+    };
+  }
+  return { ...updateInfo, signatureUrl: githubDownload.signatureUrl };
+}
+
 /**
  * 获取更新包保存路径
  * @param dataPath 数据目录（macOS: ~/Library/Application
@@ -915,12 +1018,30 @@ interface ChangesJson {
   modified: string[];
 }
 
+// 后端 apply_incremental_update / apply_full_update 返回的结果
+interface UpdateApplyResult {
+  // 因被占用而安排在下次开机时替换的文件
+  pending_reboot_files: string[];
+}
+
+// 后端 verify_update_compatibility / verify_installed_maafw 返回的兼容性报告
+export interface UpdateCompatReport {
+  loaded: boolean;
+  compatible: boolean;
+  version?: string | null;
+  minimum?: string | null;
+  error?: string | null;
+  installed: boolean;
+}
+
 export interface InstallUpdateOptions {
   zipPath: string; // 下载的更新包路径
   targetDir: string; // 目标安装目录
   newVersion: string; // 新版本号（用于兜底时创建文件夹）
   projectName?: string; // 项目名称（用于备份配置文件）
+  scheduleOnReboot?: boolean; // 目标文件被占用时安排在下次开机时替换（默认开启）
   onProgress?: (stage: string, detail?: string) => void;
+  onPendingReboot?: (files: string[]) => void; // 有文件需要重启电脑后才能替换时回调
 }
 
 /**
@@ -936,10 +1057,12 @@ export function isExecutableInstaller(filePath: string): boolean {
  * 1. 如果是 exe/dmg 文件，直接打开（调用系统默认程序）
  * 2. 否则解压更新包（支持 zip/tar.gz/tgz）
  * 3. 检查是否为增量包（存在 changes.json）
- * 4. 增量包：删除 deleted 文件，复制覆盖
- * 5. 全量包：删除同名文件夹，复制覆盖
- * 6. 清理临时文件
- * 7. 如果失败，尝试兜底：创建 v版本号 文件夹
+ * 4. 检查新 MaaFramework 兼容性，不兼容时取消更新（安装目录保持不变）
+ * 5. 增量包：删除 deleted 文件，复制覆盖
+ * 6. 全量包：删除同名文件夹，复制覆盖
+ * 7. 安装后再次检查 MaaFramework，不可用时恢复更新前备份的 maafw
+ * 8. 清理临时文件
+ * 9. 如果失败，尝试兜底：创建 v版本号 文件夹
  */
 export async function installUpdate(options: InstallUpdateOptions): Promise<boolean> {
   if (isInstalling) {
@@ -948,11 +1071,26 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
   }
   isInstalling = true;
 
-  const { zipPath, targetDir, newVersion, projectName, onProgress } = options;
+  const {
+    zipPath,
+    targetDir,
+    newVersion,
+    projectName,
+    scheduleOnReboot = true,
+    onProgress,
+    onPendingReboot,
+  } = options;
 
   log.info(`开始安装更新: ${zipPath} -> ${targetDir}`);
 
   try {
+    // 签名校验未通过时不做任何改动（包括兜底更新）
+    onProgress?.('verifying');
+    const signatureVerified = await invoke<boolean>('verify_update_signature', {
+      packagePath: zipPath,
+    });
+    log.info(signatureVerified ? '更新包签名校验通过' : '当前构建未内置发布公钥，跳过签名校验');
+
     await backupConfigBeforeUpdate(targetDir, projectName);
 
     // 对于 exe/dmg 文件，直接打开而不是解压
@@ -998,6 +1136,24 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
         extractDir,
       });
 
+      // 3. 安装前检查新 MaaFramework 能否加载且版本兼容，不兼容时取消更新
+      onProgress?.('checking', 'maafw');
+      const compat = await invoke<UpdateCompatReport>('verify_update_compatibility', {
+        extractDir,
+        targetDir,
+      });
+      if (!compat.loaded || !compat.compatible) {
+        await invoke('cleanup_extract_dir', { extractDir }).catch(() => {});
+        await moveToOldFolder(zipPath);
+        throw new UpdateCompatError('cancelled', compat);
+      }
+
+      // 更新涉及 MaaFramework 时（version 非空）先备份当前 maafw，安装后检查失败时回滚
+      const maafwBackupDir = compat.version
+        ? await invoke<string | null>('backup_installed_maafw', { extractDir, targetDir })
+        : null;
+
+      let applyResult: UpdateApplyResult;
       if (changesJson) {
         // 增量更新
         log.info(
@@ -1007,23 +1163,58 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
         );
         onProgress?.('applying', 'incremental');
 
-        await invoke('apply_incremental_update', {
+        applyResult = await invoke<UpdateApplyResult>('apply_incremental_update', {
           extractDir,
           targetDir,
           deletedFiles: changesJson.deleted,
+          scheduleOnReboot,
+          packagePath: zipPath,
         });
       } else {
         // 全量更新
         log.info('全量更新');
         onProgress?.('applying', 'full');
 
-        await invoke('apply_full_update', {
+        applyResult = await invoke<UpdateApplyResult>('apply_full_update', {
           extractDir,
           targetDir,
+          scheduleOnReboot,
+          packagePath: zipPath,
         });
       }
 
-      // 3. 清理临时文件
+      if (applyResult.pending_reboot_files.length > 0) {
+        log.warn('部分文件被占用，将在下次开机时替换:', applyResult.pending_reboot_files);
+        onPendingReboot?.(applyResult.pending_reboot_files);
+      }
+
+      // 4. 安装后检查安装目录中的 MaaFramework，不可用时恢复更新前的 maafw
+      let installedCompatError: UpdateCompatError | null = null;
+      if (maafwBackupDir) {
+        onProgress?.('checking', 'maafw');
+        const installed = await invoke<UpdateCompatReport>('verify_installed_maafw', {
+          targetDir,
+          pendingRebootFiles: applyResult.pending_reboot_files,
+        });
+        if (!installed.loaded || !installed.compatible) {
+          onProgress?.('rollback', 'maafw');
+          try {
+            await invoke('rollback_maafw_update', { backupDir: maafwBackupDir, targetDir });
+            installedCompatError = new UpdateCompatError('rolledBack', installed);
+          } catch (rollbackError) {
+            log.error('恢复更新前的 MaaFramework 失败:', rollbackError);
+            installedCompatError = new UpdateCompatError(
+              'rollbackFailed',
+              installed,
+              getErrorMessage(rollbackError),
+            );
+          }
+        } else {
+          await invoke('cleanup_extract_dir', { extractDir: maafwBackupDir }).catch(() => {});
+        }
+      }
+
+      // 5. 清理临时文件
       onProgress?.('cleanup');
       log.info('清理临时文件...');
 
@@ -1038,11 +1229,26 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
         log.warn('清理更新残留产物失败（忽略）:', e);
       });
 
+      if (installedCompatError) {
+        throw installedCompatError;
+      }
+
       log.info('更新安装完成');
       onProgress?.('done');
 
       return true;
     } catch (error) {
+      // 兼容性检查未通过时已取消更新或已回滚 maafw，兜底目录中的新版本同样不可用
+      if (error instanceof UpdateCompatError) {
+        throw error;
+      }
+      // 磁盘空间不足时预检已拦截，安装目录未改动；兜底更新同样需要空间，直接报告
+      const diskError = toInsufficientDiskSpaceError(error);
+      if (diskError) {
+        log.error('更新安装失败，磁盘空间不足:', diskError.message);
+        await invoke('cleanup_extract_dir', { extractDir }).catch(() => {});
+        throw diskError;
+      }
       log.error('更新安装失败:', error);
 
       // 兜底逻辑：尝试将新文件解压到 v版本号 文件夹
@@ -1054,6 +1260,7 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
           extractDir,
           targetDir,
           newVersion,
+          packagePath: zipPath,
         });
 
         log.info(`兜底更新成功，新文件已解压到: ${fallbackDir}`);
@@ -1065,7 +1272,7 @@ export async function installUpdate(options: InstallUpdateOptions): Promise<bool
 
         // 抛出特殊错误，告知用户可以使用兜底文件夹
         throw new FallbackUpdateError(
-          `更新失败，但已将新版本文件解压到 ${fallbackDir}，您可以临时使用该文件夹中的程序`,
+          `更新失败，新版本文件已解压到兜底目录: ${fallbackDir}`,
           fallbackDir,
         );
       } catch (fallbackError) {
@@ -1100,6 +1307,62 @@ export class FallbackUpdateError extends Error {
   }
 }
 
+/**
+ * 新版本 MaaFramework 不可用
+ * - cancelled：安装前检查未通过，已取消更新，安装目录未改动
+ * - rolledBack：安装后检查未通过，已恢复更新前的 maafw
+ * - rollbackFailed：安装后检查未通过，且恢复 maafw 失败
+ */
+export class UpdateCompatError extends Error {
+  public readonly stage: 'cancelled' | 'rolledBack' | 'rollbackFailed';
+  public readonly report: UpdateCompatReport;
+  public readonly rollbackError?: string;
+
+  constructor(
+    stage: 'cancelled' | 'rolledBack' | 'rollbackFailed',
+    report: UpdateCompatReport,
+    rollbackError?: string,
+  ) {
+    super(`新版本 MaaFramework 不可用 (${stage}): ${report.error ?? report.version ?? ''}`);
+    this.name = 'UpdateCompatError';
+    this.stage = stage;
+    this.report = report;
+    this.rollbackError = rollbackError;
+  }
+}
+
+/**
+ * 目标磁盘剩余空间不足（后端 INSUFFICIENT_DISK_SPACE），安装目录未改动
+ */
+export class InsufficientDiskSpaceError extends Error {
+  public readonly path: string;
+  public readonly requiredBytes: number;
+  public readonly availableBytes: number;
+
+  constructor(message: string, path: string, requiredBytes: number, availableBytes: number) {
+    super(message);
+    this.name = 'InsufficientDiskSpaceError';
+    this.path = path;
+    this.requiredBytes = requiredBytes;
+    this.availableBytes = availableBytes;
+  }
+}
+
+/**
+ * 将后端的磁盘空间不足错误转为 InsufficientDiskSpaceError，其他错误返回 null
+ */
+export function toInsufficientDiskSpaceError(error: unknown): InsufficientDiskSpaceError | null {
+  if (!isMxuError(error) || getErrorCode(error) !== 'INSUFFICIENT_DISK_SPACE') return null;
+  const details = error.details ?? {};
+  const path = typeof details.path === 'string' ? details.path : error.context;
+  return new InsufficientDiskSpaceError(
+    error.message,
+    path ?? '',
+    Number(details.requiredBytes ?? 0),
+    Number(details.availableBytes ?? 0),
+  );
+}
+
 // 更新完成信息存储 key
 const UPDATE_COMPLETE_STORAGE_KEY = 'mxu-update-complete';
 // 待安装更新信息存储 key
@@ -1207,7 +1470,10 @@ export async function getPendingUpdateInfo(): Promise<PendingUpdateInfo | null>
     const info = JSON.parse(data) as PendingUpdateInfo;
 
     // 检查更新包文件是否仍然存在
-    if (info.downloadSavePath && !(await exists(info.downloadSavePath))) {
+    if (
+      info.downloadSavePath &&
+      !(await invoke<boolean>('local_file_exists', { filename: info.downloadSavePath }))
+    ) {
       log.info('更新包文件已被删除，清除待安装更新信息:', info.downloadSavePath);
       localStorage.removeItem(PENDING_UPDATE_STORAGE_KEY);
       return null;
@@ -6,6 +6,7 @@
  * 通过订阅 API 将事件分发给各消费者。
  */
 
+import type { ResolutionChangedEvent } from '@/types/maa';
 import { createLogger } from '@/utils/logger';
 
 const log = createLogger('wsService');
@@ -15,10 +16,55 @@ const log = createLogger('wsService');
 // ============================================================================
 
 export interface WsMaaCallbackPayload {
+  instance_id: string;
   message: string;
   details: string;
 }
 
+/** 节点事件详情 */
+export interface MaaNodeDetail {
+  task_id: number;
+  name: string;
+  node_id?: number;
+  reco_id?: number;
+  action_id?: number;
+  focus?: unknown;
+}
+
+/** 类型化的 Maa 回调事件（maa-event），按 kind 区分 */
+export type MaaEventPayload = { instance_id: string } & (
+  | {
+      kind: 'resource_load_started' | 'resource_loaded' | 'resource_load_failed';
+      res_id: number;
+      path: string;
+    }
+  | {
+      kind:
+        | 'connect_started'
+        | 'connected'
+        | 'connect_failed'
+        | 'controller_action_started'
+        | 'controller_action_succeeded'
+        | 'controller_action_failed';
+      ctrl_id: number;
+      action: string;
+    }
+  | { kind: 'task_started' | 'task_succeeded' | 'task_failed'; task_id: number; entry: string }
+  | ({
+      kind:
+        | 'node_started'
+        | 'node_succeeded'
+        | 'node_failed'
+        | 'node_recognition_started'
+        | 'node_recognized'
+        | 'node_recognition_failed'
+        | 'node_action_started'
+        | 'node_acted'
+        | 'node_action_failed';
+    } & MaaNodeDetail)
+  | { kind: 'other'; message: string; details: unknown }
+);
+
 export interface WsAgentOutputPayload {
   instance_id: string;
   stream: string;
@@ -27,16 +73,23 @@ export interface WsAgentOutputPayload {
 
 export type WsMessage =
   | { type: 'maa-callback'; payload: WsMaaCallbackPayload }
+  | { type: 'maa-event'; payload: MaaEventPayload }
   | { type: 'maa-agent-output'; payload: WsAgentOutputPayload }
+  | { type: 'maa-resolution-changed'; payload: ResolutionChangedEvent }
   | { type: 'config-changed'; payload: undefined }
-  | { type: 'state-changed'; payload: { instance_id: string; kind: string } };
+  | {
+      type: 'state-changed';
+      payload: { instance_id: string; kind: string; correlation_id?: string };
+    };
 
 // ============================================================================
 // 订阅者类型
 // ============================================================================
 
-type MaaCallbackHandler = (message: string, details: string) => void;
+type MaaCallbackHandler = (message: string, details: string, instanceId: string) => void;
+type MaaEventHandler = (event: MaaEventPayload) => void;
 type AgentOutputHandler = (instanceId: string, stream: string, line: string) => void;
+type ResolutionChangedHandler = (event: ResolutionChangedEvent) => void;
 type ConfigChangedHandler = () => void;
 type StateChangedHandler = (instanceId: string, kind: string) => void;
 type ConnectionStatusHandler = (connected: boolean) => void;
@@ -53,7 +106,9 @@ let stopped = false;
 let serverPort: number | null = null;
 
 const maaCallbackHandlers = new Set<MaaCallbackHandler>();
+const maaEventHandlers = new Set<MaaEventHandler>();
 const agentOutputHandlers = new Set<AgentOutputHandler>();
+const resolutionChangedHandlers = new Set<ResolutionChangedHandler>();
 const configChangedHandlers = new Set<ConfigChangedHandler>();
 const stateChangedHandlers = new Set<StateChangedHandler>();
 const connectionStatusHandlers = new Set<ConnectionStatusHandler>();
@@ -162,13 +217,21 @@ function onMessage(event: MessageEvent) {
 
   switch (msg.type) {
     case 'maa-callback':
-      maaCallbackHandlers.forEach((h) => h(msg.payload.message, msg.payload.details));
+      maaCallbackHandlers.forEach((h) =>
+        h(msg.payload.message, msg.payload.details, msg.payload.instance_id),
+      );
+      break;
+    case 'maa-event':
+      maaEventHandlers.forEach((h) => h(msg.payload));
       break;
     case 'maa-agent-output':
       agentOutputHandlers.forEach((h) =>
         h(msg.payload.instance_id, msg.payload.stream, msg.payload.line),
       );
       break;
+    case 'maa-resolution-changed':
+      resolutionChangedHandlers.forEach((h) => h(msg.payload));
+      break;
     case 'config-changed':
       configChangedHandlers.forEach((h) => h());
       break;
@@ -231,6 +294,18 @@ export function onMaaCallback(handler: MaaCallbackHandler): () => void {
   return () => maaCallbackHandlers.delete(handler);
 }
 
+/** 订阅 maa-event 类型化回调事件，返回取消订阅函数 */
+export function onMaaEvent(handler: MaaEventHandler): () => void {
+  maaEventHandlers.add(handler);
+  return () => maaEventHandlers.delete(handler);
+}
+
+/** 订阅 maa-resolution-changed 事件，返回取消订阅函数 */
+export function onResolutionChanged(handler: ResolutionChangedHandler): () => void {
+  resolutionChangedHandlers.add(handler);
+  return () => resolutionChangedHandlers.delete(handler);
+}
+
 /** 订阅 maa-agent-output 事件，返回取消订阅函数 */
 export function onAgentOutput(handler: AgentOutputHandler): () => void {
   agentOutputHandlers.add(handler);
@@ -139,7 +139,13 @@ export const useAppStore = create<AppState>()(
     setLanguage: (lang) => {
       set({ language: lang });
       setI18nLanguage(lang);
-      if (!isTauri()) patchWebUIAppearance({ language: lang });
+      if (isTauri()) {
+        maaService.setBackendLocale(i18n.language).catch((err) => {
+          loggers.app.warn('同步后端语言失败:', err);
+        });
+      } else {
+        patchWebUIAppearance({ language: lang });
+      }
     },
     setBackgroundImage: (path) => {
       set({ backgroundImage: path });
@@ -373,11 +379,19 @@ export const useAppStore = create<AppState>()(
               id: t.id,
               taskName: t.taskName,
               customName: t.customName,
+              timeoutSecs: t.timeoutSecs,
+              timeoutRestarts: t.timeoutRestarts,
               enabled: t.enabled,
               optionValues: t.optionValues,
             })),
             schedulePolicies: instanceToClose.schedulePolicies,
             preActions: instanceToClose.preActions,
+            runSpeed: instanceToClose.runSpeed,
+            actionIntervalMs: instanceToClose.actionIntervalMs,
+            tags: instanceToClose.tags,
+            captureResolution: instanceToClose.captureResolution,
+            appBackup: instanceToClose.appBackup,
+            serverReset: instanceToClose.serverReset,
           };
           // 添加到列表头部，并限制最大条目数
           newRecentlyClosed = [closedRecord, ...state.recentlyClosed].slice(0, MAX_RECENTLY_CLOSED);
@@ -731,6 +745,26 @@ export const useAppStore = create<AppState>()(
         ),
       })),
 
+    setTaskTimeout: (instanceId, taskId, timeoutSecs, timeoutRestarts) =>
+      set((state) => ({
+        instances: state.instances.map((i) =>
+          i.id === instanceId
+            ? {
+                ...i,
+                selectedTasks: i.selectedTasks.map((t) =>
+                  t.id === taskId
+                    ? {
+                        ...t,
+                        timeoutSecs: timeoutSecs || undefined,
+                        timeoutRestarts: timeoutSecs ? timeoutRestarts || undefined : undefined,
+                      }
+                    : t,
+                ),
+              }
+            : i,
+        ),
+      })),
+
     renameTask: (instanceId, taskId, newName) =>
       set((state) => ({
         instances: state.instances.map((i) =>
@@ -873,6 +907,12 @@ export const useAppStore = create<AppState>()(
         })),
         isRunning: false,
         preActions: sourceInstance.preActions?.map((a) => ({ ...a, id: generateId() })),
+        runSpeed: sourceInstance.runSpeed,
+        actionIntervalMs: sourceInstance.actionIntervalMs,
+        tags: sourceInstance.tags,
+        captureResolution: sourceInstance.captureResolution,
+        appBackup: sourceInstance.appBackup,
+        serverReset: sourceInstance.serverReset,
       };
 
       // 复制源实例的控制器和资源选择
@@ -1036,6 +1076,8 @@ export const useAppStore = create<AppState>()(
                 id: t.id,
                 taskName: t.taskName,
                 customName: t.customName,
+                timeoutSecs: t.timeoutSecs,
+                timeoutRestarts: t.timeoutRestarts,
                 enabled: t.enabled,
                 optionValues: t.optionValues,
                 expanded: prevExpandedByTask.get(t.id) ?? false,
@@ -1058,6 +1100,8 @@ export const useAppStore = create<AppState>()(
               id: t.id,
               taskName: t.taskName,
               customName: t.customName,
+              timeoutSecs: t.timeoutSecs,
+              timeoutRestarts: t.timeoutRestarts,
               enabled: t.enabled,
               optionValues: mergedValues,
               expanded: prevExpandedByTask.get(t.id) ?? false,
@@ -1076,6 +1120,12 @@ export const useAppStore = create<AppState>()(
           isRunning: prevRunningByInstance.get(inst.id) ?? false,
           schedulePolicies: inst.schedulePolicies,
           preActions: migratePreActions(inst),
+          runSpeed: inst.runSpeed,
+          actionIntervalMs: inst.actionIntervalMs,
+          tags: inst.tags,
+          captureResolution: inst.captureResolution,
+          appBackup: inst.appBackup,
+          serverReset: inst.serverReset,
         };
       });
 
@@ -1781,6 +1831,8 @@ export const useAppStore = create<AppState>()(
           id: generateId(),
           taskName: t.taskName,
           customName: t.customName,
+          timeoutSecs: t.timeoutSecs,
+          timeoutRestarts: t.timeoutRestarts,
           enabled: t.enabled,
           optionValues: cleanOptionValues(t.optionValues, pi),
           expanded: false,
@@ -1788,6 +1840,12 @@ export const useAppStore = create<AppState>()(
         isRunning: false,
         schedulePolicies: closedInstance.schedulePolicies,
         preActions: migratePreActions(closedInstance),
+        runSpeed: closedInstance.runSpeed,
+        actionIntervalMs: closedInstance.actionIntervalMs,
+        tags: closedInstance.tags,
+        captureResolution: closedInstance.captureResolution,
+        appBackup: closedInstance.appBackup,
+        serverReset: closedInstance.serverReset,
       };
 
       // 恢复选中的控制器和资源状态
@@ -2044,11 +2102,19 @@ function generateConfig(): MxuConfig {
         id: t.id,
         taskName: t.taskName,
         customName: t.customName,
+        timeoutSecs: t.timeoutSecs,
+        timeoutRestarts: t.timeoutRestarts,
         enabled: t.enabled,
         optionValues: t.optionValues,
       })),
       schedulePolicies: inst.schedulePolicies,
       preActions: inst.preActions,
+      runSpeed: inst.runSpeed,
+      actionIntervalMs: inst.actionIntervalMs,
+      tags: inst.tags,
+      captureResolution: inst.captureResolution,
+      appBackup: inst.appBackup,
+      serverReset: inst.serverReset,
     })),
     // WebUI 模式下保留后端原始的外观 & 布局设置，避免覆盖桌面端偏好
     ...(() => {
@@ -56,6 +56,7 @@ export interface UpdateInfo {
   fileSize?: number;
   filename?: string;
   downloadSource?: 'mirrorchyan' | 'github';
+  signatureUrl?: string; // 更新包分离签名的下载链接（内置发布公钥的构建安装前校验）
   // MirrorChyan API 错误信息
   errorCode?: number;
   errorMessage?: string;
@@ -187,6 +188,12 @@ export interface AppState {
   selectAllTasks: (instanceId: string, enabled: boolean) => void;
   collapseAllTasks: (instanceId: string, expanded: boolean) => void;
   renameTask: (instanceId: string, taskId: string, newName: string) => void;
+  setTaskTimeout: (
+    instanceId: string,
+    taskId: string,
+    timeoutSecs?: number,
+    timeoutRestarts?: number,
+  ) => void;
 
   // 任务右键菜单操作
   duplicateTask: (instanceId: string, taskId: string) => void;
@@ -12,6 +12,13 @@ export interface SchedulePolicy {
   enabled: boolean; // 是否启用
   weekdays: number[]; // 重复日期 (0-6, 0=周日)
   hours: number[]; // 开始时间 (0-23)
+  afterReset?: boolean; // 在实例的服务器每日重置后执行（忽略 hours）
+}
+
+// 游戏服务器每日重置时间
+export interface ServerResetSettings {
+  hour?: number; // 重置整点（0-23，默认 4）
+  timezone?: string; // 服务器时区（固定 UTC 偏移，如 UTC+8、-05:00），缺省为本机时区
 }
 
 // 保存的任务配置
@@ -21,6 +28,8 @@ export interface SavedTask {
   customName?: string; // 用户自定义名称
   enabled: boolean;
   optionValues: Record<string, OptionValue>;
+  timeoutSecs?: number; // 最长运行时间（秒），超时后停止
+  timeoutRestarts?: number; // 超时后自动重新执行的次数（最多 5 次）
 }
 
 // 保存的设备信息
@@ -62,6 +71,12 @@ export interface SavedInstance {
   // 定时执行策略列表
   schedulePolicies?: SchedulePolicy[];
   preActions?: ActionConfig[];
+  runSpeed?: number; // 运行速度倍率
+  actionIntervalMs?: number; // 最小操作间隔（毫秒）
+  tags?: string[]; // 分组标签
+  captureResolution?: CaptureResolution; // 截图分辨率预设（优先于 interface 的 display_short_side）
+  appBackup?: AppBackupSettings; // adb 应用数据备份的默认包名与范围
+  serverReset?: ServerResetSettings; // 服务器每日重置时间
   /** @deprecated 旧版单前置程序字段，仅用于向后兼容读取 */
   preAction?: LegacyActionConfig;
 }
@@ -91,6 +106,12 @@ export interface RecentlyClosedInstance {
   tasks: SavedTask[]; // 保存的任务配置
   schedulePolicies?: SchedulePolicy[]; // 定时执行策略
   preActions?: ActionConfig[];
+  runSpeed?: number; // 运行速度倍率
+  actionIntervalMs?: number; // 最小操作间隔（毫秒）
+  tags?: string[]; // 分组标签
+  captureResolution?: CaptureResolution; // 截图分辨率预设（优先于 interface 的 display_short_side）
+  appBackup?: AppBackupSettings; // adb 应用数据备份的默认包名与范围
+  serverReset?: ServerResetSettings; // 服务器每日重置时间
   /** @deprecated 旧版单前置程序字段，仅用于向后兼容读取 */
   preAction?: LegacyActionConfig;
 }
@@ -114,6 +135,105 @@ export interface ProxySettings {
   url: string; // 代理地址，格式：http://host:port 或 socks5://host:port
 }
 
+// 本地 REST 控制服务与事件推送设置（重启生效）
+export interface ApiServerSettings {
+  enabled: boolean;
+  port?: number; // 监听端口（默认 12711，仅绑定 127.0.0.1）
+  token: string; // 访问令牌（Authorization: Bearer <token>）
+}
+
+// MQTT 状态发布设置（启动时由后端读取，运行时通过 mqtt_configure / mqtt_enable 修改）
+export interface MqttSettings {
+  enabled: boolean;
+  host: string;
+  port?: number; // 默认 1883
+  username?: string;
+  password?: string;
+  clientId?: string;
+  topicPrefix?: string; // 主题前缀，默认 mxu
+  qos?: 0 | 1 | 2;
+}
+
+// 缓存自动清理策略（应用启动时由后端执行）
+export interface CachePruneSettings {
+  enabled: boolean;
+  maxTotalMb?: number; // 缓存总容量上限（MB），超出时从最旧的文件开始删除
+  maxAgeDays?: number; // 最长保留天数
+}
+
+// 截图历史（运行中每个节点结束时保存，按实例滚动）
+export interface ScreenshotHistorySettings {
+  capacity?: number; // 每个实例保留的截图数（默认 30，0 为关闭）
+  minIntervalMs?: number; // 两次保存的最小间隔（默认 1000ms）
+}
+
+// ADB 设备电量与温度监控
+export interface DeviceHealthSettings {
+  enabled?: boolean; // 是否启用（默认 true）
+  pollIntervalSecs?: number; // 检查间隔（默认 60 秒）
+  minBattery?: number; // 电量低于该百分比且未充电时停止任务（默认 15）
+  maxTemperature?: number; // 电池温度高于该值（°C）时停止任务（默认 45）
+}
+
+// 控制器断线重连看门狗
+export interface ReconnectWatchdogSettings {
+  enabled?: boolean; // 是否启用（默认 false）
+  intervalSecs?: number; // 检查间隔（默认 10 秒）
+  maxAttempts?: number; // 每次断线的最大重连次数（默认 3）
+  resumeTasks?: boolean; // 重连成功后重新提交未完成的任务（默认 false）
+}
+
+// 系统时钟偏差检测（NTP）
+export interface ClockDriftSettings {
+  enabled?: boolean; // 启动时是否检查（默认 true）
+  server?: string; // NTP 服务器（缺省依次尝试内置列表）
+  thresholdSecs?: number; // 偏差阈值（默认 60 秒）
+}
+
+// 程序文件完整性校验（签名清单）
+export interface IntegritySettings {
+  enabled?: boolean; // 启动时是否校验（默认 true）
+  policy?: 'warn' | 'block'; // 校验未通过时仅提示或拒绝启动任务（默认 warn）
+}
+
+// 游戏维护时段：一次性时段使用 start/end，每周时段使用 weekdays + startTime/endTime
+export interface MaintenanceWindow {
+  name: string;
+  enabled?: boolean; // 默认 true
+  start?: string; // 一次性时段开始（本地时间 YYYY-MM-DD HH:MM）
+  end?: string; // 一次性时段结束
+  weekdays?: number[]; // 每周生效的星期（1 = 周一 … 7 = 周日）
+  startTime?: string; // 每周时段开始（HH:MM）
+  endTime?: string; // 每周时段结束（不晚于 startTime 时视为跨越午夜）
+}
+
+export interface MaintenanceSettings {
+  policy?: 'refuse' | 'delay'; // 维护时段内启动任务：拒绝（默认）或等待结束后启动
+  maxDelayMinutes?: number; // delay 策略的最长等待时间（默认 180 分钟）
+  windows?: MaintenanceWindow[];
+}
+
+// 实例的 adb 应用数据备份设置
+export interface AppBackupSettings {
+  package?: string; // 目标应用包名
+  targets?: ('sharedPrefs' | 'data' | 'obb')[]; // 备份范围（sharedPrefs 需要 root）
+}
+
+// 实例截图分辨率预设：按短边缩放或使用原始分辨率
+export type CaptureResolution = { shortSide: number } | 'raw';
+
+// 下载设置（更新包、MaaFramework、资源下载）
+export interface DownloadSettings {
+  /** 自定义 User-Agent，`{default}` 会被替换为默认值 */
+  userAgent?: string;
+  /** 按主机名分组的请求头（如私有镜像的鉴权令牌），只发往对应主机，键可为 `host` 或 `host:port` */
+  headers?: Record<string, Record<string, string>>;
+  /** 下载限速（KB/s，0 或缺省为不限速） */
+  maxKbps?: number;
+  /** 分块下载并发连接数（默认 4，1 为禁用；服务器支持 Range 且文件不小于 16 MB 时生效） */
+  connections?: number;
+}
+
 // 快捷键设置
 export interface HotkeySettings {
   /** 开始任务快捷键（例如：F10） */
@@ -155,6 +275,20 @@ export interface AppSettings {
   tcpCompatMode?: boolean; // 通信兼容模式，强制使用 TCP 而非 IPC
   allowLanAccess?: boolean; // Web UI 允许局域网访问（绑定 0.0.0.0，重启生效）
   webServerPort?: number; // Web 服务器监听端口（默认 12701，重启生效）
+  apiServer?: ApiServerSettings; // 本地 REST 控制服务
+  mqtt?: MqttSettings; // MQTT 状态发布
+  preventSleep?: boolean; // 任务运行期间阻止系统休眠（默认 true）
+  adbKeepAwake?: boolean; // 任务运行期间保持 ADB 设备亮屏（默认 false）
+  deviceHealth?: DeviceHealthSettings; // ADB 设备电量与温度监控
+  reconnectWatchdog?: ReconnectWatchdogSettings; // 控制器断线重连看门狗
+  win32ElevationPolicy?: 'warn' | 'block' | 'restart'; // 目标窗口以管理员权限运行时的处理方式（默认 warn）
+  maintenance?: MaintenanceSettings; // 游戏维护时段
+  clockDrift?: ClockDriftSettings; // 系统时钟偏差检测
+  integrity?: IntegritySettings; // 程序文件完整性校验
+  asciiPathMapping?: boolean; // 安装路径含非 ASCII 字符时将传给 MaaFramework 的路径映射为 ASCII（默认 true）
+  cachePrune?: CachePruneSettings; // 缓存自动清理
+  download?: DownloadSettings; // 下载设置
+  screenshotHistory?: ScreenshotHistorySettings; // 截图历史
   minimizeToTray?: boolean; // 关闭时最小化到托盘（默认 false）
   autoStartInstanceId?: string; // 启动后自动执行的实例 ID（为空或 undefined 表示不自动执行）
   autoRunOnLaunch?: boolean; // 非开机自启动的手动启动场景下，是否也自动执行选定的实例（默认 false）
@@ -1,5 +1,7 @@
 // MaaFramework ProjectInterface V2 协议类型定义
 
+import type { AppBackupSettings, CaptureResolution, ServerResetSettings } from './config';
+
 export interface ProjectInterface {
   interface_version: 2;
   languages?: Record<string, string>;
@@ -45,6 +47,14 @@ export interface AgentConfig {
   identifier?: string;
   /** 连接超时时间（毫秒），-1 表示无限等待 */
   timeout?: number;
+  /** TCP 兼容模式下使用的固定端口 */
+  tcp_port?: number;
+  /** TCP 兼容模式下 Agent 连接的地址（默认 127.0.0.1） */
+  tcp_interface?: string;
+  /** tcp_interface 为非回环地址时允许连接的 Agent 地址（IP 或主机名），必须填写 */
+  tcp_allowed_peers?: string[];
+  /** 远程 Agent 地址（host:port），设置后不启动本地子进程 */
+  remote?: string;
 }
 
 /**
@@ -103,6 +113,12 @@ export interface GamepadConfig {
   window_regex?: string;
   gamepad_type?: 'Xbox360' | 'DualShock4' | 'DS4';
   screencap?: string;
+  /** 摇杆死区（0-100，百分比） */
+  stick_dead_zone?: number;
+  /** 扳机灵敏度（1-100，百分比） */
+  trigger_sensitivity?: number;
+  /** 轮询频率（Hz） */
+  polling_rate?: number;
 }
 
 export interface ResourceItem {
@@ -216,6 +232,8 @@ export interface SelectedTask {
   enabled: boolean;
   optionValues: Record<string, OptionValue>;
   expanded: boolean;
+  timeoutSecs?: number; // 最长运行时间（秒），超时后停止
+  timeoutRestarts?: number; // 超时后自动重新执行的次数
 }
 
 export type OptionValue =
@@ -253,6 +271,7 @@ export interface SchedulePolicy {
   enabled: boolean; // 是否启用
   weekdays: number[]; // 重复日期 (0-6, 0=周日)
   hours: number[]; // 开始时间 (0-23)
+  afterReset?: boolean; // 在实例的服务器每日重置后执行（忽略 hours）
 }
 
 // pre-action config
@@ -283,6 +302,18 @@ export interface Instance {
   // 定时执行策略列表
   schedulePolicies?: SchedulePolicy[];
   preActions?: ActionConfig[];
+  // 运行速度倍率（缩放节点延迟，默认 1）
+  runSpeed?: number;
+  // 最小操作间隔（毫秒，防止操作过于密集）
+  actionIntervalMs?: number;
+  // 分组标签（可按标签批量开始/停止）
+  tags?: string[];
+  // 截图分辨率预设（优先于 interface 的 display_short_side）
+  captureResolution?: CaptureResolution;
+  // adb 应用数据备份的默认包名与范围
+  appBackup?: AppBackupSettings;
+  // 服务器每日重置时间
+  serverReset?: ServerResetSettings;
 }
 
 /** v2.3.0: 预设中的任务配置 */
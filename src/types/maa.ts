@@ -1,5 +1,7 @@
 // MaaFramework 类型定义
 
+import type { GamepadConfig } from './interface';
+
 /** ADB 设备信息 */
 export interface AdbDevice {
   name: string;
@@ -15,6 +17,14 @@ export interface Win32Window {
   handle: number;
   class_name: string;
   window_name: string;
+  /** 所属进程 ID */
+  pid?: number | null;
+  /** 所属进程名 */
+  process_name?: string | null;
+  /** 窗口矩形（屏幕坐标） */
+  rect?: { x: number; y: number; width: number; height: number } | null;
+  /** DPI 缩放比例（1.0 = 96 DPI） */
+  dpi_scale?: number | null;
 }
 
 /** ADB 控制器配置 */
@@ -36,6 +46,8 @@ export interface Win32ControllerConfig {
   mouse_method: number;
   keyboard_method: number;
   display_short_side?: number;
+  /** DXGI 桌面复制截取的显示器设备名（如 \\.\DISPLAY2），缺省为窗口当前所在显示器 */
+  monitor?: string;
 }
 
 /** WlRoots 控制器配置 (Linux) */
@@ -58,6 +70,11 @@ export interface GamepadControllerConfig {
   type: 'Gamepad';
   handle: number;
   display_short_side?: number;
+  stick_dead_zone?: number;
+  trigger_sensitivity?: number;
+  polling_rate?: number;
+  /** DXGI 桌面复制截取的显示器设备名，缺省为窗口当前所在显示器 */
+  monitor?: string;
 }
 
 /** 控制器配置 */
@@ -68,6 +85,125 @@ export type ControllerConfig =
   | PlayCoverControllerConfig
   | GamepadControllerConfig;
 
+/** 录制的输入 */
+export type RecordedInput =
+  | { type: 'click'; x: number; y: number }
+  | { type: 'swipe'; x1: number; y1: number; x2: number; y2: number; duration: number };
+
+/** 输入脚本（录制 / 回放） */
+export interface InputScript {
+  version: number;
+  steps: (RecordedInput & { delayMs: number })[];
+}
+
+/** 识别测试候选结果 */
+export interface RecognitionCandidate {
+  box: [number, number, number, number];
+  score?: number | null;
+  text?: string | null;
+}
+
+/** 识别测试结果 */
+export interface QuickRecognitionResult {
+  hit: boolean;
+  best?: RecognitionCandidate | null;
+  filtered: RecognitionCandidate[];
+  all: RecognitionCandidate[];
+  cost_ms: number;
+}
+
+/** 图像对比结果 */
+export interface ImageDiffResult {
+  width: number;
+  height: number;
+  /** 分辨率不同，imageB 已缩放到 imageA 的尺寸 */
+  resized: boolean;
+  originalBSize: [number, number];
+  /** 平均差异（0 完全相同，1 完全不同） */
+  score: number;
+  /** 差异超过阈值的像素占比 */
+  changedRatio: number;
+  /** 平均亮度差（imageB - imageA） */
+  brightnessDelta: number;
+  /** 热力图 PNG 路径 */
+  heatmapPath: string;
+}
+
+/** 屏幕方向 */
+export type Orientation = 'landscape' | 'portrait';
+
+/** 控制器信息 */
+export interface ControllerInfo {
+  uuid: string | null;
+  /** 原始截图分辨率（尚未截图时为 0） */
+  width: number;
+  height: number;
+  orientation: Orientation;
+}
+
+/** 控制器分辨率变化事件 */
+export interface ResolutionChangedEvent {
+  instance_id: string;
+  previous: [number, number];
+  current: [number, number];
+  orientation: Orientation;
+}
+
+/** ADB 设备健康状态（无法读取的字段为 null） */
+export interface DeviceHealth {
+  /** 电量百分比 */
+  batteryLevel: number | null;
+  charging: boolean | null;
+  /** 电池温度（°C） */
+  batteryTemperature: number | null;
+  /** Android 热状态（0 NONE ~ 6 SHUTDOWN） */
+  thermalStatus: number | null;
+}
+
+/** 启动程序并连接窗口的结果 */
+export interface LaunchAttachResult {
+  pid: number;
+  window: Win32Window;
+  /** 连接请求 ID，通过 maa-callback 获取连接结果 */
+  connId: number;
+}
+
+/** 显示器信息 */
+export interface MonitorInfo {
+  /** 设备名（如 \\.\DISPLAY1），用于控制器配置的 monitor 字段 */
+  deviceName: string;
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+  primary: boolean;
+}
+
+/** Win32 窗口 DPI 信息 */
+export interface WindowDpiInfo {
+  dpi: number;
+  /** 缩放百分比（96 DPI 为 100） */
+  scalePercent: number;
+  awareness: 'unaware' | 'system' | 'perMonitor' | 'unknown';
+  /** 坐标或截图尺寸是否可能被系统缩放影响 */
+  scalingRisk: boolean;
+}
+
+/** 应用数据备份范围 */
+export type AppBackupTarget = 'sharedPrefs' | 'data' | 'obb';
+
+/** 一次应用数据备份 */
+export interface AppBackupInfo {
+  id: string;
+  package: string;
+  /** 成功备份的范围 */
+  targets: AppBackupTarget[];
+  /** 备份失败的范围及原因 */
+  failures: [AppBackupTarget, string][];
+  createdAt: string;
+  path: string;
+}
+
 /** 连接状态 */
 export type ConnectionStatus = 'Disconnected' | 'Connecting' | 'Connected' | { Failed: string };
 
@@ -158,6 +294,17 @@ export function parseWin32ScreencapMethod(name: string | string[]): number {
   return Number(Win32ScreencapMethod.FramePool);
 }
 
+/** 从 interface 的 gamepad 配置中提取手柄输入参数 */
+export function getGamepadTuning(
+  gamepad?: GamepadConfig,
+): Pick<GamepadControllerConfig, 'stick_dead_zone' | 'trigger_sensitivity' | 'polling_rate'> {
+  return {
+    stick_dead_zone: gamepad?.stick_dead_zone,
+    trigger_sensitivity: gamepad?.trigger_sensitivity,
+    polling_rate: gamepad?.polling_rate,
+  };
+}
+
 /** 解析 Win32 输入方法名称 */
 export function parseWin32InputMethod(name: string): number {
   const method = Win32InputMethodNames[name];
@@ -175,6 +322,14 @@ export interface AgentConfig {
   identifier?: string;
   /** 连接超时时间（毫秒），-1 表示无限等待 */
   timeout?: number;
+  /** TCP 兼容模式下使用的固定端口 */
+  tcp_port?: number;
+  /** TCP 兼容模式下 Agent 连接的地址（默认 127.0.0.1） */
+  tcp_interface?: string;
+  /** tcp_interface 为非回环地址时允许连接的 Agent 地址（IP 或主机名），必须填写 */
+  tcp_allowed_peers?: string[];
+  /** 远程 Agent 地址（host:port），设置后不启动本地子进程 */
+  remote?: string;
 }
 
 /** 任务配置 */
@@ -183,4 +338,92 @@ export interface TaskConfig {
   pipeline_override: string;
   /** 对应的前端选中任务 ID（用于后端跟踪 per-task 状态） */
   selected_task_id?: string;
+  /** 任务最长运行时间（秒），超时后由后端看门狗停止 */
+  timeout_secs?: number;
+  /** 超时后自动重新执行的次数 */
+  timeout_restarts?: number;
+  /** 依赖的前置任务（同批任务列表中的下标），前置任务失败时跳过本任务 */
+  depends_on?: number[];
+  /** 执行条件表达式（如 `task[0].succeeded && vars.stamina > 100`），不满足时跳过本任务 */
+  run_if?: string;
+}
+
+/** 实例概要（配置信息 + 运行状态） */
+export interface InstanceSummary {
+  id: string;
+  name: string;
+  tags: string[];
+  connected: boolean;
+  resourceLoaded: boolean;
+  isRunning: boolean;
+}
+
+/** 实例筛选条件（同时指定时取交集） */
+export interface InstanceFilter {
+  tag?: string;
+  name?: string;
+  running?: boolean;
+}
+
+/** 控制器重连事件 */
+export interface ControllerReconnectEvent {
+  instanceId: string;
+  phase: 'lost' | 'reconnecting' | 'reconnected' | 'resumed' | 'failed';
+  attempt: number;
+  message: string | null;
+}
+
+/** 会话恢复结果 */
+export interface SessionRestoreResult {
+  /** 快照保存时间，无快照时为 null */
+  savedAt: string | null;
+  restored: string[];
+  failed: { instanceId: string; error: string }[];
+}
+
+/** 运行报告中的单个任务记录 */
+export interface TaskReport {
+  taskId: number;
+  entry: string;
+  selectedTaskId: string | null;
+  status: 'pending' | 'running' | 'succeeded' | 'failed';
+  startedAt: string | null;
+  finishedAt: string | null;
+  durationMs: number | null;
+  /** 失败时的截图路径 */
+  errorScreenshot: string | null;
+}
+
+/** 一次运行的总结报告 */
+export interface RunReport {
+  runId: string;
+  instanceId: string;
+  startedAt: string;
+  finishedAt: string | null;
+  durationMs: number | null;
+  status: 'Running' | 'Succeeded' | 'Failed' | 'Stopped';
+  succeeded: number;
+  failed: number;
+  tasks: TaskReport[];
+}
+
+/** 任务失败截图记录 */
+export interface FailureCapture {
+  id: string;
+  instanceId: string;
+  taskId: number;
+  entry: string;
+  runId: string | null;
+  capturedAt: string;
+  imagePath: string;
+}
+
+/** 截图历史记录 */
+export interface ScreenshotHistoryEntry {
+  instanceId: string;
+  /** 截图时间（YYYY-MM-DD HH:MM:SS.mmm） */
+  capturedAt: string;
+  /** 截图时所在的节点 */
+  node: string;
+  imagePath: string;
 }
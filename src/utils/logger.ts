@@ -4,6 +4,7 @@
  */
 
 import log from 'loglevel';
+import { invoke } from '@tauri-apps/api/core';
 import { getDebugDir, isTauri as checkTauri } from './paths';
 
 // 日志级别类型
@@ -28,19 +29,16 @@ async function initFileLogger(): Promise<void> {
   try {
     logsDir = await getDebugDir();
 
-    const { mkdir, exists, readDir } = await import('@tauri-apps/plugin-fs');
-    if (!(await exists(logsDir))) {
-      await mkdir(logsDir, { recursive: true });
-    }
-
     const today = formatLocalDateTime(new Date(), 'date');
     let maxIndex = 0;
     try {
-      const entries = await readDir(logsDir);
+      const entries = await invoke<{ name: string; isFile: boolean }[]>('list_local_dir', {
+        dirname: logsDir,
+      });
       const prefix = `${today}-`;
       for (const entry of entries) {
         if (!entry.isFile) continue;
-        const name = entry.name ?? '';
+        const name = entry.name;
         if (!name.startsWith(prefix) || !name.endsWith('.log')) continue;
         const idx = Number.parseInt(name.slice(prefix.length, -'.log'.length), 10);
         if (Number.isFinite(idx) && idx > maxIndex) {
@@ -90,8 +88,7 @@ async function writeLogToFile(line: string): Promise<void> {
   const logFile = `${logsDir}/${logFileName}`;
 
   try {
-    const { writeTextFile } = await import('@tauri-apps/plugin-fs');
-    await writeTextFile(logFile, line + '\n', { append: true });
+    await invoke('write_local_file', { filename: logFile, content: line + '\n', append: true });
   } catch {
     // 写入失败时静默处理
   }
@@ -0,0 +1,69 @@
+/**
+ * 后端结构化错误
+ * 实例、控制器、资源、任务与 Agent 相关命令失败时 invoke 抛出
+ * `{ code, message, context?, hint?, details? }`，下载与全量更新的磁盘空间预检也抛出该结构；
+ * 文件、配置等其余命令仍抛出字符串
+ */
+
+/** 后端错误码 */
+export type MxuErrorCode =
+  | 'NOT_INITIALIZED'
+  | 'INSTANCE_NOT_FOUND'
+  | 'CONTROLLER_NOT_CONNECTED'
+  | 'RESOURCE_NOT_LOADED'
+  | 'TARGET_ELEVATED'
+  | 'AGENT_FAILED'
+  | 'ADB_UNAUTHORIZED'
+  | 'INSUFFICIENT_DISK_SPACE'
+  | 'PATH_TOO_LONG'
+  | 'INVALID_ARGUMENT'
+  | 'UNKNOWN';
+
+export interface MxuError {
+  code: MxuErrorCode;
+  message: string;
+  /** 出错时的上下文（如实例 ID、文件路径） */
+  context?: string;
+  /** 恢复建议 */
+  hint?: string;
+  /** 结构化数据（如 INSUFFICIENT_DISK_SPACE 的 path、requiredBytes、availableBytes） */
+  details?: Record<string, unknown>;
+}
+
+/** 是否为后端结构化错误 */
+export function isMxuError(err: unknown): err is MxuError {
+  return (
+    typeof err === 'object' &&
+    err !== null &&
+    typeof (err as MxuError).code === 'string' &&
+    typeof (err as MxuError).message === 'string'
+  );
+}
+
+/** 获取错误码，非结构化错误返回 undefined */
+export function getErrorCode(err: unknown): MxuErrorCode | undefined {
+  return isMxuError(err) ? err.code : undefined;
+}
+
+/** 将 invoke 抛出的任意错误转为可显示的文本 */
+export function getErrorMessage(err: unknown): string {
+  if (isMxuError(err)) return err.message;
+  if (err instanceof Error) return err.message;
+  return String(err);
+}
+
+type Translate = (key: string, options?: Record<string, unknown>) => string;
+
+/**
+ * 将 invoke 抛出的错误转为本地化文本
+ * 结构化错误按错误码显示本地化说明与恢复建议，未归类的错误显示原始消息
+ * @param fallback 取不到错误消息时的兜底文本
+ */
+export function describeError(err: unknown, t: Translate, fallback = ''): string {
+  if (isMxuError(err) && err.code !== 'UNKNOWN') {
+    const hint = t(`errors.hints.${err.code}`, { defaultValue: '' });
+    const text = `${t(`errors.codes.${err.code}`)}: ${err.message}`;
+    return hint ? `${text}\n${hint}` : text;
+  }
+  return getErrorMessage(err) || fallback;
+}